@@ -1,7 +1,7 @@
 //! Consolidation phase of the interop proof program.
 
 use super::FaultProofProgramError;
-use crate::interop::util::fetch_output_block_hash;
+use crate::interop::util::{fetch_output_block_hash, prefetch_output_roots};
 use alloc::sync::Arc;
 use alloy_evm::{EvmFactory, FromRecoveredTx, FromTxWithEncoded};
 use core::fmt::Debug;
@@ -51,6 +51,9 @@ where
         .map(|(optimistic_block, pre_state)| (pre_state, optimistic_block.block_hash))
         .collect::<HashMap<_, _>>();
 
+    // Fetch all cross-safe output roots from the host in one round trip.
+    prefetch_output_roots(oracle.as_ref(), &transition_state.pre_state.output_roots).await?;
+
     let mut headers = HashMap::default();
     let mut l2_providers = HashMap::default();
     for (cross_safe_output, local_safe_block_hash) in transition_meta {
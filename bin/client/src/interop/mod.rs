@@ -3,7 +3,7 @@
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::B256;
 use consolidate::consolidate_dependencies;
-use core::{cmp::Ordering, fmt::Debug};
+use core::{fmt::Debug, num::NonZeroUsize};
 use kona_derive::PipelineErrorKind;
 use kona_driver::DriverError;
 use kona_executor::ExecutorError;
@@ -51,24 +51,52 @@ pub enum FaultProofProgramError {
     MissingRollupConfig(u64),
 }
 
+/// The default number of preimages kept in the [CachingOracle] of the interop program.
+///
+/// Every cached entry holds a full preimage, which is typically a trie node of at most ~532
+/// bytes, but can be as large as a contract's bytecode or a block's encoded transactions. Memory
+/// usage therefore grows linearly with the LRU size, so larger sizes should only be used when the
+/// program runs with enough memory, e.g. for consolidations across many chains.
+pub const DEFAULT_ORACLE_LRU_SIZE: NonZeroUsize = NonZeroUsize::new(1024).unwrap();
+
 /// Executes the interop fault proof program with the given [PreimageOracleClient] and
-/// [HintWriterClient].
+/// [HintWriterClient], caching up to `oracle_lru_size` preimages.
 #[inline]
-pub async fn run<P, H>(oracle_client: P, hint_client: H) -> Result<(), FaultProofProgramError>
+pub async fn run<P, H>(
+    oracle_client: P,
+    hint_client: H,
+    oracle_lru_size: NonZeroUsize,
+) -> Result<(), FaultProofProgramError>
 where
     P: PreimageOracleClient + Send + Sync + Debug + Clone + 'static,
     H: HintWriterClient + Send + Sync + Debug + Clone + 'static,
 {
-    const ORACLE_LRU_SIZE: usize = 1024;
+    run_with_step_budget(oracle_client, hint_client, oracle_lru_size, None).await.map(|_| ())
+}
 
+/// Executes the interop fault proof program like [run], taking at most `step_budget` transition
+/// steps if set.
+///
+/// If the budget runs out before the claimed post-state is reached, the partial [PreState] is
+/// returned, and the program can be resumed by running it with the partial [PreState] as the
+/// agreed pre-state. Without a budget, `None` is always returned on success.
+pub async fn run_with_step_budget<P, H>(
+    oracle_client: P,
+    hint_client: H,
+    oracle_lru_size: NonZeroUsize,
+    step_budget: Option<u64>,
+) -> Result<Option<PreState>, FaultProofProgramError>
+where
+    P: PreimageOracleClient + Send + Sync + Debug + Clone + 'static,
+    H: HintWriterClient + Send + Sync + Debug + Clone + 'static,
+{
     // Instantiate the oracle and bootstrap the program from local inputs.
-    let oracle =
-        Arc::new(CachingOracle::new(ORACLE_LRU_SIZE, oracle_client.clone(), hint_client.clone()));
+    let oracle = new_caching_oracle(oracle_lru_size, oracle_client.clone(), hint_client.clone());
     let boot = match BootInfo::load(oracle.as_ref()).await {
         Ok(boot) => boot,
         Err(BootstrapError::InvalidToInvalid) => {
             info!(target: "client_interop", "No-op transition, short-circuiting.");
-            return Ok(());
+            return Ok(None);
         }
         Err(e) => {
             error!(target: "client_interop", "Failed to load boot info: {}", e);
@@ -86,7 +114,7 @@ where
             // post-state must be the agreed pre-state to accommodate trace extension.
             if super_root.timestamp >= boot.claimed_l2_timestamp {
                 if boot.agreed_pre_state_commitment == boot.claimed_post_state {
-                    return Ok(());
+                    return Ok(None);
                 } else {
                     return Err(FaultProofProgramError::InvalidClaim(
                         boot.agreed_pre_state_commitment,
@@ -96,33 +124,50 @@ where
             }
 
             // If the pre-state is a super root, the first sub-problem is always selected.
-            sub_transition(oracle, boot, evm_factory).await
+            sub_transition(oracle, boot, evm_factory, step_budget).await
         }
         PreState::TransitionState(ref transition_state) => {
-            // If the claimed L2 block timestamp is less than the prestate timestamp, the
-            // claim must be invalid.
-            if transition_state.pre_state.timestamp >= boot.claimed_l2_timestamp {
-                return Err(FaultProofProgramError::InvalidClaim(
-                    boot.agreed_pre_state_commitment,
-                    boot.claimed_post_state,
-                ));
-            }
-
             // If the pre-state is a transition state, the sub-problem is selected based on the
-            // current step.
-            match transition_state.step.cmp(&TRANSITION_STATE_MAX_STEPS) {
-                Ordering::Equal => consolidate_dependencies(oracle, boot, evm_factory).await,
-                Ordering::Less => sub_transition(oracle, boot, evm_factory).await,
-                Ordering::Greater => {
-                    error!(
-                        target: "client_interop",
-                        transition_state_step = transition_state.step,
-                        transition_state_max_steps = TRANSITION_STATE_MAX_STEPS,
-                        "Invalid transition state step; 'step' is greater than maximum value."
-                    );
-                    Err(FaultProofProgramError::StateTransitionFailed)
-                }
+            // current step. A claimed timestamp at or before the pre-state and steps past the last
+            // one are rejected when loading the boot info.
+            if transition_state.step == TRANSITION_STATE_MAX_STEPS {
+                consolidate_dependencies(oracle, boot, evm_factory).await.map(|_| None)
+            } else {
+                sub_transition(oracle, boot, evm_factory, step_budget).await
             }
         }
     }
 }
+
+/// Creates the [CachingOracle] used by the interop program, holding up to `oracle_lru_size`
+/// preimages.
+fn new_caching_oracle<P, H>(
+    oracle_lru_size: NonZeroUsize,
+    oracle_client: P,
+    hint_client: H,
+) -> Arc<CachingOracle<P, H>>
+where
+    P: PreimageOracleClient,
+    H: HintWriterClient,
+{
+    Arc::new(CachingOracle::new(oracle_lru_size.get(), oracle_client, hint_client))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_preimage::{BidirectionalChannel, HintWriter, OracleReader};
+
+    #[test]
+    fn test_new_caching_oracle_size() {
+        let preimage = BidirectionalChannel::new().unwrap();
+        let hint = BidirectionalChannel::new().unwrap();
+
+        let oracle = new_caching_oracle(
+            DEFAULT_ORACLE_LRU_SIZE.saturating_mul(NonZeroUsize::new(4).unwrap()),
+            OracleReader::new(preimage.client),
+            HintWriter::new(hint.client),
+        );
+        assert_eq!(oracle.cache_size(), 4 * DEFAULT_ORACLE_LRU_SIZE.get());
+    }
+}
@@ -18,18 +18,114 @@ use kona_proof::{
     l2::OracleL2ChainProvider,
     sync::new_oracle_pipeline_cursor,
 };
-use kona_proof_interop::{BootInfo, INVALID_TRANSITION_HASH, OptimisticBlock, PreState};
+use kona_proof_interop::{
+    BootInfo, INVALID_TRANSITION_HASH, OptimisticBlock, PreState, TRANSITION_STATE_MAX_STEPS,
+};
 use op_alloy_consensus::OpTxEnvelope;
 use op_revm::OpSpecId;
 use tracing::{error, info, warn};
 
+/// The outcome of deriving the next step of the transition.
+enum TransitionStep {
+    /// The pre-state transitions with the given [OptimisticBlock], if any.
+    Transition(Option<OptimisticBlock>),
+    /// The data source was exhausted before the disputed L2 block could be derived.
+    Exhausted,
+}
+
 /// Executes a sub-transition of the interop proof with the given [PreimageOracleClient] and
 /// [HintWriterClient].
+///
+/// Without a `step_budget`, a single step is taken from the agreed pre-state and checked against
+/// the claimed post-state. With a budget, steps are taken until the claimed post-state is reached,
+/// up to `step_budget` steps. If the budget runs out first, the partial [PreState] is returned,
+/// and the transition can be resumed by passing it as the agreed pre-state of the next call.
 pub(crate) async fn sub_transition<P, H, Evm>(
     oracle: Arc<CachingOracle<P, H>>,
-    boot: BootInfo,
+    mut boot: BootInfo,
     evm_factory: Evm,
-) -> Result<(), FaultProofProgramError>
+    step_budget: Option<u64>,
+) -> Result<Option<PreState>, FaultProofProgramError>
+where
+    P: PreimageOracleClient + Send + Sync + Debug + Clone,
+    H: HintWriterClient + Send + Sync + Debug + Clone,
+    Evm: EvmFactory<Spec = OpSpecId> + Send + Sync + Debug + Clone + 'static,
+    <Evm as EvmFactory>::Tx: FromTxWithEncoded<OpTxEnvelope> + FromRecoveredTx<OpTxEnvelope>,
+{
+    let Some(step_budget) = step_budget else {
+        match next_step(oracle, &boot, evm_factory).await? {
+            TransitionStep::Transition(optimistic_block) => transition_and_check(
+                boot.agreed_pre_state,
+                optimistic_block,
+                boot.claimed_post_state,
+            )?,
+            TransitionStep::Exhausted => check_invalid_transition(boot.claimed_post_state)?,
+        }
+        return Ok(None);
+    };
+
+    for _ in 0..step_budget {
+        // The sub-transitions end at the consolidation step.
+        if let PreState::TransitionState(ref transition_state) = boot.agreed_pre_state {
+            if transition_state.step >= TRANSITION_STATE_MAX_STEPS {
+                break;
+            }
+        }
+
+        let optimistic_block = match next_step(oracle.clone(), &boot, evm_factory.clone()).await? {
+            TransitionStep::Transition(optimistic_block) => optimistic_block,
+            TransitionStep::Exhausted => {
+                check_invalid_transition(boot.claimed_post_state)?;
+                return Ok(None);
+            }
+        };
+        boot.agreed_pre_state = boot
+            .agreed_pre_state
+            .transition(optimistic_block)
+            .ok_or(FaultProofProgramError::StateTransitionFailed)?;
+        boot.agreed_pre_state_commitment = boot.agreed_pre_state.hash();
+
+        if boot.agreed_pre_state_commitment == boot.claimed_post_state {
+            info!(
+                target: "interop_client",
+                "Successfully validated progressed transition state with commitment {post_state_commitment}",
+                post_state_commitment = boot.claimed_post_state
+            );
+            return Ok(None);
+        }
+    }
+
+    if matches!(
+        boot.agreed_pre_state,
+        PreState::TransitionState(ref transition_state)
+            if transition_state.step >= TRANSITION_STATE_MAX_STEPS
+    ) {
+        error!(
+            target: "interop_client",
+            "Reached the consolidation step without the claimed post-state commitment {expected}",
+            expected = boot.claimed_post_state
+        );
+        return Err(FaultProofProgramError::InvalidClaim(
+            boot.claimed_post_state,
+            boot.agreed_pre_state_commitment,
+        ));
+    }
+
+    info!(
+        target: "interop_client",
+        "Exhausted step budget of {step_budget} at transition state commitment {commitment}",
+        commitment = boot.agreed_pre_state_commitment
+    );
+    Ok(Some(boot.agreed_pre_state))
+}
+
+/// Derives the [OptimisticBlock] of the active chain that the agreed pre-state of `boot` is
+/// transitioned with.
+async fn next_step<P, H, Evm>(
+    oracle: Arc<CachingOracle<P, H>>,
+    boot: &BootInfo,
+    evm_factory: Evm,
+) -> Result<TransitionStep, FaultProofProgramError>
 where
     P: PreimageOracleClient + Send + Sync + Debug + Clone,
     H: HintWriterClient + Send + Sync + Debug + Clone,
@@ -44,7 +140,7 @@ where
                 "No derivation/execution required, transition state is already saturated."
             );
 
-            return transition_and_check(boot.agreed_pre_state, None, boot.claimed_post_state);
+            return Ok(TransitionStep::Transition(None));
         }
     }
 
@@ -87,11 +183,7 @@ where
             .active_l2_output_root()
             .ok_or(FaultProofProgramError::StateTransitionFailed)?;
         let optimistic_block = OptimisticBlock::new(safe_head.hash(), active_root.output_root);
-        return transition_and_check(
-            boot.agreed_pre_state,
-            Some(optimistic_block),
-            boot.claimed_post_state,
-        );
+        return Ok(TransitionStep::Transition(Some(optimistic_block)));
     }
 
     // Create a new derivation driver with the given boot information and oracle.
@@ -130,34 +222,17 @@ where
     // Run the derivation pipeline until we are able to produce the output root of the claimed
     // L2 block.
     match driver.advance_to_target(rollup_config.as_ref(), Some(disputed_l2_block_number)).await {
-        Ok((safe_head, output_root)) => {
-            let optimistic_block = OptimisticBlock::new(safe_head.block_info.hash, output_root);
-            transition_and_check(
-                boot.agreed_pre_state,
-                Some(optimistic_block),
-                boot.claimed_post_state,
-            )?;
-
-            info!(
-                target: "interop_client",
-                "Successfully validated progressed transition state claim with commitment {post_state_commitment}",
-                post_state_commitment = boot.claimed_post_state
-            );
-
-            Ok(())
-        }
+        Ok((safe_head, output_root)) => Ok(TransitionStep::Transition(Some(OptimisticBlock::new(
+            safe_head.block_info.hash,
+            output_root,
+        )))),
         Err(DriverError::Pipeline(PipelineErrorKind::Critical(PipelineError::EndOfSource))) => {
             warn!(
                 target: "interop_client",
                 "Exhausted data source; Transitioning to invalid state."
             );
 
-            (boot.claimed_post_state == INVALID_TRANSITION_HASH).then_some(()).ok_or(
-                FaultProofProgramError::InvalidClaim(
-                    INVALID_TRANSITION_HASH,
-                    boot.claimed_post_state,
-                ),
-            )
+            Ok(TransitionStep::Exhausted)
         }
         Err(e) => {
             error!(
@@ -170,6 +245,14 @@ where
     }
 }
 
+/// Checks that the claimed post-state is the invalid transition, after the data source was
+/// exhausted.
+fn check_invalid_transition(claimed_post_state: B256) -> Result<(), FaultProofProgramError> {
+    (claimed_post_state == INVALID_TRANSITION_HASH)
+        .then_some(())
+        .ok_or(FaultProofProgramError::InvalidClaim(INVALID_TRANSITION_HASH, claimed_post_state))
+}
+
 /// Transitions the [PreState] with the given [OptimisticBlock] and checks if the resulting state
 /// commitment matches the expected commitment.
 fn transition_and_check(
@@ -211,3 +294,101 @@ fn transition_and_check(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fpvm_evm::FpvmOpEvmFactory, interop::new_caching_oracle};
+    use alloc::{vec, vec::Vec};
+    use kona_interop::{OutputRootWithChain, SuperRoot};
+    use kona_preimage::{BidirectionalChannel, HintWriter, OracleReader};
+    use kona_proof_interop::TransitionState;
+
+    fn boot_info(agreed_pre_state: PreState, claimed_post_state: B256) -> BootInfo {
+        BootInfo {
+            l1_head: B256::ZERO,
+            agreed_pre_state_commitment: agreed_pre_state.hash(),
+            agreed_pre_state,
+            claimed_post_state,
+            claimed_l2_timestamp: 11,
+            rollup_configs: Default::default(),
+            l1_config: Default::default(),
+        }
+    }
+
+    fn transition(pre_state: &PreState, steps: u64) -> PreState {
+        (0..steps).fold(pre_state.clone(), |state, _| state.transition(None).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_sub_transition_step_budget() {
+        let preimage = BidirectionalChannel::new().unwrap();
+        let hint = BidirectionalChannel::new().unwrap();
+        let oracle_reader = OracleReader::new(preimage.client);
+        let hint_writer = HintWriter::new(hint.client);
+        let oracle = new_caching_oracle(16, oracle_reader.clone(), hint_writer.clone());
+        let evm_factory = FpvmOpEvmFactory::new(hint_writer, oracle_reader);
+
+        // A saturated transition state only takes padding steps, which need no derivation.
+        let super_root = SuperRoot::new(
+            10,
+            vec![
+                OutputRootWithChain::new(1, B256::repeat_byte(1)),
+                OutputRootWithChain::new(2, B256::repeat_byte(2)),
+            ],
+        );
+        let pre_state = PreState::TransitionState(TransitionState::new(
+            super_root,
+            vec![OptimisticBlock::default(); 2],
+            2,
+        ));
+        let final_state = transition(&pre_state, 8);
+        let claimed_post_state = final_state.hash();
+
+        let mut state = pre_state.clone();
+        let mut partial_states = Vec::new();
+        while let Some(partial_state) = sub_transition(
+            oracle.clone(),
+            boot_info(state, claimed_post_state),
+            evm_factory.clone(),
+            Some(3),
+        )
+        .await
+        .unwrap()
+        {
+            partial_states.push(partial_state.clone());
+            state = partial_state;
+        }
+        assert_eq!(partial_states, vec![transition(&pre_state, 3), transition(&pre_state, 6)]);
+
+        // Without a budget, the last step is checked against the claim.
+        let last_state = transition(&pre_state, 7);
+        assert!(
+            sub_transition(
+                oracle.clone(),
+                boot_info(last_state, claimed_post_state),
+                evm_factory.clone(),
+                None,
+            )
+            .await
+            .unwrap()
+            .is_none()
+        );
+
+        // The budgeted transition ends at the consolidation step.
+        let mut consolidation_state = pre_state;
+        if let PreState::TransitionState(ref mut transition_state) = consolidation_state {
+            transition_state.step = TRANSITION_STATE_MAX_STEPS - 1;
+        }
+        assert!(matches!(
+            sub_transition(
+                oracle,
+                boot_info(consolidation_state, B256::ZERO),
+                evm_factory,
+                Some(3),
+            )
+            .await,
+            Err(FaultProofProgramError::InvalidClaim(..))
+        ));
+    }
+}
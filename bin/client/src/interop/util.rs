@@ -1,7 +1,8 @@
 //! Utilities for the interop proof program
 
-use alloc::string::ToString;
+use alloc::{string::ToString, vec::Vec};
 use alloy_primitives::B256;
+use kona_interop::OutputRootWithChain;
 use kona_preimage::{CommsClient, PreimageKey, errors::PreimageOracleError};
 use kona_proof::errors::OracleProviderError;
 use kona_proof_interop::{HintType, PreState};
@@ -43,3 +44,27 @@ where
 
     output_preimage[96..128].try_into().map_err(OracleProviderError::SliceConversion)
 }
+
+/// Hints the host to fetch the preimages of all passed output roots in a single round trip.
+///
+/// Hosts that do not prefetch output roots drop the hint once the next one is sent, and fetch each
+/// output root when it is hinted on its own.
+pub(crate) async fn prefetch_output_roots<O>(
+    caching_oracle: &O,
+    output_roots: &[OutputRootWithChain],
+) -> Result<(), OracleProviderError>
+where
+    O: CommsClient,
+{
+    if output_roots.is_empty() {
+        return Ok(());
+    }
+
+    let data = output_roots
+        .iter()
+        .flat_map(|root| [root.output_root.as_slice(), &root.chain_id.to_be_bytes()].concat())
+        .collect::<Vec<_>>();
+    HintType::L2OutputRoots.with_data(&[data.as_slice()]).send(caching_oracle).await?;
+
+    Ok(())
+}
@@ -37,5 +37,9 @@ fn main() -> Result<(), String> {
             .expect("Failed to set tracing subscriber");
     }
 
-    kona_proof::block_on(kona_client::interop::run(ORACLE_READER, HINT_WRITER))
+    kona_proof::block_on(kona_client::interop::run(
+        ORACLE_READER,
+        HINT_WRITER,
+        kona_client::interop::DEFAULT_ORACLE_LRU_SIZE,
+    ))
 }
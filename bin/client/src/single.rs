@@ -158,6 +158,13 @@ where
     let (safe_head, output_root) = driver
         .advance_to_target(rollup_config.as_ref(), Some(boot.claimed_l2_block_number))
         .await?;
+    let counters = driver.pipeline.counters;
+    info!(
+        target: "client",
+        l1_blocks = counters.l1_blocks,
+        l2_attributes = counters.l2_attributes,
+        "Derivation throughput",
+    );
 
     ////////////////////////////////////////////////////////////////
     //                          EPILOGUE                          //
@@ -1,6 +1,6 @@
 //! This module contains all CLI-specific code for the interop entrypoint.
 
-use super::{InteropHintHandler, InteropLocalInputs};
+use super::{ClaimPrecheckError, InteropHintHandler, InteropLocalInputs};
 use crate::{
     DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
     OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore,
@@ -10,6 +10,7 @@ use alloy_primitives::{B256, Bytes};
 use alloy_provider::{Provider, RootProvider};
 use clap::Parser;
 use kona_cli::cli_styles;
+use kona_client::interop::{DEFAULT_ORACLE_LRU_SIZE, run_with_step_budget};
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_preimage::{
     BidirectionalChannel, Channel, HintReader, HintWriter, OracleReader, OracleServer,
@@ -19,11 +20,19 @@ use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use op_alloy_network::Optimism;
 use serde::Serialize;
-use std::{collections::HashMap, fs, path::PathBuf, str::FromStr, sync::Arc};
+use std::{
+    collections::HashMap,
+    fs,
+    num::{NonZeroU64, NonZeroUsize},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+};
 use tokio::{
     sync::RwLock,
     task::{self, JoinHandle},
 };
+use tracing::info;
 
 /// The interop host application.
 #[derive(Default, Parser, Serialize, Clone, Debug)]
@@ -78,16 +87,21 @@ pub struct InteropHost {
         long,
         visible_alias = "db",
         required_unless_present_all = ["l2_node_addresses", "l1_node_address", "l1_beacon_address"],
+        required_unless_present = "precheck",
         env
     )]
     pub data_dir: Option<PathBuf>,
     /// Run the client program natively.
-    #[arg(long, conflicts_with = "server", required_unless_present = "server")]
+    #[arg(long, conflicts_with = "server", required_unless_present_any = ["server", "precheck"])]
     pub native: bool,
     /// Run in pre-image server mode without executing any client program. If not provided, the
     /// host will run the client program in the host process.
-    #[arg(long, conflicts_with = "native", required_unless_present = "native")]
+    #[arg(long, conflicts_with = "native", required_unless_present_any = ["native", "precheck"])]
     pub server: bool,
+    /// Only check that the claim is plausible, without running the client program or serving any
+    /// preimages. See [InteropHost::precheck_claim].
+    #[arg(long, conflicts_with_all = ["native", "server"], env)]
+    pub precheck: bool,
     /// Path to rollup configs. If provided, the host will use this config instead of attempting to
     /// look up the configs in the superchain registry.
     /// The rollup configs should be stored as serde-JSON serialized files.
@@ -98,6 +112,20 @@ pub struct InteropHost {
     /// The l1 configs should be stored as serde-JSON serialized files.
     #[arg(long, alias = "l1-cfgs", value_delimiter = ',', env)]
     pub l1_config_paths: Option<Vec<PathBuf>>,
+    /// The number of preimages cached by the client program when running natively. Larger
+    /// values avoid refetching preimages during consolidations across many chains, at the cost of
+    /// memory. Defaults to [DEFAULT_ORACLE_LRU_SIZE].
+    #[arg(long, env)]
+    pub oracle_lru_size: Option<NonZeroUsize>,
+    /// Fetch all output roots of the agreed pre-state as soon as the client program hints them
+    /// in a single batch, rather than one at a time when each of them is requested.
+    #[arg(long, default_value = "false", env)]
+    pub prefetch_output_roots: bool,
+    /// The maximum number of transition steps taken by the client program when running natively.
+    /// If the budget runs out before the claimed post-state is reached, the partial pre-state is
+    /// logged, to be passed as the agreed pre-state of the next run.
+    #[arg(long, requires = "native", env)]
+    pub step_budget: Option<NonZeroU64>,
 }
 
 /// An error that can occur when handling interop hosts
@@ -124,6 +152,9 @@ pub enum InteropHostError {
     /// An error when no provider found for chain ID.
     #[error("No provider found for chain ID: {0}")]
     RootProviderError(u64),
+    /// The claim was rejected by [InteropHost::precheck_claim].
+    #[error("Invalid claim: {0}")]
+    InvalidClaim(#[from] ClaimPrecheckError),
     /// Any other error.
     #[error("Error: {0}")]
     Other(&'static str),
@@ -132,7 +163,11 @@ pub enum InteropHostError {
 impl InteropHost {
     /// Starts the [InteropHost] application.
     pub async fn start(self) -> Result<(), InteropHostError> {
-        if self.server {
+        if self.precheck {
+            self.precheck_claim()?;
+            info!(target: "host", "Claim passed the precheck");
+            Ok(())
+        } else if self.server {
             let hint = FileChannel::new(FileDescriptor::HintRead, FileDescriptor::HintWrite);
             let preimage =
                 FileChannel::new(FileDescriptor::PreimageRead, FileDescriptor::PreimageWrite);
@@ -167,13 +202,16 @@ impl InteropHost {
             })
         } else {
             let providers = self.create_providers().await?;
-            let backend = OnlineHostBackend::new(
+            let mut backend = OnlineHostBackend::new(
                 self.clone(),
                 kv_store.clone(),
                 providers,
                 InteropHintHandler,
             )
             .with_proactive_hint(HintType::L2BlockData);
+            if self.prefetch_output_roots {
+                backend = backend.with_proactive_hint(HintType::L2OutputRoots);
+            }
 
             task::spawn(async {
                 PreimageServer::new(
@@ -197,13 +235,23 @@ impl InteropHost {
         let preimage = BidirectionalChannel::new()?;
 
         let server_task = self.start_server(hint.host, preimage.host).await?;
-        let client_task = task::spawn(kona_client::interop::run(
+        let client_task = task::spawn(run_with_step_budget(
             OracleReader::new(preimage.client),
             HintWriter::new(hint.client),
+            self.oracle_lru_size.unwrap_or(DEFAULT_ORACLE_LRU_SIZE),
+            self.step_budget.map(NonZeroU64::get),
         ));
 
         let (_, client_result) = tokio::try_join!(server_task, client_task)?;
 
+        if let Ok(Some(pre_state)) = &client_result {
+            info!(
+                target: "host",
+                partial_pre_state = %Bytes::from(alloy_rlp::encode(pre_state)),
+                "Exhausted the step budget, resume with the partial pre-state as the agreed pre-state."
+            );
+        }
+
         // Bubble up the exit status of the client program if execution completes.
         std::process::exit(client_result.is_err() as i32)
     }
@@ -352,6 +400,7 @@ mod tests {
         assert_eq!(host.claimed_l2_post_state, hash);
         assert_eq!(host.claimed_l2_timestamp, 0);
         assert!(host.native);
+        assert!(!host.prefetch_output_roots);
     }
 
     #[test]
@@ -381,4 +430,70 @@ mod tests {
         assert_eq!(host.claimed_l2_timestamp, 0);
         assert!(host.native);
     }
+
+    #[test]
+    fn test_parse_interop_step_budget() {
+        let hash = b256!("ffd7db0f9d5cdeb49c4c9eba649d4dc6d852d64671e65488e57f58584992ac68");
+        let args = [
+            "interop-host",
+            "--l1-head",
+            "ffd7db0f9d5cdeb49c4c9eba649d4dc6d852d64671e65488e57f58584992ac68",
+            "--l2-pre-state",
+            "ff",
+            "--claimed-l2-post-state",
+            &hash.to_string(),
+            "--claimed-l2-timestamp",
+            "0",
+            "--data-dir",
+            "/tmp",
+            "--step-budget",
+            "4",
+        ];
+        assert!(InteropHost::try_parse_from(args.iter().chain(&["--server"])).is_err());
+        let host = InteropHost::parse_from(args.iter().chain(&["--native"]));
+        assert_eq!(host.step_budget, NonZeroU64::new(4));
+    }
+
+    #[test]
+    fn test_parse_interop_prefetch_output_roots() {
+        let hash = b256!("ffd7db0f9d5cdeb49c4c9eba649d4dc6d852d64671e65488e57f58584992ac68");
+        let host = InteropHost::parse_from([
+            "interop-host",
+            "--l1-head",
+            "ffd7db0f9d5cdeb49c4c9eba649d4dc6d852d64671e65488e57f58584992ac68",
+            "--l2-pre-state",
+            "ff",
+            "--claimed-l2-post-state",
+            &hash.to_string(),
+            "--claimed-l2-timestamp",
+            "0",
+            "--server",
+            "--data-dir",
+            "/tmp",
+            "--prefetch-output-roots",
+        ]);
+        assert!(host.prefetch_output_roots);
+    }
+
+    #[test]
+    fn test_parse_interop_precheck() {
+        let hash = b256!("ffd7db0f9d5cdeb49c4c9eba649d4dc6d852d64671e65488e57f58584992ac68");
+        let args = [
+            "interop-host",
+            "--l1-head",
+            "ffd7db0f9d5cdeb49c4c9eba649d4dc6d852d64671e65488e57f58584992ac68",
+            "--l2-pre-state",
+            "ff",
+            "--claimed-l2-post-state",
+            &hash.to_string(),
+            "--claimed-l2-timestamp",
+            "0",
+            "--precheck",
+        ];
+        let host = InteropHost::parse_from(args);
+        assert!(host.precheck);
+        assert!(!host.native && !host.server);
+        assert!(InteropHost::try_parse_from(args.iter().chain(&["--native"])).is_err());
+        assert!(InteropHost::try_parse_from(args.iter().chain(&["--server"])).is_err());
+    }
 }
@@ -1,6 +1,6 @@
 //! [HintHandler] for the [InteropHost].
 
-use super::InteropHost;
+use super::{InteropHost, InteropProviders};
 use crate::{
     HintHandler, OnlineHostBackend, OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore,
     backend::util::store_ordered_trie,
@@ -18,6 +18,7 @@ use alloy_rpc_types::Block;
 use anyhow::{Result, anyhow, ensure};
 use ark_ff::{BigInteger, PrimeField};
 use async_trait::async_trait;
+use futures::future::try_join_all;
 use kona_derive::EthereumDataSource;
 use kona_driver::Driver;
 use kona_executor::TrieDBProvider;
@@ -35,7 +36,7 @@ use kona_proof::{
 use kona_proof_interop::{HintType, PreState};
 use kona_protocol::{BlockInfo, OutputRoot, Predeploys};
 use kona_registry::{L1_CONFIGS, ROLLUP_CONFIGS};
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 use tokio::task;
 use tracing::{Instrument, debug, info, info_span, warn};
 
@@ -207,75 +208,33 @@ impl HintHandler for InteropHintHandler {
 
                 let hash = B256::from_slice(&hint.data.as_ref()[0..32]);
                 let chain_id = u64::from_be_bytes(hint.data.as_ref()[32..40].try_into()?);
-                let l2_provider = providers.l2(&chain_id)?;
-
-                // Decode the pre-state to determine the timestamp of the block.
-                let pre = PreState::decode(&mut cfg.agreed_l2_pre_state.as_ref())?;
-                let timestamp = match pre {
-                    PreState::SuperRoot(super_root) => super_root.timestamp,
-                    PreState::TransitionState(transition_state) => {
-                        transition_state.pre_state.timestamp
-                    }
-                };
-
-                // Convert the timestamp to an L2 block number, using the rollup config for the
-                // chain ID embedded within the hint.
-                let rollup_config = cfg
-                    .read_rollup_configs()
-                    // If an error occurred while reading the rollup configs, return the error.
-                    .transpose()?
-                    // Try to find the appropriate rollup config for the chain ID.
-                    .and_then(|configs| configs.get(&chain_id).cloned())
-                    // If we can't find the rollup config, try to find it in the global rollup
-                    // configs.
-                    .or_else(|| ROLLUP_CONFIGS.get(&chain_id).cloned())
-                    .map(Arc::new)
-                    .ok_or(anyhow!("No rollup config found for chain ID: {chain_id}"))?;
-                let block_number = rollup_config.block_number_from_timestamp(timestamp);
-
-                // Fetch the header for the L2 head block.
-                let raw_header: Bytes = l2_provider
-                    .client()
-                    .request("debug_getRawHeader", &[format!("0x{block_number:x}")])
-                    .await
-                    .map_err(|e| anyhow!("Failed to fetch header RLP: {e}"))?;
-                let header = Header::decode(&mut raw_header.as_ref())?;
-
-                // Check if tenrec (new withdrawals) is enabled
-                let withdrawal_root = if rollup_config.is_tenrec_active(header.timestamp) {
-                    // Fetch the withdrawal root from storage of L2MessagePasser
-                    let withdrawal_root = l2_provider
-                        .get_storage_at(
-                            Predeploys::L2_TO_L1_MESSAGE_PASSER,
-                            Predeploys::WITHDRAWAL_ROOT_STORAGE_SLOT.into(),
-                        )
-                        .block_id(block_number.into())
-                        .await?;
-                    withdrawal_root.into()
-                } else {
-                    // Fetch the storage root for the L2 head block.
-                    let l2_to_l1_message_passer = l2_provider
-                        .get_proof(Predeploys::L2_TO_L1_MESSAGE_PASSER, Default::default())
-                        .block_id(block_number.into())
-                        .await?;
-                    l2_to_l1_message_passer.storage_hash
-                };
-
-                let output_root =
-                    OutputRoot::from_parts(header.state_root, withdrawal_root, header.hash_slow());
-                let output_root_hash = output_root.hash();
-
-                ensure!(
-                    output_root_hash == hash,
-                    "Output root does not match L2 head. Expected: {hash}, got: {output_root_hash}"
-                );
+                let output_root = fetch_output_root(cfg, providers, hash, chain_id).await?;
 
                 let mut kv_lock = kv.write().await;
                 kv_lock.set(
-                    PreimageKey::new_keccak256(*output_root_hash).into(),
+                    PreimageKey::new_keccak256(*output_root.hash()).into(),
                     output_root.encode().into(),
                 )?;
             }
+            HintType::L2OutputRoots => {
+                let requests = parse_output_roots_hint(hint.data.as_ref())?;
+
+                // Fetch all output roots concurrently, failing the hint if any of them fails.
+                let output_roots = try_join_all(
+                    requests
+                        .into_iter()
+                        .map(|(hash, chain_id)| fetch_output_root(cfg, providers, hash, chain_id)),
+                )
+                .await?;
+
+                let mut kv_lock = kv.write().await;
+                for output_root in output_roots {
+                    kv_lock.set(
+                        PreimageKey::new_keccak256(*output_root.hash()).into(),
+                        output_root.encode().into(),
+                    )?;
+                }
+            }
             HintType::L2BlockHeader => {
                 ensure!(hint.data.len() == 40, "Invalid hint data length");
 
@@ -629,3 +588,273 @@ impl HintHandler for InteropHintHandler {
         Ok(())
     }
 }
+
+/// The length of a single `(output root, chain ID)` entry within a [HintType::L2OutputRoots] hint.
+pub const OUTPUT_ROOTS_HINT_ENTRY_LEN: usize = 32 + 8;
+
+/// An error returned when a [HintType::L2OutputRoots] hint is malformed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum OutputRootsHintError {
+    /// The hint does not contain any entries.
+    #[error("Output roots hint contains no entries")]
+    Empty,
+    /// The hint data is not a whole number of entries.
+    #[error("Output roots hint data length {0} is not a multiple of {OUTPUT_ROOTS_HINT_ENTRY_LEN}")]
+    InvalidLength(usize),
+    /// The hint requests the output root of the same chain more than once.
+    #[error("Output roots hint contains chain ID {0} more than once")]
+    DuplicateChainId(u64),
+}
+
+/// Parses the `(output root, chain ID)` pairs out of a [HintType::L2OutputRoots] hint.
+fn parse_output_roots_hint(data: &[u8]) -> Result<Vec<(B256, u64)>, OutputRootsHintError> {
+    if data.is_empty() {
+        return Err(OutputRootsHintError::Empty);
+    }
+    if data.len() % OUTPUT_ROOTS_HINT_ENTRY_LEN != 0 {
+        return Err(OutputRootsHintError::InvalidLength(data.len()));
+    }
+
+    let mut chain_ids = HashSet::new();
+    data.chunks_exact(OUTPUT_ROOTS_HINT_ENTRY_LEN)
+        .map(|entry| {
+            let hash = B256::from_slice(&entry[..32]);
+            let chain_id = u64::from_be_bytes(entry[32..].try_into().expect("entry is 40 bytes"));
+            if !chain_ids.insert(chain_id) {
+                return Err(OutputRootsHintError::DuplicateChainId(chain_id));
+            }
+            Ok((hash, chain_id))
+        })
+        .collect()
+}
+
+/// Fetches the [OutputRoot] of the given chain at the timestamp of the agreed upon pre-state, and
+/// checks that it matches the `hash` requested by the client.
+async fn fetch_output_root(
+    cfg: &InteropHost,
+    providers: &InteropProviders,
+    hash: B256,
+    chain_id: u64,
+) -> Result<OutputRoot> {
+    let l2_provider = providers.l2(&chain_id)?;
+
+    // Decode the pre-state to determine the timestamp of the block.
+    let pre = PreState::decode(&mut cfg.agreed_l2_pre_state.as_ref())?;
+    let timestamp = match pre {
+        PreState::SuperRoot(super_root) => super_root.timestamp,
+        PreState::TransitionState(transition_state) => transition_state.pre_state.timestamp,
+    };
+
+    // Convert the timestamp to an L2 block number, using the rollup config for the
+    // chain ID embedded within the hint.
+    let rollup_config = cfg
+        .read_rollup_configs()
+        // If an error occurred while reading the rollup configs, return the error.
+        .transpose()?
+        // Try to find the appropriate rollup config for the chain ID.
+        .and_then(|configs| configs.get(&chain_id).cloned())
+        // If we can't find the rollup config, try to find it in the global rollup
+        // configs.
+        .or_else(|| ROLLUP_CONFIGS.get(&chain_id).cloned())
+        .map(Arc::new)
+        .ok_or(anyhow!("No rollup config found for chain ID: {chain_id}"))?;
+    let block_number = rollup_config.block_number_from_timestamp(timestamp);
+
+    // Fetch the header for the L2 head block.
+    let raw_header: Bytes = l2_provider
+        .client()
+        .request("debug_getRawHeader", &[format!("0x{block_number:x}")])
+        .await
+        .map_err(|e| anyhow!("Failed to fetch header RLP: {e}"))?;
+    let header = Header::decode(&mut raw_header.as_ref())?;
+
+    // Check if tenrec (new withdrawals) is enabled
+    let withdrawal_root = if rollup_config.is_tenrec_active(header.timestamp) {
+        // Fetch the withdrawal root from storage of L2MessagePasser
+        let withdrawal_root = l2_provider
+            .get_storage_at(
+                Predeploys::L2_TO_L1_MESSAGE_PASSER,
+                Predeploys::WITHDRAWAL_ROOT_STORAGE_SLOT.into(),
+            )
+            .block_id(block_number.into())
+            .await?;
+        withdrawal_root.into()
+    } else {
+        // Fetch the storage root for the L2 head block.
+        let l2_to_l1_message_passer = l2_provider
+            .get_proof(Predeploys::L2_TO_L1_MESSAGE_PASSER, Default::default())
+            .block_id(block_number.into())
+            .await?;
+        l2_to_l1_message_passer.storage_hash
+    };
+
+    let output_root =
+        OutputRoot::from_parts(header.state_root, withdrawal_root, header.hash_slow());
+    let output_root_hash = output_root.hash();
+
+    ensure!(
+        output_root_hash == hash,
+        "Output root does not match L2 head. Expected: {hash}, got: {output_root_hash}"
+    );
+
+    Ok(output_root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryKeyValueStore;
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_rpc_types::EIP1186AccountProofResponse;
+    use alloy_transport::mock::{Asserter, MockTransport};
+    use kona_interop::SuperRoot;
+    use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+    use op_alloy_network::Optimism;
+    use std::collections::HashMap;
+    use tokio::sync::RwLock;
+
+    fn mock_provider<N: alloy_provider::network::Network>(asserter: Asserter) -> RootProvider<N> {
+        RootProvider::new(RpcClient::new(MockTransport::new(asserter), false))
+    }
+
+    /// Sets up an [InteropHost] and mocked [InteropProviders] for the given chains, returning the
+    /// output roots that the mocked L2 providers will serve.
+    fn mock_host(chain_ids: &[u64]) -> (InteropHost, InteropProviders, Vec<(u64, OutputRoot)>) {
+        let pre_state = PreState::SuperRoot(SuperRoot::new(1_700_000_000, Vec::new()));
+        let cfg = InteropHost {
+            agreed_l2_pre_state: alloy_rlp::encode(&pre_state).into(),
+            ..Default::default()
+        };
+
+        let mut l2s = HashMap::default();
+        let mut output_roots = Vec::new();
+        for (i, chain_id) in chain_ids.iter().enumerate() {
+            let header =
+                Header { state_root: B256::repeat_byte(i as u8 + 1), ..Default::default() };
+            let proof = EIP1186AccountProofResponse {
+                storage_hash: B256::repeat_byte(i as u8 + 0x10),
+                ..Default::default()
+            };
+
+            let asserter = Asserter::new();
+            asserter.push_success(&Bytes::from(alloy_rlp::encode(&header)));
+            asserter.push_success(&proof);
+            l2s.insert(*chain_id, mock_provider(asserter));
+
+            output_roots.push((
+                *chain_id,
+                OutputRoot::from_parts(header.state_root, proof.storage_hash, header.hash_slow()),
+            ));
+        }
+
+        let providers = InteropProviders {
+            l1: mock_provider(Asserter::new()),
+            blobs: OnlineBlobProvider {
+                beacon_client: OnlineBeaconClient::new_http("http://localhost:5052".to_string()),
+                genesis_time: 0,
+                slot_interval: 12,
+            },
+            l2s,
+        };
+
+        (cfg, providers, output_roots)
+    }
+
+    #[tokio::test]
+    async fn test_l2_output_roots_hint() {
+        let (cfg, providers, output_roots) = mock_host(&[10, 8453]);
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+
+        let data = output_roots
+            .iter()
+            .flat_map(|(chain_id, root)| [root.hash().as_slice(), &chain_id.to_be_bytes()].concat())
+            .collect::<Vec<_>>();
+        let hint = HintType::L2OutputRoots.with_data(&[data.as_slice()]);
+        InteropHintHandler::fetch_hint(hint, &cfg, &providers, kv.clone()).await.unwrap();
+
+        let kv = kv.read().await;
+        for (_, root) in output_roots {
+            let preimage = kv.get(PreimageKey::new_keccak256(*root.hash()).into());
+            assert_eq!(preimage, Some(root.encode().to_vec()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_l2_output_roots_hint_mismatch() {
+        let (cfg, providers, _) = mock_host(&[10]);
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+
+        let data = [B256::repeat_byte(0xFF).as_slice(), &10u64.to_be_bytes()].concat();
+        let hint = HintType::L2OutputRoots.with_data(&[data.as_slice()]);
+        let err = InteropHintHandler::fetch_hint(hint, &cfg, &providers, kv).await.unwrap_err();
+        assert!(err.to_string().contains("Output root does not match L2 head"));
+    }
+
+    #[tokio::test]
+    async fn test_l2_output_roots_hint_malformed() {
+        let (cfg, providers, _) = mock_host(&[10]);
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+
+        let hint =
+            HintType::L2OutputRoots.with_data(&[&[0u8; OUTPUT_ROOTS_HINT_ENTRY_LEN + 1][..]]);
+        let err = InteropHintHandler::fetch_hint(hint, &cfg, &providers, kv).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<OutputRootsHintError>(),
+            Some(&OutputRootsHintError::InvalidLength(OUTPUT_ROOTS_HINT_ENTRY_LEN + 1))
+        );
+    }
+
+    /// Serves the trie nodes stored in a key-value store.
+    struct KvTrieProvider<'a>(&'a (dyn crate::KeyValueStore + Send + Sync));
+
+    impl kona_mpt::TrieProvider for KvTrieProvider<'_> {
+        type Error = String;
+
+        fn trie_node_by_hash(&self, key: B256) -> Result<kona_mpt::TrieNode, String> {
+            let node = self
+                .0
+                .get(PreimageKey::new_keccak256(*key).into())
+                .ok_or_else(|| format!("missing trie node {key}"))?;
+            kona_mpt::TrieNode::decode(&mut node.as_slice()).map_err(|e| e.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_l2_receipts_hint() {
+        let (cfg, mut providers, _) = mock_host(&[]);
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+
+        let raw_receipts = (0..3u8).map(|i| Bytes::from(vec![i; 64])).collect::<Vec<_>>();
+        let asserter = Asserter::new();
+        asserter.push_success(&raw_receipts);
+        providers.l2s.insert(10, mock_provider(asserter));
+
+        let hint = HintType::l2_receipts(10, B256::repeat_byte(1));
+        InteropHintHandler::fetch_hint(hint, &cfg, &providers, kv.clone()).await.unwrap();
+
+        let mut hb = kona_mpt::ordered_trie_with_encoder(&raw_receipts, |receipt, buf| {
+            buf.put_slice(receipt.as_ref());
+        });
+        let receipts_root = hb.root();
+
+        let kv = kv.read().await;
+        let walker =
+            kona_mpt::OrderedListWalker::try_new_hydrated(receipts_root, &KvTrieProvider(&*kv))
+                .unwrap();
+        let receipts = walker.into_iter().map(|(_, receipt)| receipt).collect::<Vec<_>>();
+        assert_eq!(receipts, raw_receipts);
+    }
+
+    #[test]
+    fn test_parse_output_roots_hint() {
+        assert_eq!(parse_output_roots_hint(&[]), Err(OutputRootsHintError::Empty));
+
+        let entry = [B256::ZERO.as_slice(), &10u64.to_be_bytes()].concat();
+        assert_eq!(parse_output_roots_hint(&entry), Ok(vec![(B256::ZERO, 10)]));
+        assert_eq!(
+            parse_output_roots_hint(&[entry.as_slice(), entry.as_slice()].concat()),
+            Err(OutputRootsHintError::DuplicateChainId(10))
+        );
+    }
+}
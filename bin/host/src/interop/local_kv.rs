@@ -3,30 +3,59 @@
 
 use super::InteropHost;
 use crate::KeyValueStore;
-use alloy_primitives::{B256, keccak256};
+use alloy_primitives::{B256, U256, keccak256};
 use anyhow::Result;
 use kona_preimage::PreimageKey;
 use kona_proof_interop::boot::{
     L1_CONFIG_KEY, L1_HEAD_KEY, L2_AGREED_PRE_STATE_KEY, L2_CLAIMED_POST_STATE_KEY,
     L2_CLAIMED_TIMESTAMP_KEY, L2_ROLLUP_CONFIG_KEY,
 };
+use std::collections::HashMap;
+use tracing::{debug, warn};
 
 /// A simple, synchronous key-value store that returns data from a [InteropHost] config.
+///
+/// Individual local keys can be overridden with an overlay, which takes precedence over the
+/// values derived from the config. This allows injecting a specific pre-state or claim without
+/// regenerating the whole input set.
 #[derive(Debug)]
 pub struct InteropLocalInputs {
     cfg: InteropHost,
+    overlay: HashMap<U256, Vec<u8>>,
 }
 
 impl InteropLocalInputs {
     /// Create a new [InteropLocalInputs] with the given [InteropHost] config.
-    pub const fn new(cfg: InteropHost) -> Self {
-        Self { cfg }
+    pub fn new(cfg: InteropHost) -> Self {
+        Self { cfg, overlay: HashMap::new() }
+    }
+
+    /// Overrides the value served for the given local key, taking precedence over the value
+    /// derived from the [InteropHost] config.
+    pub fn with_overlay(mut self, key: U256, value: Vec<u8>) -> Self {
+        warn!(
+            target: "interop_local_kv",
+            %key,
+            value = %alloy_primitives::hex::encode(&value),
+            "Overriding local input"
+        );
+        self.overlay.insert(key, value);
+        self
     }
 }
 
 impl KeyValueStore for InteropLocalInputs {
     fn get(&self, key: B256) -> Option<Vec<u8>> {
         let preimage_key = PreimageKey::try_from(*key).ok()?;
+        if let Some(value) = self.overlay.get(&preimage_key.key_value()) {
+            debug!(
+                target: "interop_local_kv",
+                key = %preimage_key.key_value(),
+                "Serving overlaid local input"
+            );
+            return Some(value.clone());
+        }
+
         match preimage_key.key_value() {
             L1_HEAD_KEY => Some(self.cfg.l1_head.to_vec()),
             L2_AGREED_PRE_STATE_KEY => {
@@ -50,3 +79,31 @@ impl KeyValueStore for InteropLocalInputs {
         unreachable!("LocalKeyValueStore is read-only")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local_key(key: U256) -> B256 {
+        PreimageKey::new_local(key.to()).into()
+    }
+
+    #[test]
+    fn test_overlay_claimed_post_state() {
+        let cfg = InteropHost {
+            claimed_l2_post_state: B256::repeat_byte(0x01),
+            claimed_l2_timestamp: 10,
+            ..Default::default()
+        };
+        let overlay_claim = B256::repeat_byte(0xFF);
+        let inputs = InteropLocalInputs::new(cfg)
+            .with_overlay(L2_CLAIMED_POST_STATE_KEY, overlay_claim.to_vec());
+
+        assert_eq!(inputs.get(local_key(L2_CLAIMED_POST_STATE_KEY)), Some(overlay_claim.to_vec()));
+        // Keys without an overlay are still served from the config.
+        assert_eq!(
+            inputs.get(local_key(L2_CLAIMED_TIMESTAMP_KEY)),
+            Some(10u64.to_be_bytes().to_vec())
+        );
+    }
+}
@@ -6,5 +6,8 @@ pub use cfg::{InteropHost, InteropHostError, InteropProviders};
 mod local_kv;
 pub use local_kv::InteropLocalInputs;
 
+mod precheck;
+pub use precheck::ClaimPrecheckError;
+
 mod handler;
-pub use handler::InteropHintHandler;
+pub use handler::{InteropHintHandler, OUTPUT_ROOTS_HINT_ENTRY_LEN, OutputRootsHintError};
@@ -0,0 +1,277 @@
+//! Cheap, offline plausibility checks of an interop claim.
+
+use super::{InteropHost, InteropHostError};
+use alloy_primitives::{B256, keccak256};
+use alloy_rlp::Decodable;
+use kona_interop::SuperRoot;
+use kona_proof_interop::{
+    INVALID_TRANSITION, INVALID_TRANSITION_HASH, PreState,
+    boot::{BootstrapError, validate_pre_state},
+};
+use kona_registry::ROLLUP_CONFIGS;
+
+/// A reason for rejecting a claim during [InteropHost::precheck_claim].
+#[derive(Debug, thiserror::Error)]
+pub enum ClaimPrecheckError {
+    /// The L1 head hash is zero.
+    #[error("L1 head hash is zero")]
+    ZeroL1Head,
+    /// The agreed pre-state is invalid, but the claim is not the invalid transition hash.
+    #[error("Agreed pre-state is invalid, but the claimed post-state is {0}")]
+    InvalidPostState(B256),
+    /// The agreed pre-state could not be decoded.
+    #[error("Failed to decode agreed pre-state: {0}")]
+    MalformedPreState(alloy_rlp::Error),
+    /// The agreed pre-state is rejected by the boot loader of the client program.
+    #[error("Invalid agreed pre-state: {0}")]
+    InvalidPreState(BootstrapError),
+    /// An output root in the pre-state has a chain ID of zero.
+    #[error("Output root has a chain ID of zero")]
+    ZeroChainId,
+    /// The output roots in the pre-state are not strictly sorted by chain ID.
+    #[error("Output roots are not strictly sorted by chain ID: {previous} precedes {next}")]
+    UnsortedChainIds {
+        /// The chain ID of the preceding output root.
+        previous: u64,
+        /// The chain ID of the following output root.
+        next: u64,
+    },
+    /// No rollup config is known for a chain in the pre-state.
+    #[error("No rollup config found for chain ID: {0}")]
+    UnknownChainId(u64),
+    /// The claim extends the trace past the super root, but differs from the agreed pre-state.
+    #[error("Claimed post-state {claimed} must equal the agreed pre-state {agreed}")]
+    TraceExtensionMismatch {
+        /// The agreed pre-state commitment.
+        agreed: B256,
+        /// The claimed post-state commitment.
+        claimed: B256,
+    },
+}
+
+impl InteropHost {
+    /// Checks that the claim is plausible before committing compute to the full fault proof.
+    ///
+    /// This validates the boot information and the structure of the agreed pre-state, without
+    /// touching the preimage oracle or any remote provider. Passing the precheck does not imply
+    /// that the claim is valid.
+    pub fn precheck_claim(&self) -> Result<(), InteropHostError> {
+        if self.l1_head.is_zero() {
+            return Err(ClaimPrecheckError::ZeroL1Head.into());
+        }
+
+        if self.agreed_l2_pre_state == INVALID_TRANSITION {
+            if self.claimed_l2_post_state != INVALID_TRANSITION_HASH {
+                return Err(ClaimPrecheckError::InvalidPostState(self.claimed_l2_post_state).into());
+            }
+            return Ok(());
+        }
+
+        let pre_state = PreState::decode(&mut self.agreed_l2_pre_state.as_ref())
+            .map_err(ClaimPrecheckError::MalformedPreState)?;
+        // The same checks as the boot loader of the client program, so that a claim passing the
+        // precheck is not rejected as soon as the program starts.
+        validate_pre_state(&pre_state, self.claimed_l2_timestamp)
+            .map_err(ClaimPrecheckError::InvalidPreState)?;
+
+        let super_root = match &pre_state {
+            PreState::SuperRoot(super_root) => super_root,
+            PreState::TransitionState(transition_state) => &transition_state.pre_state,
+        };
+        self.precheck_super_root(super_root)?;
+
+        // Claims at or before the timestamp of a super root pre-state extend the trace, and must
+        // commit to the agreed pre-state.
+        let agreed = keccak256(self.agreed_l2_pre_state.as_ref());
+        if matches!(pre_state, PreState::SuperRoot(_)) &&
+            super_root.timestamp >= self.claimed_l2_timestamp &&
+            agreed != self.claimed_l2_post_state
+        {
+            return Err(ClaimPrecheckError::TraceExtensionMismatch {
+                agreed,
+                claimed: self.claimed_l2_post_state,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the output roots of the [SuperRoot] are sorted by chain ID and that all of
+    /// the chains are known.
+    fn precheck_super_root(&self, super_root: &SuperRoot) -> Result<(), InteropHostError> {
+        let rollup_configs = self.read_rollup_configs().transpose()?;
+        let mut previous = None;
+        for output_root in &super_root.output_roots {
+            let chain_id = output_root.chain_id;
+            if chain_id == 0 {
+                return Err(ClaimPrecheckError::ZeroChainId.into());
+            }
+            if let Some(previous) = previous.filter(|previous| *previous >= chain_id) {
+                return Err(ClaimPrecheckError::UnsortedChainIds { previous, next: chain_id }.into());
+            }
+            previous = Some(chain_id);
+
+            let known =
+                rollup_configs.as_ref().is_some_and(|configs| configs.contains_key(&chain_id)) ||
+                    ROLLUP_CONFIGS.contains_key(&chain_id);
+            if !known {
+                return Err(ClaimPrecheckError::UnknownChainId(chain_id).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+    use kona_interop::OutputRootWithChain;
+    use kona_proof_interop::{OptimisticBlock, TRANSITION_STATE_MAX_STEPS, TransitionState};
+
+    const TIMESTAMP: u64 = 1_700_000_000;
+
+    fn super_root(chain_ids: &[u64]) -> SuperRoot {
+        SuperRoot {
+            timestamp: TIMESTAMP,
+            output_roots: chain_ids
+                .iter()
+                .map(|chain_id| OutputRootWithChain::new(*chain_id, B256::repeat_byte(0x01)))
+                .collect(),
+        }
+    }
+
+    fn host(pre_state: &PreState) -> InteropHost {
+        InteropHost {
+            l1_head: B256::repeat_byte(0xAA),
+            agreed_l2_pre_state: alloy_rlp::encode(pre_state).into(),
+            claimed_l2_post_state: B256::repeat_byte(0xBB),
+            claimed_l2_timestamp: TIMESTAMP + 1,
+            ..Default::default()
+        }
+    }
+
+    fn precheck_err(host: &InteropHost) -> ClaimPrecheckError {
+        match host.precheck_claim() {
+            Err(InteropHostError::InvalidClaim(err)) => err,
+            other => panic!("expected claim to be rejected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_precheck_well_formed_claim() {
+        let pre_state = PreState::SuperRoot(super_root(&[10, 8453]));
+        host(&pre_state).precheck_claim().unwrap();
+
+        let transition = PreState::TransitionState(TransitionState::new(
+            super_root(&[10, 8453]),
+            vec![OptimisticBlock::new(B256::ZERO, B256::ZERO)],
+            1,
+        ));
+        host(&transition).precheck_claim().unwrap();
+    }
+
+    #[test]
+    fn test_precheck_invalid_pre_state() {
+        let mut host = host(&PreState::SuperRoot(super_root(&[10])));
+        host.agreed_l2_pre_state = INVALID_TRANSITION;
+        assert!(matches!(
+            precheck_err(&host),
+            ClaimPrecheckError::InvalidPostState(post) if post == B256::repeat_byte(0xBB)
+        ));
+
+        host.claimed_l2_post_state = INVALID_TRANSITION_HASH;
+        host.precheck_claim().unwrap();
+    }
+
+    #[test]
+    fn test_precheck_malformed_claims() {
+        let pre_state = PreState::SuperRoot(super_root(&[10]));
+
+        let mut zero_head = host(&pre_state);
+        zero_head.l1_head = B256::ZERO;
+        assert!(matches!(precheck_err(&zero_head), ClaimPrecheckError::ZeroL1Head));
+
+        let mut garbage = host(&pre_state);
+        garbage.agreed_l2_pre_state = Bytes::from_static(&[0xFF, 0x00]);
+        assert!(matches!(precheck_err(&garbage), ClaimPrecheckError::MalformedPreState(_)));
+
+        let empty = host(&PreState::SuperRoot(super_root(&[])));
+        assert!(matches!(
+            precheck_err(&empty),
+            ClaimPrecheckError::InvalidPreState(BootstrapError::EmptyPreState)
+        ));
+
+        let unsorted = host(&PreState::SuperRoot(super_root(&[8453, 10])));
+        assert!(matches!(
+            precheck_err(&unsorted),
+            ClaimPrecheckError::UnsortedChainIds { previous: 8453, next: 10 }
+        ));
+
+        let unknown = host(&PreState::SuperRoot(super_root(&[10, u64::MAX])));
+        assert!(matches!(precheck_err(&unknown), ClaimPrecheckError::UnknownChainId(u64::MAX)));
+
+        let mut trace_extension = host(&pre_state);
+        trace_extension.claimed_l2_timestamp = TIMESTAMP;
+        assert!(matches!(
+            precheck_err(&trace_extension),
+            ClaimPrecheckError::TraceExtensionMismatch { .. }
+        ));
+    }
+
+    #[test]
+    fn test_precheck_malformed_transition_state() {
+        let inconsistent = host(&PreState::TransitionState(TransitionState::new(
+            super_root(&[10, 8453]),
+            vec![
+                OptimisticBlock::new(B256::ZERO, B256::ZERO),
+                OptimisticBlock::new(B256::ZERO, B256::ZERO),
+            ],
+            1,
+        )));
+        assert!(matches!(
+            precheck_err(&inconsistent),
+            ClaimPrecheckError::InvalidPreState(BootstrapError::InvalidPendingProgress {
+                pending: 2,
+                step: 1,
+                chains: 2
+            })
+        ));
+
+        let out_of_range = host(&PreState::TransitionState(TransitionState::new(
+            super_root(&[10]),
+            vec![OptimisticBlock::new(B256::ZERO, B256::ZERO)],
+            TRANSITION_STATE_MAX_STEPS + 1,
+        )));
+        assert!(matches!(
+            precheck_err(&out_of_range),
+            ClaimPrecheckError::InvalidPreState(BootstrapError::InvalidTransitionStep(step))
+                if step == TRANSITION_STATE_MAX_STEPS + 1
+        ));
+
+        let mut stale = host(&PreState::TransitionState(TransitionState::new(
+            super_root(&[10]),
+            vec![OptimisticBlock::new(B256::ZERO, B256::ZERO)],
+            1,
+        )));
+        stale.claimed_l2_timestamp = TIMESTAMP;
+        assert!(matches!(
+            precheck_err(&stale),
+            ClaimPrecheckError::InvalidPreState(
+                BootstrapError::ClaimedTimestampNotAfterPreState { claimed, pre_state }
+            ) if claimed == TIMESTAMP && pre_state == TIMESTAMP
+        ));
+
+        let unsorted = host(&PreState::TransitionState(TransitionState::new(
+            super_root(&[8453, 10]),
+            vec![OptimisticBlock::new(B256::ZERO, B256::ZERO)],
+            1,
+        )));
+        assert!(matches!(
+            precheck_err(&unsorted),
+            ClaimPrecheckError::UnsortedChainIds { previous: 8453, next: 10 }
+        ));
+    }
+}
@@ -1,10 +1,14 @@
 //! Bootstore Subcommand
 
 use crate::flags::GlobalArgs;
-use clap::Parser;
+use clap::{Parser, Subcommand};
+use discv5::Enr;
 use kona_cli::LogConfig;
 use kona_peers::{BootStore, BootStoreFile};
-use std::path::PathBuf;
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
 
 /// The `bootstore` Subcommand
 ///
@@ -13,7 +17,7 @@ use std::path::PathBuf;
 /// # Usage
 ///
 /// ```sh
-/// kona-node bootstore [FLAGS] [OPTIONS]
+/// kona-node bootstore [FLAGS] [OPTIONS] [ACTION]
 /// ```
 #[derive(Parser, Default, PartialEq, Debug, Clone)]
 #[command(about = "Utility tool to interact with local bootstores")]
@@ -25,6 +29,25 @@ pub struct BootstoreCommand {
     /// The directory to store the bootstore.
     #[arg(long = "p2p.bootstore", env = "KONA_NODE_P2P_BOOTSTORE")]
     pub bootstore: Option<PathBuf>,
+    /// An action to perform on the bootstore of the chain configured with `--l2-chain-id`.
+    /// Prints the bootstore information if omitted.
+    #[command(subcommand)]
+    pub action: Option<BootstoreAction>,
+}
+
+/// An action performed on a bootstore.
+#[derive(Subcommand, PartialEq, Debug, Clone)]
+pub enum BootstoreAction {
+    /// Exports the stored ENRs to a JSON file, for inspection or to seed another node.
+    Export {
+        /// The file to write the ENRs to.
+        path: PathBuf,
+    },
+    /// Merges the ENRs of a JSON file into the bootstore, skipping the ones already stored.
+    Import {
+        /// The file to read the ENRs from.
+        path: PathBuf,
+    },
 }
 
 impl BootstoreCommand {
@@ -36,11 +59,24 @@ impl BootstoreCommand {
 
     /// Runs the subcommand.
     pub fn run(self, args: &GlobalArgs) -> anyhow::Result<()> {
-        println!("--------------------------");
-        if self.all {
-            self.all()?;
-        } else {
-            self.info(args.l2_chain_id.into())?;
+        let chain_id = args.l2_chain_id.into();
+        match &self.action {
+            Some(BootstoreAction::Export { path }) => {
+                let count = self.export(chain_id, path)?;
+                println!("Exported {count} ENRs to {}", path.display());
+            }
+            Some(BootstoreAction::Import { path }) => {
+                let count = self.import(chain_id, path)?;
+                println!("Imported {count} new ENRs from {}", path.display());
+            }
+            None => {
+                println!("--------------------------");
+                if self.all {
+                    self.all()?;
+                } else {
+                    self.info(chain_id)?;
+                }
+            }
         }
         Ok(())
     }
@@ -59,15 +95,138 @@ impl BootstoreCommand {
             .get(&chain_id)
             .ok_or(anyhow::anyhow!("Chain ID {} not found in the registry", chain_id))?;
         println!("{} Bootstore (Chain ID: {})", chain.name, chain_id);
-        let bootstore: BootStoreFile = self
-            .bootstore
-            .clone()
-            .map_or(BootStoreFile::Default { chain_id }, BootStoreFile::Custom);
-        let bootstore: BootStore = bootstore.try_into()?;
+        let bootstore = self.open(chain_id)?;
         println!("Path: {}", self.bootstore.clone().unwrap_or_default().display());
         println!("Peer Count: {}", bootstore.peers.len());
         println!("Valid peers: {}", bootstore.valid_peers_with_chain_id(chain_id).len());
         println!("--------------------------");
         Ok(())
     }
+
+    /// Writes the ENRs of the bootstore with the given chain ID to `path`, returning their count.
+    pub fn export(&self, chain_id: u64, path: &Path) -> anyhow::Result<usize> {
+        let bootstore = self.open(chain_id)?;
+        serde_json::to_writer_pretty(File::create(path)?, &bootstore.peers)?;
+        Ok(bootstore.len())
+    }
+
+    /// Merges the ENRs read from `path` into the bootstore with the given chain ID, returning the
+    /// number of ENRs that were not stored yet. Once the bootstore is full, each new ENR rotates
+    /// the oldest one out.
+    ///
+    /// Fails without modifying the bootstore if any entry is not a well-formed ENR.
+    pub fn import(&self, chain_id: u64, path: &Path) -> anyhow::Result<usize> {
+        let entries: Vec<String> = serde_json::from_reader(File::open(path)?)?;
+        let enrs = entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                entry.parse::<Enr>().map_err(|e| anyhow::anyhow!("Invalid ENR at index {i}: {e}"))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut bootstore = self.open(chain_id)?;
+        let mut new_enrs = Vec::new();
+        for enr in enrs {
+            if !bootstore.peers.contains(&enr) && !new_enrs.contains(&enr) {
+                new_enrs.push(enr);
+            }
+        }
+        let count = new_enrs.len();
+        bootstore.merge(new_enrs);
+        bootstore.sync()?;
+        Ok(count)
+    }
+
+    /// Opens the bootstore with the given chain ID.
+    fn open(&self, chain_id: u64) -> anyhow::Result<BootStore> {
+        let bootstore: BootStoreFile = self
+            .bootstore
+            .clone()
+            .map_or(BootStoreFile::Default { chain_id }, BootStoreFile::Custom);
+        Ok(bootstore.try_into()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use discv5::enr::CombinedKey;
+
+    fn enr() -> Enr {
+        Enr::builder().build(&CombinedKey::generate_secp256k1()).unwrap()
+    }
+
+    #[test]
+    fn test_import_export_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = BootstoreCommand {
+            bootstore: Some(dir.path().join("bootstore.json")),
+            ..Default::default()
+        };
+        let enrs = [enr(), enr()];
+
+        // Import a file listing one of the ENRs twice.
+        let import = dir.path().join("import.json");
+        let entries = [enrs[0].to_base64(), enrs[1].to_base64(), enrs[0].to_base64()];
+        serde_json::to_writer(File::create(&import).unwrap(), &entries).unwrap();
+        assert_eq!(command.import(10, &import).unwrap(), 2);
+        assert_eq!(command.import(10, &import).unwrap(), 0);
+
+        let export = dir.path().join("export.json");
+        assert_eq!(command.export(10, &export).unwrap(), 2);
+        let exported: Vec<Enr> = serde_json::from_reader(File::open(&export).unwrap()).unwrap();
+        assert_eq!(exported, enrs);
+
+        // Importing the export into another bootstore round-trips.
+        let other = BootstoreCommand {
+            bootstore: Some(dir.path().join("other.json")),
+            ..Default::default()
+        };
+        assert_eq!(other.import(10, &export).unwrap(), 2);
+        let reexport = dir.path().join("reexport.json");
+        other.export(10, &reexport).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&export).unwrap(),
+            std::fs::read_to_string(&reexport).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_enr() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = BootstoreCommand {
+            bootstore: Some(dir.path().join("bootstore.json")),
+            ..Default::default()
+        };
+
+        let import = dir.path().join("import.json");
+        let entries = [enr().to_base64(), "enr:-invalid".to_string()];
+        serde_json::to_writer(File::create(&import).unwrap(), &entries).unwrap();
+
+        let err = command.import(10, &import).unwrap_err();
+        assert!(err.to_string().contains("index 1"));
+        assert_eq!(command.export(10, &dir.path().join("export.json")).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_import_into_full_bootstore() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = BootstoreCommand {
+            bootstore: Some(dir.path().join("bootstore.json")),
+            ..Default::default()
+        };
+
+        // Fill the bootstore up to its capacity of 2048 peers.
+        let import = dir.path().join("import.json");
+        let entries: Vec<_> = (0..2048).map(|_| enr().to_base64()).collect();
+        serde_json::to_writer(File::create(&import).unwrap(), &entries).unwrap();
+        assert_eq!(command.import(10, &import).unwrap(), 2048);
+
+        // New ENRs are counted even though they rotate the oldest ones out.
+        let entries = [entries[2047].clone(), enr().to_base64(), enr().to_base64()];
+        serde_json::to_writer(File::create(&import).unwrap(), &entries).unwrap();
+        assert_eq!(command.import(10, &import).unwrap(), 2);
+        assert_eq!(command.export(10, &dir.path().join("export.json")).unwrap(), 2048);
+    }
 }
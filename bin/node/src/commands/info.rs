@@ -3,7 +3,9 @@
 use crate::flags::GlobalArgs;
 use clap::Parser;
 use kona_cli::LogConfig;
+use kona_genesis::RollupConfig;
 use kona_registry::{OPCHAINS, ROLLUP_CONFIGS};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::info;
 
 /// The `info` Subcommand
@@ -13,12 +15,20 @@ use tracing::info;
 /// # Usage
 ///
 /// ```sh
-/// kona-node info
+/// kona-node info [--forks [--timestamp <TIMESTAMP>]]
 /// ```
 
 #[derive(Parser, Default, PartialEq, Debug, Clone)]
 #[command(about = "Runs the information stack for the kona-node.")]
-pub struct InfoCommand;
+pub struct InfoCommand {
+    /// Prints the hardfork activation schedule of the chain, with the activation timestamp and
+    /// block number of each fork.
+    #[arg(long)]
+    pub forks: bool,
+    /// The timestamp against which forks are marked as active. Defaults to the current time.
+    #[arg(long, requires = "forks")]
+    pub timestamp: Option<u64>,
+}
 
 impl InfoCommand {
     /// Initializes the logging system based on global arguments.
@@ -41,9 +51,97 @@ impl InfoCommand {
         println!("Public RPC - {}", op_chain_config.public_rpc);
         println!("Sequencer RPC - {}", op_chain_config.sequencer_rpc);
         println!("Explorer - {}", op_chain_config.explorer);
-        println!("Hardforks: {}", op_rollup_config.hardforks);
+        if self.forks {
+            let now = self.timestamp.unwrap_or_else(|| {
+                SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs()
+            });
+            println!("Hardforks (at timestamp {now}):");
+            for line in Self::fork_schedule(op_rollup_config, now) {
+                println!("{line}");
+            }
+        } else {
+            println!("Hardforks: {}", op_rollup_config.hardforks);
+        }
         println!("-------------");
 
         Ok(())
     }
+
+    /// Returns one line per hardfork of the chain, with its activation timestamp and the number of
+    /// its first L2 block, marking the forks active at `now`.
+    ///
+    /// A fork is active if the rollup config says so, which includes the forks implied by an
+    /// active later fork even if they aren't scheduled themselves.
+    pub fn fork_schedule(config: &RollupConfig, now: u64) -> Vec<String> {
+        config
+            .hardforks
+            .iter()
+            .map(|(name, time)| {
+                let active = Self::is_fork_active(config, name, now);
+                time.map_or_else(
+                    || {
+                        if active {
+                            format!("-> {name}: active, implied by a later fork")
+                        } else {
+                            format!("-> {name}: not scheduled")
+                        }
+                    },
+                    |time| {
+                        // The first block at or past the activation time, as the genesis time may
+                        // not be aligned with the fork times.
+                        let block = config.genesis.l2.number +
+                            time.saturating_sub(config.genesis.l2_time)
+                                .div_ceil(config.block_time);
+                        let status = if active { "active" } else { "scheduled" };
+                        format!("-> {name}: timestamp {time}, block {block} ({status})")
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Returns whether the hardfork with the given name is active at `timestamp`.
+    fn is_fork_active(config: &RollupConfig, name: &str, timestamp: u64) -> bool {
+        match name {
+            "Regolith" => config.is_regolith_active(timestamp),
+            "Canyon" => config.is_canyon_active(timestamp),
+            "Delta" => config.is_delta_active(timestamp),
+            "Ecotone" => config.is_ecotone_active(timestamp),
+            "Fjord" => config.is_fjord_active(timestamp),
+            "Granite" => config.is_granite_active(timestamp),
+            "Holocene" => config.is_holocene_active(timestamp),
+            "Pectra Blob Schedule" => config.is_pectra_blob_schedule_active(timestamp),
+            "Isthmus" => config.is_isthmus_active(timestamp),
+            "Jovian" => config.is_jovian_active(timestamp),
+            "Interop" => config.is_interop_active(timestamp),
+            "Tenrec" => config.is_tenrec_active(timestamp),
+            "Fulu" => config.is_fulu_active(timestamp),
+            _ => unreachable!("unknown hardfork {name}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fork_schedule_op_mainnet() {
+        let schedule = InfoCommand::fork_schedule(&ROLLUP_CONFIGS[&10], 1_710_000_000);
+        assert_eq!(schedule.len(), ROLLUP_CONFIGS[&10].hardforks.iter().count());
+
+        // Regolith isn't scheduled on OP Mainnet, but is implied by Canyon.
+        assert_eq!(schedule[0], "-> Regolith: active, implied by a later fork");
+        assert_eq!(schedule[1], "-> Canyon: timestamp 1704992401, block 114696812 (active)");
+        assert_eq!(schedule[2], "-> Delta: timestamp 1708560000, block 116480612 (active)");
+        assert_eq!(schedule[3], "-> Ecotone: timestamp 1710374401, block 117387812 (scheduled)");
+        assert!(schedule.contains(&"-> Interop: not scheduled".to_string()));
+    }
+
+    #[test]
+    fn test_fork_schedule_before_canyon() {
+        let schedule = InfoCommand::fork_schedule(&ROLLUP_CONFIGS[&10], 1_700_000_000);
+        assert_eq!(schedule[0], "-> Regolith: not scheduled");
+        assert_eq!(schedule[1], "-> Canyon: timestamp 1704992401, block 114696812 (scheduled)");
+    }
 }
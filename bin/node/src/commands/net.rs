@@ -1,8 +1,9 @@
 //! Net Subcommand
 
 use crate::flags::{GlobalArgs, P2PArgs, RpcArgs};
-use clap::Parser;
-use futures::future::OptionFuture;
+use anyhow::{anyhow, bail};
+use clap::{Parser, Subcommand};
+use futures::{StreamExt, future::OptionFuture};
 use jsonrpsee::{RpcModule, server::Server};
 use kona_cli::LogConfig;
 use kona_gossip::P2pRpcRequest;
@@ -10,9 +11,15 @@ use kona_node_service::{
     NetworkActor, NetworkBuilder, NetworkContext, NetworkInboundData, NodeActor,
 };
 use kona_registry::scr_rollup_config_by_alloy_ident;
-use kona_rpc::{OpP2PApiServer, P2pRpc, RpcBuilder};
+use kona_rpc::{HealthRegistry, NodeComponent, OpP2PApiServer, P2pRpc, RpcBuilder};
+use libp2p::{
+    Multiaddr, PeerId, Swarm, SwarmBuilder, identify, noise, ping,
+    swarm::{NetworkBehaviour, SwarmEvent},
+    tcp, yamux,
+};
+use std::time::{Duration, Instant};
 use tokio_util::sync::CancellationToken;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 use url::Url;
 
 /// The `net` Subcommand
@@ -22,7 +29,7 @@ use url::Url;
 /// # Usage
 ///
 /// ```sh
-/// kona-node net [FLAGS] [OPTIONS]
+/// kona-node net [FLAGS] [OPTIONS] [ACTION]
 /// ```
 #[derive(Parser, Default, PartialEq, Debug, Clone)]
 #[command(about = "Runs the networking stack for the kona-node.")]
@@ -38,6 +45,44 @@ pub struct NetCommand {
     /// RPC CLI Flags
     #[command(flatten)]
     pub rpc: RpcArgs,
+    /// A network diagnostic to run instead of the networking stack.
+    #[command(subcommand)]
+    pub action: Option<NetAction>,
+}
+
+/// A network diagnostic run by the `net` subcommand.
+#[derive(Subcommand, PartialEq, Debug, Clone)]
+pub enum NetAction {
+    /// Dials a peer over libp2p and reports the round-trip time and the protocols it supports.
+    Ping {
+        /// The multiaddress of the peer, e.g. `/ip4/127.0.0.1/tcp/9222`.
+        addr: Multiaddr,
+        /// The number of seconds to wait for the peer before giving up.
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+    },
+}
+
+/// The outcome of a successful [`NetAction::Ping`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PingReport {
+    /// The peer ID of the remote peer.
+    pub peer_id: PeerId,
+    /// The time it took to establish the connection.
+    pub connect_time: Duration,
+    /// The round-trip time of the ping.
+    pub rtt: Duration,
+    /// The agent version advertised by the peer, if it supports the identify protocol.
+    pub agent_version: Option<String>,
+    /// The protocols supported by the peer, if it supports the identify protocol.
+    pub protocols: Vec<String>,
+}
+
+/// The [`NetworkBehaviour`] of the swarm used by [`NetAction::Ping`].
+#[derive(NetworkBehaviour)]
+struct PingBehaviour {
+    ping: ping::Behaviour,
+    identify: identify::Behaviour,
 }
 
 impl NetCommand {
@@ -55,6 +100,20 @@ impl NetCommand {
 
     /// Run the Net subcommand.
     pub async fn run(self, args: &GlobalArgs) -> anyhow::Result<()> {
+        if let Some(NetAction::Ping { addr, timeout }) = self.action {
+            println!("Pinging {addr}...");
+            let report = Self::ping(addr, Duration::from_secs(timeout)).await?;
+            println!("Peer ID: {}", report.peer_id);
+            println!("Connected in: {:?}", report.connect_time);
+            println!("RTT: {:?}", report.rtt);
+            println!("Agent: {}", report.agent_version.as_deref().unwrap_or("unknown"));
+            println!("Protocols:");
+            for protocol in report.protocols {
+                println!("  {protocol}");
+            }
+            return Ok(());
+        }
+
         let signer = args.genesis_signer()?;
         info!(target: "net", "Genesis block signer: {:?}", signer);
 
@@ -72,7 +131,13 @@ impl NetCommand {
             NetworkActor::new(NetworkBuilder::from(p2p_config));
 
         let (blocks, mut blocks_rx) = tokio::sync::mpsc::channel(1024);
-        network.start(NetworkContext { blocks, cancellation: CancellationToken::new() }).await?;
+        network
+            .start(NetworkContext {
+                blocks,
+                cancellation: CancellationToken::new(),
+                heartbeat: HealthRegistry::default().heartbeat(NodeComponent::Network),
+            })
+            .await?;
 
         info!(target: "net", "Network started, receiving blocks.");
 
@@ -129,4 +194,123 @@ impl NetCommand {
             }
         }
     }
+
+    /// Dials the peer at `addr` and waits for a ping round trip and its identify information.
+    ///
+    /// Fails with the reason if the peer can't be reached, or if it does not answer within
+    /// `timeout`. Peers that do not support the identify protocol are reported without protocols.
+    pub async fn ping(addr: Multiaddr, timeout: Duration) -> anyhow::Result<PingReport> {
+        let mut swarm = Self::ping_swarm(timeout)?;
+        let start = Instant::now();
+        swarm.dial(addr.clone())?;
+        tokio::time::timeout(timeout, Self::await_ping(&mut swarm, start))
+            .await
+            .map_err(|_| anyhow!("Timed out after {timeout:?} waiting for {addr}"))?
+    }
+
+    /// Builds a swarm with a fresh identity, speaking the ping and identify protocols.
+    fn ping_swarm(idle_timeout: Duration) -> anyhow::Result<Swarm<PingBehaviour>> {
+        Ok(SwarmBuilder::with_new_identity()
+            .with_tokio()
+            .with_tcp(
+                tcp::Config::default().nodelay(true),
+                noise::Config::new,
+                yamux::Config::default,
+            )?
+            .with_behaviour(|key| PingBehaviour {
+                ping: ping::Behaviour::default(),
+                identify: identify::Behaviour::new(
+                    identify::Config::new(String::new(), key.public())
+                        .with_agent_version("kona".to_string()),
+                ),
+            })?
+            .with_swarm_config(|c| c.with_idle_connection_timeout(idle_timeout))
+            .build())
+    }
+
+    /// Drives the swarm until the dialed peer answered a ping and sent its identify information.
+    async fn await_ping(
+        swarm: &mut Swarm<PingBehaviour>,
+        start: Instant,
+    ) -> anyhow::Result<PingReport> {
+        let mut connected = None;
+        let mut rtt = None;
+        let mut identity = None;
+        loop {
+            match swarm.select_next_some().await {
+                SwarmEvent::ConnectionEstablished { peer_id, .. } => {
+                    connected = Some((peer_id, start.elapsed()));
+                }
+                SwarmEvent::OutgoingConnectionError { error, .. } => {
+                    bail!("Failed to connect: {error}");
+                }
+                SwarmEvent::ConnectionClosed { cause, .. } => {
+                    bail!("Connection closed by the peer: {cause:?}");
+                }
+                SwarmEvent::Behaviour(PingBehaviourEvent::Ping(event)) => {
+                    rtt = Some(event.result.map_err(|e| anyhow!("Ping failed: {e}"))?);
+                }
+                SwarmEvent::Behaviour(PingBehaviourEvent::Identify(
+                    identify::Event::Received { info, .. },
+                )) => {
+                    let protocols = info.protocols.iter().map(ToString::to_string).collect();
+                    identity = Some((Some(info.agent_version), protocols));
+                }
+                SwarmEvent::Behaviour(PingBehaviourEvent::Identify(identify::Event::Error {
+                    error,
+                    ..
+                })) => {
+                    debug!(target: "net", "Peer did not identify: {error}");
+                    identity = Some((None, Vec::new()));
+                }
+                _ => {}
+            }
+
+            if let (Some((peer_id, connect_time)), Some(rtt), Some((agent_version, protocols))) =
+                (connected, rtt, &identity)
+            {
+                return Ok(PingReport {
+                    peer_id,
+                    connect_time,
+                    rtt,
+                    agent_version: agent_version.clone(),
+                    protocols: protocols.clone(),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_ping_local_listener() {
+        let mut listener = NetCommand::ping_swarm(Duration::from_secs(10)).unwrap();
+        let peer_id = *listener.local_peer_id();
+        listener.listen_on("/ip4/127.0.0.1/tcp/0".parse().unwrap()).unwrap();
+        let addr = loop {
+            if let SwarmEvent::NewListenAddr { address, .. } = listener.select_next_some().await {
+                break address;
+            }
+        };
+        tokio::spawn(async move { while listener.next().await.is_some() {} });
+
+        let report = NetCommand::ping(addr, Duration::from_secs(10)).await.unwrap();
+        assert_eq!(report.peer_id, peer_id);
+        assert_eq!(report.agent_version.as_deref(), Some("kona"));
+        assert!(report.protocols.iter().any(|p| p == "/ipfs/ping/1.0.0"));
+        assert!(report.protocols.iter().any(|p| p == "/ipfs/id/1.0.0"));
+    }
+
+    #[tokio::test]
+    async fn test_ping_unreachable_peer() {
+        // Reserve a free port, then release it so that nothing listens on it.
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let addr = format!("/ip4/127.0.0.1/tcp/{port}").parse().unwrap();
+
+        let err = NetCommand::ping(addr, Duration::from_secs(10)).await.unwrap_err();
+        assert!(err.to_string().contains("Failed to connect"));
+    }
 }
@@ -9,6 +9,7 @@ use anyhow::{Result, bail};
 use backon::{ExponentialBuilder, Retryable};
 use clap::Parser;
 use kona_cli::{LogConfig, MetricsArgs};
+use kona_engine::ReloadableJwtSecret;
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_node_service::{NodeMode, RollupNode, RollupNodeService};
 use kona_registry::{L1Config, scr_rollup_config_by_alloy_ident};
@@ -16,7 +17,7 @@ use op_alloy_provider::ext::engine::OpEngineApi;
 use serde_json::from_reader;
 use std::{fs::File, path::PathBuf, sync::Arc};
 use strum::IntoEnumIterator;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 use url::Url;
 
 /// A JWT token validation error.
@@ -282,6 +283,15 @@ impl NodeCommand {
         args.metrics.enabled.then(|| init_rollup_config_metrics(&cfg));
 
         let jwt_secret = self.validate_jwt(&cfg).await?;
+        let mut jwt_secret = ReloadableJwtSecret::new(jwt_secret);
+        if let Some(path) = self.jwt_secret_path() {
+            jwt_secret = jwt_secret.with_path(path);
+        }
+        // Reload the secret when its file is rotated, for as long as the node runs.
+        let _jwt_watcher = jwt_secret.watch().unwrap_or_else(|e| {
+            warn!(target: "rollup_node", "Failed to watch the JWT secret file for rotations: {e}");
+            None
+        });
 
         self.p2p_flags.check_ports()?;
         let p2p_config = self.p2p_flags.config(&cfg, args, Some(self.l1_eth_rpc.clone())).await?;
@@ -368,6 +378,14 @@ impl NodeCommand {
         Self::default_jwt_secret()
     }
 
+    /// Returns the path of the JWT secret file, which defaults to `jwt.hex` in the current
+    /// directory.
+    pub fn jwt_secret_path(&self) -> Option<PathBuf> {
+        self.l2_engine_jwt_secret
+            .clone()
+            .or_else(|| std::env::current_dir().ok().map(|dir| dir.join("jwt.hex")))
+    }
+
     /// Uses the current directory to attempt to read
     /// the JWT secret from a file named `jwt.hex`.
     /// If the file is not found, it will return `None`.
@@ -1,8 +1,16 @@
 //! Registry Subcommand
 
 use crate::flags::GlobalArgs;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use kona_cli::LogConfig;
+use kona_genesis::RollupConfig;
+use serde_json::Value;
+use std::{
+    collections::BTreeSet,
+    fmt,
+    fs::File,
+    path::{Path, PathBuf},
+};
 
 /// The `registry` Subcommand
 ///
@@ -11,11 +19,49 @@ use kona_cli::LogConfig;
 /// # Usage
 ///
 /// ```sh
-/// kona-node registry [FLAGS] [OPTIONS]
+/// kona-node registry [FLAGS] [OPTIONS] [ACTION]
 /// ```
 #[derive(Parser, Default, PartialEq, Debug, Clone)]
 #[command(about = "Lists the OP Stack chains available in the superchain-registry")]
-pub struct RegistryCommand;
+pub struct RegistryCommand {
+    /// An action to perform on the registry.
+    /// Lists the available chains if omitted.
+    #[command(subcommand)]
+    pub action: Option<RegistryAction>,
+}
+
+/// An action performed on the registry.
+#[derive(Subcommand, PartialEq, Debug, Clone)]
+pub enum RegistryAction {
+    /// Prints the differences between a local rollup config and the one in the registry.
+    Diff {
+        /// The L2 chain ID of the registry config to compare against.
+        chain_id: u64,
+        /// The path to the local rollup config, in the `rollup.json` format.
+        path: PathBuf,
+    },
+}
+
+/// A field whose value differs between two rollup configs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDiff {
+    /// The dot-separated path of the field, e.g. `genesis.system_config.gasLimit`.
+    pub field: String,
+    /// The value of the field in the registry config, or [`Value::Null`] if unset.
+    pub registry: Value,
+    /// The value of the field in the local config, or [`Value::Null`] if unset.
+    pub local: Value,
+}
+
+impl fmt::Display for ConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let display = |value: &Value| match value {
+            Value::Null => "<unset>".to_string(),
+            value => value.to_string(),
+        };
+        write!(f, "{}: {} -> {}", self.field, display(&self.registry), display(&self.local))
+    }
+}
 
 impl RegistryCommand {
     /// Initializes the logging system based on global arguments.
@@ -26,6 +72,17 @@ impl RegistryCommand {
 
     /// Runs the subcommand.
     pub fn run(self, _args: &GlobalArgs) -> anyhow::Result<()> {
+        if let Some(RegistryAction::Diff { chain_id, path }) = &self.action {
+            let diffs = Self::diff(*chain_id, path)?;
+            if diffs.is_empty() {
+                println!("No differences with the registry config of chain {chain_id}");
+            }
+            for diff in diffs {
+                println!("{diff}");
+            }
+            return Ok(());
+        }
+
         let chains = kona_registry::CHAINS.chains.clone();
         let mut table = tabled::Table::new(chains);
         table.with(tabled::settings::Style::modern());
@@ -36,4 +93,108 @@ impl RegistryCommand {
         println!("{table}");
         Ok(())
     }
+
+    /// Compares the rollup config at `path` to the registry config of the given chain ID,
+    /// returning the fields that differ, sorted by path.
+    pub fn diff(chain_id: u64, path: &Path) -> anyhow::Result<Vec<ConfigDiff>> {
+        let registry = kona_registry::ROLLUP_CONFIGS
+            .get(&chain_id)
+            .ok_or(anyhow::anyhow!("Chain ID {} not found in the registry", chain_id))?;
+        let local: RollupConfig = serde_json::from_reader(File::open(path)?)
+            .map_err(|e| anyhow::anyhow!("Failed to parse rollup config: {}", e))?;
+
+        let mut diffs = Vec::new();
+        diff_values(
+            String::new(),
+            &serde_json::to_value(registry)?,
+            &serde_json::to_value(&local)?,
+            &mut diffs,
+        );
+        Ok(diffs)
+    }
+}
+
+/// Recursively collects the leaf fields that differ between `registry` and `local`.
+fn diff_values(field: String, registry: &Value, local: &Value, diffs: &mut Vec<ConfigDiff>) {
+    match (registry, local) {
+        (Value::Object(registry), Value::Object(local)) => {
+            let keys: BTreeSet<_> = registry.keys().chain(local.keys()).collect();
+            for key in keys {
+                let nested = if field.is_empty() { key.clone() } else { format!("{field}.{key}") };
+                diff_values(
+                    nested,
+                    registry.get(key).unwrap_or(&Value::Null),
+                    local.get(key).unwrap_or(&Value::Null),
+                    diffs,
+                );
+            }
+        }
+        (registry, local) if registry != local => {
+            diffs.push(ConfigDiff { field, registry: registry.clone(), local: local.clone() });
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn write_config(dir: &Path, config: &RollupConfig) -> PathBuf {
+        let path = dir.join("rollup.json");
+        serde_json::to_writer(File::create(&path).unwrap(), config).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_diff_identical_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_config(dir.path(), &kona_registry::ROLLUP_CONFIGS[&10]);
+        assert!(RegistryCommand::diff(10, &path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_modified_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = kona_registry::ROLLUP_CONFIGS[&10].clone();
+        let mut local = registry.clone();
+        local.genesis.l2.number += 1;
+        local.genesis.system_config.as_mut().unwrap().gas_limit = 1;
+        local.hardforks.isthmus_time = None;
+        let path = write_config(dir.path(), &local);
+
+        let diffs = RegistryCommand::diff(10, &path).unwrap();
+        assert_eq!(
+            diffs,
+            vec![
+                ConfigDiff {
+                    field: "genesis.l2.number".to_string(),
+                    registry: json!(registry.genesis.l2.number),
+                    local: json!(local.genesis.l2.number),
+                },
+                ConfigDiff {
+                    field: "genesis.system_config.gasLimit".to_string(),
+                    registry: json!(registry.genesis.system_config.unwrap().gas_limit),
+                    local: json!(1),
+                },
+                ConfigDiff {
+                    field: "isthmus_time".to_string(),
+                    registry: json!(registry.hardforks.isthmus_time.unwrap()),
+                    local: Value::Null,
+                },
+            ]
+        );
+        assert_eq!(
+            diffs[2].to_string(),
+            format!("isthmus_time: {} -> <unset>", registry.hardforks.isthmus_time.unwrap())
+        );
+    }
+
+    #[test]
+    fn test_diff_unknown_chain() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_config(dir.path(), &kona_registry::ROLLUP_CONFIGS[&10]);
+        assert!(RegistryCommand::diff(0, &path).is_err());
+    }
 }
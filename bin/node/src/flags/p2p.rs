@@ -191,6 +191,15 @@ pub struct P2PArgs {
     )]
     pub topic_scoring: bool,
 
+    /// Services block propagation before discovery maintenance. This reduces block propagation
+    /// latency when the node is under CPU pressure, at the cost of slower peer discovery.
+    #[arg(
+        long = "p2p.prioritize-blocks",
+        default_value = "false",
+        env = "KONA_NODE_P2P_PRIORITIZE_BLOCKS"
+    )]
+    pub prioritize_blocks: bool,
+
     /// An optional unsafe block signer address.
     ///
     /// By default, this is fetched from the chain config in the superchain-registry using the
@@ -417,6 +426,7 @@ impl P2PArgs {
             bootnodes: self.bootnodes,
             rollup_config: config.clone(),
             gossip_signer: self.signer.config(args)?,
+            prioritize_blocks: self.prioritize_blocks,
         })
     }
 
@@ -3,7 +3,7 @@
 //! Flags for configuring the RPC server.
 
 use clap::Parser;
-use kona_rpc::RpcBuilder;
+use kona_rpc::{RpcBuilder, WsRPC};
 use std::{
     net::{IpAddr, SocketAddr},
     path::PathBuf,
@@ -34,6 +34,15 @@ pub struct RpcArgs {
     /// Enables websocket rpc server to track block production
     #[arg(long = "rpc.ws-enabled", default_value = "false", env = "KONA_NODE_RPC_WS_ENABLED")]
     pub ws_enabled: bool,
+    /// The number of updates buffered per websocket subscription. Clients falling behind on
+    /// non-critical streams by more than this many updates are disconnected.
+    #[arg(
+        long = "rpc.ws-buffer-size",
+        default_value_t = WsRPC::DEFAULT_BUFFER_SIZE,
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+        env = "KONA_NODE_RPC_WS_BUFFER_SIZE"
+    )]
+    pub ws_buffer_size: usize,
     /// Enables development RPC endpoints for engine state introspection
     #[arg(long = "rpc.dev-enabled", default_value = "false", env = "KONA_NODE_RPC_DEV_ENABLED")]
     pub dev_enabled: bool,
@@ -58,6 +67,7 @@ impl From<RpcArgs> for Option<RpcBuilder> {
             enable_admin: args.enable_admin,
             admin_persistence: args.admin_persistence,
             ws_enabled: args.ws_enabled,
+            ws_buffer_size: args.ws_buffer_size,
             dev_enabled: args.dev_enabled,
         })
     }
@@ -77,6 +87,7 @@ mod tests {
     #[case::disable_rpc_alias(&["--rpc.port", "8743"], |args: &mut RpcArgs| { args.listen_port = 8743; })]
     #[case::disable_rpc(&["--rpc.enable-admin"], |args: &mut RpcArgs| { args.enable_admin = true; })]
     #[case::disable_rpc(&["--rpc.admin-state", "/"], |args: &mut RpcArgs| { args.admin_persistence = Some(PathBuf::from("/")); })]
+    #[case::ws_buffer_size(&["--rpc.ws-buffer-size", "16"], |args: &mut RpcArgs| { args.ws_buffer_size = 16; })]
     fn test_parse_rpc_args(#[case] args: &[&str], #[case] mutate: impl Fn(&mut RpcArgs)) {
         let args = [&["kona-node"], args].concat();
         let cli = RpcArgs::parse_from(args);
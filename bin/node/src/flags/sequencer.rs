@@ -55,6 +55,25 @@ pub struct SequencerArgs {
         value_parser = |arg: &str| -> Result<Duration, ParseIntError> {Ok(Duration::from_secs(arg.parse()?))}
     )]
     pub conductor_rpc_timeout: Duration,
+
+    /// Maximum drift, in seconds, between the sequencer's clock and the timestamp of the selected
+    /// L1 origin before a warning is emitted. Disabled if unset.
+    #[arg(long = "sequencer.max-clock-drift", env = "KONA_NODE_SEQUENCER_MAX_CLOCK_DRIFT")]
+    pub max_clock_drift: Option<u64>,
+
+    /// Clamps the sequencer's clock between the L1 origin timestamp and the maximum sequencer
+    /// drift past it when scheduling the next block. Block timestamps are not affected.
+    #[arg(
+        long = "sequencer.clamp-clock",
+        default_value = "false",
+        env = "KONA_NODE_SEQUENCER_CLAMP_CLOCK"
+    )]
+    pub clamp_clock: bool,
+
+    /// Maximum time, in milliseconds, to wait for the execution layer to return a block built by
+    /// the sequencer. Defaults to half of the block time, and at least one second.
+    #[arg(long = "sequencer.payload-timeout-ms", env = "KONA_NODE_SEQUENCER_PAYLOAD_TIMEOUT_MS")]
+    pub payload_timeout_ms: Option<u64>,
 }
 
 impl Default for SequencerArgs {
@@ -73,6 +92,9 @@ impl SequencerArgs {
             sequencer_recovery_mode: self.recover,
             conductor_rpc_url: self.conductor_rpc.clone(),
             l1_conf_delay: self.l1_confs,
+            max_clock_drift: self.max_clock_drift,
+            clamp_clock: self.clamp_clock,
+            payload_timeout: self.payload_timeout_ms.map(Duration::from_millis),
         }
     }
 }
@@ -1,16 +1,28 @@
 //! Contains the supervisor CLI.
 
-use crate::{flags::SupervisorArgs, metrics::VersionInfo};
-use anyhow::Result;
-use clap::Parser;
+use crate::{flags::SupervisorArgs, inspect::InspectArgs, metrics::VersionInfo};
+use anyhow::{Result, anyhow};
+use clap::{Parser, Subcommand};
 use kona_cli::{LogArgs, LogConfig, MetricsArgs, cli_styles};
 use kona_supervisor_service::Service;
-use tracing::{error, info};
+use tracing::{error, info, warn};
+
+/// Subcommands of the supervisor CLI. Without a subcommand, the supervisor service is run.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Inspects the database of a chain while the supervisor is stopped.
+    Inspect(InspectArgs),
+}
 
 /// CLI for the Rust implementation of the OP Supervisor.
 #[derive(Parser, Debug)]
 #[command(name = "op-supervisor", about = "Rust implementation of the OP Supervisor", styles = cli_styles())]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Cli {
+    /// Subcommand to run instead of the supervisor service.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Global args
     #[command(flatten)]
     pub global: LogArgs,
@@ -19,14 +31,20 @@ pub struct Cli {
     #[command(flatten)]
     pub metrics: MetricsArgs,
 
-    /// Supervisor args
+    /// Supervisor args, required unless a subcommand is given.
     #[command(flatten)]
-    pub supervisor: SupervisorArgs,
+    pub supervisor: Option<SupervisorArgs>,
 }
 
 impl Cli {
     /// Runs the CLI.
     pub fn run(self) -> Result<()> {
+        if let Some(Command::Inspect(args)) = &self.command {
+            return args.run(&mut std::io::stdout().lock());
+        }
+        let supervisor =
+            self.supervisor.as_ref().ok_or_else(|| anyhow!("missing supervisor args"))?;
+
         self.metrics.init_metrics()?;
         // Register build metrics
         VersionInfo::from_build().register_version_metrics();
@@ -34,7 +52,7 @@ impl Cli {
         self.init_logs(&self.global)?;
 
         Self::run_until_ctrl_c(async move {
-            let config = self.supervisor.init_config().await?;
+            let config = supervisor.init_config().await?;
             let mut service = Service::new(config);
 
             tokio::select! {
@@ -48,8 +66,21 @@ impl Cli {
                 }
             }
 
-            service.shutdown().await?; // Call shutdown on the service instance itself
-            info!(target: "supervisor", "Supervisor service shut down gracefully.");
+            let report = service.shutdown().await?; // Call shutdown on the service instance itself
+            if report.is_clean() {
+                info!(
+                    target: "supervisor",
+                    drained = report.drained,
+                    "Supervisor service shut down gracefully."
+                );
+            } else {
+                warn!(
+                    target: "supervisor",
+                    drained = report.drained,
+                    dropped = report.dropped,
+                    "Supervisor service shut down before all in-flight events were handled."
+                );
+            }
             Ok(())
         })
     }
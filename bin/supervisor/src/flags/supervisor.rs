@@ -5,14 +5,15 @@ use anyhow::{Context as _, Ok, Result, anyhow};
 use clap::Args;
 use glob::glob;
 use kona_genesis::RollupConfig;
-use kona_interop::DependencySet;
+use kona_interop::{DependencySet, SafetyLevel};
 use kona_protocol::BlockInfo;
 use kona_supervisor_core::{
     config::{Config, RollupConfigSet},
-    syncnode::ClientConfig,
+    syncnode::{AuthenticationError, ClientConfig, JwtRefresh},
 };
 use serde::de::DeserializeOwned;
 use std::{
+    collections::HashMap,
     net::{IpAddr, SocketAddr},
     path::{Path, PathBuf},
 };
@@ -63,9 +64,48 @@ pub struct SupervisorArgs {
     #[arg(long = "rpc.port", env = "RPC_PORT", default_value_t = 8545)]
     pub rpc_port: u16,
 
+    /// Maximum size of an RPC request body, in bytes.
+    #[arg(
+        long = "rpc.max-request-body-size",
+        env = "RPC_MAX_REQUEST_BODY_SIZE",
+        default_value_t = Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE
+    )]
+    pub rpc_max_request_body_size: u32,
+
+    /// Maximum number of calls in an RPC batch request.
+    #[arg(
+        long = "rpc.max-batch-len",
+        env = "RPC_MAX_BATCH_LEN",
+        default_value_t = Config::DEFAULT_RPC_MAX_BATCH_LEN
+    )]
+    pub rpc_max_batch_len: u32,
+
     /// Enable the Supervisor Admin API.
     #[arg(long = "rpc.enable-admin", env = "RPC_ENABLE_ADMIN", default_value_t = false)]
     pub enable_admin_api: bool,
+
+    /// Event channel capacities of individual chains, as `<chain_id>=<capacity>` pairs.
+    /// Chains not listed use the default capacity.
+    #[arg(long = "event-channel.capacity", env = "EVENT_CHANNEL_CAPACITY", value_delimiter = ',')]
+    pub event_channel_capacities: Vec<String>,
+
+    /// Optional supervisor RPC used to validate message dependencies that are missing from local
+    /// storage, e.g. while a chain is still backfilling.
+    #[arg(long = "dependency-fallback.rpc", env = "DEPENDENCY_FALLBACK_RPC")]
+    pub dependency_fallback_rpc: Option<String>,
+
+    /// Maximum number of blocks whose logs are processed in parallel while catching up.
+    #[arg(long = "log-indexer.concurrency", env = "LOG_INDEXER_CONCURRENCY", default_value_t = 1)]
+    pub log_indexer_concurrency: usize,
+
+    /// Safety levels to skip, e.g. `cross-unsafe`. Levels required by an enabled level can't be
+    /// disabled.
+    #[arg(long = "safety.disabled-levels", env = "SAFETY_DISABLED_LEVELS", value_delimiter = ',')]
+    pub disabled_safety_levels: Vec<String>,
+
+    /// Records histograms of the latency of database table reads and writes.
+    #[arg(long = "metrics.storage-latency", env = "METRICS_STORAGE_LATENCY")]
+    pub storage_latency_metrics: bool,
 }
 
 impl SupervisorArgs {
@@ -184,11 +224,55 @@ impl SupervisorArgs {
                 anyhow::anyhow!("Failed to parse JWT secret from '{}': {}", secret_path, err)
             })?;
 
-            managed_nodes.push(ClientConfig { url: rpc_url.clone(), jwt_secret });
+            // re-read the secret file when the node rejects the token, the secret may be rotated
+            let refresh_path = secret_path.clone();
+            let jwt_refresh = JwtRefresh::new(move || {
+                std::fs::read_to_string(&refresh_path)
+                    .ok()
+                    .and_then(|secret| JwtSecret::from_hex(secret).ok())
+                    .ok_or(AuthenticationError::InvalidJwt)
+            });
+
+            managed_nodes.push(ClientConfig {
+                url: rpc_url.clone(),
+                jwt_secret,
+                jwt_refresh: Some(jwt_refresh),
+            });
         }
         Ok(managed_nodes)
     }
 
+    /// initialise and return the event channel capacity of each configured chain.
+    pub fn init_event_channel_capacities(&self) -> Result<HashMap<u64, usize>> {
+        self.event_channel_capacities
+            .iter()
+            .map(|entry| {
+                let (chain_id, capacity) = entry.trim().split_once('=').ok_or_else(|| {
+                    anyhow!(
+                        "Invalid event channel capacity '{entry}', expected <chain_id>=<capacity>"
+                    )
+                })?;
+                let chain_id = chain_id.trim().parse().with_context(|| {
+                    format!("Invalid chain ID in event channel capacity '{entry}'")
+                })?;
+                let capacity = capacity.trim().parse().with_context(|| {
+                    format!("Invalid capacity in event channel capacity '{entry}'")
+                })?;
+                Ok((chain_id, capacity))
+            })
+            .collect()
+    }
+
+    /// Parses the safety levels to disable.
+    pub fn init_disabled_safety_levels(&self) -> Result<Vec<SafetyLevel>> {
+        self.disabled_safety_levels
+            .iter()
+            .map(|entry| {
+                entry.trim().parse().map_err(|_| anyhow!("Invalid safety level '{entry}'"))
+            })
+            .collect()
+    }
+
     /// initialise and return the Supervisor [`Config`].
     pub async fn init_config(&self) -> Result<Config> {
         let dependency_set = self.init_dependency_set().await?;
@@ -196,16 +280,27 @@ impl SupervisorArgs {
 
         let rpc_addr = SocketAddr::new(self.rpc_address, self.rpc_port);
         let managed_nodes_config = self.init_managed_nodes_config()?;
+        let event_channel_capacities = self.init_event_channel_capacities()?;
+        let disabled_safety_levels = self.init_disabled_safety_levels()?;
 
-        Ok(Config {
+        let config = Config {
             l1_rpc: self.l1_rpc.clone(),
             l2_consensus_nodes_config: managed_nodes_config,
             datadir: self.datadir.clone(),
             rpc_addr,
+            rpc_max_request_body_size: self.rpc_max_request_body_size,
+            rpc_max_batch_len: self.rpc_max_batch_len,
             enable_admin_api: self.enable_admin_api,
             dependency_set,
             rollup_config_set,
-        })
+            event_channel_capacities,
+            dependency_fallback_rpc: self.dependency_fallback_rpc.clone(),
+            log_indexer_concurrency: self.log_indexer_concurrency,
+            disabled_safety_levels,
+            storage_table_metrics: self.storage_latency_metrics,
+        };
+        config.validate_safety_levels()?;
+        Ok(config)
     }
 }
 
@@ -258,6 +353,11 @@ mod tests {
         assert_eq!(cli.supervisor.rollup_config_paths, PathBuf::from("/configs/rollup-*.json"));
         assert_eq!(cli.supervisor.rpc_address, IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)));
         assert_eq!(cli.supervisor.rpc_port, 8545);
+        assert_eq!(
+            cli.supervisor.rpc_max_request_body_size,
+            Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE
+        );
+        assert_eq!(cli.supervisor.rpc_max_batch_len, Config::DEFAULT_RPC_MAX_BATCH_LEN);
     }
 
     #[test]
@@ -282,6 +382,10 @@ mod tests {
             "192.168.1.100",
             "--rpc.port",
             "9001",
+            "--rpc.max-request-body-size",
+            "1024",
+            "--rpc.max-batch-len",
+            "10",
         ]);
 
         assert_eq!(cli.supervisor.l1_rpc, "http://l1.example.com");
@@ -296,6 +400,58 @@ mod tests {
         assert_eq!(cli.supervisor.rollup_config_paths, PathBuf::from("/configs/rollup-*.json"));
         assert_eq!(cli.supervisor.rpc_address, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)));
         assert_eq!(cli.supervisor.rpc_port, 9001);
+        assert_eq!(cli.supervisor.rpc_max_request_body_size, 1024);
+        assert_eq!(cli.supervisor.rpc_max_batch_len, 10);
+    }
+
+    #[test]
+    fn test_init_event_channel_capacities() {
+        let cli = TestCli::parse_from([
+            "test_app",
+            "--l1-rpc",
+            "http://localhost:8545",
+            "--datadir",
+            "/tmp/supervisor_data",
+            "--dependency-set",
+            "/path/to/deps.json",
+            "--rollup-config-paths",
+            "/configs/rollup-*.json",
+            "--event-channel.capacity",
+            "10=500, 11=2000",
+        ]);
+
+        let capacities = cli.supervisor.init_event_channel_capacities().unwrap();
+        assert_eq!(capacities, std::collections::HashMap::from([(10, 500), (11, 2000)]));
+
+        let mut args = cli.supervisor;
+        args.event_channel_capacities = vec!["10:500".to_string()];
+        assert!(args.init_event_channel_capacities().is_err());
+        args.event_channel_capacities = vec!["10=many".to_string()];
+        assert!(args.init_event_channel_capacities().is_err());
+    }
+
+    #[test]
+    fn test_init_disabled_safety_levels() {
+        let cli = TestCli::parse_from([
+            "test_app",
+            "--l1-rpc",
+            "http://localhost:8545",
+            "--datadir",
+            "/tmp/supervisor_data",
+            "--dependency-set",
+            "/path/to/deps.json",
+            "--rollup-config-paths",
+            "/configs/rollup-*.json",
+            "--safety.disabled-levels",
+            "cross-unsafe",
+        ]);
+
+        let levels = cli.supervisor.init_disabled_safety_levels().unwrap();
+        assert_eq!(levels, vec![SafetyLevel::CrossUnsafe]);
+
+        let mut args = cli.supervisor;
+        args.disabled_safety_levels = vec!["sometimes-safe".to_string()];
+        assert!(args.init_disabled_safety_levels().is_err());
     }
 
     #[tokio::test]
@@ -330,7 +486,14 @@ mod tests {
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let result = args.init_dependency_set().await;
@@ -362,7 +525,14 @@ mod tests {
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let result = args.init_dependency_set().await;
@@ -388,7 +558,14 @@ mod tests {
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
             rpc_address: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let result = args.init_dependency_set().await;
@@ -462,7 +639,14 @@ mod tests {
             rollup_config_paths: dir.path().join("rollup-*.json"),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let configs = args.get_rollup_configs().await?;
@@ -484,7 +668,14 @@ mod tests {
             rollup_config_paths: dir.path().join("rollup-*.json"),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let configs = args.get_rollup_configs().await?;
@@ -509,7 +700,14 @@ mod tests {
             rollup_config_paths: dir.path().join("rollup-*.json"),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let result = args.get_rollup_configs().await;
@@ -529,7 +727,14 @@ mod tests {
             rollup_config_paths: PathBuf::from(""),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
         let result = args.get_rollup_configs().await;
         assert!(result.is_err());
@@ -549,7 +754,14 @@ mod tests {
             rollup_config_paths: PathBuf::from("dummy/rollup_config_*.json"),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
         let result = args.init_managed_nodes_config();
         assert!(result.is_err());
@@ -576,7 +788,14 @@ mod tests {
             rollup_config_paths: PathBuf::from(""),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let res = args.init_managed_nodes_config();
@@ -606,7 +825,14 @@ mod tests {
             rollup_config_paths: PathBuf::from(""),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let res = args.init_managed_nodes_config().unwrap();
@@ -627,7 +853,14 @@ mod tests {
             rollup_config_paths: PathBuf::from(""),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let err = args.init_managed_nodes_config().unwrap_err();
@@ -650,7 +883,14 @@ mod tests {
             rollup_config_paths: PathBuf::from(""),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let err = args.init_managed_nodes_config().unwrap_err();
@@ -670,7 +910,14 @@ mod tests {
             rollup_config_paths: PathBuf::from(""),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         let res = args.init_managed_nodes_config();
@@ -761,7 +1008,14 @@ mod tests {
             rollup_config_paths: rollup_dir.path().join("rollup-*.json"),
             rpc_address: "127.0.0.1".parse().unwrap(),
             rpc_port: 8545,
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
+            event_channel_capacities: vec![],
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_latency_metrics: false,
         };
 
         // This will fail at the L1 RPC call unless you mock RootProvider.
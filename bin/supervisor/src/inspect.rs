@@ -0,0 +1,163 @@
+//! Offline inspection of the supervisor database.
+
+use anyhow::{Context as _, Result};
+use clap::Args;
+use kona_protocol::BlockInfo;
+use kona_supervisor_storage::{ChainDb, HeadRefStorageReader, LogStorageReader, StorageError};
+use std::{io::Write, path::PathBuf};
+
+/// Prints the heads, recent logs and table sizes of a chain's database.
+///
+/// The database is opened read-only, and only if no running supervisor has it open.
+#[derive(Args, Debug)]
+pub struct InspectArgs {
+    /// Directory the supervisor stores its data in.
+    #[arg(long, env = "DATADIR")]
+    pub datadir: PathBuf,
+
+    /// Chain to inspect.
+    #[arg(long = "chain-id")]
+    pub chain_id: u64,
+
+    /// Number of most recent logs to print.
+    #[arg(long, default_value_t = 10)]
+    pub logs: usize,
+}
+
+impl InspectArgs {
+    /// Writes the inspection report to `out`.
+    pub fn run(&self, out: &mut impl Write) -> Result<()> {
+        let path = self.datadir.join(self.chain_id.to_string());
+        if !path.exists() {
+            anyhow::bail!("no database for chain {} at {}", self.chain_id, path.display());
+        }
+        let db = ChainDb::open_read_only(self.chain_id, &path)
+            .with_context(|| format!("failed to open database at {}", path.display()))?;
+
+        writeln!(out, "chain {}", self.chain_id)?;
+
+        let head = db.get_super_head().context("failed to read heads")?;
+        writeln!(out, "heads:")?;
+        let heads = [
+            ("l1 source", head.l1_source),
+            ("local unsafe", Some(head.local_unsafe)),
+            ("cross unsafe", head.cross_unsafe),
+            ("local safe", head.local_safe),
+            ("cross safe", head.cross_safe),
+            ("finalized", head.finalized),
+        ];
+        for (name, block) in heads {
+            writeln!(out, "  {name}: {}", format_block(block))?;
+        }
+
+        writeln!(out, "tables:")?;
+        for table in db.table_stats().context("failed to read table stats")? {
+            writeln!(out, "  {}: {} entries, {} bytes", table.name, table.entries, table.size)?;
+        }
+
+        writeln!(out, "logs:")?;
+        let mut remaining = self.logs;
+        let mut block_number = Some(head.local_unsafe.number);
+        while let Some(number) = block_number.filter(|_| remaining > 0) {
+            match db.get_block(number) {
+                Ok(_) => {}
+                // reached the first indexed block
+                Err(StorageError::EntryNotFound(_)) => break,
+                Err(err) => return Err(err).context("failed to read block"),
+            }
+            let logs = db.get_logs(number).context("failed to read logs")?;
+            for log in logs.iter().rev().take(remaining) {
+                write!(out, "  block {number} log {}: {}", log.index, log.hash)?;
+                if let Some(message) = &log.executing_message {
+                    write!(
+                        out,
+                        ", executes chain {} block {} log {}",
+                        message.chain_id, message.block_number, message.log_index
+                    )?;
+                }
+                writeln!(out)?;
+            }
+            remaining = remaining.saturating_sub(logs.len());
+            block_number = number.checked_sub(1);
+        }
+        Ok(())
+    }
+}
+
+fn format_block(block: Option<BlockInfo>) -> String {
+    block.map_or_else(
+        || "none".to_string(),
+        |block| format!("#{} {} (timestamp {})", block.number, block.hash, block.timestamp),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Command};
+    use alloy_primitives::B256;
+    use clap::Parser;
+    use kona_supervisor_storage::LogStorageWriter;
+    use kona_supervisor_types::Log;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_inspect_subcommand() {
+        let cli = Cli::parse_from([
+            "kona-supervisor",
+            "inspect",
+            "--datadir",
+            "/data",
+            "--chain-id",
+            "10",
+        ]);
+
+        let Some(Command::Inspect(args)) = cli.command else {
+            panic!("expected inspect subcommand");
+        };
+        assert_eq!(args.datadir, PathBuf::from("/data"));
+        assert_eq!(args.chain_id, 10);
+        assert_eq!(args.logs, 10);
+        assert!(cli.supervisor.is_none());
+    }
+
+    #[test]
+    fn test_inspect_closed_db() {
+        let datadir = tempdir().unwrap();
+        let anchor = BlockInfo { number: 0, hash: B256::repeat_byte(1), ..Default::default() };
+        let block = BlockInfo {
+            number: 1,
+            hash: B256::repeat_byte(2),
+            parent_hash: anchor.hash,
+            timestamp: 2,
+        };
+        {
+            let db = ChainDb::new(10, &datadir.path().join("10")).unwrap();
+            db.initialise_log_storage(anchor).unwrap();
+            let logs = (0..3)
+                .map(|index| Log { index, hash: B256::repeat_byte(0xa0), executing_message: None })
+                .collect();
+            db.store_block_logs(&block, logs).unwrap();
+        }
+
+        let args = InspectArgs { datadir: datadir.path().to_path_buf(), chain_id: 10, logs: 2 };
+        let mut out = Vec::new();
+        args.run(&mut out).unwrap();
+        let report = String::from_utf8(out).unwrap();
+
+        assert!(report.contains(&format!("local unsafe: #1 {}", block.hash)));
+        assert!(report.contains(&format!("cross unsafe: #0 {}", anchor.hash)));
+        assert!(report.contains("local safe: none"));
+        assert!(report.contains("LogEntries: 3 entries"));
+        assert!(report.contains("block 1 log 2:"));
+        assert!(report.contains("block 1 log 1:"));
+        assert!(!report.contains("block 1 log 0:"));
+    }
+
+    #[test]
+    fn test_inspect_missing_db() {
+        let datadir = tempdir().unwrap();
+        let args = InspectArgs { datadir: datadir.path().to_path_buf(), chain_id: 10, logs: 2 };
+        assert!(args.run(&mut Vec::new()).is_err());
+    }
+}
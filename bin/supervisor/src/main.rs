@@ -8,6 +8,7 @@
 
 pub mod cli;
 pub mod flags;
+pub mod inspect;
 pub mod metrics;
 pub(crate) mod version;
 
@@ -1,5 +1,6 @@
 //! Contains a builder for the discovery service.
 
+use alloy_rlp::Bytes;
 use discv5::{Config, Discv5, Enr, enr::k256};
 use kona_peers::{BootStoreFile, OpStackEnr};
 use std::net::IpAddr;
@@ -47,7 +48,11 @@ impl LocalNode {
     /// broadcast to the other nodes in the network. See
     /// [the op-node implementation](https://github.com/ethereum-optimism/optimism/blob/174e55f0a1e73b49b80a561fd3fedd4fea5770c6/op-node/p2p/discovery.go#L61-L97)
     /// for the go equivalent
-    fn build_enr(self, chain_id: u64) -> Result<Enr, discv5::enr::Error> {
+    fn build_enr(
+        self,
+        chain_id: u64,
+        fields: &[(Vec<u8>, Bytes)],
+    ) -> Result<Enr, discv5::enr::Error> {
         let opstack = OpStackEnr::from_chain_id(chain_id);
         let mut opstack_data = Vec::new();
         use alloy_rlp::Encodable;
@@ -55,6 +60,9 @@ impl LocalNode {
 
         let mut enr_builder = Enr::builder();
         enr_builder.add_value_rlp(OpStackEnr::OP_CL_KEY, opstack_data.into());
+        for (key, value) in fields {
+            enr_builder.add_value(key, value);
+        }
         match self.ip {
             IpAddr::V4(addr) => {
                 enr_builder.ip4(addr).tcp4(self.tcp_port).udp4(self.udp_port);
@@ -68,6 +76,28 @@ impl LocalNode {
     }
 }
 
+/// The keys of the [`Enr`] that are set by the discovery service itself, and can't be customized.
+const RESERVED_ENR_KEYS: [&str; 9] =
+    ["id", "secp256k1", "ip", "ip6", "tcp", "tcp6", "udp", "udp6", OpStackEnr::OP_CL_KEY];
+
+/// Validates the key of a custom [`Enr`] field.
+///
+/// The ENR spec doesn't bound the length of a key beyond the maximum size of the record, which is
+/// checked when the record is signed. A key must however be non-empty, and must not override one
+/// of the keys set by the discovery service.
+fn validate_enr_key(key: &[u8]) -> Result<(), Discv5BuilderError> {
+    if key.is_empty() {
+        return Err(Discv5BuilderError::InvalidEnrField("empty key".to_string()));
+    }
+    if RESERVED_ENR_KEYS.iter().any(|reserved| reserved.as_bytes() == key) {
+        return Err(Discv5BuilderError::InvalidEnrField(format!(
+            "reserved key `{}`",
+            String::from_utf8_lossy(key)
+        )));
+    }
+    Ok(())
+}
+
 /// Discovery service builder.
 #[derive(Debug, Clone)]
 pub struct Discv5Builder {
@@ -89,6 +119,8 @@ pub struct Discv5Builder {
     store_interval: Option<Duration>,
     /// Whether or not to forward the initial set of valid ENRs to the gossip layer.
     forward: bool,
+    /// Custom key/value pairs to advertise in the local ENR.
+    enr_fields: Vec<(Vec<u8>, Bytes)>,
 }
 
 impl Discv5Builder {
@@ -104,6 +136,7 @@ impl Discv5Builder {
             bootnodes: Vec::new(),
             store_interval: None,
             forward: true,
+            enr_fields: Vec::new(),
         }
     }
 
@@ -155,6 +188,15 @@ impl Discv5Builder {
         self
     }
 
+    /// Adds a custom key/value pair to the local ENR, e.g. to tag the node with a client version.
+    ///
+    /// The value is RLP-encoded as a byte string. The key is validated when the discovery service
+    /// is built, and must not override one of the keys set by the discovery service.
+    pub fn with_enr_field(mut self, key: impl Into<Vec<u8>>, value: impl Into<Bytes>) -> Self {
+        self.enr_fields.push((key.into(), value.into()));
+        self
+    }
+
     /// Disables forwarding of the initial set of valid ENRs to the gossip layer.
     pub const fn disable_forward(mut self) -> Self {
         self.forward = false;
@@ -170,7 +212,12 @@ impl Discv5Builder {
         let local_node = self.local_node;
         let key = local_node.signing_key.clone();
 
-        let enr = local_node.build_enr(chain_id).map_err(|_| Discv5BuilderError::EnrBuildFailed)?;
+        for (key, _) in &self.enr_fields {
+            validate_enr_key(key)?;
+        }
+        let enr = local_node
+            .build_enr(chain_id, &self.enr_fields)
+            .map_err(|_| Discv5BuilderError::EnrBuildFailed)?;
 
         let interval = self.interval.unwrap_or(Duration::from_secs(5));
         let disc = Discv5::new(enr, key.into(), config)
@@ -192,6 +239,46 @@ mod tests {
     use kona_peers::EnrValidation;
     use std::net::{IpAddr, Ipv4Addr};
 
+    fn builder() -> Discv5Builder {
+        let CombinedKey::Secp256k1(k256_key) = CombinedKey::generate_secp256k1() else {
+            unreachable!()
+        };
+        let addr = LocalNode::new(k256_key, IpAddr::V4(Ipv4Addr::UNSPECIFIED), 9099, 9099);
+        Discv5Builder::new(
+            addr,
+            10,
+            ConfigBuilder::new(ListenConfig::Ipv4 { ip: Ipv4Addr::UNSPECIFIED, port: 0 }).build(),
+        )
+    }
+
+    #[test]
+    fn test_custom_enr_field() {
+        let driver = builder().with_enr_field("client", "kona/v1.0.0").build().unwrap();
+        let enr = driver.disc.local_enr();
+
+        assert_eq!(
+            enr.get_decodable::<Bytes>("client").unwrap().unwrap(),
+            Bytes::from_static(b"kona/v1.0.0")
+        );
+        assert!(EnrValidation::validate(&enr, 10).is_valid());
+    }
+
+    #[test]
+    fn test_invalid_enr_field() {
+        assert_eq!(
+            builder().with_enr_field("", "kona").build().unwrap_err(),
+            Discv5BuilderError::InvalidEnrField("empty key".to_string())
+        );
+        assert_eq!(
+            builder().with_enr_field("opstack", "kona").build().unwrap_err(),
+            Discv5BuilderError::InvalidEnrField("reserved key `opstack`".to_string())
+        );
+        assert_eq!(
+            builder().with_enr_field("client", vec![0u8; 512]).build().unwrap_err(),
+            Discv5BuilderError::EnrBuildFailed
+        );
+    }
+
     #[test]
     fn test_builds_valid_enr() {
         let CombinedKey::Secp256k1(k256_key) = CombinedKey::generate_secp256k1() else {
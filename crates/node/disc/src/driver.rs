@@ -349,6 +349,9 @@ impl Discv5Driver {
                                         }
                                     }
                                 },
+                                HandlerRequest::UnbanNode(node_id) => {
+                                    self.disc.ban_node_remove(&node_id);
+                                },
                             }
                             None => {
                                 trace!(target: "discovery", "Receiver `None` peer enr");
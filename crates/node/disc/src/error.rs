@@ -12,4 +12,7 @@ pub enum Discv5BuilderError {
     /// Failed to build the ENR.
     #[error("failed to build ENR")]
     EnrBuildFailed,
+    /// A custom ENR field has an invalid key.
+    #[error("invalid ENR field key: {0}")]
+    InvalidEnrField(String),
 }
@@ -69,6 +69,12 @@ pub enum HandlerRequest {
         /// Duration for which the addresses should be banned.
         ban_duration: Duration,
     },
+
+    /// Lift the ban of a node.
+    ///
+    /// Lets the discovery service interact with a node banned through
+    /// [`HandlerRequest::BanAddrs`] again.
+    UnbanNode(NodeId),
 }
 
 /// Handler to the spawned [`discv5::Discv5`] service.
@@ -148,6 +154,16 @@ impl Discv5Handler {
         rx
     }
 
+    /// Lifts the ban of the given [`NodeId`] in the discovery service.
+    pub fn unban_node(&self, node_id: NodeId) {
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(e) = sender.send(HandlerRequest::UnbanNode(node_id)).await {
+                warn!(target: "discv5_handler", "Failed to send unban node request: {:?}", e);
+            }
+        });
+    }
+
     /// Blocking request for the metrics of the discovery service.
     ///
     /// Returns `None` if the request could not be sent or received.
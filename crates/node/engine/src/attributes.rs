@@ -386,6 +386,35 @@ pub enum AttributesMismatch {
     IsthmusMissingWithdrawalsRoot,
 }
 
+impl AttributesMismatch {
+    /// Returns the name of the mismatching field, used to label metrics.
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::ParentHash(..) => "parent_hash",
+            Self::Timestamp(..) => "timestamp",
+            Self::PrevRandao(..) => "prev_randao",
+            Self::MalformedBlockTransactions |
+            Self::MalformedAttributesTransaction |
+            Self::TransactionLen(..) |
+            Self::TransactionContent(..) |
+            Self::Transactions(..) => "transactions",
+            Self::MissingAttributesEIP1559 |
+            Self::MissingBlockEIP1559 |
+            Self::InvalidExtraDataVersion |
+            Self::UnknownExtraDataDecodingError(_) |
+            Self::InvalidEIP1559ParamsCombination |
+            Self::EIP1559Parameters(..) => "eip1559_params",
+            Self::GasLimit(..) | Self::MissingAttributesGasLimit => "gas_limit",
+            Self::FeeRecipient(..) => "fee_recipient",
+            Self::ParentBeaconBlockRoot(..) => "parent_beacon_block_root",
+            Self::CanyonWithdrawalsNotEmpty |
+            Self::CanyonNotEmptyHash |
+            Self::BedrockWithdrawals |
+            Self::IsthmusMissingWithdrawalsRoot => "withdrawals",
+        }
+    }
+}
+
 impl From<AttributesMismatch> for AttributesMatch {
     fn from(mismatch: AttributesMismatch) -> Self {
         Self::Mismatch(mismatch)
@@ -498,6 +527,15 @@ mod tests {
         assert!(check.is_mismatch());
     }
 
+    #[test]
+    fn test_attributes_mismatch_field() {
+        assert_eq!(AttributesMismatch::Timestamp(1, 2).field(), "timestamp");
+        assert_eq!(AttributesMismatch::GasLimit(1, 2).field(), "gas_limit");
+        assert_eq!(AttributesMismatch::MissingAttributesGasLimit.field(), "gas_limit");
+        assert_eq!(AttributesMismatch::TransactionLen(1, 2).field(), "transactions");
+        assert_eq!(AttributesMismatch::CanyonNotEmptyHash.field(), "withdrawals");
+    }
+
     #[test]
     fn test_attributes_match_check_parent_beacon_block_root() {
         let cfg = default_rollup_config();
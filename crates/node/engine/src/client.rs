@@ -1,6 +1,6 @@
 //! An Engine API Client.
 
-use crate::Metrics;
+use crate::{JwtAuthLayer, JwtAuthService, Metrics, ReloadableJwtSecret};
 use alloy_eips::eip1898::BlockNumberOrTag;
 use alloy_network::Network;
 use alloy_primitives::{B256, BlockHash, Bytes};
@@ -8,12 +8,12 @@ use alloy_provider::{Provider, RootProvider};
 use alloy_rpc_client::RpcClient;
 use alloy_rpc_types_engine::{
     ClientVersionV1, ExecutionPayloadBodiesV1, ExecutionPayloadEnvelopeV2, ExecutionPayloadInputV2,
-    ExecutionPayloadV3, ForkchoiceState, ForkchoiceUpdated, JwtSecret, PayloadId, PayloadStatus,
+    ExecutionPayloadV3, ForkchoiceState, ForkchoiceUpdated, PayloadId, PayloadStatus,
 };
 use alloy_rpc_types_eth::Block;
 use alloy_transport::{RpcError, TransportErrorKind, TransportResult};
 use alloy_transport_http::{
-    AuthLayer, AuthService, Http, HyperClient,
+    Http, HyperClient,
     hyper_util::{
         client::legacy::{Client, connect::HttpConnector},
         rt::TokioExecutor,
@@ -47,7 +47,7 @@ pub enum EngineClientError {
     BlockInfoDecodeError(#[from] FromBlockError),
 }
 /// A Hyper HTTP client with a JWT authentication layer.
-type HyperAuthClient<B = Full<Bytes>> = HyperClient<B, AuthService<Client<HttpConnector, B>>>;
+type HyperAuthClient<B = Full<Bytes>> = HyperClient<B, JwtAuthService<Client<HttpConnector, B>>>;
 
 /// An Engine API client that provides authenticated HTTP communication with an execution layer.
 ///
@@ -87,9 +87,9 @@ pub struct EngineClient {
 
 impl EngineClient {
     /// Creates a new RPC client for the given address and JWT secret.
-    fn rpc_client<T: Network>(addr: Url, jwt: JwtSecret) -> RootProvider<T> {
+    fn rpc_client<T: Network>(addr: Url, jwt: ReloadableJwtSecret) -> RootProvider<T> {
         let hyper_client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
-        let auth_layer = AuthLayer::new(jwt);
+        let auth_layer = JwtAuthLayer::new(jwt);
         let service = ServiceBuilder::new().layer(auth_layer).service(hyper_client);
         let layer_transport = HyperClient::with_service(service);
 
@@ -108,9 +108,15 @@ impl EngineClient {
     /// * `engine` - L2 Engine API endpoint URL (typically port 8551)
     /// * `l1_rpc` - L1 chain RPC endpoint URL
     /// * `cfg` - Rollup configuration for version selection
-    /// * `jwt` - JWT secret for authentication
-    pub fn new_http(engine: Url, l1_rpc: Url, cfg: Arc<RollupConfig>, jwt: JwtSecret) -> Self {
-        let engine = Self::rpc_client::<Optimism>(engine, jwt);
+    /// * `jwt` - JWT secret for authentication, which may be a [`ReloadableJwtSecret`] to pick up
+    ///   rotations of the secret file
+    pub fn new_http(
+        engine: Url,
+        l1_rpc: Url,
+        cfg: Arc<RollupConfig>,
+        jwt: impl Into<ReloadableJwtSecret>,
+    ) -> Self {
+        let engine = Self::rpc_client::<Optimism>(engine, jwt.into());
         let l1_provider = RootProvider::new_http(l1_rpc);
 
         Self { engine, l1_provider, cfg }
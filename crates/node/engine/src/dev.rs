@@ -0,0 +1,173 @@
+//! Development utilities for exercising the execution engine's payload validation.
+//!
+//! These utilities submit deliberately malformed payloads to the execution engine. They are only
+//! available with the `dev` feature and must never be enabled in production builds.
+
+use crate::EngineClient;
+use alloy_primitives::B256;
+use alloy_provider::ext::EngineApi;
+use alloy_rpc_types_engine::{ExecutionPayloadInputV2, PayloadStatus};
+use alloy_transport::{RpcError, TransportErrorKind};
+use op_alloy_consensus::OpBlock;
+use op_alloy_provider::ext::engine::OpEngineApi;
+use op_alloy_rpc_types_engine::{OpExecutionPayload, OpExecutionPayloadEnvelope};
+
+/// The defect to introduce into a payload injected with [`InvalidPayloadKind::envelope`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InvalidPayloadKind {
+    /// The payload commits to a state root that does not match its execution.
+    BadStateRoot,
+    /// The payload commits to a receipts root that does not match its execution.
+    BadReceiptsRoot,
+    /// The payload timestamp is far ahead of its parent.
+    FutureTimestamp,
+}
+
+impl InvalidPayloadKind {
+    /// All [`InvalidPayloadKind`]s.
+    pub const ALL: [Self; 3] = [Self::BadStateRoot, Self::BadReceiptsRoot, Self::FutureTimestamp];
+
+    /// The offset, in seconds, added to the block timestamp by
+    /// [`InvalidPayloadKind::FutureTimestamp`].
+    pub const FUTURE_TIMESTAMP_OFFSET: u64 = 24 * 60 * 60;
+
+    /// Introduces the defect into the given block's header.
+    pub fn corrupt(&self, block: &mut OpBlock) {
+        let header = &mut block.header;
+        match self {
+            Self::BadStateRoot => header.state_root = !header.state_root,
+            Self::BadReceiptsRoot => header.receipts_root = !header.receipts_root,
+            Self::FutureTimestamp => {
+                header.timestamp = header.timestamp.saturating_add(Self::FUTURE_TIMESTAMP_OFFSET)
+            }
+        }
+    }
+
+    /// Builds the payload envelope for the given block with the defect introduced.
+    ///
+    /// The block hash is recomputed over the corrupted header, so that the execution engine
+    /// rejects the payload on execution rather than on a block hash mismatch.
+    pub fn envelope(&self, mut block: OpBlock) -> OpExecutionPayloadEnvelope {
+        self.corrupt(&mut block);
+
+        let (execution_payload, _) = OpExecutionPayload::from_block_slow(&block);
+        OpExecutionPayloadEnvelope {
+            parent_beacon_block_root: block.header.parent_beacon_block_root,
+            execution_payload,
+        }
+    }
+}
+
+/// Submits the payload envelope to the execution engine through the `engine_newPayload` version
+/// matching the payload, returning the engine's [`PayloadStatus`].
+pub(crate) async fn new_payload(
+    client: &EngineClient,
+    envelope: OpExecutionPayloadEnvelope,
+) -> Result<PayloadStatus, RpcError<TransportErrorKind>> {
+    let parent_beacon_block_root = envelope.parent_beacon_block_root.unwrap_or(B256::ZERO);
+    match envelope.execution_payload {
+        OpExecutionPayload::V1(payload) => client.new_payload_v1(payload).await,
+        OpExecutionPayload::V2(payload) => {
+            client
+                .new_payload_v2(ExecutionPayloadInputV2 {
+                    execution_payload: payload.payload_inner,
+                    withdrawals: Some(payload.withdrawals),
+                })
+                .await
+        }
+        OpExecutionPayload::V3(payload) => {
+            client.new_payload_v3(payload, parent_beacon_block_root).await
+        }
+        OpExecutionPayload::V4(payload) => {
+            client.new_payload_v4(payload, parent_beacon_block_root).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Block, BlockBody, Header};
+    use alloy_primitives::b256;
+
+    fn block() -> OpBlock {
+        Block {
+            header: Header {
+                number: 10,
+                timestamp: 1_000,
+                state_root: b256!(
+                    "0x1111111111111111111111111111111111111111111111111111111111111111"
+                ),
+                receipts_root: b256!(
+                    "0x2222222222222222222222222222222222222222222222222222222222222222"
+                ),
+                base_fee_per_gas: Some(1),
+                ..Default::default()
+            },
+            body: BlockBody { transactions: vec![], ommers: vec![], withdrawals: None },
+        }
+    }
+
+    #[test]
+    fn test_bad_state_root() {
+        let valid = block();
+        let envelope = InvalidPayloadKind::BadStateRoot.envelope(valid.clone());
+        let payload = envelope.execution_payload.as_v1();
+
+        assert_ne!(payload.state_root, valid.header.state_root);
+        assert_eq!(payload.receipts_root, valid.header.receipts_root);
+        assert_eq!(payload.timestamp, valid.header.timestamp);
+    }
+
+    #[test]
+    fn test_bad_receipts_root() {
+        let valid = block();
+        let envelope = InvalidPayloadKind::BadReceiptsRoot.envelope(valid.clone());
+        let payload = envelope.execution_payload.as_v1();
+
+        assert_eq!(payload.state_root, valid.header.state_root);
+        assert_ne!(payload.receipts_root, valid.header.receipts_root);
+        assert_eq!(payload.timestamp, valid.header.timestamp);
+    }
+
+    #[test]
+    fn test_future_timestamp() {
+        let valid = block();
+        let envelope = InvalidPayloadKind::FutureTimestamp.envelope(valid.clone());
+        let payload = envelope.execution_payload.as_v1();
+
+        assert_eq!(payload.state_root, valid.header.state_root);
+        assert_eq!(payload.receipts_root, valid.header.receipts_root);
+        assert_eq!(
+            payload.timestamp,
+            valid.header.timestamp + InvalidPayloadKind::FUTURE_TIMESTAMP_OFFSET
+        );
+    }
+
+    #[test]
+    fn test_block_hash_commits_to_defect() {
+        for kind in InvalidPayloadKind::ALL {
+            let mut corrupted = block();
+            kind.corrupt(&mut corrupted);
+
+            let envelope = kind.envelope(block());
+            let payload = envelope.execution_payload.as_v1();
+
+            assert_ne!(payload.block_hash, block().header.hash_slow(), "{kind:?}");
+            assert_eq!(payload.block_hash, corrupted.header.hash_slow(), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn test_kind_serde() {
+        for (kind, name) in InvalidPayloadKind::ALL.into_iter().zip([
+            "\"badStateRoot\"",
+            "\"badReceiptsRoot\"",
+            "\"futureTimestamp\"",
+        ]) {
+            assert_eq!(serde_json::to_string(&kind).unwrap(), name);
+            assert_eq!(serde_json::from_str::<InvalidPayloadKind>(name).unwrap(), kind);
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! JWT authentication of the Engine API, with a secret that can be rotated at runtime.
+
+use alloy_rpc_types_engine::{Claims, JwtSecret};
+use alloy_transport_http::hyper::{
+    Request, Response, StatusCode,
+    header::{AUTHORIZATION, HeaderValue},
+};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    future::Future,
+    path::PathBuf,
+    pin::Pin,
+    sync::{Arc, PoisonError, RwLock},
+    task::{Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tower::{Layer, Service};
+
+/// The lifetime of the issued tokens, in seconds.
+///
+/// The Engine API rejects tokens issued more than 60 seconds away from its clock.
+const TOKEN_LIFETIME: u64 = 60;
+
+/// A [`JwtSecret`] shared by the clients authenticating to the engine, which can be reloaded from
+/// the file it was read from.
+///
+/// Clones share the same secret, so a reload is seen by every client built with the
+/// [`ReloadableJwtSecret`].
+#[derive(Debug, Clone)]
+pub struct ReloadableJwtSecret {
+    /// The current secret.
+    secret: Arc<RwLock<JwtSecret>>,
+    /// The file the secret is reloaded from. Reloading is disabled if [`None`].
+    path: Option<PathBuf>,
+}
+
+impl From<JwtSecret> for ReloadableJwtSecret {
+    fn from(secret: JwtSecret) -> Self {
+        Self::new(secret)
+    }
+}
+
+impl ReloadableJwtSecret {
+    /// Creates a new [`ReloadableJwtSecret`], which is never reloaded.
+    pub fn new(secret: JwtSecret) -> Self {
+        Self { secret: Arc::new(RwLock::new(secret)), path: None }
+    }
+
+    /// Sets the file the secret is reloaded from.
+    pub fn with_path(self, path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()), ..self }
+    }
+
+    /// Returns the current secret.
+    pub fn secret(&self) -> JwtSecret {
+        *self.secret.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Reloads the secret from its file, returning whether it changed.
+    ///
+    /// If the file is missing or does not hold a valid hex-encoded secret, the error is logged and
+    /// the current secret is kept.
+    pub fn reload(&self) -> bool {
+        let Some(path) = &self.path else {
+            return false;
+        };
+
+        let secret = match std::fs::read_to_string(path) {
+            Ok(content) => match JwtSecret::from_hex(content.trim()) {
+                Ok(secret) => secret,
+                Err(e) => {
+                    error!(target: "engine", path = %path.display(), "Malformed JWT secret file, keeping the current secret: {e}");
+                    return false;
+                }
+            },
+            Err(e) => {
+                error!(target: "engine", path = %path.display(), "Failed to read the JWT secret file, keeping the current secret: {e}");
+                return false;
+            }
+        };
+
+        let mut current = self.secret.write().unwrap_or_else(PoisonError::into_inner);
+        if current.as_bytes() == secret.as_bytes() {
+            return false;
+        }
+        *current = secret;
+        info!(target: "engine", path = %path.display(), "Reloaded the engine JWT secret");
+        true
+    }
+
+    /// Starts watching the secret file, reloading the secret whenever it is written or replaced.
+    ///
+    /// The parent directory is watched rather than the file itself, so that secrets rotated by
+    /// atomically renaming a new file over the old one are picked up. The secret is only reloaded
+    /// as long as the returned watcher is alive.
+    ///
+    /// Returns `Ok(None)` if the secret has no file to reload from.
+    pub fn watch(&self) -> Result<Option<RecommendedWatcher>, notify::Error> {
+        let Some(path) = self.path.clone() else {
+            return Ok(None);
+        };
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let file_name = path.file_name().map(ToOwned::to_owned);
+
+        let secret = self.clone();
+        let mut watcher = notify::recommended_watcher(move |res: Result<Event, notify::Error>| {
+            match res {
+                Ok(Event { kind: EventKind::Create(_) | EventKind::Modify(_), paths, .. })
+                    if paths.iter().any(|p| p.file_name() == file_name.as_deref()) =>
+                {
+                    secret.reload();
+                }
+                Ok(event) => {
+                    trace!(target: "engine", event = ?event, "Ignoring JWT secret watcher event");
+                }
+                Err(e) => {
+                    error!(target: "engine", error = %e, "Failed to receive JWT secret watcher event");
+                }
+            }
+        })?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+        info!(target: "engine", path = %path.display(), "Watching the engine JWT secret for rotations");
+        Ok(Some(watcher))
+    }
+}
+
+/// A [`Layer`] authenticating requests to the engine with the current [`ReloadableJwtSecret`].
+#[derive(Debug, Clone)]
+pub struct JwtAuthLayer {
+    secret: ReloadableJwtSecret,
+}
+
+impl JwtAuthLayer {
+    /// Creates a new [`JwtAuthLayer`].
+    pub const fn new(secret: ReloadableJwtSecret) -> Self {
+        Self { secret }
+    }
+}
+
+impl<S> Layer<S> for JwtAuthLayer {
+    type Service = JwtAuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        JwtAuthService { inner, secret: self.secret.clone() }
+    }
+}
+
+/// A [`Service`] adding a bearer token signed with the current [`ReloadableJwtSecret`] to each
+/// request.
+///
+/// Requests rejected as unauthorized are retried once if the secret changed since they were signed,
+/// after reloading it from its file.
+#[derive(Debug, Clone)]
+pub struct JwtAuthService<S> {
+    inner: S,
+    secret: ReloadableJwtSecret,
+}
+
+impl<S, B, ResBody> Service<Request<B>> for JwtAuthService<S>
+where
+    S: Service<Request<B>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    S::Error: Send + 'static,
+    B: Clone + Send + 'static,
+    ResBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        // Use the service that was polled ready, leaving a fresh clone for the next request.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let secret = self.secret.clone();
+
+        Box::pin(async move {
+            let retry = clone_request(&request);
+            let signed_with = secret.secret();
+            let response = inner.call(authorize(request, &signed_with)).await?;
+            if response.status() != StatusCode::UNAUTHORIZED {
+                return Ok(response);
+            }
+
+            secret.reload();
+            let current = secret.secret();
+            if current.as_bytes() == signed_with.as_bytes() {
+                return Ok(response);
+            }
+            drop(response);
+
+            warn!(target: "engine", "Engine rejected the JWT, retrying with the rotated secret");
+            std::future::poll_fn(|cx| inner.poll_ready(cx)).await?;
+            inner.call(authorize(retry, &current)).await
+        })
+    }
+}
+
+/// Adds a bearer token signed with `secret` to the request.
+fn authorize<B>(mut request: Request<B>, secret: &JwtSecret) -> Request<B> {
+    let iat = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let claims = Claims { iat, exp: Some(iat + TOKEN_LIFETIME) };
+    match secret.encode(&claims).map(|token| HeaderValue::from_str(&format!("Bearer {token}"))) {
+        Ok(Ok(header)) => {
+            request.headers_mut().insert(AUTHORIZATION, header);
+        }
+        Ok(Err(e)) => error!(target: "engine", "Invalid JWT authorization header: {e}"),
+        Err(e) => error!(target: "engine", "Failed to sign the JWT: {e}"),
+    }
+    request
+}
+
+/// Clones a request, without its extensions.
+fn clone_request<B: Clone>(request: &Request<B>) -> Request<B> {
+    let mut clone = Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    clone
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        path::Path,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    /// An engine accepting the requests authenticated with its secret.
+    #[derive(Debug, Clone)]
+    struct MockEngine {
+        secret: Arc<RwLock<JwtSecret>>,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl MockEngine {
+        fn new(secret: JwtSecret) -> Self {
+            Self { secret: Arc::new(RwLock::new(secret)), calls: Arc::default() }
+        }
+
+        fn rotate(&self, secret: JwtSecret) {
+            *self.secret.write().unwrap() = secret;
+        }
+    }
+
+    impl Service<Request<()>> for MockEngine {
+        type Response = Response<()>;
+        type Error = std::convert::Infallible;
+        type Future = std::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<()>) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let authorized = request
+                .headers()
+                .get(AUTHORIZATION)
+                .and_then(|header| header.to_str().ok()?.strip_prefix("Bearer "))
+                .is_some_and(|token| self.secret.read().unwrap().validate(token).is_ok());
+            let mut response = Response::new(());
+            if !authorized {
+                *response.status_mut() = StatusCode::UNAUTHORIZED;
+            }
+            std::future::ready(Ok(response))
+        }
+    }
+
+    async fn send(service: &mut JwtAuthService<MockEngine>) -> StatusCode {
+        std::future::poll_fn(|cx| service.poll_ready(cx)).await.unwrap();
+        service.call(Request::new(())).await.unwrap().status()
+    }
+
+    fn write_secret(path: &Path, secret: &JwtSecret) {
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, alloy_primitives::hex::encode(secret.as_bytes())).unwrap();
+        std::fs::rename(tmp, path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rotated_secret_is_watched() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jwt.hex");
+        let (old, new) = (JwtSecret::random(), JwtSecret::random());
+        write_secret(&path, &old);
+
+        let secret = ReloadableJwtSecret::new(old).with_path(&path);
+        let _watcher = secret.watch().unwrap().unwrap();
+        let engine = MockEngine::new(old);
+        let mut service = JwtAuthLayer::new(secret.clone()).layer(engine.clone());
+        assert_eq!(send(&mut service).await, StatusCode::OK);
+
+        engine.rotate(new);
+        write_secret(&path, &new);
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while secret.secret().as_bytes() != new.as_bytes() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("secret was not reloaded");
+
+        assert_eq!(send(&mut service).await, StatusCode::OK);
+        assert_eq!(engine.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unauthorized_call_retried_after_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jwt.hex");
+        let (old, new) = (JwtSecret::random(), JwtSecret::random());
+        write_secret(&path, &old);
+
+        let secret = ReloadableJwtSecret::new(old).with_path(&path);
+        let engine = MockEngine::new(new);
+        let mut service = JwtAuthLayer::new(secret.clone()).layer(engine.clone());
+
+        // The file still holds the rejected secret, so the call is not retried.
+        assert_eq!(send(&mut service).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(engine.calls.load(Ordering::SeqCst), 1);
+
+        write_secret(&path, &new);
+        assert_eq!(send(&mut service).await, StatusCode::OK);
+        assert_eq!(engine.calls.load(Ordering::SeqCst), 3);
+        assert_eq!(secret.secret().as_bytes(), new.as_bytes());
+    }
+
+    #[test]
+    fn test_invalid_secret_file_keeps_secret() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jwt.hex");
+        let old = JwtSecret::random();
+        let secret = ReloadableJwtSecret::new(old).with_path(&path);
+
+        // Missing file.
+        assert!(!secret.reload());
+        assert_eq!(secret.secret().as_bytes(), old.as_bytes());
+
+        // Malformed file.
+        std::fs::write(&path, "not a secret").unwrap();
+        assert!(!secret.reload());
+        assert_eq!(secret.secret().as_bytes(), old.as_bytes());
+    }
+}
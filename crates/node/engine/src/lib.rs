@@ -26,7 +26,8 @@
 //! ## Module Organization
 //!
 //! - **Task Queue** - Core engine task queue and execution logic via [`Engine`]
-//! - **Client** - HTTP client for Engine API communication via [`EngineClient`]
+//! - **Client** - HTTP client for Engine API communication via [`EngineClient`], authenticated with
+//!   a [`ReloadableJwtSecret`]
 //! - **State** - Engine state management and synchronization via [`EngineState`]
 //! - **Versions** - Engine API version selection via [`EngineForkchoiceVersion`],
 //!   [`EngineNewPayloadVersion`], [`EngineGetPayloadVersion`]
@@ -42,8 +43,8 @@ mod task_queue;
 pub use task_queue::{
     BuildTask, BuildTaskError, ConsolidateTask, ConsolidateTaskError, Engine, EngineBuildError,
     EngineResetError, EngineTask, EngineTaskError, EngineTaskErrorSeverity, EngineTaskErrors,
-    EngineTaskExt, FinalizeTask, FinalizeTaskError, InsertTask, InsertTaskError, SynchronizeTask,
-    SynchronizeTaskError,
+    EngineTaskExt, EngineTaskPriority, FinalizeTask, FinalizeTaskError, InsertTask,
+    InsertTaskError, SynchronizeTask, SynchronizeTaskError,
 };
 
 mod attributes;
@@ -52,6 +53,9 @@ pub use attributes::{AttributesMatch, AttributesMismatch};
 mod client;
 pub use client::{EngineClient, EngineClientError};
 
+mod jwt;
+pub use jwt::{JwtAuthLayer, JwtAuthService, ReloadableJwtSecret};
+
 mod versions;
 pub use versions::{EngineForkchoiceVersion, EngineGetPayloadVersion, EngineNewPayloadVersion};
 
@@ -64,5 +68,10 @@ pub use kinds::EngineKind;
 mod query;
 pub use query::{EngineQueries, EngineQueriesError, EngineQuerySender};
 
+#[cfg(feature = "dev")]
+mod dev;
+#[cfg(feature = "dev")]
+pub use dev::InvalidPayloadKind;
+
 mod metrics;
 pub use metrics::Metrics;
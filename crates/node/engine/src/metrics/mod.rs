@@ -72,6 +72,13 @@ impl Metrics {
     /// Identifier for the counter that tracks the number of times the engine has been reset.
     pub const ENGINE_RESET_COUNT: &str = "kona_node_engine_reset_count";
 
+    /// Identifier for the counter that tracks retried forkchoice updates in the synchronize task.
+    pub const SYNCHRONIZE_TASK_RETRY_COUNT: &str = "kona_node_engine_synchronize_task_retry_count";
+
+    /// Identifier for the counter that tracks derived attributes not matching the unsafe block
+    /// during consolidation, labeled by the first mismatching field.
+    pub const CONSOLIDATION_MISMATCH_COUNT: &str = "kona_node_engine_consolidation_mismatch_count";
+
     /// Initializes metrics for the engine.
     ///
     /// This does two things:
@@ -106,6 +113,20 @@ impl Metrics {
             metrics::Unit::Count,
             "Engine reset count"
         );
+
+        // Synchronize task retry counter
+        metrics::describe_counter!(
+            Self::SYNCHRONIZE_TASK_RETRY_COUNT,
+            metrics::Unit::Count,
+            "Synchronize task forkchoice update retry count"
+        );
+
+        // Consolidation mismatch counter
+        metrics::describe_counter!(
+            Self::CONSOLIDATION_MISMATCH_COUNT,
+            metrics::Unit::Count,
+            "Derived attributes not matching the unsafe block during consolidation"
+        );
     }
 
     /// Initializes metrics to `0` so they can be queried immediately by consumers of prometheus
@@ -125,5 +146,8 @@ impl Metrics {
 
         // Engine reset count
         kona_macros::set!(counter, Self::ENGINE_RESET_COUNT, 0);
+
+        // Synchronize task retry count
+        kona_macros::set!(counter, Self::SYNCHRONIZE_TASK_RETRY_COUNT, 0);
     }
 }
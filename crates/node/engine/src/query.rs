@@ -6,11 +6,15 @@
 
 use std::sync::Arc;
 
-use alloy_eips::BlockNumberOrTag;
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::B256;
 use alloy_provider::Provider;
+#[cfg(feature = "dev")]
+use alloy_rpc_types_engine::PayloadStatus;
+use alloy_rpc_types_eth::EIP1186AccountProofResponse;
 use alloy_transport::{RpcError, TransportErrorKind};
 use kona_genesis::RollupConfig;
-use kona_protocol::{L2BlockInfo, OutputRoot, Predeploys};
+use kona_protocol::{BlockInfo, L2BlockInfo, OutputRoot, Predeploys};
 use tokio::sync::oneshot::Sender;
 
 use crate::{EngineClient, EngineClientError, EngineState};
@@ -38,12 +42,36 @@ pub enum EngineQueries {
         /// Response channel for (block_info, output_root, engine_state).
         sender: Sender<(L2BlockInfo, OutputRoot, EngineState)>,
     },
+    /// Request the `L2ToL1MessagePasser` account proof at a specific block.
+    ///
+    /// The proof commits to the withdrawal storage root included in the block's output root.
+    MessagePasserProof {
+        /// The block to retrieve the proof at.
+        block: BlockId,
+        /// Response channel for the account proof, or the error that prevented its retrieval.
+        sender: Sender<Result<EIP1186AccountProofResponse, EngineQueriesError>>,
+    },
+    /// Request the L1 origin [`BlockInfo`] of the current safe head.
+    ///
+    /// Responds with `None` if derivation has not yet produced a safe head.
+    SafeHeadL1Origin(Sender<Option<BlockInfo>>),
     /// Subscribe to engine state updates via a watch channel receiver.
     StateReceiver(Sender<tokio::sync::watch::Receiver<EngineState>>),
     /// Development API: Subscribe to task queue length updates.
     QueueLengthReceiver(Sender<tokio::sync::watch::Receiver<usize>>),
     /// Development API: Get the current number of pending tasks in the queue.
     TaskQueueLength(Sender<usize>),
+    /// Development API: Submit a copy of the unsafe head with the given defect to the execution
+    /// engine.
+    ///
+    /// Responds with the [`PayloadStatus`] returned by the execution engine.
+    #[cfg(feature = "dev")]
+    InjectInvalidPayload {
+        /// The defect to introduce into the payload.
+        kind: crate::InvalidPayloadKind,
+        /// Response channel for the engine's payload status.
+        sender: Sender<Result<PayloadStatus, EngineQueriesError>>,
+    },
 }
 
 /// An error that can occur when querying the engine.
@@ -64,6 +92,16 @@ pub enum EngineQueriesError {
     /// Impossible to retrieve L2 withdrawals root from state.
     #[error("Impossible to retrieve L2 withdrawals root from state. {0}")]
     FailedToRetrieveWithdrawalsRoot(#[from] RpcError<TransportErrorKind>),
+    /// Failed to retrieve the L1 origin block of the safe head.
+    #[error("Failed to retrieve the safe head L1 origin: {0}")]
+    L1OriginRetrievalFailed(RpcError<TransportErrorKind>),
+    /// No L1 block found for the safe head's L1 origin.
+    #[error("No L1 block found for the safe head L1 origin: {0}")]
+    NoL1OriginFound(B256),
+    /// Failed to submit the invalid payload to the execution engine.
+    #[cfg(feature = "dev")]
+    #[error("Failed to submit the invalid payload: {0}")]
+    PayloadInjectionFailed(RpcError<TransportErrorKind>),
 }
 
 impl EngineQueries {
@@ -124,6 +162,34 @@ impl EngineQueries {
                     .send((output_block_info, output_root, state))
                     .map_err(|_| EngineQueriesError::OutputChannelClosed)
             }
+            Self::MessagePasserProof { block, sender } => {
+                let proof = client
+                    .get_proof(Predeploys::L2_TO_L1_MESSAGE_PASSER, Default::default())
+                    .block_id(block)
+                    .await
+                    .map_err(EngineQueriesError::FailedToRetrieveWithdrawalsRoot);
+
+                sender.send(proof).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
+            Self::SafeHeadL1Origin(sender) => {
+                let safe_head = state.sync_state.safe_head();
+
+                // An unset safe head means derivation hasn't progressed yet.
+                if safe_head == L2BlockInfo::default() {
+                    return sender.send(None).map_err(|_| EngineQueriesError::OutputChannelClosed);
+                }
+
+                let l1_origin = client
+                    .l1_provider()
+                    .get_block(safe_head.l1_origin.hash.into())
+                    .await
+                    .map_err(EngineQueriesError::L1OriginRetrievalFailed)?
+                    .ok_or(EngineQueriesError::NoL1OriginFound(safe_head.l1_origin.hash))?
+                    .into_consensus()
+                    .into();
+
+                sender.send(Some(l1_origin)).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
             Self::StateReceiver(subscription) => subscription
                 .send(state_recv.clone())
                 .map_err(|_| EngineQueriesError::OutputChannelClosed),
@@ -137,6 +203,31 @@ impl EngineQueries {
                 }
                 Ok(())
             }
+            #[cfg(feature = "dev")]
+            Self::InjectInvalidPayload { kind, sender } => {
+                let status = Self::inject_invalid_payload(client, kind).await;
+                sender.send(status).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
         }
     }
+
+    /// Submits a copy of the unsafe head with the given defect to the execution engine.
+    #[cfg(feature = "dev")]
+    async fn inject_invalid_payload(
+        client: &EngineClient,
+        kind: crate::InvalidPayloadKind,
+    ) -> Result<PayloadStatus, EngineQueriesError> {
+        let head = client
+            .l2_block_by_label(BlockNumberOrTag::Latest)
+            .await?
+            .ok_or(EngineQueriesError::NoL2BlockFound(BlockNumberOrTag::Latest))?
+            .into_consensus()
+            .map_transactions(|tx| tx.inner.inner.into_inner());
+
+        warn!(target: "engine", ?kind, number = head.header.number, "Injecting invalid payload");
+
+        crate::dev::new_payload(client, kind.envelope(head))
+            .await
+            .map_err(EngineQueriesError::PayloadInjectionFailed)
+    }
 }
@@ -4,7 +4,7 @@ use super::EngineTaskExt;
 use crate::{
     EngineClient, EngineState, EngineSyncStateUpdate, EngineTask, EngineTaskError,
     EngineTaskErrorSeverity, Metrics, SynchronizeTask, SynchronizeTaskError,
-    task_queue::EngineTaskErrors,
+    task_queue::{EngineTaskErrors, EngineTaskQueue},
 };
 use alloy_provider::Provider;
 use alloy_rpc_types_eth::Transaction;
@@ -12,16 +12,16 @@ use kona_genesis::{RollupConfig, SystemConfig};
 use kona_protocol::{BlockInfo, L2BlockInfo, OpBlockConversionError, to_system_config};
 use kona_sources::{SyncStartError, find_starting_forkchoice};
 use op_alloy_consensus::OpTxEnvelope;
-use std::{collections::BinaryHeap, sync::Arc};
+use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::watch::Sender;
 
 /// The [`Engine`] task queue.
 ///
-/// Tasks of a shared [`EngineTask`] variant are processed in FIFO order, providing synchronization
-/// guarantees for the L2 execution layer and other actors. A priority queue, ordered by
-/// [`EngineTask`]'s [`Ord`] implementation, is used to prioritize tasks executed by the
-/// [`Engine::drain`] method.
+/// Tasks of a shared [`EngineTaskPriority`](crate::EngineTaskPriority) are processed in FIFO order,
+/// providing synchronization guarantees for the L2 execution layer and other actors. A priority
+/// queue, ordered by [`EngineTask::priority`], is used to prioritize tasks executed by the
+/// [`Engine::drain`] method. Starvation of lower priority tasks is bounded.
 ///
 ///  Because tasks are executed one at a time, they are considered to be atomic operations over the
 /// [`EngineState`], and are given exclusive access to the engine state during execution.
@@ -38,7 +38,7 @@ pub struct Engine {
     /// A sender that can be used to notify the engine actor of task queue length changes.
     task_queue_length: Sender<usize>,
     /// The task queue.
-    tasks: BinaryHeap<EngineTask>,
+    tasks: EngineTaskQueue,
 }
 
 impl Engine {
@@ -48,7 +48,12 @@ impl Engine {
         state_sender: Sender<EngineState>,
         task_queue_length: Sender<usize>,
     ) -> Self {
-        Self { state: initial_state, state_sender, task_queue_length, tasks: BinaryHeap::default() }
+        Self {
+            state: initial_state,
+            state_sender,
+            task_queue_length,
+            tasks: EngineTaskQueue::default(),
+        }
     }
 
     /// Returns a reference to the inner [`EngineState`].
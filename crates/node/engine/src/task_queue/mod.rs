@@ -3,5 +3,8 @@
 mod core;
 pub use core::{Engine, EngineResetError};
 
+mod queue;
+pub(crate) use queue::EngineTaskQueue;
+
 mod tasks;
 pub use tasks::*;
@@ -0,0 +1,234 @@
+//! A priority queue of [`EngineTask`]s with per-priority FIFO ordering.
+
+use crate::{EngineTask, EngineTaskPriority};
+use std::collections::VecDeque;
+
+/// A priority queue of [`EngineTask`]s.
+///
+/// Tasks are bucketed by their [`EngineTaskPriority`]. The next task to execute is the oldest task
+/// of the highest non-empty priority, so tasks sharing a priority are executed in FIFO order.
+///
+/// To bound starvation, every time a task is executed while tasks of a lower priority are waiting,
+/// the waiting priorities are charged a skip. Once a priority has been skipped
+/// [`EngineTaskQueue::MAX_SKIPS`] times in a row, its oldest task is executed next regardless of
+/// the higher priority backlog. Finalize tasks are never promoted ahead of pending consolidations,
+/// as the block they finalize may not be safe yet.
+#[derive(Debug, Default)]
+pub(crate) struct EngineTaskQueue {
+    /// The tasks, bucketed by priority. Indexed by [`EngineTaskQueue::index`].
+    buckets: [VecDeque<EngineTask>; EngineTaskPriority::ALL.len()],
+    /// The number of consecutive times each priority has been passed over while non-empty.
+    skips: [usize; EngineTaskPriority::ALL.len()],
+}
+
+impl EngineTaskQueue {
+    /// The maximum number of consecutive times a non-empty priority may be passed over in favor
+    /// of higher priority tasks.
+    pub(crate) const MAX_SKIPS: usize = 16;
+
+    /// Returns the bucket index of the given [`EngineTaskPriority`].
+    const fn index(priority: EngineTaskPriority) -> usize {
+        priority as usize
+    }
+
+    /// Returns the bucket index of the next task to execute, if any.
+    fn next_index(&self) -> Option<usize> {
+        // Starved priorities are served first, lowest priority first.
+        let starved = (0..self.buckets.len()).find(|&i| {
+            !self.buckets[i].is_empty() && self.skips[i] >= Self::MAX_SKIPS && !self.is_blocked(i)
+        });
+
+        starved.or_else(|| (0..self.buckets.len()).rev().find(|&i| !self.buckets[i].is_empty()))
+    }
+
+    /// Returns whether the bucket at the given index must wait for a higher priority bucket to be
+    /// drained before being served.
+    fn is_blocked(&self, index: usize) -> bool {
+        index == Self::index(EngineTaskPriority::Low) &&
+            !self.buckets[Self::index(EngineTaskPriority::Normal)].is_empty()
+    }
+
+    /// Pushes a new [`EngineTask`] to the back of its priority bucket.
+    pub(crate) fn push(&mut self, task: EngineTask) {
+        self.buckets[Self::index(task.priority())].push_back(task);
+    }
+
+    /// Returns a reference to the next [`EngineTask`] to execute, without removing it.
+    pub(crate) fn peek(&self) -> Option<&EngineTask> {
+        self.next_index().and_then(|i| self.buckets[i].front())
+    }
+
+    /// Removes and returns the next [`EngineTask`] to execute.
+    pub(crate) fn pop(&mut self) -> Option<EngineTask> {
+        let index = self.next_index()?;
+
+        // Charge a skip to every waiting priority below the one being served.
+        for i in 0..index {
+            if !self.buckets[i].is_empty() {
+                self.skips[i] += 1;
+            }
+        }
+        self.skips[index] = 0;
+
+        self.buckets[index].pop_front()
+    }
+
+    /// Returns the number of tasks in the queue.
+    pub(crate) fn len(&self) -> usize {
+        self.buckets.iter().map(VecDeque::len).sum()
+    }
+
+    /// Clears the queue.
+    pub(crate) fn clear(&mut self) {
+        self.buckets.iter_mut().for_each(VecDeque::clear);
+        self.skips = Default::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConsolidateTask, EngineClient, FinalizeTask, InsertTask};
+    use alloy_primitives::{Address, B256, Bloom, Bytes, U256};
+    use alloy_rpc_types_engine::{ExecutionPayloadV1, JwtSecret};
+    use kona_genesis::RollupConfig;
+    use kona_protocol::{L2BlockInfo, OpAttributesWithParent};
+    use op_alloy_rpc_types_engine::{
+        OpExecutionPayload, OpExecutionPayloadEnvelope, OpPayloadAttributes,
+    };
+    use std::sync::Arc;
+    use url::Url;
+
+    fn test_client(cfg: Arc<RollupConfig>) -> Arc<EngineClient> {
+        Arc::new(EngineClient::new_http(
+            Url::parse("http://127.0.0.1:8551").unwrap(),
+            Url::parse("http://127.0.0.1:8545").unwrap(),
+            cfg,
+            JwtSecret::from_hex("0".repeat(64)).unwrap(),
+        ))
+    }
+
+    fn insert_task(client: Arc<EngineClient>, cfg: Arc<RollupConfig>, number: u64) -> EngineTask {
+        let payload = ExecutionPayloadV1 {
+            parent_hash: B256::ZERO,
+            fee_recipient: Address::ZERO,
+            state_root: B256::ZERO,
+            receipts_root: B256::ZERO,
+            logs_bloom: Bloom::ZERO,
+            prev_randao: B256::ZERO,
+            block_number: number,
+            gas_limit: 0,
+            gas_used: 0,
+            timestamp: number,
+            extra_data: Bytes::new(),
+            base_fee_per_gas: U256::ZERO,
+            block_hash: B256::ZERO,
+            transactions: vec![],
+        };
+        let envelope = OpExecutionPayloadEnvelope {
+            parent_beacon_block_root: None,
+            execution_payload: OpExecutionPayload::V1(payload),
+        };
+        EngineTask::Insert(Box::new(InsertTask::new(client, cfg, envelope, false)))
+    }
+
+    fn finalize_task(client: Arc<EngineClient>, cfg: Arc<RollupConfig>, number: u64) -> EngineTask {
+        EngineTask::Finalize(Box::new(FinalizeTask::new(client, cfg, number)))
+    }
+
+    fn consolidate_task(client: Arc<EngineClient>, cfg: Arc<RollupConfig>) -> EngineTask {
+        let attributes = OpAttributesWithParent::new(
+            OpPayloadAttributes::default(),
+            L2BlockInfo::default(),
+            None,
+            true,
+        );
+        EngineTask::Consolidate(Box::new(ConsolidateTask::new(client, cfg, attributes, true)))
+    }
+
+    #[test]
+    fn test_finalize_runs_before_insert_backlog() {
+        let cfg = Arc::new(RollupConfig::default());
+        let client = test_client(cfg.clone());
+        let mut queue = EngineTaskQueue::default();
+
+        for number in 0..100 {
+            queue.push(insert_task(client.clone(), cfg.clone(), number));
+        }
+        queue.push(finalize_task(client, cfg, 1));
+        assert_eq!(queue.len(), 101);
+
+        assert!(matches!(queue.peek(), Some(EngineTask::Finalize(_))));
+        assert!(matches!(queue.pop(), Some(EngineTask::Finalize(_))));
+        assert_eq!(queue.len(), 100);
+    }
+
+    #[test]
+    fn test_fifo_within_priority() {
+        let cfg = Arc::new(RollupConfig::default());
+        let client = test_client(cfg.clone());
+        let mut queue = EngineTaskQueue::default();
+
+        for number in 0..10 {
+            queue.push(finalize_task(client.clone(), cfg.clone(), number));
+        }
+
+        for number in 0..10 {
+            let Some(EngineTask::Finalize(task)) = queue.pop() else {
+                panic!("expected finalize task");
+            };
+            assert_eq!(task.block_number, number);
+        }
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn test_starvation_is_bounded() {
+        let cfg = Arc::new(RollupConfig::default());
+        let client = test_client(cfg.clone());
+        let mut queue = EngineTaskQueue::default();
+
+        queue.push(insert_task(client.clone(), cfg.clone(), 0));
+        for _ in 0..EngineTaskQueue::MAX_SKIPS * 2 {
+            queue.push(finalize_task(client.clone(), cfg.clone(), 1));
+        }
+
+        for _ in 0..EngineTaskQueue::MAX_SKIPS {
+            assert!(matches!(queue.pop(), Some(EngineTask::Finalize(_))));
+        }
+        assert!(matches!(queue.pop(), Some(EngineTask::Insert(_))));
+        assert!(matches!(queue.pop(), Some(EngineTask::Finalize(_))));
+    }
+
+    #[test]
+    fn test_finalize_runs_after_consolidate() {
+        let cfg = Arc::new(RollupConfig::default());
+        let client = test_client(cfg.clone());
+        let mut queue = EngineTaskQueue::default();
+
+        queue.push(finalize_task(client.clone(), cfg.clone(), 1));
+        queue.push(consolidate_task(client, cfg));
+
+        let drained = std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>();
+        assert!(matches!(
+            drained.as_slice(),
+            [EngineTask::Consolidate(_), EngineTask::Finalize(_)]
+        ));
+    }
+
+    #[test]
+    fn test_starved_finalize_waits_for_consolidate() {
+        let cfg = Arc::new(RollupConfig::default());
+        let client = test_client(cfg.clone());
+        let mut queue = EngineTaskQueue::default();
+
+        queue.push(finalize_task(client.clone(), cfg.clone(), 1));
+        for _ in 0..EngineTaskQueue::MAX_SKIPS * 2 {
+            queue.push(consolidate_task(client.clone(), cfg.clone()));
+        }
+
+        let drained = std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>();
+        assert_eq!(drained.len(), EngineTaskQueue::MAX_SKIPS * 2 + 1);
+        assert!(matches!(drained.last(), Some(EngineTask::Finalize(_))));
+    }
+}
@@ -8,6 +8,7 @@ use alloy_rpc_types_engine::PayloadStatusEnum;
 use alloy_transport::{RpcError, TransportErrorKind};
 use kona_protocol::FromBlockError;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::sync::mpsc;
 
@@ -45,6 +46,9 @@ pub enum EngineBuildError {
     /// The engine is syncing.
     #[error("The engine is syncing")]
     EngineSyncing,
+    /// The `engine_getPayload` call did not complete within the payload timeout.
+    #[error("Timed out after {0:?} waiting for the built payload")]
+    PayloadTimeout(Duration),
 }
 
 /// An error that occurs when running the [crate::SynchronizeTask].
@@ -109,6 +113,9 @@ impl EngineTaskError for BuildTaskError {
             Self::EngineBuildError(EngineBuildError::EngineSyncing) => {
                 EngineTaskErrorSeverity::Temporary
             }
+            Self::EngineBuildError(EngineBuildError::PayloadTimeout(_)) => {
+                EngineTaskErrorSeverity::Temporary
+            }
             Self::GetPayloadFailed(_) => EngineTaskErrorSeverity::Temporary,
             Self::HoloceneInvalidFlush => EngineTaskErrorSeverity::Flush,
             Self::DepositOnlyPayloadReattemptFailed => EngineTaskErrorSeverity::Critical,
@@ -14,10 +14,18 @@ use kona_protocol::{L2BlockInfo, OpAttributesWithParent};
 use op_alloy_provider::ext::engine::OpEngineApi;
 use op_alloy_rpc_types_engine::{OpExecutionPayload, OpExecutionPayloadEnvelope};
 use std::{
+    future::Future,
     sync::Arc,
     time::{Duration, Instant, SystemTime},
 };
-use tokio::{sync::mpsc, time::sleep};
+use tokio::{
+    sync::mpsc,
+    time::{sleep, timeout},
+};
+
+/// The lower bound of the default payload retrieval timeout, so that rollups with a block time of
+/// zero or one second still leave the execution layer time to return the payload.
+const MIN_PAYLOAD_TIMEOUT: Duration = Duration::from_secs(1);
 
 /// Task for building new blocks with automatic forkchoice synchronization.
 ///
@@ -56,6 +64,9 @@ pub struct BuildTask {
     /// An optional channel to send the built [`OpExecutionPayloadEnvelope`] to, after the block
     /// has been built, imported, and canonicalized.
     pub payload_tx: Option<mpsc::Sender<OpExecutionPayloadEnvelope>>,
+    /// The maximum amount of time to wait for the `engine_getPayload` call to complete. If not
+    /// set, defaults to half of the rollup's block time, and at least one second.
+    pub payload_timeout: Option<Duration>,
 }
 
 impl BuildTask {
@@ -67,7 +78,37 @@ impl BuildTask {
         is_attributes_derived: bool,
         payload_tx: Option<mpsc::Sender<OpExecutionPayloadEnvelope>>,
     ) -> Self {
-        Self { engine, cfg, attributes, is_attributes_derived, payload_tx }
+        Self { engine, cfg, attributes, is_attributes_derived, payload_tx, payload_timeout: None }
+    }
+
+    /// Sets the maximum amount of time to wait for the built payload to be retrieved from the
+    /// execution layer.
+    pub const fn with_payload_timeout(mut self, payload_timeout: Duration) -> Self {
+        self.payload_timeout = Some(payload_timeout);
+        self
+    }
+
+    /// Returns the payload retrieval timeout, falling back to half of the rollup's block time, and
+    /// at least one second, if none was configured.
+    pub fn payload_timeout(&self) -> Duration {
+        self.payload_timeout.unwrap_or_else(|| Self::default_payload_timeout(self.cfg.block_time))
+    }
+
+    /// Returns the default payload retrieval timeout for the given block time, in seconds.
+    fn default_payload_timeout(block_time: u64) -> Duration {
+        (Duration::from_secs(block_time) / 2).max(MIN_PAYLOAD_TIMEOUT)
+    }
+
+    /// Awaits the given payload retrieval future, failing with
+    /// [`EngineBuildError::PayloadTimeout`] if it does not complete within `payload_timeout`.
+    async fn with_payload_timeout_limit<T>(
+        payload_timeout: Duration,
+        fut: impl Future<Output = Result<T, BuildTaskError>>,
+    ) -> Result<T, BuildTaskError> {
+        timeout(payload_timeout, fut).await.map_err(|_| {
+            warn!(target: "engine_builder", ?payload_timeout, "Timed out fetching built payload");
+            BuildTaskError::EngineBuildError(EngineBuildError::PayloadTimeout(payload_timeout))
+        })?
     }
 
     /// Starts the block building process by sending an initial `engine_forkchoiceUpdate` call with
@@ -283,9 +324,11 @@ impl EngineTaskExt for BuildTask {
 
         // Fetch the payload just inserted from the EL and import it into the engine.
         let block_import_start_time = Instant::now();
-        let new_payload = self
-            .fetch_payload(&self.cfg, &self.engine, payload_id, self.attributes.clone())
-            .await?;
+        let new_payload = Self::with_payload_timeout_limit(
+            self.payload_timeout(),
+            self.fetch_payload(&self.cfg, &self.engine, payload_id, self.attributes.clone()),
+        )
+        .await?;
 
         let new_block_ref = L2BlockInfo::from_payload_and_genesis(
             new_payload.execution_payload.clone(),
@@ -318,16 +361,9 @@ impl EngineTaskExt for BuildTask {
             {
                 warn!(target: "engine_builder", error = ?e, "Re-attempting payload import with deposits only.");
                 // HOLOCENE: Re-attempt payload import with deposits only
-                match Self::new(
-                    self.engine.clone(),
-                    self.cfg.clone(),
-                    self.attributes.as_deposits_only(),
-                    self.is_attributes_derived,
-                    self.payload_tx.clone(),
-                )
-                .execute(state)
-                .await
-                {
+                let deposits_only_task =
+                    Self { attributes: self.attributes.as_deposits_only(), ..self.clone() };
+                match deposits_only_task.execute(state).await {
                     Ok(_) => {
                         info!(target: "engine_builder", "Successfully imported deposits-only payload")
                     }
@@ -364,3 +400,45 @@ impl EngineTaskExt for BuildTask {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_payload_timeout() {
+        let payload_timeout = Duration::from_secs(1);
+        let delayed_payload = async {
+            // Simulate an execution layer that hangs on `engine_getPayload`.
+            sleep(payload_timeout * 2).await;
+            Ok(())
+        };
+
+        let result = BuildTask::with_payload_timeout_limit(payload_timeout, delayed_payload).await;
+        assert!(matches!(
+            result,
+            Err(BuildTaskError::EngineBuildError(EngineBuildError::PayloadTimeout(t)))
+                if t == payload_timeout
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_payload_within_timeout() {
+        let payload_timeout = Duration::from_secs(1);
+        let payload = async {
+            sleep(payload_timeout / 2).await;
+            Ok(42)
+        };
+
+        let result = BuildTask::with_payload_timeout_limit(payload_timeout, payload).await;
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[test]
+    fn test_default_payload_timeout() {
+        assert_eq!(BuildTask::default_payload_timeout(0), MIN_PAYLOAD_TIMEOUT);
+        assert_eq!(BuildTask::default_payload_timeout(1), MIN_PAYLOAD_TIMEOUT);
+        assert_eq!(BuildTask::default_payload_timeout(2), Duration::from_secs(1));
+        assert_eq!(BuildTask::default_payload_timeout(12), Duration::from_secs(6));
+    }
+}
@@ -1,12 +1,14 @@
 //! A task to consolidate the engine state.
 
 use crate::{
-    BuildTask, ConsolidateTaskError, EngineClient, EngineState, EngineTaskExt, SynchronizeTask,
-    state::EngineSyncStateUpdate,
+    AttributesMatch, AttributesMismatch, BuildTask, ConsolidateTaskError, EngineClient,
+    EngineState, EngineTaskExt, SynchronizeTask, state::EngineSyncStateUpdate,
 };
+use alloy_rpc_types_eth::Block;
 use async_trait::async_trait;
 use kona_genesis::RollupConfig;
 use kona_protocol::{L2BlockInfo, OpAttributesWithParent};
+use op_alloy_rpc_types::Transaction;
 use std::{sync::Arc, time::Instant};
 
 /// The [`ConsolidateTask`] attempts to consolidate the engine state
@@ -52,6 +54,19 @@ impl ConsolidateTask {
         Ok(build_task.execute(state).await?)
     }
 
+    /// Verifies that the derived [`OpAttributesWithParent`] match the unsafe L2 block, returning
+    /// the first mismatching field otherwise.
+    pub fn verify_attributes(
+        cfg: &RollupConfig,
+        attributes: &OpAttributesWithParent,
+        block: &Block<Transaction>,
+    ) -> Result<(), AttributesMismatch> {
+        match AttributesMatch::check(cfg, attributes, block) {
+            AttributesMatch::Match => Ok(()),
+            AttributesMatch::Mismatch(field) => Err(field),
+        }
+    }
+
     /// Attempts consolidation on the engine state.
     pub async fn consolidate(&self, state: &mut EngineState) -> Result<(), ConsolidateTaskError> {
         let global_start = Instant::now();
@@ -76,7 +91,8 @@ impl ConsolidateTask {
         // If this is successful, the forkchoice change synchronizes.
         // Otherwise, the attributes need to be processed.
         let block_hash = block.header.hash;
-        if crate::AttributesMatch::check(&self.cfg, &self.attributes, &block).is_match() {
+        let verification = Self::verify_attributes(&self.cfg, &self.attributes, &block);
+        if verification.is_ok() {
             trace!(
                 target: "engine",
                 attributes = ?self.attributes,
@@ -152,6 +168,20 @@ impl ConsolidateTask {
         }
 
         // Otherwise, the attributes need to be processed.
+        if let Err(field) = verification {
+            kona_macros::inc!(
+                counter,
+                crate::Metrics::CONSOLIDATION_MISMATCH_COUNT,
+                "field" => field.field()
+            );
+            warn!(
+                target: "engine",
+                block_number = block_num,
+                block_hash = %block_hash,
+                mismatch = ?field,
+                "Derived attributes do not match the unsafe L2 block"
+            );
+        }
         debug!(
             target: "engine",
             attributes = ?self.attributes,
@@ -179,3 +209,48 @@ impl EngineTaskExt for ConsolidateTask {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_registry::ROLLUP_CONFIGS;
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+    fn default_attributes() -> OpAttributesWithParent {
+        OpAttributesWithParent {
+            inner: OpPayloadAttributes::default(),
+            parent: L2BlockInfo::default(),
+            derived_from: None,
+            is_last_in_span: true,
+        }
+    }
+
+    #[test]
+    fn test_verify_attributes_reports_mismatched_field() {
+        let cfg = ROLLUP_CONFIGS.get(&10).expect("rollup config should exist");
+        let attributes = default_attributes();
+        let mut block = Block::<Transaction>::default();
+        block.header.inner.timestamp = attributes.inner().payload_attributes.timestamp + 2;
+
+        let mismatch = ConsolidateTask::verify_attributes(cfg, &attributes, &block).unwrap_err();
+        assert_eq!(
+            mismatch,
+            AttributesMismatch::Timestamp(
+                attributes.inner().payload_attributes.timestamp,
+                block.header.inner.timestamp
+            )
+        );
+    }
+
+    #[test]
+    fn test_verify_attributes_reports_gas_limit_mismatch() {
+        let cfg = ROLLUP_CONFIGS.get(&10).expect("rollup config should exist");
+        let mut attributes = default_attributes();
+        attributes.inner.gas_limit = Some(30_000_000);
+        let mut block = Block::<Transaction>::default();
+        block.header.inner.gas_limit = 60_000_000;
+
+        let mismatch = ConsolidateTask::verify_attributes(cfg, &attributes, &block).unwrap_err();
+        assert_eq!(mismatch, AttributesMismatch::GasLimit(30_000_000, 60_000_000));
+    }
+}
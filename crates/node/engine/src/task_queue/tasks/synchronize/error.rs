@@ -22,6 +22,25 @@ pub enum SynchronizeTaskError {
     UnexpectedPayloadStatus(PayloadStatusEnum),
 }
 
+impl SynchronizeTaskError {
+    /// Returns `true` if the error is transient and the forkchoice update may be retried.
+    ///
+    /// Transport-level failures, such as request timeouts or dropped connections, are retryable.
+    /// JSON-RPC error responses, invalid forkchoice states and unexpected payload statuses (e.g.
+    /// `INVALID`) are terminal, as re-sending the same forkchoice update will not change the
+    /// outcome.
+    pub const fn is_retryable(&self) -> bool {
+        match self {
+            Self::ForkchoiceUpdateFailed(err) => {
+                matches!(err, RpcError::Transport(_) | RpcError::NullResp)
+            }
+            Self::FinalizedAheadOfUnsafe(_, _) |
+            Self::InvalidForkchoiceState |
+            Self::UnexpectedPayloadStatus(_) => false,
+        }
+    }
+}
+
 impl EngineTaskError for SynchronizeTaskError {
     fn severity(&self) -> EngineTaskErrorSeverity {
         match self {
@@ -32,3 +51,37 @@ impl EngineTaskError for SynchronizeTaskError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transport_errors_are_retryable() {
+        let err = SynchronizeTaskError::ForkchoiceUpdateFailed(TransportErrorKind::custom_str(
+            "request timed out",
+        ));
+        assert!(err.is_retryable());
+
+        let err = SynchronizeTaskError::ForkchoiceUpdateFailed(TransportErrorKind::backend_gone());
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn test_error_responses_are_terminal() {
+        let payload =
+            serde_json::from_str(r#"{"code":-32603,"message":"Internal error"}"#).unwrap();
+        let err = SynchronizeTaskError::ForkchoiceUpdateFailed(RpcError::ErrorResp(payload));
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_invalid_statuses_are_terminal() {
+        let err = SynchronizeTaskError::UnexpectedPayloadStatus(PayloadStatusEnum::Invalid {
+            validation_error: "invalid block".to_string(),
+        });
+        assert!(!err.is_retryable());
+        assert!(!SynchronizeTaskError::InvalidForkchoiceState.is_retryable());
+        assert!(!SynchronizeTaskError::FinalizedAheadOfUnsafe(1, 2).is_retryable());
+    }
+}
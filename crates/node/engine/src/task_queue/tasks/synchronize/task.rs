@@ -4,10 +4,11 @@ use crate::{
     EngineClient, EngineState, EngineTaskExt, SynchronizeTaskError, state::EngineSyncStateUpdate,
 };
 use alloy_rpc_types_engine::{INVALID_FORK_CHOICE_STATE_ERROR, PayloadStatusEnum};
+use alloy_transport::{RpcError, TransportErrorKind};
 use async_trait::async_trait;
 use kona_genesis::RollupConfig;
 use op_alloy_provider::ext::engine::OpEngineApi;
-use std::sync::Arc;
+use std::{future::Future, sync::Arc, time::Duration};
 use tokio::time::Instant;
 
 /// Internal task for execution layer forkchoice synchronization.
@@ -45,6 +46,14 @@ pub struct SynchronizeTask {
 }
 
 impl SynchronizeTask {
+    /// The maximum number of times a forkchoice update that failed with a retryable
+    /// [`SynchronizeTaskError`] is re-attempted.
+    pub const MAX_RETRIES: u32 = 3;
+
+    /// The initial backoff between forkchoice update retries. The backoff is doubled after each
+    /// failed attempt.
+    pub const RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
     /// Creates a new [`SynchronizeTask`].
     pub const fn new(
         client: Arc<EngineClient>,
@@ -54,6 +63,48 @@ impl SynchronizeTask {
         Self { client, rollup, state_update }
     }
 
+    /// Runs the given fallible operation, retrying it with exponential backoff while it fails with
+    /// a retryable [`SynchronizeTaskError`], up to [`Self::MAX_RETRIES`] times. Terminal errors
+    /// are returned immediately.
+    async fn with_retries<T, F, Fut>(mut op: F) -> Result<T, SynchronizeTaskError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, SynchronizeTaskError>>,
+    {
+        let mut backoff = Self::RETRY_BACKOFF;
+        let mut attempt = 0;
+        loop {
+            match op().await {
+                Err(e) if e.is_retryable() && attempt < Self::MAX_RETRIES => {
+                    attempt += 1;
+                    kona_macros::inc!(counter, crate::Metrics::SYNCHRONIZE_TASK_RETRY_COUNT);
+                    debug!(
+                        target: "engine",
+                        attempt,
+                        ?backoff,
+                        "Retrying forkchoice update after transient error: {e}"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Maps an error of the `engine_forkchoiceUpdated` call to a [`SynchronizeTaskError`].
+    ///
+    /// An invalid forkchoice state error response is fatal, and maps to
+    /// [`SynchronizeTaskError::InvalidForkchoiceState`].
+    fn forkchoice_updated_error(err: RpcError<TransportErrorKind>) -> SynchronizeTaskError {
+        err.as_error_resp()
+            .and_then(|e| {
+                (e.code == INVALID_FORK_CHOICE_STATE_ERROR as i64)
+                    .then_some(SynchronizeTaskError::InvalidForkchoiceState)
+            })
+            .unwrap_or_else(|| SynchronizeTaskError::ForkchoiceUpdateFailed(err))
+    }
+
     /// Checks the response of the `engine_forkchoiceUpdated` call, and updates the sync status if
     /// necessary.
     fn check_forkchoice_updated_status(
@@ -129,17 +180,16 @@ impl EngineTaskExt for SynchronizeTask {
         // NOTE: it doesn't matter which version we use here, because we're not sending any
         // payload attributes. The forkchoice updated call is version agnostic if no payload
         // attributes are provided.
-        let response = self.client.fork_choice_updated_v3(forkchoice, None).await;
-
-        let valid_response = response.map_err(|e| {
-            // Fatal forkchoice update error.
-            e.as_error_resp()
-                .and_then(|e| {
-                    (e.code == INVALID_FORK_CHOICE_STATE_ERROR as i64)
-                        .then_some(SynchronizeTaskError::InvalidForkchoiceState)
-                })
-                .unwrap_or_else(|| SynchronizeTaskError::ForkchoiceUpdateFailed(e))
-        })?;
+        //
+        // Transient failures (e.g. request timeouts) are retried with backoff, while terminal
+        // failures are returned immediately.
+        let valid_response = Self::with_retries(|| async move {
+            self.client
+                .fork_choice_updated_v3(forkchoice, None)
+                .await
+                .map_err(Self::forkchoice_updated_error)
+        })
+        .await?;
 
         self.check_forkchoice_updated_status(state, &valid_response.payload_status.status)?;
 
@@ -158,3 +208,87 @@ impl EngineTaskExt for SynchronizeTask {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_transient_timeout() {
+        let mut attempts = 0;
+        let result = SynchronizeTask::with_retries(|| {
+            attempts += 1;
+            let attempt = attempts;
+            async move {
+                if attempt == 1 {
+                    Err(SynchronizeTaskError::ForkchoiceUpdateFailed(
+                        TransportErrorKind::custom_str("request timed out"),
+                    ))
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(attempts, 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_are_bounded() {
+        let mut attempts = 0;
+        let result: Result<(), _> = SynchronizeTask::with_retries(|| {
+            attempts += 1;
+            async {
+                Err(SynchronizeTaskError::ForkchoiceUpdateFailed(TransportErrorKind::custom_str(
+                    "request timed out",
+                )))
+            }
+        })
+        .await;
+
+        assert!(matches!(result, Err(SynchronizeTaskError::ForkchoiceUpdateFailed(_))));
+        assert_eq!(attempts, SynchronizeTask::MAX_RETRIES + 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_does_not_retry_invalid_forkchoice_state() {
+        let mut attempts = 0;
+        let result: Result<(), _> = SynchronizeTask::with_retries(|| {
+            attempts += 1;
+            async {
+                let payload = serde_json::from_value(serde_json::json!({
+                    "code": INVALID_FORK_CHOICE_STATE_ERROR,
+                    "message": "Invalid forkchoice state",
+                }))
+                .unwrap();
+                Err(SynchronizeTask::forkchoice_updated_error(RpcError::ErrorResp(payload)))
+            }
+        })
+        .await;
+
+        assert!(matches!(result, Err(SynchronizeTaskError::InvalidForkchoiceState)));
+        assert_eq!(attempts, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_does_not_retry_error_response() {
+        let mut attempts = 0;
+        let result: Result<(), _> = SynchronizeTask::with_retries(|| {
+            attempts += 1;
+            async {
+                let payload =
+                    serde_json::from_str(r#"{"code":-32603,"message":"Internal error"}"#).unwrap();
+                Err(SynchronizeTask::forkchoice_updated_error(RpcError::ErrorResp(payload)))
+            }
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(SynchronizeTaskError::ForkchoiceUpdateFailed(RpcError::ErrorResp(_)))
+        ));
+        assert_eq!(attempts, 1);
+    }
+}
@@ -8,7 +8,6 @@ use crate::{
 };
 use async_trait::async_trait;
 use derive_more::Display;
-use std::cmp::Ordering;
 use thiserror::Error;
 
 /// The severity of an engine task error.
@@ -30,6 +29,34 @@ pub enum EngineTaskErrorSeverity {
     Flush,
 }
 
+/// The priority of an [`EngineTask`] within the [`Engine`]'s task queue.
+///
+/// Tasks with a higher priority are executed before tasks with a lower priority. Tasks sharing the
+/// same priority are executed in FIFO order.
+///
+/// [`Engine`]: crate::Engine
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display, Clone, Copy)]
+pub enum EngineTaskPriority {
+    /// Import of unsafe blocks received over gossip.
+    #[display("lowest")]
+    Lowest,
+    /// Finalization, which must never lag behind a backlog of unsafe block imports, but must
+    /// never run ahead of the consolidation of the block it finalizes.
+    #[display("low")]
+    Low,
+    /// Consolidation of the safe chain via derivation.
+    #[display("normal")]
+    Normal,
+    /// Block building, prioritized above all other tasks to give priority to the sequencer.
+    #[display("critical")]
+    Critical,
+}
+
+impl EngineTaskPriority {
+    /// All priorities, ordered from lowest to highest.
+    pub const ALL: [Self; 4] = [Self::Lowest, Self::Low, Self::Normal, Self::Critical];
+}
+
 /// The interface for an engine task error.
 ///
 /// An engine task error should have an associated severity level to specify how to handle the error
@@ -109,6 +136,26 @@ impl EngineTask {
         Ok(())
     }
 
+    /// Returns the [`EngineTaskPriority`] of the task.
+    ///
+    /// <https://specs.optimism.io/protocol/derivation.html#forkchoice-synchronization>
+    ///
+    /// - Block building jobs are prioritized above all other tasks, to give priority to the
+    ///   sequencer. BuildTask handles forkchoice updates automatically.
+    /// - Consolidate tasks are prioritized over Finalize tasks, since a block must be consolidated
+    ///   into the safe chain before it can be finalized.
+    /// - Finalize tasks are prioritized over InsertUnsafe tasks, so that finality never lags behind
+    ///   a backlog of unsafe block imports.
+    /// - InsertUnsafe tasks have the lowest priority.
+    pub const fn priority(&self) -> EngineTaskPriority {
+        match self {
+            Self::Build(_) => EngineTaskPriority::Critical,
+            Self::Consolidate(_) => EngineTaskPriority::Normal,
+            Self::Finalize(_) => EngineTaskPriority::Low,
+            Self::Insert(_) => EngineTaskPriority::Lowest,
+        }
+    }
+
     const fn task_metrics_label(&self) -> &'static str {
         match self {
             Self::Insert(_) => crate::Metrics::INSERT_TASK_LABEL,
@@ -133,47 +180,6 @@ impl PartialEq for EngineTask {
 
 impl Eq for EngineTask {}
 
-impl PartialOrd for EngineTask {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for EngineTask {
-    fn cmp(&self, other: &Self) -> Ordering {
-        // Order (descending): BuildBlock -> InsertUnsafe -> Consolidate -> Finalize
-        //
-        // https://specs.optimism.io/protocol/derivation.html#forkchoice-synchronization
-        //
-        // - Block building jobs are prioritized above all other tasks, to give priority to the
-        //   sequencer. BuildTask handles forkchoice updates automatically.
-        // - InsertUnsafe tasks are prioritized over Consolidate tasks, to ensure that unsafe block
-        //   gossip is imported promptly.
-        // - Consolidate tasks are prioritized over Finalize tasks, as they advance the safe chain
-        //   via derivation.
-        // - Finalize tasks have the lowest priority, as they only update finalized status.
-        match (self, other) {
-            // Same variant cases
-            (Self::Insert(_), Self::Insert(_)) => Ordering::Equal,
-            (Self::Consolidate(_), Self::Consolidate(_)) => Ordering::Equal,
-            (Self::Build(_), Self::Build(_)) => Ordering::Equal,
-            (Self::Finalize(_), Self::Finalize(_)) => Ordering::Equal,
-
-            // BuildBlock tasks are prioritized over InsertUnsafe and Consolidate tasks
-            (Self::Build(_), _) => Ordering::Greater,
-            (_, Self::Build(_)) => Ordering::Less,
-
-            // InsertUnsafe tasks are prioritized over Consolidate and Finalize tasks
-            (Self::Insert(_), _) => Ordering::Greater,
-            (_, Self::Insert(_)) => Ordering::Less,
-
-            // Consolidate tasks are prioritized over Finalize tasks
-            (Self::Consolidate(_), _) => Ordering::Greater,
-            (_, Self::Consolidate(_)) => Ordering::Less,
-        }
-    }
-}
-
 #[async_trait]
 impl EngineTaskExt for EngineTask {
     type Output = ();
@@ -78,6 +78,17 @@ pub enum BlockInvalidError {
     },
 }
 
+impl BlockInvalidError {
+    /// Returns whether the error proves the block was crafted or tampered with, in which case
+    /// the peer propagating it is misbehaving.
+    ///
+    /// Other errors, such as a stale timestamp or too many blocks for a height, may be caused by
+    /// benign network conditions, and are left to peer scoring.
+    pub const fn is_malicious(&self) -> bool {
+        matches!(self, Self::Signature | Self::BlockHash { .. })
+    }
+}
+
 impl From<BlockInvalidError> for MessageAcceptance {
     fn from(value: BlockInvalidError) -> Self {
         // We only want to ignore blocks that we have already seen.
@@ -488,7 +499,10 @@ pub(crate) mod tests {
             unsafe_signer,
         );
 
-        assert!(matches!(handler.block_valid(&envelope), Err(BlockInvalidError::Timestamp { .. })));
+        let err = handler.block_valid(&envelope).unwrap_err();
+        assert!(matches!(err, BlockInvalidError::Timestamp { .. }));
+        // a stale block may just be late, so its source isn't banned
+        assert!(!err.is_malicious());
     }
 
     /// Generates a random block with an invalid timestamp and ensure it is rejected
@@ -656,7 +670,9 @@ pub(crate) mod tests {
         envelope.signature = Signature::from_raw_array(&signature_bytes).unwrap();
 
         assert!(handler.seen_hashes.is_empty());
-        assert!(matches!(handler.block_valid(&envelope), Err(BlockInvalidError::Signature)));
+        let err = handler.block_valid(&envelope).unwrap_err();
+        assert!(matches!(err, BlockInvalidError::Signature));
+        assert!(err.is_malicious());
     }
 
     /// Blocks with invalid signers should be rejected.
@@ -22,8 +22,8 @@ use std::{
 use tokio::sync::Mutex;
 
 use crate::{
-    Behaviour, BlockHandler, ConnectionGate, ConnectionGater, Event, GossipDriverBuilder, Handler,
-    PublishError,
+    BanReason, Behaviour, BlockHandler, ConnectionGate, ConnectionGater, Event,
+    GossipDriverBuilder, Handler, PublishError,
 };
 
 /// A driver for a [`Swarm`] instance.
@@ -57,6 +57,11 @@ pub struct GossipDriver<G: ConnectionGate> {
     pub peer_monitoring: Option<PeerMonitoring>,
     /// Tracks connection start time for peers
     pub peer_connection_start: HashMap<PeerId, Instant>,
+    /// Gossip score penalties forgiven when a peer was unbanned.
+    ///
+    /// The forgiven penalty is offset through the peer's application-specific score, and shrinks
+    /// as the underlying penalty decays. See [`GossipDriver::forgive_peer_score`].
+    pub forgiven_penalties: HashMap<PeerId, f64>,
     /// The connection gate.
     pub connection_gate: G,
     /// Tracks ping times for peers.
@@ -93,6 +98,7 @@ where
             peerstore: Default::default(),
             peer_monitoring: None,
             peer_connection_start: Default::default(),
+            forgiven_penalties: Default::default(),
             sync_handler,
             sync_protocol: Some(sync_protocol),
             connection_gate: gate,
@@ -128,6 +134,41 @@ where
         Ok(Some(id))
     }
 
+    /// Forgives the gossip score penalty a peer has accrued so far.
+    ///
+    /// Gossipsub retains the score of a disconnected peer, so a peer unbanned with a negative
+    /// score would be banned again on the next peer score inspection. The penalty is offset
+    /// through the application-specific score (weighted `1.0` by every
+    /// [`kona_peers::PeerScoreLevel`]), bringing the peer back to a neutral score. Penalties
+    /// accrued afterwards still count.
+    pub fn forgive_peer_score(&mut self, peer_id: &PeerId) {
+        let gossipsub = &mut self.swarm.behaviour_mut().gossipsub;
+        let Some(score) = gossipsub.peer_score(peer_id) else {
+            return;
+        };
+        let forgiven = self.forgiven_penalties.remove(peer_id).unwrap_or_default();
+        let penalty = (forgiven - score).max(0.0);
+        if gossipsub.set_application_score(peer_id, penalty) && penalty > 0.0 {
+            self.forgiven_penalties.insert(*peer_id, penalty);
+        }
+    }
+
+    /// Shrinks the forgiven penalties as the underlying gossip score penalties decay.
+    ///
+    /// A forgiven penalty never grows, so it cannot offset penalties accrued after the peer
+    /// was unbanned. Must be called before inspecting peer scores.
+    pub fn decay_forgiven_penalties(&mut self) {
+        let gossipsub = &mut self.swarm.behaviour_mut().gossipsub;
+        self.forgiven_penalties.retain(|peer_id, forgiven| {
+            let Some(score) = gossipsub.peer_score(peer_id) else {
+                return false;
+            };
+            let penalty = (*forgiven - score).clamp(0.0, *forgiven);
+            *forgiven = penalty;
+            gossipsub.set_application_score(peer_id, penalty) && penalty > 0.0
+        });
+    }
+
     /// Handles the sync request/response protocol.
     ///
     /// This is a mock handler that supports the `payload_by_number` protocol.
@@ -345,6 +386,26 @@ where
         }
     }
 
+    /// Bans a peer that propagated a provably malicious block, if peer banning is enabled.
+    ///
+    /// Blocks rejected for benign reasons only lower the peer's gossipsub score.
+    fn ban_invalid_block_source(&mut self, peer_id: PeerId) {
+        let Some(ban_duration) = self.peer_monitoring.as_ref().map(|m| m.ban_duration) else {
+            return;
+        };
+
+        // Protected peers are exempt from bans.
+        if self.connection_gate.list_protected_peers().contains(&peer_id) {
+            return;
+        }
+
+        warn!(target: "gossip", peer = ?peer_id, "Banning peer for propagating an invalid block");
+        self.connection_gate.ban_peer(&peer_id, BanReason::InvalidBlock, Some(ban_duration));
+        if self.swarm.disconnect_peer_id(peer_id).is_err() {
+            debug!(target: "gossip", peer = ?peer_id, "Banned peer was not connected");
+        }
+    }
+
     /// Handles a [`libp2p::gossipsub::Event`].
     fn handle_gossipsub_event(
         &mut self,
@@ -359,12 +420,15 @@ where
                 trace!(target: "gossip", "Received message with topic: {}", message.topic);
                 kona_macros::inc!(gauge, crate::Metrics::GOSSIP_EVENT, "type" => "message", "topic" => message.topic.to_string());
                 if self.handler.topics().contains(&message.topic) {
-                    let (status, payload) = self.handler.handle(message);
+                    let (status, payload, malicious) = self.handler.handle(message);
                     _ = self
                         .swarm
                         .behaviour_mut()
                         .gossipsub
                         .report_message_validation_result(&id, &src, status);
+                    if malicious {
+                        self.ban_invalid_block_source(src);
+                    }
                     return payload;
                 }
             }
@@ -485,3 +549,101 @@ where
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{B256, Signature};
+    use alloy_rpc_types_engine::ExecutionPayloadV2;
+    use libp2p::gossipsub::Message;
+    use op_alloy_rpc_types_engine::{OpExecutionPayload, PayloadHash};
+
+    fn monitored_driver() -> GossipDriver<ConnectionGater> {
+        let (driver, _) = GossipDriver::<ConnectionGater>::builder(
+            RollupConfig::default(),
+            Address::ZERO,
+            "/ip4/127.0.0.1/tcp/0".parse().unwrap(),
+            Keypair::generate_secp256k1(),
+        )
+        .with_peer_monitoring(Some(PeerMonitoring {
+            ban_threshold: -100.0,
+            ban_duration: Duration::from_secs(60),
+        }))
+        .build()
+        .unwrap();
+        driver
+    }
+
+    fn block_message(
+        driver: &GossipDriver<ConnectionGater>,
+        source: PeerId,
+        payload: ExecutionPayloadV2,
+    ) -> libp2p::gossipsub::Event {
+        let envelope = OpNetworkPayloadEnvelope {
+            payload: OpExecutionPayload::V2(payload),
+            signature: Signature::test_signature(),
+            payload_hash: PayloadHash(B256::ZERO),
+            parent_beacon_block_root: None,
+        };
+        let topic = driver.handler.blocks_v2_topic.clone();
+        libp2p::gossipsub::Event::Message {
+            propagation_source: source,
+            message_id: MessageId::new(b"block"),
+            message: Message {
+                source: None,
+                data: driver.handler.encode(topic.clone(), envelope).unwrap(),
+                sequence_number: None,
+                topic: topic.hash(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_block_bans_source() {
+        let mut driver = monitored_driver();
+
+        // A block whose header does not hash to the advertised block hash can only have been
+        // forged, so the propagating peer is banned.
+        let mut payload = ExecutionPayloadV2::from_block_slow(&crate::v2_valid_block());
+        payload.payload_inner.block_hash = B256::ZERO;
+
+        let source = PeerId::random();
+        let event = block_message(&driver, source, payload);
+        assert!(driver.handle_gossipsub_event(event).is_none());
+
+        let banned = driver.connection_gate.list_banned_peers();
+        assert_eq!(banned.len(), 1);
+        assert_eq!(banned[0].peer_id, source.to_string());
+        assert_eq!(banned[0].reason, BanReason::InvalidBlock);
+        assert!(banned[0].expiry.is_some());
+        assert!(driver.connection_gate.list_blocked_peers().contains(&source));
+    }
+
+    #[tokio::test]
+    async fn test_benign_reject_does_not_ban_source() {
+        let mut driver = monitored_driver();
+
+        // A stale block is rejected, but honest peers relay those around reorgs and clock skew.
+        let mut block = crate::v2_valid_block();
+        block.header.timestamp -= 120;
+        let payload = ExecutionPayloadV2::from_block_slow(&block);
+
+        let event = block_message(&driver, PeerId::random(), payload);
+        assert!(driver.handle_gossipsub_event(event).is_none());
+
+        // Undecodable data is rejected without a ban as well.
+        let event = libp2p::gossipsub::Event::Message {
+            propagation_source: PeerId::random(),
+            message_id: MessageId::new(b"garbage"),
+            message: Message {
+                source: None,
+                data: vec![0xFF; 32],
+                sequence_number: None,
+                topic: driver.handler.blocks_v3_topic.hash(),
+            },
+        };
+        assert!(driver.handle_gossipsub_event(event).is_none());
+
+        assert!(driver.connection_gate.list_banned_peers().is_empty());
+    }
+}
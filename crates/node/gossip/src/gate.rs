@@ -1,9 +1,9 @@
 //! Connection Gate for the libp2p Gossip Swarm.
 
-use crate::{Connectedness, DialError};
+use crate::{BanReason, BannedPeer, Connectedness, DialError};
 use ipnet::IpNet;
 use libp2p::{Multiaddr, PeerId};
-use std::net::IpAddr;
+use std::{net::IpAddr, time::Duration};
 
 /// Connection Gate
 ///
@@ -42,6 +42,19 @@ pub trait ConnectionGate {
     /// Lists the blocked peers.
     fn list_blocked_peers(&self) -> Vec<PeerId>;
 
+    /// Bans a given peer id for the given [`BanReason`].
+    ///
+    /// If a duration is given, the ban is lifted once it has elapsed.
+    fn ban_peer(&mut self, peer_id: &PeerId, reason: BanReason, duration: Option<Duration>);
+
+    /// Lists the banned peers, along with the reason and expiry of their ban.
+    fn list_banned_peers(&self) -> Vec<BannedPeer>;
+
+    /// Lifts the ban of a given peer id, without dialing it.
+    ///
+    /// Returns whether the peer was banned.
+    fn unban_peer(&mut self, peer_id: &PeerId) -> bool;
+
     /// Blocks a given ip address from connecting to the gossip swarm.
     fn block_addr(&mut self, ip: IpAddr);
 
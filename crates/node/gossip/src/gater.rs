@@ -1,12 +1,12 @@
 //! An implementation of the [`ConnectionGate`] trait.
 
-use crate::{Connectedness, ConnectionGate, DialError};
+use crate::{BanReason, BannedPeer, Connectedness, ConnectionGate, DialError};
 use ipnet::IpNet;
 use libp2p::{Multiaddr, PeerId};
 use std::{
     collections::{HashMap, HashSet},
     net::IpAddr,
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use tokio::time::Instant;
 
@@ -77,6 +77,8 @@ pub struct ConnectionGater {
     pub protected_peers: HashSet<PeerId>,
     /// A set of blocked peer ids.
     pub blocked_peers: HashSet<PeerId>,
+    /// A mapping from blocked peer id to the reason and expiry of its ban.
+    pub banned_peers: HashMap<PeerId, BannedPeer>,
     /// A set of blocked ip addresses that cannot be dialed.
     pub blocked_addrs: HashSet<IpAddr>,
     /// A set of blocked subnets that cannot be connected to.
@@ -93,6 +95,7 @@ impl ConnectionGater {
             connectedness: HashMap::new(),
             protected_peers: HashSet::new(),
             blocked_peers: HashSet::new(),
+            banned_peers: HashMap::new(),
             blocked_addrs: HashSet::new(),
             blocked_subnets: HashSet::new(),
         }
@@ -142,6 +145,19 @@ impl ConnectionGater {
         })
     }
 
+    /// Returns the current unix timestamp in seconds.
+    fn unix_now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+    }
+
+    /// Returns if the ban of the given [`PeerId`] has expired.
+    fn ban_expired(&self, peer_id: &PeerId) -> bool {
+        self.banned_peers
+            .get(peer_id)
+            .and_then(|ban| ban.expiry)
+            .is_some_and(|expiry| expiry <= Self::unix_now())
+    }
+
     /// Checks if a given [`IpAddr`] is within any of the `blocked_subnets`.
     pub fn check_ip_in_blocked_subnets(&self, ip_addr: &IpAddr) -> bool {
         for subnet in &self.blocked_subnets {
@@ -181,6 +197,12 @@ impl ConnectionGate for ConnectionGater {
             return Err(DialError::ThresholdReached { addr: addr.clone() });
         }
 
+        // If the ban of the peer has expired, lift it.
+        if self.ban_expired(&peer_id) {
+            debug!(target: "gossip", peer=?addr, "Peer ban expired, unblocking");
+            self.unblock_peer(&peer_id);
+        }
+
         // If the peer is blocked, do not dial.
         if self.blocked_peers.contains(&peer_id) {
             debug!(target: "gossip", peer=?addr, "Peer is blocked, not dialing");
@@ -265,12 +287,17 @@ impl ConnectionGate for ConnectionGater {
 
     fn block_peer(&mut self, peer_id: &PeerId) {
         self.blocked_peers.insert(*peer_id);
+        self.banned_peers.insert(
+            *peer_id,
+            BannedPeer { peer_id: peer_id.to_string(), reason: BanReason::Manual, expiry: None },
+        );
         debug!(target: "gossip", peer=?peer_id, "Blocked peer");
         self.connectedness.insert(*peer_id, Connectedness::CannotConnect);
     }
 
     fn unblock_peer(&mut self, peer_id: &PeerId) {
         self.blocked_peers.remove(peer_id);
+        self.banned_peers.remove(peer_id);
         debug!(target: "gossip", peer=?peer_id, "Unblocked peer");
         self.connectedness.insert(*peer_id, Connectedness::NotConnected);
     }
@@ -279,6 +306,30 @@ impl ConnectionGate for ConnectionGater {
         self.blocked_peers.iter().copied().collect()
     }
 
+    fn ban_peer(&mut self, peer_id: &PeerId, reason: BanReason, duration: Option<Duration>) {
+        self.block_peer(peer_id);
+        let expiry = duration.map(|duration| Self::unix_now().saturating_add(duration.as_secs()));
+        debug!(target: "gossip", peer=?peer_id, %reason, ?expiry, "Banned peer");
+        self.banned_peers
+            .insert(*peer_id, BannedPeer { peer_id: peer_id.to_string(), reason, expiry });
+    }
+
+    fn list_banned_peers(&self) -> Vec<BannedPeer> {
+        self.banned_peers
+            .iter()
+            .filter(|(peer_id, _)| !self.ban_expired(peer_id))
+            .map(|(_, ban)| ban.clone())
+            .collect()
+    }
+
+    fn unban_peer(&mut self, peer_id: &PeerId) -> bool {
+        let banned = self.blocked_peers.contains(peer_id);
+        if banned {
+            self.unblock_peer(peer_id);
+        }
+        banned
+    }
+
     fn block_addr(&mut self, ip: IpAddr) {
         self.blocked_addrs.insert(ip);
         debug!(target: "gossip", ?ip, "Blocked ip address");
@@ -369,3 +420,62 @@ fn test_dial_error_handling() {
     let result = gater.can_dial(&valid_addr);
     assert!(matches!(result, Err(DialError::AlreadyDialing { .. })));
 }
+
+#[test]
+fn test_ban_peer_reason_and_expiry() {
+    use crate::{BanReason, ConnectionGate, DialError};
+    use std::str::FromStr;
+
+    let mut gater = ConnectionGater::new(GaterConfig::default());
+    let addr = Multiaddr::from_str(
+        "/ip4/127.0.0.1/tcp/8080/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+    )
+    .unwrap();
+    let peer_id = ConnectionGater::peer_id_from_addr(&addr).unwrap();
+
+    // A manual block does not expire.
+    gater.block_peer(&peer_id);
+    let banned = gater.list_banned_peers();
+    assert_eq!(banned.len(), 1);
+    assert_eq!(banned[0].reason, BanReason::Manual);
+    assert_eq!(banned[0].expiry, None);
+
+    // A timed ban overrides the reason and records its expiry.
+    gater.ban_peer(&peer_id, BanReason::GossipScore, Some(Duration::from_secs(60)));
+    let banned = gater.list_banned_peers();
+    assert_eq!(banned[0].reason, BanReason::GossipScore);
+    assert!(banned[0].expiry.unwrap() > ConnectionGater::unix_now());
+    assert!(matches!(gater.can_dial(&addr), Err(DialError::PeerBlocked { .. })));
+
+    // An expired ban is not listed, and is lifted when dialing.
+    gater.ban_peer(&peer_id, BanReason::InvalidBlock, Some(Duration::ZERO));
+    assert!(gater.list_banned_peers().is_empty());
+    assert!(gater.can_dial(&addr).is_ok());
+    assert!(gater.list_blocked_peers().is_empty());
+}
+
+#[test]
+fn test_unban_peer() {
+    use crate::{BanReason, ConnectionGate};
+    use std::str::FromStr;
+
+    let mut gater = ConnectionGater::new(GaterConfig::default());
+    let addr = Multiaddr::from_str(
+        "/ip4/127.0.0.1/tcp/8080/p2p/12D3KooWEyoppNCUx8Yx66oV9fJnriXwCcXwDDUA2kj6vnc6iDEp",
+    )
+    .unwrap();
+    let peer_id = ConnectionGater::peer_id_from_addr(&addr).unwrap();
+
+    gater.ban_peer(&peer_id, BanReason::InvalidBlock, Some(Duration::from_secs(60)));
+    assert!(gater.can_dial(&addr).is_err());
+
+    // Unbanning lifts the ban without dialing the peer.
+    assert!(gater.unban_peer(&peer_id));
+    assert!(gater.list_banned_peers().is_empty());
+    assert!(!gater.current_dials.contains(&peer_id));
+    assert_eq!(gater.connectedness(&peer_id), Connectedness::NotConnected);
+    assert!(gater.can_dial(&addr).is_ok());
+
+    // The peer is no longer banned.
+    assert!(!gater.unban_peer(&peer_id));
+}
@@ -16,7 +16,13 @@ use tokio::sync::watch::Receiver;
 pub trait Handler: Send {
     /// Manages validation and further processing of messages
     /// This is a stateful method, because the handler needs to keep track of seen hashes.
-    fn handle(&mut self, msg: Message) -> (MessageAcceptance, Option<OpNetworkPayloadEnvelope>);
+    ///
+    /// Along with the acceptance and the decoded payload, returns whether a rejected message
+    /// proves that the peer propagating it is malicious.
+    fn handle(
+        &mut self,
+        msg: Message,
+    ) -> (MessageAcceptance, Option<OpNetworkPayloadEnvelope>, bool);
 
     /// Specifies which topics the handler is interested in
     fn topics(&self) -> Vec<TopicHash>;
@@ -45,7 +51,10 @@ pub struct BlockHandler {
 impl Handler for BlockHandler {
     /// Checks validity of a [`OpNetworkPayloadEnvelope`] received over P2P gossip.
     /// If valid, sends the [`OpNetworkPayloadEnvelope`] to the block update channel.
-    fn handle(&mut self, msg: Message) -> (MessageAcceptance, Option<OpNetworkPayloadEnvelope>) {
+    fn handle(
+        &mut self,
+        msg: Message,
+    ) -> (MessageAcceptance, Option<OpNetworkPayloadEnvelope>, bool) {
         let decoded = if msg.topic == self.blocks_v1_topic.hash() {
             OpNetworkPayloadEnvelope::decode_v1(&msg.data)
         } else if msg.topic == self.blocks_v2_topic.hash() {
@@ -56,20 +65,21 @@ impl Handler for BlockHandler {
             OpNetworkPayloadEnvelope::decode_v4(&msg.data)
         } else {
             warn!(target: "gossip", topic = ?msg.topic, "Received block with unknown topic");
-            return (MessageAcceptance::Reject, None);
+            return (MessageAcceptance::Reject, None, false);
         };
 
         match decoded {
             Ok(envelope) => match self.block_valid(&envelope) {
-                Ok(()) => (MessageAcceptance::Accept, Some(envelope)),
+                Ok(()) => (MessageAcceptance::Accept, Some(envelope), false),
                 Err(err) => {
                     warn!(target: "gossip", ?err, hash = ?envelope.payload_hash, "Received invalid block");
-                    (err.into(), None)
+                    let malicious = err.is_malicious();
+                    (err.into(), None, malicious)
                 }
             },
             Err(err) => {
                 warn!(target: "gossip", ?err, "Failed to decode block");
-                (MessageAcceptance::Reject, None)
+                (MessageAcceptance::Reject, None, false)
             }
         }
     }
@@ -21,6 +21,7 @@
 //! ### Connection Management
 //! - [`Connectedness`]: Peer connection state enumeration
 //! - [`Direction`]: Connection direction (inbound/outbound)
+//! - [`BannedPeer`]: A banned peer, with the [`BanReason`] and expiry of its ban
 //!
 //! ## RPC Methods
 //!
@@ -48,6 +49,6 @@ pub use request::P2pRpcRequest;
 
 mod types;
 pub use types::{
-    Connectedness, Direction, GossipScores, PeerCount, PeerDump, PeerInfo, PeerScores, PeerStats,
-    ReqRespScores, TopicScores,
+    BanReason, BannedPeer, Connectedness, Direction, GossipScores, PeerCount, PeerDump, PeerInfo,
+    PeerScores, PeerStats, ReqRespScores, TopicScores,
 };
@@ -10,7 +10,7 @@ use discv5::{
 };
 use ipnet::IpNet;
 use kona_disc::Discv5Handler;
-use kona_peers::OpStackEnr;
+use kona_peers::{OpStackEnr, p2p_id_to_node_id};
 use libp2p::{Multiaddr, PeerId, gossipsub::TopicHash};
 use tokio::sync::oneshot::Sender;
 
@@ -49,6 +49,13 @@ pub enum P2pRpcRequest {
         /// The [`PeerId`] of the peer to unblock.
         id: PeerId,
     },
+    /// Request to lift the ban of a peer by its [`PeerId`], resetting its gossip score.
+    UnbanPeer {
+        /// The [`PeerId`] of the peer to unban.
+        id: PeerId,
+        /// The output channel to send whether the peer was banned to.
+        out: Sender<bool>,
+    },
     /// Request to list all blocked peers.
     ListBlockedPeers(Sender<Vec<PeerId>>),
     /// Request to block a given IP Address.
@@ -118,6 +125,7 @@ impl P2pRpcRequest {
             Self::ConnectPeer { address } => Self::connect_peer(address, gossip),
             Self::BlockPeer { id } => Self::block_peer(id, gossip),
             Self::UnblockPeer { id } => Self::unblock_peer(id, gossip),
+            Self::UnbanPeer { id, out } => Self::unban_peer(id, out, gossip, disc),
             Self::ListBlockedPeers(s) => Self::list_blocked_peers(s, gossip),
             Self::BlockAddr { address } => Self::block_addr(address, gossip),
             Self::UnblockAddr { address } => Self::unblock_addr(address, gossip),
@@ -163,6 +171,26 @@ impl P2pRpcRequest {
         gossip.swarm.behaviour_mut().gossipsub.remove_blacklisted_peer(&id);
     }
 
+    fn unban_peer<G: ConnectionGate>(
+        id: PeerId,
+        s: Sender<bool>,
+        gossip: &mut GossipDriver<G>,
+        disc: &Discv5Handler,
+    ) {
+        let banned = gossip.connection_gate.unban_peer(&id);
+        gossip.swarm.behaviour_mut().gossipsub.remove_blacklisted_peer(&id);
+        // Forgive the retained gossip score, so the peer is not banned again on the next peer
+        // score inspection.
+        gossip.forgive_peer_score(&id);
+        // Lift the discovery ban issued alongside score-based bans.
+        if let Some(node_id) = p2p_id_to_node_id(&id) {
+            disc.unban_node(node_id);
+        }
+        if let Err(e) = s.send(banned) {
+            warn!(target: "p2p::rpc", "Failed to send unban result through response channel: {:?}", e);
+        }
+    }
+
     fn list_blocked_peers<G: ConnectionGate>(s: Sender<Vec<PeerId>>, gossip: &GossipDriver<G>) {
         let blocked_peers = gossip.connection_gate.list_blocked_peers();
         if let Err(e) = s.send(blocked_peers) {
@@ -268,6 +268,35 @@ impl From<u8> for Connectedness {
         }
     }
 }
+/// The reason a peer was banned.
+#[derive(Debug, Clone, Display, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BanReason {
+    /// The peer's gossip score dropped below the ban threshold.
+    #[display("Gossip Score")]
+    GossipScore,
+    /// The peer propagated an invalid block.
+    #[display("Invalid Block")]
+    InvalidBlock,
+    /// The peer was banned manually through the RPC API.
+    #[display("Manual")]
+    Manual,
+}
+
+/// A banned peer, along with the reason and expiry of its ban.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BannedPeer {
+    /// The peer id.
+    #[serde(rename = "peerID")]
+    pub peer_id: String,
+    /// The reason the peer was banned.
+    pub reason: BanReason,
+    /// The unix timestamp, in seconds, at which the ban expires.
+    /// If not set, the ban lasts until the peer is unblocked.
+    pub expiry: Option<u64>,
+}
+
 /// Direction represents the direction of a connection.
 #[derive(Debug, Clone, Display, Copy, PartialEq, Eq, Default)]
 pub enum Direction {
@@ -38,7 +38,8 @@ pub use record::{NodeRecord, NodeRecordParseError};
 
 mod utils;
 pub use utils::{
-    PeerIdConversionError, enr_to_multiaddr, local_id_to_p2p_id, peer_id_to_secp256k1_pubkey,
+    PeerIdConversionError, enr_to_multiaddr, local_id_to_p2p_id, p2p_id_to_node_id,
+    peer_id_to_secp256k1_pubkey,
 };
 
 mod monitoring;
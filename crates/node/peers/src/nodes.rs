@@ -21,8 +21,8 @@ impl BootNodes {
             return Self(vec![]);
         };
         match chain.parent.chain_id() {
-            1 => Self::mainnet(),
-            11155111 => Self::testnet(),
+            Some(1) => Self::mainnet(),
+            Some(11155111) => Self::testnet(),
             _ => Self(vec![]),
         }
     }
@@ -1,8 +1,9 @@
 //! Utilities to translate types.
 
+use alloy_primitives::keccak256;
 use discv5::{
     Enr,
-    enr::{CombinedPublicKey, EnrPublicKey},
+    enr::{CombinedPublicKey, EnrPublicKey, NodeId},
     multiaddr::Protocol,
 };
 use libp2p::Multiaddr;
@@ -49,6 +50,16 @@ pub fn peer_id_to_secp256k1_pubkey(id: PeerId) -> Result<secp256k1::PublicKey, s
     secp256k1::PublicKey::from_slice(&full_pubkey)
 }
 
+/// Converts a [`libp2p::PeerId`] into the discv5 [`NodeId`] of the same secp256k1 public key.
+///
+/// Returns `None` if the peer id does not embed a secp256k1 public key.
+pub fn p2p_id_to_node_id(peer_id: &libp2p::PeerId) -> Option<NodeId> {
+    let public_key =
+        libp2p_identity::PublicKey::try_decode_protobuf(peer_id.as_ref().digest()).ok()?;
+    let uncompressed = public_key.try_into_secp256k1().ok()?.to_bytes_uncompressed();
+    Some(NodeId::new(&keccak256(&uncompressed[1..]).0))
+}
+
 /// An error that can occur when converting a [`PeerId`] to a [`libp2p::PeerId`].
 #[derive(Debug, thiserror::Error)]
 pub enum PeerIdConversionError {
@@ -166,6 +177,21 @@ mod tests {
         assert_eq!(received_p2p_id, Some(peer_id));
     }
 
+    #[test]
+    fn test_p2p_id_to_node_id() {
+        let private_key = CombinedKey::generate_secp256k1();
+        let enr = Enr::builder().build(&private_key).unwrap();
+
+        let public_key = private_key.public().encode();
+        let public_key =
+            libp2p_identity::secp256k1::PublicKey::try_from_bytes(&public_key).unwrap();
+        let peer_id = libp2p::PeerId::from_public_key(&public_key.into());
+
+        assert_eq!(p2p_id_to_node_id(&peer_id), Some(enr.node_id()));
+        // A peer id that does not embed a public key has no node id.
+        assert_eq!(p2p_id_to_node_id(&libp2p::PeerId::random()), None);
+    }
+
     #[test]
     fn test_convert_local_peer_id_to_multi_peer_id() {
         let p2p_keypair = discv5::libp2p_identity::secp256k1::Keypair::generate();
@@ -7,6 +7,8 @@ use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject},
 };
+use kona_derive::PipelineSnapshot;
+use kona_gossip::BannedPeer;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 use tokio::sync::oneshot;
 
@@ -35,10 +37,24 @@ pub enum NetworkAdminQuery {
         /// The payload to post.
         payload: OpExecutionPayloadEnvelope,
     },
+    /// A query for the banned peers, along with the reason and expiry of their ban.
+    BannedPeers(oneshot::Sender<Vec<BannedPeer>>),
+}
+
+/// The query types to the derivation actor for the admin api.
+#[derive(Debug)]
+pub enum DerivationAdminQuery {
+    /// A query for a snapshot of the data buffered by the derivation pipeline's stages.
+    PipelineSnapshot(oneshot::Sender<PipelineSnapshot>),
+    /// A query to stop consuming new L1 data.
+    PauseDerivation,
+    /// A query to resume consuming L1 data from the last processed L1 block.
+    ResumeDerivation,
 }
 
 type SequencerQuerySender = tokio::sync::mpsc::Sender<SequencerAdminQuery>;
 type NetworkAdminQuerySender = tokio::sync::mpsc::Sender<NetworkAdminQuery>;
+type DerivationAdminQuerySender = tokio::sync::mpsc::Sender<DerivationAdminQuery>;
 
 /// The admin rpc server.
 #[derive(Debug)]
@@ -47,6 +63,8 @@ pub struct AdminRpc {
     pub sequencer_sender: Option<SequencerQuerySender>,
     /// The sender to the network actor.
     pub network_sender: NetworkAdminQuerySender,
+    /// The sender to the derivation actor.
+    pub derivation_sender: DerivationAdminQuerySender,
 }
 
 #[async_trait]
@@ -141,4 +159,134 @@ impl AdminApiServer for AdminRpc {
             .await
             .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
     }
+
+    async fn admin_derivation_stages(&self) -> RpcResult<PipelineSnapshot> {
+        kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "admin_derivationStages");
+        let (tx, rx) = oneshot::channel();
+        self.derivation_sender
+            .send(DerivationAdminQuery::PipelineSnapshot(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn admin_pause_derivation(&self) -> RpcResult<()> {
+        kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "admin_pauseDerivation");
+        self.derivation_sender
+            .send(DerivationAdminQuery::PauseDerivation)
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn admin_resume_derivation(&self) -> RpcResult<()> {
+        kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "admin_resumeDerivation");
+        self.derivation_sender
+            .send(DerivationAdminQuery::ResumeDerivation)
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn admin_banned_peers(&self) -> RpcResult<Vec<BannedPeer>> {
+        kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "admin_bannedPeers");
+        let (tx, rx) = oneshot::channel();
+        self.network_sender
+            .send(NetworkAdminQuery::BannedPeers(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_protocol::BlockInfo;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_admin_derivation_stages() {
+        let (network_sender, _network_rx) = mpsc::channel(1);
+        let (derivation_sender, mut derivation_rx) = mpsc::channel(1);
+        let rpc = AdminRpc { sequencer_sender: None, network_sender, derivation_sender };
+
+        let expected = PipelineSnapshot {
+            origin: Some(BlockInfo { number: 10, ..Default::default() }),
+            frame_queue_depth: 3,
+            channel_count: 1,
+            channel_bank_size: 1_024,
+            batch_queue_depth: 2,
+            ..Default::default()
+        };
+        tokio::spawn(async move {
+            let Some(DerivationAdminQuery::PipelineSnapshot(tx)) = derivation_rx.recv().await
+            else {
+                panic!("expected a pipeline snapshot query");
+            };
+            tx.send(expected).unwrap();
+        });
+
+        let snapshot = rpc.admin_derivation_stages().await.unwrap();
+        assert_eq!(snapshot, expected);
+
+        let json = serde_json::to_value(snapshot).unwrap();
+        assert_eq!(json["frameQueueDepth"], 3);
+        assert_eq!(json["channelBankSize"], 1_024);
+        assert_eq!(json["batchQueueDepth"], 2);
+        assert_eq!(json["origin"]["number"], 10);
+    }
+
+    #[tokio::test]
+    async fn test_admin_derivation_stages_actor_closed() {
+        let (network_sender, _network_rx) = mpsc::channel(1);
+        let (derivation_sender, derivation_rx) = mpsc::channel(1);
+        drop(derivation_rx);
+        let rpc = AdminRpc { sequencer_sender: None, network_sender, derivation_sender };
+
+        assert!(rpc.admin_derivation_stages().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_admin_pause_resume_derivation() {
+        let (network_sender, _network_rx) = mpsc::channel(1);
+        let (derivation_sender, mut derivation_rx) = mpsc::channel(2);
+        let rpc = AdminRpc { sequencer_sender: None, network_sender, derivation_sender };
+
+        rpc.admin_pause_derivation().await.unwrap();
+        rpc.admin_resume_derivation().await.unwrap();
+        assert!(matches!(derivation_rx.recv().await, Some(DerivationAdminQuery::PauseDerivation)));
+        assert!(matches!(derivation_rx.recv().await, Some(DerivationAdminQuery::ResumeDerivation)));
+
+        drop(derivation_rx);
+        assert!(rpc.admin_pause_derivation().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_admin_banned_peers() {
+        use kona_gossip::BanReason;
+
+        let (network_sender, mut network_rx) = mpsc::channel(1);
+        let (derivation_sender, _derivation_rx) = mpsc::channel(1);
+        let rpc = AdminRpc { sequencer_sender: None, network_sender, derivation_sender };
+
+        let banned = BannedPeer {
+            peer_id: "16Uiu2HAmBanned".to_string(),
+            reason: BanReason::InvalidBlock,
+            expiry: Some(1_700_000_000),
+        };
+        let expected = vec![banned.clone()];
+        tokio::spawn(async move {
+            let Some(NetworkAdminQuery::BannedPeers(tx)) = network_rx.recv().await else {
+                panic!("expected a banned peers query");
+            };
+            tx.send(expected).unwrap();
+        });
+
+        let peers = rpc.admin_banned_peers().await.unwrap();
+        assert_eq!(peers, vec![banned]);
+
+        let json = serde_json::to_value(&peers).unwrap();
+        assert_eq!(json[0]["peerID"], "16Uiu2HAmBanned");
+        assert_eq!(json[0]["reason"], "invalidBlock");
+        assert_eq!(json[0]["expiry"], 1_700_000_000);
+    }
 }
@@ -16,6 +16,8 @@ pub struct RpcBuilder {
     pub admin_persistence: Option<PathBuf>,
     /// Enable the websocket rpc server
     pub ws_enabled: bool,
+    /// The number of updates buffered per websocket subscription.
+    pub ws_buffer_size: usize,
     /// Enable development RPC endpoints
     pub dev_enabled: bool,
 }
@@ -26,6 +28,11 @@ impl RpcBuilder {
         self.ws_enabled
     }
 
+    /// Returns the number of updates buffered per websocket subscription.
+    pub const fn ws_buffer_size(&self) -> usize {
+        self.ws_buffer_size
+    }
+
     /// Returns whether development RPC endpoints are enabled
     pub const fn dev_enabled(&self) -> bool {
         self.dev_enabled
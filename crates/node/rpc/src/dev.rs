@@ -112,3 +112,93 @@ impl DevEngineApiServer for DevEngineRpc {
         })
     }
 }
+
+#[cfg(feature = "dev")]
+#[async_trait]
+impl crate::DevPayloadApiServer for DevEngineRpc {
+    async fn dev_inject_invalid_payload(
+        &self,
+        kind: kona_engine::InvalidPayloadKind,
+    ) -> RpcResult<alloy_rpc_types_engine::PayloadStatus> {
+        let (query_tx, query_rx) = tokio::sync::oneshot::channel();
+
+        self.engine_query_sender
+            .send(EngineQueries::InjectInvalidPayload { kind, sender: query_tx })
+            .await
+            .map_err(|_| {
+                jsonrpsee::types::ErrorObjectOwned::owned(
+                    ErrorCode::InternalError.code(),
+                    "Engine query channel closed",
+                    None::<()>,
+                )
+            })?;
+
+        query_rx
+            .await
+            .map_err(|_| {
+                jsonrpsee::types::ErrorObjectOwned::owned(
+                    ErrorCode::InternalError.code(),
+                    "Failed to receive payload status",
+                    None::<()>,
+                )
+            })?
+            .map_err(|e| {
+                jsonrpsee::types::ErrorObjectOwned::owned(
+                    ErrorCode::InternalError.code(),
+                    e.to_string(),
+                    None::<()>,
+                )
+            })
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::*;
+    use crate::DevPayloadApiServer;
+    use alloy_rpc_types_engine::{PayloadStatus, PayloadStatusEnum};
+    use kona_engine::InvalidPayloadKind;
+
+    /// Spawns a mock engine actor that rejects every injected payload, reporting the defect as the
+    /// validation error.
+    fn mock_dev_rpc() -> DevEngineRpc {
+        let (engine_sender, mut engine_recv) = tokio::sync::mpsc::channel(1);
+
+        tokio::spawn(async move {
+            while let Some(query) = engine_recv.recv().await {
+                if let EngineQueries::InjectInvalidPayload { kind, sender } = query {
+                    let status = PayloadStatus::from_status(PayloadStatusEnum::Invalid {
+                        validation_error: format!("{kind:?}"),
+                    });
+                    let _ = sender.send(Ok(status));
+                }
+            }
+        });
+
+        DevEngineRpc::new(engine_sender)
+    }
+
+    #[tokio::test]
+    async fn test_inject_invalid_payload() {
+        let rpc = mock_dev_rpc();
+
+        for kind in InvalidPayloadKind::ALL {
+            let status = rpc.dev_inject_invalid_payload(kind).await.unwrap();
+            assert_eq!(
+                status.status,
+                PayloadStatusEnum::Invalid { validation_error: format!("{kind:?}") }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inject_invalid_payload_engine_closed() {
+        let (engine_sender, _) = tokio::sync::mpsc::channel(1);
+        let rpc = DevEngineRpc::new(engine_sender);
+
+        for kind in InvalidPayloadKind::ALL {
+            let err = rpc.dev_inject_invalid_payload(kind).await.unwrap_err();
+            assert_eq!(err.code(), ErrorCode::InternalError.code());
+        }
+    }
+}
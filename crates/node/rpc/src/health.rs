@@ -0,0 +1,258 @@
+//! Liveness tracking of the node's components, served by the `healthz` endpoint.
+
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+/// A component of the node whose liveness is reported by the `healthz` endpoint.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeComponent {
+    /// The derivation actor.
+    Derivation,
+    /// The engine actor.
+    Engine,
+    /// The network actor.
+    Network,
+    /// The sequencer actor.
+    Sequencer,
+}
+
+/// The liveness of a single [`NodeComponent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentHealth {
+    /// The unix timestamp, in seconds, of the component's last heartbeat.
+    pub last_heartbeat: u64,
+    /// Whether the component has not sent a heartbeat within the stall threshold.
+    pub stalled: bool,
+    /// Whether the component was paused by an operator. A paused component keeps sending
+    /// heartbeats, and doesn't make the node unhealthy.
+    #[serde(default)]
+    pub paused: bool,
+}
+
+/// A healthcheck response for the RPC server.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct HealthzResponse {
+    /// The application version.
+    pub version: String,
+    /// Whether none of the node's components are stalled.
+    #[serde(default = "default_healthy")]
+    pub healthy: bool,
+    /// The liveness of each of the node's running components.
+    #[serde(default)]
+    pub components: BTreeMap<NodeComponent, ComponentHealth>,
+}
+
+const fn default_healthy() -> bool {
+    true
+}
+
+/// The last heartbeat of a component.
+#[derive(Debug, Clone, Copy)]
+struct LastHeartbeat {
+    /// Used to measure how long the component has been silent.
+    at: Instant,
+    /// Used to report when the component was last heard from.
+    timestamp: SystemTime,
+    /// Whether the component is paused.
+    paused: bool,
+}
+
+impl LastHeartbeat {
+    fn now() -> Self {
+        Self { at: Instant::now(), timestamp: SystemTime::now(), paused: false }
+    }
+}
+
+/// A registry of the last heartbeat of each of the node's components.
+///
+/// Components register through [`HealthRegistry::heartbeat`], and the registry reports a component
+/// as stalled once it has not sent a heartbeat for longer than the stall threshold. The registry is
+/// cheaply cloneable and shared between the components and the RPC server.
+#[derive(Debug, Clone)]
+pub struct HealthRegistry {
+    /// The last heartbeat of each registered component.
+    heartbeats: Arc<RwLock<BTreeMap<NodeComponent, LastHeartbeat>>>,
+    /// The duration after which a silent component is considered stalled.
+    stall_threshold: Duration,
+}
+
+impl Default for HealthRegistry {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_STALL_THRESHOLD)
+    }
+}
+
+impl HealthRegistry {
+    /// The default duration after which a silent component is considered stalled.
+    pub const DEFAULT_STALL_THRESHOLD: Duration = Duration::from_secs(60);
+
+    /// Creates a new [`HealthRegistry`] with the given stall threshold.
+    pub fn new(stall_threshold: Duration) -> Self {
+        Self { heartbeats: Default::default(), stall_threshold }
+    }
+
+    /// Registers the given component and returns the [`Heartbeat`] it must use to report its
+    /// liveness.
+    pub fn heartbeat(&self, component: NodeComponent) -> Heartbeat {
+        let heartbeat = Heartbeat::new(component, self.clone());
+        heartbeat.beat();
+        heartbeat
+    }
+
+    /// Returns the [`HealthzResponse`] for the current state of the registered components.
+    pub fn report(&self, version: String) -> HealthzResponse {
+        let heartbeats = self.heartbeats.read().unwrap_or_else(|e| e.into_inner());
+
+        let components: BTreeMap<_, _> = heartbeats
+            .iter()
+            .map(|(component, last)| {
+                let health = ComponentHealth {
+                    last_heartbeat: last
+                        .timestamp
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs(),
+                    stalled: last.at.elapsed() > self.stall_threshold,
+                    paused: last.paused,
+                };
+                (*component, health)
+            })
+            .collect();
+
+        let healthy = components.values().all(|health| !health.stalled);
+        HealthzResponse { version, healthy, components }
+    }
+
+    fn record(&self, component: NodeComponent) {
+        let mut heartbeats = self.heartbeats.write().unwrap_or_else(|e| e.into_inner());
+        let paused = heartbeats.get(&component).is_some_and(|last| last.paused);
+        heartbeats.insert(component, LastHeartbeat { paused, ..LastHeartbeat::now() });
+    }
+
+    fn set_paused(&self, component: NodeComponent, paused: bool) {
+        let mut heartbeats = self.heartbeats.write().unwrap_or_else(|e| e.into_inner());
+        heartbeats.entry(component).or_insert_with(LastHeartbeat::now).paused = paused;
+    }
+}
+
+/// A handle used by a component to report its liveness to the [`HealthRegistry`].
+///
+/// Components are expected to [`Heartbeat::beat`] on every iteration of their event loop, and to
+/// poll [`Heartbeat::tick`] alongside their other events so that idle components keep reporting.
+/// A component that is wedged on a single event stops reporting and is eventually flagged as
+/// stalled.
+#[derive(Debug)]
+pub struct Heartbeat {
+    /// The component reporting through this handle.
+    component: NodeComponent,
+    /// The registry the heartbeats are recorded in.
+    registry: HealthRegistry,
+    /// The interval at which idle components report.
+    interval: Interval,
+}
+
+impl Heartbeat {
+    /// The interval at which idle components report their liveness.
+    pub const INTERVAL: Duration = Duration::from_secs(5);
+
+    fn new(component: NodeComponent, registry: HealthRegistry) -> Self {
+        let mut interval = tokio::time::interval(Self::INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self { component, registry, interval }
+    }
+
+    /// Records a heartbeat for the component.
+    pub fn beat(&self) {
+        self.registry.record(self.component);
+    }
+
+    /// Reports whether the component is paused. The component must keep sending heartbeats while
+    /// paused.
+    pub fn set_paused(&self, paused: bool) {
+        self.registry.set_paused(self.component, paused);
+    }
+
+    /// Waits for the next heartbeat interval and records a heartbeat.
+    pub async fn tick(&mut self) {
+        self.interval.tick().await;
+        self.beat();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stalled_component_is_unhealthy() {
+        let registry = HealthRegistry::new(Duration::from_secs(30));
+        let mut engine = registry.heartbeat(NodeComponent::Engine);
+        let _derivation = registry.heartbeat(NodeComponent::Derivation);
+
+        let report = registry.report("test".to_string());
+        assert!(report.healthy);
+        assert_eq!(report.components.len(), 2);
+
+        // The engine keeps ticking while the derivation actor is wedged.
+        for _ in 0..10 {
+            engine.tick().await;
+        }
+
+        let report = registry.report("test".to_string());
+        assert!(!report.healthy);
+        assert!(!report.components[&NodeComponent::Engine].stalled);
+        assert!(report.components[&NodeComponent::Derivation].stalled);
+        assert!(!report.components.contains_key(&NodeComponent::Sequencer));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_recovered_component_is_healthy() {
+        let registry = HealthRegistry::new(Duration::from_secs(30));
+        let network = registry.heartbeat(NodeComponent::Network);
+
+        tokio::time::advance(Duration::from_secs(31)).await;
+        assert!(!registry.report("test".to_string()).healthy);
+
+        network.beat();
+        assert!(registry.report("test".to_string()).healthy);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_paused_component() {
+        let registry = HealthRegistry::new(Duration::from_secs(30));
+        let mut derivation = registry.heartbeat(NodeComponent::Derivation);
+
+        derivation.set_paused(true);
+        derivation.tick().await;
+        let report = registry.report("test".to_string());
+        assert!(report.healthy);
+        assert!(report.components[&NodeComponent::Derivation].paused);
+
+        derivation.set_paused(false);
+        assert!(!registry.report("test".to_string()).components[&NodeComponent::Derivation].paused);
+    }
+
+    #[test]
+    fn test_healthz_response_compat() {
+        let response: HealthzResponse = serde_json::from_str(r#"{"version":"1.0.0"}"#).unwrap();
+        assert_eq!(response.version, "1.0.0");
+        assert!(response.healthy);
+        assert!(response.components.is_empty());
+
+        let registry = HealthRegistry::default();
+        let _sequencer = registry.heartbeat(NodeComponent::Sequencer);
+        let response = registry.report("1.0.0".to_string());
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["version"], "1.0.0");
+        assert_eq!(json["components"]["sequencer"]["stalled"], false);
+        assert_eq!(json["components"]["sequencer"]["paused"], false);
+    }
+}
@@ -9,9 +9,10 @@ use jsonrpsee::{
     core::{RpcResult, SubscriptionResult},
     proc_macros::rpc,
 };
+use kona_derive::PipelineSnapshot;
 use kona_genesis::RollupConfig;
-use kona_gossip::{PeerCount, PeerDump, PeerInfo, PeerStats};
-use kona_protocol::SyncStatus;
+use kona_gossip::{BannedPeer, PeerCount, PeerDump, PeerInfo, PeerStats};
+use kona_protocol::{BlockInfo, SyncStatus};
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 
 #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), allow(unused_imports))]
@@ -28,9 +29,15 @@ pub use op_alloy_rpc_jsonrpsee::traits::{MinerApiExtServer, OpAdminApiServer};
 #[cfg_attr(feature = "client", rpc(server, client, namespace = "optimism"))]
 pub trait RollupNodeApi {
     /// Get the output root at a specific block.
+    ///
+    /// If `with_proof` is set, the response also contains the `L2ToL1MessagePasser` account proof
+    /// against the block's state root.
     #[method(name = "outputAtBlock")]
-    async fn op_output_at_block(&self, block_number: BlockNumberOrTag)
-    -> RpcResult<OutputResponse>;
+    async fn op_output_at_block(
+        &self,
+        block_number: BlockNumberOrTag,
+        with_proof: Option<bool>,
+    ) -> RpcResult<OutputResponse>;
 
     /// Gets the safe head at an L1 block height.
     #[method(name = "safeHeadAtL1Block")]
@@ -39,6 +46,10 @@ pub trait RollupNodeApi {
         block_number: BlockNumberOrTag,
     ) -> RpcResult<SafeHeadResponse>;
 
+    /// Gets the L1 origin of the current safe head.
+    #[method(name = "safeHeadL1Origin")]
+    async fn op_safe_head_l1_origin(&self) -> RpcResult<BlockInfo>;
+
     /// Get the synchronization status.
     #[method(name = "syncStatus")]
     async fn op_sync_status(&self) -> RpcResult<SyncStatus>;
@@ -84,6 +95,10 @@ pub trait OpP2PApi {
     #[method(name = "unblockPeer")]
     async fn opp2p_unblock_peer(&self, peer: String) -> RpcResult<()>;
 
+    /// Lifts the ban of the given peer, returning whether it was banned
+    #[method(name = "unbanPeer")]
+    async fn opp2p_unban_peer(&self, peer: String) -> RpcResult<bool>;
+
     /// Lists blocked peers
     #[method(name = "listBlockedPeers")]
     async fn opp2p_list_blocked_peers(&self) -> RpcResult<Vec<String>>;
@@ -161,6 +176,23 @@ pub trait DevEngineApi {
     async fn dev_task_queue_length(&self) -> RpcResult<usize>;
 }
 
+/// Development RPC API for exercising the execution engine's handling of invalid payloads.
+///
+/// Only available with the `dev` feature. Must never be enabled in production builds.
+#[cfg(feature = "dev")]
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "dev"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "dev"))]
+#[async_trait]
+pub trait DevPayloadApi {
+    /// Submit a copy of the unsafe head with the given defect to the execution engine, returning
+    /// the engine's payload status.
+    #[method(name = "injectInvalidPayload")]
+    async fn dev_inject_invalid_payload(
+        &self,
+        kind: kona_engine::InvalidPayloadKind,
+    ) -> RpcResult<alloy_rpc_types_engine::PayloadStatus>;
+}
+
 /// The admin namespace for the consensus node.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
 #[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
@@ -193,4 +225,21 @@ pub trait AdminApi {
     /// Overrides the leader in the conductor.
     #[method(name = "overrideLeader")]
     async fn admin_override_leader(&self) -> RpcResult<()>;
+
+    /// Returns a snapshot of the data buffered by the derivation pipeline's stages.
+    #[method(name = "derivationStages")]
+    async fn admin_derivation_stages(&self) -> RpcResult<PipelineSnapshot>;
+
+    /// Pauses derivation. The node stops consuming new L1 data, while the engine and network
+    /// keep running.
+    #[method(name = "pauseDerivation")]
+    async fn admin_pause_derivation(&self) -> RpcResult<()>;
+
+    /// Resumes derivation from the last processed L1 block.
+    #[method(name = "resumeDerivation")]
+    async fn admin_resume_derivation(&self) -> RpcResult<()>;
+
+    /// Returns the banned peers, along with the reason and expiry of their ban.
+    #[method(name = "bannedPeers")]
+    async fn admin_banned_peers(&self) -> RpcResult<Vec<BannedPeer>>;
 }
@@ -10,7 +10,7 @@
 extern crate tracing;
 
 mod admin;
-pub use admin::{AdminRpc, NetworkAdminQuery, SequencerAdminQuery};
+pub use admin::{AdminRpc, DerivationAdminQuery, NetworkAdminQuery, SequencerAdminQuery};
 
 mod config;
 pub use config::RpcBuilder;
@@ -24,12 +24,17 @@ mod response;
 pub use response::SafeHeadResponse;
 
 mod output;
-pub use output::OutputResponse;
+pub use output::{OutputProofError, OutputResponse};
 
 mod dev;
 pub use dev::DevEngineRpc;
 
+mod health;
+pub use health::{ComponentHealth, HealthRegistry, HealthzResponse, Heartbeat, NodeComponent};
+
 mod jsonrpsee;
+#[cfg(feature = "dev")]
+pub use jsonrpsee::DevPayloadApiServer;
 pub use jsonrpsee::{
     AdminApiServer, DevEngineApiServer, MinerApiExtServer, OpAdminApiServer, OpP2PApiServer,
     RollupNodeApiServer, WsServer,
@@ -43,10 +48,3 @@ pub use l1_watcher::{L1State, L1WatcherQueries, L1WatcherQuerySender};
 
 mod ws;
 pub use ws::WsRPC;
-
-/// A healthcheck response for the RPC server.
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
-pub struct HealthzResponse {
-    /// The application version.
-    pub version: String,
-}
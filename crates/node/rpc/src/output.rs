@@ -1,7 +1,13 @@
 //! Output Types
 
-use alloy_primitives::B256;
-use kona_protocol::{L2BlockInfo, OutputRoot, SyncStatus};
+use alloy_primitives::{Address, B256, keccak256};
+use alloy_rlp::Encodable;
+use alloy_rpc_types_eth::EIP1186AccountProofResponse;
+use alloy_trie::{
+    Nibbles, TrieAccount,
+    proof::{ProofVerificationError, verify_proof},
+};
+use kona_protocol::{L2BlockInfo, OutputRoot, Predeploys, SyncStatus};
 
 /// An [output response][or] for Optimism Rollup.
 ///
@@ -21,6 +27,12 @@ pub struct OutputResponse {
     pub state_root: B256,
     /// The status of the node sync.
     pub sync_status: SyncStatus,
+    /// The `L2ToL1MessagePasser` account proof against the state root, if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub withdrawal_proof: Option<EIP1186AccountProofResponse>,
+    /// The reason the requested withdrawal proof could not be generated, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_error: Option<String>,
 }
 
 impl OutputResponse {
@@ -33,6 +45,183 @@ impl OutputResponse {
             withdrawal_storage_root: v0.bridge_storage_root,
             state_root: v0.state_root,
             sync_status,
+            withdrawal_proof: None,
+            proof_error: None,
         }
     }
+
+    /// Attaches the result of the withdrawal proof generation to the [`OutputResponse`]. If the
+    /// proof could not be generated, the response is flagged with the error instead.
+    pub fn with_withdrawal_proof<E: ToString>(
+        mut self,
+        proof: Result<EIP1186AccountProofResponse, E>,
+    ) -> Self {
+        match proof {
+            Ok(proof) => self.withdrawal_proof = Some(proof),
+            Err(e) => self.proof_error = Some(e.to_string()),
+        }
+        self
+    }
+
+    /// Verifies that the attached withdrawal proof is a valid `L2ToL1MessagePasser` account proof
+    /// against the state root, and that it commits to the claimed withdrawal storage root.
+    pub fn verify_withdrawal_proof(&self) -> Result<(), OutputProofError> {
+        let proof = self.withdrawal_proof.as_ref().ok_or(OutputProofError::MissingProof)?;
+
+        if proof.address != Predeploys::L2_TO_L1_MESSAGE_PASSER {
+            return Err(OutputProofError::UnexpectedAddress(proof.address));
+        }
+
+        if proof.storage_hash != self.withdrawal_storage_root {
+            return Err(OutputProofError::StorageRootMismatch {
+                expected: self.withdrawal_storage_root,
+                actual: proof.storage_hash,
+            });
+        }
+
+        let account = TrieAccount {
+            nonce: proof.nonce,
+            balance: proof.balance,
+            storage_root: proof.storage_hash,
+            code_hash: proof.code_hash,
+        };
+        let mut encoded = Vec::with_capacity(account.length());
+        account.encode(&mut encoded);
+
+        verify_proof(
+            self.state_root,
+            Nibbles::unpack(keccak256(proof.address)),
+            Some(encoded),
+            &proof.account_proof,
+        )
+        .map_err(OutputProofError::InvalidAccountProof)
+    }
+}
+
+/// An error that occurs when verifying the withdrawal proof of an [`OutputResponse`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum OutputProofError {
+    /// The response does not contain a withdrawal proof.
+    #[error("The output response does not contain a withdrawal proof")]
+    MissingProof,
+    /// The proof is not for the `L2ToL1MessagePasser` account.
+    #[error("The withdrawal proof is for an unexpected account: {0}")]
+    UnexpectedAddress(Address),
+    /// The proven storage root does not match the claimed withdrawal storage root.
+    #[error("Withdrawal storage root mismatch: expected {expected}, got {actual}")]
+    StorageRootMismatch {
+        /// The claimed withdrawal storage root.
+        expected: B256,
+        /// The storage root committed to by the proof.
+        actual: B256,
+    },
+    /// The account proof does not verify against the state root.
+    #[error("Invalid withdrawal account proof: {0}")]
+    InvalidAccountProof(ProofVerificationError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{U256, b256};
+    use alloy_trie::{HashBuilder, proof::ProofRetainer};
+    use kona_protocol::BlockInfo;
+
+    fn sync_status() -> SyncStatus {
+        SyncStatus {
+            current_l1: BlockInfo::default(),
+            current_l1_finalized: BlockInfo::default(),
+            head_l1: BlockInfo::default(),
+            safe_l1: BlockInfo::default(),
+            finalized_l1: BlockInfo::default(),
+            unsafe_l2: L2BlockInfo::default(),
+            safe_l2: L2BlockInfo::default(),
+            finalized_l2: L2BlockInfo::default(),
+            cross_unsafe_l2: L2BlockInfo::default(),
+            local_safe_l2: L2BlockInfo::default(),
+        }
+    }
+
+    /// Builds a state trie containing only the `L2ToL1MessagePasser` account with the given
+    /// storage root, returning the state root and the account proof.
+    fn message_passer_proof(storage_root: B256) -> (B256, EIP1186AccountProofResponse) {
+        let address = Predeploys::L2_TO_L1_MESSAGE_PASSER;
+        let account_key = Nibbles::unpack(keccak256(address));
+        let account =
+            TrieAccount { nonce: 0, balance: U256::ZERO, storage_root, code_hash: keccak256("") };
+
+        let mut hb =
+            HashBuilder::default().with_proof_retainer(ProofRetainer::new(vec![account_key]));
+        let mut encoded = Vec::with_capacity(account.length());
+        account.encode(&mut encoded);
+        hb.add_leaf(account_key, &encoded);
+
+        let state_root = hb.root();
+        let account_proof =
+            hb.take_proof_nodes().into_nodes_sorted().into_iter().map(|(_, node)| node).collect();
+
+        let proof = EIP1186AccountProofResponse {
+            address,
+            balance: account.balance,
+            code_hash: account.code_hash,
+            nonce: account.nonce,
+            storage_hash: storage_root,
+            account_proof,
+            storage_proof: vec![],
+        };
+        (state_root, proof)
+    }
+
+    #[test]
+    fn test_withdrawal_proof_round_trip() {
+        let storage_root =
+            b256!("0x1111111111111111111111111111111111111111111111111111111111111111");
+        let (state_root, proof) = message_passer_proof(storage_root);
+
+        let output_root = OutputRoot::from_parts(state_root, storage_root, B256::ZERO);
+        let response = OutputResponse::from_v0(output_root, sync_status(), L2BlockInfo::default())
+            .with_withdrawal_proof(Ok::<_, String>(proof));
+
+        assert!(response.proof_error.is_none());
+        assert_eq!(response.verify_withdrawal_proof(), Ok(()));
+
+        // The proof survives a serialization round trip.
+        let serialized = serde_json::to_string(&response).unwrap();
+        let deserialized: OutputResponse = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, response);
+        assert_eq!(deserialized.verify_withdrawal_proof(), Ok(()));
+    }
+
+    #[test]
+    fn test_withdrawal_proof_wrong_storage_root() {
+        let storage_root =
+            b256!("0x1111111111111111111111111111111111111111111111111111111111111111");
+        let (state_root, proof) = message_passer_proof(storage_root);
+
+        let output_root = OutputRoot::from_parts(state_root, B256::ZERO, B256::ZERO);
+        let response = OutputResponse::from_v0(output_root, sync_status(), L2BlockInfo::default())
+            .with_withdrawal_proof(Ok::<_, String>(proof));
+
+        assert_eq!(
+            response.verify_withdrawal_proof(),
+            Err(OutputProofError::StorageRootMismatch {
+                expected: B256::ZERO,
+                actual: storage_root
+            })
+        );
+    }
+
+    #[test]
+    fn test_withdrawal_proof_error_is_flagged() {
+        let response = OutputResponse::from_v0(
+            OutputRoot::from_parts(B256::ZERO, B256::ZERO, B256::ZERO),
+            sync_status(),
+            L2BlockInfo::default(),
+        )
+        .with_withdrawal_proof(Err::<EIP1186AccountProofResponse, _>("missing trie node"));
+
+        assert!(response.withdrawal_proof.is_none());
+        assert_eq!(response.proof_error.as_deref(), Some("missing trie node"));
+        assert_eq!(response.verify_withdrawal_proof(), Err(OutputProofError::MissingProof));
+    }
 }
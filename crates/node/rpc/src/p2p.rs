@@ -100,6 +100,19 @@ impl OpP2PApiServer for P2pRpc {
             .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
     }
 
+    async fn opp2p_unban_peer(&self, peer_id: String) -> RpcResult<bool> {
+        kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "opp2p_unbanPeer");
+        let id = libp2p::PeerId::from_str(&peer_id)
+            .map_err(|_| ErrorObject::from(ErrorCode::InvalidParams))?;
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::UnbanPeer { id, out: tx })
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
     async fn opp2p_list_blocked_peers(&self) -> RpcResult<Vec<String>> {
         kona_macros::inc!(gauge, kona_gossip::Metrics::RPC_CALLS, "method" => "opp2p_listBlockedPeers");
         let (tx, rx) = tokio::sync::oneshot::channel();
@@ -10,7 +10,7 @@ use jsonrpsee::{
 };
 use kona_engine::{EngineQueries, EngineQuerySender, EngineState};
 use kona_genesis::RollupConfig;
-use kona_protocol::SyncStatus;
+use kona_protocol::{BlockInfo, SyncStatus};
 
 use crate::{
     L1State, L1WatcherQueries, OutputResponse, RollupNodeApiServer, SafeHeadResponse,
@@ -63,7 +63,11 @@ impl RollupRpc {
 
 #[async_trait]
 impl RollupNodeApiServer for RollupRpc {
-    async fn op_output_at_block(&self, block_num: BlockNumberOrTag) -> RpcResult<OutputResponse> {
+    async fn op_output_at_block(
+        &self,
+        block_num: BlockNumberOrTag,
+        with_proof: Option<bool>,
+    ) -> RpcResult<OutputResponse> {
         kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_outputAtBlock");
 
         let (output_send, output_recv) = tokio::sync::oneshot::channel();
@@ -90,7 +94,27 @@ impl RollupNodeApiServer for RollupRpc {
 
         let sync_status = Self::sync_status_from_actor_queries(l1_sync_status, l2_sync_status);
 
-        Ok(OutputResponse::from_v0(output_root, sync_status, l2_block_info))
+        let response = OutputResponse::from_v0(output_root, sync_status, l2_block_info);
+
+        if !with_proof.unwrap_or_default() {
+            return Ok(response);
+        }
+
+        // Failing to generate the proof does not fail the request; the response is flagged instead.
+        let (proof_send, proof_recv) = tokio::sync::oneshot::channel();
+        let query = EngineQueries::MessagePasserProof {
+            block: l2_block_info.block_info.hash.into(),
+            sender: proof_send,
+        };
+        let proof = match self.engine_sender.send(query).await {
+            Ok(()) => proof_recv
+                .await
+                .map_err(|e| e.to_string())
+                .and_then(|proof| proof.map_err(|e| e.to_string())),
+            Err(e) => Err(e.to_string()),
+        };
+
+        Ok(response.with_withdrawal_proof(proof))
     }
 
     /// This RPC endpoint is not supported. It is not necessary to track the safe head for every L1
@@ -103,6 +127,26 @@ impl RollupNodeApiServer for RollupRpc {
         return Err(ErrorObject::from(ErrorCode::MethodNotFound));
     }
 
+    async fn op_safe_head_l1_origin(&self) -> RpcResult<BlockInfo> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_safeHeadL1Origin");
+
+        let (l1_origin_send, l1_origin_recv) = tokio::sync::oneshot::channel();
+        self.engine_sender
+            .send(EngineQueries::SafeHeadL1Origin(l1_origin_send))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        let l1_origin =
+            l1_origin_recv.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        l1_origin.ok_or_else(|| {
+            ErrorObject::borrowed(
+                ErrorCode::InternalError.code(),
+                "Derivation has not progressed: no safe head L1 origin available",
+                None,
+            )
+        })
+    }
+
     async fn op_sync_status(&self) -> RpcResult<SyncStatus> {
         kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_syncStatus");
 
@@ -150,3 +194,44 @@ impl RollupNodeApiServer for RollupRpc {
         return Ok(RPC_VERSION.to_string());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use tokio::sync::mpsc;
+
+    /// Spawns a mock engine actor that answers [`EngineQueries::SafeHeadL1Origin`] queries with
+    /// the given L1 origin.
+    fn mock_rollup_rpc(l1_origin: Option<BlockInfo>) -> RollupRpc {
+        let (engine_sender, mut engine_recv) = mpsc::channel(1);
+        let (l1_watcher_sender, _) = mpsc::channel(1);
+
+        tokio::spawn(async move {
+            while let Some(query) = engine_recv.recv().await {
+                if let EngineQueries::SafeHeadL1Origin(sender) = query {
+                    let _ = sender.send(l1_origin);
+                }
+            }
+        });
+
+        RollupRpc::new(engine_sender, l1_watcher_sender)
+    }
+
+    #[tokio::test]
+    async fn test_safe_head_l1_origin() {
+        let l1_origin = BlockInfo::new(B256::with_last_byte(1), 100, B256::with_last_byte(2), 1200);
+        let rpc = mock_rollup_rpc(Some(l1_origin));
+
+        assert_eq!(rpc.op_safe_head_l1_origin().await.unwrap(), l1_origin);
+    }
+
+    #[tokio::test]
+    async fn test_safe_head_l1_origin_before_derivation() {
+        let rpc = mock_rollup_rpc(None);
+
+        let err = rpc.op_safe_head_l1_origin().await.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InternalError.code());
+        assert!(err.message().contains("Derivation has not progressed"));
+    }
+}
@@ -10,17 +10,40 @@ use jsonrpsee::core::to_json_raw_value;
 
 use crate::jsonrpsee::WsServer;
 
+/// How a subscription stream treats a client that cannot keep up with its updates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backpressure {
+    /// Critical streams: stop consuming engine state until the client catches up. Updates are
+    /// coalesced by the engine state watcher in the meantime, so nothing accumulates.
+    Block,
+    /// Non-critical streams: keep consuming engine state and drop the oldest buffered updates. The
+    /// subscription is closed once the client falls more than a full buffer behind before catching
+    /// up again.
+    DropOldest,
+}
+
 /// An RPC server that handles subscriptions to the node's state.
 #[derive(Debug)]
 pub struct WsRPC {
     /// The engine query sender.
     engine_query_sender: EngineQuerySender,
+    /// The number of updates buffered per subscription.
+    buffer_size: usize,
 }
 
 impl WsRPC {
+    /// The default number of updates buffered per subscription.
+    pub const DEFAULT_BUFFER_SIZE: usize = 64;
+
     /// Constructs a new [`WsRPC`] instance.
     pub const fn new(engine_query_sender: EngineQuerySender) -> Self {
-        Self { engine_query_sender }
+        Self { engine_query_sender, buffer_size: Self::DEFAULT_BUFFER_SIZE }
+    }
+
+    /// Sets the number of updates buffered per subscription. Must be non-zero.
+    pub const fn with_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
     }
 
     async fn engine_state_watcher(
@@ -55,68 +78,269 @@ impl WsRPC {
             )
         })
     }
-}
 
-#[async_trait::async_trait]
-impl WsServer for WsRPC {
-    async fn ws_safe_head_updates(&self, sink: PendingSubscriptionSink) -> SubscriptionResult {
+    /// Streams the changes of the given head to the subscription sink, until either the engine
+    /// state watcher or the subscription is closed.
+    async fn stream_head_updates(
+        &self,
+        sink: PendingSubscriptionSink,
+        head: fn(&EngineState) -> L2BlockInfo,
+        backpressure: Backpressure,
+    ) -> Result<(), jsonrpsee::core::SubscriptionError> {
         let sink = sink.accept().await?;
+        let subscription = self.engine_state_watcher().await?;
+
+        Self::forward_head_updates(subscription, head, backpressure, self.buffer_size, |update| {
+            Self::send_state_update(&sink, update)
+        })
+        .await
+    }
 
-        let mut subscription = self.engine_state_watcher().await?;
+    /// Forwards the changes of the given head through a buffer of `buffer_size` updates to `send`.
+    ///
+    /// The engine state watcher is consumed by a separate task, so that a slow `send` is handled
+    /// according to the [`Backpressure`] policy rather than by buffering without bound.
+    async fn forward_head_updates<F, Fut>(
+        mut subscription: tokio::sync::watch::Receiver<EngineState>,
+        head: fn(&EngineState) -> L2BlockInfo,
+        backpressure: Backpressure,
+        buffer_size: usize,
+        mut send: F,
+    ) -> Result<(), jsonrpsee::core::SubscriptionError>
+    where
+        F: FnMut(L2BlockInfo) -> Fut,
+        Fut: Future<Output = Result<(), jsonrpsee::core::SubscriptionError>>,
+    {
+        let mut current_head = head(&subscription.borrow());
 
-        let mut current_safe_head = subscription.borrow().sync_state.safe_head();
+        match backpressure {
+            Backpressure::Block => {
+                let (tx, mut rx) = tokio::sync::mpsc::channel(buffer_size);
+                let watcher = tokio::spawn(async move {
+                    while let Ok(new_head) = subscription
+                        .wait_for(|state| head(state) != current_head)
+                        .await
+                        .map(|state| head(&state))
+                    {
+                        current_head = new_head;
+                        if tx.send(new_head).await.is_err() {
+                            break;
+                        }
+                    }
+                });
 
-        while let Ok(new_state) = subscription
-            .wait_for(|state| state.sync_state.safe_head() != current_safe_head)
-            .await
-            .map(|state| *state)
-        {
-            info!(target: "rpc::ws", "Sending safe head update: {:?}", new_state.sync_state.safe_head());
-            current_safe_head = new_state.sync_state.safe_head();
-            Self::send_state_update(&sink, current_safe_head).await?;
+                let result = async {
+                    while let Some(update) = rx.recv().await {
+                        send(update).await?;
+                    }
+                    Ok(())
+                }
+                .await;
+
+                watcher.abort();
+                result
+            }
+            Backpressure::DropOldest => {
+                let (tx, mut rx) = tokio::sync::broadcast::channel(buffer_size);
+                let watcher = tokio::spawn(async move {
+                    while let Ok(new_head) = subscription
+                        .wait_for(|state| head(state) != current_head)
+                        .await
+                        .map(|state| head(&state))
+                    {
+                        current_head = new_head;
+                        if tx.send(new_head).is_err() {
+                            break;
+                        }
+                    }
+                });
+
+                let mut dropped = 0;
+                let result = async {
+                    loop {
+                        match rx.recv().await {
+                            Ok(update) => {
+                                send(update).await?;
+                                // The client caught up, so past lag no longer counts against it.
+                                if rx.is_empty() {
+                                    dropped = 0;
+                                }
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped)) => {
+                                dropped += skipped as usize;
+                                warn!(target: "rpc::ws", skipped, dropped, "Subscriber is lagging behind, dropped the oldest head updates.");
+
+                                if dropped > buffer_size {
+                                    return Err(jsonrpsee::core::SubscriptionError::from(format!(
+                                        "Subscription closed: the client fell too far behind and {dropped} head updates were dropped.",
+                                    )));
+                                }
+                            }
+                            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                                return Ok(());
+                            }
+                        }
+                    }
+                }
+                .await;
+
+                watcher.abort();
+                result
+            }
         }
+    }
+}
+
+#[async_trait::async_trait]
+impl WsServer for WsRPC {
+    async fn ws_safe_head_updates(&self, sink: PendingSubscriptionSink) -> SubscriptionResult {
+        self.stream_head_updates(sink, |state| state.sync_state.safe_head(), Backpressure::Block)
+            .await?;
 
         warn!(target: "rpc::ws", "Subscription to safe head updates has been closed.");
         Ok(())
     }
 
     async fn ws_finalized_head_updates(&self, sink: PendingSubscriptionSink) -> SubscriptionResult {
-        let sink = sink.accept().await?;
-
-        let mut subscription = self.engine_state_watcher().await?;
-
-        let mut current_finalized_head = subscription.borrow().sync_state.finalized_head();
-
-        while let Ok(new_state) = subscription
-            .wait_for(|state| state.sync_state.finalized_head() != current_finalized_head)
-            .await
-            .map(|state| *state)
-        {
-            current_finalized_head = new_state.sync_state.finalized_head();
-            Self::send_state_update(&sink, current_finalized_head).await?;
-        }
+        self.stream_head_updates(
+            sink,
+            |state| state.sync_state.finalized_head(),
+            Backpressure::Block,
+        )
+        .await?;
 
         warn!(target: "rpc::ws", "Subscription to finalized head updates has been closed.");
         Ok(())
     }
 
     async fn ws_unsafe_head_updates(&self, sink: PendingSubscriptionSink) -> SubscriptionResult {
-        let sink = sink.accept().await?;
+        self.stream_head_updates(
+            sink,
+            |state| state.sync_state.unsafe_head(),
+            Backpressure::DropOldest,
+        )
+        .await?;
 
-        let mut subscription = self.engine_state_watcher().await?;
+        warn!(target: "rpc::ws", "Subscription to unsafe head updates has been closed.");
+        Ok(())
+    }
+}
 
-        let mut current_unsafe_head = subscription.borrow().sync_state.unsafe_head();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_engine::EngineSyncStateUpdate;
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
 
-        while let Ok(new_state) = subscription
-            .wait_for(|state| state.sync_state.unsafe_head() != current_unsafe_head)
-            .await
-            .map(|state| *state)
-        {
-            current_unsafe_head = new_state.sync_state.unsafe_head();
-            Self::send_state_update(&sink, current_unsafe_head).await?;
-        }
+    const BUFFER_SIZE: usize = 4;
+    const UPDATES: u64 = 1_000;
 
-        warn!(target: "rpc::ws", "Subscription to unsafe head updates has been closed.");
-        Ok(())
+    fn state_with_unsafe_head(number: u64) -> EngineState {
+        let mut state = EngineState::default();
+        let mut head = L2BlockInfo::default();
+        head.block_info.number = number;
+        state.sync_state = state
+            .sync_state
+            .apply_update(EngineSyncStateUpdate { unsafe_head: Some(head), ..Default::default() });
+        state
+    }
+
+    /// Publishes [`UPDATES`] unsafe head changes far faster than the consumer below reads them.
+    fn spawn_fast_producer() -> tokio::sync::watch::Receiver<EngineState> {
+        let (state_tx, state_rx) = tokio::sync::watch::channel(EngineState::default());
+        tokio::spawn(async move {
+            for number in 1..=UPDATES {
+                state_tx.send_replace(state_with_unsafe_head(number));
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        });
+        state_rx
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_consumer_is_closed_when_dropping_oldest() {
+        let received = Arc::new(AtomicUsize::new(0));
+
+        let result = WsRPC::forward_head_updates(
+            spawn_fast_producer(),
+            |state| state.sync_state.unsafe_head(),
+            Backpressure::DropOldest,
+            BUFFER_SIZE,
+            |_| {
+                let received = received.clone();
+                async move {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    received.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                }
+            },
+        )
+        .await;
+
+        let err = result.unwrap_err();
+        assert!(format!("{err:?}").contains("fell too far behind"));
+        assert!(received.load(Ordering::Relaxed) < UPDATES as usize);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dropped_updates_reset_once_caught_up() {
+        // Two bursts, each dropping fewer updates than the buffer holds, but more in total.
+        let (state_tx, state_rx) = tokio::sync::watch::channel(EngineState::default());
+        tokio::spawn(async move {
+            let mut number = 0;
+            for _ in 0..2 {
+                for _ in 0..2 * BUFFER_SIZE {
+                    number += 1;
+                    state_tx.send_replace(state_with_unsafe_head(number));
+                    tokio::time::sleep(Duration::from_millis(1)).await;
+                }
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        });
+
+        let result = WsRPC::forward_head_updates(
+            state_rx,
+            |state| state.sync_state.unsafe_head(),
+            Backpressure::DropOldest,
+            BUFFER_SIZE,
+            |_| async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Ok(())
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_slow_consumer_applies_backpressure() {
+        let mut last = 0;
+
+        let result = WsRPC::forward_head_updates(
+            spawn_fast_producer(),
+            |state| state.sync_state.unsafe_head(),
+            Backpressure::Block,
+            BUFFER_SIZE,
+            |head| {
+                // Updates are coalesced rather than buffered, but always arrive in order.
+                assert!(head.block_info.number > last);
+                last = head.block_info.number;
+                async {
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    Ok(())
+                }
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(last, UPDATES);
     }
 }
@@ -15,6 +15,7 @@ use kona_providers_alloy::{
     AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlineBlobProvider,
     OnlinePipeline,
 };
+use kona_rpc::{DerivationAdminQuery, Heartbeat};
 use op_alloy_network::Optimism;
 use thiserror::Error;
 use tokio::{
@@ -62,6 +63,8 @@ where
     ///
     /// Specs: <https://specs.optimism.io/protocol/derivation.html#l1-sync-payload-attributes-processing>
     derivation_signal_rx: mpsc::Receiver<Signal>,
+    /// The receiver for admin queries, answered in between steps of the derivation pipeline.
+    admin_query_rx: mpsc::Receiver<DerivationAdminQuery>,
 }
 
 /// The state for the derivation actor.
@@ -78,6 +81,9 @@ where
     /// A flag indicating whether or not derivation is waiting for a signal. When waiting for a
     /// signal, derivation cannot process any incoming events.
     pub waiting_for_signal: bool,
+    /// A flag indicating whether or not derivation was paused through the admin API. When paused,
+    /// derivation doesn't consume new L1 data, but still receives signals.
+    pub paused: bool,
 }
 
 /// The size of the cache used in the derivation pipeline's providers.
@@ -112,6 +118,10 @@ pub struct DerivationBuilder {
     pub l1_config: Arc<L1ChainConfig>,
     /// The interop mode.
     pub interop_mode: InteropMode,
+    /// Overrides the channel timeout of the rollup config, in L1 blocks.
+    ///
+    /// Diverges from consensus. Must only be set in tests.
+    pub channel_timeout: Option<u64>,
 }
 
 #[async_trait]
@@ -139,6 +149,7 @@ impl PipelineBuilder for DerivationBuilder {
                 OnlineBlobProvider::init(self.l1_beacon.clone()).await,
                 l1_derivation_provider,
                 l2_derivation_provider,
+                self.channel_timeout,
             ),
             InteropMode::Indexed => OnlinePipeline::new_indexed(
                 self.rollup_config.clone(),
@@ -146,6 +157,7 @@ impl PipelineBuilder for DerivationBuilder {
                 OnlineBlobProvider::init(self.l1_beacon.clone()).await,
                 l1_derivation_provider,
                 l2_derivation_provider,
+                self.channel_timeout,
             ),
         };
 
@@ -169,6 +181,8 @@ pub struct DerivationInboundChannels {
     /// This channel should be used by the engine actor to send [`Signal`]s to the derivation
     /// pipeline. The signals are received by `DerivationActor::derivation_signal_rx`.
     pub derivation_signal_tx: mpsc::Sender<Signal>,
+    /// A sender for admin queries to the derivation actor.
+    pub admin_query_tx: mpsc::Sender<DerivationAdminQuery>,
 }
 
 /// The communication context used by the derivation actor.
@@ -181,6 +195,8 @@ pub struct DerivationContext {
     /// The reset request sender, used to handle [`PipelineErrorKind::Reset`] events and forward
     /// them to the engine.
     pub reset_request_tx: mpsc::Sender<()>,
+    /// Reports the liveness of the actor.
+    pub heartbeat: Heartbeat,
 }
 
 impl CancellableContext for DerivationContext {
@@ -195,7 +211,7 @@ where
 {
     /// Creates a new instance of the [DerivationState].
     pub const fn new(pipeline: P) -> Self {
-        Self { pipeline, derivation_idle: true, waiting_for_signal: false }
+        Self { pipeline, derivation_idle: true, waiting_for_signal: false, paused: false }
     }
 
     /// Handles a [`Signal`] received over the derivation signal receiver channel.
@@ -340,6 +356,9 @@ where
         } else if self.waiting_for_signal {
             trace!(target: "derivation", "Waiting to receive a signal, skipping derivation");
             return Ok(());
+        } else if self.paused {
+            trace!(target: "derivation", "Derivation is paused, skipping derivation");
+            return Ok(());
         }
 
         // If derivation isn't idle and the message hasn't observed a safe head update already,
@@ -408,12 +427,14 @@ where
             watch::channel(L2BlockInfo::default());
         let (el_sync_complete_tx, el_sync_complete_rx) = oneshot::channel();
         let (derivation_signal_tx, derivation_signal_rx) = mpsc::channel(16);
+        let (admin_query_tx, admin_query_rx) = mpsc::channel(16);
         let actor = Self {
             state,
             l1_head_updates: l1_head_updates_rx,
             engine_l2_safe_head: engine_l2_safe_head_rx,
             el_sync_complete_rx,
             derivation_signal_rx,
+            admin_query_rx,
         };
 
         (
@@ -422,6 +443,7 @@ where
                 engine_l2_safe_head_tx,
                 el_sync_complete_tx,
                 derivation_signal_tx,
+                admin_query_tx,
             },
             actor,
         )
@@ -448,11 +470,14 @@ where
             derived_attributes_tx,
             reset_request_tx,
             cancellation,
+            mut heartbeat,
         }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
         let mut state = self.state.build().await;
 
         loop {
+            heartbeat.beat();
+
             select! {
                 biased;
 
@@ -476,6 +501,28 @@ where
                     state.signal(signal).await;
                     state.waiting_for_signal = false;
                 }
+                Some(query) = self.admin_query_rx.recv() => {
+                    match query {
+                        DerivationAdminQuery::PipelineSnapshot(tx) => {
+                            if tx.send(state.pipeline.snapshot()).is_err() {
+                                warn!(target: "derivation", "Failed to send pipeline snapshot");
+                            }
+                        }
+                        DerivationAdminQuery::PauseDerivation => {
+                            info!(target: "derivation", "Pausing derivation");
+                            state.paused = true;
+                            heartbeat.set_paused(true);
+                        }
+                        DerivationAdminQuery::ResumeDerivation => {
+                            info!(target: "derivation", "Resuming derivation");
+                            state.paused = false;
+                            heartbeat.set_paused(false);
+                            // The pipeline kept its state while paused, pick up from its current
+                            // L1 origin.
+                            state.process(InboundDerivationMessage::NewDataAvailable, &mut self.engine_l2_safe_head, &self.el_sync_complete_rx, &derived_attributes_tx, &reset_request_tx).await?;
+                        }
+                    }
+                }
                 msg = self.l1_head_updates.changed() => {
                     if let Err(err) = msg {
                         error!(
@@ -496,6 +543,7 @@ where
                     // Optimistically process the first message.
                     state.process(InboundDerivationMessage::NewDataAvailable, &mut self.engine_l2_safe_head, &self.el_sync_complete_rx, &derived_attributes_tx, &reset_request_tx).await?;
                 }
+                _ = heartbeat.tick() => {}
             }
         }
     }
@@ -530,3 +578,114 @@ pub enum DerivationError {
     #[error("Failed to receive L2 safe head")]
     L2SafeHeadReceiveFailed,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use kona_derive::{OriginProvider, PipelineResult, PipelineSnapshot};
+    use kona_genesis::SystemConfig;
+
+    /// A pipeline preparing payload attributes on every step.
+    #[derive(Debug, Default)]
+    struct TestPipeline {
+        steps: usize,
+        prepared: Option<OpAttributesWithParent>,
+        config: RollupConfig,
+    }
+
+    impl Iterator for TestPipeline {
+        type Item = OpAttributesWithParent;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.prepared.take()
+        }
+    }
+
+    impl OriginProvider for TestPipeline {
+        fn origin(&self) -> Option<BlockInfo> {
+            Some(BlockInfo::default())
+        }
+    }
+
+    #[async_trait]
+    impl SignalReceiver for TestPipeline {
+        async fn signal(&mut self, _: Signal) -> PipelineResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Pipeline for TestPipeline {
+        fn peek(&self) -> Option<&OpAttributesWithParent> {
+            self.prepared.as_ref()
+        }
+
+        async fn step(&mut self, cursor: L2BlockInfo) -> StepResult {
+            self.steps += 1;
+            self.prepared =
+                Some(OpAttributesWithParent::new(Default::default(), cursor, None, false));
+            StepResult::PreparedAttributes
+        }
+
+        fn snapshot(&self) -> PipelineSnapshot {
+            PipelineSnapshot::default()
+        }
+
+        fn rollup_config(&self) -> &RollupConfig {
+            &self.config
+        }
+
+        async fn system_config_by_number(
+            &mut self,
+            _: u64,
+        ) -> Result<SystemConfig, PipelineErrorKind> {
+            Ok(SystemConfig::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pause_resume_derivation() {
+        let mut state = DerivationState::new(TestPipeline::default());
+        let safe_head = L2BlockInfo {
+            block_info: BlockInfo { hash: B256::repeat_byte(1), ..Default::default() },
+            ..Default::default()
+        };
+        let (_safe_head_tx, mut safe_head_rx) = watch::channel(safe_head);
+        let (el_sync_complete_tx, mut el_sync_complete_rx) = oneshot::channel();
+        el_sync_complete_tx.send(()).unwrap();
+        (&mut el_sync_complete_rx).await.unwrap();
+        let (attributes_tx, mut attributes_rx) = mpsc::channel(4);
+        let (reset_request_tx, _reset_request_rx) = mpsc::channel(1);
+
+        // No progress is made while paused.
+        state.paused = true;
+        state
+            .process(
+                InboundDerivationMessage::NewDataAvailable,
+                &mut safe_head_rx,
+                &el_sync_complete_rx,
+                &attributes_tx,
+                &reset_request_tx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(state.pipeline.steps, 0);
+        assert!(attributes_rx.try_recv().is_err());
+
+        // Derivation picks up where it left off once resumed.
+        state.paused = false;
+        state
+            .process(
+                InboundDerivationMessage::NewDataAvailable,
+                &mut safe_head_rx,
+                &el_sync_complete_rx,
+                &attributes_tx,
+                &reset_request_tx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(state.pipeline.steps, 1);
+        assert_eq!(attributes_rx.try_recv().unwrap().parent, safe_head);
+    }
+}
@@ -1,19 +1,19 @@
 //! The [`EngineActor`].
 
 use super::{EngineError, L2Finalizer};
-use alloy_rpc_types_engine::JwtSecret;
 use async_trait::async_trait;
 use futures::future::OptionFuture;
 use kona_derive::{ResetSignal, Signal};
 use kona_engine::{
     BuildTask, ConsolidateTask, Engine, EngineClient, EngineQueries,
     EngineState as InnerEngineState, EngineTask, EngineTaskError, EngineTaskErrorSeverity,
-    InsertTask,
+    InsertTask, ReloadableJwtSecret,
 };
 use kona_genesis::RollupConfig;
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
+use kona_rpc::Heartbeat;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tokio::{
     sync::{mpsc, oneshot, watch},
     task::JoinHandle,
@@ -25,7 +25,9 @@ use crate::{NodeActor, NodeMode, actors::CancellableContext};
 
 /// The [`EngineActor`] is responsible for managing the operations sent to the execution layer's
 /// Engine API. To accomplish this, it uses the [`Engine`] task queue to order Engine API
-/// interactions based off of the [`Ord`] implementation of [`EngineTask`].
+/// interactions based off of the [`EngineTaskPriority`] of each [`EngineTask`].
+///
+/// [`EngineTaskPriority`]: kona_engine::EngineTaskPriority
 #[derive(Debug)]
 pub struct EngineActor {
     /// The [`EngineActorState`] used to build the actor.
@@ -87,11 +89,14 @@ pub struct EngineBuilder {
     /// The L1 rpc url.
     pub l1_rpc_url: Url,
     /// The engine jwt secret.
-    pub jwt_secret: JwtSecret,
+    pub jwt_secret: ReloadableJwtSecret,
     /// The mode of operation for the node.
     /// When the node is in sequencer mode, the engine actor will receive requests to build blocks
     /// from the sequencer actor.
     pub mode: NodeMode,
+    /// The maximum amount of time to wait for the execution layer to return a block built for the
+    /// sequencer. Defaults to the [`BuildTask`]'s timeout if [`None`].
+    pub payload_timeout: Option<Duration>,
 }
 
 impl EngineBuilder {
@@ -105,6 +110,7 @@ impl EngineBuilder {
 
         EngineActorState {
             rollup: self.config,
+            payload_timeout: self.payload_timeout,
             client,
             engine: Engine::new(state, engine_state_send, engine_queue_length_send),
         }
@@ -116,7 +122,7 @@ impl EngineBuilder {
             self.engine_url.clone(),
             self.l1_rpc_url.clone(),
             self.config.clone(),
-            self.jwt_secret,
+            self.jwt_secret.clone(),
         )
         .into()
     }
@@ -127,6 +133,8 @@ impl EngineBuilder {
 pub(super) struct EngineActorState {
     /// The [`RollupConfig`] used to build tasks.
     pub(super) rollup: Arc<RollupConfig>,
+    /// The timeout of the [`BuildTask`]s building blocks for the sequencer.
+    pub(super) payload_timeout: Option<Duration>,
     /// An [`EngineClient`] used for creating engine tasks.
     pub(super) client: Arc<EngineClient>,
     /// The [`Engine`] task queue.
@@ -150,6 +158,8 @@ pub struct EngineContext {
     pub sync_complete_tx: oneshot::Sender<()>,
     /// A way for the engine actor to send a [`Signal`] back to the derivation actor.
     pub derivation_signal_tx: mpsc::Sender<Signal>,
+    /// Reports the liveness of the actor.
+    pub heartbeat: Heartbeat,
 }
 
 impl CancellableContext for EngineContext {
@@ -377,6 +387,7 @@ impl NodeActor for EngineActor {
             sync_complete_tx,
             derivation_signal_tx,
             mut engine_unsafe_head_tx,
+            mut heartbeat,
         }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
         let mut state = self.builder.build_state();
@@ -389,6 +400,8 @@ impl NodeActor for EngineActor {
         let mut sync_complete_tx = Some(sync_complete_tx);
 
         loop {
+            heartbeat.beat();
+
             // Attempt to drain all outstanding tasks from the engine queue before adding new ones.
             state
                 .drain(
@@ -435,15 +448,18 @@ impl NodeActor for EngineActor {
                         return Err(EngineError::ChannelClosed);
                     };
 
-                    let task = EngineTask::Build(Box::new(BuildTask::new(
+                    let mut task = BuildTask::new(
                         state.client.clone(),
                         state.rollup.clone(),
                         attributes,
                         // The payload is not derived in this case.
                         false,
                         Some(response_tx),
-                    )));
-                    state.engine.enqueue(task);
+                    );
+                    if let Some(payload_timeout) = state.payload_timeout {
+                        task = task.with_payload_timeout(payload_timeout);
+                    }
+                    state.engine.enqueue(EngineTask::Build(Box::new(task)));
                 }
                 unsafe_block = self.unsafe_block_rx.recv() => {
                     let Some(envelope) = unsafe_block else {
@@ -485,6 +501,7 @@ impl NodeActor for EngineActor {
                     // chain.
                     self.finalizer.try_finalize_next(&mut state).await;
                 }
+                _ = heartbeat.tick() => {}
             }
         }
     }
@@ -1,9 +1,9 @@
 use alloy_primitives::Address;
 use async_trait::async_trait;
-use kona_gossip::P2pRpcRequest;
-use kona_rpc::NetworkAdminQuery;
+use kona_gossip::{ConnectionGate, P2pRpcRequest};
+use kona_rpc::{Heartbeat, NetworkAdminQuery};
 use kona_sources::BlockSignerError;
-use libp2p::TransportError;
+use libp2p::{TransportError, swarm::SwarmEvent};
 use op_alloy_rpc_types_engine::{OpExecutionPayloadEnvelope, OpNetworkPayloadEnvelope};
 use thiserror::Error;
 use tokio::{self, select, sync::mpsc};
@@ -13,6 +13,7 @@ use crate::{
     CancellableContext, NodeActor,
     actors::network::{
         builder::NetworkBuilder, driver::NetworkDriverError, error::NetworkBuilderError,
+        handler::NetworkHandler,
     },
 };
 
@@ -103,6 +104,8 @@ pub struct NetworkContext {
     pub blocks: mpsc::Sender<OpExecutionPayloadEnvelope>,
     /// Cancels the network actor.
     pub cancellation: CancellationToken,
+    /// Reports the liveness of the actor.
+    pub heartbeat: Heartbeat,
 }
 
 impl CancellableContext for NetworkContext {
@@ -137,6 +140,78 @@ pub enum NetworkActorError {
     FailedToSignPayload(#[from] BlockSignerError),
 }
 
+/// Forwards an unsafe block received from the gossip layer or the admin API.
+async fn forward_block(
+    blocks: &mpsc::Sender<OpExecutionPayloadEnvelope>,
+    block: Option<OpExecutionPayloadEnvelope>,
+) -> Result<(), NetworkActorError> {
+    let Some(block) = block else {
+        error!(target: "node::p2p", "The unsafe block receiver channel has closed");
+        return Err(NetworkActorError::ChannelClosed);
+    };
+
+    if blocks.send(block).await.is_err() {
+        warn!(target: "network", "Failed to forward unsafe block");
+        return Err(NetworkActorError::ChannelClosed);
+    }
+
+    Ok(())
+}
+
+/// Signs an unsafe block produced locally and publishes it through the gossip layer.
+async fn publish_block(
+    handler: &mut NetworkHandler,
+    block: OpExecutionPayloadEnvelope,
+) -> Result<(), NetworkActorError> {
+    let timestamp = block.execution_payload.timestamp();
+    let selector = |handler: &kona_gossip::BlockHandler| handler.topic(timestamp);
+    let Some(signer) = handler.signer.as_ref() else {
+        warn!(target: "net", "No local signer available to sign the payload");
+        return Ok(());
+    };
+
+    let chain_id = handler.discovery.chain_id;
+
+    let sender_address = *handler.unsafe_block_signer_sender.borrow();
+
+    let payload_hash = block.payload_hash();
+    let signature = signer.sign_block(payload_hash, chain_id, sender_address).await?;
+
+    let payload = OpNetworkPayloadEnvelope {
+        payload: block.execution_payload,
+        parent_beacon_block_root: block.parent_beacon_block_root,
+        signature,
+        payload_hash,
+    };
+
+    match handler.gossip.publish(selector, Some(payload)) {
+        Ok(id) => info!("Published unsafe payload | {:?}", id),
+        Err(e) => warn!("Failed to publish unsafe payload: {:?}", e),
+    }
+
+    Ok(())
+}
+
+/// Handles an event of the gossip swarm, queueing the unsafe block it carries to be forwarded.
+fn handle_gossip_event(
+    handler: &mut NetworkHandler,
+    unsafe_block_tx: &mpsc::UnboundedSender<OpExecutionPayloadEnvelope>,
+    event: Option<SwarmEvent<kona_gossip::Event>>,
+) -> Result<(), NetworkActorError> {
+    let Some(event) = event else {
+        error!(target: "node::p2p", "The gossip swarm stream has ended");
+        return Err(NetworkActorError::ChannelClosed);
+    };
+
+    if let Some(payload) = handler.gossip.handle_event(event) {
+        if unsafe_block_tx.send(payload.into()).is_err() {
+            warn!(target: "node::p2p", "Failed to send unsafe block to network handler");
+        }
+    }
+
+    Ok(())
+}
+
 #[async_trait]
 impl NodeActor for NetworkActor {
     type Error = NetworkActorError;
@@ -150,14 +225,41 @@ impl NodeActor for NetworkActor {
 
     async fn start(
         mut self,
-        NetworkContext { blocks, cancellation }: Self::OutboundData,
+        NetworkContext { blocks, cancellation, mut heartbeat }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
         let mut handler = self.builder.build()?.start().await?;
 
         // New unsafe block channel.
-        let (unsafe_block_tx, mut unsafe_block_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (unsafe_block_tx, mut unsafe_block_rx) = mpsc::unbounded_channel();
 
         loop {
+            heartbeat.beat();
+
+            // If block propagation is prioritized, the block arms are serviced first, as long as
+            // any of them is ready. Discovery maintenance and the other arms only run once none is.
+            if handler.prioritize_blocks {
+                let serviced = select! {
+                    biased;
+                    _ = cancellation.cancelled() => false,
+                    block = unsafe_block_rx.recv() => {
+                        forward_block(&blocks, block).await?;
+                        true
+                    }
+                    Some(block) = self.publish_rx.recv(), if !self.publish_rx.is_closed() => {
+                        publish_block(&mut handler, block).await?;
+                        true
+                    }
+                    event = handler.gossip.next() => {
+                        handle_gossip_event(&mut handler, &unsafe_block_tx, event)?;
+                        true
+                    }
+                    _ = std::future::ready(()) => false,
+                };
+                if serviced {
+                    continue;
+                }
+            }
+
             select! {
                 _ = cancellation.cancelled() => {
                     info!(
@@ -167,15 +269,7 @@ impl NodeActor for NetworkActor {
                     return Ok(());
                 }
                 block = unsafe_block_rx.recv() => {
-                    let Some(block) = block else {
-                        error!(target: "node::p2p", "The unsafe block receiver channel has closed");
-                        return Err(NetworkActorError::ChannelClosed);
-                    };
-
-                    if blocks.send(block).await.is_err() {
-                        warn!(target: "network", "Failed to forward unsafe block");
-                        return Err(NetworkActorError::ChannelClosed);
-                    }
+                    forward_block(&blocks, block).await?;
                 }
                 signer = self.signer.recv() => {
                     let Some(signer) = signer else {
@@ -193,45 +287,10 @@ impl NodeActor for NetworkActor {
                     }
                 }
                 Some(block) = self.publish_rx.recv(), if !self.publish_rx.is_closed() => {
-                    let timestamp = block.execution_payload.timestamp();
-                    let selector = |handler: &kona_gossip::BlockHandler| {
-                        handler.topic(timestamp)
-                    };
-                    let Some(signer) = handler.signer.as_ref() else {
-                        warn!(target: "net", "No local signer available to sign the payload");
-                        continue;
-                    };
-
-                    let chain_id = handler.discovery.chain_id;
-
-                    let sender_address = *handler.unsafe_block_signer_sender.borrow();
-
-                    let payload_hash = block.payload_hash();
-                    let signature = signer.sign_block(payload_hash, chain_id, sender_address).await?;
-
-                    let payload = OpNetworkPayloadEnvelope {
-                        payload: block.execution_payload,
-                        parent_beacon_block_root: block.parent_beacon_block_root,
-                        signature,
-                        payload_hash,
-                    };
-
-                    match handler.gossip.publish(selector, Some(payload)) {
-                        Ok(id) => info!("Published unsafe payload | {:?}", id),
-                        Err(e) => warn!("Failed to publish unsafe payload: {:?}", e),
-                    }
+                    publish_block(&mut handler, block).await?;
                 }
                 event = handler.gossip.next() => {
-                    let Some(event) = event else {
-                        error!(target: "node::p2p", "The gossip swarm stream has ended");
-                        return Err(NetworkActorError::ChannelClosed);
-                    };
-
-                    if let Some(payload) = handler.gossip.handle_event(event) {
-                        if unsafe_block_tx.send(payload.into()).is_err() {
-                            warn!(target: "node::p2p", "Failed to send unsafe block to network handler");
-                        }
-                    }
+                    handle_gossip_event(&mut handler, &unsafe_block_tx, event)?;
                 },
                 enr = handler.enr_receiver.recv() => {
                     let Some(enr) = enr else {
@@ -243,15 +302,26 @@ impl NodeActor for NetworkActor {
                 _ = handler.peer_score_inspector.tick(), if handler.gossip.peer_monitoring.as_ref().is_some() => {
                     handler.handle_peer_monitoring().await;
                 },
-                Some(NetworkAdminQuery::PostUnsafePayload { payload }) = self.admin_rpc.recv(), if !self.admin_rpc.is_closed() => {
-                    debug!(target: "node::p2p", "Broadcasting unsafe payload from admin api");
-                    if unsafe_block_tx.send(payload).is_err() {
-                        warn!(target: "node::p2p", "Failed to send unsafe block to network handler");
+                Some(query) = self.admin_rpc.recv(), if !self.admin_rpc.is_closed() => {
+                    match query {
+                        NetworkAdminQuery::PostUnsafePayload { payload } => {
+                            debug!(target: "node::p2p", "Broadcasting unsafe payload from admin api");
+                            if unsafe_block_tx.send(payload).is_err() {
+                                warn!(target: "node::p2p", "Failed to send unsafe block to network handler");
+                            }
+                        }
+                        NetworkAdminQuery::BannedPeers(tx) => {
+                            let banned = handler.gossip.connection_gate.list_banned_peers();
+                            if tx.send(banned).is_err() {
+                                warn!(target: "node::p2p", "Failed to send banned peers through response channel");
+                            }
+                        }
                     }
                 },
                 Some(req) = self.p2p_rpc.recv(), if !self.p2p_rpc.is_closed() => {
                     req.handle(&mut handler.gossip, &handler.discovery);
                 },
+                _ = heartbeat.tick() => {},
             }
         }
     }
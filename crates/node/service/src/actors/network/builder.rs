@@ -29,6 +29,8 @@ pub struct NetworkBuilder {
     /// This may be set to false if the node is configured to use a static advertised address (when
     /// used with a nat for example).
     pub(super) enr_update: bool,
+    /// Whether to service block propagation before discovery maintenance.
+    pub(super) prioritize_blocks: bool,
 }
 
 impl From<NetworkConfig> for NetworkBuilder {
@@ -52,6 +54,7 @@ impl From<NetworkConfig> for NetworkBuilder {
         .with_peer_monitoring(config.monitor_peers)
         .with_topic_scoring(config.topic_scoring)
         .with_gater_config(config.gater_config)
+        .with_block_priority(config.prioritize_blocks)
     }
 }
 
@@ -80,6 +83,7 @@ impl NetworkBuilder {
             ),
             signer,
             enr_update: true,
+            prioritize_blocks: false,
         }
     }
 
@@ -88,6 +92,11 @@ impl NetworkBuilder {
         Self { enr_update, ..self }
     }
 
+    /// Sets whether block propagation is serviced before discovery maintenance.
+    pub fn with_block_priority(self, prioritize_blocks: bool) -> Self {
+        Self { prioritize_blocks, ..self }
+    }
+
     /// Sets the configuration for the connection gater.
     pub fn with_gater_config(self, config: GaterConfig) -> Self {
         Self { gossip: self.gossip.with_gater_config(config), ..self }
@@ -169,6 +178,7 @@ impl NetworkBuilder {
             unsafe_block_signer_sender,
             signer: self.signer,
             enr_update: self.enr_update,
+            prioritize_blocks: self.prioritize_blocks,
         })
     }
 }
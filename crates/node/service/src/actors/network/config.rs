@@ -48,6 +48,9 @@ pub struct NetworkConfig {
     pub rollup_config: RollupConfig,
     /// A signer for gossip payloads.
     pub gossip_signer: Option<BlockSigner>,
+    /// Whether to service block propagation before discovery maintenance. This reduces block
+    /// propagation latency under CPU pressure, at the cost of slower peer discovery.
+    pub prioritize_blocks: bool,
 }
 
 impl NetworkConfig {
@@ -96,6 +99,7 @@ impl NetworkConfig {
             topic_scoring: Default::default(),
             monitor_peers: Default::default(),
             gossip_signer: Default::default(),
+            prioritize_blocks: Default::default(),
         }
     }
 }
@@ -27,6 +27,8 @@ pub struct NetworkDriver {
     pub unsafe_block_signer_sender: watch::Sender<Address>,
     /// A block signer. This is optional and should be set if the node is configured to sign blocks
     pub signer: Option<BlockSigner>,
+    /// Whether to service block propagation before discovery maintenance.
+    pub prioritize_blocks: bool,
 }
 
 /// An error from the [`NetworkDriver`].
@@ -92,6 +94,7 @@ impl NetworkDriver {
             unsafe_block_signer_sender: self.unsafe_block_signer_sender,
             peer_score_inspector,
             signer,
+            prioritize_blocks: self.prioritize_blocks,
         })
     }
 }
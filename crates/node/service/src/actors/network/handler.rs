@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use alloy_primitives::Address;
 use discv5::Enr;
 use kona_disc::{Discv5Handler, HandlerRequest};
-use kona_gossip::{ConnectionGater, GossipDriver};
+use kona_gossip::{BanReason, ConnectionGate, ConnectionGater, GossipDriver};
 use kona_sources::BlockSignerHandler;
 use tokio::sync::{mpsc, watch};
 
@@ -22,15 +22,20 @@ pub struct NetworkHandler {
     pub peer_score_inspector: tokio::time::Interval,
     /// A handler for the block signer.
     pub signer: Option<BlockSignerHandler>,
+    /// Whether to service block propagation before discovery maintenance.
+    pub prioritize_blocks: bool,
 }
 
 impl NetworkHandler {
     pub(super) async fn handle_peer_monitoring(&mut self) {
         // Inspect peer scores and ban peers that are below the threshold.
-        let Some(ban_peers) = self.gossip.peer_monitoring.as_ref() else {
+        let Some(ban_peers) = self.gossip.peer_monitoring.clone() else {
             return;
         };
 
+        // Shrink the penalties forgiven to unbanned peers before inspecting scores.
+        self.gossip.decay_forgiven_penalties();
+
         // We iterate over all connected peers and check their scores.
         // We collect a list of peers to remove
         let peers_to_remove = self
@@ -64,6 +69,7 @@ impl NetworkHandler {
                         // In that case, we ban the peer. This means...
                         // 1. We remove the peer from the network gossip.
                         // 2. We ban the peer from the discv5 service.
+                        self.gossip.connection_gate.ban_peer(&peer_to_remove, BanReason::GossipScore, Some(ban_peers.ban_duration));
                         if self.gossip.swarm.disconnect_peer_id(peer_to_remove).is_err() {
                             warn!(peer = ?peer_to_remove, "Trying to disconnect a non-existing peer from the gossip driver.");
                         }
@@ -79,7 +85,6 @@ impl NetworkHandler {
                         }
 
                         if let Some(info) = self.gossip.peerstore.remove(&peer_to_remove){
-                            use kona_gossip::ConnectionGate;
                             self.gossip.connection_gate.remove_dial(&peer_to_remove);
                             let score = self.gossip.swarm.behaviour().gossipsub.peer_score(&peer_to_remove).unwrap_or_default();
                             kona_macros::inc!(gauge, kona_gossip::Metrics::BANNED_PEERS, "peer_id" => peer_to_remove.to_string(), "score" => score.to_string());
@@ -103,3 +108,122 @@ impl NetworkHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_genesis::RollupConfig;
+    use kona_gossip::P2pRpcRequest;
+    use kona_peers::{PeerMonitoring, PeerScoreLevel, p2p_id_to_node_id};
+    use libp2p::{identity::Keypair, multiaddr::Protocol};
+    use std::time::Duration;
+
+    async fn started_driver(monitoring: bool) -> GossipDriver<ConnectionGater> {
+        let mut builder = GossipDriver::<ConnectionGater>::builder(
+            RollupConfig { block_time: 2, ..Default::default() },
+            Address::ZERO,
+            "/ip4/127.0.0.1/tcp/0".parse().unwrap(),
+            Keypair::generate_secp256k1(),
+        );
+        if monitoring {
+            builder = builder
+                .with_peer_scoring(PeerScoreLevel::Light)
+                .with_topic_scoring(true)
+                .with_peer_monitoring(Some(PeerMonitoring {
+                    ban_threshold: -100.0,
+                    ban_duration: Duration::from_secs(60),
+                }));
+        }
+        let (mut driver, _) = builder.build().unwrap();
+        driver.start().await.unwrap();
+        driver
+    }
+
+    fn dial_addr(driver: &GossipDriver<ConnectionGater>) -> libp2p::Multiaddr {
+        let mut addr = driver.addr.clone();
+        addr.push(Protocol::P2p(*driver.local_peer_id()));
+        addr
+    }
+
+    /// Drives both swarms until `done` holds.
+    async fn drive_until(
+        handler: &mut NetworkHandler,
+        peer: &mut GossipDriver<ConnectionGater>,
+        mut done: impl FnMut(&NetworkHandler, &GossipDriver<ConnectionGater>) -> bool,
+    ) {
+        tokio::time::timeout(Duration::from_secs(30), async {
+            while !done(handler, peer) {
+                tokio::select! {
+                    Some(event) = handler.gossip.next() => {
+                        handler.gossip.handle_event(event);
+                    }
+                    Some(event) = peer.next() => {
+                        peer.handle_event(event);
+                    }
+                }
+            }
+        })
+        .await
+        .expect("swarms did not reach the expected state");
+    }
+
+    #[tokio::test]
+    async fn test_unbanned_peer_survives_monitoring_tick() {
+        let (disc_tx, mut disc_rx) = mpsc::channel(16);
+        let (_enr_tx, enr_receiver) = mpsc::channel(1);
+        let mut handler = NetworkHandler {
+            gossip: started_driver(true).await,
+            discovery: Discv5Handler::new(0, disc_tx),
+            enr_receiver,
+            unsafe_block_signer_sender: watch::channel(Address::ZERO).0,
+            peer_score_inspector: tokio::time::interval(Duration::from_secs(1)),
+            signer: None,
+            prioritize_blocks: false,
+        };
+        let mut peer = started_driver(false).await;
+        let peer_id = *peer.local_peer_id();
+        let topic = handler.gossip.handler.blocks_v3_topic.hash();
+
+        // Once in the mesh, the peer propagates an undecodable block, which is penalized by the
+        // topic score.
+        peer.dial_multiaddr(dial_addr(&handler.gossip));
+        drive_until(&mut handler, &mut peer, |_, peer| {
+            peer.swarm.behaviour().gossipsub.mesh_peers(&topic).next().is_some()
+        })
+        .await;
+        peer.swarm.behaviour_mut().gossipsub.publish(topic.clone(), vec![0xFF; 32]).unwrap();
+        drive_until(&mut handler, &mut peer, |handler, _| {
+            let score = handler.gossip.swarm.behaviour().gossipsub.peer_score(&peer_id);
+            score.unwrap_or_default() < -100.0
+        })
+        .await;
+
+        // The monitoring tick bans the peer.
+        handler.handle_peer_monitoring().await;
+        assert_eq!(handler.gossip.connection_gate.list_banned_peers().len(), 1);
+        drive_until(&mut handler, &mut peer, |handler, _| handler.gossip.connected_peers() == 0)
+            .await;
+
+        // Unbanning lifts the gossip and discovery bans, and forgives the retained score.
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        P2pRpcRequest::UnbanPeer { id: peer_id, out: tx }
+            .handle(&mut handler.gossip, &handler.discovery);
+        assert!(rx.await.unwrap());
+        let node_id = loop {
+            if let HandlerRequest::UnbanNode(node_id) = disc_rx.recv().await.unwrap() {
+                break node_id;
+            }
+        };
+        assert_eq!(Some(node_id), p2p_id_to_node_id(&peer_id));
+
+        // The peer connects again with its retained score, and survives the next monitoring tick.
+        handler.gossip.dial_multiaddr(dial_addr(&peer));
+        drive_until(&mut handler, &mut peer, |handler, _| handler.gossip.connected_peers() == 1)
+            .await;
+        handler.handle_peer_monitoring().await;
+        assert!(handler.gossip.connection_gate.list_banned_peers().is_empty());
+        assert_eq!(handler.gossip.connected_peers(), 1);
+        let score = handler.gossip.swarm.behaviour().gossipsub.peer_score(&peer_id).unwrap();
+        assert!(score > -100.0);
+    }
+}
@@ -4,15 +4,21 @@ use crate::{NodeActor, actors::CancellableContext};
 use async_trait::async_trait;
 use kona_gossip::P2pRpcRequest;
 use kona_rpc::{
-    AdminApiServer, AdminRpc, DevEngineApiServer, DevEngineRpc, HealthzResponse, NetworkAdminQuery,
-    OpP2PApiServer, RollupNodeApiServer, SequencerAdminQuery, WsRPC, WsServer,
+    AdminApiServer, AdminRpc, DerivationAdminQuery, DevEngineApiServer, DevEngineRpc,
+    HealthRegistry, NetworkAdminQuery, OpP2PApiServer, RollupNodeApiServer, SequencerAdminQuery,
+    WsRPC, WsServer,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
 };
-use std::time::Duration;
 
 use jsonrpsee::{
     RpcModule,
-    core::RegisterMethodError,
-    server::{Server, ServerHandle, middleware::http::ProxyGetRequestLayer},
+    core::{BoxError, RegisterMethodError},
+    server::{HttpBody, HttpRequest, HttpResponse, Server, ServerHandle},
 };
 use kona_engine::EngineQueries;
 use kona_rpc::{L1WatcherQueries, P2pRpc, RollupRpc, RpcBuilder};
@@ -59,10 +65,14 @@ pub struct RpcContext {
     pub network_admin: mpsc::Sender<NetworkAdminQuery>,
     /// The sequencer admin rpc sender.
     pub sequencer_admin: Option<mpsc::Sender<SequencerAdminQuery>>,
+    /// The derivation admin rpc sender.
+    pub derivation_admin: mpsc::Sender<DerivationAdminQuery>,
     /// The l1 watcher queries sender.
     pub l1_watcher_queries: mpsc::Sender<L1WatcherQueries>,
     /// The engine query sender.
     pub engine_query: mpsc::Sender<EngineQueries>,
+    /// The registry of the node's component heartbeats, served by the healthz endpoint.
+    pub health: HealthRegistry,
     /// The cancellation token, shared between all tasks.
     pub cancellation: CancellationToken,
 }
@@ -73,6 +83,65 @@ impl CancellableContext for RpcContext {
     }
 }
 
+/// The application version reported by the healthz endpoint.
+const VERSION: &str = std::env!("CARGO_PKG_VERSION");
+
+/// A [`tower::Layer`] serving `GET /healthz` from the [`HealthRegistry`].
+///
+/// Responds with `200 OK` if all components are live, and `503 Service Unavailable` if any of them
+/// is stalled, so that orchestrators can restart wedged nodes.
+#[derive(Debug, Clone)]
+struct HealthzLayer {
+    health: HealthRegistry,
+}
+
+impl<S> tower::Layer<S> for HealthzLayer {
+    type Service = HealthzService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        HealthzService { inner, health: self.health.clone() }
+    }
+}
+
+/// The [`tower::Service`] built by the [`HealthzLayer`].
+#[derive(Debug, Clone)]
+struct HealthzService<S> {
+    inner: S,
+    health: HealthRegistry,
+}
+
+impl<S, B> tower::Service<HttpRequest<B>> for HealthzService<S>
+where
+    S: tower::Service<HttpRequest<B>, Response = HttpResponse>,
+    S::Error: Into<BoxError> + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, req: HttpRequest<B>) -> Self::Future {
+        if req.method().as_str() != "GET" || req.uri().path() != "/healthz" {
+            let fut = self.inner.call(req);
+            return Box::pin(async move { fut.await.map_err(Into::into) });
+        }
+
+        let report = self.health.report(VERSION.to_string());
+        let status = if report.healthy { 200 } else { 503 };
+        Box::pin(async move {
+            let body = serde_json::to_string(&report)?;
+            Ok(HttpResponse::<()>::builder()
+                .status(status)
+                .header("content-type", "application/json")
+                .body(HttpBody::from(body))?)
+        })
+    }
+}
+
 /// Launches the jsonrpsee [`Server`].
 ///
 /// If the RPC server is disabled, this will return `Ok(None)`.
@@ -83,13 +152,10 @@ impl CancellableContext for RpcContext {
 async fn launch(
     config: &RpcBuilder,
     module: RpcModule<()>,
+    health: HealthRegistry,
 ) -> Result<ServerHandle, std::io::Error> {
-    let middleware = tower::ServiceBuilder::new()
-        .layer(
-            ProxyGetRequestLayer::new([("/healthz", "healthz")])
-                .expect("Critical: Failed to build GET method proxy"),
-        )
-        .timeout(Duration::from_secs(2));
+    let middleware =
+        tower::ServiceBuilder::new().layer(HealthzLayer { health }).timeout(Duration::from_secs(2));
     let server = Server::builder().set_http_middleware(middleware).build(config.socket).await?;
 
     if let Ok(addr) = server.local_addr() {
@@ -121,12 +187,15 @@ impl NodeActor for RpcActor {
             engine_query,
             network_admin,
             sequencer_admin,
+            derivation_admin,
+            health,
         }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
         let mut modules = RpcModule::new(());
 
-        modules.register_method("healthz", |_, _, _| {
-            let response = HealthzResponse { version: std::env!("CARGO_PKG_VERSION").to_string() };
+        let healthz = health.clone();
+        modules.register_method("healthz", move |_, _, _| {
+            let response = healthz.report(VERSION.to_string());
             jsonrpsee::core::RpcResult::Ok(response)
         })?;
 
@@ -135,8 +204,12 @@ impl NodeActor for RpcActor {
 
         // Build the admin rpc module.
         modules.merge(
-            AdminRpc { sequencer_sender: sequencer_admin, network_sender: network_admin }
-                .into_rpc(),
+            AdminRpc {
+                sequencer_sender: sequencer_admin,
+                network_sender: network_admin,
+                derivation_sender: derivation_admin,
+            }
+            .into_rpc(),
         )?;
 
         // Create context for communication between actors.
@@ -146,21 +219,29 @@ impl NodeActor for RpcActor {
         // Add development RPC module for engine state introspection if enabled
         if self.config.dev_enabled() {
             let dev_rpc = DevEngineRpc::new(engine_query.clone());
-            modules.merge(dev_rpc.into_rpc())?;
+            modules.merge(DevEngineApiServer::into_rpc(dev_rpc))?;
+
+            #[cfg(feature = "dev")]
+            {
+                let dev_payload_rpc = DevEngineRpc::new(engine_query.clone());
+                modules.merge(kona_rpc::DevPayloadApiServer::into_rpc(dev_payload_rpc))?;
+            }
         }
 
         if self.config.ws_enabled() {
-            modules.merge(WsRPC::new(engine_query).into_rpc())?;
+            modules.merge(
+                WsRPC::new(engine_query).with_buffer_size(self.config.ws_buffer_size()).into_rpc(),
+            )?;
         }
 
         let restarts = self.config.restart_count();
 
-        let mut handle = launch(&self.config, modules.clone()).await?;
+        let mut handle = launch(&self.config, modules.clone(), health.clone()).await?;
 
         for _ in 0..=restarts {
             tokio::select! {
                 _ = handle.clone().stopped() => {
-                    match launch(&self.config, modules.clone()).await {
+                    match launch(&self.config, modules.clone(), health.clone()).await {
                         Ok(h) => handle = h,
                         Err(err) => {
                             error!(target: "rpc", ?err, "Failed to launch rpc server");
@@ -198,9 +279,10 @@ mod tests {
             enable_admin: false,
             admin_persistence: None,
             ws_enabled: false,
+            ws_buffer_size: WsRPC::DEFAULT_BUFFER_SIZE,
             dev_enabled: false,
         };
-        let result = launch(&launcher, RpcModule::new(())).await;
+        let result = launch(&launcher, RpcModule::new(()), HealthRegistry::default()).await;
         assert!(result.is_ok());
     }
 
@@ -212,6 +294,7 @@ mod tests {
             enable_admin: false,
             admin_persistence: None,
             ws_enabled: false,
+            ws_buffer_size: WsRPC::DEFAULT_BUFFER_SIZE,
             dev_enabled: false,
         };
         let mut modules = RpcModule::new(());
@@ -220,7 +303,7 @@ mod tests {
         modules.merge(RpcModule::new(())).expect("module merge");
         modules.merge(RpcModule::new(())).expect("module merge");
 
-        let result = launch(&launcher, modules).await;
+        let result = launch(&launcher, modules, HealthRegistry::default()).await;
         assert!(result.is_ok());
     }
 }
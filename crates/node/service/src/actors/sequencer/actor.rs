@@ -2,6 +2,7 @@
 
 use super::{
     DelayedL1OriginSelectorProvider, L1OriginSelector, L1OriginSelectorError, SequencerConfig,
+    drift::DriftMonitor,
 };
 use crate::{CancellableContext, NodeActor, actors::sequencer::conductor::ConductorClient};
 use alloy_provider::RootProvider;
@@ -10,12 +11,12 @@ use kona_derive::{AttributesBuilder, PipelineErrorKind, StatefulAttributesBuilde
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use kona_providers_alloy::{AlloyChainProvider, AlloyL2ChainProvider};
-use kona_rpc::SequencerAdminQuery;
+use kona_rpc::{Heartbeat, SequencerAdminQuery};
 use op_alloy_network::Optimism;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 use std::{
     sync::Arc,
-    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant},
 };
 use tokio::{
     select,
@@ -49,6 +50,8 @@ pub(super) struct SequencerActorState<AB: AttributesBuilder> {
     pub build_ticker: tokio::time::Interval,
     /// The conductor RPC client.
     pub conductor: Option<ConductorClient>,
+    /// The [`DriftMonitor`], checking the sequencer's clock against the selected L1 origin.
+    pub drift_monitor: DriftMonitor,
     /// Whether the sequencer is active. This is used inside communications between the sequencer
     /// and the op-conductor to activate/deactivate the sequencer when leader election occurs.
     ///
@@ -81,6 +84,10 @@ impl SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2Ch
             sequencer_recovery_mode,
             conductor_rpc_url,
             l1_conf_delay,
+            max_clock_drift,
+            clamp_clock,
+            // applied by the engine actor, which builds the sequenced blocks
+            payload_timeout: _,
         } = seq_builder.seq_cfg.clone();
 
         let cfg = seq_builder.rollup_cfg.clone();
@@ -102,6 +109,7 @@ impl SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2Ch
             origin_selector,
             build_ticker,
             conductor,
+            drift_monitor: DriftMonitor::new(max_clock_drift, clamp_clock),
             is_active: !sequencer_stopped,
             is_recovery_mode: sequencer_recovery_mode,
         }
@@ -179,6 +187,8 @@ pub struct SequencerContext {
     /// A sender to asynchronously sign and gossip built [`OpExecutionPayloadEnvelope`]s to the
     /// network actor.
     pub gossip_payload_tx: mpsc::Sender<OpExecutionPayloadEnvelope>,
+    /// Reports the liveness of the actor.
+    pub heartbeat: Heartbeat,
 }
 
 impl CancellableContext for SequencerContext {
@@ -386,9 +396,16 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
             );
         }
 
-        let now =
-            SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs();
-        let then = payload.execution_payload.timestamp() + self.cfg.block_time;
+        self.schedule_next_build(&l1_origin, payload.execution_payload.timestamp());
+
+        self.schedule_gossip(ctx, payload).await
+    }
+
+    /// Schedules the build of the block following the one at `timestamp`, building it
+    /// immediately if the sequencer's clock has already caught up with it.
+    fn schedule_next_build(&mut self, l1_origin: &BlockInfo, timestamp: u64) {
+        let now = self.drift_monitor.read(&self.cfg, l1_origin).clock;
+        let then = timestamp + self.cfg.block_time;
         if then.saturating_sub(now) <= self.cfg.block_time {
             warn!(
                 target: "sequencer",
@@ -396,8 +413,6 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
             );
             self.build_ticker.reset_immediately();
         }
-
-        self.schedule_gossip(ctx, payload).await
     }
 
     /// Waits for the next payload to be built and returns it, if there is a payload receiver
@@ -490,6 +505,8 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
         state.schedule_initial_reset(&mut ctx, &mut self.unsafe_head_rx).await?;
 
         loop {
+            ctx.heartbeat.beat();
+
             select! {
                 // We are using a biased select here to ensure that the admin queries are given priority over the block building task.
                 // This is important to limit the occurrence of race conditions where a stopped query is received when a sequencer is building a new block.
@@ -522,7 +539,104 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
                 _ = state.build_ticker.tick(), if state.is_active => {
                     state.build_block(&mut ctx, &mut self.unsafe_head_rx, state.is_recovery_mode).await?;
                 }
+                _ = ctx.heartbeat.tick() => {}
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::Bytes;
+    use futures::FutureExt;
+    use kona_derive::PipelineResult;
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+    const L1_ORIGIN_TIMESTAMP: u64 = 1_000;
+    const MAX_SEQUENCER_DRIFT: u64 = 600;
+
+    /// An [`AttributesBuilder`] that is never called.
+    #[derive(Debug)]
+    struct UnusedAttributesBuilder;
+
+    #[async_trait]
+    impl AttributesBuilder for UnusedAttributesBuilder {
+        async fn prepare_payload_attributes(
+            &mut self,
+            _: L2BlockInfo,
+            _: BlockNumHash,
+            _: Option<Bytes>,
+        ) -> PipelineResult<OpPayloadAttributes> {
+            unreachable!("attributes are not built in these tests")
+        }
+    }
+
+    fn state(drift_monitor: DriftMonitor) -> SequencerActorState<UnusedAttributesBuilder> {
+        let cfg = Arc::new(RollupConfig {
+            block_time: 2,
+            max_sequencer_drift: MAX_SEQUENCER_DRIFT,
+            ..Default::default()
+        });
+        let l1_provider = DelayedL1OriginSelectorProvider::new(
+            RootProvider::new_http("http://127.0.0.1:8545".parse().unwrap()),
+            watch::channel(None).1,
+            0,
+        );
+
+        SequencerActorState {
+            origin_selector: L1OriginSelector::new(cfg.clone(), l1_provider),
+            build_ticker: tokio::time::interval(Duration::from_secs(3_600)),
+            cfg,
+            builder: UnusedAttributesBuilder,
+            conductor: None,
+            drift_monitor,
+            is_active: true,
+            is_recovery_mode: false,
+        }
+    }
+
+    /// Schedules the block following the one at `timestamp`, and returns whether it is built
+    /// immediately.
+    async fn builds_immediately(
+        state: &mut SequencerActorState<UnusedAttributesBuilder>,
+        timestamp: u64,
+    ) -> bool {
+        // Consume the first tick, which always completes immediately.
+        state.build_ticker.tick().await;
+
+        let l1_origin = BlockInfo { timestamp: L1_ORIGIN_TIMESTAMP, ..Default::default() };
+        state.schedule_next_build(&l1_origin, timestamp);
+        state.build_ticker.tick().now_or_never().is_some()
+    }
+
+    #[tokio::test]
+    async fn test_schedule_next_build_clock_in_sync() {
+        let monitor = DriftMonitor::new(Some(120), true).with_clock(|| L1_ORIGIN_TIMESTAMP + 24);
+        let mut state = state(monitor);
+
+        // The next block is in the future, and is built on the next tick.
+        assert!(!builds_immediately(&mut state, L1_ORIGIN_TIMESTAMP + 30).await);
+    }
+
+    #[tokio::test]
+    async fn test_schedule_next_build_drifted_clock() {
+        let timestamp = L1_ORIGIN_TIMESTAMP + MAX_SEQUENCER_DRIFT + 100;
+        let clock = || L1_ORIGIN_TIMESTAMP + 3_600;
+
+        // Without clamping, the drifted clock makes the sequencer rush out the next block.
+        let monitor = DriftMonitor::new(Some(120), false).with_clock(clock);
+        let reading = monitor.read(&RollupConfig::default(), &BlockInfo {
+            timestamp: L1_ORIGIN_TIMESTAMP,
+            ..Default::default()
+        });
+        assert!(reading.exceeded);
+        assert!(builds_immediately(&mut state(monitor), timestamp).await);
+
+        // With clamping, the clock is held at the sequencer drift bound of the L1 origin, and the
+        // next block waits for the next tick.
+        let monitor = DriftMonitor::new(Some(120), true).with_clock(clock);
+        assert!(!builds_immediately(&mut state(monitor), timestamp).await);
+    }
+}
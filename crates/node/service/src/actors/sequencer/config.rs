@@ -2,6 +2,7 @@
 //!
 //! [`SequencerActor`]: super::SequencerActor
 
+use std::time::Duration;
 use url::Url;
 
 /// Configuration for the [`SequencerActor`].
@@ -17,4 +18,16 @@ pub struct SequencerConfig {
     pub conductor_rpc_url: Option<Url>,
     /// The confirmation delay for the sequencer.
     pub l1_conf_delay: u64,
+    /// The maximum drift between the sequencer's clock and the timestamp of the selected L1
+    /// origin, in seconds, above which a warning is emitted. Disabled if [`None`].
+    pub max_clock_drift: Option<u64>,
+    /// Whether to clamp the sequencer's clock between the L1 origin timestamp and the maximum
+    /// sequencer drift past it when scheduling the next block.
+    ///
+    /// Block timestamps are not affected: the timestamp of an L2 block is always its parent's
+    /// timestamp plus the block time.
+    pub clamp_clock: bool,
+    /// The maximum amount of time to wait for the execution layer to return a block built by the
+    /// sequencer. Defaults to half of the block time, and at least one second, if [`None`].
+    pub payload_timeout: Option<Duration>,
 }
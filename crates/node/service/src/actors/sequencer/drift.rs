@@ -0,0 +1,144 @@
+//! Clock drift detection for the [`SequencerActor`].
+//!
+//! [`SequencerActor`]: super::SequencerActor
+
+use kona_genesis::RollupConfig;
+use kona_protocol::BlockInfo;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Returns the current unix timestamp of the system clock, in seconds.
+fn system_clock() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("Time went backwards").as_secs()
+}
+
+/// A reading of the sequencer's clock, relative to the selected L1 origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct ClockReading {
+    /// The sequencer's clock used to schedule the next block, clamped if clamping is enabled.
+    pub clock: u64,
+    /// The signed difference between the wall clock and the L1 origin timestamp, in seconds.
+    pub drift: i64,
+    /// Whether the drift exceeds the configured threshold.
+    pub exceeded: bool,
+}
+
+/// Compares the sequencer's wall clock to the timestamp of the selected L1 origin.
+///
+/// The L1 origin is expected to lag the wall clock by a few L1 slots, and never by more than the
+/// maximum sequencer drift. A clock running ahead or behind L1 breaks that expectation, and leads
+/// to blocks being scheduled too early or too late.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct DriftMonitor {
+    /// The drift threshold above which a warning is emitted, in seconds. Disabled if [`None`].
+    threshold: Option<u64>,
+    /// Whether to clamp the clock within the bounds of the spec.
+    clamp: bool,
+    /// The wall clock, returning the current unix timestamp in seconds.
+    clock: fn() -> u64,
+}
+
+impl DriftMonitor {
+    /// Creates a new [`DriftMonitor`] reading the system clock.
+    pub(super) const fn new(threshold: Option<u64>, clamp: bool) -> Self {
+        Self { threshold, clamp, clock: system_clock }
+    }
+
+    /// Replaces the wall clock of the [`DriftMonitor`].
+    #[cfg(test)]
+    pub(super) const fn with_clock(mut self, clock: fn() -> u64) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Reads the wall clock and checks its drift against the given L1 origin.
+    ///
+    /// If clamping is enabled, the clock is clamped between the L1 origin timestamp and the maximum
+    /// sequencer drift past it, the bounds of a valid L2 timestamp for the origin. The clamped
+    /// clock only drives block scheduling: L2 block timestamps are fixed by the protocol.
+    pub(super) fn read(&self, cfg: &RollupConfig, l1_origin: &BlockInfo) -> ClockReading {
+        let now = (self.clock)();
+        let drift = now as i64 - l1_origin.timestamp as i64;
+        let exceeded = self.threshold.is_some_and(|threshold| drift.unsigned_abs() > threshold);
+
+        kona_macros::set!(gauge, crate::Metrics::SEQUENCER_CLOCK_DRIFT, drift as f64);
+        if exceeded {
+            warn!(
+                target: "sequencer",
+                drift,
+                now,
+                l1_origin_timestamp = l1_origin.timestamp,
+                "Sequencer clock drifted from the L1 origin beyond the threshold"
+            );
+            kona_macros::inc!(counter, crate::Metrics::SEQUENCER_CLOCK_DRIFT_WARNINGS);
+        }
+
+        let clock = if self.clamp {
+            let max_drift = cfg.max_sequencer_drift(l1_origin.timestamp);
+            now.clamp(l1_origin.timestamp, l1_origin.timestamp + max_drift)
+        } else {
+            now
+        };
+
+        ClockReading { clock, drift, exceeded }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const L1_ORIGIN_TIMESTAMP: u64 = 1_000;
+
+    fn l1_origin() -> BlockInfo {
+        BlockInfo { timestamp: L1_ORIGIN_TIMESTAMP, ..Default::default() }
+    }
+
+    fn cfg() -> RollupConfig {
+        RollupConfig { max_sequencer_drift: 600, ..Default::default() }
+    }
+
+    #[test]
+    fn test_clock_in_sync() {
+        let monitor = DriftMonitor::new(Some(120), true).with_clock(|| L1_ORIGIN_TIMESTAMP + 24);
+        let reading = monitor.read(&cfg(), &l1_origin());
+        assert_eq!(
+            reading,
+            ClockReading { clock: L1_ORIGIN_TIMESTAMP + 24, drift: 24, exceeded: false }
+        );
+    }
+
+    #[test]
+    fn test_clock_ahead() {
+        let clock = || L1_ORIGIN_TIMESTAMP + 3_600;
+
+        let reading =
+            DriftMonitor::new(Some(120), false).with_clock(clock).read(&cfg(), &l1_origin());
+        assert_eq!(
+            reading,
+            ClockReading { clock: L1_ORIGIN_TIMESTAMP + 3_600, drift: 3_600, exceeded: true }
+        );
+
+        let reading =
+            DriftMonitor::new(Some(120), true).with_clock(clock).read(&cfg(), &l1_origin());
+        assert_eq!(
+            reading,
+            ClockReading { clock: L1_ORIGIN_TIMESTAMP + 600, drift: 3_600, exceeded: true }
+        );
+    }
+
+    #[test]
+    fn test_clock_behind() {
+        let monitor = DriftMonitor::new(Some(120), true).with_clock(|| L1_ORIGIN_TIMESTAMP - 300);
+        let reading = monitor.read(&cfg(), &l1_origin());
+        assert_eq!(
+            reading,
+            ClockReading { clock: L1_ORIGIN_TIMESTAMP, drift: -300, exceeded: true }
+        );
+    }
+
+    #[test]
+    fn test_threshold_disabled() {
+        let monitor = DriftMonitor::new(None, false).with_clock(|| L1_ORIGIN_TIMESTAMP + 3_600);
+        assert!(!monitor.read(&cfg(), &l1_origin()).exceeded);
+    }
+}
@@ -3,6 +3,8 @@
 mod config;
 pub use config::SequencerConfig;
 
+mod drift;
+
 mod origin_selector;
 pub use origin_selector::{
     DelayedL1OriginSelectorProvider, L1OriginSelector, L1OriginSelectorError,
@@ -29,6 +29,12 @@ impl Metrics {
     pub const SEQUENCER_CONDUCTOR_COMMITMENT_DURATION: &str =
         "kona_node_sequencer_conductor_commitment_duration";
 
+    /// Gauge for the drift between the sequencer's clock and the L1 origin timestamp, in seconds.
+    pub const SEQUENCER_CLOCK_DRIFT: &str = "kona_node_sequencer_clock_drift";
+
+    /// Counter for the number of times the sequencer's clock drift exceeded the threshold.
+    pub const SEQUENCER_CLOCK_DRIFT_WARNINGS: &str = "kona_node_sequencer_clock_drift_warnings";
+
     /// Initializes metrics for the node service.
     ///
     /// This does two things:
@@ -75,6 +81,20 @@ impl Metrics {
             Self::SEQUENCER_CONDUCTOR_COMMITMENT_DURATION,
             "Duration of the sequencer conductor commitment"
         );
+
+        // Sequencer clock drift
+        metrics::describe_gauge!(
+            Self::SEQUENCER_CLOCK_DRIFT,
+            metrics::Unit::Seconds,
+            "Drift between the sequencer clock and the L1 origin timestamp"
+        );
+
+        // Sequencer clock drift warnings
+        metrics::describe_counter!(
+            Self::SEQUENCER_CLOCK_DRIFT_WARNINGS,
+            metrics::Unit::Count,
+            "Number of times the sequencer clock drift exceeded the threshold"
+        );
     }
 
     /// Initializes metrics to `0` so they can be queried immediately by consumers of prometheus
@@ -86,5 +106,8 @@ impl Metrics {
 
         // Derivation critical error
         kona_macros::set!(counter, Self::DERIVATION_CRITICAL_ERROR, 0);
+
+        // Sequencer clock drift warnings
+        kona_macros::set!(counter, Self::SEQUENCER_CLOCK_DRIFT_WARNINGS, 0);
     }
 }
@@ -10,6 +10,7 @@ use crate::{
 };
 use async_trait::async_trait;
 use kona_derive::{AttributesBuilder, Pipeline, SignalReceiver};
+use kona_rpc::{HealthRegistry, NodeComponent};
 use std::fmt::Display;
 use tokio_util::sync::CancellationToken;
 
@@ -106,6 +107,9 @@ pub trait RollupNodeService {
         // Create a global cancellation token for graceful shutdown of tasks.
         let cancellation = CancellationToken::new();
 
+        // Create the registry tracking the liveness of the actors, served by the healthz endpoint.
+        let health = HealthRegistry::default();
+
         // Create the DA watcher actor.
         let (L1WatcherRpcInboundChannels { inbound_queries: da_watcher_rpc }, da_watcher) =
             Self::DataAvailabilityWatcher::build(self.da_watcher_builder());
@@ -114,6 +118,7 @@ pub trait RollupNodeService {
         let (
             DerivationInboundChannels {
                 derivation_signal_tx,
+                admin_query_tx: derivation_admin_rpc,
                 l1_head_updates_tx,
                 engine_l2_safe_head_tx,
                 el_sync_complete_tx,
@@ -164,8 +169,10 @@ pub trait RollupNodeService {
                         p2p_network: network_rpc,
                         network_admin: net_admin_rpc,
                         sequencer_admin: sequencer_inbound_data.as_ref().map(|s| s.admin_query_tx.clone()),
+                        derivation_admin: derivation_admin_rpc,
                         l1_watcher_queries: da_watcher_rpc,
                         engine_query: engine_rpc,
+                        health: health.clone(),
                     }
                 )),
                 sequencer.map(|s| (
@@ -178,11 +185,16 @@ pub trait RollupNodeService {
                         ),
                         gossip_payload_tx,
                         cancellation: cancellation.clone(),
+                        heartbeat: health.heartbeat(NodeComponent::Sequencer),
                     })
                 ),
                 Some((
                     network,
-                    NetworkContext { blocks: unsafe_block_tx, cancellation: cancellation.clone() }
+                    NetworkContext {
+                        blocks: unsafe_block_tx,
+                        cancellation: cancellation.clone(),
+                        heartbeat: health.heartbeat(NodeComponent::Network),
+                    }
                 )),
                 Some((
                     da_watcher,
@@ -199,6 +211,7 @@ pub trait RollupNodeService {
                         reset_request_tx: reset_request_tx.clone(),
                         derived_attributes_tx: attributes_tx,
                         cancellation: cancellation.clone(),
+                        heartbeat: health.heartbeat(NodeComponent::Derivation),
                 })),
                 Some((engine,
                     EngineContext {
@@ -208,6 +221,7 @@ pub trait RollupNodeService {
                         sync_complete_tx: el_sync_complete_tx,
                         derivation_signal_tx,
                         cancellation: cancellation.clone(),
+                        heartbeat: health.heartbeat(NodeComponent::Engine),
                     })
                 ),
             ]
@@ -4,9 +4,8 @@ use crate::{EngineBuilder, InteropMode, NetworkConfig, NodeMode, RollupNode, Seq
 use alloy_primitives::Bytes;
 use alloy_provider::RootProvider;
 use alloy_rpc_client::RpcClient;
-use alloy_rpc_types_engine::JwtSecret;
 use alloy_transport_http::{
-    AuthLayer, Http, HyperClient,
+    Http, HyperClient,
     hyper_util::{client::legacy::Client, rt::TokioExecutor},
 };
 use http_body_util::Full;
@@ -15,6 +14,7 @@ use std::sync::Arc;
 use tower::ServiceBuilder;
 use url::Url;
 
+use kona_engine::{JwtAuthLayer, ReloadableJwtSecret};
 use kona_genesis::{L1ChainConfig, RollupConfig};
 use kona_providers_alloy::OnlineBeaconClient;
 use kona_rpc::RpcBuilder;
@@ -37,7 +37,7 @@ pub struct RollupNodeBuilder {
     /// Whether to trust the L2 RPC.
     l2_trust_rpc: bool,
     /// The JWT secret.
-    jwt_secret: Option<JwtSecret>,
+    jwt_secret: Option<ReloadableJwtSecret>,
     /// The [`NetworkConfig`].
     p2p_config: Option<NetworkConfig>,
     /// An RPC Configuration.
@@ -87,8 +87,11 @@ impl RollupNodeBuilder {
     }
 
     /// Appends a JWT secret to the builder.
-    pub fn with_jwt_secret(self, jwt_secret: JwtSecret) -> Self {
-        Self { jwt_secret: Some(jwt_secret), ..self }
+    ///
+    /// The secret is shared by every client authenticating to the engine, so reloading a
+    /// [`ReloadableJwtSecret`] rotates it for the whole node.
+    pub fn with_jwt_secret(self, jwt_secret: impl Into<ReloadableJwtSecret>) -> Self {
+        Self { jwt_secret: Some(jwt_secret.into()), ..self }
     }
 
     /// Appends the P2P [`NetworkConfig`] to the builder.
@@ -128,7 +131,7 @@ impl RollupNodeBuilder {
         let jwt_secret = self.jwt_secret.expect("jwt secret not set");
         let hyper_client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
 
-        let auth_layer = AuthLayer::new(jwt_secret);
+        let auth_layer = JwtAuthLayer::new(jwt_secret.clone());
         let service = ServiceBuilder::new().layer(auth_layer).service(hyper_client);
 
         let layer_transport = HyperClient::with_service(service);
@@ -138,16 +141,17 @@ impl RollupNodeBuilder {
 
         let rollup_config = Arc::new(self.config);
         let l1_config = Arc::new(self.l1_config);
+        let sequencer_config = self.sequencer_config.unwrap_or_default();
         let engine_builder = EngineBuilder {
             config: Arc::clone(&rollup_config),
             l1_rpc_url,
             engine_url,
             jwt_secret,
             mode: self.mode,
+            payload_timeout: sequencer_config.payload_timeout,
         };
 
         let p2p_config = self.p2p_config.expect("P2P config not set");
-        let sequencer_config = self.sequencer_config.unwrap_or_default();
 
         RollupNode {
             config: rollup_config,
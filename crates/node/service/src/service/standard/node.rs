@@ -110,6 +110,7 @@ impl RollupNodeService for RollupNode {
             rollup_config: self.config.clone(),
             l1_config: self.l1_config.clone(),
             interop_mode: self.interop_mode,
+            channel_timeout: None,
         }
     }
 }
@@ -8,6 +8,7 @@ use alloy_primitives::Address;
 use kona_disc::LocalNode;
 use kona_genesis::RollupConfig;
 use kona_node_service::{NetworkActor, NetworkBuilder, NetworkContext, NodeActor};
+use kona_rpc::{HealthRegistry, NodeComponent};
 use kona_sources::BlockSigner;
 use libp2p::{Multiaddr, identity::Keypair, multiaddr::Protocol};
 use rand::RngCore;
@@ -20,6 +21,7 @@ pub(crate) struct TestNetworkBuilder {
     chain_id: u64,
     unsafe_block_signer: Address,
     custom_keypair: Option<Keypair>,
+    prioritize_blocks: bool,
 }
 
 impl TestNetworkBuilder {
@@ -30,7 +32,18 @@ impl TestNetworkBuilder {
     pub(crate) fn new() -> Self {
         let chain_id = rand::rng().next_u64();
 
-        Self { chain_id, unsafe_block_signer: Address::ZERO, custom_keypair: None }
+        Self {
+            chain_id,
+            unsafe_block_signer: Address::ZERO,
+            custom_keypair: None,
+            prioritize_blocks: false,
+        }
+    }
+
+    /// Services block propagation before discovery maintenance in the networks built.
+    pub(crate) const fn with_block_priority(mut self) -> Self {
+        self.prioritize_blocks = true;
+        self
     }
 
     /// Sets a sequencer keypair for the network.
@@ -90,14 +103,19 @@ impl TestNetworkBuilder {
             discovery_config,
             Some(BlockSigner::Local(local_node_key.into())),
         )
-        .with_bootnodes(bootnodes);
+        .with_bootnodes(bootnodes)
+        .with_block_priority(self.prioritize_blocks);
 
         let (inbound_data, actor) = NetworkActor::new(builder);
 
         let (blocks_tx, blocks_rx) = mpsc::channel(1024);
         let cancellation = CancellationToken::new();
 
-        let context = NetworkContext { blocks: blocks_tx, cancellation };
+        let context = NetworkContext {
+            blocks: blocks_tx,
+            cancellation,
+            heartbeat: HealthRegistry::default().heartbeat(NodeComponent::Network),
+        };
 
         let handle = tokio::spawn(async move { actor.start(context).await });
 
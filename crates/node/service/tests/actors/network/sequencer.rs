@@ -76,3 +76,48 @@ async fn test_sequencer_network_propagation() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Test that networks prioritizing block propagation still discover and connect to each other,
+/// and propagate a burst of blocks.
+#[tokio::test(flavor = "multi_thread")]
+async fn test_sequencer_network_block_priority() -> anyhow::Result<()> {
+    const BLOCKS: usize = 8;
+
+    let mut builder = TestNetworkBuilder::new().set_sequencer().with_block_priority();
+
+    let sequencer_network = builder.build(vec![]);
+    let enr = sequencer_network.peer_enr().await?;
+
+    let mut validator_network = builder.build(vec![enr]);
+
+    sequencer_network.is_connected_to_with_retries(&validator_network).await?;
+    validator_network.is_connected_to_with_retries(&sequencer_network).await?;
+
+    let mut seed_generator = SEED_GENERATOR_BUILDER.next_generator();
+    let mut envelopes = Vec::with_capacity(BLOCKS);
+    for _ in 0..BLOCKS {
+        let envelope = seed_generator.random_valid_payload(PayloadVersion::V1)?;
+        sequencer_network.inbound_data.gossip_payload_tx.send(envelope.clone()).await?;
+        envelopes.push(envelope);
+    }
+
+    let mut received = Vec::with_capacity(BLOCKS);
+    for _ in 0..BLOCKS {
+        let block =
+            validator_network.blocks_rx.recv().await.ok_or(anyhow::anyhow!("No block received"))?;
+        received.push(block.execution_payload.block_hash());
+    }
+
+    let mut sent = envelopes
+        .iter()
+        .map(|envelope| envelope.execution_payload.block_hash())
+        .collect::<Vec<_>>();
+    sent.sort();
+    received.sort();
+    assert_eq!(sent, received);
+
+    // The other arms of the event loop are serviced once the blocks are.
+    validator_network.is_connected_to(&sequencer_network).await?;
+
+    Ok(())
+}
@@ -130,6 +130,34 @@ where
         self.executor.wait_until_ready().await;
     }
 
+    /// Derives and executes L2 blocks until the L2 safe head is exactly at block `target`.
+    ///
+    /// Unlike [`Self::advance_to_target`], which halts at the current safe head once the L1 data
+    /// is exhausted, this method fails if the target can't be reached.
+    ///
+    /// # Returns
+    /// * `Ok(TipCursor)` - The tip of the L2 safe head at block `target`
+    /// * `Err(DriverError::UnreachableL2Target)` - The L1 data was exhausted before reaching the
+    ///   target, or the safe head is already past it
+    /// * `Err(DriverError)` - Any error returned by [`Self::advance_to_target`]
+    pub async fn advance_to_l2(&mut self, target: u64) -> DriverResult<TipCursor, E::Error> {
+        let safe_head = self.cursor.read().l2_safe_head().block_info.number;
+        if safe_head > target {
+            return Err(DriverError::UnreachableL2Target { target, safe_head });
+        }
+
+        let cfg = self.pipeline.rollup_config().clone();
+        let (safe_head, _) = self.advance_to_target(&cfg, Some(target)).await?;
+        if safe_head.block_info.number != target {
+            return Err(DriverError::UnreachableL2Target {
+                target,
+                safe_head: safe_head.block_info.number,
+            });
+        }
+
+        Ok(self.cursor.read().tip().clone())
+    }
+
     /// Advances the derivation pipeline to the target block number.
     ///
     /// This is the main driver method that coordinates the derivation pipeline and block
@@ -331,3 +359,57 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        TestExecutor, TestPipeline,
+        TestStep::{self, *},
+    };
+    use alloc::vec;
+    use alloy_consensus::{Header, Sealable};
+    use kona_protocol::BlockInfo;
+
+    fn driver(script: Vec<TestStep>) -> Driver<TestExecutor, TestPipeline, TestPipeline> {
+        let genesis = Header::default().seal_slow();
+        let l2_genesis = L2BlockInfo {
+            block_info: BlockInfo { hash: genesis.hash(), ..Default::default() },
+            ..Default::default()
+        };
+        let mut cursor = PipelineCursor::new(10, BlockInfo::default());
+        cursor
+            .advance(BlockInfo::default(), TipCursor::new(l2_genesis, genesis.clone(), B256::ZERO));
+        Driver::new(
+            Arc::new(RwLock::new(cursor)),
+            TestExecutor::new(genesis),
+            TestPipeline::new(script),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_advance_to_l2() {
+        // Two L1 blocks, deriving 2 and 3 L2 blocks respectively.
+        let mut driver =
+            driver(vec![Advance, Prepare, Prepare, Advance, Prepare, Prepare, Prepare]);
+
+        let tip = driver.advance_to_l2(4).await.unwrap();
+        assert_eq!(tip.l2_safe_head.block_info.number, 4);
+        assert_eq!(tip.l2_safe_head.l1_origin.number, 2);
+        assert_eq!(tip.l2_safe_head_header.number, 4);
+        assert_eq!(tip.l2_safe_head_output_root, tip.l2_safe_head_header.hash());
+        assert_eq!(driver.cursor.read().l2_safe_head(), &tip.l2_safe_head);
+
+        // The safe head can't move backwards.
+        assert!(matches!(
+            driver.advance_to_l2(3).await,
+            Err(DriverError::UnreachableL2Target { target: 3, safe_head: 4 })
+        ));
+
+        // The L1 data runs out after the 5th L2 block.
+        assert!(matches!(
+            driver.advance_to_l2(10).await,
+            Err(DriverError::UnreachableL2Target { target: 10, safe_head: 5 })
+        ));
+    }
+}
@@ -0,0 +1,43 @@
+//! Contains the [`DerivationCounters`], which track the derivation throughput of a
+//! [`DriverPipeline`].
+//!
+//! [`DriverPipeline`]: crate::DriverPipeline
+
+/// Counters of the L1 blocks consumed and L2 payload attributes produced by a [`DriverPipeline`].
+///
+/// The ratio of the two is the derivation throughput of the pipeline, a key health signal during
+/// range sync. The counters are plain integers updated by [`DriverPipeline::produce_payload`], and
+/// can be reset between runs with [`Self::reset`].
+///
+/// [`DriverPipeline`]: crate::DriverPipeline
+/// [`DriverPipeline::produce_payload`]: crate::DriverPipeline::produce_payload
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DerivationCounters {
+    /// The number of L1 blocks the pipeline advanced its origin through.
+    pub l1_blocks: u64,
+    /// The number of L2 payload attributes the pipeline produced.
+    pub l2_attributes: u64,
+}
+
+impl DerivationCounters {
+    /// Records that the pipeline advanced its origin to the next L1 block.
+    pub const fn record_l1_block(&mut self) {
+        self.l1_blocks += 1;
+    }
+
+    /// Records that the pipeline produced L2 payload attributes.
+    pub const fn record_l2_attributes(&mut self) {
+        self.l2_attributes += 1;
+    }
+
+    /// Resets the counters, returning their values before the reset.
+    pub const fn reset(&mut self) -> Self {
+        core::mem::replace(self, Self { l1_blocks: 0, l2_attributes: 0 })
+    }
+
+    /// Returns the average number of L2 payload attributes produced per L1 block, or `None` if no
+    /// L1 block was consumed.
+    pub fn l2_per_l1(&self) -> Option<f64> {
+        (self.l1_blocks > 0).then(|| self.l2_attributes as f64 / self.l1_blocks as f64)
+    }
+}
@@ -25,4 +25,12 @@ where
     /// Error decoding or encoding RLP.
     #[error("RLP error: {0}")]
     Rlp(alloy_rlp::Error),
+    /// The target L2 block can't be reached from the current L2 safe head and L1 data.
+    #[error("L2 block {target} is unreachable, the L2 safe head is at block {safe_head}")]
+    UnreachableL2Target {
+        /// The target L2 block number.
+        target: u64,
+        /// The L2 safe head block number derivation stopped at.
+        safe_head: u64,
+    },
 }
@@ -18,6 +18,9 @@ pub use errors::{DriverError, DriverResult};
 mod pipeline;
 pub use pipeline::DriverPipeline;
 
+mod counters;
+pub use counters::DerivationCounters;
+
 mod executor;
 pub use executor::Executor;
 
@@ -29,3 +32,6 @@ pub use cursor::PipelineCursor;
 
 mod tip;
 pub use tip::TipCursor;
+
+#[cfg(test)]
+mod test_utils;
@@ -4,6 +4,7 @@
 //! abstraction for the driver's derivation pipeline. The pipeline is responsible
 //! for deriving L2 blocks from L1 data and producing payload attributes for execution.
 
+use crate::DerivationCounters;
 use alloc::boxed::Box;
 use alloy_primitives::Address;
 use async_trait::async_trait;
@@ -54,6 +55,20 @@ where
     /// stepping, but can also be called manually if needed.
     fn flush(&mut self);
 
+    /// Returns the [`DerivationCounters`] of the pipeline, or `None` if it doesn't track its
+    /// derivation throughput.
+    fn counters(&self) -> Option<&DerivationCounters> {
+        None
+    }
+
+    /// Returns a mutable reference to the [`DerivationCounters`] of the pipeline, or `None` if it
+    /// doesn't track its derivation throughput.
+    ///
+    /// [`Self::produce_payload`] updates the counters through this method.
+    fn counters_mut(&mut self) -> Option<&mut DerivationCounters> {
+        None
+    }
+
     /// Produces payload attributes for the next block after the given L2 safe head.
     ///
     /// This method advances the derivation pipeline to produce the next set of
@@ -103,6 +118,9 @@ where
                     info!(target: "client_derivation_driver", "Stepped derivation pipeline")
                 }
                 StepResult::AdvancedOrigin => {
+                    if let Some(counters) = self.counters_mut() {
+                        counters.record_l1_block();
+                    }
                     info!(
                         target: "client_derivation_driver",
                         l1_block_number = self.origin().map(|o| o.number).ok_or(PipelineError::MissingOrigin.crit())?,
@@ -166,8 +184,48 @@ where
             }
 
             if let Some(attrs) = self.next() {
+                if let Some(counters) = self.counters_mut() {
+                    counters.record_l2_attributes();
+                }
                 return Ok(attrs);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestPipeline, TestStep::*};
+    use alloc::vec;
+
+    #[tokio::test]
+    async fn test_produce_payload_counters() {
+        // Three L1 blocks, deriving 2, 0 and 3 L2 blocks respectively.
+        let mut pipeline = TestPipeline::new(vec![
+            Advance,
+            Prepare,
+            Prepare,
+            Advance,
+            NotEnoughData,
+            Advance,
+            Prepare,
+            Prepare,
+            Prepare,
+        ]);
+
+        let mut produced = 0;
+        while pipeline.produce_payload(L2BlockInfo::default()).await.is_ok() {
+            produced += 1;
+        }
+
+        assert_eq!(produced, 5);
+        let counters = *pipeline.counters().unwrap();
+        assert_eq!(counters, DerivationCounters { l1_blocks: 3, l2_attributes: 5 });
+        assert_eq!(counters.l2_per_l1(), Some(5.0 / 3.0));
+
+        assert_eq!(pipeline.counters_mut().unwrap().reset(), counters);
+        assert_eq!(pipeline.counters(), Some(&DerivationCounters::default()));
+        assert_eq!(pipeline.counters().unwrap().l2_per_l1(), None);
+    }
+}
@@ -0,0 +1,190 @@
+//! Test utilities for the driver.
+
+use crate::{DerivationCounters, DriverPipeline, Executor};
+use alloc::{boxed::Box, collections::VecDeque, vec};
+use alloy_consensus::{Header, Sealable, Sealed};
+use alloy_eips::Encodable2718;
+use alloy_primitives::{Address, B256, TxKind, U256};
+use async_trait::async_trait;
+use kona_derive::{
+    OriginProvider, Pipeline, PipelineError, PipelineErrorKind, PipelineResult, PipelineSnapshot,
+    Signal, SignalReceiver, StepResult,
+};
+use kona_executor::BlockBuildingOutcome;
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_protocol::{
+    BlockInfo, L1BlockInfoBedrock, L1BlockInfoTx, L2BlockInfo, OpAttributesWithParent,
+};
+use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+/// A step of the scripted [`TestPipeline`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TestStep {
+    /// Advance the origin to the next L1 block.
+    Advance,
+    /// Prepare the payload attributes of the next L2 block.
+    Prepare,
+    /// Fail with a temporary error.
+    NotEnoughData,
+}
+
+/// A pipeline stepping through a fixed script, and running out of data at its end.
+#[derive(Debug, Default)]
+pub(crate) struct TestPipeline {
+    script: VecDeque<TestStep>,
+    origin: BlockInfo,
+    prepared: VecDeque<OpAttributesWithParent>,
+    config: RollupConfig,
+    counters: DerivationCounters,
+}
+
+impl TestPipeline {
+    /// Creates a [`TestPipeline`] stepping through the given script.
+    pub(crate) fn new(script: impl IntoIterator<Item = TestStep>) -> Self {
+        Self { script: script.into_iter().collect(), ..Default::default() }
+    }
+
+    /// Builds the payload attributes of the child of `parent`, holding only the L1 info deposit.
+    fn attributes(&self, parent: L2BlockInfo) -> OpAttributesWithParent {
+        let l1_info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            number: self.origin.number,
+            block_hash: self.origin.hash,
+            sequence_number: parent.seq_num + 1,
+            ..Default::default()
+        });
+        let deposit = TxDeposit {
+            source_hash: B256::with_last_byte(parent.block_info.number as u8),
+            from: Address::ZERO,
+            to: TxKind::Call(Address::ZERO),
+            mint: 0,
+            value: U256::ZERO,
+            gas_limit: 1_000_000,
+            is_system_transaction: true,
+            input: l1_info.encode_calldata(),
+        };
+        let inner = OpPayloadAttributes {
+            transactions: Some(vec![
+                OpTxEnvelope::Deposit(deposit.seal_slow()).encoded_2718().into(),
+            ]),
+            ..Default::default()
+        };
+        OpAttributesWithParent::new(inner, parent, Some(self.origin), false)
+    }
+}
+
+impl Iterator for TestPipeline {
+    type Item = OpAttributesWithParent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.prepared.pop_front()
+    }
+}
+
+impl OriginProvider for TestPipeline {
+    fn origin(&self) -> Option<BlockInfo> {
+        Some(self.origin)
+    }
+}
+
+#[async_trait]
+impl SignalReceiver for TestPipeline {
+    async fn signal(&mut self, _: Signal) -> PipelineResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Pipeline for TestPipeline {
+    fn peek(&self) -> Option<&OpAttributesWithParent> {
+        self.prepared.front()
+    }
+
+    async fn step(&mut self, cursor: L2BlockInfo) -> StepResult {
+        match self.script.pop_front() {
+            Some(TestStep::Advance) => {
+                self.origin.number += 1;
+                self.origin.hash = B256::with_last_byte(self.origin.number as u8);
+                StepResult::AdvancedOrigin
+            }
+            Some(TestStep::Prepare) => {
+                let attributes = self.attributes(cursor);
+                self.prepared.push_back(attributes);
+                StepResult::PreparedAttributes
+            }
+            Some(TestStep::NotEnoughData) => {
+                StepResult::StepFailed(PipelineError::NotEnoughData.temp())
+            }
+            None => StepResult::StepFailed(PipelineError::EndOfSource.crit()),
+        }
+    }
+
+    fn snapshot(&self) -> PipelineSnapshot {
+        PipelineSnapshot::default()
+    }
+
+    fn rollup_config(&self) -> &RollupConfig {
+        &self.config
+    }
+
+    async fn system_config_by_number(&mut self, _: u64) -> Result<SystemConfig, PipelineErrorKind> {
+        Ok(SystemConfig::default())
+    }
+}
+
+impl DriverPipeline<Self> for TestPipeline {
+    fn flush(&mut self) {}
+
+    fn counters(&self) -> Option<&DerivationCounters> {
+        Some(&self.counters)
+    }
+
+    fn counters_mut(&mut self) -> Option<&mut DerivationCounters> {
+        Some(&mut self.counters)
+    }
+}
+
+/// An executor building empty children of the safe head, using the block hash as output root.
+#[derive(Debug)]
+pub(crate) struct TestExecutor {
+    safe_head: Sealed<Header>,
+}
+
+impl TestExecutor {
+    /// Creates a [`TestExecutor`] building on top of `safe_head`.
+    pub(crate) const fn new(safe_head: Sealed<Header>) -> Self {
+        Self { safe_head }
+    }
+}
+
+#[async_trait]
+impl Executor for TestExecutor {
+    type Error = core::fmt::Error;
+
+    async fn wait_until_ready(&mut self) {}
+
+    fn update_safe_head(&mut self, header: Sealed<Header>) {
+        self.safe_head = header;
+    }
+
+    async fn execute_payload(
+        &mut self,
+        attributes: OpPayloadAttributes,
+    ) -> Result<BlockBuildingOutcome, Self::Error> {
+        let header = Header {
+            parent_hash: self.safe_head.hash(),
+            number: self.safe_head.number + 1,
+            timestamp: attributes.payload_attributes.timestamp,
+            ..Default::default()
+        };
+        self.safe_head = header.seal_slow();
+        Ok(BlockBuildingOutcome {
+            header: self.safe_head.clone(),
+            execution_result: Default::default(),
+        })
+    }
+
+    fn compute_output_root(&mut self) -> Result<B256, Self::Error> {
+        Ok(self.safe_head.hash())
+    }
+}
@@ -20,6 +20,9 @@ use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use op_revm::OpSpecId;
 use revm::database::{State, states::bundle_state::BundleRetention};
 
+#[cfg(feature = "execution-trace")]
+use super::ExecutionTrace;
+
 /// Stateless OP Stack L2 block builder that derives state from trie proofs during execution.
 ///
 /// The [`StatelessL2Builder`] is a specialized block execution engine designed for fault proof
@@ -91,6 +94,9 @@ where
     /// understand OP-specific transaction types, system calls, and state
     /// management required for proper L2 block execution.
     pub(crate) factory: OpBlockExecutorFactory<OpAlloyReceiptBuilder, RollupConfig, Evm>,
+    /// The execution trace of the last built block, if tracing is enabled.
+    #[cfg(feature = "execution-trace")]
+    pub(crate) trace: Option<ExecutionTrace>,
 }
 
 impl<'a, P, H, Evm> StatelessL2Builder<'a, P, H, Evm>
@@ -138,7 +144,29 @@ where
             config.clone(),
             evm_factory,
         );
-        Self { config, trie_db, factory }
+        Self {
+            config,
+            trie_db,
+            factory,
+            #[cfg(feature = "execution-trace")]
+            trace: None,
+        }
+    }
+
+    /// Enables recording an [`ExecutionTrace`] of every built block, to compare executions of
+    /// the same block with [`ExecutionTrace::first_divergence`].
+    ///
+    /// Tracing slows down execution and is only meant for debugging.
+    #[cfg(feature = "execution-trace")]
+    pub fn with_execution_trace(mut self) -> Self {
+        self.trace = Some(ExecutionTrace::default());
+        self
+    }
+
+    /// Takes the [`ExecutionTrace`] of the last built block, if tracing is enabled.
+    #[cfg(feature = "execution-trace")]
+    pub fn take_execution_trace(&mut self) -> Option<ExecutionTrace> {
+        self.trace.as_mut().map(core::mem::take)
     }
 
     /// Builds and executes a new L2 block using the provided payload attributes.
@@ -260,7 +288,23 @@ where
             .recovered_transactions_with_encoded()
             .collect::<Result<Vec<_>, RecoveryError>>()
             .map_err(ExecutorError::Recovery)?;
+        #[cfg(not(feature = "execution-trace"))]
         let ex_result = executor.execute_block(transactions.iter())?;
+        #[cfg(feature = "execution-trace")]
+        let ex_result = match self.trace.as_mut() {
+            Some(trace) => {
+                let mut executor = executor;
+                *trace = ExecutionTrace::default();
+                executor.apply_pre_execution_changes()?;
+                for tx in &transactions {
+                    let output = executor.execute_transaction_without_commit(tx)?;
+                    trace.record(&output);
+                    executor.commit_transaction(output, tx)?;
+                }
+                executor.apply_post_execution_changes()?
+            }
+            None => executor.execute_block(transactions.iter())?,
+        };
 
         info!(
             target: "block_builder",
@@ -7,3 +7,8 @@ mod assemble;
 pub use assemble::compute_receipts_root;
 
 mod env;
+
+#[cfg(feature = "execution-trace")]
+mod trace;
+#[cfg(feature = "execution-trace")]
+pub use trace::{AccountDiff, ExecutionTrace, TraceDivergence, TransactionTrace};
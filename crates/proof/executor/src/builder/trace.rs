@@ -0,0 +1,210 @@
+//! Differential execution tracing for the [StatelessL2Builder].
+//!
+//! Tracing is a debugging tool for nondeterminism between executions of the same block, and is
+//! not meant to be enabled when producing proofs.
+//!
+//! [StatelessL2Builder]: crate::StatelessL2Builder
+
+use alloc::vec::Vec;
+use alloy_primitives::{Address, B256, U256};
+use revm::{context::result::ResultAndState, state::EvmState};
+
+/// The execution trace of a block, holding the state diff of every transaction in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExecutionTrace {
+    /// The traces of the executed transactions.
+    pub transactions: Vec<TransactionTrace>,
+}
+
+impl ExecutionTrace {
+    /// Records the outcome of the next transaction.
+    pub(crate) fn record<H>(&mut self, output: &ResultAndState<H>) {
+        self.transactions.push(TransactionTrace {
+            gas_used: output.result.gas_used(),
+            success: output.result.is_success(),
+            state_diff: AccountDiff::from_state(&output.state),
+        });
+    }
+
+    /// Returns the first transaction at which `self` and `other` diverge, or `None` if the traces
+    /// are identical.
+    pub fn first_divergence(&self, other: &Self) -> Option<TraceDivergence> {
+        let len = self.transactions.len().max(other.transactions.len());
+        (0..len).find_map(|index| {
+            let left = self.transactions.get(index);
+            let right = other.transactions.get(index);
+            (left != right).then(|| TraceDivergence {
+                index,
+                left: left.cloned(),
+                right: right.cloned(),
+            })
+        })
+    }
+}
+
+/// The outcome of a transaction and the state it changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionTrace {
+    /// The gas used by the transaction.
+    pub gas_used: u64,
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// The accounts touched by the transaction, sorted by address.
+    pub state_diff: Vec<AccountDiff>,
+}
+
+/// The state of an account after a transaction touched it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountDiff {
+    /// The address of the account.
+    pub address: Address,
+    /// The balance of the account.
+    pub balance: U256,
+    /// The nonce of the account.
+    pub nonce: u64,
+    /// The code hash of the account.
+    pub code_hash: B256,
+    /// The changed storage slots and their new values, sorted by slot.
+    pub storage: Vec<(U256, U256)>,
+}
+
+impl AccountDiff {
+    /// Collects the touched accounts of `state` in a deterministic order.
+    fn from_state(state: &EvmState) -> Vec<Self> {
+        let mut diff = state
+            .iter()
+            .filter(|(_, account)| account.is_touched())
+            .map(|(address, account)| {
+                let mut storage = account
+                    .storage
+                    .iter()
+                    .filter(|(_, slot)| slot.is_changed())
+                    .map(|(key, slot)| (*key, slot.present_value))
+                    .collect::<Vec<_>>();
+                storage.sort_unstable();
+                Self {
+                    address: *address,
+                    balance: account.info.balance,
+                    nonce: account.info.nonce,
+                    code_hash: account.info.code_hash,
+                    storage,
+                }
+            })
+            .collect::<Vec<_>>();
+        diff.sort_unstable_by_key(|account| account.address);
+        diff
+    }
+}
+
+/// The first transaction at which two [ExecutionTrace]s diverge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceDivergence {
+    /// The index of the transaction in the block.
+    pub index: usize,
+    /// The transaction trace of the first execution, if it executed the transaction.
+    pub left: Option<TransactionTrace>,
+    /// The transaction trace of the second execution, if it executed the transaction.
+    pub right: Option<TransactionTrace>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NoopTrieDBProvider, StatelessL2Builder};
+    use alloc::vec;
+    use alloy_consensus::{Header, Sealable};
+    use alloy_eips::Encodable2718;
+    use alloy_op_evm::OpEvmFactory;
+    use alloy_primitives::{Bytes, TxKind};
+    use alloy_rpc_types_engine::PayloadAttributes;
+    use alloy_trie::EMPTY_ROOT_HASH;
+    use kona_genesis::RollupConfig;
+    use kona_mpt::NoopTrieHinter;
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+
+    fn deposit(from: Address, to: Address, mint: u128) -> Bytes {
+        let tx = TxDeposit {
+            source_hash: B256::repeat_byte(from.0[0]),
+            from,
+            to: TxKind::Call(to),
+            mint,
+            value: U256::from(mint),
+            gas_limit: 100_000,
+            is_system_transaction: false,
+            input: Bytes::new(),
+        };
+        OpTxEnvelope::Deposit(tx.seal_slow()).encoded_2718().into()
+    }
+
+    fn execute(config: &RollupConfig, transactions: Vec<Bytes>) -> ExecutionTrace {
+        let parent_header =
+            Header { state_root: EMPTY_ROOT_HASH, gas_limit: 30_000_000, ..Default::default() };
+        let mut builder = StatelessL2Builder::new(
+            config,
+            OpEvmFactory::default(),
+            NoopTrieDBProvider,
+            NoopTrieHinter,
+            parent_header.seal_slow(),
+        )
+        .with_execution_trace();
+        let attrs = OpPayloadAttributes {
+            payload_attributes: PayloadAttributes {
+                timestamp: 2,
+                prev_randao: B256::ZERO,
+                suggested_fee_recipient: Address::ZERO,
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            },
+            transactions: Some(transactions),
+            no_tx_pool: None,
+            gas_limit: Some(30_000_000),
+            eip_1559_params: None,
+            min_base_fee: None,
+        };
+        builder.build_block(attrs).unwrap();
+        builder.take_execution_trace().unwrap()
+    }
+
+    #[test]
+    fn test_identical_traces() {
+        let config = RollupConfig::default();
+        let transactions = vec![
+            deposit(Address::repeat_byte(1), Address::repeat_byte(2), 10),
+            deposit(Address::repeat_byte(3), Address::repeat_byte(4), 20),
+        ];
+
+        let left = execute(&config, transactions.clone());
+        let right = execute(&config, transactions);
+
+        assert_eq!(left.transactions.len(), 2);
+        assert!(left.transactions.iter().all(|tx| tx.success && !tx.state_diff.is_empty()));
+        assert_eq!(left.first_divergence(&right), None);
+    }
+
+    #[test]
+    fn test_first_divergence() {
+        let config = RollupConfig::default();
+        let first = deposit(Address::repeat_byte(1), Address::repeat_byte(2), 10);
+
+        let left = execute(
+            &config,
+            vec![first.clone(), deposit(Address::repeat_byte(3), Address::repeat_byte(4), 20)],
+        );
+        let right = execute(
+            &config,
+            vec![first, deposit(Address::repeat_byte(3), Address::repeat_byte(5), 20)],
+        );
+
+        let divergence = left.first_divergence(&right).unwrap();
+        assert_eq!(divergence.index, 1);
+        assert_eq!(divergence.left.as_ref(), left.transactions.get(1));
+        assert_eq!(divergence.right.as_ref(), right.transactions.get(1));
+
+        let mut truncated = left.clone();
+        truncated.transactions.pop();
+        let divergence = left.first_divergence(&truncated).unwrap();
+        assert_eq!(divergence.index, 1);
+        assert_eq!(divergence.right, None);
+    }
+}
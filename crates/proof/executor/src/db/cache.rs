@@ -0,0 +1,119 @@
+//! Contains the [TrieDBCache], which caches resolved accounts, storage slots and bytecode of the
+//! [TrieDB].
+//!
+//! [TrieDB]: crate::TrieDB
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_trie::TrieAccount;
+use revm::{primitives::HashMap, state::Bytecode};
+
+/// The hit and miss counters of a [TrieDB]'s cache.
+///
+/// [TrieDB]: crate::TrieDB
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrieDBCacheStats {
+    /// The number of lookups served from the cache.
+    pub hits: u64,
+    /// The number of lookups resolved through the trie or the [TrieDBProvider].
+    ///
+    /// [TrieDBProvider]: crate::TrieDBProvider
+    pub misses: u64,
+}
+
+/// A cache of the accounts, storage slots and bytecode resolved by a [TrieDB].
+///
+/// Accounts and their storage are invalidated when the account is written to the trie. Bytecode is
+/// keyed by its hash and never goes stale.
+///
+/// [TrieDB]: crate::TrieDB
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TrieDBCache {
+    /// The resolved accounts, `None` if the account does not exist.
+    accounts: HashMap<Address, Option<TrieAccount>>,
+    /// The resolved storage slots of each account.
+    storage: HashMap<Address, HashMap<U256, U256>>,
+    /// The resolved bytecode by code hash.
+    bytecode: HashMap<B256, Bytecode>,
+    /// The hit and miss counters.
+    stats: TrieDBCacheStats,
+}
+
+impl TrieDBCache {
+    /// Returns the hit and miss counters of the cache.
+    pub(crate) const fn stats(&self) -> TrieDBCacheStats {
+        self.stats
+    }
+
+    /// Returns the cached account at `address`, if it was resolved.
+    pub(crate) fn account(&mut self, address: &Address) -> Option<Option<TrieAccount>> {
+        let account = self.accounts.get(address).copied();
+        self.count(account.is_some());
+        account
+    }
+
+    /// Caches the account resolved at `address`.
+    pub(crate) fn insert_account(&mut self, address: Address, account: Option<TrieAccount>) {
+        self.accounts.insert(address, account);
+    }
+
+    /// Returns the cached value of the storage slot `index` of `address`, if it was resolved.
+    pub(crate) fn storage(&mut self, address: &Address, index: &U256) -> Option<U256> {
+        let value = self.storage.get(address).and_then(|slots| slots.get(index)).copied();
+        self.count(value.is_some());
+        value
+    }
+
+    /// Caches the value resolved for the storage slot `index` of `address`.
+    pub(crate) fn insert_storage(&mut self, address: Address, index: U256, value: U256) {
+        self.storage.entry(address).or_default().insert(index, value);
+    }
+
+    /// Returns the cached bytecode with the given hash, if it was resolved.
+    pub(crate) fn bytecode(&mut self, code_hash: &B256) -> Option<Bytecode> {
+        let bytecode = self.bytecode.get(code_hash).cloned();
+        self.count(bytecode.is_some());
+        bytecode
+    }
+
+    /// Caches the bytecode resolved for the given hash.
+    pub(crate) fn insert_bytecode(&mut self, code_hash: B256, bytecode: Bytecode) {
+        self.bytecode.insert(code_hash, bytecode);
+    }
+
+    /// Invalidates the account at `address` and all of its storage slots.
+    pub(crate) fn invalidate_account(&mut self, address: &Address) {
+        self.accounts.remove(address);
+        self.storage.remove(address);
+    }
+
+    const fn count(&mut self, hit: bool) {
+        if hit {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalidate_account() {
+        let mut cache = TrieDBCache::default();
+        let address = Address::repeat_byte(1);
+        cache.insert_account(address, Some(TrieAccount::default()));
+        cache.insert_storage(address, U256::ZERO, U256::from(1));
+        cache.insert_storage(Address::repeat_byte(2), U256::ZERO, U256::from(2));
+
+        assert_eq!(cache.account(&address), Some(Some(TrieAccount::default())));
+        assert_eq!(cache.storage(&address, &U256::ZERO), Some(U256::from(1)));
+
+        cache.invalidate_account(&address);
+        assert_eq!(cache.account(&address), None);
+        assert_eq!(cache.storage(&address, &U256::ZERO), None);
+        assert_eq!(cache.storage(&Address::repeat_byte(2), &U256::ZERO), Some(U256::from(2)));
+        assert_eq!(cache.stats(), TrieDBCacheStats { hits: 3, misses: 2 });
+    }
+}
@@ -16,7 +16,11 @@ use revm::{
 };
 
 mod traits;
-pub use traits::{NoopTrieDBProvider, TrieDBProvider};
+pub use traits::{NoopTrieDBProvider, RecordingTrieDBProvider, TrieDBAccessSet, TrieDBProvider};
+
+mod cache;
+use cache::TrieDBCache;
+pub use cache::TrieDBCacheStats;
 
 /// A Trie DB that caches open state in-memory.
 ///
@@ -35,6 +39,9 @@ pub use traits::{NoopTrieDBProvider, TrieDBProvider};
 ///   [Self::state_root].
 /// - When querying for the code hash of an account, the [`TrieDBProvider`] is consulted to fetch
 ///   the code hash of the account.
+/// - Resolved accounts, storage slots and bytecode are cached, so that repeated lookups skip the
+///   trie walk, the hints and the [`TrieDBProvider`]. An account and its storage are evicted from
+///   the cache when the account is written to the trie by [Self::state_root].
 /// - When a [`BundleState`] changeset is committed to the parent [`State`] database, the changes
 ///   are first applied to the [`State`]'s cache, then the trie hash is recomputed with
 ///   [Self::state_root].
@@ -89,6 +96,8 @@ where
     storage_roots: HashMap<Address, TrieNode>,
     /// The parent block hash of the current block.
     parent_block_header: Sealed<Header>,
+    /// The cache of resolved accounts, storage slots and bytecode.
+    cache: TrieDBCache,
     /// The [`TrieDBProvider`]
     pub fetcher: F,
     /// The [`TrieHinter`]
@@ -106,6 +115,7 @@ where
             root_node: TrieNode::new_blinded(parent_block_header.state_root),
             storage_roots: Default::default(),
             parent_block_header,
+            cache: Default::default(),
             fetcher,
             hinter,
         }
@@ -126,6 +136,11 @@ where
         &self.storage_roots
     }
 
+    /// Returns the hit and miss counters of the account, storage and bytecode cache.
+    pub const fn cache_stats(&self) -> TrieDBCacheStats {
+        self.cache.stats()
+    }
+
     /// Returns a reference to the current parent block header of the trie DB.
     pub const fn parent_block_header(&self) -> &Sealed<Header> {
         &self.parent_block_header
@@ -157,8 +172,11 @@ where
         // Recompute the root hash of the trie.
         let root = self.root_node.blind();
 
+        let stats = self.cache.stats();
         debug!(
             target: "client_executor",
+            cache_hits = stats.hits,
+            cache_misses = stats.misses,
             "Recomputed state root: {root}",
         );
 
@@ -218,6 +236,7 @@ where
             if bundle_account.status.is_not_modified() {
                 continue;
             }
+            self.cache.invalidate_account(address);
 
             // Compute the path to the account in the trie.
             let account_path = Nibbles::unpack(hashed_address.as_slice());
@@ -340,16 +359,26 @@ where
     type Error = TrieDBError;
 
     fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
-        // Fetch the account from the trie.
-        let Some(trie_account) =
-            self.get_trie_account(&address, self.parent_block_header.number)?
-        else {
-            // If the account does not exist in the trie, return `Ok(None)`.
+        let trie_account = if let Some(trie_account) = self.cache.account(&address) {
+            trie_account
+        } else {
+            // Fetch the account from the trie.
+            let trie_account = self.get_trie_account(&address, self.parent_block_header.number)?;
+            self.cache.insert_account(address, trie_account);
+            trie_account
+        };
+
+        // If the account does not exist in the trie, return `Ok(None)`.
+        let Some(trie_account) = trie_account else {
             return Ok(None);
         };
 
-        // Insert the account's storage root into the cache.
-        self.storage_roots.insert(address, TrieNode::new_blinded(trie_account.storage_root));
+        // Insert the account's storage root into the cache. An existing storage root is kept, as
+        // it is written back to the account along with any storage changes, and keeps the storage
+        // nodes opened so far.
+        self.storage_roots
+            .entry(address)
+            .or_insert_with(|| TrieNode::new_blinded(trie_account.storage_root));
 
         // Return a partial DB account. The storage and code are not loaded out-right, and are
         // loaded optimistically in the `Database` + `DatabaseRef` trait implementations.
@@ -362,13 +391,24 @@ where
     }
 
     fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
-        self.fetcher
+        if let Some(bytecode) = self.cache.bytecode(&code_hash) {
+            return Ok(bytecode);
+        }
+
+        let bytecode = self
+            .fetcher
             .bytecode_by_hash(code_hash)
             .map(Bytecode::new_raw)
-            .map_err(|e| TrieDBError::Provider(e.to_string()))
+            .map_err(|e| TrieDBError::Provider(e.to_string()))?;
+        self.cache.insert_bytecode(code_hash, bytecode.clone());
+        Ok(bytecode)
     }
 
     fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        if let Some(value) = self.cache.storage(&address, &index) {
+            return Ok(value);
+        }
+
         // Send a hint to the host to fetch the storage proof.
         self.hinter
             .hint_storage_proof(address, index, self.parent_block_header.number)
@@ -390,10 +430,12 @@ where
                         // Decode the storage slot value.
                         let int_slot = U256::decode(&mut slot_value.as_ref())
                             .map_err(TrieNodeError::RLPError)?;
+                        self.cache.insert_storage(address, index, int_slot);
                         Ok(int_slot)
                     }
                     None => {
                         // If the storage slot does not exist, return zero.
+                        self.cache.insert_storage(address, index, U256::ZERO);
                         Ok(U256::ZERO)
                     }
                 }
@@ -427,9 +469,18 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::StatelessL2Builder;
+    use alloc::{string::String, vec};
     use alloy_consensus::Sealable;
-    use alloy_primitives::b256;
-    use kona_mpt::NoopTrieHinter;
+    use alloy_eips::Encodable2718;
+    use alloy_op_evm::OpEvmFactory;
+    use alloy_primitives::{Bytes, TxKind, b256, bytes};
+    use alloy_rpc_types_engine::PayloadAttributes;
+    use core::cell::Cell;
+    use kona_genesis::RollupConfig;
+    use kona_mpt::{NoopTrieHinter, TrieProvider};
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
 
     fn new_test_db() -> TrieDB<NoopTrieDBProvider, NoopTrieHinter> {
         TrieDB::new(Header::default().seal_slow(), NoopTrieDBProvider, NoopTrieHinter)
@@ -486,4 +537,167 @@ mod tests {
             b256!("78dec18c6d7da925bbe773c315653cdc70f6444ed6c1de9ac30bdb36cff74c3b")
         );
     }
+
+    /// Increments the storage slot `0` of the contract.
+    const COUNTER_CODE: Bytes = bytes!("600054600101600055");
+
+    /// A [TrieDBProvider] serving a state with a counter contract, counting its lookups.
+    #[derive(Debug, Default)]
+    struct CountingProvider {
+        nodes: HashMap<B256, TrieNode>,
+        bytecodes: HashMap<B256, Bytes>,
+        trie_node_lookups: Cell<usize>,
+        bytecode_lookups: Cell<usize>,
+    }
+
+    impl CountingProvider {
+        /// Returns the provider and the state root holding the counter contract at `address`.
+        fn with_counter(address: Address) -> (Self, B256) {
+            let mut provider = Self::default();
+
+            let mut storage = TrieNode::Empty;
+            let mut value = Vec::new();
+            U256::from(1).encode(&mut value);
+            let slot_path = Nibbles::unpack(keccak256(U256::ZERO.to_be_bytes::<32>()));
+            storage.insert(&slot_path, value.into(), &NoopTrieDBProvider).unwrap();
+            let storage_root = storage.blind();
+            provider.nodes.insert(storage_root, storage);
+
+            let account = TrieAccount {
+                storage_root,
+                code_hash: keccak256(&COUNTER_CODE),
+                ..Default::default()
+            };
+            let mut account_buf = Vec::new();
+            account.encode(&mut account_buf);
+            let mut state = TrieNode::Empty;
+            let account_path = Nibbles::unpack(keccak256(address));
+            state.insert(&account_path, account_buf.into(), &NoopTrieDBProvider).unwrap();
+            let state_root = state.blind();
+            provider.nodes.insert(state_root, state);
+            provider.bytecodes.insert(keccak256(&COUNTER_CODE), COUNTER_CODE);
+
+            (provider, state_root)
+        }
+    }
+
+    impl TrieProvider for CountingProvider {
+        type Error = String;
+
+        fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+            self.trie_node_lookups.set(self.trie_node_lookups.get() + 1);
+            self.nodes.get(&key).cloned().ok_or_else(|| "missing trie node".into())
+        }
+    }
+
+    impl TrieDBProvider for CountingProvider {
+        fn bytecode_by_hash(&self, code_hash: B256) -> Result<Bytes, Self::Error> {
+            self.bytecode_lookups.set(self.bytecode_lookups.get() + 1);
+            self.bytecodes.get(&code_hash).cloned().ok_or_else(|| "missing bytecode".into())
+        }
+
+        fn header_by_hash(&self, _hash: B256) -> Result<Header, Self::Error> {
+            Err("missing header".into())
+        }
+    }
+
+    fn call_counter(counter: Address, sender: u8, timestamp: u64) -> OpPayloadAttributes {
+        let transactions = (0..3)
+            .map(|i| {
+                let tx = TxDeposit {
+                    source_hash: B256::repeat_byte(sender + i),
+                    from: Address::repeat_byte(sender + i),
+                    to: TxKind::Call(counter),
+                    gas_limit: 100_000,
+                    ..Default::default()
+                };
+                OpTxEnvelope::Deposit(tx.seal_slow()).encoded_2718().into()
+            })
+            .collect();
+        OpPayloadAttributes {
+            payload_attributes: PayloadAttributes {
+                timestamp,
+                prev_randao: B256::ZERO,
+                suggested_fee_recipient: Address::ZERO,
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            },
+            transactions: Some(transactions),
+            no_tx_pool: None,
+            gas_limit: Some(30_000_000),
+            eip_1559_params: None,
+            min_base_fee: None,
+        }
+    }
+
+    #[test]
+    fn test_cache_shared_contract() {
+        let config = RollupConfig::default();
+        let counter = Address::repeat_byte(0xc0);
+        let (provider, state_root) = CountingProvider::with_counter(counter);
+        let parent_header = Header { state_root, gas_limit: 30_000_000, ..Default::default() };
+        let mut builder = StatelessL2Builder::new(
+            &config,
+            OpEvmFactory::default(),
+            provider,
+            NoopTrieHinter,
+            parent_header.seal_slow(),
+        );
+
+        builder.build_block(call_counter(counter, 0x10, 2)).unwrap();
+        builder.build_block(call_counter(counter, 0x20, 4)).unwrap();
+
+        // Every block resolves the contract's code again, but only the first one reaches the
+        // provider.
+        assert_eq!(builder.trie_db.fetcher.bytecode_lookups.get(), 1);
+        assert!(builder.trie_db.cache_stats().hits > 0);
+
+        // The counter was written by both blocks, so its storage must not be served stale.
+        assert_eq!(builder.trie_db.fetch_storage(counter, U256::ZERO).unwrap(), U256::from(7));
+        let lookups = builder.trie_db.fetcher.trie_node_lookups.get();
+        assert_eq!(builder.trie_db.fetch_storage(counter, U256::ZERO).unwrap(), U256::from(7));
+        assert_eq!(builder.trie_db.fetcher.trie_node_lookups.get(), lookups);
+    }
+
+    #[test]
+    fn test_recorded_witness() {
+        let config = RollupConfig::default();
+        let counter = Address::repeat_byte(0xc0);
+        let (provider, state_root) = CountingProvider::with_counter(counter);
+        let parent_header =
+            Header { state_root, gas_limit: 30_000_000, ..Default::default() }.seal_slow();
+        let execute = |provider| {
+            StatelessL2Builder::new(
+                &config,
+                OpEvmFactory::default(),
+                provider,
+                NoopTrieHinter,
+                parent_header.clone(),
+            )
+            .build_block(call_counter(counter, 0x10, 2))
+            .map(|outcome| outcome.header)
+        };
+
+        let mut builder = StatelessL2Builder::new(
+            &config,
+            OpEvmFactory::default(),
+            RecordingTrieDBProvider::new(provider),
+            NoopTrieHinter,
+            parent_header.clone(),
+        );
+        let header = builder.build_block(call_counter(counter, 0x10, 2)).unwrap().header;
+        let (mut provider, accessed) = builder.trie_db.fetcher.into_parts();
+        assert!(!accessed.trie_nodes.is_empty());
+        assert!(!accessed.bytecodes.is_empty());
+
+        // The recorded keys are enough to execute the block again.
+        provider.nodes.retain(|hash, _| accessed.trie_nodes.contains(hash));
+        provider.bytecodes.retain(|hash, _| accessed.bytecodes.contains(hash));
+        assert_eq!(execute(provider).unwrap(), header);
+
+        // Without the contract code, execution fails.
+        let (mut provider, _) = CountingProvider::with_counter(counter);
+        provider.bytecodes.clear();
+        assert!(execute(provider).is_err());
+    }
 }
@@ -1,9 +1,10 @@
 //! Contains the [TrieDBProvider] trait for fetching EVM bytecode hash preimages as well as [Header]
 //! preimages.
 
-use alloc::string::String;
+use alloc::{collections::BTreeSet, string::String};
 use alloy_consensus::Header;
 use alloy_primitives::{B256, Bytes};
+use core::cell::RefCell;
 use kona_mpt::{TrieNode, TrieProvider};
 
 /// The [TrieDBProvider] trait defines the synchronous interface for fetching EVM bytecode hash
@@ -55,3 +56,82 @@ impl TrieDBProvider for NoopTrieDBProvider {
         Ok(Header::default())
     }
 }
+
+/// A [TrieDBProvider] that records the key of every preimage requested from the wrapped provider,
+/// whether or not the lookup succeeds.
+///
+/// The recorded [TrieDBAccessSet] is the witness needed to execute the same blocks again, e.g. to
+/// build an executor test fixture.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingTrieDBProvider<P> {
+    /// The wrapped provider.
+    inner: P,
+    /// The keys requested so far.
+    accessed: RefCell<TrieDBAccessSet>,
+}
+
+impl<P> RecordingTrieDBProvider<P> {
+    /// Creates a new [RecordingTrieDBProvider] wrapping the given provider.
+    pub fn new(inner: P) -> Self {
+        Self { inner, accessed: Default::default() }
+    }
+
+    /// Returns a reference to the wrapped provider.
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the keys requested so far.
+    pub fn accessed(&self) -> TrieDBAccessSet {
+        self.accessed.borrow().clone()
+    }
+
+    /// Consumes `self`, returning the wrapped provider and the requested keys.
+    pub fn into_parts(self) -> (P, TrieDBAccessSet) {
+        (self.inner, self.accessed.into_inner())
+    }
+}
+
+impl<P: TrieDBProvider> TrieProvider for RecordingTrieDBProvider<P> {
+    type Error = P::Error;
+
+    fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+        self.accessed.borrow_mut().trie_nodes.insert(key);
+        self.inner.trie_node_by_hash(key)
+    }
+}
+
+impl<P: TrieDBProvider> TrieDBProvider for RecordingTrieDBProvider<P> {
+    fn bytecode_by_hash(&self, code_hash: B256) -> Result<Bytes, Self::Error> {
+        self.accessed.borrow_mut().bytecodes.insert(code_hash);
+        self.inner.bytecode_by_hash(code_hash)
+    }
+
+    fn header_by_hash(&self, hash: B256) -> Result<Header, Self::Error> {
+        self.accessed.borrow_mut().headers.insert(hash);
+        self.inner.header_by_hash(hash)
+    }
+}
+
+/// The preimage keys requested from a [RecordingTrieDBProvider].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrieDBAccessSet {
+    /// The hashes of the requested trie nodes, covering the accounts and storage slots accessed.
+    pub trie_nodes: BTreeSet<B256>,
+    /// The hashes of the requested bytecode.
+    pub bytecodes: BTreeSet<B256>,
+    /// The hashes of the requested block headers.
+    pub headers: BTreeSet<B256>,
+}
+
+impl TrieDBAccessSet {
+    /// Returns `true` if no preimage was requested.
+    pub fn is_empty(&self) -> bool {
+        self.trie_nodes.is_empty() && self.bytecodes.is_empty() && self.headers.is_empty()
+    }
+
+    /// Returns an iterator over all requested keys.
+    pub fn keys(&self) -> impl Iterator<Item = &B256> {
+        self.trie_nodes.iter().chain(&self.bytecodes).chain(&self.headers)
+    }
+}
@@ -13,9 +13,14 @@ extern crate alloc;
 extern crate tracing;
 
 mod db;
-pub use db::{NoopTrieDBProvider, TrieDB, TrieDBProvider};
+pub use db::{
+    NoopTrieDBProvider, RecordingTrieDBProvider, TrieDB, TrieDBAccessSet, TrieDBCacheStats,
+    TrieDBProvider,
+};
 
 mod builder;
+#[cfg(feature = "execution-trace")]
+pub use builder::{AccountDiff, ExecutionTrace, TraceDivergence, TransactionTrace};
 pub use builder::{BlockBuildingOutcome, StatelessL2Builder, compute_receipts_root};
 
 mod errors;
@@ -1,7 +1,7 @@
 //! Test utilities for the executor.
 
-use crate::{StatelessL2Builder, TrieDBProvider};
-use alloy_consensus::Header;
+use crate::{RecordingTrieDBProvider, StatelessL2Builder, TrieDBProvider};
+use alloy_consensus::{Header, Sealed};
 use alloy_op_evm::OpEvmFactory;
 use alloy_primitives::{B256, Bytes, Sealable};
 use alloy_provider::{Provider, RootProvider, network::primitives::BlockTransactions};
@@ -17,11 +17,18 @@ use rocksdb::{DB, Options};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::BufReader;
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 use tokio::{fs, runtime::Handle, sync::Mutex};
 
-/// Executes a [ExecutorTestFixture] stored at the passed `fixture_path` and asserts that the
-/// produced block hash matches the expected block hash.
+/// Executes the [ExecutorTestFixture]s stored at the passed `fixture_path` and asserts that the
+/// produced block hashes match the expected block hashes.
+///
+/// Archives of a block range hold a fixture per block, which are executed in order on top of each
+/// other.
 pub async fn run_test_fixture(fixture_path: PathBuf) {
     // First, untar the fixture.
     let fixture_dir = tempfile::tempdir().expect("Failed to create temporary directory");
@@ -41,25 +48,39 @@ pub async fn run_test_fixture(fixture_path: PathBuf) {
     let kv_store = DB::open(&options, fixture_dir.path().join("kv"))
         .unwrap_or_else(|e| panic!("Failed to open database at {fixture_dir:?}: {e}"));
     let provider = DiskTrieNodeProvider::new(kv_store);
-    let fixture: ExecutorTestFixture =
-        serde_json::from_slice(&fs::read(fixture_dir.path().join("fixture.json")).await.unwrap())
-            .expect("Failed to deserialize fixture");
+
+    let mut fixtures = Vec::new();
+    let mut entries = fs::read_dir(fixture_dir.path()).await.unwrap();
+    while let Some(entry) = entries.next_entry().await.unwrap() {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if file_name.starts_with("fixture") && file_name.ends_with(".json") {
+            let fixture: ExecutorTestFixture =
+                serde_json::from_slice(&fs::read(entry.path()).await.unwrap())
+                    .expect("Failed to deserialize fixture");
+            fixtures.push(fixture);
+        }
+    }
+    fixtures.sort_by_key(|fixture| fixture.parent_header.number);
+    let first = fixtures.first().expect("No fixture found");
 
     let mut executor = StatelessL2Builder::new(
-        &fixture.rollup_config,
+        &first.rollup_config,
         OpEvmFactory::default(),
         provider,
         NoopTrieHinter,
-        fixture.parent_header.seal_slow(),
+        first.parent_header.clone().seal_slow(),
     );
 
-    let outcome = executor.build_block(fixture.executing_payload).unwrap();
+    for fixture in &fixtures {
+        let outcome = executor.build_block(fixture.executing_payload.clone()).unwrap();
 
-    assert_eq!(
-        outcome.header.hash(),
-        fixture.expected_block_hash,
-        "Produced header does not match the expected header"
-    );
+        assert_eq!(
+            outcome.header.hash(),
+            fixture.expected_block_hash,
+            "Produced header does not match the expected header"
+        );
+    }
 }
 
 /// The test fixture format for the [`StatelessL2Builder`].
@@ -122,6 +143,92 @@ impl ExecutorTestFixtureCreator {
 impl ExecutorTestFixtureCreator {
     /// Create a static test fixture with the configuration provided.
     pub async fn create_static_fixture(self) {
+        let rollup_config = self.rollup_config().await;
+        let block = self.fetch_block(&rollup_config, self.block_number).await;
+
+        let fixture_path = self.data_dir.join("fixture.json");
+        let fixture = ExecutorTestFixture {
+            rollup_config: rollup_config.clone(),
+            parent_header: block.parent_header.inner().clone(),
+            executing_payload: block.payload_attrs.clone(),
+            expected_block_hash: block.header.hash_slow(),
+        };
+
+        let mut executor = StatelessL2Builder::new(
+            &rollup_config,
+            OpEvmFactory::default(),
+            RecordingTrieDBProvider::new(self),
+            NoopTrieHinter,
+            block.parent_header,
+        );
+        let outcome = executor.build_block(block.payload_attrs).expect("Failed to execute block");
+
+        info!("Block: {:#?}", outcome);
+        assert_eq!(
+            outcome.header.inner(),
+            &block.header,
+            "Produced header does not match the expected header"
+        );
+        Self::check_witness(executor.trie_db.fetcher).await;
+        fs::write(fixture_path.as_path(), serde_json::to_vec(&fixture).unwrap()).await.unwrap();
+
+        let data_dir = fixture_path.parent().unwrap();
+        Self::tar_fixture(data_dir, data_dir.file_name().unwrap()).await;
+    }
+
+    /// Create a static test fixture for each block from `start` to `end`, inclusive.
+    ///
+    /// The blocks are executed in order on top of each other, so they share a single witness.
+    /// Each fixture is written to `fixture-{number}.json` next to the witness, and the fixtures
+    /// are archived together as `block-{start}-{end}.tar.gz`.
+    ///
+    /// ## Panics
+    /// Panics if the range is empty, or if the RPC cannot serve the state of any of the blocks.
+    pub async fn create_static_fixture_range(self, start: u64, end: u64) {
+        assert!(start <= end, "Invalid block range {start}..={end}");
+
+        let rollup_config = self.rollup_config().await;
+        let mut blocks = Vec::with_capacity((end - start + 1) as usize);
+        for block_number in start..=end {
+            blocks.push(self.fetch_block(&rollup_config, block_number).await);
+        }
+
+        let data_dir = self.data_dir.clone();
+        let parent_header = blocks[0].parent_header.clone();
+        let mut executor = StatelessL2Builder::new(
+            &rollup_config,
+            OpEvmFactory::default(),
+            RecordingTrieDBProvider::new(self),
+            NoopTrieHinter,
+            parent_header,
+        );
+        for block in blocks {
+            let fixture = ExecutorTestFixture {
+                rollup_config: rollup_config.clone(),
+                parent_header: block.parent_header.inner().clone(),
+                executing_payload: block.payload_attrs.clone(),
+                expected_block_hash: block.header.hash_slow(),
+            };
+
+            let outcome =
+                executor.build_block(block.payload_attrs).expect("Failed to execute block");
+            assert_eq!(
+                outcome.header.inner(),
+                &block.header,
+                "Produced header does not match the expected header of block {}",
+                block.header.number
+            );
+
+            let fixture_path = data_dir.join(format!("fixture-{}.json", block.header.number));
+            fs::write(fixture_path, serde_json::to_vec(&fixture).unwrap()).await.unwrap();
+        }
+        Self::check_witness(executor.trie_db.fetcher).await;
+
+        Self::tar_fixture(&data_dir, format!("block-{start}-{end}")).await;
+    }
+
+    /// Returns the rollup config of the chain, read from the rollup config file if provided.
+    async fn rollup_config(&self) -> RollupConfig {
         let chain_id = self.provider.get_chain_id().await.expect("Failed to get chain ID");
         let rollup_config = if let Some(rollup_dir) = &self.rollup_dir {
             debug!("Using rollup config from {rollup_dir:?}");
@@ -133,16 +240,24 @@ impl ExecutorTestFixtureCreator {
             ROLLUP_CONFIGS.get(&chain_id).expect("Rollup config not found").clone()
         };
         debug!("Using provided rollup config: {:?}", rollup_config);
+        rollup_config
+    }
 
+    /// Fetches the block with the given number and the payload attributes that reproduce it.
+    ///
+    /// ## Panics
+    /// Panics if the RPC cannot serve the state the block is executed on, e.g. because it is not
+    /// archival.
+    async fn fetch_block(&self, rollup_config: &RollupConfig, block_number: u64) -> FixtureBlock {
         let executing_block = self
             .provider
-            .get_block_by_number(self.block_number.into())
+            .get_block_by_number(block_number.into())
             .await
             .expect("Failed to get parent block")
             .expect("Block not found");
         let parent_block = self
             .provider
-            .get_block_by_number((self.block_number - 1).into())
+            .get_block_by_number((block_number - 1).into())
             .await
             .expect("Failed to get parent block")
             .expect("Block not found");
@@ -150,6 +265,20 @@ impl ExecutorTestFixtureCreator {
         let executing_header = executing_block.header;
         let parent_header = parent_block.header.inner.seal_slow();
 
+        // The witness is fetched from the parent state, which only archival nodes keep for old
+        // blocks.
+        if let Err(e) = self
+            .provider
+            .client()
+            .request::<&[B256; 1], Bytes>("debug_dbGet", &[parent_header.state_root])
+            .await
+        {
+            panic!(
+                "The state of block {} is not available on the RPC, which must be archival: {e}",
+                parent_header.number
+            );
+        }
+
         let encoded_executing_transactions = match executing_block.transactions {
             BlockTransactions::Hashes(transactions) => {
                 let mut encoded_transactions = Vec::with_capacity(transactions.len());
@@ -194,36 +323,28 @@ impl ExecutorTestFixtureCreator {
             }),
         };
 
-        let fixture_path = self.data_dir.join("fixture.json");
-        let fixture = ExecutorTestFixture {
-            rollup_config: rollup_config.clone(),
-            parent_header: parent_header.inner().clone(),
-            executing_payload: payload_attrs.clone(),
-            expected_block_hash: executing_header.hash_slow(),
-        };
-
-        let mut executor = StatelessL2Builder::new(
-            &rollup_config,
-            OpEvmFactory::default(),
-            self,
-            NoopTrieHinter,
-            parent_header,
-        );
-        let outcome = executor.build_block(payload_attrs).expect("Failed to execute block");
+        FixtureBlock { header: executing_header.inner, parent_header, payload_attrs }
+    }
 
-        info!("Block: {:#?}", outcome);
-        assert_eq!(
-            outcome.header.inner(),
-            &executing_header.inner,
-            "Produced header does not match the expected header"
-        );
-        fs::write(fixture_path.as_path(), serde_json::to_vec(&fixture).unwrap()).await.unwrap();
+    /// Checks that the fixture holds the preimage of every key requested during execution.
+    async fn check_witness(recorder: RecordingTrieDBProvider<Self>) {
+        let (creator, accessed) = recorder.into_parts();
+        let kv_store = creator.kv_store.lock().await;
+        for key in accessed.keys() {
+            assert!(
+                kv_store.get(key).expect("Failed to read fixture").is_some(),
+                "Fixture is missing the preimage of {key}"
+            );
+        }
+    }
 
-        // Tar the fixture.
-        let data_dir = fixture_path.parent().unwrap();
+    /// Archives `data_dir` next to it as `{name}.tar.gz`, and removes it.
+    async fn tar_fixture(data_dir: &Path, name: impl AsRef<OsStr>) {
+        let mut archive = name.as_ref().to_os_string();
+        archive.push(".tar.gz");
         tokio::process::Command::new("tar")
             .arg("-czf")
-            .arg(data_dir.with_extension("tar.gz").file_name().unwrap())
+            .arg(archive)
             .arg(data_dir.file_name().unwrap())
             .current_dir(data_dir.parent().unwrap())
             .output()
@@ -235,6 +356,17 @@ impl ExecutorTestFixtureCreator {
     }
 }
 
+/// A block to create a fixture for, with the inputs to execute it.
+#[derive(Debug)]
+struct FixtureBlock {
+    /// The header of the block.
+    header: Header,
+    /// The sealed header of the parent block.
+    parent_header: Sealed<Header>,
+    /// The payload attributes that reproduce the block.
+    payload_attrs: OpPayloadAttributes,
+}
+
 impl TrieProvider for ExecutorTestFixtureCreator {
     type Error = TestTrieNodeProviderError;
 
@@ -394,3 +526,29 @@ pub enum TestTrieNodeProviderError {
     #[error("Failed to write back to key value store")]
     KVStore,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates and replays a two-block fixture from the archival L2 RPC at `L2_RPC_URL`.
+    #[tokio::test(flavor = "multi_thread")]
+    #[ignore = "requires an archival L2 RPC at L2_RPC_URL"]
+    async fn test_create_static_fixture_range() {
+        let url = std::env::var("L2_RPC_URL").expect("L2_RPC_URL not set");
+        let base_dir = tempfile::tempdir().unwrap();
+
+        let http = Http::<Client>::new(url.parse().unwrap());
+        let provider: RootProvider = RootProvider::new(RpcClient::new(http, false));
+        let start = provider.get_block_number().await.unwrap() - 10;
+
+        ExecutorTestFixtureCreator::new(&url, start, base_dir.path().to_path_buf(), None)
+            .create_static_fixture_range(start, start + 1)
+            .await;
+
+        let archive = base_dir.path().join(format!("block-{start}-{}.tar.gz", start + 1));
+        assert!(archive.exists());
+        assert!(!base_dir.path().join(format!("block-{start}")).exists());
+        run_test_fixture(archive).await;
+    }
+}
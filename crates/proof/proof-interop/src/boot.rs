@@ -1,8 +1,10 @@
 //! This module contains the prologue phase of the client program, pulling in the boot information
 //! through the `PreimageOracle` ABI as local keys.
 
-use crate::{HintType, INVALID_TRANSITION, INVALID_TRANSITION_HASH, PreState};
-use alloc::{string::ToString, vec::Vec};
+use crate::{
+    HintType, INVALID_TRANSITION, INVALID_TRANSITION_HASH, PreState, TRANSITION_STATE_MAX_STEPS,
+};
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
 use alloy_primitives::{B256, Bytes, U256};
 use alloy_rlp::Decodable;
 use kona_genesis::{L1ChainConfig, RollupConfig};
@@ -110,6 +112,7 @@ impl BootInfo {
 
         let agreed_pre_state =
             PreState::decode(&mut raw_pre_state.as_ref()).map_err(OracleProviderError::Rlp)?;
+        validate_pre_state(&agreed_pre_state, l2_claim_block)?;
 
         let chain_ids: Vec<_> = match agreed_pre_state {
             PreState::SuperRoot(ref super_root) => {
@@ -140,6 +143,10 @@ impl BootInfo {
             serde_json::from_slice(&ser_cfg).map_err(OracleProviderError::Serde)?
         };
 
+        if let Some(chain_id) = chain_ids.iter().find(|id| !rollup_configs.contains_key(id)) {
+            return Err(BootstrapError::MissingRollupConfig(*chain_id));
+        }
+
         // Attempt to load the l1 config from the chain ID. If there is no config for the chain,
         // fall back to loading the config from the preimage oracle.
 
@@ -211,6 +218,87 @@ pub enum BootstrapError {
     /// The l1 config is invalid because the chain ids are not the same.
     #[error("The l1 config is invalid because the chain ids are not the same.")]
     InvalidL1Config,
+    /// The agreed pre-state has no output roots.
+    #[error("The agreed pre-state has no output roots.")]
+    EmptyPreState,
+    /// The agreed pre-state has several output roots for the same chain.
+    #[error("The agreed pre-state has several output roots for chain ID {0}.")]
+    DuplicateChainId(u64),
+    /// The step of the agreed transition state is beyond the last step.
+    #[error("The agreed transition state step {0} exceeds {max}.", max = TRANSITION_STATE_MAX_STEPS)]
+    InvalidTransitionStep(u64),
+    /// The agreed transition state has more pending blocks than steps taken or chains.
+    #[error(
+        "The agreed transition state has {pending} pending blocks, at step {step} with {chains} chains."
+    )]
+    InvalidPendingProgress {
+        /// The number of pending blocks.
+        pending: usize,
+        /// The step of the transition state.
+        step: u64,
+        /// The number of chains of the pre-state.
+        chains: usize,
+    },
+    /// The claimed L2 timestamp is not after the timestamp of the agreed transition state.
+    #[error("The claimed L2 timestamp {claimed} is not after the pre-state timestamp {pre_state}.")]
+    ClaimedTimestampNotAfterPreState {
+        /// The claimed L2 timestamp.
+        claimed: u64,
+        /// The timestamp of the agreed pre-state.
+        pre_state: u64,
+    },
+    /// No rollup config was found for a chain of the agreed pre-state.
+    #[error("No rollup config for chain ID {0}.")]
+    MissingRollupConfig(u64),
+}
+
+/// Checks the consistency of the agreed pre-state with itself and the claimed L2 timestamp, so that
+/// malformed boot info is rejected before any expensive work.
+///
+/// A claimed timestamp at or before a [SuperRoot] pre-state is valid, as it denotes a trace
+/// extension, but not one at or before a [TransitionState] pre-state.
+///
+/// [SuperRoot]: kona_interop::SuperRoot
+/// [TransitionState]: crate::TransitionState
+pub fn validate_pre_state(
+    pre_state: &PreState,
+    claimed_l2_timestamp: u64,
+) -> Result<(), BootstrapError> {
+    let super_root = match pre_state {
+        PreState::SuperRoot(super_root) => super_root,
+        PreState::TransitionState(transition_state) => &transition_state.pre_state,
+    };
+    if super_root.output_roots.is_empty() {
+        return Err(BootstrapError::EmptyPreState);
+    }
+    let mut chain_ids = BTreeSet::new();
+    if let Some(root) = super_root.output_roots.iter().find(|root| !chain_ids.insert(root.chain_id))
+    {
+        return Err(BootstrapError::DuplicateChainId(root.chain_id));
+    }
+
+    let PreState::TransitionState(transition_state) = pre_state else {
+        return Ok(());
+    };
+    if transition_state.step > TRANSITION_STATE_MAX_STEPS {
+        return Err(BootstrapError::InvalidTransitionStep(transition_state.step));
+    }
+    let pending = transition_state.pending_progress.len();
+    let chains = super_root.output_roots.len();
+    if pending as u64 > transition_state.step || pending > chains {
+        return Err(BootstrapError::InvalidPendingProgress {
+            pending,
+            step: transition_state.step,
+            chains,
+        });
+    }
+    if claimed_l2_timestamp <= super_root.timestamp {
+        return Err(BootstrapError::ClaimedTimestampNotAfterPreState {
+            claimed: claimed_l2_timestamp,
+            pre_state: super_root.timestamp,
+        });
+    }
+    Ok(())
 }
 
 /// Reads the raw pre-state from the preimage oracle.
@@ -238,3 +326,86 @@ where
 
     Ok(Bytes::from(pre))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{OptimisticBlock, TransitionState};
+    use alloc::vec;
+    use kona_interop::{OutputRootWithChain, SuperRoot};
+
+    fn super_root(chain_ids: &[u64]) -> SuperRoot {
+        SuperRoot {
+            timestamp: 10,
+            output_roots: chain_ids
+                .iter()
+                .map(|chain_id| OutputRootWithChain::new(*chain_id, B256::ZERO))
+                .collect(),
+        }
+    }
+
+    fn transition_state(pending: usize, step: u64) -> PreState {
+        PreState::TransitionState(TransitionState::new(
+            super_root(&[1, 2]),
+            vec![OptimisticBlock::default(); pending],
+            step,
+        ))
+    }
+
+    #[test]
+    fn test_validate_valid_pre_states() {
+        validate_pre_state(&PreState::SuperRoot(super_root(&[1, 2])), 11).unwrap();
+        // trace extension
+        validate_pre_state(&PreState::SuperRoot(super_root(&[1, 2])), 5).unwrap();
+        validate_pre_state(&transition_state(1, 1), 11).unwrap();
+        validate_pre_state(&transition_state(2, TRANSITION_STATE_MAX_STEPS), 11).unwrap();
+    }
+
+    #[test]
+    fn test_validate_empty_pre_state() {
+        assert!(matches!(
+            validate_pre_state(&PreState::SuperRoot(super_root(&[])), 11),
+            Err(BootstrapError::EmptyPreState)
+        ));
+    }
+
+    #[test]
+    fn test_validate_duplicate_chain_id() {
+        assert!(matches!(
+            validate_pre_state(&PreState::SuperRoot(super_root(&[1, 2, 2])), 11),
+            Err(BootstrapError::DuplicateChainId(2))
+        ));
+        assert!(matches!(
+            validate_pre_state(&PreState::SuperRoot(super_root(&[2, 1, 2])), 11),
+            Err(BootstrapError::DuplicateChainId(2))
+        ));
+    }
+
+    #[test]
+    fn test_validate_transition_step() {
+        assert!(matches!(
+            validate_pre_state(&transition_state(2, TRANSITION_STATE_MAX_STEPS + 1), 11),
+            Err(BootstrapError::InvalidTransitionStep(step)) if step == TRANSITION_STATE_MAX_STEPS + 1
+        ));
+    }
+
+    #[test]
+    fn test_validate_pending_progress() {
+        assert!(matches!(
+            validate_pre_state(&transition_state(2, 1), 11),
+            Err(BootstrapError::InvalidPendingProgress { pending: 2, step: 1, chains: 2 })
+        ));
+        assert!(matches!(
+            validate_pre_state(&transition_state(3, 3), 11),
+            Err(BootstrapError::InvalidPendingProgress { pending: 3, step: 3, chains: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_validate_claimed_timestamp() {
+        assert!(matches!(
+            validate_pre_state(&transition_state(1, 1), 10),
+            Err(BootstrapError::ClaimedTimestampNotAfterPreState { claimed: 10, pre_state: 10 })
+        ));
+    }
+}
@@ -1,15 +1,18 @@
 //! Interop dependency resolution and consolidation logic.
 
 use crate::{BootInfo, OptimisticBlock, OracleInteropProvider, PreState};
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 use alloy_consensus::{Header, Sealed};
 use alloy_eips::Encodable2718;
 use alloy_evm::{EvmFactory, FromRecoveredTx, FromTxWithEncoded};
-use alloy_primitives::{Address, B256, Bytes, Sealable, TxKind, U256, address};
+use alloy_primitives::{Address, B256, Bytes, ChainId, Sealable, TxKind, U256, address};
 use alloy_rpc_types_engine::PayloadAttributes;
 use core::fmt::Debug;
 use kona_executor::{ExecutorError, StatelessL2Builder};
-use kona_interop::{MessageGraph, MessageGraphError};
+use kona_genesis::RollupConfig;
+use kona_interop::{
+    EnrichedExecutingMessage, InteropProvider, MessageGraph, MessageGraphError, MessageGraphResult,
+};
 use kona_mpt::OrderedListWalker;
 use kona_preimage::CommsClient;
 use kona_proof::{errors::OracleProviderError, l2::OracleL2ChainProvider};
@@ -38,6 +41,8 @@ where
     l2_providers: HashMap<u64, OracleL2ChainProvider<C>>,
     /// The inner [`EvmFactory`] to create EVM instances for re-execution of bad blocks.
     evm_factory: Evm,
+    /// The results of the previous consolidations, reused by [Self::consolidate_incremental].
+    incremental: IncrementalConsolidation,
 }
 
 impl<'a, C, Evm> SuperchainConsolidator<'a, C, Evm>
@@ -49,13 +54,25 @@ where
     /// Creates a new [SuperchainConsolidator] with the given providers and [Header]s.
     ///
     /// [Header]: alloy_consensus::Header
-    pub const fn new(
+    pub fn new(
         boot_info: &'a mut BootInfo,
         interop_provider: OracleInteropProvider<C>,
         l2_providers: HashMap<u64, OracleL2ChainProvider<C>>,
         evm_factory: Evm,
     ) -> Self {
-        Self { boot_info, interop_provider, l2_providers, evm_factory }
+        Self {
+            boot_info,
+            interop_provider,
+            l2_providers,
+            evm_factory,
+            incremental: IncrementalConsolidation::default(),
+        }
+    }
+
+    /// Returns the [OracleInteropProvider], e.g. to replace the local safe head of a chain before
+    /// [Self::consolidate_incremental].
+    pub const fn interop_provider_mut(&mut self) -> &mut OracleInteropProvider<C> {
+        &mut self.interop_provider
     }
 
     /// Recursively consolidates the dependencies of the blocks within the [MessageGraph].
@@ -63,53 +80,41 @@ where
     /// This method will recurse until all invalid cross-chain dependencies have been resolved,
     /// re-executing deposit-only blocks for chains with invalid dependencies as needed.
     pub async fn consolidate(&mut self) -> Result<(), ConsolidationError> {
-        info!(target: "superchain_consolidator", "Consolidating superchain");
-
-        loop {
-            match self.consolidate_once().await {
-                Ok(()) => {
-                    info!(target: "superchain_consolidator", "Superchain consolidation complete");
-                    return Ok(());
-                }
-                Err(ConsolidationError::MessageGraph(MessageGraphError::InvalidMessages(_))) => {
-                    // If invalid messages are still present in the graph, continue the loop.
-                    continue;
-                }
-                Err(e) => {
-                    error!(target: "superchain_consolidator", "Error consolidating superchain: {:?}", e);
-                    return Err(e);
-                }
-            }
-        }
+        self.consolidate_chains(None).await
     }
 
-    /// Performs a single iteration of the consolidation process.
-    ///
-    /// Step-wise:
-    /// 1. Derive a new [MessageGraph] from the current set of local safe [Header]s.
-    /// 2. Resolve the [MessageGraph].
-    /// 3. If any invalid messages are found, re-execute the bad block(s) only deposit transactions,
-    ///    and bubble up the error.
+    /// Consolidates the superchain after the local safe heads of the `changed` chains were
+    /// replaced, reusing the results of the previous consolidation for the other chains.
     ///
-    /// [Header]: alloy_consensus::Header
-    async fn consolidate_once(&mut self) -> Result<(), ConsolidationError> {
-        // Derive the message graph from the current set of block headers.
-        let graph = MessageGraph::derive(
-            self.interop_provider.local_safe_heads(),
-            &self.interop_provider,
-            &self.boot_info.rollup_configs,
-        )
-        .await?;
-
-        // Attempt to resolve the message graph. If there were any invalid messages found, we must
-        // initiate a re-execution of the original block, with only deposit transactions.
-        if let Err(MessageGraphError::InvalidMessages(invalid_chains)) = graph.resolve().await {
-            self.re_execute_deposit_only(&invalid_chains.keys().copied().collect::<Vec<_>>())
-                .await?;
-            return Err(MessageGraphError::InvalidMessages(invalid_chains).into());
-        }
+    /// Only the messages of the changed chains, and of the chains transitively executing messages
+    /// initiated on them, are re-validated. The result is the same as the one of
+    /// [Self::consolidate], which is run instead if no consolidation completed yet.
+    pub async fn consolidate_incremental(
+        &mut self,
+        changed: &[ChainId],
+    ) -> Result<(), ConsolidationError> {
+        self.consolidate_chains(Some(changed)).await
+    }
 
-        Ok(())
+    /// Consolidates the superchain, re-validating only the `changed` chains and their dependents
+    /// if given, until no invalid messages are left.
+    async fn consolidate_chains(
+        &mut self,
+        changed: Option<&[ChainId]>,
+    ) -> Result<(), ConsolidationError> {
+        info!(target: "superchain_consolidator", "Consolidating superchain");
+
+        let mut incremental = core::mem::take(&mut self.incremental);
+        let result = incremental.consolidate(self, changed).await;
+        self.incremental = incremental;
+
+        match &result {
+            Ok(()) => info!(target: "superchain_consolidator", "Superchain consolidation complete"),
+            Err(e) => {
+                error!(target: "superchain_consolidator", "Error consolidating superchain: {:?}", e)
+            }
+        }
+        result
     }
 
     /// Re-executes the original blocks, keyed by their chain IDs, with only their deposit
@@ -276,6 +281,177 @@ where
     }
 }
 
+impl<C, Evm> ConsolidationTarget for SuperchainConsolidator<'_, C, Evm>
+where
+    C: CommsClient + Debug + Send + Sync,
+    Evm: EvmFactory<Spec = OpSpecId> + Send + Sync + Debug + Clone + 'static,
+    <Evm as EvmFactory>::Tx: FromTxWithEncoded<OpTxEnvelope> + FromRecoveredTx<OpTxEnvelope>,
+{
+    type Provider = OracleInteropProvider<C>;
+    type Error = ConsolidationError;
+
+    fn local_safe_heads(&self) -> &HashMap<u64, Sealed<Header>> {
+        self.interop_provider.local_safe_heads()
+    }
+
+    fn provider(&self) -> &Self::Provider {
+        &self.interop_provider
+    }
+
+    fn rollup_configs(&self) -> &HashMap<u64, RollupConfig> {
+        &self.boot_info.rollup_configs
+    }
+
+    async fn replace_invalid_blocks(&mut self, chain_ids: &[u64]) -> Result<(), Self::Error> {
+        self.re_execute_deposit_only(chain_ids).await
+    }
+}
+
+/// The local safe blocks of the superchain, consolidated by [IncrementalConsolidation].
+trait ConsolidationTarget {
+    /// The provider of the headers and receipts of the chains.
+    type Provider: InteropProvider;
+    /// The error returned when consolidation fails.
+    type Error: From<MessageGraphError<<Self::Provider as InteropProvider>::Error>>;
+
+    /// Returns the local safe heads of the chains, keyed by chain ID.
+    fn local_safe_heads(&self) -> &HashMap<u64, Sealed<Header>>;
+
+    /// Returns the provider of the headers and receipts of the chains.
+    fn provider(&self) -> &Self::Provider;
+
+    /// Returns the rollup configs of the chains, keyed by chain ID.
+    fn rollup_configs(&self) -> &HashMap<u64, RollupConfig>;
+
+    /// Replaces the local safe heads of the given chains with their deposit-only blocks.
+    async fn replace_invalid_blocks(&mut self, chain_ids: &[u64]) -> Result<(), Self::Error>;
+}
+
+/// The consolidation loop, along with the results of the previous consolidations.
+#[derive(Debug, Default, Clone)]
+struct IncrementalConsolidation {
+    /// The chains the messages of each chain depend on, as of the last resolution.
+    dependencies: MessageDependencies,
+    /// Whether a consolidation has completed, so that its results can be reused.
+    consolidated: bool,
+}
+
+impl IncrementalConsolidation {
+    /// Consolidates the `target`, re-validating only the `changed` chains and their dependents if
+    /// given and a consolidation already completed, or all of the chains otherwise.
+    ///
+    /// Step-wise, until no invalid messages are left:
+    /// 1. Derive a [MessageGraph] from the local safe [Header]s of the chains to re-validate.
+    /// 2. Resolve the [MessageGraph].
+    /// 3. If any invalid messages are found, replace the bad block(s) with their deposit-only
+    ///    blocks, and re-validate the replaced chains along with their dependents.
+    async fn consolidate<T: ConsolidationTarget>(
+        &mut self,
+        target: &mut T,
+        changed: Option<&[u64]>,
+    ) -> Result<(), T::Error> {
+        let mut chains = match changed {
+            Some(changed) if self.consolidated => {
+                Some(self.dependencies.affected(changed.iter().copied()))
+            }
+            _ => None,
+        };
+
+        loop {
+            let all_chains;
+            let resolved = match &chains {
+                Some(chains) => chains,
+                None => {
+                    all_chains = target.local_safe_heads().keys().copied().collect();
+                    &all_chains
+                }
+            };
+
+            match resolve_chains(
+                target.local_safe_heads(),
+                resolved,
+                target.provider(),
+                target.rollup_configs(),
+                &mut self.dependencies,
+            )
+            .await
+            {
+                Ok(()) => {
+                    self.consolidated = true;
+                    return Ok(());
+                }
+                Err(MessageGraphError::InvalidMessages(invalid_chains)) => {
+                    let invalid = invalid_chains.keys().copied().collect::<Vec<_>>();
+                    target.replace_invalid_blocks(&invalid).await?;
+
+                    // The replaced blocks can only invalidate the messages initiated on them.
+                    if chains.is_some() {
+                        chains = Some(self.dependencies.affected(invalid));
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// The chains whose messages each chain executes.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct MessageDependencies(HashMap<u64, BTreeSet<u64>>);
+
+impl MessageDependencies {
+    /// Replaces the dependencies of `chains` with the ones of their `messages`.
+    fn record(&mut self, chains: &BTreeSet<u64>, messages: &[EnrichedExecutingMessage]) {
+        for chain_id in chains {
+            self.0.insert(*chain_id, BTreeSet::new());
+        }
+        for message in messages {
+            self.0
+                .entry(message.executing_chain_id)
+                .or_default()
+                .insert(message.inner.identifier.chainId.saturating_to());
+        }
+    }
+
+    /// Returns the `changed` chains, along with the chains transitively executing messages
+    /// initiated on them.
+    fn affected(&self, changed: impl IntoIterator<Item = u64>) -> BTreeSet<u64> {
+        let mut affected: BTreeSet<_> = changed.into_iter().collect();
+        loop {
+            let dependents = self
+                .0
+                .iter()
+                .filter(|(chain_id, dependencies)| {
+                    !affected.contains(chain_id) && !dependencies.is_disjoint(&affected)
+                })
+                .map(|(chain_id, _)| *chain_id)
+                .collect::<Vec<_>>();
+            if dependents.is_empty() {
+                return affected;
+            }
+            affected.extend(dependents);
+        }
+    }
+}
+
+/// Resolves the messages executed by the blocks of `chains`, recording the chains they depend on.
+async fn resolve_chains<P: InteropProvider>(
+    heads: &HashMap<u64, Sealed<Header>>,
+    chains: &BTreeSet<u64>,
+    provider: &P,
+    rollup_configs: &HashMap<u64, RollupConfig>,
+    dependencies: &mut MessageDependencies,
+) -> MessageGraphResult<(), P> {
+    let heads = heads
+        .iter()
+        .filter(|(chain_id, _)| chains.contains(chain_id))
+        .map(|(chain_id, header)| (*chain_id, header.clone()))
+        .collect();
+    let graph = MessageGraph::derive(&heads, provider, rollup_configs).await?;
+    dependencies.record(chains, graph.messages());
+    graph.resolve().await
+}
+
 /// An error type for the [SuperchainConsolidator] struct.
 #[derive(Debug, Error)]
 pub enum ConsolidationError {
@@ -298,3 +474,137 @@ pub enum ConsolidationError {
     #[error(transparent)]
     OracleProvider(#[from] OracleProviderError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use alloy_primitives::{hex, keccak256};
+    use kona_interop::{
+        ExecutingMessageBuilder, InteropProviderError, MockInteropProvider, SuperchainBuilder,
+    };
+
+    const MESSAGE: [u8; 4] = hex!("deadbeef");
+
+    /// Chain 2 executes a message initiated on chain 1, and chain 3 one initiated on chain 2. Only
+    /// the `initiating` chains actually initiate their message.
+    fn superchain(initiating: &[u64]) -> SuperchainBuilder {
+        let mut superchain = SuperchainBuilder::new();
+        for chain_id in 1..=3 {
+            let chain = superchain
+                .chain(chain_id)
+                .with_timestamp(2)
+                .with_block_time(2)
+                .with_interop_activation_time(0);
+            if initiating.contains(&chain_id) {
+                chain.add_initiating_message(MESSAGE.into());
+            }
+            if chain_id > 1 {
+                chain.add_executing_message(
+                    ExecutingMessageBuilder::default()
+                        .with_message_hash(keccak256(MESSAGE))
+                        .with_origin_chain_id(chain_id - 1)
+                        .with_origin_timestamp(2),
+                );
+            }
+        }
+        superchain
+    }
+
+    /// A superchain whose invalid blocks are replaced by blocks without any message, as their
+    /// deposit-only re-execution would.
+    #[derive(Debug)]
+    struct MockSuperchain {
+        heads: HashMap<u64, Sealed<Header>>,
+        cfgs: HashMap<u64, RollupConfig>,
+        provider: MockInteropProvider,
+    }
+
+    impl MockSuperchain {
+        fn new(initiating: &[u64]) -> Self {
+            let (heads, cfgs, provider) = superchain(initiating).build();
+            Self { heads, cfgs, provider }
+        }
+
+        fn head_hashes(&self) -> BTreeMap<u64, B256> {
+            self.heads.iter().map(|(chain_id, head)| (*chain_id, head.hash())).collect()
+        }
+    }
+
+    impl ConsolidationTarget for MockSuperchain {
+        type Provider = MockInteropProvider;
+        type Error = MessageGraphError<InteropProviderError>;
+
+        fn local_safe_heads(&self) -> &HashMap<u64, Sealed<Header>> {
+            &self.heads
+        }
+
+        fn provider(&self) -> &Self::Provider {
+            &self.provider
+        }
+
+        fn rollup_configs(&self) -> &HashMap<u64, RollupConfig> {
+            &self.cfgs
+        }
+
+        async fn replace_invalid_blocks(&mut self, chain_ids: &[u64]) -> Result<(), Self::Error> {
+            for chain_id in chain_ids {
+                let mut header = self.heads[chain_id].inner().clone();
+                header.extra_data = Bytes::from_static(b"deposit-only");
+                let header = header.seal_slow();
+
+                self.provider
+                    .headers
+                    .get_mut(chain_id)
+                    .unwrap()
+                    .insert(header.number, header.clone());
+                self.provider.receipts.get_mut(chain_id).unwrap().insert(header.number, Vec::new());
+                self.heads.insert(*chain_id, header);
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_incremental_consolidation_matches_full_consolidation() {
+        let all_chains = [1, 2, 3];
+        let mut initial = IncrementalConsolidation::default();
+        initial.consolidate(&mut MockSuperchain::new(&all_chains), None).await.unwrap();
+
+        // Replacing a block invalidates the messages initiated on it, transitively.
+        for (changed, expected_replaced) in [(1, vec![2, 3]), (2, vec![3]), (3, vec![])] {
+            // the block of the changed chain no longer initiates its message
+            let initiating = all_chains.into_iter().filter(|c| *c != changed).collect::<Vec<_>>();
+            let unconsolidated = MockSuperchain::new(&initiating).head_hashes();
+
+            let mut full_target = MockSuperchain::new(&initiating);
+            let mut full = IncrementalConsolidation::default();
+            full.consolidate(&mut full_target, None).await.unwrap();
+
+            let mut incremental_target = MockSuperchain::new(&initiating);
+            let mut incremental = initial.clone();
+            incremental.consolidate(&mut incremental_target, Some(&[changed])).await.unwrap();
+
+            assert_eq!(incremental_target.head_hashes(), full_target.head_hashes());
+            assert_eq!(incremental.dependencies, full.dependencies);
+            let replaced = all_chains
+                .into_iter()
+                .filter(|c| full_target.heads[c].hash() != unconsolidated[c])
+                .collect::<Vec<_>>();
+            assert_eq!(replaced, expected_replaced);
+        }
+    }
+
+    #[test]
+    fn test_affected_chains_are_transitive() {
+        let mut dependencies = MessageDependencies::default();
+        dependencies.0.insert(1, BTreeSet::new());
+        dependencies.0.insert(2, BTreeSet::from([1]));
+        dependencies.0.insert(3, BTreeSet::from([2]));
+        dependencies.0.insert(4, BTreeSet::new());
+
+        assert_eq!(dependencies.affected([1]), BTreeSet::from([1, 2, 3]));
+        assert_eq!(dependencies.affected([2]), BTreeSet::from([2, 3]));
+        assert_eq!(dependencies.affected([4]), BTreeSet::from([4]));
+    }
+}
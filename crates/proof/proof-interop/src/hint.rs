@@ -1,6 +1,7 @@
 //! This module contains the [HintType] enum.
 
 use alloc::{string::ToString, vec::Vec};
+use alloy_primitives::B256;
 use core::{fmt::Display, str::FromStr};
 use kona_proof::{Hint, errors::HintParsingError};
 
@@ -21,7 +22,8 @@ pub enum HintType {
     L2BlockHeader,
     /// A hint that specifies the transactions of a layer 2 block.
     L2Transactions,
-    /// A hint that specifies the receipts of a layer 2 block.
+    /// A hint that specifies the receipts of a layer 2 block, by block hash and chain ID. See
+    /// [HintType::l2_receipts].
     L2Receipts,
     /// A hint that specifies the code of a contract on layer 2.
     L2Code,
@@ -30,6 +32,9 @@ pub enum HintType {
     /// A hint that specifies the preimage of an L2 output root within the agreed upon pre-state,
     /// by chain ID.
     L2OutputRoot,
+    /// A hint that specifies the preimages of several L2 output roots within the agreed upon
+    /// pre-state at once, as a concatenation of `(output root, chain ID)` pairs.
+    L2OutputRoots,
     /// A hint that specifies the state node in the L2 state trie.
     L2StateNode,
     /// A hint that specifies the proof on the path to an account in the L2 state trie.
@@ -55,6 +60,15 @@ impl HintType {
         });
         Hint::new(self, hint_data)
     }
+
+    /// Creates a [HintType::L2Receipts] hint for the receipts of the block with the given hash on
+    /// the given chain. The data is the block hash followed by the big-endian chain ID.
+    ///
+    /// The host responds by storing the receipts trie of the block, whose leaves are the
+    /// EIP-2718 encoded receipts.
+    pub fn l2_receipts(chain_id: u64, block_hash: B256) -> Hint<Self> {
+        Self::L2Receipts.with_data(&[block_hash.as_slice(), &chain_id.to_be_bytes()])
+    }
 }
 
 impl FromStr for HintType {
@@ -73,6 +87,7 @@ impl FromStr for HintType {
             "l2-code" => Ok(Self::L2Code),
             "agreed-pre-state" => Ok(Self::AgreedPreState),
             "l2-output-root" => Ok(Self::L2OutputRoot),
+            "l2-output-roots" => Ok(Self::L2OutputRoots),
             "l2-state-node" => Ok(Self::L2StateNode),
             "l2-account-proof" => Ok(Self::L2AccountProof),
             "l2-account-storage-proof" => Ok(Self::L2AccountStorageProof),
@@ -97,6 +112,7 @@ impl From<HintType> for &str {
             HintType::L2Code => "l2-code",
             HintType::AgreedPreState => "agreed-pre-state",
             HintType::L2OutputRoot => "l2-output-root",
+            HintType::L2OutputRoots => "l2-output-roots",
             HintType::L2StateNode => "l2-state-node",
             HintType::L2AccountProof => "l2-account-proof",
             HintType::L2AccountStorageProof => "l2-account-storage-proof",
@@ -133,6 +149,7 @@ mod test {
         assert_eq!(HintType::from_str("l2-code").unwrap(), HintType::L2Code);
         assert_eq!(HintType::from_str("agreed-pre-state").unwrap(), HintType::AgreedPreState);
         assert_eq!(HintType::from_str("l2-output-root").unwrap(), HintType::L2OutputRoot);
+        assert_eq!(HintType::from_str("l2-output-roots").unwrap(), HintType::L2OutputRoots);
         assert_eq!(HintType::from_str("l2-account-proof").unwrap(), HintType::L2AccountProof);
         assert_eq!(
             HintType::from_str("l2-account-storage-proof").unwrap(),
@@ -166,6 +183,7 @@ mod test {
         assert_eq!(<&str>::from(HintType::L2Code), "l2-code");
         assert_eq!(<&str>::from(HintType::AgreedPreState), "agreed-pre-state");
         assert_eq!(<&str>::from(HintType::L2OutputRoot), "l2-output-root");
+        assert_eq!(<&str>::from(HintType::L2OutputRoots), "l2-output-roots");
         assert_eq!(<&str>::from(HintType::L2StateNode), "l2-state-node");
         assert_eq!(<&str>::from(HintType::L2AccountProof), "l2-account-proof");
         assert_eq!(<&str>::from(HintType::L2AccountStorageProof), "l2-account-storage-proof");
@@ -183,6 +201,17 @@ mod test {
         assert_eq!(l1_block_header.data, Bytes::from(hint_data));
     }
 
+    #[test]
+    fn test_l2_receipts_hint() {
+        use super::HintType;
+        use alloy_primitives::B256;
+
+        let hint = HintType::l2_receipts(10, B256::repeat_byte(1));
+        assert_eq!(hint.ty, HintType::L2Receipts);
+        assert_eq!(&hint.data[..32], B256::repeat_byte(1).as_slice());
+        assert_eq!(&hint.data[32..], 10u64.to_be_bytes().as_slice());
+    }
+
     #[test]
     fn test_hint_fmt() {
         use super::HintType;
@@ -12,7 +12,7 @@ extern crate alloc;
 mod pre_state;
 pub use pre_state::{
     INVALID_TRANSITION, INVALID_TRANSITION_HASH, OptimisticBlock, PreState,
-    TRANSITION_STATE_MAX_STEPS, TransitionState,
+    TRANSITION_STATE_MAX_STEPS, TransitionState, TransitionStateError,
 };
 
 mod hint;
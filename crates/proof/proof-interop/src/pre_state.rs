@@ -5,6 +5,7 @@ use alloy_primitives::{B256, Bytes, b256, keccak256};
 use alloy_rlp::{Buf, Decodable, Encodable, Header, RlpDecodable, RlpEncodable};
 use kona_interop::{OutputRootWithChain, SUPER_ROOT_VERSION, SuperRoot};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// The current [TransitionState] encoding format version.
 pub(crate) const TRANSITION_STATE_VERSION: u8 = 255;
@@ -176,6 +177,37 @@ impl TransitionState {
         keccak256(&rlp_buf)
     }
 
+    /// Appends the [OptimisticBlock]s of the next chains to the pending progress, advancing one
+    /// step per block, as repeated [PreState::transition]s would.
+    ///
+    /// Fails without modifying the [TransitionState] if the step isn't contiguous with the pending
+    /// progress, if there are more blocks than remaining chains, or if the step would exceed
+    /// [TRANSITION_STATE_MAX_STEPS].
+    pub fn extend(&mut self, blocks: Vec<OptimisticBlock>) -> Result<(), TransitionStateError> {
+        if self.step != self.pending_progress.len() as u64 {
+            return Err(TransitionStateError::NonContiguousStep {
+                step: self.step,
+                progress: self.pending_progress.len(),
+            });
+        }
+
+        let steps = self.step + blocks.len() as u64;
+        if steps > TRANSITION_STATE_MAX_STEPS {
+            return Err(TransitionStateError::TooManySteps(steps));
+        }
+        let chains = self.pre_state.output_roots.len();
+        if self.pending_progress.len() + blocks.len() > chains {
+            return Err(TransitionStateError::TooManyBlocks {
+                blocks: self.pending_progress.len() + blocks.len(),
+                chains,
+            });
+        }
+
+        self.pending_progress.extend(blocks);
+        self.step = steps;
+        Ok(())
+    }
+
     /// Returns the RLP payload length of the [TransitionState].
     pub fn payload_length(&self) -> usize {
         Header { list: false, payload_length: self.pre_state.encoded_length() }.length() +
@@ -234,6 +266,30 @@ impl Decodable for TransitionState {
     }
 }
 
+/// An error returned by [TransitionState::extend].
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum TransitionStateError {
+    /// The step doesn't match the pending progress, e.g. because padding steps were taken.
+    #[error("step {step} is not contiguous with {progress} pending blocks")]
+    NonContiguousStep {
+        /// The step of the transition state.
+        step: u64,
+        /// The number of pending blocks.
+        progress: usize,
+    },
+    /// The step would exceed [TRANSITION_STATE_MAX_STEPS].
+    #[error("step {0} exceeds the maximum of {max} steps", max = TRANSITION_STATE_MAX_STEPS)]
+    TooManySteps(u64),
+    /// There would be more pending blocks than chains in the pre-state.
+    #[error("{blocks} pending blocks exceed the {chains} chains of the pre-state")]
+    TooManyBlocks {
+        /// The number of pending blocks.
+        blocks: usize,
+        /// The number of chains in the pre-state.
+        chains: usize,
+    },
+}
+
 /// A wrapper around a pending output root hash with the block hash it commits to.
 #[derive(
     Default, Debug, Clone, Eq, PartialEq, RlpEncodable, RlpDecodable, Serialize, Deserialize,
@@ -255,12 +311,57 @@ impl OptimisticBlock {
 
 #[cfg(test)]
 mod test {
-    use super::{OptimisticBlock, SuperRoot, TransitionState};
+    use super::{
+        OptimisticBlock, SuperRoot, TRANSITION_STATE_MAX_STEPS, TransitionState,
+        TransitionStateError,
+    };
     use alloc::{vec, vec::Vec};
     use alloy_primitives::B256;
     use alloy_rlp::{Decodable, Encodable};
     use kona_interop::OutputRootWithChain;
 
+    #[test]
+    fn transition_state(chains: u64) -> TransitionState {
+        let output_roots =
+            (0..chains).map(|chain_id| OutputRootWithChain::new(chain_id, B256::ZERO)).collect();
+        TransitionState::new(SuperRoot::new(10, output_roots), vec![], 0)
+    }
+
+    #[test]
+    fn test_extend_to_max_steps() {
+        let max = TRANSITION_STATE_MAX_STEPS as usize;
+        let mut state = transition_state(TRANSITION_STATE_MAX_STEPS + 1);
+
+        state.extend(vec![OptimisticBlock::default(); 2]).unwrap();
+        state.extend(vec![OptimisticBlock::default(); max - 2]).unwrap();
+        assert_eq!(state.step, TRANSITION_STATE_MAX_STEPS);
+        assert_eq!(state.pending_progress.len(), max);
+
+        let before = state.clone();
+        assert_eq!(
+            state.extend(vec![OptimisticBlock::default()]),
+            Err(TransitionStateError::TooManySteps(TRANSITION_STATE_MAX_STEPS + 1))
+        );
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn test_extend_rejects_invalid_progress() {
+        let mut state = transition_state(2);
+        assert_eq!(
+            state.extend(vec![OptimisticBlock::default(); 3]),
+            Err(TransitionStateError::TooManyBlocks { blocks: 3, chains: 2 })
+        );
+
+        state.extend(vec![OptimisticBlock::default(); 2]).unwrap();
+        // a padding step
+        state.step += 1;
+        assert_eq!(
+            state.extend(vec![]),
+            Err(TransitionStateError::NonContiguousStep { step: 3, progress: 2 })
+        );
+    }
+
     #[test]
     fn test_static_transition_state_roundtrip() {
         let transition_state = TransitionState::new(
@@ -85,10 +85,7 @@ where
     ) -> Result<Vec<OpReceiptEnvelope>, <Self as InteropProvider>::Error> {
         // Send a hint for the block's receipts, and walk through the receipts trie in the header to
         // verify them.
-        HintType::L2Receipts
-            .with_data(&[block_hash.as_ref(), chain_id.to_be_bytes().as_slice()])
-            .send(self.oracle.as_ref())
-            .await?;
+        HintType::l2_receipts(chain_id, block_hash).send(self.oracle.as_ref()).await?;
         let trie_walker = OrderedListWalker::try_new_hydrated(header.receipts_root, self)
             .map_err(OracleProviderError::TrieWalker)?;
 
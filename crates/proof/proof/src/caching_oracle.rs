@@ -51,6 +51,11 @@ where
             hint_writer,
         }
     }
+
+    /// Returns the maximum number of responses that the cache can hold.
+    pub fn cache_size(&self) -> usize {
+        self.cache.lock().cap().get()
+    }
 }
 
 /// A trait that provides a method to flush a cache.
@@ -7,10 +7,11 @@ use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_derive::{
     ChainProvider, DataAvailabilityProvider, DerivationPipeline, L2ChainProvider, OriginProvider,
-    Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult, PolledAttributesQueueStage,
-    ResetSignal, Signal, SignalReceiver, StatefulAttributesBuilder, StepResult,
+    Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult, PipelineSnapshot,
+    PolledAttributesQueueStage, ResetSignal, Signal, SignalReceiver, StatefulAttributesBuilder,
+    StepResult,
 };
-use kona_driver::{DriverPipeline, PipelineCursor};
+use kona_driver::{DerivationCounters, DriverPipeline, PipelineCursor};
 use kona_genesis::{L1ChainConfig, RollupConfig, SystemConfig};
 use kona_preimage::CommsClient;
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
@@ -41,6 +42,8 @@ where
     pub caching_oracle: Arc<O>,
     /// The data availability provider.
     pub da_provider: DA,
+    /// The derivation throughput counters.
+    pub counters: DerivationCounters,
 }
 
 impl<O, L1, L2, DA> OraclePipeline<O, L1, L2, DA>
@@ -98,7 +101,7 @@ where
             )
             .await?;
 
-        Ok(Self { pipeline, caching_oracle, da_provider })
+        Ok(Self { pipeline, caching_oracle, da_provider, counters: DerivationCounters::default() })
     }
 }
 
@@ -118,6 +121,14 @@ where
     fn flush(&mut self) {
         self.caching_oracle.flush();
     }
+
+    fn counters(&self) -> Option<&DerivationCounters> {
+        Some(&self.counters)
+    }
+
+    fn counters_mut(&mut self) -> Option<&mut DerivationCounters> {
+        Some(&mut self.counters)
+    }
 }
 
 #[async_trait]
@@ -179,6 +190,11 @@ where
         self.pipeline.step(cursor).await
     }
 
+    /// Returns a [PipelineSnapshot] of the data buffered by the pipeline's stages.
+    fn snapshot(&self) -> PipelineSnapshot {
+        self.pipeline.snapshot()
+    }
+
     /// Returns the rollup config.
     fn rollup_config(&self) -> &RollupConfig {
         self.pipeline.rollup_config()
@@ -43,11 +43,13 @@ mod traits;
 pub use traits::{
     AttributesBuilder, AttributesProvider, BatchValidationProviderDerive, BlobProvider,
     ChainProvider, DataAvailabilityProvider, L2ChainProvider, NextAttributes, OriginAdvancer,
-    OriginProvider, Pipeline, ResetProvider, SignalReceiver,
+    OriginProvider, Pipeline, ResetProvider, SignalReceiver, SnapshotProvider,
 };
 
 mod types;
-pub use types::{ActivationSignal, PipelineResult, ResetSignal, Signal, StepResult};
+pub use types::{
+    ActivationSignal, PipelineResult, PipelineSnapshot, ResetSignal, Signal, StepResult,
+};
 
 mod metrics;
 pub use metrics::Metrics;
@@ -27,6 +27,7 @@ where
     origin: Option<BlockInfo>,
     rollup_config: Option<Arc<RollupConfig>>,
     l1_blocks: Option<BTreeSet<u64>>,
+    channel_timeout: Option<u64>,
 }
 
 impl<B, P, T, D> Default for PipelineBuilder<B, P, T, D>
@@ -45,6 +46,7 @@ where
             origin: None,
             rollup_config: None,
             l1_blocks: None,
+            channel_timeout: None,
         }
     }
 }
@@ -103,6 +105,18 @@ where
         self
     }
 
+    /// Overrides the channel timeout of the rollup config, in L1 blocks.
+    ///
+    /// The override applies to the channel stages only, both before and after Granite. If unset,
+    /// the channel timeout of the rollup config is used.
+    ///
+    /// Overriding the channel timeout diverges from consensus, and must only be used in tests,
+    /// e.g. to exercise Holocene channel timeouts over a short L1 range.
+    pub const fn with_channel_timeout(mut self, blocks: u64) -> Self {
+        self.channel_timeout = Some(blocks);
+        self
+    }
+
     /// Builds a derivation pipeline with the [`PolledAttributesQueueStage`].
     pub fn build_polled(self) -> DerivationPipeline<PolledAttributesQueueStage<D, P, T, B>, T> {
         self.into()
@@ -114,6 +128,24 @@ where
     }
 }
 
+/// Returns the rollup config used by the channel stages, with the channel timeout overridden if
+/// set.
+fn channel_config(
+    rollup_config: &Arc<RollupConfig>,
+    channel_timeout: Option<u64>,
+) -> Arc<RollupConfig> {
+    channel_timeout.map_or_else(
+        || Arc::clone(rollup_config),
+        |timeout| {
+            Arc::new(RollupConfig {
+                channel_timeout: timeout,
+                granite_channel_timeout: timeout,
+                ..rollup_config.as_ref().clone()
+            })
+        },
+    )
+}
+
 impl<B, P, T, D> From<PipelineBuilder<B, P, T, D>>
     for DerivationPipeline<PolledAttributesQueueStage<D, P, T, B>, T>
 where
@@ -139,7 +171,10 @@ where
         l1_traversal.block = Some(builder.origin.expect("origin must be set"));
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source, builder.l1_blocks.clone());
         let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
-        let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue);
+        let channel_provider = ChannelProvider::new(
+            channel_config(&rollup_config, builder.channel_timeout),
+            frame_queue,
+        );
         let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config));
         let batch_stream =
             BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone());
@@ -174,7 +209,10 @@ where
         l1_traversal.block = Some(builder.origin.expect("origin must be set"));
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source, builder.l1_blocks);
         let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
-        let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue);
+        let channel_provider = ChannelProvider::new(
+            channel_config(&rollup_config, builder.channel_timeout),
+            frame_queue,
+        );
         let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config));
         let batch_stream =
             BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone());
@@ -187,3 +225,92 @@ where
         Self::new(attributes, rollup_config, l2_chain_provider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ChannelBank, ChannelReaderProvider, OriginAdvancer, PipelineError,
+        test_utils::TestNextFrameProvider,
+    };
+    use alloc::{vec, vec::Vec};
+    use kona_genesis::HardForkConfig;
+
+    #[test]
+    fn test_channel_config_default() {
+        let cfg = Arc::new(kona_registry::ROLLUP_CONFIGS.get(&10).cloned().unwrap());
+        assert!(Arc::ptr_eq(&channel_config(&cfg, None), &cfg));
+
+        let overridden = channel_config(&cfg, Some(2));
+        assert_eq!(overridden.channel_timeout(0), 2);
+        assert_eq!(overridden.channel_timeout(u64::MAX), 2);
+        assert_eq!(
+            RollupConfig { channel_timeout: cfg.channel_timeout, ..overridden.as_ref().clone() },
+            RollupConfig { granite_channel_timeout: 2, ..cfg.as_ref().clone() },
+        );
+    }
+
+    #[tokio::test]
+    async fn test_channel_timeout_override() {
+        const TIMEOUT: u64 = 2;
+
+        let cfg = Arc::new(kona_registry::ROLLUP_CONFIGS.get(&10).cloned().unwrap());
+        assert!(cfg.channel_timeout(0) > TIMEOUT + 1);
+
+        let frames = [
+            crate::frame!(0xFF, 0, vec![0xDD; 50], false),
+            crate::frame!(0xFF, 1, vec![0xDD; 50], true),
+        ];
+        let mock = TestNextFrameProvider::new(frames.into_iter().map(Ok).collect::<Vec<_>>());
+        let mut channel_bank = ChannelBank::new(channel_config(&cfg, Some(TIMEOUT)), mock);
+
+        // Ingest the first frame, and advance the origin past the overridden timeout.
+        let err = channel_bank.next_data().await.unwrap_err();
+        assert_eq!(err, PipelineError::NotEnoughData.temp());
+        for _ in 0..TIMEOUT + 1 {
+            channel_bank.advance_origin().await.unwrap();
+        }
+        assert_eq!(channel_bank.channels.len(), 1);
+
+        // The channel timed out and is pruned.
+        channel_bank.next_data().await.unwrap();
+        assert!(channel_bank.channels.is_empty());
+        assert!(channel_bank.channel_queue.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_channel_timeout_override_holocene() {
+        const TIMEOUT: u64 = 2;
+
+        let cfg = Arc::new(RollupConfig {
+            channel_timeout: 300,
+            granite_channel_timeout: 50,
+            hardforks: HardForkConfig { holocene_time: Some(0), ..Default::default() },
+            ..Default::default()
+        });
+        assert!(cfg.channel_timeout(0) > TIMEOUT + 1);
+
+        let frames = [
+            crate::frame!(0xFF, 0, vec![0xDD; 50], false),
+            crate::frame!(0xFF, 1, vec![0xDD; 50], true),
+        ];
+        let mut mock = TestNextFrameProvider::new(frames.into_iter().rev().map(Ok).collect());
+        mock.block_info = Some(BlockInfo::default());
+        let mut channel_provider = ChannelProvider::new(channel_config(&cfg, Some(TIMEOUT)), mock);
+
+        // Under Holocene, the first frame opens a channel in the channel assembler.
+        let err = channel_provider.next_data().await.unwrap_err();
+        assert_eq!(err, PipelineError::NotEnoughData.temp());
+        let assembler = channel_provider.channel_assembler.as_mut().unwrap();
+        assert!(assembler.channel.is_some());
+
+        // Move the origin past the overridden timeout, but well within the configured one.
+        assembler.prev.block_info = Some(BlockInfo { number: TIMEOUT + 1, ..Default::default() });
+        assert!(assembler.is_timed_out().unwrap());
+
+        // The channel is discarded, and the closing frame can't complete it anymore.
+        let err = channel_provider.next_data().await.unwrap_err();
+        assert_eq!(err, PipelineError::NotEnoughData.temp());
+        assert!(channel_provider.channel_assembler.unwrap().channel.is_none());
+    }
+}
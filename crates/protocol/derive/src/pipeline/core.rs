@@ -2,8 +2,8 @@
 
 use crate::{
     ActivationSignal, L2ChainProvider, NextAttributes, OriginAdvancer, OriginProvider, Pipeline,
-    PipelineError, PipelineErrorKind, PipelineResult, ResetSignal, Signal, SignalReceiver,
-    StepResult,
+    PipelineError, PipelineErrorKind, PipelineResult, PipelineSnapshot, ResetSignal, Signal,
+    SignalReceiver, SnapshotProvider, StepResult,
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use async_trait::async_trait;
@@ -135,7 +135,14 @@ where
 #[async_trait]
 impl<S, P> Pipeline for DerivationPipeline<S, P>
 where
-    S: NextAttributes + SignalReceiver + OriginProvider + OriginAdvancer + Debug + Send + Sync,
+    S: NextAttributes
+        + SignalReceiver
+        + OriginProvider
+        + OriginAdvancer
+        + SnapshotProvider
+        + Debug
+        + Send
+        + Sync,
     P: L2ChainProvider + Send + Sync + Debug,
 {
     /// Peeks at the next prepared [`OpAttributesWithParent`] from the pipeline.
@@ -143,6 +150,17 @@ where
         self.prepared.front()
     }
 
+    /// Returns a [`PipelineSnapshot`] of the data buffered by the pipeline's stages.
+    fn snapshot(&self) -> PipelineSnapshot {
+        let mut snapshot = PipelineSnapshot {
+            origin: self.origin(),
+            prepared_attributes: self.prepared.len(),
+            ..Default::default()
+        };
+        self.attributes.record_snapshot(&mut snapshot);
+        snapshot
+    }
+
     /// Returns the rollup config.
     fn rollup_config(&self) -> &RollupConfig {
         &self.rollup_config
@@ -227,11 +245,14 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{DerivationPipeline, test_utils::*};
-    use alloc::{string::ToString, sync::Arc};
+    use crate::{BatchQueue, ChannelBank, DerivationPipeline, test_utils::*};
+    use alloc::{string::ToString, sync::Arc, vec};
     use alloy_rpc_types_engine::PayloadAttributes;
     use kona_genesis::{RollupConfig, SystemConfig};
-    use kona_protocol::{L2BlockInfo, OpAttributesWithParent};
+    use kona_protocol::{
+        Batch, BatchWithInclusionBlock, Channel, ChannelId, FRAME_OVERHEAD, Frame, L2BlockInfo,
+        OpAttributesWithParent, SingleBatch,
+    };
     use op_alloy_rpc_types_engine::OpPayloadAttributes;
 
     fn default_test_payload_attributes() -> OpAttributesWithParent {
@@ -276,6 +297,50 @@ mod tests {
         assert_eq!(result, Some(expected));
     }
 
+    #[test]
+    fn test_pipeline_snapshot_partial_data() {
+        let mut pipeline = new_test_pipeline();
+        assert_eq!(
+            pipeline.snapshot(),
+            PipelineSnapshot { origin: Some(BlockInfo::default()), ..Default::default() }
+        );
+
+        let cfg = Arc::clone(&pipeline.rollup_config);
+        let batch_provider = &mut pipeline.attributes.prev;
+        let mut batch_stream = batch_provider.prev.take().unwrap();
+        batch_stream.buffer.push_back(SingleBatch::default());
+
+        // Buffer two frames in the frame queue, and a partial channel in the channel bank.
+        let channel_provider = &mut batch_stream.prev.prev;
+        let mut frame_queue = channel_provider.prev.take().unwrap();
+        frame_queue.queue.extend([Frame::default(), Frame { number: 1, ..Default::default() }]);
+        let mut channel_bank = ChannelBank::new(Arc::clone(&cfg), frame_queue);
+        let mut channel = Channel::new(ChannelId::default(), BlockInfo::default());
+        channel
+            .add_frame(Frame { data: vec![0; 10], ..Default::default() }, BlockInfo::default())
+            .unwrap();
+        channel_bank.channels.insert(channel.id(), channel);
+        channel_provider.channel_bank = Some(channel_bank);
+
+        // Buffer a batch in the batch queue.
+        let mut batch_queue = BatchQueue::new(cfg, batch_stream, TestL2ChainProvider::default());
+        batch_queue.batches.push(BatchWithInclusionBlock::new(
+            BlockInfo::default(),
+            Batch::Single(SingleBatch::default()),
+        ));
+        batch_provider.batch_queue = Some(batch_queue);
+
+        pipeline.prepared.push_back(default_test_payload_attributes());
+
+        let snapshot = pipeline.snapshot();
+        assert_eq!(snapshot.frame_queue_depth, 2);
+        assert_eq!(snapshot.channel_count, 1);
+        assert_eq!(snapshot.channel_bank_size, 10 + FRAME_OVERHEAD);
+        assert_eq!(snapshot.batch_stream_depth, 1);
+        assert_eq!(snapshot.batch_queue_depth, 1);
+        assert_eq!(snapshot.prepared_attributes, 1);
+    }
+
     #[tokio::test]
     async fn test_derivation_pipeline_missing_block() {
         let mut pipeline = new_test_pipeline();
@@ -4,9 +4,9 @@ use crate::{
     errors::{PipelineError, ResetError},
     traits::{
         AttributesBuilder, AttributesProvider, NextAttributes, OriginAdvancer, OriginProvider,
-        SignalReceiver,
+        SignalReceiver, SnapshotProvider,
     },
-    types::{PipelineResult, Signal},
+    types::{PipelineResult, PipelineSnapshot, Signal},
 };
 use alloc::{boxed::Box, sync::Arc};
 use async_trait::async_trait;
@@ -182,6 +182,21 @@ where
     }
 }
 
+impl<P, AB> SnapshotProvider for AttributesQueue<P, AB>
+where
+    P: AttributesProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + SnapshotProvider
+        + Debug,
+    AB: AttributesBuilder + Debug,
+{
+    fn record_snapshot(&self, snapshot: &mut PipelineSnapshot) {
+        self.prev.record_snapshot(snapshot);
+    }
+}
+
 #[async_trait]
 impl<P, AB> SignalReceiver for AttributesQueue<P, AB>
 where
@@ -3,7 +3,8 @@
 use super::NextBatchProvider;
 use crate::{
     AttributesProvider, BatchQueue, BatchValidator, L2ChainProvider, OriginAdvancer,
-    OriginProvider, PipelineError, PipelineResult, Signal, SignalReceiver,
+    OriginProvider, PipelineError, PipelineResult, PipelineSnapshot, Signal, SignalReceiver,
+    SnapshotProvider,
 };
 use alloc::{boxed::Box, sync::Arc};
 use async_trait::async_trait;
@@ -126,6 +127,32 @@ where
     }
 }
 
+impl<P, F> SnapshotProvider for BatchProvider<P, F>
+where
+    P: NextBatchProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + SnapshotProvider
+        + Debug,
+    F: L2ChainProvider + Clone + Debug,
+{
+    fn record_snapshot(&self, snapshot: &mut PipelineSnapshot) {
+        let prev = if let Some(batch_validator) = self.batch_validator.as_ref() {
+            Some(&batch_validator.prev)
+        } else if let Some(batch_queue) = self.batch_queue.as_ref() {
+            snapshot.batch_queue_depth = batch_queue.batches.len();
+            Some(&batch_queue.prev)
+        } else {
+            self.prev.as_ref()
+        };
+
+        if let Some(prev) = prev {
+            prev.record_snapshot(snapshot);
+        }
+    }
+}
+
 #[async_trait]
 impl<P, F> SignalReceiver for BatchProvider<P, F>
 where
@@ -2,7 +2,7 @@
 
 use crate::{
     L2ChainProvider, NextBatchProvider, OriginAdvancer, OriginProvider, PipelineEncodingError,
-    PipelineError, PipelineResult, Signal, SignalReceiver,
+    PipelineError, PipelineResult, PipelineSnapshot, Signal, SignalReceiver, SnapshotProvider,
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use async_trait::async_trait;
@@ -219,6 +219,22 @@ where
     }
 }
 
+impl<P, BF> SnapshotProvider for BatchStream<P, BF>
+where
+    P: BatchStreamProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + SnapshotProvider
+        + Debug,
+    BF: L2ChainProvider + Debug,
+{
+    fn record_snapshot(&self, snapshot: &mut PipelineSnapshot) {
+        snapshot.batch_stream_depth = self.buffer.len();
+        self.prev.record_snapshot(snapshot);
+    }
+}
+
 #[async_trait]
 impl<P, BF> SignalReceiver for BatchStream<P, BF>
 where
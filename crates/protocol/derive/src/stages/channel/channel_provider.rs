@@ -3,8 +3,8 @@
 use super::{ChannelAssembler, ChannelBank, ChannelReaderProvider, NextFrameProvider};
 use crate::{
     errors::PipelineError,
-    traits::{OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, Signal},
+    traits::{OriginAdvancer, OriginProvider, SignalReceiver, SnapshotProvider},
+    types::{PipelineResult, PipelineSnapshot, Signal},
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Bytes;
@@ -117,6 +117,35 @@ where
     }
 }
 
+impl<P> SnapshotProvider for ChannelProvider<P>
+where
+    P: NextFrameProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + SnapshotProvider
+        + Debug,
+{
+    fn record_snapshot(&self, snapshot: &mut PipelineSnapshot) {
+        let prev = if let Some(channel_assembler) = self.channel_assembler.as_ref() {
+            let channel = channel_assembler.channel.as_ref();
+            snapshot.channel_count = channel.map_or(0, |_| 1);
+            snapshot.channel_bank_size = channel.map_or(0, |channel| channel.size());
+            Some(&channel_assembler.prev)
+        } else if let Some(channel_bank) = self.channel_bank.as_ref() {
+            snapshot.channel_count = channel_bank.channels.len();
+            snapshot.channel_bank_size = channel_bank.size();
+            Some(&channel_bank.prev)
+        } else {
+            self.prev.as_ref()
+        };
+
+        if let Some(prev) = prev {
+            prev.record_snapshot(snapshot);
+        }
+    }
+}
+
 #[async_trait]
 impl<P> SignalReceiver for ChannelProvider<P>
 where
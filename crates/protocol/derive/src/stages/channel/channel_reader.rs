@@ -1,8 +1,8 @@
 //! This module contains the `ChannelReader` struct.
 
 use crate::{
-    BatchStreamProvider, OriginAdvancer, OriginProvider, PipelineError, PipelineResult, Signal,
-    SignalReceiver,
+    BatchStreamProvider, OriginAdvancer, OriginProvider, PipelineError, PipelineResult,
+    PipelineSnapshot, Signal, SignalReceiver, SnapshotProvider,
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Bytes;
@@ -172,6 +172,20 @@ where
     }
 }
 
+impl<P> SnapshotProvider for ChannelReader<P>
+where
+    P: ChannelReaderProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + SnapshotProvider
+        + Debug,
+{
+    fn record_snapshot(&self, snapshot: &mut PipelineSnapshot) {
+        self.prev.record_snapshot(snapshot);
+    }
+}
+
 #[async_trait]
 impl<P> SignalReceiver for ChannelReader<P>
 where
@@ -1,8 +1,8 @@
 //! This module contains the [FrameQueue] stage of the derivation pipeline.
 
 use crate::{
-    NextFrameProvider, OriginAdvancer, OriginProvider, PipelineError, PipelineResult, Signal,
-    SignalReceiver,
+    NextFrameProvider, OriginAdvancer, OriginProvider, PipelineError, PipelineResult,
+    PipelineSnapshot, Signal, SignalReceiver, SnapshotProvider,
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use alloy_primitives::Bytes;
@@ -185,6 +185,15 @@ where
     }
 }
 
+impl<P> SnapshotProvider for FrameQueue<P>
+where
+    P: FrameQueueProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+{
+    fn record_snapshot(&self, snapshot: &mut PipelineSnapshot) {
+        snapshot.frame_queue_depth = self.queue.len();
+    }
+}
+
 #[async_trait]
 impl<P> SignalReceiver for FrameQueue<P>
 where
@@ -13,7 +13,7 @@ use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use crate::{
     AttributesQueue, BatchStream, ChannelProvider, ChannelReader, DerivationPipeline, FrameQueue,
     L1Retrieval, NextAttributes, OriginAdvancer, OriginProvider, PipelineBuilder, PipelineError,
-    PollingTraversal, Signal, SignalReceiver,
+    PipelineSnapshot, PollingTraversal, Signal, SignalReceiver, SnapshotProvider,
     test_utils::{TestAttributesBuilder, TestDAP},
 };
 
@@ -48,6 +48,11 @@ impl OriginAdvancer for TestNextAttributes {
     }
 }
 
+impl SnapshotProvider for TestNextAttributes {
+    /// The test stage does not buffer any data.
+    fn record_snapshot(&self, _: &mut PipelineSnapshot) {}
+}
+
 #[async_trait::async_trait]
 impl NextAttributes for TestNextAttributes {
     /// Returns the next valid [`OpAttributesWithParent`].
@@ -6,7 +6,7 @@ use core::iter::Iterator;
 use kona_genesis::{RollupConfig, SystemConfig};
 use kona_protocol::{L2BlockInfo, OpAttributesWithParent};
 
-use crate::{OriginProvider, PipelineErrorKind, StepResult};
+use crate::{OriginProvider, PipelineErrorKind, PipelineSnapshot, StepResult};
 
 /// This trait defines the interface for interacting with the derivation pipeline.
 #[async_trait]
@@ -17,6 +17,9 @@ pub trait Pipeline: OriginProvider + Iterator<Item = OpAttributesWithParent> {
     /// Attempts to progress the pipeline.
     async fn step(&mut self, cursor: L2BlockInfo) -> StepResult;
 
+    /// Returns a [`PipelineSnapshot`] of the data buffered by the pipeline's stages.
+    fn snapshot(&self) -> PipelineSnapshot;
+
     /// Returns the rollup config.
     fn rollup_config(&self) -> &RollupConfig;
 
@@ -4,7 +4,7 @@ use alloc::boxed::Box;
 use async_trait::async_trait;
 use kona_protocol::BlockInfo;
 
-use crate::{PipelineResult, Signal};
+use crate::{PipelineResult, PipelineSnapshot, Signal};
 
 /// Providers a way for the pipeline to accept a signal from the driver.
 #[async_trait]
@@ -26,3 +26,10 @@ pub trait OriginAdvancer {
     /// This method is the equivalent of the reference implementation `advance_l1_block`.
     async fn advance_origin(&mut self) -> PipelineResult<()>;
 }
+
+/// Provides a read-only view of the data buffered by a stage and the stages before it.
+pub trait SnapshotProvider {
+    /// Records the data buffered by the stage, and by the stages before it, into the given
+    /// [`PipelineSnapshot`].
+    fn record_snapshot(&self, snapshot: &mut PipelineSnapshot);
+}
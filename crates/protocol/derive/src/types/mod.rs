@@ -3,5 +3,8 @@
 mod results;
 pub use results::{PipelineResult, StepResult};
 
+mod snapshot;
+pub use snapshot::PipelineSnapshot;
+
 mod signals;
 pub use signals::{ActivationSignal, ResetSignal, Signal};
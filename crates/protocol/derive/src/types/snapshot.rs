@@ -0,0 +1,27 @@
+//! Contains the [`PipelineSnapshot`] type.
+
+use kona_protocol::BlockInfo;
+
+/// A read-only snapshot of the data buffered by the stages of the derivation pipeline.
+///
+/// Stages that are not active at the time of the snapshot report no buffered data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PipelineSnapshot {
+    /// The current L1 origin of the pipeline.
+    pub origin: Option<BlockInfo>,
+    /// The number of frames buffered in the frame queue.
+    pub frame_queue_depth: usize,
+    /// The number of channels buffered in the channel bank, or the channel being assembled by
+    /// the channel assembler after Holocene.
+    pub channel_count: usize,
+    /// The total size, in bytes, of the frames buffered in the channel stage.
+    pub channel_bank_size: usize,
+    /// The number of single batches buffered from the current span batch in the batch stream.
+    pub batch_stream_depth: usize,
+    /// The number of batches buffered in the batch queue.
+    pub batch_queue_depth: usize,
+    /// The number of prepared payload attributes not yet consumed from the pipeline.
+    pub prepared_attributes: usize,
+}
@@ -44,8 +44,8 @@ mod system;
 pub use system::{
     BatcherUpdateError, CONFIG_UPDATE_EVENT_VERSION_0, CONFIG_UPDATE_TOPIC, EIP1559UpdateError,
     GasConfigUpdateError, GasLimitUpdateError, LogProcessingError, MinBaseFeeUpdateError,
-    OperatorFeeUpdateError, SystemConfig, SystemConfigLog, SystemConfigUpdate,
-    SystemConfigUpdateError, SystemConfigUpdateKind, UPDATE_TYPE_BATCHER_TOPIC,
+    OperatorFeeUpdateError, SystemConfig, SystemConfigLog, SystemConfigReplayError,
+    SystemConfigUpdate, SystemConfigUpdateError, SystemConfigUpdateKind, UPDATE_TYPE_BATCHER_TOPIC,
     UnsafeBlockSignerUpdateError,
 };
 
@@ -1,8 +1,8 @@
 //! Contains the [`SystemConfig`] type.
 
 use crate::{
-    CONFIG_UPDATE_TOPIC, RollupConfig, SystemConfigLog, SystemConfigUpdateError,
-    SystemConfigUpdateKind,
+    CONFIG_UPDATE_TOPIC, RollupConfig, SystemConfigLog, SystemConfigReplayError,
+    SystemConfigUpdateError, SystemConfigUpdateKind,
 };
 use alloy_consensus::{Eip658Value, Receipt};
 use alloy_primitives::{Address, B64, Log, U256};
@@ -141,6 +141,25 @@ impl SystemConfig {
         Ok(updated)
     }
 
+    /// Replays the given system config update logs, in order, on top of the [SystemConfig] and
+    /// returns the resulting [SystemConfig].
+    ///
+    /// Every log must be a `ConfigUpdate` event emitted by the system config contract. Replay stops
+    /// at the first log that fails to decode, and the returned error carries its index.
+    pub fn apply_updates(
+        &self,
+        logs: &[Log],
+        ecotone_active: bool,
+    ) -> Result<Self, SystemConfigReplayError> {
+        let mut config = *self;
+        for (index, log) in logs.iter().enumerate() {
+            config
+                .process_config_update_log(log, ecotone_active)
+                .map_err(|error| SystemConfigReplayError { index, error })?;
+        }
+        Ok(config)
+    }
+
     /// Returns the eip1559 parameters from a [SystemConfig] encoded as a [B64].
     pub fn eip_1559_params(
         &self,
@@ -213,7 +232,10 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{CONFIG_UPDATE_EVENT_VERSION_0, HardForkConfig};
+    use crate::{
+        CONFIG_UPDATE_EVENT_VERSION_0, HardForkConfig, LogProcessingError, MinBaseFeeUpdate,
+        MinBaseFeeUpdateError, OperatorFeeUpdate, OperatorFeeUpdateError,
+    };
     use alloc::vec;
     use alloy_primitives::{B256, LogData, address, b256, hex};
 
@@ -560,4 +582,161 @@ mod test {
         assert_eq!(system_config.operator_fee_scalar, Some(0xbabe_u32));
         assert_eq!(system_config.operator_fee_constant, Some(0xbeef_u64));
     }
+
+    #[test]
+    fn test_system_config_update_operator_fee_log_scalar_not_validated() {
+        let mut system_config = SystemConfig::default();
+        let update_log = update_log(
+            5,
+            &hex!(
+                "000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000ffffffff000000000000beef"
+            ),
+        );
+
+        // The scalar is above the safe range, but derivation applies it anyway.
+        let update =
+            OperatorFeeUpdate { operator_fee_scalar: u32::MAX, operator_fee_constant: 0xbeef };
+        assert_eq!(update.validate(), Err(OperatorFeeUpdateError::ScalarTooLarge(u32::MAX)));
+        let kind = system_config.process_config_update_log(&update_log, false).unwrap();
+        assert_eq!(kind, SystemConfigUpdateKind::OperatorFee);
+        assert_eq!(system_config.operator_fee_scalar, Some(u32::MAX));
+        assert_eq!(system_config.operator_fee_constant, Some(0xbeef));
+    }
+
+    #[test]
+    fn test_system_config_update_min_base_fee_log_not_validated() {
+        let mut system_config = SystemConfig {
+            eip1559_denominator: Some(250),
+            eip1559_elasticity: Some(0),
+            ..Default::default()
+        };
+        let update_log = update_log(
+            6,
+            &hex!(
+                "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+            ),
+        );
+
+        // A min base fee is incompatible with a zero elasticity, but derivation applies it anyway.
+        let update = MinBaseFeeUpdate { min_base_fee: 0xbeef };
+        assert_eq!(
+            update.validate(&system_config),
+            Err(MinBaseFeeUpdateError::IncompatibleEip1559Params {
+                min_base_fee: 0xbeef,
+                denominator: 250,
+                elasticity: 0,
+            })
+        );
+        let kind = system_config.process_config_update_log(&update_log, false).unwrap();
+        assert_eq!(kind, SystemConfigUpdateKind::MinBaseFee);
+        assert_eq!(system_config.min_base_fee, Some(0xbeef));
+    }
+
+    fn update_log(update_type: u8, data: &[u8]) -> Log {
+        let mut topic = B256::ZERO;
+        topic.0[31] = update_type;
+        Log {
+            address: Address::ZERO,
+            data: LogData::new_unchecked(
+                vec![CONFIG_UPDATE_TOPIC, CONFIG_UPDATE_EVENT_VERSION_0, topic],
+                data.to_vec().into(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_system_config_apply_updates() {
+        let logs = [
+            update_log(
+                0,
+                &hex!(
+                    "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+                ),
+            ),
+            update_log(
+                2,
+                &hex!(
+                    "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+                ),
+            ),
+            update_log(
+                4,
+                &hex!(
+                    "000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000babe0000beef"
+                ),
+            ),
+            update_log(
+                5,
+                &hex!(
+                    "0000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000babe000000000000beef"
+                ),
+            ),
+            // A later update overrides an earlier one.
+            update_log(
+                0,
+                &hex!(
+                    "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000babe"
+                ),
+            ),
+        ];
+
+        let initial = SystemConfig { gas_limit: 30_000_000, ..Default::default() };
+        let config = initial.apply_updates(&logs, false).unwrap();
+
+        let expected = SystemConfig {
+            batcher_address: address!("000000000000000000000000000000000000bAbE"),
+            gas_limit: 0xbeef,
+            eip1559_denominator: Some(0xbabe),
+            eip1559_elasticity: Some(0xbeef),
+            operator_fee_scalar: Some(0xbabe),
+            operator_fee_constant: Some(0xbeef),
+            ..Default::default()
+        };
+        assert_eq!(config, expected);
+        assert_eq!(initial.gas_limit, 30_000_000);
+    }
+
+    #[test]
+    fn test_system_config_apply_updates_failure_index() {
+        let mut invalid_version = update_log(
+            2,
+            &hex!(
+                "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+            ),
+        );
+        let mut topics = invalid_version.topics().to_vec();
+        topics[1] = B256::with_last_byte(1);
+        invalid_version.data = LogData::new_unchecked(topics, invalid_version.data.data.clone());
+
+        let logs = [
+            update_log(
+                0,
+                &hex!(
+                    "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+                ),
+            ),
+            invalid_version,
+            update_log(
+                2,
+                &hex!(
+                    "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+                ),
+            ),
+        ];
+
+        let err = SystemConfig::default().apply_updates(&logs, false).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(
+            err.error,
+            SystemConfigUpdateError::LogProcessing(LogProcessingError::UnsupportedVersion(
+                B256::with_last_byte(1)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_system_config_apply_updates_empty() {
+        let config = SystemConfig { gas_limit: 1, ..Default::default() };
+        assert_eq!(config.apply_updates(&[], true).unwrap(), config);
+    }
 }
@@ -33,6 +33,17 @@ pub enum SystemConfigUpdateError {
     MinBaseFee(MinBaseFeeUpdateError),
 }
 
+/// An error for replaying a sequence of [crate::SystemConfig] update logs.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[error("Failed to apply system config update log at index {index}: {error}")]
+pub struct SystemConfigReplayError {
+    /// The index of the log that failed to apply.
+    pub index: usize,
+    /// The error applying the log.
+    pub error: SystemConfigUpdateError,
+}
+
 /// An error occurred while processing the update log.
 #[derive(Debug, From, thiserror::Error, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -151,6 +162,30 @@ pub enum MinBaseFeeUpdateError {
     /// Failed to decode the min base fee argument from the min base fee update log.
     #[error("Failed to decode min base fee update log: min base fee")]
     MinBaseFeeDecodingError,
+    /// A non-zero min base fee is set while the EIP-1559 denominator or elasticity is zero.
+    #[error(
+        "Min base fee {min_base_fee} is incompatible with EIP-1559 parameters: denominator {denominator}, elasticity {elasticity}"
+    )]
+    IncompatibleEip1559Params {
+        /// The min base fee.
+        min_base_fee: u64,
+        /// The EIP-1559 denominator.
+        denominator: u32,
+        /// The EIP-1559 elasticity multiplier.
+        elasticity: u32,
+    },
+    /// The min base fee exceeds the ceiling allowed by the EIP-1559 elasticity.
+    #[error(
+        "Min base fee {min_base_fee} exceeds the ceiling of {ceiling} for an EIP-1559 elasticity of {elasticity}"
+    )]
+    ExceedsCeiling {
+        /// The min base fee.
+        min_base_fee: u64,
+        /// The ceiling on the min base fee.
+        ceiling: u64,
+        /// The EIP-1559 elasticity multiplier.
+        elasticity: u32,
+    },
 }
 
 /// An error for updating the gas limit on the [crate::SystemConfig].
@@ -226,4 +261,10 @@ pub enum OperatorFeeUpdateError {
     /// Failed to decode the constant argument from the update log.
     #[error("Failed to decode operator fee parameter update log: constant")]
     ConstantDecodingError,
+    /// The operator fee scalar is above the maximum safe scalar.
+    #[error(
+        "Operator fee scalar {0} exceeds the maximum of {max}",
+        max = crate::OperatorFeeUpdate::MAX_OPERATOR_FEE_SCALAR
+    )]
+    ScalarTooLarge(u32),
 }
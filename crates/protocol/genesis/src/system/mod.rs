@@ -27,6 +27,6 @@ pub use kind::SystemConfigUpdateKind;
 mod errors;
 pub use errors::{
     BatcherUpdateError, EIP1559UpdateError, GasConfigUpdateError, GasLimitUpdateError,
-    LogProcessingError, MinBaseFeeUpdateError, OperatorFeeUpdateError, SystemConfigUpdateError,
-    UnsafeBlockSignerUpdateError,
+    LogProcessingError, MinBaseFeeUpdateError, OperatorFeeUpdateError, SystemConfigReplayError,
+    SystemConfigUpdateError, UnsafeBlockSignerUpdateError,
 };
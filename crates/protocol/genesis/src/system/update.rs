@@ -28,6 +28,9 @@ pub enum SystemConfigUpdate {
 
 impl SystemConfigUpdate {
     /// Applies the update to the [`SystemConfig`].
+    ///
+    /// Operator fee and min base fee updates are applied unconditionally, as op-node does, since
+    /// rejecting an L1 log would halt derivation.
     pub const fn apply(&self, config: &mut SystemConfig) {
         match self {
             Self::Batcher(update) => update.apply(config),
@@ -14,10 +14,59 @@ pub struct MinBaseFeeUpdate {
 }
 
 impl MinBaseFeeUpdate {
+    /// The ceiling on the min base fee, in wei, per unit of EIP-1559 elasticity.
+    ///
+    /// A min base fee above `elasticity * MAX_MIN_BASE_FEE_PER_ELASTICITY` is treated as a
+    /// misconfiguration of the system config.
+    pub const MAX_MIN_BASE_FEE_PER_ELASTICITY: u64 = 100_000_000_000;
+
     /// Applies the update to the [`SystemConfig`].
     pub const fn apply(&self, config: &mut SystemConfig) {
         config.min_base_fee = Some(self.min_base_fee);
     }
+
+    /// Checks that the min base fee is consistent with the EIP-1559 parameters of the
+    /// [`SystemConfig`].
+    ///
+    /// A zero min base fee disables the floor and is always valid, as is any min base fee when the
+    /// EIP-1559 parameters are not set.
+    ///
+    /// This is an opt-in check for operator tooling. Derivation applies every min base fee update
+    /// from L1 with [`MinBaseFeeUpdate::apply`], regardless of this check.
+    pub fn validate(&self, config: &SystemConfig) -> Result<(), MinBaseFeeUpdateError> {
+        let min_base_fee = self.min_base_fee;
+        let (Some(denominator), Some(elasticity)) =
+            (config.eip1559_denominator, config.eip1559_elasticity)
+        else {
+            return Ok(());
+        };
+        if min_base_fee == 0 {
+            return Ok(());
+        }
+
+        if denominator == 0 || elasticity == 0 {
+            return Err(MinBaseFeeUpdateError::IncompatibleEip1559Params {
+                min_base_fee,
+                denominator,
+                elasticity,
+            });
+        }
+
+        let ceiling = Self::MAX_MIN_BASE_FEE_PER_ELASTICITY.saturating_mul(u64::from(elasticity));
+        if min_base_fee > ceiling {
+            return Err(MinBaseFeeUpdateError::ExceedsCeiling { min_base_fee, ceiling, elasticity });
+        }
+
+        Ok(())
+    }
+
+    /// Validates the update against the [`SystemConfig`] with [`MinBaseFeeUpdate::validate`], and
+    /// applies it if it is valid.
+    pub fn try_apply(&self, config: &mut SystemConfig) -> Result<(), MinBaseFeeUpdateError> {
+        self.validate(config)?;
+        self.apply(config);
+        Ok(())
+    }
 }
 
 impl TryFrom<&SystemConfigLog> for MinBaseFeeUpdate {
@@ -80,6 +129,65 @@ mod tests {
         assert_eq!(update.min_base_fee, 0xbeef_u64);
     }
 
+    fn eip1559_config(denominator: u32, elasticity: u32) -> SystemConfig {
+        SystemConfig {
+            eip1559_denominator: Some(denominator),
+            eip1559_elasticity: Some(elasticity),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_min_base_fee_update_valid() {
+        let mut config = eip1559_config(250, 6);
+        let update = MinBaseFeeUpdate { min_base_fee: 1_000_000_000 };
+
+        update.try_apply(&mut config).unwrap();
+        assert_eq!(config.min_base_fee, Some(1_000_000_000));
+
+        // The ceiling itself is valid.
+        let ceiling = MinBaseFeeUpdate::MAX_MIN_BASE_FEE_PER_ELASTICITY * 6;
+        MinBaseFeeUpdate { min_base_fee: ceiling }.validate(&config).unwrap();
+
+        // Without EIP-1559 parameters, there is nothing to validate against.
+        MinBaseFeeUpdate { min_base_fee: u64::MAX }.validate(&SystemConfig::default()).unwrap();
+    }
+
+    #[test]
+    fn test_min_base_fee_update_exceeds_ceiling() {
+        let mut config = eip1559_config(250, 2);
+        let ceiling = MinBaseFeeUpdate::MAX_MIN_BASE_FEE_PER_ELASTICITY * 2;
+        let update = MinBaseFeeUpdate { min_base_fee: ceiling + 1 };
+
+        let err = update.try_apply(&mut config).unwrap_err();
+        assert_eq!(
+            err,
+            MinBaseFeeUpdateError::ExceedsCeiling {
+                min_base_fee: ceiling + 1,
+                ceiling,
+                elasticity: 2
+            }
+        );
+        assert_eq!(config.min_base_fee, None);
+    }
+
+    #[test]
+    fn test_min_base_fee_update_zero_eip1559_params() {
+        let config = eip1559_config(0, 6);
+        let err = MinBaseFeeUpdate { min_base_fee: 1 }.validate(&config).unwrap_err();
+        assert_eq!(
+            err,
+            MinBaseFeeUpdateError::IncompatibleEip1559Params {
+                min_base_fee: 1,
+                denominator: 0,
+                elasticity: 6
+            }
+        );
+
+        // A zero min base fee disables the floor.
+        MinBaseFeeUpdate { min_base_fee: 0 }.validate(&config).unwrap();
+    }
+
     #[test]
     fn test_min_base_fee_update_invalid_data_len() {
         let log =
@@ -16,11 +16,43 @@ pub struct OperatorFeeUpdate {
 }
 
 impl OperatorFeeUpdate {
+    /// The maximum amount of gas for which the operator fee is guaranteed not to overflow.
+    pub const MAX_OPERATOR_FEE_GAS: u64 = 1 << 40;
+
+    /// The maximum safe operator fee scalar.
+    ///
+    /// The operator fee is computed as `gas_used * scalar / 1e6 + constant`. Bounding the scalar
+    /// guarantees that `gas_used * scalar` fits in a [`u64`] for up to
+    /// [`OperatorFeeUpdate::MAX_OPERATOR_FEE_GAS`] gas.
+    pub const MAX_OPERATOR_FEE_SCALAR: u32 = (u64::MAX / Self::MAX_OPERATOR_FEE_GAS) as u32;
+
     /// Applies the update to the [`SystemConfig`].
     pub const fn apply(&self, config: &mut SystemConfig) {
         config.operator_fee_scalar = Some(self.operator_fee_scalar);
         config.operator_fee_constant = Some(self.operator_fee_constant);
     }
+
+    /// Checks that the operator fee scalar is within the safe range, up to
+    /// [`OperatorFeeUpdate::MAX_OPERATOR_FEE_SCALAR`].
+    ///
+    /// This is an opt-in check for operator tooling. Derivation applies every operator fee update
+    /// from L1 with [`OperatorFeeUpdate::apply`], regardless of this check.
+    pub const fn validate(&self) -> Result<(), OperatorFeeUpdateError> {
+        if self.operator_fee_scalar > Self::MAX_OPERATOR_FEE_SCALAR {
+            return Err(OperatorFeeUpdateError::ScalarTooLarge(self.operator_fee_scalar));
+        }
+        Ok(())
+    }
+
+    /// Validates the update with [`OperatorFeeUpdate::validate`], and applies it to the
+    /// [`SystemConfig`] if it is valid.
+    pub const fn try_apply(&self, config: &mut SystemConfig) -> Result<(), OperatorFeeUpdateError> {
+        if let Err(err) = self.validate() {
+            return Err(err);
+        }
+        self.apply(config);
+        Ok(())
+    }
 }
 
 impl TryFrom<&SystemConfigLog> for OperatorFeeUpdate {
@@ -74,6 +106,35 @@ mod tests {
     use alloc::vec;
     use alloy_primitives::{Address, B256, Bytes, Log, LogData, hex};
 
+    #[test]
+    fn test_operator_fee_update_max_scalar() {
+        assert_eq!(OperatorFeeUpdate::MAX_OPERATOR_FEE_SCALAR, 16_777_215);
+
+        let mut config = SystemConfig::default();
+        let update = OperatorFeeUpdate {
+            operator_fee_scalar: OperatorFeeUpdate::MAX_OPERATOR_FEE_SCALAR,
+            operator_fee_constant: 0xbeef,
+        };
+        update.try_apply(&mut config).unwrap();
+        assert_eq!(config.operator_fee_scalar, Some(OperatorFeeUpdate::MAX_OPERATOR_FEE_SCALAR));
+        assert_eq!(config.operator_fee_constant, Some(0xbeef));
+
+        let fee = OperatorFeeUpdate::MAX_OPERATOR_FEE_GAS
+            .checked_mul(u64::from(OperatorFeeUpdate::MAX_OPERATOR_FEE_SCALAR));
+        assert!(fee.is_some());
+    }
+
+    #[test]
+    fn test_operator_fee_update_scalar_too_large() {
+        let mut config = SystemConfig::default();
+        let scalar = OperatorFeeUpdate::MAX_OPERATOR_FEE_SCALAR + 1;
+        let update = OperatorFeeUpdate { operator_fee_scalar: scalar, operator_fee_constant: 0 };
+
+        let err = update.try_apply(&mut config).unwrap_err();
+        assert_eq!(err, OperatorFeeUpdateError::ScalarTooLarge(scalar));
+        assert_eq!(config.operator_fee_scalar, None);
+    }
+
     #[test]
     fn test_operator_fee_update_try_from() {
         let log = Log {
@@ -6,6 +6,10 @@ use kona_protocol::Predeploys;
 ///
 /// See [`parse_access_list_item_to_inbox_entries`] for more details. Return flattened iterator with
 /// all inbox entries.
+///
+/// Entries are not deduplicated, as distinct messages may share identical entries, such as the
+/// chain ID extension entry of messages initiated on the same chain. Deduplicate whole messages
+/// after parsing the entries instead.
 pub fn parse_access_list_items_to_inbox_entries<'a>(
     access_list_items: impl Iterator<Item = &'a AccessListItem>,
 ) -> impl Iterator<Item = &'a B256> {
@@ -111,6 +111,22 @@ pub enum SuperRootError {
 /// A [Result] alias for the [SuperRootError] type.
 pub type SuperRootResult<T> = core::result::Result<T, SuperRootError>;
 
+/// Errors returned when building a [ChainRootInfo].
+///
+/// [ChainRootInfo]: crate::ChainRootInfo
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+pub enum ChainRootInfoError {
+    /// No chain ID was set.
+    #[error("chain root info is missing a chain ID")]
+    MissingChainId,
+    /// The chain is not part of the dependency set.
+    #[error("chain {0} is not in the dependency set")]
+    UnknownChain(u64),
+    /// The canonical output root is missing or zero.
+    #[error("chain {0} has a zero canonical output root")]
+    ZeroOutputRoot(u64),
+}
+
 /// Errors that can occur during interop validation.
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum InteropValidationError {
@@ -38,6 +38,112 @@ pub struct ManagedEvent {
     pub derivation_origin_update: Option<BlockInfo>,
 }
 
+impl ManagedEvent {
+    /// Returns the [ManagedEventFilter] selecting exactly the kinds of events set on this event.
+    pub const fn kinds(&self) -> ManagedEventFilter {
+        let mut kinds = ManagedEventFilter::NONE;
+        if self.reset.is_some() {
+            kinds = kinds.union(ManagedEventFilter::RESET);
+        }
+        if self.unsafe_block.is_some() {
+            kinds = kinds.union(ManagedEventFilter::UNSAFE_BLOCK);
+        }
+        if self.derivation_update.is_some() {
+            kinds = kinds.union(ManagedEventFilter::DERIVATION_UPDATE);
+        }
+        if self.exhaust_l1.is_some() {
+            kinds = kinds.union(ManagedEventFilter::EXHAUST_L1);
+        }
+        if self.replace_block.is_some() {
+            kinds = kinds.union(ManagedEventFilter::REPLACE_BLOCK);
+        }
+        if self.derivation_origin_update.is_some() {
+            kinds = kinds.union(ManagedEventFilter::DERIVATION_ORIGIN_UPDATE);
+        }
+        kinds
+    }
+
+    /// Returns `true` if any of the kinds of events set on this event is selected by the filter.
+    ///
+    /// Only checks which fields are set, so it is cheap to call before handling the event.
+    pub const fn matches(&self, filter: &ManagedEventFilter) -> bool {
+        self.kinds().intersects(*filter)
+    }
+}
+
+/// A set of [ManagedEvent] kinds, combined as bitflags.
+///
+/// ```
+/// use kona_interop::ManagedEventFilter;
+///
+/// let filter = ManagedEventFilter::REPLACE_BLOCK | ManagedEventFilter::RESET;
+/// assert!(filter.contains(ManagedEventFilter::RESET));
+/// assert!(!filter.contains(ManagedEventFilter::UNSAFE_BLOCK));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ManagedEventFilter(u8);
+
+impl ManagedEventFilter {
+    /// Selects no events.
+    pub const NONE: Self = Self(0);
+    /// Selects [ManagedEvent::reset].
+    pub const RESET: Self = Self(1 << 0);
+    /// Selects [ManagedEvent::unsafe_block].
+    pub const UNSAFE_BLOCK: Self = Self(1 << 1);
+    /// Selects [ManagedEvent::derivation_update].
+    pub const DERIVATION_UPDATE: Self = Self(1 << 2);
+    /// Selects [ManagedEvent::exhaust_l1].
+    pub const EXHAUST_L1: Self = Self(1 << 3);
+    /// Selects [ManagedEvent::replace_block].
+    pub const REPLACE_BLOCK: Self = Self(1 << 4);
+    /// Selects [ManagedEvent::derivation_origin_update].
+    pub const DERIVATION_ORIGIN_UPDATE: Self = Self(1 << 5);
+    /// Selects the events that update the local safety heads.
+    pub const SAFETY_UPDATES: Self =
+        Self::UNSAFE_BLOCK.union(Self::DERIVATION_UPDATE).union(Self::DERIVATION_ORIGIN_UPDATE);
+    /// Selects all events.
+    pub const ALL: Self = Self(0b11_1111);
+
+    /// Returns the raw bits of the filter.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the union of both filters.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns `true` if the filter selects all the kinds selected by `other`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if the filters select at least one kind in common.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns `true` if the filter selects no events.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl core::ops::BitOr for ManagedEventFilter {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl core::ops::BitOrAssign for ManagedEventFilter {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
 impl core::fmt::Display for ManagedEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut parts = Vec::new();
@@ -63,3 +169,107 @@ impl core::fmt::Display for ManagedEvent {
         if parts.is_empty() { write!(f, "none") } else { write!(f, "{}", parts.join(", ")) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloy_primitives::B256;
+
+    fn pair() -> DerivedRefPair {
+        DerivedRefPair { source: BlockInfo::default(), derived: BlockInfo::default() }
+    }
+
+    fn events() -> [(ManagedEvent, ManagedEventFilter); 6] {
+        [
+            (
+                ManagedEvent { reset: Some("reset".to_string()), ..Default::default() },
+                ManagedEventFilter::RESET,
+            ),
+            (
+                ManagedEvent { unsafe_block: Some(BlockInfo::default()), ..Default::default() },
+                ManagedEventFilter::UNSAFE_BLOCK,
+            ),
+            (
+                ManagedEvent { derivation_update: Some(pair()), ..Default::default() },
+                ManagedEventFilter::DERIVATION_UPDATE,
+            ),
+            (
+                ManagedEvent { exhaust_l1: Some(pair()), ..Default::default() },
+                ManagedEventFilter::EXHAUST_L1,
+            ),
+            (
+                ManagedEvent {
+                    replace_block: Some(BlockReplacement::new(BlockInfo::default(), B256::ZERO)),
+                    ..Default::default()
+                },
+                ManagedEventFilter::REPLACE_BLOCK,
+            ),
+            (
+                ManagedEvent {
+                    derivation_origin_update: Some(BlockInfo::default()),
+                    ..Default::default()
+                },
+                ManagedEventFilter::DERIVATION_ORIGIN_UPDATE,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_managed_event_matches_own_kind_only() {
+        let events = events();
+        for (event, kind) in &events {
+            assert_eq!(event.kinds(), *kind);
+            assert!(event.matches(kind));
+            assert!(event.matches(&ManagedEventFilter::ALL));
+            assert!(!event.matches(&ManagedEventFilter::NONE));
+
+            for (_, other) in events.iter().filter(|(_, other)| other != kind) {
+                assert!(!event.matches(other));
+            }
+        }
+    }
+
+    #[test]
+    fn test_managed_event_combined_filters() {
+        let safety = ManagedEventFilter::SAFETY_UPDATES;
+        let replacements = ManagedEventFilter::REPLACE_BLOCK | ManagedEventFilter::RESET;
+
+        let [reset, unsafe_block, derivation, exhaust, replace, origin] =
+            events().map(|(event, _)| event);
+        assert!(unsafe_block.matches(&safety));
+        assert!(derivation.matches(&safety));
+        assert!(origin.matches(&safety));
+        assert!(!reset.matches(&safety));
+        assert!(!exhaust.matches(&safety));
+        assert!(!replace.matches(&safety));
+
+        assert!(reset.matches(&replacements));
+        assert!(replace.matches(&replacements));
+        assert!(!unsafe_block.matches(&replacements));
+
+        // An event carrying several updates matches if any of them is selected.
+        let combined = ManagedEvent {
+            derivation_update: Some(pair()),
+            derivation_origin_update: Some(BlockInfo::default()),
+            ..Default::default()
+        };
+        assert!(combined.matches(&ManagedEventFilter::DERIVATION_ORIGIN_UPDATE));
+        assert!(!combined.matches(&replacements));
+
+        // An empty event matches nothing.
+        assert!(!ManagedEvent::default().matches(&ManagedEventFilter::ALL));
+    }
+
+    #[test]
+    fn test_managed_event_filter_ops() {
+        let mut filter = ManagedEventFilter::NONE;
+        assert!(filter.is_empty());
+        filter |= ManagedEventFilter::EXHAUST_L1;
+        filter |= ManagedEventFilter::RESET;
+        assert_eq!(filter.bits(), 0b1001);
+        assert!(filter.contains(ManagedEventFilter::RESET));
+        assert!(!filter.contains(ManagedEventFilter::RESET | ManagedEventFilter::UNSAFE_BLOCK));
+        assert!(ManagedEventFilter::ALL.contains(ManagedEventFilter::SAFETY_UPDATES));
+    }
+}
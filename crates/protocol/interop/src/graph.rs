@@ -6,13 +6,39 @@ use crate::{
     message::{EnrichedExecutingMessage, extract_executing_messages},
     traits::InteropProvider,
 };
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
 use alloy_consensus::{Header, Sealed};
 use alloy_primitives::keccak256;
+use core::fmt::{Debug, Write};
 use kona_genesis::RollupConfig;
 use kona_registry::{HashMap, ROLLUP_CONFIGS};
 use tracing::{info, warn};
 
+/// The messages of a [`MessageGraph`] whose dependencies could be checked, as returned by
+/// [`MessageGraph::resolve_partial`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolved<E: Debug> {
+    /// Messages whose dependencies are valid.
+    pub valid: Vec<EnrichedExecutingMessage>,
+    /// Errors of the chains whose block contains an invalid message, de-duplicated by chain ID as
+    /// in [`MessageGraphError::InvalidMessages`].
+    pub invalid: HashMap<u64, MessageGraphError<E>>,
+}
+
+/// A message of a [`MessageGraph`] whose dependencies couldn't be fetched from the
+/// [`InteropProvider`], e.g. because the remote chain hasn't been synced that far yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingMessage<E: Debug> {
+    /// The message.
+    pub message: EnrichedExecutingMessage,
+    /// The provider error that prevented checking the message.
+    pub error: E,
+}
+
 /// The [`MessageGraph`] represents a set of blocks at a given timestamp and the interop
 /// dependencies between them.
 ///
@@ -31,6 +57,8 @@ pub struct MessageGraph<'a, P> {
     ///
     /// These are derived from the transactions within the blocks.
     messages: Vec<EnrichedExecutingMessage>,
+    /// The number of the block of each chain within the graph.
+    block_numbers: HashMap<u64, u64>,
     /// The data provider for the graph. Required for fetching headers, receipts and remote
     /// messages within history during resolution.
     provider: &'a P,
@@ -73,7 +101,56 @@ where
             num_messages = messages.len(),
             "Derived message graph successfully",
         );
-        Ok(Self { messages, provider, rollup_configs })
+        let block_numbers =
+            blocks.iter().map(|(chain_id, header)| (*chain_id, header.number)).collect();
+        Ok(Self { messages, block_numbers, provider, rollup_configs })
+    }
+
+    /// Returns the executing messages within the graph.
+    pub fn messages(&self) -> &[EnrichedExecutingMessage] {
+        &self.messages
+    }
+
+    /// Renders the graph in the Graphviz DOT format, as a debugging aid.
+    ///
+    /// Each chain is a cluster of the blocks its messages refer to. Each message is an edge from
+    /// the block claimed to hold the initiating message to the block executing it, labelled by the
+    /// initiating block number and log index.
+    pub fn to_dot(&self) -> String {
+        let mut blocks = BTreeMap::<u64, BTreeSet<u64>>::new();
+        let mut edges = Vec::with_capacity(self.messages.len());
+        for message in &self.messages {
+            let identifier = &message.inner.identifier;
+            let initiating =
+                (identifier.chainId.saturating_to(), identifier.blockNumber.saturating_to());
+            let executing = (
+                message.executing_chain_id,
+                self.block_numbers.get(&message.executing_chain_id).copied().unwrap_or_default(),
+            );
+            blocks.entry(initiating.0).or_default().insert(initiating.1);
+            blocks.entry(executing.0).or_default().insert(executing.1);
+            edges.push((initiating, executing, identifier.logIndex.saturating_to::<u64>()));
+        }
+
+        // writing to a `String` can't fail
+        let mut dot = String::from("digraph messages {\n");
+        for (chain_id, numbers) in blocks {
+            let _ = writeln!(dot, "  subgraph cluster_{chain_id} {{");
+            let _ = writeln!(dot, "    label=\"chain {chain_id}\";");
+            for number in numbers {
+                let _ = writeln!(dot, "    \"{chain_id}/{number}\" [label=\"block {number}\"];");
+            }
+            dot.push_str("  }\n");
+        }
+        for ((chain_id, number), (executing_chain_id, executing_number), log_index) in edges {
+            let _ = writeln!(
+                dot,
+                "  \"{chain_id}/{number}\" -> \"{executing_chain_id}/{executing_number}\" \
+                 [label=\"block {number} log {log_index}\"];"
+            );
+        }
+        dot.push_str("}\n");
+        dot
     }
 
     /// Checks the validity of all messages within the graph.
@@ -137,6 +214,45 @@ where
         Ok(())
     }
 
+    /// Checks the validity of the messages within the graph whose dependencies are available,
+    /// without failing on the others.
+    ///
+    /// Unlike [`Self::resolve`], messages whose dependencies can't be fetched from the
+    /// [`InteropProvider`] aren't considered invalid, but returned as pending so the check can be
+    /// retried once they're available, e.g. while backfilling.
+    pub async fn resolve_partial(self) -> (Resolved<P::Error>, Vec<PendingMessage<P::Error>>) {
+        let mut resolved = Resolved { valid: Vec::new(), invalid: HashMap::default() };
+        let mut pending = Vec::new();
+
+        for message in self.messages.iter() {
+            match self.check_single_dependency(message).await {
+                Ok(()) => resolved.valid.push(message.clone()),
+                Err(MessageGraphError::InteropProviderError(error)) => {
+                    pending.push(PendingMessage { message: message.clone(), error })
+                }
+                Err(e) => {
+                    warn!(
+                        target: "message_graph",
+                        executing_chain_id = message.executing_chain_id,
+                        message_hash = ?message.inner.payloadHash,
+                        err = %e,
+                        "Invalid ExecutingMessage found",
+                    );
+                    resolved.invalid.insert(message.executing_chain_id, e);
+                }
+            }
+        }
+
+        info!(
+            target: "message_graph",
+            num_valid_messages = resolved.valid.len(),
+            num_invalid_messages = resolved.invalid.len(),
+            num_pending_messages = pending.len(),
+            "Partially resolved the message graph",
+        );
+        (resolved, pending)
+    }
+
     /// Checks the dependency of a single [`EnrichedExecutingMessage`]. If the message's
     /// dependencies are unavailable, the message is considered invalid and an [`Err`] is
     /// returned.
@@ -248,7 +364,7 @@ mod test {
     use super::{MESSAGE_EXPIRY_WINDOW, MessageGraph};
     use crate::{
         MessageGraphError,
-        test_util::{ExecutingMessageBuilder, SuperchainBuilder},
+        test_util::{ExecutingMessageBuilder, InteropProviderError, SuperchainBuilder},
     };
     use alloy_primitives::{Address, hex, keccak256};
 
@@ -633,4 +749,73 @@ mod test {
             }
         );
     }
+
+    #[tokio::test]
+    async fn test_to_dot() {
+        let mut superchain = default_superchain();
+
+        let chain_a_time = superchain.chain(CHAIN_A_ID).header.timestamp;
+
+        superchain.chain(CHAIN_A_ID).add_initiating_message(MOCK_MESSAGE.into());
+        superchain
+            .chain(CHAIN_B_ID)
+            .modify_header(|header| header.number = 7)
+            .add_executing_message(
+                ExecutingMessageBuilder::default()
+                    .with_message_hash(keccak256(MOCK_MESSAGE))
+                    .with_origin_chain_id(CHAIN_A_ID)
+                    .with_origin_timestamp(chain_a_time)
+                    .with_origin_log_index(3),
+            );
+
+        let (headers, cfgs, provider) = superchain.build();
+
+        let graph = MessageGraph::derive(&headers, &provider, &cfgs).await.unwrap();
+        let dot = graph.to_dot();
+
+        assert!(dot.starts_with("digraph messages {"));
+        assert!(dot.contains("subgraph cluster_1 {"));
+        assert!(dot.contains("subgraph cluster_2 {"));
+        assert!(dot.contains("\"1/0\" [label=\"block 0\"];"));
+        assert!(dot.contains("\"2/7\" [label=\"block 7\"];"));
+        assert!(dot.contains("\"1/0\" -> \"2/7\" [label=\"block 0 log 3\"];"));
+        assert_eq!(dot.matches("->").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_partial() {
+        let mut superchain = default_superchain();
+
+        let chain_a_time = superchain.chain(CHAIN_A_ID).header.timestamp;
+
+        superchain.chain(CHAIN_A_ID).add_initiating_message(MOCK_MESSAGE.into());
+        superchain
+            .chain(CHAIN_B_ID)
+            .add_executing_message(
+                ExecutingMessageBuilder::default()
+                    .with_message_hash(keccak256(MOCK_MESSAGE))
+                    .with_origin_chain_id(CHAIN_A_ID)
+                    .with_origin_timestamp(chain_a_time),
+            )
+            // chain A hasn't been synced up to block 1 yet
+            .add_executing_message(
+                ExecutingMessageBuilder::default()
+                    .with_message_hash(keccak256(MOCK_MESSAGE))
+                    .with_origin_chain_id(CHAIN_A_ID)
+                    .with_origin_block_number(1)
+                    .with_origin_timestamp(chain_a_time),
+            );
+
+        let (headers, cfgs, provider) = superchain.build();
+
+        let graph = MessageGraph::derive(&headers, &provider, &cfgs).await.unwrap();
+        let (resolved, pending) = graph.resolve_partial().await;
+
+        assert_eq!(resolved.valid.len(), 1);
+        assert_eq!(resolved.valid[0].inner.identifier.blockNumber.to::<u64>(), 0);
+        assert!(resolved.invalid.is_empty());
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].message.inner.identifier.blockNumber.to::<u64>(), 1);
+        assert_eq!(pending[0].error, InteropProviderError);
+    }
 }
@@ -9,35 +9,39 @@
 extern crate alloc;
 
 mod graph;
-pub use graph::MessageGraph;
+pub use graph::{MessageGraph, PendingMessage, Resolved};
 
 mod event;
-pub use event::ManagedEvent;
+pub use event::{ManagedEvent, ManagedEventFilter};
 
 mod control;
 pub use control::ControlEvent;
 
 mod replacement;
-pub use replacement::BlockReplacement;
+pub use replacement::{BlockReplacement, ReplacementError};
 
 mod traits;
 pub use traits::{InteropProvider, InteropValidator};
 
 mod safety;
-pub use safety::SafetyLevelParseError;
+pub use safety::{SafetyLevelCode, SafetyLevelParseError};
 
 mod errors;
 pub use errors::{
-    InteropValidationError, MessageGraphError, MessageGraphResult, SuperRootError, SuperRootResult,
+    ChainRootInfoError, InteropValidationError, MessageGraphError, MessageGraphResult,
+    SuperRootError, SuperRootResult,
 };
 
 mod root;
-pub use root::{ChainRootInfo, OutputRootWithChain, SuperRoot, SuperRootOutput};
+pub use root::{
+    ChainRootInfo, ChainRootInfoBuilder, OutputRootWithChain, SuperRoot, SuperRootOutput,
+};
 
 mod message;
 pub use message::{
-    EnrichedExecutingMessage, ExecutingDescriptor, ExecutingMessage, MessageIdentifier,
-    RawMessagePayload, extract_executing_messages, parse_log_to_executing_message,
+    EnrichedExecutingMessage, ExecutingDescriptor, ExecutingMessage, ExtractionStats,
+    MessageIdentifier, RawMessagePayload, extract_executing_messages,
+    extract_executing_messages_with_stats, parse_log_to_executing_message,
     parse_logs_to_executing_msgs,
 };
 
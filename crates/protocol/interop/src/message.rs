@@ -4,7 +4,7 @@
 //! <https://github.com/ethereum-optimism/optimism/blob/34d5f66ade24bd1f3ce4ce7c0a6cfc1a6540eca1/packages/contracts-bedrock/src/L2/CrossL2Inbox.sol>
 
 use alloc::{vec, vec::Vec};
-use alloy_primitives::{Bytes, ChainId, Log, keccak256};
+use alloy_primitives::{Address, B256, Bytes, ChainId, Log, U256, keccak256};
 use alloy_sol_types::{SolEvent, sol};
 use derive_more::{AsRef, Constructor, From};
 use kona_protocol::Predeploys;
@@ -45,6 +45,70 @@ sol! {
     ) external;
 }
 
+impl MessageIdentifier {
+    /// Constructs the [MessageIdentifier] of the log at the given location.
+    ///
+    /// The `origin` of the returned identifier is unset. It must be set with
+    /// [MessageIdentifier::with_origin] before computing the checksum of the identifier.
+    pub fn from_location(
+        chain_id: ChainId,
+        block_number: u64,
+        log_index: u32,
+        timestamp: u64,
+    ) -> Self {
+        Self {
+            origin: Address::ZERO,
+            blockNumber: U256::from(block_number),
+            logIndex: U256::from(log_index),
+            timestamp: U256::from(timestamp),
+            chainId: U256::from(chain_id),
+        }
+    }
+
+    /// Sets the address of the contract that emitted the initiating message.
+    pub const fn with_origin(mut self, origin: Address) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Computes the type 3 checksum of the identifier and the given message payload hash, as
+    /// computed by the `CrossL2Inbox` predeploy.
+    ///
+    /// Returns `None` if the block number, log index or timestamp exceed the sizes accepted
+    /// on-chain.
+    ///
+    /// Reference: [Checksum Calculation](https://github.com/ethereum-optimism/specs/blob/main/specs/interop/predeploys.md#type-3-checksum)
+    pub fn to_checksum_hash(&self, payload_hash: B256) -> Option<B256> {
+        let log_hash = keccak256([self.origin.as_slice(), payload_hash.as_slice()].concat());
+        self.checksum_from_log_hash(log_hash)
+    }
+
+    /// Computes the type 3 checksum of the identifier from the hash of the initiating log,
+    /// `keccak256(origin ++ payload_hash)`. The `origin` of the identifier is not read.
+    ///
+    /// Returns `None` if the block number, log index or timestamp exceed the sizes accepted
+    /// on-chain.
+    pub fn checksum_from_log_hash(&self, log_hash: B256) -> Option<B256> {
+        let block_number: u64 = self.blockNumber.try_into().ok()?;
+        let log_index: u32 = self.logIndex.try_into().ok()?;
+        let timestamp: u64 = self.timestamp.try_into().ok()?;
+
+        // idPacked = 12 zero bytes ++ block_number ++ timestamp ++ log_index
+        let mut id_packed = [0u8; 32];
+        id_packed[12..20].copy_from_slice(&block_number.to_be_bytes());
+        id_packed[20..28].copy_from_slice(&timestamp.to_be_bytes());
+        id_packed[28..32].copy_from_slice(&log_index.to_be_bytes());
+
+        let id_log_hash = keccak256([log_hash.as_slice(), &id_packed].concat());
+        let mut checksum =
+            keccak256([id_log_hash.as_slice(), &self.chainId.to_be_bytes::<32>()].concat());
+
+        // Overwrite the first byte with the checksum type.
+        checksum.0[0] = 0x03;
+        Some(checksum)
+    }
+}
+
 /// A [RawMessagePayload] is the raw payload of an initiating message.
 #[derive(Debug, Clone, From, AsRef, PartialEq, Eq)]
 pub struct RawMessagePayload(Bytes);
@@ -106,7 +170,7 @@ pub struct ExecutingDescriptor {
 
 /// A wrapper type for [ExecutingMessage] containing the chain ID of the chain that the message was
 /// executed on.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EnrichedExecutingMessage {
     /// The inner [ExecutingMessage].
     pub inner: ExecutingMessage,
@@ -141,6 +205,53 @@ pub fn extract_executing_messages(receipts: &[OpReceiptEnvelope]) -> Vec<Executi
     })
 }
 
+/// Statistics collected while extracting [ExecutingMessage]s from [Log]s.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExtractionStats {
+    /// The number of logs scanned.
+    pub logs_scanned: usize,
+    /// The number of [ExecutingMessage]s found.
+    pub messages_found: usize,
+    /// The number of logs that carry the [ExecutingMessage] event signature, but could not be
+    /// decoded.
+    pub malformed_skipped: usize,
+}
+
+impl ExtractionStats {
+    /// Parses the [Log] to an [ExecutingMessage], if any, and records the outcome.
+    ///
+    /// See [`parse_log_to_executing_message`].
+    pub fn scan(&mut self, log: &Log) -> Option<ExecutingMessage> {
+        self.logs_scanned += 1;
+
+        let message = parse_log_to_executing_message(log);
+        if message.is_some() {
+            self.messages_found += 1;
+        } else if log.address == Predeploys::CROSS_L2_INBOX &&
+            log.topics().first() == Some(&ExecutingMessage::SIGNATURE_HASH)
+        {
+            self.malformed_skipped += 1;
+        }
+        message
+    }
+}
+
+/// Extracts all [ExecutingMessage] events from list of [OpReceiptEnvelope]s, along with the
+/// [ExtractionStats] of the extraction.
+///
+/// Malformed executing message logs are counted and skipped.
+pub fn extract_executing_messages_with_stats(
+    receipts: &[OpReceiptEnvelope],
+) -> (Vec<ExecutingMessage>, ExtractionStats) {
+    let mut stats = ExtractionStats::default();
+    let messages = receipts
+        .iter()
+        .flat_map(|envelope| envelope.logs())
+        .filter_map(|log| stats.scan(log))
+        .collect();
+    (messages, stats)
+}
+
 /// Parses [`Log`]s to [`ExecutingMessage`]s.
 ///
 /// See [`parse_log_to_executing_message`] for more details. Return iterator maps 1-1 with input.
@@ -162,10 +273,61 @@ pub fn parse_log_to_executing_message(log: &Log) -> Option<ExecutingMessage> {
 
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{Address, B256, LogData, U256};
+    use alloy_primitives::{LogData, address, b256};
 
     use super::*;
 
+    #[test]
+    fn test_message_identifier_from_location() {
+        let origin = address!("0xe0e1e2e3e4e5e6e7e8e9f0f1f2f3f4f5f6f7f8f9");
+        let identifier =
+            MessageIdentifier::from_location(10, 123456, 7, 1_700_000_000).with_origin(origin);
+        assert_eq!(
+            identifier,
+            MessageIdentifier {
+                origin,
+                blockNumber: U256::from(123456),
+                logIndex: U256::from(7),
+                timestamp: U256::from(1_700_000_000),
+                chainId: U256::from(10),
+            }
+        );
+    }
+
+    #[test]
+    fn test_message_identifier_checksum_known_values() {
+        let identifier = MessageIdentifier::from_location(3, 2587, 66, 4660);
+        let log_hash = b256!("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef");
+        assert_eq!(
+            identifier.checksum_from_log_hash(log_hash),
+            Some(b256!("0x03ca886771056d8ea647bb809b888ba14986f57daaf28954d40408321717716a"))
+        );
+    }
+
+    #[test]
+    fn test_message_identifier_checksum_hashes_origin_and_payload() {
+        let origin = address!("0xe0e1e2e3e4e5e6e7e8e9f0f1f2f3f4f5f6f7f8f9");
+        let payload_hash = keccak256(b"example payload");
+        let identifier =
+            MessageIdentifier::from_location(10, 123456, 7, 1_700_000_000).with_origin(origin);
+        let log_hash = keccak256([origin.as_slice(), payload_hash.as_slice()].concat());
+        assert_eq!(
+            identifier.to_checksum_hash(payload_hash),
+            identifier.checksum_from_log_hash(log_hash)
+        );
+    }
+
+    #[test]
+    fn test_message_identifier_checksum_out_of_range() {
+        let mut identifier = MessageIdentifier::from_location(10, 1, 1, 1);
+        identifier.logIndex = U256::from(u32::MAX) + U256::from(1);
+        assert_eq!(identifier.to_checksum_hash(B256::ZERO), None);
+
+        let mut identifier = MessageIdentifier::from_location(10, 1, 1, 1);
+        identifier.blockNumber = U256::from(u64::MAX) + U256::from(1);
+        assert_eq!(identifier.to_checksum_hash(B256::ZERO), None);
+    }
+
     // Test the serialization of ExecutingDescriptor
     #[cfg(feature = "serde")]
     #[test]
@@ -213,6 +375,52 @@ mod tests {
         assert_eq!(deserialized, expected);
     }
 
+    #[test]
+    fn test_extract_executing_messages_with_stats() {
+        use alloy_consensus::{Receipt, ReceiptWithBloom};
+
+        let identifier = MessageIdentifier::from_location(10, 200, 3, 777777)
+            .with_origin(Address::repeat_byte(0x77));
+        let event = ExecutingMessage { payloadHash: B256::repeat_byte(0x88), identifier };
+        let executing = Log {
+            address: Predeploys::CROSS_L2_INBOX,
+            data: ExecutingMessage::encode_log_data(&event),
+        };
+        // An event from another contract.
+        let non_executing = Log {
+            address: Address::repeat_byte(0x99),
+            data: LogData::new_unchecked(vec![B256::ZERO], Bytes::default()),
+        };
+        // An executing message event with a truncated identifier.
+        let malformed = Log {
+            address: Predeploys::CROSS_L2_INBOX,
+            data: LogData::new_unchecked(
+                vec![ExecutingMessage::SIGNATURE_HASH, B256::repeat_byte(0x88)],
+                Bytes::from_static(&[0u8; 32]),
+            ),
+        };
+
+        let receipt = |logs: Vec<Log>| {
+            OpReceiptEnvelope::Eip1559(ReceiptWithBloom {
+                receipt: Receipt { logs, ..Default::default() },
+                ..Default::default()
+            })
+        };
+        let receipts = vec![
+            receipt(vec![executing.clone(), non_executing.clone()]),
+            receipt(vec![malformed, executing]),
+            receipt(vec![non_executing]),
+        ];
+
+        let (messages, stats) = extract_executing_messages_with_stats(&receipts);
+        assert_eq!(messages, vec![event.clone(), event]);
+        assert_eq!(
+            stats,
+            ExtractionStats { logs_scanned: 5, messages_found: 2, malformed_skipped: 1 }
+        );
+        assert_eq!(extract_executing_messages(&receipts), messages);
+    }
+
     #[test]
     fn test_parse_logs_to_executing_msgs_iterator() {
         // One valid, one invalid log
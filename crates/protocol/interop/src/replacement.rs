@@ -3,6 +3,7 @@
 use alloy_primitives::B256;
 use derive_more::Display;
 use kona_protocol::BlockInfo;
+use thiserror::Error;
 
 /// Represents a [`BlockReplacement`] event where one block replaces another.
 #[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
@@ -22,3 +23,66 @@ impl<T> BlockReplacement<T> {
         Self { replacement, invalidated }
     }
 }
+
+impl BlockReplacement {
+    /// Validates that the [`BlockReplacement`] does not reorg the chain at or below the given
+    /// finalized block.
+    ///
+    /// Finalized blocks can never be replaced, so a replacement reaching that deep is always
+    /// invalid.
+    pub const fn validate_depth(&self, finalized: BlockInfo) -> Result<(), ReplacementError> {
+        if self.replacement.number <= finalized.number {
+            return Err(ReplacementError::BelowFinalized {
+                replacement: self.replacement.number,
+                finalized: finalized.number,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// An error for an invalid [`BlockReplacement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ReplacementError {
+    /// The replacement reorgs the chain at or below the finalized block.
+    #[error("replacement block {replacement} is at or below finalized block {finalized}")]
+    BelowFinalized {
+        /// The number of the replacement block.
+        replacement: u64,
+        /// The number of the finalized block.
+        finalized: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replacement_at(number: u64) -> BlockReplacement {
+        BlockReplacement::new(
+            BlockInfo { number, hash: B256::with_last_byte(1), ..Default::default() },
+            B256::with_last_byte(2),
+        )
+    }
+
+    fn finalized_at(number: u64) -> BlockInfo {
+        BlockInfo { number, ..Default::default() }
+    }
+
+    #[test]
+    fn test_validate_depth_above_finalized() {
+        assert_eq!(replacement_at(101).validate_depth(finalized_at(100)), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_depth_below_finalized() {
+        assert_eq!(
+            replacement_at(99).validate_depth(finalized_at(100)),
+            Err(ReplacementError::BelowFinalized { replacement: 99, finalized: 100 })
+        );
+        assert_eq!(
+            replacement_at(100).validate_depth(finalized_at(100)),
+            Err(ReplacementError::BelowFinalized { replacement: 100, finalized: 100 })
+        );
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! Represents a snapshot of the state of the superchain at a given integer timestamp.
 
-use crate::{SUPER_ROOT_VERSION, SuperRootError, SuperRootResult};
+use crate::{
+    ChainRootInfoError, DependencySet, SUPER_ROOT_VERSION, SuperRootError, SuperRootResult,
+};
 use alloc::vec::Vec;
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{B256, Bytes, U256, keccak256};
@@ -105,6 +107,60 @@ pub struct ChainRootInfo {
     pub pending: Bytes,
 }
 
+impl ChainRootInfo {
+    /// Returns a [ChainRootInfoBuilder], validating the chain root info against a
+    /// [DependencySet].
+    pub const fn builder() -> ChainRootInfoBuilder {
+        ChainRootInfoBuilder { chain_id: None, canonical: B256::ZERO, pending: Bytes::new() }
+    }
+}
+
+/// Builder for a [ChainRootInfo], see [ChainRootInfo::builder].
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ChainRootInfoBuilder {
+    chain_id: Option<u64>,
+    canonical: B256,
+    pending: Bytes,
+}
+
+impl ChainRootInfoBuilder {
+    /// Sets the chain ID.
+    pub const fn with_chain_id(mut self, chain_id: u64) -> Self {
+        self.chain_id = Some(chain_id);
+        self
+    }
+
+    /// Sets the canonical output root.
+    pub const fn with_canonical(mut self, canonical: B256) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Sets the pending output root preimage.
+    pub fn with_pending(mut self, pending: Bytes) -> Self {
+        self.pending = pending;
+        self
+    }
+
+    /// Builds the [ChainRootInfo].
+    ///
+    /// Fails if the chain is not part of `dependency_set`, or if the canonical output root is
+    /// zero, which usually means it was never set.
+    pub fn build(
+        self,
+        dependency_set: &DependencySet,
+    ) -> Result<ChainRootInfo, ChainRootInfoError> {
+        let chain_id = self.chain_id.ok_or(ChainRootInfoError::MissingChainId)?;
+        if !dependency_set.dependencies.contains_key(&chain_id) {
+            return Err(ChainRootInfoError::UnknownChain(chain_id));
+        }
+        if self.canonical.is_zero() {
+            return Err(ChainRootInfoError::ZeroOutputRoot(chain_id));
+        }
+        Ok(ChainRootInfo { chain_id, canonical: self.canonical, pending: self.pending })
+    }
+}
+
 /// The super root response type.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -143,10 +199,56 @@ impl OutputRootWithChain {
 
 #[cfg(test)]
 mod test {
-    use crate::{SUPER_ROOT_VERSION, errors::SuperRootError};
+    use crate::{
+        ChainDependency, ChainRootInfoError, DependencySet, SUPER_ROOT_VERSION,
+        errors::SuperRootError,
+    };
+
+    use super::{ChainRootInfo, OutputRootWithChain, SuperRoot};
+    use alloy_primitives::{B256, Bytes, b256};
+    use kona_registry::HashMap;
+
+    fn dependency_set() -> DependencySet {
+        let mut dependencies = HashMap::default();
+        dependencies.insert(10, ChainDependency {});
+        DependencySet { dependencies, override_message_expiry_window: None }
+    }
+
+    #[test]
+    fn test_build_chain_root_info() {
+        let info = ChainRootInfo::builder()
+            .with_chain_id(10)
+            .with_canonical(B256::repeat_byte(1))
+            .with_pending(Bytes::from_static(&[2]))
+            .build(&dependency_set())
+            .unwrap();
+
+        assert_eq!(
+            info,
+            ChainRootInfo {
+                chain_id: 10,
+                canonical: B256::repeat_byte(1),
+                pending: Bytes::from_static(&[2]),
+            }
+        );
+    }
 
-    use super::{OutputRootWithChain, SuperRoot};
-    use alloy_primitives::{B256, b256};
+    #[test]
+    fn test_build_chain_root_info_rejects_invalid() {
+        let builder = ChainRootInfo::builder().with_canonical(B256::repeat_byte(1));
+        assert_eq!(
+            builder.clone().build(&dependency_set()),
+            Err(ChainRootInfoError::MissingChainId)
+        );
+        assert_eq!(
+            builder.with_chain_id(11).build(&dependency_set()),
+            Err(ChainRootInfoError::UnknownChain(11))
+        );
+        assert_eq!(
+            ChainRootInfo::builder().with_chain_id(10).build(&dependency_set()),
+            Err(ChainRootInfoError::ZeroOutputRoot(10))
+        );
+    }
 
     #[test]
     fn test_super_root_sorts_outputs() {
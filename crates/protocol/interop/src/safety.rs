@@ -1,16 +1,68 @@
 //! Message safety level for interoperability.
 use alloc::string::String;
+use op_alloy_consensus::interop::SafetyLevel;
 use thiserror::Error;
 
-/// Error when parsing SafetyLevel from string.
-#[derive(Error, Debug)]
-#[error("Invalid SafetyLevel, error: {0}")]
-pub struct SafetyLevelParseError(pub String);
+/// Error when parsing SafetyLevel.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum SafetyLevelParseError {
+    /// The string does not name a safety level.
+    #[error("Invalid SafetyLevel, error: {0}")]
+    Invalid(String),
+    /// The numeric code does not map to a safety level.
+    #[error("Unknown SafetyLevel code: {0}")]
+    UnknownCode(u8),
+}
+
+/// Compact numeric encoding of a [SafetyLevel].
+///
+/// The mapping is stable, and safe to persist:
+///
+/// | Code       | Level                        |
+/// |------------|------------------------------|
+/// | `0`        | [SafetyLevel::LocalUnsafe]   |
+/// | `1`        | [SafetyLevel::CrossUnsafe]   |
+/// | `2`        | [SafetyLevel::LocalSafe]     |
+/// | `3`        | [SafetyLevel::CrossSafe]     |
+/// | `4`        | [SafetyLevel::Finalized]     |
+/// | `u8::MAX`  | [SafetyLevel::Invalid]       |
+pub trait SafetyLevelCode: Sized {
+    /// Parses the safety level from its numeric code.
+    fn from_code(code: u8) -> Result<Self, SafetyLevelParseError>;
+
+    /// Returns the numeric code of the safety level.
+    fn to_code(&self) -> u8;
+}
+
+impl SafetyLevelCode for SafetyLevel {
+    fn from_code(code: u8) -> Result<Self, SafetyLevelParseError> {
+        match code {
+            0 => Ok(Self::LocalUnsafe),
+            1 => Ok(Self::CrossUnsafe),
+            2 => Ok(Self::LocalSafe),
+            3 => Ok(Self::CrossSafe),
+            4 => Ok(Self::Finalized),
+            u8::MAX => Ok(Self::Invalid),
+            _ => Err(SafetyLevelParseError::UnknownCode(code)),
+        }
+    }
+
+    fn to_code(&self) -> u8 {
+        match self {
+            Self::LocalUnsafe => 0,
+            Self::CrossUnsafe => 1,
+            Self::LocalSafe => 2,
+            Self::CrossSafe => 3,
+            Self::Finalized => 4,
+            Self::Invalid => u8::MAX,
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use core::str::FromStr;
-    use op_alloy_consensus::interop::SafetyLevel;
 
     #[test]
     #[cfg(feature = "serde")]
@@ -50,4 +102,26 @@ mod tests {
         assert!(SafetyLevel::from_str("").is_err());
         assert!(SafetyLevel::from_str("safe ").is_err());
     }
+
+    #[test]
+    fn test_safety_level_code_roundtrip() {
+        let levels = [
+            (SafetyLevel::LocalUnsafe, 0),
+            (SafetyLevel::CrossUnsafe, 1),
+            (SafetyLevel::LocalSafe, 2),
+            (SafetyLevel::CrossSafe, 3),
+            (SafetyLevel::Finalized, 4),
+            (SafetyLevel::Invalid, u8::MAX),
+        ];
+        for (level, code) in levels {
+            assert_eq!(level.to_code(), code);
+            assert_eq!(SafetyLevel::from_code(code).unwrap(), level);
+        }
+    }
+
+    #[test]
+    fn test_safety_level_unknown_code() {
+        assert_eq!(SafetyLevel::from_code(5), Err(SafetyLevelParseError::UnknownCode(5)));
+        assert_eq!(SafetyLevel::from_code(42), Err(SafetyLevelParseError::UnknownCode(42)));
+    }
 }
@@ -39,7 +39,7 @@ impl InteropProvider for MockInteropProvider {
             .headers
             .get(&chain_id)
             .and_then(|headers| headers.get(&number))
-            .unwrap()
+            .ok_or(InteropProviderError)?
             .inner()
             .clone())
     }
@@ -49,7 +49,12 @@ impl InteropProvider for MockInteropProvider {
         chain_id: u64,
         number: u64,
     ) -> Result<Vec<OpReceiptEnvelope>, Self::Error> {
-        Ok(self.receipts.get(&chain_id).and_then(|receipts| receipts.get(&number)).unwrap().clone())
+        Ok(self
+            .receipts
+            .get(&chain_id)
+            .and_then(|receipts| receipts.get(&number))
+            .ok_or(InteropProviderError)?
+            .clone())
     }
 
     async fn receipts_by_hash(
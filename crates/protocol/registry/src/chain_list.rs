@@ -1,7 +1,12 @@
 //! List of OP Stack chains.
 
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use alloy_chains::Chain as AlloyChain;
+use kona_genesis::{ChainConfig, SuperchainLevel};
 
 /// List of Chains.
 #[derive(Debug, Clone, Default, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -32,6 +37,16 @@ impl ChainList {
         self.get_chain_by_id(chain.id())
     }
 
+    /// Returns all [Chain]s at the given [SuperchainLevel].
+    pub fn chains_by_level(&self, level: SuperchainLevel) -> Vec<&Chain> {
+        self.chains.iter().filter(|c| c.superchain_level == level as u64).collect()
+    }
+
+    /// Returns all [Chain]s at the [SuperchainLevel::Standard] level.
+    pub fn standard_chains(&self) -> Vec<&Chain> {
+        self.chains_by_level(SuperchainLevel::Standard)
+    }
+
     /// Returns the number of chains.
     pub const fn len(&self) -> usize {
         self.chains.len()
@@ -69,6 +84,22 @@ pub struct Chain {
     pub parent: SuperchainParent,
 }
 
+impl From<&ChainConfig> for Chain {
+    /// Builds the chain list entry for a [ChainConfig], identified by the chain's name.
+    fn from(config: &ChainConfig) -> Self {
+        Self {
+            name: config.name.clone(),
+            identifier: config.name.clone(),
+            chain_id: config.chain_id,
+            rpc: vec![config.public_rpc.clone()],
+            explorers: vec![config.explorer.clone()],
+            superchain_level: config.superchain_level as u64,
+            data_availability_type: config.data_availability_type.clone(),
+            parent: SuperchainParent::from_l1_chain_id(config.l1_chain_id),
+        }
+    }
+}
+
 /// A Chain Parent
 #[derive(Debug, Clone, Default, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -80,13 +111,24 @@ pub struct SuperchainParent {
 }
 
 impl SuperchainParent {
-    /// Returns the chain id for the parent.
-    pub fn chain_id(&self) -> u64 {
+    /// Returns the L2 parent for the given L1 chain id.
+    pub fn from_l1_chain_id(l1_chain_id: u64) -> Self {
+        let chain = match l1_chain_id {
+            1 => "mainnet".to_string(),
+            11155111 => "sepolia".to_string(),
+            11155421 => "sepolia-dev-0".to_string(),
+            id => id.to_string(),
+        };
+        Self { r#type: "L2".to_string(), chain }
+    }
+
+    /// Returns the chain id for the parent, or `None` if the parent chain is unknown.
+    pub fn chain_id(&self) -> Option<u64> {
         match self.chain.as_ref() {
-            "mainnet" => 1,
-            "sepolia" => 11155111,
-            "sepolia-dev-0" => 11155421,
-            _ => 10,
+            "mainnet" => Some(1),
+            "sepolia" => Some(11155111),
+            "sepolia-dev-0" => Some(11155421),
+            chain => chain.parse().ok(),
         }
     }
 }
@@ -102,4 +144,47 @@ mod tests {
         let base_chain = chains.iter().find(|c| c.name == "Base").unwrap();
         assert_eq!(base_chain.chain_id, 8453);
     }
+
+    #[test]
+    fn test_standard_chains() {
+        let standard = crate::CHAINS.standard_chains();
+        assert!(standard.iter().any(|c| c.identifier == "mainnet/op"));
+        assert!(standard.iter().all(|c| c.superchain_level == SuperchainLevel::Standard as u64));
+
+        // Frontier chains are excluded.
+        assert!(!standard.iter().any(|c| c.identifier == "mainnet/bob"));
+        assert!(!standard.iter().any(|c| c.identifier == "mainnet/redstone"));
+    }
+
+    #[test]
+    fn test_chains_by_level() {
+        let frontier = crate::CHAINS.chains_by_level(SuperchainLevel::Frontier);
+        assert!(frontier.iter().any(|c| c.identifier == "mainnet/bob"));
+        assert!(!frontier.iter().any(|c| c.identifier == "mainnet/op"));
+
+        let candidates = crate::CHAINS.chains_by_level(SuperchainLevel::StandardCandidate);
+        assert!(candidates.iter().any(|c| c.identifier == "mainnet/base"));
+
+        // Every chain is at exactly one level.
+        let total: usize = [
+            SuperchainLevel::Frontier,
+            SuperchainLevel::StandardCandidate,
+            SuperchainLevel::Standard,
+        ]
+        .into_iter()
+        .map(|level| crate::CHAINS.chains_by_level(level).len())
+        .sum();
+        assert_eq!(total, crate::CHAINS.len());
+    }
+
+    #[test]
+    fn test_parent_chain_id() {
+        for l1_chain_id in [1, 11155111, 11155421, 900] {
+            let parent = SuperchainParent::from_l1_chain_id(l1_chain_id);
+            assert_eq!(parent.chain_id(), Some(l1_chain_id));
+        }
+
+        let unknown = SuperchainParent { r#type: "L2".to_string(), chain: "holesky".to_string() };
+        assert_eq!(unknown.chain_id(), None);
+    }
 }
@@ -56,6 +56,22 @@ impl L1Config {
     const HOLESKY_DEPOSIT_CONTRACT_ADDRESS: Address =
         address!("0x4242424242424242424242424242424242424242");
 
+    /// The names of the known L1 chains, with their chain ids.
+    const NAMED_CHAIN_IDS: [(&'static str, u64); 5] = [
+        ("mainnet", 1),
+        ("sepolia", 11155111),
+        ("holesky", 17000),
+        ("zircuit-devnet", 900),
+        ("zircuit-sandbox", 3151908),
+    ];
+
+    /// Returns the [`L1ChainConfig`] of a known L1 chain by its name, ignoring case.
+    pub fn by_name(name: &str) -> Option<&'static L1ChainConfig> {
+        let (_, chain_id) =
+            Self::NAMED_CHAIN_IDS.iter().find(|(known, _)| known.eq_ignore_ascii_case(name))?;
+        crate::L1_CONFIGS.get(chain_id)
+    }
+
     /// Get the genesis for a given chain ID.
     pub fn get_l1_genesis(chain_id: u64) -> Result<Self, L1GenesisGetterErrors> {
         match NamedChain::try_from(chain_id)
@@ -397,4 +413,23 @@ mod tests {
         let l1_config = L1Config::get_l1_genesis(1000000).unwrap_err();
         assert!(matches!(l1_config, L1GenesisGetterErrors::ChainIDDoesNotExist(1000000)));
     }
+
+    #[test]
+    fn test_l1_config_by_name() {
+        for (name, chain_id) in [
+            ("mainnet", u64::from(NamedChain::Mainnet)),
+            ("Sepolia", u64::from(NamedChain::Sepolia)),
+            ("HOLESKY", u64::from(NamedChain::Holesky)),
+            ("zircuit-devnet", 900),
+            ("zircuit-sandbox", 3151908),
+        ] {
+            assert_eq!(L1Config::by_name(name).unwrap().chain_id, chain_id, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_l1_config_by_name_unknown() {
+        assert!(L1Config::by_name("goerli").is_none());
+        assert!(L1Config::by_name("").is_none());
+    }
 }
@@ -55,6 +55,11 @@ pub fn scr_rollup_config_by_alloy_ident(chain: &alloy_chains::Chain) -> Option<&
     ROLLUP_CONFIGS.get(&chain.id())
 }
 
+/// Returns a [ChainConfig] by its [alloy_chains::Chain] identifier.
+pub fn scr_chain_config_by_alloy_ident(chain: &alloy_chains::Chain) -> Option<&ChainConfig> {
+    OPCHAINS.get(&chain.id())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +104,21 @@ mod tests {
         assert_eq!(rollup_config_by_ident, rollup_config_by_id);
         assert_eq!(rollup_config_by_alloy_ident, rollup_config_by_id);
     }
+
+    #[test]
+    fn test_chain_config_by_alloy_ident() {
+        const ALLOY_BASE: AlloyChain = AlloyChain::base_mainnet();
+
+        let chain_config = scr_chain_config_by_alloy_ident(&ALLOY_BASE).unwrap();
+        assert_eq!(chain_config.chain_id, 8453);
+        assert_eq!(chain_config.name, "Base");
+        assert_eq!(chain_config.explorer, "https://explorer.base.org");
+        assert_eq!(chain_config, OPCHAINS.get(&8453).unwrap());
+    }
+
+    #[test]
+    fn test_chain_config_by_alloy_ident_unknown() {
+        let unknown = AlloyChain::from_id(u64::MAX);
+        assert!(scr_chain_config_by_alloy_ident(&unknown).is_none());
+    }
 }
@@ -2,7 +2,9 @@
 
 use crate::L1Config;
 
-use super::ChainList;
+use super::{Chain, ChainList};
+use alloc::vec::Vec;
+use alloy_chains::Chain as AlloyChain;
 use alloy_primitives::map::HashMap;
 use kona_genesis::{ChainConfig, L1ChainConfig, RollupConfig, Superchains};
 
@@ -58,12 +60,66 @@ impl Registry {
 
         Self { chain_list, op_chains, rollup_configs, l1_configs: L1Config::build_l1_configs() }
     }
+
+    /// Merges the given chain configurations over the chains of the registry.
+    ///
+    /// Custom chains are added to the chain list under their name. A custom configuration takes
+    /// precedence over an existing chain with the same chain id.
+    pub fn with_custom_configs(mut self, configs: Vec<ChainConfig>) -> Self {
+        for chain_config in configs {
+            let chain_id = chain_config.chain_id;
+            if let Some(existing) = self.op_chains.get(&chain_id) {
+                tracing::warn!(
+                    target: "registry",
+                    chain_id,
+                    existing = %existing.name,
+                    custom = %chain_config.name,
+                    "Custom chain config overrides an existing chain"
+                );
+            }
+
+            self.chain_list.chains.retain(|c| c.chain_id != chain_id);
+            self.chain_list.chains.push(Chain::from(&chain_config));
+            self.rollup_configs.insert(chain_id, chain_config.as_rollup_config());
+            self.op_chains.insert(chain_id, chain_config);
+        }
+        self
+    }
+
+    /// Returns a [ChainConfig] by its chain id.
+    pub fn chain_config(&self, chain_id: u64) -> Option<&ChainConfig> {
+        self.op_chains.get(&chain_id)
+    }
+
+    /// Returns a [ChainConfig] by its [AlloyChain] identifier.
+    pub fn chain_config_by_alloy_ident(&self, chain: &AlloyChain) -> Option<&ChainConfig> {
+        self.chain_config(chain.id())
+    }
+
+    /// Returns a [RollupConfig] by its chain id.
+    pub fn rollup_config(&self, chain_id: u64) -> Option<&RollupConfig> {
+        self.rollup_configs.get(&chain_id)
+    }
+
+    /// Returns a [RollupConfig] by its identifier.
+    pub fn rollup_config_by_ident(&self, ident: &str) -> Option<&RollupConfig> {
+        let chain_id = self.chain_list.get_chain_by_ident(ident)?.chain_id;
+        self.rollup_config(chain_id)
+    }
+
+    /// Returns a [RollupConfig] by its [AlloyChain] identifier.
+    pub fn rollup_config_by_alloy_ident(&self, chain: &AlloyChain) -> Option<&RollupConfig> {
+        self.rollup_config(chain.id())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::string::{String, ToString};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
     use alloy_primitives::address;
     use kona_genesis::{AddressList, OP_MAINNET_BASE_FEE_CONFIG, Roles, SuperchainLevel};
 
@@ -148,4 +204,57 @@ mod tests {
             crate::test_utils::OP_MAINNET_CONFIG
         );
     }
+
+    fn custom_chain_config(name: &str, chain_id: u64) -> ChainConfig {
+        ChainConfig {
+            name: name.to_string(),
+            chain_id,
+            l1_chain_id: 900,
+            public_rpc: String::from("http://localhost:9545"),
+            explorer: String::from("http://localhost:4000"),
+            block_time: 1,
+            seq_window_size: 200,
+            max_sequencer_drift: 300,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_custom_config() {
+        let registry = Registry::from_chain_list().with_custom_configs(vec![
+            custom_chain_config("kona-devnet-0", 901),
+            custom_chain_config("kona-devnet-1", 902),
+        ]);
+
+        let rollup_config = registry.rollup_config(901).unwrap();
+        assert_eq!(rollup_config.l2_chain_id.id(), 901);
+        assert_eq!(rollup_config.block_time, 1);
+        assert_eq!(rollup_config.seq_window_size, 200);
+
+        let by_ident = registry.rollup_config_by_ident("kona-devnet-1").unwrap();
+        assert_eq!(by_ident.l2_chain_id.id(), 902);
+        assert_eq!(
+            registry.chain_config_by_alloy_ident(&AlloyChain::from_id(901)).unwrap().name,
+            "kona-devnet-0"
+        );
+
+        // Built-in chains are still resolvable.
+        assert_eq!(
+            *registry.rollup_config_by_ident("mainnet/op").unwrap(),
+            crate::test_utils::OP_MAINNET_CONFIG
+        );
+    }
+
+    #[test]
+    fn test_custom_config_overrides_builtin() {
+        let builtin = Registry::from_chain_list();
+        let chains = builtin.chain_list.len();
+
+        let registry = builtin.with_custom_configs(vec![custom_chain_config("kona-devnet", 8453)]);
+        assert_eq!(registry.chain_list.len(), chains);
+        assert_eq!(registry.chain_config(8453).unwrap().name, "kona-devnet");
+        assert_eq!(registry.rollup_config(8453).unwrap().block_time, 1);
+        assert!(registry.rollup_config_by_ident("mainnet/base").is_none());
+        assert_eq!(registry.chain_list.get_chain_by_ident("kona-devnet").unwrap().chain_id, 8453);
+    }
 }
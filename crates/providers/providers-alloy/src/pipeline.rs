@@ -5,7 +5,7 @@ use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_derive::{
     DerivationPipeline, EthereumDataSource, IndexedAttributesQueueStage, L2ChainProvider,
-    OriginProvider, Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult,
+    OriginProvider, Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult, PipelineSnapshot,
     PolledAttributesQueueStage, ResetSignal, Signal, SignalReceiver, StatefulAttributesBuilder,
     StepResult,
 };
@@ -69,6 +69,7 @@ impl OnlinePipeline {
             blob_provider,
             chain_provider,
             l2_chain_provider.clone(),
+            None,
         );
 
         // Reset the pipeline to populate the initial L1/L2 cursor and system configuration in L1
@@ -97,12 +98,16 @@ impl OnlinePipeline {
     /// Before using the returned pipeline, a [`ResetSignal`] must be sent to
     /// instantiate the pipeline state. [`Self::new`] is a convenience method that
     /// constructs a new online pipeline and sends the reset signal.
+    ///
+    /// If set, `channel_timeout` overrides the channel timeout of the rollup config, in L1 blocks.
+    /// This diverges from consensus and must only be used in tests.
     pub fn new_polled(
         cfg: Arc<RollupConfig>,
         l1_cfg: Arc<L1ChainConfig>,
         blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
         chain_provider: AlloyChainProvider,
         l2_chain_provider: AlloyL2ChainProvider,
+        channel_timeout: Option<u64>,
     ) -> Self {
         let attributes = StatefulAttributesBuilder::new(
             cfg.clone(),
@@ -112,16 +117,18 @@ impl OnlinePipeline {
         );
         let dap = EthereumDataSource::new_from_parts(chain_provider.clone(), blob_provider, &cfg, None);
 
-        let pipeline = PipelineBuilder::new()
+        let mut builder = PipelineBuilder::new()
             .rollup_config(cfg)
             .dap_source(dap)
             .l2_chain_provider(l2_chain_provider)
             .chain_provider(chain_provider)
             .builder(attributes)
-            .origin(BlockInfo::default())
-            .build_polled();
+            .origin(BlockInfo::default());
+        if let Some(blocks) = channel_timeout {
+            builder = builder.with_channel_timeout(blocks);
+        }
 
-        Self::Polled(pipeline)
+        Self::Polled(builder.build_polled())
     }
 
     /// Constructs a new indexed derivation pipeline that is uninitialized.
@@ -131,12 +138,16 @@ impl OnlinePipeline {
     /// Before using the returned pipeline, a [`ResetSignal`] must be sent to
     /// instantiate the pipeline state. [`Self::new`] is a convenience method that
     /// constructs a new online pipeline and sends the reset signal.
+    ///
+    /// If set, `channel_timeout` overrides the channel timeout of the rollup config, in L1 blocks.
+    /// This diverges from consensus and must only be used in tests.
     pub fn new_indexed(
         cfg: Arc<RollupConfig>,
         l1_cfg: Arc<L1ChainConfig>,
         blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
         chain_provider: AlloyChainProvider,
         l2_chain_provider: AlloyL2ChainProvider,
+        channel_timeout: Option<u64>,
     ) -> Self {
         let attributes = StatefulAttributesBuilder::new(
             cfg.clone(),
@@ -146,16 +157,18 @@ impl OnlinePipeline {
         );
         let dap = EthereumDataSource::new_from_parts(chain_provider.clone(), blob_provider, &cfg, None);
 
-        let pipeline = PipelineBuilder::new()
+        let mut builder = PipelineBuilder::new()
             .rollup_config(cfg)
             .dap_source(dap)
             .l2_chain_provider(l2_chain_provider)
             .chain_provider(chain_provider)
             .builder(attributes)
-            .origin(BlockInfo::default())
-            .build_indexed();
+            .origin(BlockInfo::default());
+        if let Some(blocks) = channel_timeout {
+            builder = builder.with_channel_timeout(blocks);
+        }
 
-        Self::Managed(pipeline)
+        Self::Managed(builder.build_indexed())
     }
 }
 
@@ -209,6 +222,14 @@ impl Pipeline for OnlinePipeline {
         }
     }
 
+    /// Returns a [PipelineSnapshot] of the data buffered by the pipeline's stages.
+    fn snapshot(&self) -> PipelineSnapshot {
+        match self {
+            Self::Polled(pipeline) => pipeline.snapshot(),
+            Self::Managed(pipeline) => pipeline.snapshot(),
+        }
+    }
+
     /// Returns the rollup config.
     fn rollup_config(&self) -> &RollupConfig {
         match self {
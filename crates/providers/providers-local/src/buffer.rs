@@ -8,8 +8,12 @@ use alloy_primitives::B256;
 use kona_protocol::L2BlockInfo;
 use lru::LruCache;
 use op_alloy_consensus::OpBlock;
-use std::num::NonZeroUsize;
-use tokio::sync::RwLock;
+use std::{
+    collections::{BTreeSet, HashMap},
+    num::NonZeroUsize,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+use tokio::sync::{RwLock, broadcast};
 
 /// Events that can affect chain state
 #[derive(Debug, Clone)]
@@ -39,8 +43,34 @@ pub enum ChainStateEvent {
         /// The blocks that were reverted
         reverted: Vec<B256>,
     },
+    /// A cached block was dropped from the buffer
+    Evicted {
+        /// The number of the evicted block
+        block: u64,
+        /// Why the block was evicted
+        reason: EvictionReason,
+    },
+    /// All cached blocks at or above a height were purged
+    Invalidated {
+        /// The lowest invalidated block number
+        from: u64,
+        /// The number of blocks that were purged
+        count: usize,
+    },
+}
+
+/// The reason a block was evicted from the [`ChainStateBuffer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionReason {
+    /// The buffer was full and the block was selected by the eviction policy
+    Capacity,
+    /// The block was invalidated by a reorg, revert or cache clear
+    Invalidated,
 }
 
+/// Number of buffered events a lagging [`ChainStateBuffer`] subscriber can fall behind by.
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
 /// Cached block data containing full block information.
 ///
 /// This structure stores a complete OP block along with its derived L2 block info.
@@ -79,7 +109,78 @@ impl CachedBlock {
     }
 }
 
-/// Buffer for managing chain state with LRU caching and reorg handling.
+/// Strategy used by the [`ChainStateBuffer`] to pick a block to evict once it is full.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BufferEvictionPolicy {
+    /// Evict the least recently inserted block. Re-inserts and chain commits refresh a block's
+    /// recency, reads don't, so that they only need shared access to the cache.
+    #[default]
+    Lru,
+    /// Evict the block that was inserted first, even if it was re-inserted since.
+    ///
+    /// This is well suited to sequential replay, where blocks are consumed in insertion order.
+    Fifo,
+    /// Evict the least frequently read block, breaking ties by insertion order.
+    Lfu,
+}
+
+/// Read counts of the cached blocks, ordered for [`BufferEvictionPolicy::Lfu`] eviction.
+///
+/// Blocks are ordered by read count, then by insertion order, so the least frequently read block
+/// is found, updated and removed in logarithmic time.
+#[derive(Debug, Default)]
+struct LfuIndex {
+    /// Read count and insertion sequence number per block hash
+    entries: HashMap<B256, (u64, u64)>,
+    /// `(read count, insertion sequence number, hash)` of every tracked block
+    order: BTreeSet<(u64, u64, B256)>,
+    /// Sequence number of the next inserted block
+    next_seq: u64,
+}
+
+impl LfuIndex {
+    /// Track a newly inserted block, with no reads.
+    fn insert(&mut self, hash: B256) {
+        if self.entries.contains_key(&hash) {
+            return;
+        }
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.entries.insert(hash, (0, seq));
+        self.order.insert((0, seq, hash));
+    }
+
+    /// Count a read of the block, returning `false` if it isn't tracked.
+    fn touch(&mut self, hash: B256) -> bool {
+        let Some((count, seq)) = self.entries.get_mut(&hash) else {
+            return false;
+        };
+        self.order.remove(&(*count, *seq, hash));
+        *count += 1;
+        self.order.insert((*count, *seq, hash));
+        true
+    }
+
+    /// Stop tracking the block.
+    fn remove(&mut self, hash: &B256) {
+        if let Some((count, seq)) = self.entries.remove(hash) {
+            self.order.remove(&(count, seq, *hash));
+        }
+    }
+
+    /// The least frequently read block, the oldest one on ties.
+    fn least_used(&self) -> Option<B256> {
+        self.order.first().map(|(_, _, hash)| *hash)
+    }
+
+    /// Stop tracking all blocks.
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+/// Buffer for managing chain state with configurable caching and reorg handling.
 ///
 /// This buffer maintains two indexes for efficient block lookups:
 /// - By hash: Direct access to blocks by their hash
@@ -88,9 +189,12 @@ impl CachedBlock {
 /// The buffer also tracks the canonical chain head and handles reorganizations
 /// up to a configurable depth. Deep reorgs beyond the configured limit will
 /// trigger a cache clear to maintain consistency.
+///
+/// Once the buffer holds `capacity` blocks, inserting a new block evicts one
+/// according to the configured [`BufferEvictionPolicy`].
 #[derive(Debug)]
 pub struct ChainStateBuffer {
-    /// LRU cache for blocks by hash
+    /// Cache for blocks by hash, ordered by the eviction policy
     blocks_by_hash: RwLock<LruCache<B256, CachedBlock>>,
     /// LRU cache for blocks by number
     blocks_by_number: RwLock<LruCache<u64, B256>>,
@@ -100,6 +204,18 @@ pub struct ChainStateBuffer {
     max_reorg_depth: u64,
     /// Cache capacity
     capacity: usize,
+    /// Number of blocks newly inserted through cache warming
+    warmed_blocks: AtomicUsize,
+    /// Policy used to select blocks for eviction
+    eviction_policy: BufferEvictionPolicy,
+    /// Read counts of the cached blocks, only tracked under [`BufferEvictionPolicy::Lfu`]
+    access_counts: RwLock<LfuIndex>,
+    /// Number of blocks evicted to make room for new ones
+    evictions: AtomicU64,
+    /// Number of accesses that refreshed a block's eviction priority
+    promotions: AtomicU64,
+    /// Sender for events emitted by the buffer
+    events: broadcast::Sender<ChainStateEvent>,
 }
 
 impl ChainStateBuffer {
@@ -110,18 +226,64 @@ impl ChainStateBuffer {
     /// * `max_reorg_depth` - Maximum reorg depth to handle before clearing cache
     pub fn new(capacity: usize, max_reorg_depth: u64) -> Self {
         Self {
-            blocks_by_hash: RwLock::new(LruCache::new(NonZeroUsize::new(capacity).unwrap())),
+            // Eviction from the block cache is driven by `evict_one` so that it can follow the
+            // configured policy, hence the cache itself is unbounded.
+            blocks_by_hash: RwLock::new(LruCache::unbounded()),
             blocks_by_number: RwLock::new(LruCache::new(NonZeroUsize::new(capacity).unwrap())),
             canonical_head: RwLock::new(None),
             max_reorg_depth,
             capacity,
+            warmed_blocks: AtomicUsize::new(0),
+            eviction_policy: BufferEvictionPolicy::default(),
+            access_counts: RwLock::new(LfuIndex::default()),
+            evictions: AtomicU64::new(0),
+            promotions: AtomicU64::new(0),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
         }
     }
 
+    /// Subscribe to events emitted by the buffer, such as [`ChainStateEvent::Evicted`].
+    pub fn subscribe(&self) -> broadcast::Receiver<ChainStateEvent> {
+        self.events.subscribe()
+    }
+
+    /// Notify subscribers that a block was dropped from the buffer.
+    fn emit_evicted(&self, block: u64, reason: EvictionReason) {
+        // Sending only fails when there are no subscribers, which is fine.
+        let _ = self.events.send(ChainStateEvent::Evicted { block, reason });
+    }
+
+    /// Set the eviction policy used once the buffer is full.
+    pub const fn with_eviction_policy(mut self, eviction_policy: BufferEvictionPolicy) -> Self {
+        self.eviction_policy = eviction_policy;
+        self
+    }
+
+    /// Get the maximum reorg depth handled by the buffer
+    pub const fn max_reorg_depth(&self) -> u64 {
+        self.max_reorg_depth
+    }
+
+    /// Get the eviction policy of the buffer
+    pub const fn eviction_policy(&self) -> BufferEvictionPolicy {
+        self.eviction_policy
+    }
+
+    /// Get the maximum number of blocks the cache can hold
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
     /// Get block by hash from cache
     pub async fn get_block_by_hash(&self, hash: B256) -> Option<CachedBlock> {
-        let cache = self.blocks_by_hash.read().await;
-        cache.peek(&hash).cloned()
+        let block = self.blocks_by_hash.read().await.peek(&hash).cloned();
+        if block.is_some() &&
+            self.eviction_policy == BufferEvictionPolicy::Lfu &&
+            self.access_counts.write().await.touch(hash)
+        {
+            self.promotions.fetch_add(1, Ordering::Relaxed);
+        }
+        block
     }
 
     /// Get block by number from cache
@@ -136,6 +298,11 @@ impl ChainStateBuffer {
         }
     }
 
+    /// Check whether a block with the given number is cached, without affecting eviction order
+    pub async fn contains_number(&self, number: u64) -> bool {
+        self.blocks_by_number.read().await.contains(&number)
+    }
+
     /// Insert a block into the cache
     pub async fn insert_block(&self, block: CachedBlock) {
         let hash = block.hash();
@@ -144,7 +311,22 @@ impl ChainStateBuffer {
         let mut blocks_by_hash = self.blocks_by_hash.write().await;
         let mut blocks_by_number = self.blocks_by_number.write().await;
 
-        blocks_by_hash.put(hash, block);
+        if let Some(existing) = blocks_by_hash.peek_mut(&hash) {
+            // Only LRU treats a re-insert as a use of the block.
+            *existing = block;
+            if self.eviction_policy == BufferEvictionPolicy::Lru {
+                blocks_by_hash.promote(&hash);
+                self.promotions.fetch_add(1, Ordering::Relaxed);
+            }
+        } else {
+            if blocks_by_hash.len() >= self.capacity {
+                self.evict_one(&mut blocks_by_hash, &mut blocks_by_number).await;
+            }
+            blocks_by_hash.put(hash, block);
+            if self.eviction_policy == BufferEvictionPolicy::Lfu {
+                self.access_counts.write().await.insert(hash);
+            }
+        }
         blocks_by_number.put(number, hash);
 
         #[cfg(feature = "metrics")]
@@ -167,6 +349,56 @@ impl ChainStateBuffer {
         }
     }
 
+    /// Evict a single block from the cache according to the eviction policy.
+    async fn evict_one(
+        &self,
+        blocks_by_hash: &mut LruCache<B256, CachedBlock>,
+        blocks_by_number: &mut LruCache<u64, B256>,
+    ) {
+        let victim = match self.eviction_policy {
+            // Under FIFO, blocks are never promoted, so the least recently used entry is
+            // also the oldest insertion.
+            BufferEvictionPolicy::Lru | BufferEvictionPolicy::Fifo => {
+                blocks_by_hash.peek_lru().map(|(hash, _)| *hash)
+            }
+            BufferEvictionPolicy::Lfu => self.access_counts.read().await.least_used(),
+        };
+        let Some(victim) = victim else {
+            return;
+        };
+
+        if let Some(block) = blocks_by_hash.pop(&victim) {
+            if blocks_by_number.peek(&block.number()) == Some(&victim) {
+                blocks_by_number.pop(&block.number());
+            }
+            self.emit_evicted(block.number(), EvictionReason::Capacity);
+        }
+        if self.eviction_policy == BufferEvictionPolicy::Lfu {
+            self.access_counts.write().await.remove(&victim);
+        }
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+
+        #[cfg(feature = "metrics")]
+        {
+            use crate::Metrics;
+            kona_macros::inc!(gauge, Metrics::CACHE_EVICTIONS);
+        }
+    }
+
+    /// Insert a block prefetched by cache warming.
+    ///
+    /// Blocks whose number is already cached are left untouched so that warming never
+    /// overwrites data provided by chain events. Returns `true` if the block was newly cached.
+    pub async fn insert_warmed_block(&self, block: CachedBlock) -> bool {
+        if self.contains_number(block.number()).await {
+            return false;
+        }
+
+        self.insert_block(block).await;
+        self.warmed_blocks.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+
     /// Handle a chain state event
     pub async fn handle_event(&self, event: ChainStateEvent) -> Result<(), ChainBufferError> {
         match event {
@@ -179,6 +411,8 @@ impl ChainStateBuffer {
             ChainStateEvent::ChainReverted { old_head, new_head, reverted } => {
                 self.handle_chain_reverted(old_head, new_head, reverted).await
             }
+            // Eviction events are emitted by the buffer itself and don't change chain state.
+            ChainStateEvent::Evicted { .. } | ChainStateEvent::Invalidated { .. } => Ok(()),
         }
     }
 
@@ -195,7 +429,12 @@ impl ChainStateBuffer {
         // Mark all committed blocks as canonical
         let mut blocks_by_hash = self.blocks_by_hash.write().await;
         for hash in committed {
-            if let Some(block) = blocks_by_hash.get_mut(&hash) {
+            let block = if self.eviction_policy == BufferEvictionPolicy::Lru {
+                blocks_by_hash.get_mut(&hash)
+            } else {
+                blocks_by_hash.peek_mut(&hash)
+            };
+            if let Some(block) = block {
                 block.canonical = true;
             }
         }
@@ -206,7 +445,7 @@ impl ChainStateBuffer {
     /// Handle chain reorged event
     async fn handle_chain_reorged(
         &self,
-        _old_head: B256,
+        old_head: B256,
         new_head: B256,
         depth: u64,
     ) -> Result<(), ChainBufferError> {
@@ -224,13 +463,21 @@ impl ChainStateBuffer {
         let mut canonical_head = self.canonical_head.write().await;
         *canonical_head = Some(new_head);
 
-        // We need to invalidate cached blocks that are no longer canonical
-        // For now, we'll clear the entire cache on deep reorgs
-        if depth > 10 {
+        // We need to invalidate cached blocks that are no longer canonical.
+        // Shallow reorgs purge everything above the common ancestor of the old head,
+        // for now we'll clear the entire cache on deep reorgs
+        if depth <= 10 {
+            let old_number =
+                self.blocks_by_hash.read().await.peek(&old_head).map(CachedBlock::number);
+            if let Some(old_number) = old_number {
+                self.invalidate_from((old_number + 1).saturating_sub(depth)).await?;
+            }
+        } else {
             let mut blocks_by_hash = self.blocks_by_hash.write().await;
             let mut blocks_by_number = self.blocks_by_number.write().await;
-            blocks_by_hash.clear();
+            self.invalidate_all(&mut blocks_by_hash);
             blocks_by_number.clear();
+            self.access_counts.write().await.clear();
 
             #[cfg(feature = "metrics")]
             {
@@ -255,13 +502,79 @@ impl ChainStateBuffer {
 
         // Mark reverted blocks as non-canonical and remove from cache
         let mut blocks_by_hash = self.blocks_by_hash.write().await;
+        let mut blocks_by_number = self.blocks_by_number.write().await;
+        let mut access_counts = self.access_counts.write().await;
         for hash in reverted {
-            blocks_by_hash.pop(&hash);
+            if let Some(block) = blocks_by_hash.pop(&hash) {
+                if blocks_by_number.peek(&block.number()) == Some(&hash) {
+                    blocks_by_number.pop(&block.number());
+                }
+                self.emit_evicted(block.number(), EvictionReason::Invalidated);
+            }
+            access_counts.remove(&hash);
         }
 
         Ok(())
     }
 
+    /// Purge all cached blocks at or above the given block number.
+    ///
+    /// Subscribers are notified of each purged block as well as of the invalidation as a whole.
+    /// Returns the number of purged blocks, or an error if the number index references a block
+    /// that is no longer cached, in which case nothing is purged.
+    pub async fn invalidate_from(&self, from: u64) -> Result<usize, ChainBufferError> {
+        let mut blocks_by_hash = self.blocks_by_hash.write().await;
+        let mut blocks_by_number = self.blocks_by_number.write().await;
+
+        let indexed: Vec<(u64, B256)> = blocks_by_number
+            .iter()
+            .filter(|(number, _)| **number >= from)
+            .map(|(number, hash)| (*number, *hash))
+            .collect();
+        if let Some((number, hash)) =
+            indexed.iter().find(|(_, hash)| !blocks_by_hash.contains(hash))
+        {
+            return Err(ChainBufferError::InconsistentIndex { number: *number, hash: *hash });
+        }
+
+        // Non-canonical blocks are not necessarily indexed by number, so scan all cached blocks.
+        let mut stale: Vec<(u64, B256)> = blocks_by_hash
+            .iter()
+            .filter(|(_, block)| block.number() >= from)
+            .map(|(hash, block)| (block.number(), *hash))
+            .collect();
+        stale.sort_unstable();
+
+        for (number, _) in indexed {
+            blocks_by_number.pop(&number);
+        }
+        let mut access_counts = self.access_counts.write().await;
+        for (number, hash) in &stale {
+            blocks_by_hash.pop(hash);
+            access_counts.remove(hash);
+            self.emit_evicted(*number, EvictionReason::Invalidated);
+        }
+
+        let _ = self.events.send(ChainStateEvent::Invalidated { from, count: stale.len() });
+        Ok(stale.len())
+    }
+
+    /// Drop every cached block, notifying subscribers of each invalidated block as well as of the
+    /// invalidation as a whole.
+    fn invalidate_all(&self, blocks_by_hash: &mut LruCache<B256, CachedBlock>) {
+        let mut from = u64::MAX;
+        let mut count = 0;
+        while let Some((_, block)) = blocks_by_hash.pop_lru() {
+            from = from.min(block.number());
+            count += 1;
+            self.emit_evicted(block.number(), EvictionReason::Invalidated);
+        }
+
+        if count > 0 {
+            let _ = self.events.send(ChainStateEvent::Invalidated { from, count });
+        }
+    }
+
     /// Get the current canonical head
     pub async fn canonical_head(&self) -> Option<B256> {
         let canonical_head = self.canonical_head.read().await;
@@ -278,6 +591,10 @@ impl ChainStateBuffer {
             blocks_by_number_len: blocks_by_number.len(),
             capacity: self.capacity,
             max_reorg_depth: self.max_reorg_depth,
+            warmed_blocks: self.warmed_blocks.load(Ordering::Relaxed),
+            eviction_policy: self.eviction_policy,
+            evictions: self.evictions.load(Ordering::Relaxed),
+            promotions: self.promotions.load(Ordering::Relaxed),
         }
     }
 
@@ -287,9 +604,10 @@ impl ChainStateBuffer {
         let mut blocks_by_number = self.blocks_by_number.write().await;
         let mut canonical_head = self.canonical_head.write().await;
 
-        blocks_by_hash.clear();
+        self.invalidate_all(&mut blocks_by_hash);
         blocks_by_number.clear();
         *canonical_head = None;
+        self.access_counts.write().await.clear();
 
         #[cfg(feature = "metrics")]
         {
@@ -312,6 +630,18 @@ pub struct CacheStats {
     pub capacity: usize,
     /// Maximum reorg depth supported
     pub max_reorg_depth: u64,
+    /// Total number of blocks newly cached through cache warming
+    pub warmed_blocks: usize,
+    /// Eviction policy of the buffer
+    pub eviction_policy: BufferEvictionPolicy,
+    /// Number of blocks evicted to make room for new ones
+    pub evictions: u64,
+    /// Number of accesses that refreshed a block's eviction priority.
+    ///
+    /// Re-inserts refresh recency under [`BufferEvictionPolicy::Lru`] and reads bump the read
+    /// count under [`BufferEvictionPolicy::Lfu`]. Always zero under
+    /// [`BufferEvictionPolicy::Fifo`].
+    pub promotions: u64,
 }
 
 /// Errors that can occur in the chain buffer
@@ -331,6 +661,14 @@ pub enum ChainBufferError {
         /// The hash of the block that was not found
         hash: B256,
     },
+    /// The number index references a block that is not cached
+    #[error("Block {number} is indexed as {hash}, which is not cached")]
+    InconsistentIndex {
+        /// The indexed block number
+        number: u64,
+        /// The hash the number is indexed to
+        hash: B256,
+    },
 }
 
 #[cfg(test)]
@@ -458,6 +796,238 @@ mod tests {
         assert_eq!(stats.blocks_by_number_len, 1);
         assert_eq!(stats.capacity, 100);
         assert_eq!(stats.max_reorg_depth, 10);
+        assert_eq!(stats.warmed_blocks, 0);
+        assert_eq!(stats.eviction_policy, BufferEvictionPolicy::Lru);
+        assert_eq!(stats.evictions, 0);
+    }
+
+    /// Fills a buffer of two blocks, re-inserts and reads the first one, then inserts a third
+    /// block. Returns the numbers of the blocks left in the buffer, and its stats.
+    async fn reinsert_then_overfill(policy: BufferEvictionPolicy) -> (Vec<u64>, CacheStats) {
+        let buffer = ChainStateBuffer::new(2, 10).with_eviction_policy(policy);
+
+        let (block1, l2_info1) = create_test_block(1, B256::ZERO, B256::ZERO);
+        let (block2, l2_info2) = create_test_block(2, B256::ZERO, l2_info1.block_info.hash);
+        let (block3, l2_info3) = create_test_block(3, B256::ZERO, l2_info2.block_info.hash);
+
+        let block1 = CachedBlock::new(block1, l2_info1);
+        buffer.insert_block(block1.clone()).await;
+        buffer.insert_block(CachedBlock::new(block2, l2_info2)).await;
+        buffer.insert_block(block1).await;
+        buffer.get_block_by_number(2).await.unwrap();
+        buffer.insert_block(CachedBlock::new(block3, l2_info3)).await;
+
+        let stats = buffer.cache_stats().await;
+        let mut cached = Vec::new();
+        for number in 1..=3 {
+            if buffer.contains_number(number).await {
+                cached.push(number);
+            }
+        }
+        (cached, stats)
+    }
+
+    #[tokio::test]
+    async fn test_eviction_policies_on_reinsert() {
+        // LRU refreshes the re-inserted block, reads don't refresh the other one.
+        let (cached, stats) = reinsert_then_overfill(BufferEvictionPolicy::Lru).await;
+        assert_eq!(cached, vec![1, 3]);
+        assert_eq!(stats.promotions, 1);
+
+        // FIFO evicts the first inserted block, regardless of the re-insert.
+        let (cached, stats) = reinsert_then_overfill(BufferEvictionPolicy::Fifo).await;
+        assert_eq!(cached, vec![2, 3]);
+        assert_eq!(stats.promotions, 0);
+        assert_eq!(stats.evictions, 1);
+
+        // LFU keeps the block that was read.
+        let (cached, stats) = reinsert_then_overfill(BufferEvictionPolicy::Lfu).await;
+        assert_eq!(cached, vec![2, 3]);
+        assert_eq!(stats.promotions, 1);
+    }
+
+    /// Replays blocks `2..=5` from a buffer of four blocks that already holds blocks `1..=4`,
+    /// after block 1 was replayed and then re-committed. Returns the number of replayed blocks
+    /// that were missing from the buffer.
+    async fn replay_misses_after_recommit(policy: BufferEvictionPolicy) -> usize {
+        let buffer = ChainStateBuffer::new(4, 10).with_eviction_policy(policy);
+
+        let mut blocks = Vec::new();
+        let mut parent = B256::ZERO;
+        for number in 1..=5 {
+            let (block, l2_info) = create_test_block(number, B256::ZERO, parent);
+            parent = l2_info.block_info.hash;
+            blocks.push(CachedBlock::new(block, l2_info));
+        }
+
+        for block in &blocks[..4] {
+            buffer.insert_block(block.clone()).await;
+        }
+        buffer.get_block_by_number(1).await.unwrap();
+        buffer.insert_block(blocks[0].clone()).await;
+        buffer.insert_block(blocks[4].clone()).await;
+
+        let mut misses = 0;
+        for number in 2..=5 {
+            if buffer.get_block_by_number(number).await.is_none() {
+                misses += 1;
+            }
+        }
+        misses
+    }
+
+    #[tokio::test]
+    async fn test_fifo_keeps_upcoming_blocks_on_sequential_replay() {
+        // LRU refreshes the re-committed block 1, which was already replayed, and evicts block 2
+        // that is replayed next. FIFO evicts block 1 instead.
+        assert_eq!(replay_misses_after_recommit(BufferEvictionPolicy::Lru).await, 1);
+        assert_eq!(replay_misses_after_recommit(BufferEvictionPolicy::Fifo).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_overfilled_buffer_emits_eviction_events() {
+        let buffer = ChainStateBuffer::new(3, 10);
+        let mut events = buffer.subscribe();
+
+        let mut parent = B256::ZERO;
+        for number in 1..=5 {
+            let (block, l2_info) = create_test_block(number, B256::ZERO, parent);
+            parent = l2_info.block_info.hash;
+            buffer.insert_block(CachedBlock::new(block, l2_info)).await;
+        }
+
+        for expected in [1, 2] {
+            let event = events.try_recv().unwrap();
+            assert!(matches!(
+                event,
+                ChainStateEvent::Evicted { block, reason: EvictionReason::Capacity }
+                    if block == expected
+            ));
+        }
+        assert!(events.try_recv().is_err());
+
+        // Clearing the buffer invalidates the remaining blocks.
+        buffer.clear().await;
+        let mut invalidated = Vec::new();
+        while let Ok(ChainStateEvent::Evicted { block, reason }) = events.try_recv() {
+            assert_eq!(reason, EvictionReason::Invalidated);
+            invalidated.push(block);
+        }
+        assert_eq!(invalidated, vec![3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_from() {
+        let buffer = ChainStateBuffer::new(100, 10);
+        let mut parent = B256::ZERO;
+        for number in 1..=6 {
+            let (block, l2_info) = create_test_block(number, B256::ZERO, parent);
+            parent = l2_info.block_info.hash;
+            buffer.insert_block(CachedBlock::new(block, l2_info)).await;
+        }
+        let mut events = buffer.subscribe();
+
+        assert_eq!(buffer.invalidate_from(4).await.unwrap(), 3);
+        assert!(buffer.contains_number(3).await);
+        assert!(!buffer.contains_number(4).await);
+        assert_eq!(buffer.cache_stats().await.blocks_by_hash_len, 3);
+
+        for expected in 4..=6 {
+            assert!(matches!(
+                events.try_recv().unwrap(),
+                ChainStateEvent::Evicted { block, reason: EvictionReason::Invalidated }
+                    if block == expected
+            ));
+        }
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            ChainStateEvent::Invalidated { from: 4, count: 3 }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shallow_reorg_invalidates_reorged_range() {
+        let buffer = ChainStateBuffer::new(100, 10);
+        let mut parent = B256::ZERO;
+        for number in 1..=5 {
+            let (block, l2_info) = create_test_block(number, B256::ZERO, parent);
+            parent = l2_info.block_info.hash;
+            buffer.insert_block(CachedBlock::new(block, l2_info)).await;
+        }
+
+        let event =
+            ChainStateEvent::ChainReorged { old_head: parent, new_head: B256::ZERO, depth: 2 };
+        buffer.handle_event(event).await.unwrap();
+
+        assert!(buffer.contains_number(3).await);
+        assert!(!buffer.contains_number(4).await);
+        assert!(!buffer.contains_number(5).await);
+    }
+
+    #[tokio::test]
+    async fn test_deep_reorg_emits_invalidated() {
+        let buffer = ChainStateBuffer::new(100, 20);
+        let mut parent = B256::ZERO;
+        for number in 1..=5 {
+            let (block, l2_info) = create_test_block(number, B256::ZERO, parent);
+            parent = l2_info.block_info.hash;
+            buffer.insert_block(CachedBlock::new(block, l2_info)).await;
+        }
+        let mut events = buffer.subscribe();
+
+        let event =
+            ChainStateEvent::ChainReorged { old_head: parent, new_head: B256::ZERO, depth: 15 };
+        buffer.handle_event(event).await.unwrap();
+
+        let mut invalidated = None;
+        while let Ok(event) = events.try_recv() {
+            if let ChainStateEvent::Invalidated { from, count } = event {
+                invalidated = Some((from, count));
+            }
+        }
+        assert_eq!(invalidated, Some((1, 5)));
+        assert!(!buffer.contains_number(1).await);
+    }
+
+    #[tokio::test]
+    async fn test_lfu_evicts_least_read_block() {
+        let buffer = ChainStateBuffer::new(2, 10).with_eviction_policy(BufferEvictionPolicy::Lfu);
+
+        let (block1, l2_info1) = create_test_block(1, B256::ZERO, B256::ZERO);
+        let (block2, l2_info2) = create_test_block(2, B256::ZERO, l2_info1.block_info.hash);
+        let (block3, l2_info3) = create_test_block(3, B256::ZERO, l2_info2.block_info.hash);
+
+        buffer.insert_block(CachedBlock::new(block1, l2_info1)).await;
+        buffer.insert_block(CachedBlock::new(block2, l2_info2)).await;
+
+        // Block 1 is read more often, so block 2 is evicted despite being newer.
+        buffer.get_block_by_number(1).await.unwrap();
+        buffer.get_block_by_number(1).await.unwrap();
+        buffer.get_block_by_number(2).await.unwrap();
+        buffer.insert_block(CachedBlock::new(block3, l2_info3)).await;
+
+        assert!(buffer.get_block_by_number(1).await.is_some());
+        assert!(buffer.get_block_by_number(2).await.is_none());
+        assert!(buffer.get_block_by_number(3).await.is_some());
+
+        let stats = buffer.cache_stats().await;
+        assert_eq!(stats.evictions, 1);
+        assert_eq!(stats.blocks_by_hash_len, 2);
+    }
+
+    #[tokio::test]
+    async fn test_insert_warmed_block_skips_cached_numbers() {
+        let buffer = ChainStateBuffer::new(100, 10);
+
+        let (block, l2_info) = create_test_block(1, B256::ZERO, B256::ZERO);
+        let cached_block = CachedBlock::new(block, l2_info);
+
+        assert!(buffer.insert_warmed_block(cached_block.clone()).await);
+        assert!(!buffer.insert_warmed_block(cached_block).await);
+
+        let stats = buffer.cache_stats().await;
+        assert_eq!(stats.blocks_by_number_len, 1);
+        assert_eq!(stats.warmed_blocks, 1);
     }
 
     #[tokio::test]
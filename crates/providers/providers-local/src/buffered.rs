@@ -7,14 +7,20 @@
 
 use alloy_primitives::B256;
 use async_trait::async_trait;
+use futures::{StreamExt, TryStreamExt, stream};
 use kona_derive::{L2ChainProvider, PipelineError, PipelineErrorKind};
 use kona_genesis::{ChainGenesis, RollupConfig, SystemConfig};
 use kona_protocol::{BatchValidationProvider, L2BlockInfo, to_system_config};
 use op_alloy_consensus::OpBlock;
-use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::{future::Future, ops::RangeInclusive, sync::Arc};
+use tokio::sync::{RwLock, broadcast};
 
-use crate::{CachedBlock, ChainBufferError, ChainStateBuffer, ChainStateEvent};
+use crate::{
+    BufferEvictionPolicy, CachedBlock, ChainBufferError, ChainStateBuffer, ChainStateEvent,
+};
+
+/// The maximum number of blocks fetched at once by [`BufferedL2Provider::warm`].
+const MAX_CONCURRENT_WARM_FETCHES: usize = 16;
 
 /// A buffered L2 provider that serves data from in-memory chain state.
 ///
@@ -58,6 +64,17 @@ impl BufferedL2Provider {
         }
     }
 
+    /// Set the eviction policy of the underlying buffer.
+    ///
+    /// This replaces the buffer, so it must be called before any blocks are added.
+    pub fn with_eviction_policy(mut self, eviction_policy: BufferEvictionPolicy) -> Self {
+        self.buffer = Arc::new(
+            ChainStateBuffer::new(self.buffer.capacity(), self.buffer.max_reorg_depth())
+                .with_eviction_policy(eviction_policy),
+        );
+        self
+    }
+
     /// Process a chain state event.
     ///
     /// This method should be called when receiving chain state notifications from
@@ -76,6 +93,8 @@ impl BufferedL2Provider {
             ChainStateEvent::ChainCommitted { .. } => "committed",
             ChainStateEvent::ChainReorged { .. } => "reorged",
             ChainStateEvent::ChainReverted { .. } => "reverted",
+            ChainStateEvent::Evicted { .. } => "evicted",
+            ChainStateEvent::Invalidated { .. } => "invalidated",
         };
 
         // Update our tracked head based on the event
@@ -86,6 +105,7 @@ impl BufferedL2Provider {
                 let mut current_head = self.current_head.write().await;
                 *current_head = Some(*new_head);
             }
+            ChainStateEvent::Evicted { .. } | ChainStateEvent::Invalidated { .. } => {}
         }
 
         // Handle the event in the buffer
@@ -130,12 +150,78 @@ impl BufferedL2Provider {
         Ok(())
     }
 
+    /// Prefetch a range of blocks into the buffer.
+    ///
+    /// Every block number in `range` that is not already cached is fetched via `fetch`, with up to
+    /// 16 fetches in flight at once, and inserted into the buffer. Since the buffer cannot hold
+    /// more than its capacity, only the highest `capacity` block numbers of the range are
+    /// warmed; inserting anything below them would just be evicted again by the LRU policy.
+    ///
+    /// Returns the number of blocks that were newly cached by this call. The running total is
+    /// reported through [`CacheStats::warmed_blocks`](crate::CacheStats::warmed_blocks).
+    ///
+    /// # Arguments
+    /// * `range` - The inclusive range of L2 block numbers to warm
+    /// * `fetch` - Fetches the block and its L2 block info for a given block number
+    pub async fn warm<F, Fut, E>(&self, range: RangeInclusive<u64>, fetch: F) -> Result<usize, E>
+    where
+        F: Fn(u64) -> Fut,
+        Fut: Future<Output = Result<(OpBlock, L2BlockInfo), E>>,
+    {
+        let capacity = self.buffer.capacity() as u64;
+        let start = (*range.start()).max(range.end().saturating_sub(capacity.saturating_sub(1)));
+
+        let mut missing = Vec::new();
+        for number in start..=*range.end() {
+            if !self.buffer.contains_number(number).await {
+                missing.push(number);
+            }
+        }
+
+        let fetched: Vec<_> = stream::iter(missing)
+            .map(&fetch)
+            .buffered(MAX_CONCURRENT_WARM_FETCHES)
+            .try_collect()
+            .await?;
+
+        let mut warmed = 0;
+        for (block, l2_block_info) in fetched {
+            if self.buffer.insert_warmed_block(CachedBlock::new(block, l2_block_info)).await {
+                warmed += 1;
+            }
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            use crate::Metrics;
+            metrics::gauge!(Metrics::BLOCKS_ADDED).increment(warmed as f64);
+        }
+
+        Ok(warmed)
+    }
+
+    /// Purge all cached blocks at or above the given height.
+    ///
+    /// This is called on reorgs so that stale blocks are not served for the reorged range.
+    /// Returns the number of purged blocks.
+    ///
+    /// # Arguments
+    /// * `block` - The lowest block number to purge
+    pub async fn invalidate_from(&self, block: u64) -> Result<usize, BufferedProviderError> {
+        self.buffer.invalidate_from(block).await.map_err(BufferedProviderError::Buffer)
+    }
+
     /// Get the current chain head
     pub async fn current_head(&self) -> Option<B256> {
         let current_head = self.current_head.read().await;
         *current_head
     }
 
+    /// Subscribe to events emitted by the underlying buffer, such as block evictions
+    pub fn subscribe(&self) -> broadcast::Receiver<ChainStateEvent> {
+        self.buffer.subscribe()
+    }
+
     /// Get cache statistics
     pub async fn cache_stats(&self) -> crate::buffer::CacheStats {
         self.buffer.cache_stats().await
@@ -279,6 +365,11 @@ impl From<BufferedProviderError> for PipelineErrorKind {
                     "Block not found in cache: {hash}"
                 )))
             }
+            BufferedProviderError::Buffer(ChainBufferError::InconsistentIndex { number, hash }) => {
+                Self::Temporary(PipelineError::Provider(format!(
+                    "Block {number} is indexed as {hash}, which is not cached"
+                )))
+            }
             BufferedProviderError::BlockNotFound(number) => Self::Temporary(
                 PipelineError::Provider(format!("Block {number} not found in cache")),
             ),
@@ -371,6 +462,17 @@ mod tests {
         assert_eq!(stats.blocks_by_number_len, 0);
     }
 
+    #[tokio::test]
+    async fn test_with_eviction_policy() {
+        let provider =
+            create_test_provider().await.with_eviction_policy(BufferEvictionPolicy::Fifo);
+        let stats = provider.cache_stats().await;
+
+        assert_eq!(stats.eviction_policy, BufferEvictionPolicy::Fifo);
+        assert_eq!(stats.capacity, 100);
+        assert_eq!(stats.max_reorg_depth, 10);
+    }
+
     #[tokio::test]
     async fn test_clear_cache() {
         let provider = create_test_provider().await;
@@ -417,4 +519,127 @@ mod tests {
         let retrieved_info = provider.l2_block_info_by_number(1).await.unwrap();
         assert_eq!(retrieved_info.block_info.number, 1);
     }
+
+    fn fetch_test_block(number: u64) -> (OpBlock, L2BlockInfo) {
+        let header = Header {
+            number,
+            parent_hash: B256::with_last_byte(number.saturating_sub(1) as u8),
+            timestamp: 1234567890 + number,
+            ..Default::default()
+        };
+        let l2_info = L2BlockInfo {
+            block_info: BlockInfo {
+                hash: B256::with_last_byte(number as u8),
+                number,
+                parent_hash: header.parent_hash,
+                timestamp: header.timestamp,
+            },
+            l1_origin: BlockNumHash { number: 1, hash: B256::ZERO },
+            seq_num: 0,
+        };
+        (OpBlock { header, body: Default::default() }, l2_info)
+    }
+
+    #[tokio::test]
+    async fn test_warm_range_serves_hits() {
+        let mut provider = create_test_provider().await;
+
+        let warmed = provider
+            .warm(1..=5, |number| async move { Ok::<_, ()>(fetch_test_block(number)) })
+            .await
+            .unwrap();
+        assert_eq!(warmed, 5);
+
+        for number in 1..=5 {
+            let block = provider.block_by_number(number).await.unwrap();
+            assert_eq!(block.header.number, number);
+        }
+
+        // Warming an overlapping range only fetches the blocks that are missing.
+        let warmed = provider
+            .warm(4..=6, |number| async move { Ok::<_, ()>(fetch_test_block(number)) })
+            .await
+            .unwrap();
+        assert_eq!(warmed, 1);
+
+        let stats = provider.cache_stats().await;
+        assert_eq!(stats.blocks_by_number_len, 6);
+        assert_eq!(stats.warmed_blocks, 6);
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_from_refetches_tail() {
+        let mut provider = create_test_provider().await;
+        let fetched = std::sync::Mutex::new(Vec::new());
+        let fetch = |number| {
+            fetched.lock().unwrap().push(number);
+            async move { Ok::<_, ()>(fetch_test_block(number)) }
+        };
+
+        assert_eq!(provider.warm(1..=8, &fetch).await.unwrap(), 8);
+        assert_eq!(provider.invalidate_from(5).await.unwrap(), 4);
+        assert!(provider.block_by_number(4).await.is_ok());
+        assert!(provider.block_by_number(5).await.is_err());
+
+        fetched.lock().unwrap().clear();
+        assert_eq!(provider.warm(1..=8, &fetch).await.unwrap(), 4);
+        assert_eq!(*fetched.lock().unwrap(), vec![5, 6, 7, 8]);
+        assert!(provider.block_by_number(8).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_warm_bounds_concurrent_fetches() {
+        let provider = create_test_provider().await;
+        let in_flight = std::sync::atomic::AtomicUsize::new(0);
+        let max_in_flight = std::sync::atomic::AtomicUsize::new(0);
+
+        let warmed = provider
+            .warm(1..=64, |number| {
+                let in_flight = &in_flight;
+                let max_in_flight = &max_in_flight;
+                async move {
+                    let current = in_flight.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, std::sync::atomic::Ordering::SeqCst);
+                    tokio::task::yield_now().await;
+                    in_flight.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+                    Ok::<_, ()>(fetch_test_block(number))
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(warmed, 64);
+        assert_eq!(max_in_flight.into_inner(), MAX_CONCURRENT_WARM_FETCHES);
+    }
+
+    #[tokio::test]
+    async fn test_warm_respects_capacity() {
+        let mut rollup_config = RollupConfig::default();
+        rollup_config.genesis.system_config = Some(SystemConfig::default());
+        let mut provider = BufferedL2Provider::new(Arc::new(rollup_config), 3, 10);
+
+        let warmed = provider
+            .warm(1..=10, |number| async move { Ok::<_, ()>(fetch_test_block(number)) })
+            .await
+            .unwrap();
+        assert_eq!(warmed, 3);
+
+        assert!(provider.block_by_number(7).await.is_err());
+        for number in 8..=10 {
+            assert!(provider.block_by_number(number).await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_warm_propagates_fetch_error() {
+        let provider = create_test_provider().await;
+
+        let result = provider
+            .warm(1..=3, |number| async move {
+                if number == 2 { Err("unavailable") } else { Ok(fetch_test_block(number)) }
+            })
+            .await;
+        assert_eq!(result, Err("unavailable"));
+        assert_eq!(provider.cache_stats().await.blocks_by_number_len, 0);
+    }
 }
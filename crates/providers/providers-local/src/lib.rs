@@ -7,7 +7,10 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 mod buffer;
-pub use buffer::{CacheStats, CachedBlock, ChainBufferError, ChainStateBuffer, ChainStateEvent};
+pub use buffer::{
+    BufferEvictionPolicy, CacheStats, CachedBlock, ChainBufferError, ChainStateBuffer,
+    ChainStateEvent, EvictionReason,
+};
 
 mod buffered;
 pub use buffered::{BufferedL2Provider, BufferedProviderError};
@@ -32,6 +32,9 @@ impl Metrics {
     /// Identifier for the gauge that tracks cache clears.
     pub const CACHE_CLEARS: &str = "kona_providers_local_cache_clears";
 
+    /// Identifier for the gauge that tracks blocks evicted from the cache.
+    pub const CACHE_EVICTIONS: &str = "kona_providers_local_cache_evictions";
+
     /// Initializes metrics for the local buffered provider.
     ///
     /// This does two things:
@@ -64,6 +67,7 @@ impl Metrics {
         metrics::describe_gauge!(Self::CACHE_CAPACITY, "Total capacity of cache");
         metrics::describe_gauge!(Self::REORG_DEPTH, "Maximum depth of reorganization observed");
         metrics::describe_gauge!(Self::CACHE_CLEARS, "Number of times cache was cleared");
+        metrics::describe_gauge!(Self::CACHE_EVICTIONS, "Number of blocks evicted from cache");
     }
 
     /// Initializes metrics to `0` so they can be queried immediately.
@@ -126,5 +130,6 @@ impl Metrics {
         kona_macros::set!(gauge, Self::CACHE_CAPACITY, 0);
         kona_macros::set!(gauge, Self::REORG_DEPTH, 0);
         kona_macros::set!(gauge, Self::CACHE_CLEARS, 0);
+        kona_macros::set!(gauge, Self::CACHE_EVICTIONS, 0);
     }
 }
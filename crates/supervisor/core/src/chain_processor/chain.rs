@@ -3,18 +3,17 @@ use super::handlers::{
     OriginHandler, ReplacementHandler, SafeBlockHandler, UnsafeBlockHandler,
 };
 use crate::{
-    LogIndexer, ProcessorState,
-    event::ChainEvent,
+    ChainProcessorError, LogIndexer, ProcessorState,
+    event::{ChainEvent, SupervisorEvent, Traced},
     syncnode::{BlockProvider, ManagedNodeCommand},
 };
 use alloy_primitives::ChainId;
 use kona_interop::InteropValidator;
-use kona_supervisor_storage::{
-    DerivationStorage, HeadRefStorageWriter, LogStorage, StorageRewinder,
-};
-use std::{fmt::Debug, sync::Arc};
+use kona_protocol::BlockInfo;
+use kona_supervisor_storage::{DerivationStorage, HeadRefStorage, LogStorage, StorageRewinder};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 use tokio::sync::mpsc;
-use tracing::debug;
+use tracing::{debug, error, warn};
 
 /// Represents a task that processes chain events from a managed node.
 /// It listens for events emitted by the managed node and handles them accordingly.
@@ -22,6 +21,10 @@ use tracing::debug;
 pub struct ChainProcessor<P, W, V> {
     chain_id: ChainId,
     metrics_enabled: Option<bool>,
+    /// Number of times a failed event is handled again before giving up on it.
+    max_retries: usize,
+    /// Notified when the processor gives up on an event.
+    supervisor_event_sender: Option<mpsc::Sender<SupervisorEvent>>,
 
     // state
     state: ProcessorState,
@@ -41,15 +44,18 @@ impl<P, W, V> ChainProcessor<P, W, V>
 where
     P: BlockProvider + 'static,
     V: InteropValidator + 'static,
-    W: LogStorage + DerivationStorage + HeadRefStorageWriter + StorageRewinder + 'static,
+    W: LogStorage + DerivationStorage + HeadRefStorage + StorageRewinder + 'static,
 {
+    /// Delay before handling a failed event again.
+    pub const RETRY_DELAY: Duration = Duration::from_millis(100);
+
     /// Creates a new [`ChainProcessor`].
     pub fn new(
         validator: Arc<V>,
         chain_id: ChainId,
         log_indexer: Arc<LogIndexer<P, W>>,
         db_provider: Arc<W>,
-        managed_node_sender: mpsc::Sender<ManagedNodeCommand>,
+        managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
     ) -> Self {
         let unsafe_handler = UnsafeBlockHandler::new(
             chain_id,
@@ -83,6 +89,8 @@ where
         Self {
             chain_id,
             metrics_enabled: None,
+            max_retries: 0,
+            supervisor_event_sender: None,
 
             state: ProcessorState::new(),
 
@@ -105,9 +113,106 @@ where
         self
     }
 
+    /// Sets how many times an event that failed with a transient error is handled again before
+    /// the processor gives up on it.
+    pub const fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sends a [`SupervisorEvent::ChainProcessorFailed`] to the given channel whenever the
+    /// processor exhausts the retries of an event.
+    pub fn with_supervisor_event_sender(mut self, sender: mpsc::Sender<SupervisorEvent>) -> Self {
+        self.supervisor_event_sender = Some(sender);
+        self
+    }
+
+    /// Restarts processing from a clean [`ProcessorState`], dropping e.g. a pending block
+    /// invalidation left behind by an event that failed.
+    pub fn restart(&mut self) {
+        self.state = ProcessorState::new();
+    }
+
     /// Handles a chain event by delegating it to the appropriate handler.
-    pub async fn handle_event(&mut self, event: ChainEvent) {
-        let result = match event {
+    ///
+    /// An event that failed with a transient error, see [`ChainProcessorError::is_transient`], is
+    /// retried up to the configured number of times. If the last attempt fails as well, the
+    /// failure is reported to the supervisor event channel, if any. Other errors are logged and
+    /// the event is dropped, as handling it again would fail the same way.
+    ///
+    /// Fatal errors, see [`ChainProcessorError::is_fatal`], are not retried but returned, so that
+    /// the caller can halt.
+    pub async fn handle_event(&mut self, event: ChainEvent) -> Result<(), ChainProcessorError> {
+        let mut attempt = 0;
+        let err = loop {
+            let Err(err) = self.dispatch(event.clone()).await else {
+                return Ok(());
+            };
+            if err.is_fatal() {
+                error!(
+                    target: "supervisor::chain_processor",
+                    chain_id = self.chain_id,
+                    %err,
+                    ?event,
+                    "Failed to process event, halting"
+                );
+                return Err(err);
+            }
+            if !err.is_transient() {
+                debug!(
+                    target: "supervisor::chain_processor",
+                    chain_id = self.chain_id,
+                    %err,
+                    ?event,
+                    "Failed to process event"
+                );
+                return Ok(());
+            }
+            if attempt == self.max_retries {
+                break err;
+            }
+            attempt += 1;
+            debug!(
+                target: "supervisor::chain_processor",
+                chain_id = self.chain_id,
+                %err,
+                ?event,
+                attempt,
+                "Failed to process event, retrying"
+            );
+            tokio::time::sleep(Self::RETRY_DELAY).await;
+        };
+
+        warn!(
+            target: "supervisor::chain_processor",
+            chain_id = self.chain_id,
+            %err,
+            ?event,
+            retries = self.max_retries,
+            "Failed to process event, retries exhausted"
+        );
+
+        let Some(sender) = &self.supervisor_event_sender else {
+            return Ok(());
+        };
+        let failure = SupervisorEvent::ChainProcessorFailed {
+            chain_id: self.chain_id,
+            category: err.category(),
+            error: err.to_string(),
+        };
+        if sender.send(failure).await.is_err() {
+            error!(
+                target: "supervisor::chain_processor",
+                chain_id = self.chain_id,
+                "Failed to report chain processor failure, supervisor event channel closed"
+            );
+        }
+        Ok(())
+    }
+
+    /// Delegates the chain event to the appropriate handler.
+    async fn dispatch(&mut self, event: ChainEvent) -> Result<BlockInfo, ChainProcessorError> {
+        match event {
             ChainEvent::UnsafeBlock { block } => {
                 self.unsafe_handler.handle(block, &mut self.state).await
             }
@@ -132,16 +237,6 @@ where
             ChainEvent::CrossSafeUpdate { derived_ref_pair } => {
                 self.cross_safe_handler.handle(derived_ref_pair, &mut self.state).await
             }
-        };
-
-        if let Err(err) = result {
-            debug!(
-                target: "supervisor::chain_processor",
-                chain_id = self.chain_id,
-                %err,
-                ?event,
-                "Failed to process event"
-            );
         }
     }
 }
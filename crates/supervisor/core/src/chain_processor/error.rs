@@ -1,4 +1,8 @@
-use crate::logindexer::LogIndexerError;
+use crate::{
+    logindexer::LogIndexerError,
+    syncnode::{ClientError, ManagedNodeError},
+};
+use kona_interop::ReplacementError;
 use kona_supervisor_storage::StorageError;
 use thiserror::Error;
 
@@ -13,7 +17,62 @@ pub enum ChainProcessorError {
     #[error(transparent)]
     LogIndexerError(#[from] LogIndexerError),
 
+    /// Represents an invalid block replacement received from the managed node.
+    #[error(transparent)]
+    ReplacementError(#[from] ReplacementError),
+
     /// Represents an error that occurred while sending an event to the channel.
     #[error("failed to send event to channel: {0}")]
     ChannelSendFailed(String),
 }
+
+impl ChainProcessorError {
+    /// Returns the [`ChainProcessorErrorCategory`] of the error.
+    pub const fn category(&self) -> ChainProcessorErrorCategory {
+        match self {
+            Self::StorageError(_) => ChainProcessorErrorCategory::Storage,
+            Self::LogIndexerError(_) => ChainProcessorErrorCategory::LogIndexer,
+            Self::ReplacementError(_) => ChainProcessorErrorCategory::Replacement,
+            Self::ChannelSendFailed(_) => ChainProcessorErrorCategory::Channel,
+        }
+    }
+
+    /// Returns whether the error can't be recovered from by handling the event again, e.g.
+    /// because the database is out of space, in which case the supervisor must halt.
+    pub const fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            Self::StorageError(StorageError::DiskFull) |
+                Self::LogIndexerError(LogIndexerError::StateWrite(StorageError::DiskFull))
+        )
+    }
+
+    /// Returns whether the error may go away by handling the event again, i.e. a failed database
+    /// operation, see [`StorageError::is_transient`], or a failed request to the managed node.
+    pub const fn is_transient(&self) -> bool {
+        match self {
+            Self::StorageError(err) |
+            Self::LogIndexerError(
+                LogIndexerError::StateWrite(err) |
+                LogIndexerError::FetchReceipt(ManagedNodeError::StorageError(err)),
+            ) => err.is_transient(),
+            Self::LogIndexerError(LogIndexerError::FetchReceipt(
+                ManagedNodeError::ClientError(ClientError::Client(_)) | ManagedNodeError::Timeout,
+            )) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Coarse category of a [`ChainProcessorError`], e.g. to decide how to recover from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainProcessorErrorCategory {
+    /// The storage layer failed.
+    Storage,
+    /// Indexing the logs of a block failed.
+    LogIndexer,
+    /// The managed node sent an invalid block replacement.
+    Replacement,
+    /// A channel to another component is closed.
+    Channel,
+}
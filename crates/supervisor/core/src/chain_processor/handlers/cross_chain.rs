@@ -1,6 +1,7 @@
 use super::EventHandler;
 use crate::{
-    ChainProcessorError, ProcessorState, chain_processor::Metrics, syncnode::ManagedNodeCommand,
+    ChainProcessorError, ProcessorState, chain_processor::Metrics, event::Traced,
+    syncnode::ManagedNodeCommand,
 };
 use alloy_primitives::ChainId;
 use async_trait::async_trait;
@@ -15,7 +16,7 @@ use tracing::{trace, warn};
 #[derive(Debug, Constructor)]
 pub struct CrossUnsafeHandler {
     chain_id: ChainId,
-    managed_node_sender: mpsc::Sender<ManagedNodeCommand>,
+    managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
 }
 
 #[async_trait]
@@ -42,7 +43,7 @@ impl EventHandler<BlockInfo> for CrossUnsafeHandler {
 impl CrossUnsafeHandler {
     async fn inner_handle(&self, block: BlockInfo) -> Result<BlockInfo, ChainProcessorError> {
         self.managed_node_sender
-            .send(ManagedNodeCommand::UpdateCrossUnsafe { block_id: block.id() })
+            .send(Traced::new(ManagedNodeCommand::UpdateCrossUnsafe { block_id: block.id() }))
             .await
             .map_err(|err| {
                 warn!(
@@ -63,7 +64,7 @@ impl CrossUnsafeHandler {
 #[derive(Debug, Constructor)]
 pub struct CrossSafeHandler {
     chain_id: ChainId,
-    managed_node_sender: mpsc::Sender<ManagedNodeCommand>,
+    managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
 }
 
 #[async_trait]
@@ -92,10 +93,10 @@ impl CrossSafeHandler {
         derived_ref_pair: DerivedRefPair,
     ) -> Result<BlockInfo, ChainProcessorError> {
         self.managed_node_sender
-            .send(ManagedNodeCommand::UpdateCrossSafe {
+            .send(Traced::new(ManagedNodeCommand::UpdateCrossSafe {
                 source_block_id: derived_ref_pair.source.id(),
                 derived_block_id: derived_ref_pair.derived.id(),
-            })
+            }))
             .await
             .map_err(|err| {
                 warn!(
@@ -115,7 +116,7 @@ impl CrossSafeHandler {
 mod tests {
     use super::*;
     use crate::syncnode::{
-        BlockProvider, ManagedNodeController, ManagedNodeDataProvider, ManagedNodeError,
+        BlockProvider, ManagedNodeController, ManagedNodeDataProvider, ManagedNodeError, ResetCause,
     };
     use alloy_primitives::B256;
     use alloy_rpc_types_eth::BlockNumHash;
@@ -171,9 +172,10 @@ mod tests {
                 derived_block_id: BlockNumHash,
             ) -> Result<(), ManagedNodeError>;
 
-            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
 
             async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+            async fn fetch_block_receipts(&self, block_id: BlockNumHash) -> Result<Receipts, ManagedNodeError>;
         }
     );
 
@@ -194,7 +196,9 @@ mod tests {
         assert!(result.is_ok());
 
         // The handler should send the correct command
-        if let Some(ManagedNodeCommand::UpdateCrossUnsafe { block_id }) = rx.recv().await {
+        if let Some(ManagedNodeCommand::UpdateCrossUnsafe { block_id }) =
+            rx.recv().await.map(Traced::into_inner)
+        {
             assert_eq!(block_id, block.id());
         } else {
             panic!("Expected UpdateCrossUnsafe command");
@@ -240,7 +244,7 @@ mod tests {
 
         // The handler should send the correct command
         if let Some(ManagedNodeCommand::UpdateCrossSafe { source_block_id, derived_block_id }) =
-            rx.recv().await
+            rx.recv().await.map(Traced::into_inner)
         {
             assert_eq!(source_block_id, source.id());
             assert_eq!(derived_block_id, derived.id());
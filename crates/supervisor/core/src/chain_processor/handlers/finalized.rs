@@ -1,6 +1,7 @@
 use super::EventHandler;
 use crate::{
-    ChainProcessorError, ProcessorState, chain_processor::Metrics, syncnode::ManagedNodeCommand,
+    ChainProcessorError, ProcessorState, chain_processor::Metrics, event::Traced,
+    syncnode::ManagedNodeCommand,
 };
 use alloy_primitives::ChainId;
 use async_trait::async_trait;
@@ -16,7 +17,7 @@ use tracing::{trace, warn};
 #[derive(Debug, Constructor)]
 pub struct FinalizedHandler<W> {
     chain_id: ChainId,
-    managed_node_sender: mpsc::Sender<ManagedNodeCommand>,
+    managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
     db_provider: Arc<W>,
 }
 
@@ -66,7 +67,9 @@ where
             })?;
 
         self.managed_node_sender
-            .send(ManagedNodeCommand::UpdateFinalized { block_id: finalized_derived_block.id() })
+            .send(Traced::new(ManagedNodeCommand::UpdateFinalized {
+                block_id: finalized_derived_block.id(),
+            }))
             .await
             .map_err(|err| {
                 warn!(
@@ -86,7 +89,7 @@ where
 mod tests {
     use super::*;
     use crate::syncnode::{
-        BlockProvider, ManagedNodeController, ManagedNodeDataProvider, ManagedNodeError,
+        BlockProvider, ManagedNodeController, ManagedNodeDataProvider, ManagedNodeError, ResetCause,
     };
     use alloy_primitives::B256;
     use alloy_rpc_types_eth::BlockNumHash;
@@ -143,9 +146,10 @@ mod tests {
                 derived_block_id: BlockNumHash,
             ) -> Result<(), ManagedNodeError>;
 
-            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
 
             async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+            async fn fetch_block_receipts(&self, block_id: BlockNumHash) -> Result<Receipts, ManagedNodeError>;
         }
     );
 
@@ -212,7 +216,9 @@ mod tests {
         assert!(result.is_ok());
 
         // The handler should send the correct command
-        if let Some(ManagedNodeCommand::UpdateFinalized { block_id }) = rx.recv().await {
+        if let Some(ManagedNodeCommand::UpdateFinalized { block_id }) =
+            rx.recv().await.map(Traced::into_inner)
+        {
             assert_eq!(block_id, finalized_derived_block.id());
         } else {
             panic!("Expected UpdateFinalized command");
@@ -2,15 +2,18 @@ use super::EventHandler;
 use crate::{
     ChainProcessorError, LogIndexer, ProcessorState,
     chain_processor::metrics::Metrics,
+    event::Traced,
     syncnode::{BlockProvider, ManagedNodeCommand},
 };
 use alloy_primitives::ChainId;
 use async_trait::async_trait;
 use derive_more::Constructor;
-use kona_interop::{BlockReplacement, DerivedRefPair};
+use kona_interop::{BlockReplacement, DerivedRefPair, SafetyLevel};
 use kona_protocol::BlockInfo;
 use kona_supervisor_metrics::observe_metrics_for_result_async;
-use kona_supervisor_storage::{DerivationStorage, LogStorage, StorageRewinder};
+use kona_supervisor_storage::{
+    DerivationStorage, HeadRefStorageReader, LogStorage, StorageError, StorageRewinder,
+};
 use kona_supervisor_types::BlockSeal;
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -21,7 +24,7 @@ use tracing::{debug, error, trace, warn};
 #[derive(Debug, Constructor)]
 pub struct InvalidationHandler<W> {
     chain_id: ChainId,
-    managed_node_sender: mpsc::Sender<ManagedNodeCommand>,
+    managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
     db_provider: Arc<W>,
 }
 
@@ -96,7 +99,7 @@ where
 
         let block_seal = BlockSeal::new(block.hash, block.number, block.timestamp);
         self.managed_node_sender
-            .send(ManagedNodeCommand::InvalidateBlock { seal: block_seal })
+            .send(Traced::new(ManagedNodeCommand::InvalidateBlock { seal: block_seal }))
             .await
             .map_err(|err| {
                 warn!(
@@ -127,7 +130,7 @@ pub struct ReplacementHandler<P, W> {
 impl<P, W> EventHandler<BlockReplacement> for ReplacementHandler<P, W>
 where
     P: BlockProvider + 'static,
-    W: LogStorage + DerivationStorage + 'static,
+    W: LogStorage + DerivationStorage + HeadRefStorageReader + 'static,
 {
     async fn handle(
         &self,
@@ -150,7 +153,7 @@ where
 impl<P, W> ReplacementHandler<P, W>
 where
     P: BlockProvider + 'static,
-    W: LogStorage + DerivationStorage + 'static,
+    W: LogStorage + DerivationStorage + HeadRefStorageReader + 'static,
 {
     async fn inner_handle(
         &self,
@@ -188,6 +191,8 @@ where
             return Ok(replacement.replacement);
         }
 
+        self.validate_depth(&replacement)?;
+
         let derived_ref_pair = DerivedRefPair {
             source: invalidated_ref_pair.source,
             derived: replacement.replacement,
@@ -198,6 +203,35 @@ where
         Ok(replacement.replacement)
     }
 
+    /// Rejects replacements reaching at or below the finalized block.
+    fn validate_depth(&self, replacement: &BlockReplacement) -> Result<(), ChainProcessorError> {
+        let finalized = match self.db_provider.get_safety_head_ref(SafetyLevel::Finalized) {
+            Ok(block) => block,
+            // nothing has been finalized yet
+            Err(StorageError::FutureData) => return Ok(()),
+            Err(err) => {
+                error!(
+                    target: "supervisor::chain_processor::db",
+                    chain_id = self.chain_id,
+                    %err,
+                    "Failed to get finalized block"
+                );
+                return Err(err.into());
+            }
+        };
+
+        replacement.validate_depth(finalized).inspect_err(|err| {
+            warn!(
+                target: "supervisor::chain_processor",
+                chain_id = self.chain_id,
+                %replacement,
+                %err,
+                "Rejecting block replacement"
+            );
+        })?;
+        Ok(())
+    }
+
     async fn retry_with_resync_derived_block(
         &self,
         derived_ref_pair: DerivedRefPair,
@@ -238,18 +272,18 @@ where
 mod tests {
     use super::*;
     use crate::syncnode::{
-        BlockProvider, ManagedNodeController, ManagedNodeDataProvider, ManagedNodeError,
+        BlockProvider, ManagedNodeController, ManagedNodeDataProvider, ManagedNodeError, ResetCause,
     };
     use alloy_primitives::B256;
     use alloy_rpc_types_eth::BlockNumHash;
     use async_trait::async_trait;
-    use kona_interop::DerivedRefPair;
+    use kona_interop::{DerivedRefPair, ReplacementError};
     use kona_protocol::BlockInfo;
     use kona_supervisor_storage::{
         DerivationStorageReader, DerivationStorageWriter, LogStorageReader, LogStorageWriter,
         StorageError,
     };
-    use kona_supervisor_types::{BlockSeal, Log, OutputV0, Receipts};
+    use kona_supervisor_types::{BlockSeal, Log, OutputV0, Receipts, SuperHead};
     use mockall::mock;
 
     mock!(
@@ -298,9 +332,10 @@ mod tests {
                 derived_block_id: BlockNumHash,
             ) -> Result<(), ManagedNodeError>;
 
-            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
 
             async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+            async fn fetch_block_receipts(&self, block_id: BlockNumHash) -> Result<Receipts, ManagedNodeError>;
         }
     );
 
@@ -353,6 +388,11 @@ mod tests {
             ) -> Result<(), StorageError>;
         }
 
+        impl HeadRefStorageReader for Db {
+            fn get_safety_head_ref(&self, safety_level: SafetyLevel) -> Result<BlockInfo, StorageError>;
+            fn get_super_head(&self) -> Result<SuperHead, StorageError>;
+        }
+
         impl StorageRewinder for Db {
             fn rewind_log_storage(&self, to: &BlockNumHash) -> Result<(), StorageError>;
             fn rewind(&self, to: &BlockNumHash) -> Result<(), StorageError>;
@@ -501,7 +541,9 @@ mod tests {
         assert_eq!(pair.derived, derived_block);
         assert_eq!(pair.source, source_block);
 
-        if let Some(ManagedNodeCommand::InvalidateBlock { seal }) = rx.recv().await {
+        if let Some(ManagedNodeCommand::InvalidateBlock { seal }) =
+            rx.recv().await.map(Traced::into_inner)
+        {
             assert_eq!(seal.hash, derived_block_clone.hash);
             assert_eq!(seal.number, derived_block_clone.number);
             assert_eq!(seal.timestamp, derived_block_clone.timestamp);
@@ -582,6 +624,10 @@ mod tests {
         let invalidated_block = BlockInfo::new(B256::from([1u8; 32]), 42, B256::ZERO, 12345);
         let replacement_block = BlockInfo::new(B256::from([2u8; 32]), 42, B256::ZERO, 12346);
 
+        mockdb.expect_get_safety_head_ref().returning(move |level| {
+            assert_eq!(level, SafetyLevel::Finalized);
+            Ok(BlockInfo::new(B256::from([3u8; 32]), 41, B256::ZERO, 12344))
+        });
         mockdb.expect_save_derived_block().returning(move |_pair| Ok(()));
         mockdb.expect_store_block_logs().returning(move |_block, _logs| Ok(()));
 
@@ -617,4 +663,54 @@ mod tests {
         // invalidated_block should be cleared
         assert!(state.get_invalidated().is_none());
     }
+
+    #[tokio::test]
+    async fn test_handle_block_replacement_below_finalized() {
+        let mut mockdb = MockDb::new();
+        let mocknode = MockNode::new();
+        let mut state = ProcessorState::new();
+
+        let source_block = BlockInfo::new(B256::from([1u8; 32]), 45, B256::ZERO, 12345);
+        let invalidated_block = BlockInfo::new(B256::from([1u8; 32]), 42, B256::ZERO, 12345);
+        let replacement_block = BlockInfo::new(B256::from([2u8; 32]), 42, B256::ZERO, 12346);
+
+        mockdb.expect_get_safety_head_ref().returning(move |_level| {
+            Ok(BlockInfo::new(B256::from([3u8; 32]), 42, B256::ZERO, 12345))
+        });
+        mockdb.expect_save_derived_block().never();
+        mockdb.expect_store_block_logs().never();
+
+        let writer = Arc::new(mockdb);
+        let managed_node = Arc::new(mocknode);
+        // Create a mock log indexer
+        let log_indexer = Arc::new(LogIndexer::new(1, Some(managed_node.clone()), writer.clone()));
+
+        state.set_invalidated(DerivedRefPair { source: source_block, derived: invalidated_block });
+
+        let handler = ReplacementHandler::new(
+            1, // chain_id
+            log_indexer,
+            writer,
+        );
+
+        let result = handler
+            .handle(
+                BlockReplacement {
+                    invalidated: invalidated_block.hash,
+                    replacement: replacement_block,
+                },
+                &mut state,
+            )
+            .await;
+        assert_eq!(
+            result,
+            Err(ChainProcessorError::ReplacementError(ReplacementError::BelowFinalized {
+                replacement: 42,
+                finalized: 42,
+            }))
+        );
+
+        // invalidated_block should remain set
+        assert!(state.get_invalidated().is_some());
+    }
 }
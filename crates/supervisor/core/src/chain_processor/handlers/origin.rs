@@ -1,5 +1,9 @@
 use super::EventHandler;
-use crate::{ChainProcessorError, ProcessorState, syncnode::ManagedNodeCommand};
+use crate::{
+    ChainProcessorError, ProcessorState,
+    event::Traced,
+    syncnode::{ManagedNodeCommand, ResetCause},
+};
 use alloy_primitives::ChainId;
 use async_trait::async_trait;
 use derive_more::Constructor;
@@ -13,7 +17,7 @@ use tracing::{debug, error, trace, warn};
 #[derive(Debug, Constructor)]
 pub struct OriginHandler<W> {
     chain_id: ChainId,
-    managed_node_sender: mpsc::Sender<ManagedNodeCommand>,
+    managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
     db_provider: Arc<W>,
 }
 
@@ -54,8 +58,12 @@ where
                     "Block out of order detected, resetting managed node"
                 );
 
-                self.managed_node_sender.send(ManagedNodeCommand::Reset {}).await.map_err(
-                    |err| {
+                self.managed_node_sender
+                    .send(Traced::new(ManagedNodeCommand::Reset {
+                        cause: ResetCause::SourceBlockOutOfOrder,
+                    }))
+                    .await
+                    .map_err(|err| {
                         warn!(
                             target: "supervisor::chain_processor::managed_node",
                             chain_id = self.chain_id,
@@ -64,8 +72,7 @@ where
                             "Failed to send reset command to managed node"
                         );
                         ChainProcessorError::ChannelSendFailed(err.to_string())
-                    },
-                )?;
+                    })?;
                 Ok(origin)
             }
             Err(err) => {
@@ -143,9 +150,10 @@ mod tests {
                 derived_block_id: BlockNumHash,
             ) -> Result<(), ManagedNodeError>;
 
-            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
 
             async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+            async fn fetch_block_receipts(&self, block_id: BlockNumHash) -> Result<Receipts, ManagedNodeError>;
         }
     );
 
@@ -219,7 +227,9 @@ mod tests {
         assert!(result.is_ok());
 
         // The handler should send the reset command
-        if let Some(ManagedNodeCommand::Reset {}) = rx.recv().await {
+        if let Some(ManagedNodeCommand::Reset { cause: ResetCause::SourceBlockOutOfOrder }) =
+            rx.recv().await.map(Traced::into_inner)
+        {
             // Command received successfully
         } else {
             panic!("Expected Reset command");
@@ -2,7 +2,8 @@ use super::EventHandler;
 use crate::{
     ChainProcessorError, LogIndexer, ProcessorState,
     chain_processor::Metrics,
-    syncnode::{BlockProvider, ManagedNodeCommand},
+    event::Traced,
+    syncnode::{BlockProvider, ManagedNodeCommand, ResetCause},
 };
 use alloy_primitives::ChainId;
 use async_trait::async_trait;
@@ -18,7 +19,7 @@ use tracing::{debug, error, info, trace, warn};
 #[derive(Debug, Constructor)]
 pub struct SafeBlockHandler<P, W, V> {
     chain_id: ChainId,
-    managed_node_sender: mpsc::Sender<ManagedNodeCommand>,
+    managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
     db_provider: Arc<W>,
     validator: Arc<V>,
     log_indexer: Arc<LogIndexer<P, W>>,
@@ -120,8 +121,12 @@ where
                     "Block out of order detected, resetting managed node"
                 );
 
-                self.managed_node_sender.send(ManagedNodeCommand::Reset {}).await.map_err(
-                    |err| {
+                self.managed_node_sender
+                    .send(Traced::new(ManagedNodeCommand::Reset {
+                        cause: ResetCause::DerivedBlockOutOfOrder,
+                    }))
+                    .await
+                    .map_err(|err| {
                         warn!(
                             target: "supervisor::chain_processor::managed_node",
                             chain_id = self.chain_id,
@@ -129,8 +134,7 @@ where
                             "Failed to send reset command to managed node"
                         );
                         ChainProcessorError::ChannelSendFailed(err.to_string())
-                    },
-                )?;
+                    })?;
                 Ok(())
             }
             Err(StorageError::ReorgRequired) => {
@@ -302,9 +306,10 @@ mod tests {
                 derived_block_id: BlockNumHash,
             ) -> Result<(), ManagedNodeError>;
 
-            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
 
             async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+            async fn fetch_block_receipts(&self, block_id: BlockNumHash) -> Result<Receipts, ManagedNodeError>;
         }
     );
 
@@ -631,7 +636,7 @@ mod tests {
             .returning(move |_pair: DerivedRefPair| Err(StorageError::BlockOutOfOrder));
 
         // Expect reset to be called
-        mocknode.expect_reset().returning(|| Ok(()));
+        mocknode.expect_reset().returning(|_| Ok(()));
 
         let writer = Arc::new(mockdb);
         let managed_node = Arc::new(mocknode);
@@ -649,8 +654,11 @@ mod tests {
         assert!(result.is_ok());
 
         // Ensure reset command was sent
-        if let Some(cmd) = rx.recv().await {
-            assert!(matches!(cmd, ManagedNodeCommand::Reset {}));
+        if let Some(cmd) = rx.recv().await.map(Traced::into_inner) {
+            assert!(matches!(
+                cmd,
+                ManagedNodeCommand::Reset { cause: ResetCause::DerivedBlockOutOfOrder }
+            ));
         } else {
             panic!("Expected reset command to be sent");
         }
@@ -3,7 +3,7 @@
 //! chain. It provides a structured way to handle tasks, manage chains, and process blocks
 //! in a supervisor environment.
 mod error;
-pub use error::ChainProcessorError;
+pub use error::{ChainProcessorError, ChainProcessorErrorCategory};
 
 mod chain;
 pub use chain::ChainProcessor;
@@ -2,9 +2,11 @@ use super::RollupConfigSet;
 use crate::syncnode::ClientConfig;
 use alloy_primitives::ChainId;
 use derive_more::Constructor;
-use kona_interop::{DependencySet, InteropValidationError, InteropValidator};
+use kona_interop::{DependencySet, InteropValidationError, InteropValidator, SafetyLevel};
 use kona_protocol::BlockInfo;
-use std::{net::SocketAddr, path::PathBuf};
+use std::{collections::HashMap, net::SocketAddr, path::PathBuf};
+use thiserror::Error;
+use tracing::warn;
 
 /// Configuration for the Supervisor service.
 #[derive(Debug, Clone, Constructor)]
@@ -21,6 +23,13 @@ pub struct Config {
     /// The socket address for the RPC server to listen on.
     pub rpc_addr: SocketAddr,
 
+    /// Maximum size of an RPC request body, in bytes. Larger requests are rejected unread.
+    pub rpc_max_request_body_size: u32,
+
+    /// Maximum number of calls in an RPC batch request. Larger batches are rejected before any
+    /// call is deserialized.
+    pub rpc_max_batch_len: u32,
+
     /// Whether to enable the Supervisor Admin API.
     pub enable_admin_api: bool,
 
@@ -29,6 +38,103 @@ pub struct Config {
 
     /// The rollup configuration set.
     pub rollup_config_set: RollupConfigSet,
+
+    /// Capacity of the event channel of each chain. Chains not listed use
+    /// [`Self::DEFAULT_EVENT_CHANNEL_CAPACITY`].
+    pub event_channel_capacities: HashMap<ChainId, usize>,
+
+    /// The URL of a supervisor used to validate message dependencies that are missing from local
+    /// storage, e.g. while a chain is backfilling.
+    pub dependency_fallback_rpc: Option<String>,
+
+    /// Maximum number of blocks whose logs are processed in parallel while a chain's log indexer
+    /// catches up.
+    pub log_indexer_concurrency: usize,
+
+    /// Safety levels the supervisor doesn't track. Their promotion is skipped and the RPC reports
+    /// them as unsupported.
+    pub disabled_safety_levels: Vec<SafetyLevel>,
+
+    /// Whether to record the latency of every database table read and write.
+    pub storage_table_metrics: bool,
+}
+
+/// Error returned when the disabled safety levels of a [`Config`] are inconsistent.
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq)]
+#[error("safety level {disabled} can't be disabled while {dependent} is enabled")]
+pub struct SafetyLevelConfigError {
+    /// The disabled safety level.
+    pub disabled: SafetyLevel,
+    /// The enabled safety level that depends on it.
+    pub dependent: SafetyLevel,
+}
+
+impl Config {
+    /// Default capacity of a chain's event channel.
+    pub const DEFAULT_EVENT_CHANNEL_CAPACITY: usize = 1000;
+
+    /// Largest capacity allowed for a chain's event channel.
+    pub const MAX_EVENT_CHANNEL_CAPACITY: usize = 100_000;
+
+    /// Default maximum size of an RPC request body, 10 MiB.
+    pub const DEFAULT_RPC_MAX_REQUEST_BODY_SIZE: u32 = 10 * 1024 * 1024;
+
+    /// Default maximum number of calls in an RPC batch request.
+    pub const DEFAULT_RPC_MAX_BATCH_LEN: u32 = 1000;
+
+    /// Returns the capacity of the event channel for the given chain.
+    ///
+    /// Configured capacities outside `1..=MAX_EVENT_CHANNEL_CAPACITY` are clamped to that range.
+    pub fn event_channel_capacity(&self, chain_id: ChainId) -> usize {
+        let Some(&capacity) = self.event_channel_capacities.get(&chain_id) else {
+            return Self::DEFAULT_EVENT_CHANNEL_CAPACITY;
+        };
+
+        let clamped = capacity.clamp(1, Self::MAX_EVENT_CHANNEL_CAPACITY);
+        if clamped != capacity {
+            warn!(
+                target: "supervisor::config",
+                chain_id,
+                capacity,
+                clamped,
+                "Event channel capacity out of range, clamping"
+            );
+        }
+        clamped
+    }
+
+    /// Returns `true` unless the given safety level is disabled.
+    pub fn is_safety_level_enabled(&self, level: SafetyLevel) -> bool {
+        !self.disabled_safety_levels.contains(&level)
+    }
+
+    /// Checks that no enabled safety level depends on a disabled one.
+    pub fn validate_safety_levels(&self) -> Result<(), SafetyLevelConfigError> {
+        for dependent in [
+            SafetyLevel::CrossUnsafe,
+            SafetyLevel::LocalSafe,
+            SafetyLevel::CrossSafe,
+            SafetyLevel::Finalized,
+        ] {
+            if !self.is_safety_level_enabled(dependent) {
+                continue;
+            }
+            let disabled = required_safety_level(dependent);
+            if !self.is_safety_level_enabled(disabled) {
+                return Err(SafetyLevelConfigError { disabled, dependent });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the safety level blocks must reach before they can be promoted to `level`.
+const fn required_safety_level(level: SafetyLevel) -> SafetyLevel {
+    match level {
+        SafetyLevel::Finalized => SafetyLevel::CrossSafe,
+        SafetyLevel::CrossSafe => SafetyLevel::LocalSafe,
+        _ => SafetyLevel::LocalUnsafe,
+    }
 }
 
 impl InteropValidator for Config {
@@ -101,15 +207,63 @@ mod tests {
             l2_consensus_nodes_config: vec![],
             datadir: PathBuf::new(),
             rpc_addr: SocketAddr::from(([127, 0, 0, 1], 8545)),
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
             enable_admin_api: false,
             dependency_set: DependencySet {
                 dependencies: Default::default(),
                 override_message_expiry_window: Some(10),
             },
             rollup_config_set: mock_rollup_config_set(),
+            event_channel_capacities: HashMap::from([(1, 64), (2, usize::MAX), (3, 0)]),
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_table_metrics: false,
         }
     }
 
+    #[test]
+    fn test_event_channel_capacity() {
+        let cfg = mock_config();
+        assert_eq!(cfg.event_channel_capacity(1), 64);
+        assert_eq!(cfg.event_channel_capacity(2), Config::MAX_EVENT_CHANNEL_CAPACITY);
+        assert_eq!(cfg.event_channel_capacity(3), 1);
+        assert_eq!(cfg.event_channel_capacity(4), Config::DEFAULT_EVENT_CHANNEL_CAPACITY);
+    }
+
+    #[test]
+    fn test_disable_cross_unsafe() {
+        let cfg =
+            Config { disabled_safety_levels: vec![SafetyLevel::CrossUnsafe], ..mock_config() };
+        assert_eq!(cfg.validate_safety_levels(), Ok(()));
+        assert!(!cfg.is_safety_level_enabled(SafetyLevel::CrossUnsafe));
+        assert!(cfg.is_safety_level_enabled(SafetyLevel::CrossSafe));
+    }
+
+    #[test]
+    fn test_disable_level_required_by_enabled_level() {
+        let cfg = Config { disabled_safety_levels: vec![SafetyLevel::LocalSafe], ..mock_config() };
+        assert_eq!(
+            cfg.validate_safety_levels(),
+            Err(SafetyLevelConfigError {
+                disabled: SafetyLevel::LocalSafe,
+                dependent: SafetyLevel::CrossSafe,
+            })
+        );
+
+        // disabling the dependent levels too is fine
+        let cfg = Config {
+            disabled_safety_levels: vec![
+                SafetyLevel::LocalSafe,
+                SafetyLevel::CrossSafe,
+                SafetyLevel::Finalized,
+            ],
+            ..mock_config()
+        };
+        assert_eq!(cfg.validate_safety_levels(), Ok(()));
+    }
+
     #[test]
     fn test_valid_case() {
         let cfg = mock_config();
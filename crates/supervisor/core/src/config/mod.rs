@@ -4,4 +4,4 @@ mod rollup_config_set;
 pub use rollup_config_set::{Genesis, RollupConfig, RollupConfigSet};
 
 mod core_config;
-pub use core_config::Config;
+pub use core_config::{Config, SafetyLevelConfigError};
@@ -119,6 +119,21 @@ impl RollupConfigSet {
         Self { rollups }
     }
 
+    /// Returns the configured chain IDs in ascending order.
+    ///
+    /// The supervisor initialises chains in this order, so that startup is the same across runs
+    /// with the same configuration. It doesn't order the per-chain tasks spawned afterwards.
+    pub fn chain_ids(&self) -> Vec<ChainId> {
+        let mut chain_ids: Vec<_> = self.rollups.keys().copied().collect();
+        chain_ids.sort_unstable();
+        chain_ids
+    }
+
+    /// Returns the rollup configurations in ascending chain ID order.
+    pub fn iter(&self) -> impl Iterator<Item = (ChainId, &RollupConfig)> {
+        self.chain_ids().into_iter().map(|chain_id| (chain_id, &self.rollups[&chain_id]))
+    }
+
     /// Returns the rollup configuration for the given chain id.
     pub fn get(&self, chain_id: u64) -> Option<&RollupConfig> {
         self.rollups.get(&chain_id)
@@ -179,6 +194,24 @@ mod tests {
         assert!(!set.is_post_interop(ChainId::from(999u64), 200));
     }
 
+    #[test]
+    fn test_chain_ids_are_ordered() {
+        let chain_ids: [ChainId; 5] = [901, 10, 8453, 1, 420];
+
+        for run in 0..chain_ids.len() {
+            let mut set = RollupConfigSet::default();
+            // insert in a different order on every run
+            for offset in 0..chain_ids.len() {
+                let chain_id = chain_ids[(run + offset) % chain_ids.len()];
+                set.rollups
+                    .insert(chain_id, RollupConfig { block_time: chain_id, ..Default::default() });
+            }
+
+            assert_eq!(set.chain_ids(), vec![1, 10, 420, 901, 8453]);
+            assert!(set.iter().all(|(chain_id, config)| config.block_time == chain_id));
+        }
+    }
+
     #[test]
     fn test_rollup_config_is_interop_interop_time_zero() {
         // Interop time is 100, block_time is 10
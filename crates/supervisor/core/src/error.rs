@@ -3,6 +3,7 @@
 use crate::syncnode::ManagedNodeError;
 use derive_more;
 use jsonrpsee::types::{ErrorCode, ErrorObjectOwned};
+use kona_interop::{InteropValidationError, SafetyLevel};
 use kona_supervisor_storage::StorageError;
 use kona_supervisor_types::AccessListError;
 use op_alloy_rpc_types::SuperchainDAError;
@@ -61,6 +62,10 @@ pub enum SupervisorError {
     /// Indicates that the chain ID could not be parsed from the access list.
     #[error("failed to parse chain id from access list")]
     ChainIdParseError(),
+
+    /// The requested safety level is disabled in the supervisor config.
+    #[error("unsupported safety level: {0}")]
+    SafetyLevelDisabled(SafetyLevel),
 }
 
 impl PartialEq for SupervisorError {
@@ -78,6 +83,7 @@ impl PartialEq for SupervisorError {
             (L1BlockMismatch { expected: a, got: b }, L1BlockMismatch { expected: c, got: d }) => {
                 a == c && b == d
             }
+            (SafetyLevelDisabled(a), SafetyLevelDisabled(b)) => a == b,
             _ => false,
         }
     }
@@ -86,7 +92,10 @@ impl PartialEq for SupervisorError {
 impl Eq for SupervisorError {}
 
 /// Extending the [`SuperchainDAError`] to include errors not in the spec.
-#[derive(Error, Debug, PartialEq, Eq, derive_more::TryFrom)]
+///
+/// The access list validation failures tell apart why a message was rejected in their message,
+/// but keep the error code the spec assigns to them on the wire.
+#[derive(Error, Debug, Clone, PartialEq, Eq, derive_more::TryFrom)]
 #[repr(i32)]
 #[try_from(repr)]
 pub enum SpecError {
@@ -97,6 +106,27 @@ pub enum SpecError {
     /// Error not in spec.
     #[error("error not in spec")]
     ErrorNotInSpec,
+
+    /// The initiating message expired before the time of execution.
+    #[error("message expired")]
+    MessageExpired,
+
+    /// The initiating chain is not part of the dependency set.
+    #[error("unknown chain")]
+    UnknownChain,
+
+    /// The initiating message is not as safe as required.
+    #[error("insufficient safety, required: {required}, actual: {actual}")]
+    InsufficientSafety {
+        /// Safety level required by the request.
+        required: SafetyLevel,
+        /// Highest safety level reached by the initiating message.
+        actual: SafetyLevel,
+    },
+
+    /// The initiating message is not indexed.
+    #[error("initiating message not found")]
+    InitiatingMessageNotFound,
 }
 
 impl SpecError {
@@ -105,7 +135,23 @@ impl SpecError {
     pub const fn code(&self) -> i32 {
         match self {
             Self::SuperchainDAError(e) => *e as i32,
-            Self::ErrorNotInSpec => -321300,
+            Self::ErrorNotInSpec | Self::UnknownChain => -321300,
+            Self::MessageExpired | Self::InsufficientSafety { .. } => {
+                SuperchainDAError::ConflictingData as i32
+            }
+            Self::InitiatingMessageNotFound => SuperchainDAError::MissedData as i32,
+        }
+    }
+}
+
+impl From<InteropValidationError> for SpecError {
+    fn from(err: InteropValidationError) -> Self {
+        match err {
+            InteropValidationError::InvalidInteropTimestamp(_) => Self::MessageExpired,
+            InteropValidationError::InteropNotEnabled |
+            InteropValidationError::InvalidTimestampInvariant { .. } => {
+                Self::from(SuperchainDAError::ConflictingData)
+            }
         }
     }
 }
@@ -131,6 +177,11 @@ impl From<SupervisorError> for ErrorObjectOwned {
             SupervisorError::ChainIdParseError() |
             SupervisorError::SerdeJson(_) => ErrorObjectOwned::from(ErrorCode::InternalError),
             SupervisorError::SpecError(err) => err.into(),
+            err @ SupervisorError::SafetyLevelDisabled(_) => ErrorObjectOwned::owned(
+                ErrorCode::InvalidParams.code(),
+                err.to_string(),
+                None::<()>,
+            ),
         }
     }
 }
@@ -188,6 +239,41 @@ mod test {
         assert_eq!(spec_err, expected_err.into());
     }
 
+    #[test]
+    fn test_validation_errors_keep_spec_codes() {
+        let cases = [
+            (SpecError::MessageExpired, SuperchainDAError::ConflictingData as i32),
+            (SpecError::UnknownChain, -321300),
+            (
+                SpecError::InsufficientSafety {
+                    required: SafetyLevel::CrossSafe,
+                    actual: SafetyLevel::LocalUnsafe,
+                },
+                SuperchainDAError::ConflictingData as i32,
+            ),
+            (SpecError::InitiatingMessageNotFound, SuperchainDAError::MissedData as i32),
+        ];
+
+        for (err, code) in cases {
+            let message = err.to_string();
+            let rpc_err = ErrorObjectOwned::from(SupervisorError::SpecError(err));
+            assert_eq!(rpc_err.code(), code);
+            assert_eq!(rpc_err.message(), message);
+        }
+    }
+
+    #[test]
+    fn test_interop_validation_error_conversion() {
+        assert_eq!(
+            SpecError::from(InteropValidationError::InvalidInteropTimestamp(10)),
+            SpecError::MessageExpired
+        );
+        assert_eq!(
+            SpecError::from(InteropValidationError::InteropNotEnabled),
+            SpecError::SuperchainDAError(SuperchainDAError::ConflictingData)
+        );
+    }
+
     #[test]
     fn test_supervisor_error_conversion() {
         // This will happen implicitly in server rpc response calls.
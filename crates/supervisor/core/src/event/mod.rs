@@ -2,3 +2,9 @@
 
 mod chain;
 pub use chain::ChainEvent;
+
+mod supervisor;
+pub use supervisor::SupervisorEvent;
+
+mod traced;
+pub use traced::Traced;
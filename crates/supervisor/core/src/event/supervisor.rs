@@ -0,0 +1,20 @@
+use crate::ChainProcessorErrorCategory;
+use alloy_primitives::ChainId;
+
+/// Represents events about the health of the supervisor's components.
+///
+/// Unlike [`ChainEvent`](super::ChainEvent)s, these are not processed per chain, but observed by
+/// the service, which decides how to recover.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SupervisorEvent {
+    /// The [`ChainProcessor`](crate::ChainProcessor) of a chain failed to handle an event, even
+    /// after exhausting its retries.
+    ChainProcessorFailed {
+        /// The chain whose processor failed.
+        chain_id: ChainId,
+        /// The category of the last error.
+        category: ChainProcessorErrorCategory,
+        /// The last error, rendered as a message.
+        error: String,
+    },
+}
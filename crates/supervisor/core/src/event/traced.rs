@@ -0,0 +1,78 @@
+use tracing::Span;
+
+/// A message sent across an actor channel, along with the [`Span`] it was sent from.
+///
+/// The consuming actor handles the message in a child of the carried span, so logs on both sides
+/// of the channel correlate. When tracing is disabled, the carried span is [`Span::none`].
+#[derive(Debug)]
+pub struct Traced<T> {
+    span: Span,
+    inner: T,
+}
+
+impl<T> Traced<T> {
+    /// Wraps the message along with the current span.
+    pub fn new(inner: T) -> Self {
+        Self { span: Span::current(), inner }
+    }
+
+    /// Returns the span the message was sent from.
+    pub const fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Returns the wrapped message.
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwraps the message, dropping the span.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Splits into the span the message was sent from, and the message.
+    pub fn into_parts(self) -> (Span, T) {
+        (self.span, self.inner)
+    }
+}
+
+impl<T> From<T> for Traced<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+    use tracing::{Dispatch, info_span};
+    use tracing_subscriber::registry::{LookupSpan, Registry};
+
+    #[test]
+    fn test_child_span_inherits_parent_across_channel() {
+        let dispatch = Dispatch::new(Registry::default());
+        let _guard = tracing::dispatcher::set_default(&dispatch);
+
+        let (tx, mut rx) = mpsc::channel(1);
+        let parent = info_span!("producer");
+        parent.in_scope(|| tx.try_send(Traced::new(42u64)).unwrap());
+
+        let (span, message) = rx.try_recv().unwrap().into_parts();
+        assert_eq!(message, 42);
+        assert_eq!(span.id(), parent.id());
+
+        let child = info_span!(parent: &span, "consumer");
+        let registry = dispatch.downcast_ref::<Registry>().unwrap();
+        let child_ref = registry.span(&child.id().unwrap()).unwrap();
+        assert_eq!(child_ref.parent().map(|parent| parent.id()), parent.id());
+    }
+
+    #[test]
+    fn test_no_span_when_tracing_is_off() {
+        let traced = Traced::new(42u64);
+        assert!(traced.span().is_none());
+        assert_eq!(*traced.inner(), 42);
+    }
+}
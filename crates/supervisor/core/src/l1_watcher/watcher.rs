@@ -1,4 +1,4 @@
-use crate::event::ChainEvent;
+use crate::event::{ChainEvent, Traced};
 use alloy_eips::{BlockNumHash, BlockNumberOrTag};
 use alloy_primitives::ChainId;
 use alloy_rpc_client::RpcClient;
@@ -23,7 +23,7 @@ pub struct L1Watcher<DB, F> {
     /// The finalized L1 block storage.
     finalized_l1_storage: Arc<F>,
     /// The event senders for each chain.
-    event_txs: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
+    event_txs: HashMap<ChainId, mpsc::Sender<Traced<ChainEvent>>>,
     /// The reorg handler.
     reorg_handler: ReorgHandler<DB>,
 }
@@ -37,7 +37,7 @@ where
     pub const fn new(
         rpc_client: RpcClient,
         finalized_l1_storage: Arc<F>,
-        event_txs: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
+        event_txs: HashMap<ChainId, mpsc::Sender<Traced<ChainEvent>>>,
         cancellation: CancellationToken,
         reorg_handler: ReorgHandler<DB>,
     ) -> Self {
@@ -139,8 +139,8 @@ where
 
     fn broadcast_finalized_source_update(&self, finalized_source_block: BlockInfo) {
         for (chain_id, sender) in &self.event_txs {
-            if let Err(err) =
-                sender.try_send(ChainEvent::FinalizedSourceUpdate { finalized_source_block })
+            if let Err(err) = sender
+                .try_send(Traced::new(ChainEvent::FinalizedSourceUpdate { finalized_source_block }))
             {
                 error!(
                     target: "supervisor::l1_watcher",
@@ -234,13 +234,13 @@ mod tests {
     use super::*;
     use crate::{
         SupervisorError,
-        syncnode::{ManagedNodeController, ManagedNodeError},
+        syncnode::{ManagedNodeController, ManagedNodeError, ResetCause},
     };
     use alloy_primitives::B256;
     use alloy_transport::mock::*;
     use async_trait::async_trait;
     use kona_supervisor_storage::{ChainDb, FinalizedL1Storage, StorageError};
-    use kona_supervisor_types::BlockSeal;
+    use kona_supervisor_types::{BlockSeal, Receipts};
     use mockall::{mock, predicate};
     use std::sync::Arc;
     use tokio::sync::mpsc;
@@ -275,9 +275,10 @@ mod tests {
                 derived_block_id: BlockNumHash,
             ) -> Result<(), ManagedNodeError>;
 
-            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
 
             async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+            async fn fetch_block_receipts(&self, block_id: BlockNumHash) -> Result<Receipts, ManagedNodeError>;
         }
     );
 
@@ -319,10 +320,10 @@ mod tests {
         watcher.broadcast_finalized_source_update(block);
 
         assert!(
-            matches!(rx1.recv().await, Some(ChainEvent::FinalizedSourceUpdate { finalized_source_block }) if finalized_source_block == block)
+            matches!(rx1.recv().await.map(Traced::into_inner), Some(ChainEvent::FinalizedSourceUpdate { finalized_source_block }) if finalized_source_block == block)
         );
         assert!(
-            matches!(rx2.recv().await, Some(ChainEvent::FinalizedSourceUpdate { finalized_source_block }) if finalized_source_block == block)
+            matches!(rx2.recv().await.map(Traced::into_inner), Some(ChainEvent::FinalizedSourceUpdate { finalized_source_block }) if finalized_source_block == block)
         );
     }
 
@@ -359,7 +360,7 @@ mod tests {
         last_finalized_number =
             watcher.handle_new_finalized_block(block.clone(), last_finalized_number);
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().into_inner();
         let expected = BlockInfo::new(
             block.header.hash,
             block.header.number,
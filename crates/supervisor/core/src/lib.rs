@@ -1,7 +1,9 @@
 //! This crate contains the core logic for the Optimism Supervisor component.
 
 pub mod chain_processor;
-pub use chain_processor::{ChainProcessor, ChainProcessorError, ProcessorState};
+pub use chain_processor::{
+    ChainProcessor, ChainProcessorError, ChainProcessorErrorCategory, ProcessorState,
+};
 
 pub mod error;
 pub use error::{SpecError, SupervisorError};
@@ -12,7 +14,8 @@ pub use supervisor::{Supervisor, SupervisorService};
 
 mod logindexer;
 pub use logindexer::{
-    LogIndexer, LogIndexerError, log_to_log_hash, log_to_message_payload, payload_hash_to_log_hash,
+    LogHashMismatch, LogIndexer, LogIndexerError, log_to_log_hash, log_to_message_payload,
+    payload_hash_to_log_hash,
 };
 
 pub mod rpc;
@@ -1,16 +1,27 @@
 use crate::{
-    logindexer::{log_to_log_hash, payload_hash_to_log_hash},
-    syncnode::{BlockProvider, ManagedNodeError},
+    event::Traced,
+    logindexer::{Metrics, log_to_log_hash, payload_hash_to_log_hash},
+    syncnode::{BlockProvider, ManagedNodeCommand, ManagedNodeError},
+};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{B256, ChainId};
+use alloy_sol_types::SolEvent;
+use kona_interop::{
+    ExecutingDescriptor, ExecutingMessage as ExecutingMessageEvent, ExtractionStats,
 };
-use alloy_primitives::ChainId;
-use kona_interop::parse_log_to_executing_message;
 use kona_protocol::BlockInfo;
+use kona_supervisor_rpc::CheckAccessListClient;
 use kona_supervisor_storage::{LogStorageReader, LogStorageWriter, StorageError};
-use kona_supervisor_types::{ExecutingMessage, Log};
-use std::sync::Arc;
+use kona_supervisor_types::{Access, ExecutingMessage, Log, Receipts, ReceiptsExt};
+use op_alloy_consensus::interop::SafetyLevel;
+use op_alloy_rpc_types::SuperchainDAError;
+use std::{collections::BTreeMap, ops::RangeInclusive, sync::Arc};
 use thiserror::Error;
-use tokio::sync::Mutex;
-use tracing::{debug, error};
+use tokio::{
+    sync::{Mutex, mpsc, oneshot},
+    task::JoinSet,
+};
+use tracing::{debug, error, warn};
 
 /// The [`LogIndexer`] is responsible for processing L2 receipts, extracting [`ExecutingMessage`]s,
 /// and persisting them to the state manager.
@@ -24,6 +35,11 @@ pub struct LogIndexer<P, S> {
     log_storage: Arc<S>,
     /// Protects concurrent catch-up
     is_catch_up_running: Mutex<bool>,
+    /// Maximum number of blocks whose logs are processed in parallel during catch-up.
+    concurrency: usize,
+    /// Channel to the managed node actor, used to fetch the receipts of the blocks missed in a
+    /// gap.
+    managed_node_sender: Option<mpsc::Sender<Traced<ManagedNodeCommand>>>,
 }
 
 impl<P, S> LogIndexer<P, S>
@@ -43,9 +59,38 @@ where
             block_provider: Mutex::new(block_provider),
             log_storage,
             is_catch_up_running: Mutex::new(false),
+            concurrency: 1,
+            managed_node_sender: None,
         }
     }
 
+    /// Sets the channel to the managed node actor.
+    ///
+    /// If set, the receipts of the blocks missed in a gap are requested with
+    /// [`ManagedNodeCommand::FetchReceipts`], which bounds each request by a timeout, instead of
+    /// being fetched from the block provider.
+    pub fn with_managed_node_sender(
+        mut self,
+        managed_node_sender: mpsc::Sender<Traced<ManagedNodeCommand>>,
+    ) -> Self {
+        self.managed_node_sender = Some(managed_node_sender);
+        self
+    }
+
+    /// Sets the maximum number of blocks whose logs are processed in parallel during catch-up.
+    ///
+    /// Logs are still stored in block order. Defaults to one, values below one are treated as one.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Initializes the metrics for the log indexer.
+    pub fn with_metrics(self) -> Self {
+        Metrics::init(self.chain_id);
+        self
+    }
+
     /// Sets the block provider
     pub async fn set_block_provider(&self, block_provider: Arc<P>) {
         let mut guard = self.block_provider.lock().await;
@@ -85,74 +130,243 @@ where
         });
     }
 
-    /// Performs log indexing sequentially from the latest indexed block up to the given target
-    /// block.
+    /// Performs log indexing from the latest indexed block up to the given target block.
+    ///
+    /// The logs of up to `concurrency` blocks are processed in parallel, but are stored strictly
+    /// in block order. The per-block tasks are owned by the catch-up, so they are aborted as soon
+    /// as it fails or is dropped.
     async fn index_log_upto(&self, block: &BlockInfo) -> Result<(), LogIndexerError> {
-        let mut current_number = self.log_storage.get_latest_block()?.number + 1;
+        let start = self.log_storage.get_latest_block()?.number + 1;
+        let provider = self.block_provider().await?;
+        let chain_id = self.chain_id;
+
+        let mut tasks = JoinSet::new();
+        // blocks processed ahead of the next one to store
+        let mut processed = BTreeMap::new();
+        let mut next_to_spawn = start;
+        let mut next_to_store = start;
+
+        while next_to_store < block.number {
+            while next_to_spawn < block.number &&
+                next_to_spawn - next_to_store < self.concurrency as u64
+            {
+                let number = next_to_spawn;
+                let provider = provider.clone();
+                let managed_node_sender = self.managed_node_sender.clone();
+                tasks.spawn(async move {
+                    let block = provider.block_by_number(number).await?;
+                    let receipts = match managed_node_sender {
+                        Some(sender) => request_receipts(&sender, block.id()).await?,
+                        None => provider.fetch_receipts(block.hash).await?,
+                    };
+                    let logs = block_logs(chain_id, &block, receipts);
+                    Ok::<_, LogIndexerError>((number, block, logs))
+                });
+                next_to_spawn += 1;
+            }
 
-        while current_number < block.number {
-            let provider = {
-                let guard = self.block_provider.lock().await;
-                guard.as_ref().ok_or(LogIndexerError::NoBlockProvider)?.clone()
-            };
+            let Some(result) = tasks.join_next().await else { break };
+            let (number, current_block, logs) =
+                result.map_err(|err| LogIndexerError::TaskFailed(err.to_string()))??;
+            processed.insert(number, (current_block, logs));
 
-            let current_block = provider.block_by_number(current_number).await?;
-            self.process_and_store_logs(&current_block).await?;
-            current_number += 1;
+            while let Some((current_block, logs)) = processed.remove(&next_to_store) {
+                self.log_storage.store_block_logs(&current_block, logs)?;
+                next_to_store += 1;
+            }
         }
         self.process_and_store_logs(block).await?;
 
         Ok(())
     }
 
+    /// Returns the current block provider.
+    async fn block_provider(&self) -> Result<Arc<P>, LogIndexerError> {
+        let guard = self.block_provider.lock().await;
+        guard.as_ref().ok_or(LogIndexerError::NoBlockProvider).cloned()
+    }
+
     /// Processes and stores the logs of a given block in into the state manager.
     ///
     /// This function:
     /// - Fetches all receipts for the given block from the specified chain.
     /// - Iterates through all logs in all receipts.
     /// - For each log, computes a hash from the log and optionally parses an [`ExecutingMessage`].
+    ///   Parsing is skipped if the logs bloom of the receipts rules out any executing message.
     /// - Records each [`Log`] including the message if found.
     /// - Saves all log entries atomically using the [`LogStorageWriter`].
     ///
     /// # Arguments
     /// - `block`: Metadata about the block being processed.
     pub async fn process_and_store_logs(&self, block: &BlockInfo) -> Result<(), LogIndexerError> {
-        let provider = {
-            let guard = self.block_provider.lock().await;
-            guard.as_ref().ok_or(LogIndexerError::NoBlockProvider)?.clone()
-        };
+        let provider = self.block_provider().await?;
+        let log_entries = fetch_block_logs(self.chain_id, provider.as_ref(), block).await?;
 
-        let receipts = provider.fetch_receipts(block.hash).await?;
-        let mut log_entries = Vec::with_capacity(receipts.len());
-        let mut log_index: u32 = 0;
-
-        for receipt in receipts {
-            for log in receipt.logs() {
-                let log_hash = log_to_log_hash(log);
-
-                let executing_message = parse_log_to_executing_message(log).map(|msg| {
-                    let payload_hash =
-                        payload_hash_to_log_hash(msg.payloadHash, msg.identifier.origin);
-                    ExecutingMessage {
-                        chain_id: msg.identifier.chainId.try_into().unwrap(),
-                        block_number: msg.identifier.blockNumber.try_into().unwrap(),
-                        log_index: msg.identifier.logIndex.try_into().unwrap(),
-                        timestamp: msg.identifier.timestamp.try_into().unwrap(),
-                        hash: payload_hash,
-                    }
+        self.log_storage.store_block_logs(block, log_entries)?;
+        Ok(())
+    }
+
+    /// Recomputes the log hashes of the given block range and checks each of them against a
+    /// reference supervisor, returning the logs the reference rejected as conflicting data.
+    ///
+    /// Any other failure of the reference, e.g. because it hasn't synced the block yet or is
+    /// unreachable, says nothing about the hash and aborts the verification with an error.
+    ///
+    /// This is a diagnostic to detect drift from the interop spec, it issues one request per log
+    /// and is meant to be run on demand.
+    pub async fn verify_against<C: CheckAccessListClient>(
+        &self,
+        client: &C,
+        range: RangeInclusive<u64>,
+    ) -> Result<Vec<LogHashMismatch>, LogIndexerError> {
+        let provider = self.block_provider().await?;
+        let mut mismatches = Vec::new();
+
+        for number in range {
+            let block = provider.block_by_number(number).await?;
+            let receipts = provider.fetch_receipts(block.hash).await?;
+            let (logs, _) = compute_logs(receipts);
+
+            let descriptor = ExecutingDescriptor::new(block.timestamp, None, Some(self.chain_id));
+            for log in logs {
+                let access = Access::from(&ExecutingMessage {
+                    chain_id: self.chain_id,
+                    block_number: block.number,
+                    log_index: log.index,
+                    timestamp: block.timestamp,
+                    hash: log.hash,
                 });
 
-                log_entries.push(Log { index: log_index, hash: log_hash, executing_message });
+                // the reference only accepts the entry if its log at that index has the same hash
+                let result = client
+                    .check_access_list(
+                        &access.to_entries(),
+                        SafetyLevel::LocalUnsafe,
+                        descriptor.clone(),
+                    )
+                    .await;
+                match result {
+                    Ok(()) => {}
+                    Err(err)
+                        if err.error_code() == Some(SuperchainDAError::ConflictingData as i64) =>
+                    {
+                        warn!(
+                            target: "supervisor::log_indexer",
+                            chain_id = %self.chain_id,
+                            block_number = block.number,
+                            log_index = log.index,
+                            %err,
+                            "Log hash rejected by reference supervisor"
+                        );
+                        mismatches.push(LogHashMismatch {
+                            block_number: block.number,
+                            log_index: log.index,
+                            local_hash: log.hash,
+                            error: err.to_string(),
+                        });
+                    }
+                    Err(err) => return Err(LogIndexerError::ReferenceRequest(err.to_string())),
+                }
+            }
+        }
+
+        Ok(mismatches)
+    }
+}
 
+/// A log whose locally computed hash was rejected by the reference supervisor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogHashMismatch {
+    /// Number of the block containing the log.
+    pub block_number: u64,
+    /// Index of the log within the block.
+    pub log_index: u32,
+    /// Locally computed hash of the log.
+    pub local_hash: B256,
+    /// Error returned by the reference supervisor.
+    pub error: String,
+}
+
+/// Requests the receipts of the given block from the managed node actor.
+async fn request_receipts(
+    sender: &mpsc::Sender<Traced<ManagedNodeCommand>>,
+    block_id: BlockNumHash,
+) -> Result<Receipts, ManagedNodeError> {
+    let (resp, receipts) = oneshot::channel();
+    sender
+        .send(Traced::new(ManagedNodeCommand::FetchReceipts { block_id, resp }))
+        .await
+        .map_err(|err| ManagedNodeError::ChannelSendFailed(err.to_string()))?;
+    receipts.await.map_err(|err| ManagedNodeError::ChannelSendFailed(err.to_string()))?
+}
+
+/// Fetches the receipts of the given block and computes its [`Log`] entries.
+async fn fetch_block_logs<P: BlockProvider>(
+    chain_id: ChainId,
+    provider: &P,
+    block: &BlockInfo,
+) -> Result<Vec<Log>, LogIndexerError> {
+    let receipts = provider.fetch_receipts(block.hash).await?;
+    Ok(block_logs(chain_id, block, receipts))
+}
+
+/// Computes the [`Log`] entries of the given block from its receipts, recording the extraction
+/// metrics.
+fn block_logs(chain_id: ChainId, block: &BlockInfo, receipts: Receipts) -> Vec<Log> {
+    let (log_entries, stats) = compute_logs(receipts);
+
+    if stats.malformed_skipped > 0 {
+        warn!(
+            target: "supervisor::log_indexer",
+            %chain_id,
+            block_number = block.number,
+            malformed = stats.malformed_skipped,
+            "Skipped malformed executing message logs"
+        );
+    }
+    Metrics::record_extraction(chain_id, &stats);
+
+    log_entries
+}
+
+/// Computes the [`Log`] entries of a block from its receipts.
+fn compute_logs(receipts: Receipts) -> (Vec<Log>, ExtractionStats) {
+    let mut log_entries = Vec::with_capacity(receipts.len());
+    let mut log_index: u32 = 0;
+    let mut stats = ExtractionStats::default();
+    let may_contain_messages =
+        receipts.may_contain_executing_messages(ExecutingMessageEvent::SIGNATURE_HASH);
+
+    for receipt in receipts {
+        for log in receipt.logs() {
+            let log_hash = log_to_log_hash(log);
+
+            if !may_contain_messages {
+                stats.logs_scanned += 1;
+                log_entries.push(Log { index: log_index, hash: log_hash, executing_message: None });
                 log_index += 1;
+                continue;
             }
-        }
 
-        log_entries.shrink_to_fit();
+            let executing_message = stats.scan(log).map(|msg| {
+                let payload_hash = payload_hash_to_log_hash(msg.payloadHash, msg.identifier.origin);
+                ExecutingMessage {
+                    chain_id: msg.identifier.chainId.try_into().unwrap(),
+                    block_number: msg.identifier.blockNumber.try_into().unwrap(),
+                    log_index: msg.identifier.logIndex.try_into().unwrap(),
+                    timestamp: msg.identifier.timestamp.try_into().unwrap(),
+                    hash: payload_hash,
+                }
+            });
 
-        self.log_storage.store_block_logs(block, log_entries)?;
-        Ok(())
+            log_entries.push(Log { index: log_index, hash: log_hash, executing_message });
+
+            log_index += 1;
+        }
     }
+
+    log_entries.shrink_to_fit();
+    (log_entries, stats)
 }
 
 /// Error type for the [`LogIndexer`].
@@ -169,16 +383,27 @@ pub enum LogIndexerError {
     /// Failed to fetch logs for a block from the state manager.   
     #[error(transparent)]
     FetchReceipt(#[from] ManagedNodeError),
+
+    /// A task processing the logs of a block failed.
+    #[error("log processing task failed: {0}")]
+    TaskFailed(String),
+
+    /// A request to the reference supervisor failed for a reason other than a hash mismatch.
+    #[error("reference supervisor request failed: {0}")]
+    ReferenceRequest(String),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::syncnode::{AuthenticationError, ClientError};
+    use alloy_json_rpc::ErrorPayload;
     use alloy_primitives::{Address, B256, Bytes};
+    use alloy_transport::TransportError;
     use async_trait::async_trait;
     use kona_interop::{ExecutingMessageBuilder, InteropProvider, SuperchainBuilder};
     use kona_protocol::BlockInfo;
+    use kona_supervisor_rpc::SupervisorClientError;
     use kona_supervisor_storage::StorageError;
     use kona_supervisor_types::{Log, Receipts};
     use mockall::mock;
@@ -361,4 +586,252 @@ mod tests {
         // Let the background task complete
         tokio::time::sleep(tokio::time::Duration::from_millis(300)).await;
     }
+
+    #[tokio::test]
+    async fn test_gap_receipts_are_requested_from_managed_node() {
+        let target_block = BlockInfo { number: 5, hash: B256::random(), ..Default::default() };
+
+        let mut mock_provider = MockBlockProvider::new();
+        mock_provider.expect_block_by_number().withf(|n| *n >= 1 && *n < 5).returning(|n| {
+            Ok(BlockInfo { number: n, hash: hash_for_number(n), ..Default::default() })
+        });
+        // only the receipts of the target block are fetched from the provider
+        mock_provider
+            .expect_fetch_receipts()
+            .withf(move |hash| *hash == target_block.hash)
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let mut mock_db = MockDb::new();
+        mock_db
+            .expect_get_latest_block()
+            .returning(|| Ok(BlockInfo { number: 0, ..Default::default() }));
+        mock_db.expect_store_block_logs().times(5).returning(|_, _| Ok(()));
+
+        let (tx, mut rx) = mpsc::channel(10);
+        let node = tokio::spawn(async move {
+            let mut requested = Vec::new();
+            while let Some(command) = rx.recv().await {
+                let ManagedNodeCommand::FetchReceipts { block_id, resp } = command.into_inner()
+                else {
+                    panic!("unexpected managed node command");
+                };
+                assert_eq!(block_id.hash, hash_for_number(block_id.number));
+                requested.push(block_id.number);
+                resp.send(Ok(vec![])).unwrap();
+            }
+            requested
+        });
+
+        let indexer = LogIndexer::new(1, Some(Arc::new(mock_provider)), Arc::new(mock_db))
+            .with_concurrency(2)
+            .with_managed_node_sender(tx);
+        indexer.index_log_upto(&target_block).await.unwrap();
+
+        drop(indexer);
+        let mut requested = node.await.unwrap();
+        requested.sort_unstable();
+        assert_eq!(requested, vec![1, 2, 3, 4]);
+    }
+
+    /// Provider that answers later for lower blocks, so parallel fetches complete out of order.
+    #[derive(Debug)]
+    struct SlowProvider;
+
+    #[async_trait]
+    impl BlockProvider for SlowProvider {
+        async fn fetch_receipts(&self, block_hash: B256) -> Result<Receipts, ManagedNodeError> {
+            let number = u64::from_be_bytes(block_hash[24..].try_into().unwrap());
+            tokio::time::sleep(tokio::time::Duration::from_millis((10 - number) * 5)).await;
+            Ok(vec![])
+        }
+
+        async fn block_by_number(&self, number: u64) -> Result<BlockInfo, ManagedNodeError> {
+            Ok(BlockInfo { number, hash: hash_for_number(number), ..Default::default() })
+        }
+    }
+
+    /// Reference supervisor accepting only the given access-list entries, and answering with the
+    /// given error code otherwise.
+    #[derive(Debug)]
+    struct ReferenceClient {
+        accepted: Vec<Vec<B256>>,
+        error_code: SuperchainDAError,
+    }
+
+    impl CheckAccessListClient for ReferenceClient {
+        async fn check_access_list(
+            &self,
+            inbox_entries: &[B256],
+            _min_safety: SafetyLevel,
+            _executing_descriptor: ExecutingDescriptor,
+        ) -> Result<(), SupervisorClientError> {
+            if self.accepted.iter().any(|entries| entries == inbox_entries) {
+                return Ok(());
+            }
+            let payload = ErrorPayload {
+                code: self.error_code as i64,
+                message: "rejected".into(),
+                data: None,
+            };
+            Err(SupervisorClientError::client(TransportError::ErrorResp(payload)))
+        }
+    }
+
+    /// Access-list entries of the original logs of [`build_receipts`], included at `timestamp`.
+    async fn original_entries(timestamp: u64) -> Vec<Vec<B256>> {
+        let (logs, _) = compute_logs(build_receipts().await);
+        assert_eq!(logs.len(), 2);
+        logs.iter()
+            .map(|log| {
+                Access::from(&ExecutingMessage {
+                    chain_id: 1,
+                    block_number: 1,
+                    log_index: log.index,
+                    timestamp,
+                    hash: log.hash,
+                })
+                .to_entries()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_verify_against_detects_corrupted_log_hash() {
+        let receipts = build_receipts().await;
+        let block = BlockInfo {
+            number: 1,
+            hash: hash_for_number(1),
+            timestamp: 123456,
+            ..Default::default()
+        };
+
+        // the reference knows the hashes of the original receipts
+        let client = ReferenceClient {
+            accepted: original_entries(block.timestamp).await,
+            error_code: SuperchainDAError::ConflictingData,
+        };
+
+        // corrupt the data of the initiating message log on the local side
+        let mut corrupted = receipts;
+        let OpReceiptEnvelope::Eip1559(receipt) = &mut corrupted[0] else {
+            panic!("unexpected receipt type");
+        };
+        receipt.receipt.logs[0].data.data = Bytes::from_static(b"corrupted");
+        let corrupted_hash = log_to_log_hash(&receipt.receipt.logs[0]);
+
+        let mut mock_provider = MockBlockProvider::new();
+        mock_provider.expect_block_by_number().returning(move |_| Ok(block));
+        mock_provider.expect_fetch_receipts().returning(move |_| Ok(corrupted.clone()));
+
+        let log_indexer =
+            LogIndexer::new(1, Some(Arc::new(mock_provider)), Arc::new(MockDb::new()));
+        let mismatches = log_indexer.verify_against(&client, 1..=1).await.unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].block_number, 1);
+        assert_eq!(mismatches[0].log_index, 0);
+        assert_eq!(mismatches[0].local_hash, corrupted_hash);
+    }
+
+    #[tokio::test]
+    async fn test_verify_against_propagates_other_reference_errors() {
+        let receipts = build_receipts().await;
+        let block = BlockInfo {
+            number: 1,
+            hash: hash_for_number(1),
+            timestamp: 123456,
+            ..Default::default()
+        };
+
+        // the reference hasn't synced the second log's block yet
+        let mut accepted = original_entries(block.timestamp).await;
+        accepted.pop();
+        let client = ReferenceClient { accepted, error_code: SuperchainDAError::FutureData };
+
+        let mut mock_provider = MockBlockProvider::new();
+        mock_provider.expect_block_by_number().returning(move |_| Ok(block));
+        mock_provider.expect_fetch_receipts().returning(move |_| Ok(receipts.clone()));
+
+        let log_indexer =
+            LogIndexer::new(1, Some(Arc::new(mock_provider)), Arc::new(MockDb::new()));
+        let result = log_indexer.verify_against(&client, 1..=1).await;
+
+        assert!(matches!(result, Err(LogIndexerError::ReferenceRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_catch_up_stores_blocks_in_order() {
+        let stored = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let mut mock_db = MockDb::new();
+        mock_db
+            .expect_get_latest_block()
+            .returning(|| Ok(BlockInfo { number: 0, ..Default::default() }));
+        let recorded = stored.clone();
+        mock_db.expect_store_block_logs().times(9).returning(move |block, _| {
+            recorded.lock().unwrap().push(block.number);
+            Ok(())
+        });
+
+        let indexer =
+            LogIndexer::new(1, Some(Arc::new(SlowProvider)), Arc::new(mock_db)).with_concurrency(4);
+
+        let target_block = BlockInfo { number: 9, hash: hash_for_number(9), ..Default::default() };
+        indexer.index_log_upto(&target_block).await.unwrap();
+
+        assert_eq!(*stored.lock().unwrap(), (1..=9).collect::<Vec<_>>());
+    }
+
+    /// Provider that fails the receipts of block 1 once the fetches of blocks 2 to 4 are in flight,
+    /// and never answers for the other blocks.
+    #[derive(Debug, Default)]
+    struct FailingProvider {
+        /// Held by every pending fetch until it is dropped.
+        in_flight: Arc<()>,
+    }
+
+    #[async_trait]
+    impl BlockProvider for FailingProvider {
+        async fn fetch_receipts(&self, block_hash: B256) -> Result<Receipts, ManagedNodeError> {
+            if block_hash == hash_for_number(1) {
+                while Arc::strong_count(&self.in_flight) < 4 {
+                    tokio::task::yield_now().await;
+                }
+                return Err(ManagedNodeError::GetBlockByNumberFailed(1));
+            }
+            let _in_flight = self.in_flight.clone();
+            std::future::pending().await
+        }
+
+        async fn block_by_number(&self, number: u64) -> Result<BlockInfo, ManagedNodeError> {
+            Ok(BlockInfo { number, hash: hash_for_number(number), ..Default::default() })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_catch_up_aborts_pending_fetches() {
+        let mut mock_db = MockDb::new();
+        mock_db
+            .expect_get_latest_block()
+            .returning(|| Ok(BlockInfo { number: 0, ..Default::default() }));
+        mock_db.expect_store_block_logs().never();
+
+        let provider = Arc::new(FailingProvider::default());
+        let indexer =
+            LogIndexer::new(1, Some(provider.clone()), Arc::new(mock_db)).with_concurrency(4);
+
+        let target_block = BlockInfo { number: 9, hash: hash_for_number(9), ..Default::default() };
+        let err = indexer.index_log_upto(&target_block).await.unwrap_err();
+        assert!(matches!(err, LogIndexerError::FetchReceipt(_)));
+
+        // the fetches of blocks 2 to 4 are dropped with the catch-up instead of running detached
+        tokio::time::timeout(tokio::time::Duration::from_secs(1), async {
+            while Arc::strong_count(&provider.in_flight) > 1 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("pending fetches were not aborted");
+    }
 }
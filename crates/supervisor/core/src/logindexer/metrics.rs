@@ -0,0 +1,64 @@
+use alloy_primitives::ChainId;
+use kona_interop::ExtractionStats;
+
+/// Metrics for log indexing.
+#[derive(Debug, Clone)]
+pub(crate) struct Metrics;
+
+impl Metrics {
+    pub(crate) const SUPERVISOR_LOG_INDEXER_LOGS_SCANNED_TOTAL: &'static str =
+        "kona_supervisor_log_indexer_logs_scanned_total";
+    pub(crate) const SUPERVISOR_LOG_INDEXER_MESSAGES_FOUND_TOTAL: &'static str =
+        "kona_supervisor_log_indexer_messages_found_total";
+    pub(crate) const SUPERVISOR_LOG_INDEXER_MALFORMED_SKIPPED_TOTAL: &'static str =
+        "kona_supervisor_log_indexer_malformed_skipped_total";
+
+    pub(crate) fn init(chain_id: ChainId) {
+        Self::describe();
+        Self::zero(chain_id);
+    }
+
+    fn describe() {
+        metrics::describe_counter!(
+            Self::SUPERVISOR_LOG_INDEXER_LOGS_SCANNED_TOTAL,
+            metrics::Unit::Count,
+            "Total number of logs scanned by the log indexer",
+        );
+
+        metrics::describe_counter!(
+            Self::SUPERVISOR_LOG_INDEXER_MESSAGES_FOUND_TOTAL,
+            metrics::Unit::Count,
+            "Total number of executing messages found by the log indexer",
+        );
+
+        metrics::describe_counter!(
+            Self::SUPERVISOR_LOG_INDEXER_MALFORMED_SKIPPED_TOTAL,
+            metrics::Unit::Count,
+            "Total number of malformed executing message logs skipped by the log indexer",
+        );
+    }
+
+    fn zero(chain_id: ChainId) {
+        Self::record_extraction(chain_id, &ExtractionStats::default());
+    }
+
+    pub(crate) fn record_extraction(chain_id: ChainId, stats: &ExtractionStats) {
+        metrics::counter!(
+            Self::SUPERVISOR_LOG_INDEXER_LOGS_SCANNED_TOTAL,
+            "chain_id" => chain_id.to_string(),
+        )
+        .increment(stats.logs_scanned as u64);
+
+        metrics::counter!(
+            Self::SUPERVISOR_LOG_INDEXER_MESSAGES_FOUND_TOTAL,
+            "chain_id" => chain_id.to_string(),
+        )
+        .increment(stats.messages_found as u64);
+
+        metrics::counter!(
+            Self::SUPERVISOR_LOG_INDEXER_MALFORMED_SKIPPED_TOTAL,
+            "chain_id" => chain_id.to_string(),
+        )
+        .increment(stats.malformed_skipped as u64);
+    }
+}
@@ -1,3 +1,5 @@
+use alloy_eips::BlockNumHash;
+use alloy_primitives::ChainId;
 use kona_supervisor_storage::StorageError;
 use thiserror::Error;
 
@@ -26,4 +28,26 @@ pub enum ReorgHandlerError {
     /// This can happen if the rewind target block is pre-interop.
     #[error("rewind target is pre-interop")]
     RewindTargetPreInterop,
+
+    /// Indicates no database is registered for the chain.
+    #[error("database not found for chain: {0}")]
+    DatabaseMissing(ChainId),
+
+    /// Indicates the requested rewind target does not match the stored source block.
+    #[error("rewind target {expected:?} does not match stored source block {actual:?}")]
+    RewindTargetMismatch {
+        /// The requested rewind target.
+        expected: BlockNumHash,
+        /// The stored source block at the same height.
+        actual: BlockNumHash,
+    },
+
+    /// Indicates the requested rewind would reach below the source of the finalized block.
+    #[error("rewind target {target} is below finalized source block {finalized_source}")]
+    RewindBelowFinalized {
+        /// The number of the requested rewind target.
+        target: u64,
+        /// The number of the source block of the finalized block.
+        finalized_source: u64,
+    },
 }
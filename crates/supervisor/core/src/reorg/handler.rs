@@ -1,11 +1,13 @@
 use super::metrics::Metrics;
 use crate::{ReorgHandlerError, reorg::task::ReorgTask};
+use alloy_eips::BlockNumHash;
 use alloy_primitives::ChainId;
 use alloy_rpc_client::RpcClient;
 use derive_more::Constructor;
 use futures::future;
 use kona_protocol::BlockInfo;
 use kona_supervisor_metrics::observe_metrics_for_result_async;
+use kona_supervisor_rpc::ReorgReport;
 use kona_supervisor_storage::{DbReader, StorageRewinder};
 use std::{collections::HashMap, sync::Arc};
 use tracing::{error, info, trace};
@@ -54,6 +56,36 @@ where
         self.verify_and_handle_chain_reorg().await
     }
 
+    /// Rewinds the storage and safety heads of a chain so that the given source block is the
+    /// latest one.
+    ///
+    /// Unlike [`Self::handle_l1_reorg`], the canonical L1 chain is not consulted. Intended for
+    /// manual incident recovery.
+    pub fn force_rewind(
+        &self,
+        chain_id: ChainId,
+        to: BlockNumHash,
+    ) -> Result<ReorgReport, ReorgHandlerError> {
+        let chain_db =
+            self.chain_dbs.get(&chain_id).ok_or(ReorgHandlerError::DatabaseMissing(chain_id))?;
+
+        let report = ReorgTask::new(chain_id, Arc::clone(chain_db), self.rpc_client.clone())
+            .force_rewind(to)
+            .inspect_err(|err| {
+                error!(target: "supervisor::reorg_handler", chain_id, %err, "Forced rewind failed");
+            })?;
+
+        Metrics::record_block_depth(chain_id, report.l1_depth, report.l2_depth);
+        info!(
+            target: "supervisor::reorg_handler",
+            chain_id,
+            l1_depth = report.l1_depth,
+            l2_depth = report.l2_depth,
+            "Forced rewind completed"
+        );
+        Ok(report)
+    }
+
     /// Verifies the consistency of each chain with the L1 chain and handles any reorgs, if any.
     async fn verify_and_handle_chain_reorg(&self) -> Result<(), ReorgHandlerError> {
         let mut handles = Vec::with_capacity(self.chain_dbs.len());
@@ -89,3 +121,112 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use alloy_transport::mock::{Asserter, MockTransport};
+    use kona_interop::{DerivedRefPair, SafetyLevel};
+    use kona_supervisor_storage::{
+        ChainDb, DerivationStorageReader, DerivationStorageWriter, HeadRefStorageReader,
+        HeadRefStorageWriter, LogStorageReader, LogStorageWriter,
+    };
+    use tempfile::TempDir;
+
+    fn block(number: u64, hash: u8, parent_hash: B256) -> BlockInfo {
+        BlockInfo { hash: B256::from([hash; 32]), number, parent_hash, timestamp: 0 }
+    }
+
+    #[test]
+    fn test_force_rewind_keeps_heads_and_logs_consistent() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let db = Arc::new(
+            ChainDb::new(1, &tmp_dir.path().join("chaindb_force_rewind")).expect("create db"),
+        );
+
+        let anchor = DerivedRefPair {
+            source: block(100, 0, B256::from([1u8; 32])),
+            derived: block(0, 2, B256::from([3u8; 32])),
+        };
+        db.initialise_log_storage(anchor.derived).expect("initialise log storage");
+        db.initialise_derivation_storage(anchor).expect("initialise derivation storage");
+
+        let source1 = block(101, 3, anchor.source.hash);
+        let source2 = block(102, 4, source1.hash);
+
+        let mut derived = vec![anchor.derived];
+        for number in 1..=5 {
+            let parent_hash = derived.last().unwrap().hash;
+            derived.push(block(number, 9 + number as u8, parent_hash));
+        }
+
+        db.save_source_block(source1).expect("save source1");
+        for block in &derived[1..=3] {
+            db.store_block_logs(block, vec![]).expect("store logs");
+            db.save_derived_block(DerivedRefPair { source: source1, derived: *block })
+                .expect("save derived block");
+        }
+        db.save_source_block(source2).expect("save source2");
+        for block in &derived[4..=5] {
+            db.store_block_logs(block, vec![]).expect("store logs");
+            db.save_derived_block(DerivedRefPair { source: source2, derived: *block })
+                .expect("save derived block");
+        }
+
+        for block in &derived[1..=4] {
+            db.update_current_cross_unsafe(block).expect("update cross unsafe");
+        }
+        for block in &derived[1..=2] {
+            db.update_current_cross_safe(block).expect("update cross safe");
+        }
+        let finalized = db.update_finalized_using_source(source1).expect("update finalized");
+        assert_eq!(finalized, derived[2]);
+
+        let rpc_client = RpcClient::new(MockTransport::new(Asserter::new()), false);
+        let handler = ReorgHandler::new(rpc_client, HashMap::from([(1, db.clone())]));
+
+        // can't rewind below the source of the finalized block
+        let err = handler.force_rewind(1, anchor.source.id()).unwrap_err();
+        assert!(matches!(
+            err,
+            ReorgHandlerError::RewindBelowFinalized { target: 100, finalized_source: 101 }
+        ));
+
+        // the target must match the stored source block
+        let err = handler.force_rewind(1, BlockNumHash::new(101, B256::ZERO)).unwrap_err();
+        assert!(matches!(err, ReorgHandlerError::RewindTargetMismatch { .. }));
+
+        // unknown chain
+        let err = handler.force_rewind(2, source1.id()).unwrap_err();
+        assert!(matches!(err, ReorgHandlerError::DatabaseMissing(2)));
+
+        let report = handler.force_rewind(1, source1.id()).expect("force rewind");
+        assert_eq!(
+            report,
+            ReorgReport {
+                chain_id: 1,
+                source: source1,
+                derived: derived[3],
+                l1_depth: 1,
+                l2_depth: 2
+            }
+        );
+
+        // storage and heads are consistent with the rewound state
+        assert_eq!(
+            db.latest_derivation_state().unwrap(),
+            DerivedRefPair { source: source1, derived: derived[3] }
+        );
+        assert_eq!(db.get_latest_block().unwrap(), derived[3]);
+        assert!(db.get_block(4).is_err());
+        assert_eq!(db.get_safety_head_ref(SafetyLevel::LocalUnsafe).unwrap(), derived[3]);
+        assert_eq!(db.get_safety_head_ref(SafetyLevel::CrossUnsafe).unwrap(), derived[3]);
+        assert_eq!(db.get_safety_head_ref(SafetyLevel::CrossSafe).unwrap(), derived[2]);
+        assert_eq!(db.get_safety_head_ref(SafetyLevel::Finalized).unwrap(), derived[2]);
+
+        // rewinding to the latest source block is a no-op
+        let report = handler.force_rewind(1, source1.id()).expect("force rewind");
+        assert_eq!((report.l1_depth, report.l2_depth), (0, 0));
+    }
+}
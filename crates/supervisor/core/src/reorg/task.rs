@@ -1,12 +1,13 @@
 use super::metrics::Metrics;
 use crate::ReorgHandlerError;
-use alloy_eips::BlockNumberOrTag;
+use alloy_eips::{BlockNumHash, BlockNumberOrTag};
 use alloy_primitives::{B256, ChainId};
 use alloy_rpc_client::RpcClient;
 use alloy_rpc_types_eth::Block;
 use derive_more::Constructor;
-use kona_interop::DerivedRefPair;
+use kona_interop::{DerivedRefPair, SafetyLevel};
 use kona_protocol::BlockInfo;
+use kona_supervisor_rpc::ReorgReport;
 use kona_supervisor_storage::{DbReader, StorageError, StorageRewinder};
 use std::sync::Arc;
 use tracing::{debug, info, trace, warn};
@@ -74,6 +75,65 @@ where
         Ok(())
     }
 
+    /// Rewinds the chain so that the given source block is the latest one, regardless of the
+    /// canonical L1 chain.
+    ///
+    /// Rejects targets below the source of the finalized block.
+    pub(crate) fn force_rewind(&self, to: BlockNumHash) -> Result<ReorgReport, ReorgHandlerError> {
+        info!(
+            target: "supervisor::reorg_handler",
+            chain_id = %self.chain_id,
+            rewind_target_source = to.number,
+            "Forcing rewind to source block..."
+        );
+
+        let latest_state = self.db.latest_derivation_state()?;
+
+        let target = self.db.get_source_block(to.number)?;
+        if target.hash != to.hash {
+            return Err(ReorgHandlerError::RewindTargetMismatch {
+                expected: to,
+                actual: target.id(),
+            });
+        }
+
+        match self.db.get_safety_head_ref(SafetyLevel::Finalized) {
+            Ok(finalized) => {
+                let finalized_source = self.db.derived_to_source(finalized.id())?;
+                if to.number < finalized_source.number {
+                    return Err(ReorgHandlerError::RewindBelowFinalized {
+                        target: to.number,
+                        finalized_source: finalized_source.number,
+                    });
+                }
+            }
+            Err(StorageError::FutureData) => { /* nothing finalized yet */ }
+            Err(err) => return Err(ReorgHandlerError::StorageError(err)),
+        }
+
+        if to.number < latest_state.source.number {
+            // rewinding is inclusive, so rewind to the first source block after the target
+            let first_rewound = self.db.get_source_block(to.number + 1)?;
+            self.db.rewind_to_source(&first_rewound.id()).inspect_err(|err| {
+                warn!(
+                    target: "supervisor::reorg_handler::db",
+                    chain_id = %self.chain_id,
+                    %err,
+                    "Failed to force rewind DB to source block"
+                );
+            })?;
+        }
+
+        let rewound_state = self.db.latest_derivation_state()?;
+        Ok(ReorgReport {
+            chain_id: self.chain_id,
+            source: rewound_state.source,
+            derived: rewound_state.derived,
+            l1_depth: latest_state.source.number - rewound_state.source.number,
+            l2_depth: latest_state.derived.number - rewound_state.derived.number,
+        })
+    }
+
     async fn rewind_to_target_source(
         &self,
         rewind_target_source: BlockInfo,
@@ -1,4 +1,6 @@
 use crate::syncnode::ClientConfig;
+use alloy_eips::BlockNumHash;
+use alloy_primitives::ChainId;
 use alloy_rpc_types_engine::JwtSecret;
 use async_trait::async_trait;
 use derive_more::Constructor;
@@ -6,7 +8,8 @@ use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject, ErrorObjectOwned},
 };
-use kona_supervisor_rpc::SupervisorAdminApiServer;
+use kona_supervisor_rpc::{ReorgReport, SupervisorAdminApiServer};
+use kona_supervisor_types::HexStringU64;
 use std::time::Duration;
 use thiserror::Error;
 use tokio::{
@@ -22,6 +25,10 @@ pub enum AdminError {
     #[error("invalid jwt secret: {0}")]
     InvalidJwtSecret(String),
 
+    /// Indicates that a destructive request was sent without explicit confirmation.
+    #[error("request requires explicit confirmation")]
+    ConfirmationRequired,
+
     /// Indicates that the request to the admin channel failed to send.
     #[error("failed to send admin request")]
     SendFailed,
@@ -43,7 +50,9 @@ impl From<AdminError> for ErrorObjectOwned {
     fn from(err: AdminError) -> Self {
         match err {
             // todo: handle these errors more gracefully
-            AdminError::InvalidJwtSecret(_) => ErrorObjectOwned::from(ErrorCode::InvalidParams),
+            AdminError::InvalidJwtSecret(_) | AdminError::ConfirmationRequired => {
+                ErrorObjectOwned::from(ErrorCode::InvalidParams)
+            }
             AdminError::SendFailed |
             AdminError::SenderDropped |
             AdminError::Timeout |
@@ -65,6 +74,15 @@ pub enum AdminRequest {
         /// The response channel to send the result back.
         resp: oneshot::Sender<Result<(), AdminError>>,
     },
+    /// Rewinds a chain so that the given source block is the latest one.
+    ForceRewind {
+        /// The chain to rewind.
+        chain_id: ChainId,
+        /// The source (L1) block to rewind to.
+        to_block: BlockNumHash,
+        /// The response channel to send the result back.
+        resp: oneshot::Sender<Result<ReorgReport, AdminError>>,
+    },
 }
 
 /// Supervisor Admin RPC interface
@@ -85,7 +103,7 @@ impl SupervisorAdminApiServer for AdminRpc {
         })?;
 
         let request = AdminRequest::AddL2Rpc {
-            cfg: ClientConfig { url: url.clone(), jwt_secret },
+            cfg: ClientConfig { url: url.clone(), jwt_secret, jwt_refresh: None },
             resp: resp_tx,
         };
 
@@ -110,6 +128,44 @@ impl SupervisorAdminApiServer for AdminRpc {
                     }),
             )
     }
+
+    /// Rewinds a chain so that the given source block is the latest one.
+    async fn force_rewind(
+        &self,
+        chain_id: HexStringU64,
+        to_block: BlockNumHash,
+        confirm: bool,
+    ) -> RpcResult<ReorgReport> {
+        let chain_id = ChainId::from(chain_id);
+        if !confirm {
+            warn!(target: "supervisor::admin_rpc", chain_id, "Force rewind requested without confirmation");
+            return Err(ErrorObject::from(AdminError::ConfirmationRequired));
+        }
+
+        let (resp_tx, resp_rx) = oneshot::channel();
+        let request = AdminRequest::ForceRewind { chain_id, to_block, resp: resp_tx };
+
+        self.admin_tx.send(request).await.map_err(|err| {
+            warn!(target: "supervisor::admin_rpc", chain_id, %err, "Failed to send AdminRequest");
+            ErrorObject::from(AdminError::SendFailed)
+        })?;
+
+        // wait for response with a timeout
+        timeout(Duration::from_secs(ADMIN_REQUEST_TIMEOUT_SECS), resp_rx)
+            .await
+            .map_or_else(
+                |_| {
+                    warn!(target: "supervisor::admin_rpc", chain_id, "AdminRequest timed out");
+                    Err(ErrorObject::from(AdminError::Timeout))
+                },
+                |res| res
+                    .unwrap_or(Err(AdminError::SenderDropped))
+                    .map_err(|err| {
+                        warn!(target: "supervisor::admin_rpc", chain_id, %err, "Failed to process AdminRequest");
+                        ErrorObject::from(err)
+                    }),
+            )
+    }
 }
 
 #[cfg(test)]
@@ -213,4 +269,47 @@ mod tests {
         // let handler finish cleanly
         handler.await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_force_rewind_requires_confirmation() {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(1);
+        let admin = AdminRpc::new(tx);
+
+        let res = admin.force_rewind(HexStringU64(1), BlockNumHash::default(), false).await;
+        assert!(res.is_err(), "expected error without confirmation");
+        assert!(rx.try_recv().is_err(), "no request must reach the service");
+    }
+
+    #[tokio::test]
+    async fn test_force_rewind_success() {
+        let (tx, mut rx) = mpsc::channel::<AdminRequest>(1);
+        let admin = AdminRpc::new(tx);
+        let to_block = BlockNumHash::new(101, alloy_primitives::B256::from([3u8; 32]));
+
+        let handler = tokio::spawn(async move {
+            if let Some(AdminRequest::ForceRewind { chain_id, to_block, resp }) = rx.recv().await {
+                assert_eq!(chain_id, 1);
+                let _ = resp.send(Ok(ReorgReport {
+                    chain_id,
+                    source: kona_protocol::BlockInfo {
+                        number: to_block.number,
+                        hash: to_block.hash,
+                        ..Default::default()
+                    },
+                    derived: Default::default(),
+                    l1_depth: 1,
+                    l2_depth: 2,
+                }));
+            } else {
+                panic!("expected ForceRewind request");
+            }
+        });
+
+        let report = admin.force_rewind(HexStringU64(1), to_block, true).await.unwrap();
+        assert_eq!(report.source.id(), to_block);
+        assert_eq!(report.l1_depth, 1);
+        assert_eq!(report.l2_depth, 2);
+
+        handler.await.unwrap();
+    }
 }
@@ -30,6 +30,9 @@ impl Metrics {
     pub(crate) const SUPERVISOR_RPC_METHOD_ALL_SAFE_DERIVED_AT: &'static str =
         "all_safe_derived_at";
     pub(crate) const SUPERVISOR_RPC_METHOD_CHECK_ACCESS_LIST: &'static str = "check_access_list";
+    pub(crate) const SUPERVISOR_RPC_METHOD_MESSAGE_DEPENDENCIES: &'static str =
+        "message_dependencies";
+    pub(crate) const SUPERVISOR_RPC_METHOD_CHAINS: &'static str = "chains";
 
     /// Initializes metrics for the Supervisor RPC service.
     ///
@@ -92,6 +95,8 @@ impl Metrics {
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_SYNC_STATUS);
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_ALL_SAFE_DERIVED_AT);
         Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_CHECK_ACCESS_LIST);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_MESSAGE_DEPENDENCIES);
+        Self::zero_rpc_method(Self::SUPERVISOR_RPC_METHOD_CHAINS);
     }
 }
 
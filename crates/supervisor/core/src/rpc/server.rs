@@ -9,7 +9,8 @@ use jsonrpsee::{core::RpcResult, types::ErrorObject};
 use kona_interop::{DependencySet, DerivedIdPair, ExecutingDescriptor, SafetyLevel};
 use kona_protocol::BlockInfo;
 use kona_supervisor_rpc::{
-    SuperRootOutputRpc, SupervisorApiServer, SupervisorChainSyncStatus, SupervisorSyncStatus,
+    ChainStatus, MessageDependency, SuperRootOutputRpc, SupervisorApiServer,
+    SupervisorChainSyncStatus, SupervisorSyncStatus,
 };
 use kona_supervisor_types::{HexStringU64, SuperHead};
 use std::sync::Arc;
@@ -121,6 +122,39 @@ where
         )
     }
 
+    async fn message_dependencies(
+        &self,
+        chain_id_hex: HexStringU64,
+        block: BlockNumHash,
+    ) -> RpcResult<Vec<MessageDependency>> {
+        let chain_id = ChainId::from(chain_id_hex);
+        crate::observe_rpc_call!(
+            Metrics::SUPERVISOR_RPC_METHOD_MESSAGE_DEPENDENCIES,
+            async {
+                trace!(target: "supervisor::rpc",
+                    %chain_id,
+                    ?block,
+                    "Received message_dependencies request"
+                );
+
+                Ok(self.supervisor.message_dependencies(chain_id, block)?)
+            }
+            .await
+        )
+    }
+
+    async fn chains(&self) -> RpcResult<Vec<ChainStatus>> {
+        crate::observe_rpc_call!(
+            Metrics::SUPERVISOR_RPC_METHOD_CHAINS,
+            async {
+                trace!(target: "supervisor::rpc", "Received chains request");
+
+                Ok(self.supervisor.chains().await?)
+            }
+            .await
+        )
+    }
+
     async fn cross_safe(&self, chain_id_hex: HexStringU64) -> RpcResult<DerivedIdPair> {
         let chain_id = ChainId::from(chain_id_hex);
         crate::observe_rpc_call!(
@@ -337,6 +371,7 @@ mod tests {
     use kona_protocol::BlockInfo;
     use kona_supervisor_storage::{EntryNotFoundError, StorageError};
     use mockall::*;
+    use op_alloy_rpc_types::SuperchainDAError;
     use std::sync::Arc;
 
     mock!(
@@ -356,7 +391,9 @@ mod tests {
             fn finalized(&self, chain: ChainId) -> Result<BlockInfo, SupervisorError>;
             fn finalized_l1(&self) -> Result<BlockInfo, SupervisorError>;
             fn check_access_list(&self, inbox_entries: Vec<B256>, min_safety: SafetyLevel, executing_descriptor: ExecutingDescriptor) -> Result<(), SupervisorError>;
+            fn message_dependencies(&self, chain: ChainId, block: BlockNumHash) -> Result<Vec<MessageDependency>, SupervisorError>;
             async fn super_root_at_timestamp(&self, timestamp: u64) -> Result<SuperRootOutputRpc, SupervisorError>;
+            async fn chains(&self) -> Result<Vec<ChainStatus>, SupervisorError>;
         }
     );
 
@@ -517,4 +554,100 @@ mod tests {
         assert_eq!(status.finalized_timestamp, 50);
         assert_eq!(status.chains.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_check_access_list_maps_validation_failures_to_spec_codes() {
+        let cases = [
+            (SpecError::MessageExpired, SuperchainDAError::ConflictingData as i32),
+            (SpecError::UnknownChain, -321300),
+            (
+                SpecError::InsufficientSafety {
+                    required: SafetyLevel::Finalized,
+                    actual: SafetyLevel::CrossSafe,
+                },
+                SuperchainDAError::ConflictingData as i32,
+            ),
+            (SpecError::InitiatingMessageNotFound, SuperchainDAError::MissedData as i32),
+        ];
+
+        for (err, code) in cases {
+            let mut mock_service = MockSupervisorService::new();
+            mock_service
+                .expect_check_access_list()
+                .times(1)
+                .returning(move |_, _, _| Err(SupervisorError::SpecError(err.clone())));
+
+            let rpc = SupervisorRpc::new(Arc::new(mock_service));
+            let result = rpc
+                .check_access_list(vec![], SafetyLevel::CrossSafe, ExecutingDescriptor::default())
+                .await;
+
+            assert_eq!(result.unwrap_err().code(), code);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_metrics_per_method_in_batches() {
+        use jsonrpsee::{
+            core::{client::ClientT, params::BatchRequestBuilder},
+            rpc_params,
+            server::ServerBuilder,
+            ws_client::WsClientBuilder,
+        };
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        // the server runs on this thread, as `tokio::test` uses a current-thread runtime
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let mut mock_service = MockSupervisorService::new();
+        mock_service.expect_local_unsafe().returning(|_| Ok(BlockInfo::default()));
+        mock_service.expect_finalized_l1().returning(|| Err(SupervisorError::EmptyDependencySet));
+        let module = SupervisorRpc::new(Arc::new(mock_service)).into_rpc();
+
+        let server = ServerBuilder::default().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        let handle = server.start(module);
+
+        // calls of a batch are dispatched to the same handlers as single calls
+        let client = WsClientBuilder::default().build(format!("ws://{addr}")).await.unwrap();
+        let mut batch = BatchRequestBuilder::new();
+        batch.insert("supervisor_localUnsafe", rpc_params!["0x1"]).unwrap();
+        batch.insert("supervisor_localUnsafe", rpc_params!["0x2"]).unwrap();
+        batch.insert("supervisor_finalizedL1", rpc_params![]).unwrap();
+        client.batch_request::<serde_json::Value>(batch).await.unwrap();
+        handle.stop().unwrap();
+
+        let mut counters = HashMap::default();
+        let mut durations = HashMap::default();
+        for (key, _, _, value) in snapshotter.snapshot().into_vec() {
+            let key = key.key();
+            let Some(method) = key.labels().find(|label| label.key() == "method") else {
+                continue;
+            };
+            let method = method.value().to_string();
+            match value {
+                DebugValue::Counter(count) => {
+                    counters.insert((key.name().to_string(), method), count);
+                }
+                DebugValue::Histogram(samples) => {
+                    durations.insert(method, samples.len());
+                }
+                DebugValue::Gauge(_) => {}
+            }
+        }
+
+        let count =
+            |name: &str, method: &str| counters.get(&(name.to_string(), method.to_string()));
+        let local_unsafe = Metrics::SUPERVISOR_RPC_METHOD_LOCAL_UNSAFE;
+        let finalized_l1 = Metrics::SUPERVISOR_RPC_METHOD_FINALIZED_L1;
+        assert_eq!(count(Metrics::SUPERVISOR_RPC_REQUESTS_SUCCESS_TOTAL, local_unsafe), Some(&2));
+        assert_eq!(count(Metrics::SUPERVISOR_RPC_REQUESTS_ERROR_TOTAL, local_unsafe), Some(&0));
+        assert_eq!(count(Metrics::SUPERVISOR_RPC_REQUESTS_SUCCESS_TOTAL, finalized_l1), Some(&0));
+        assert_eq!(count(Metrics::SUPERVISOR_RPC_REQUESTS_ERROR_TOTAL, finalized_l1), Some(&1));
+        // one more sample each, recorded when the metrics are zeroed on init
+        assert_eq!(durations.get(local_unsafe), Some(&3));
+        assert_eq!(durations.get(finalized_l1), Some(&2));
+    }
 }
@@ -1,9 +1,10 @@
 use crate::{
     CrossSafetyError,
-    safety_checker::{ValidationError, ValidationError::InitiatingMessageNotFound},
+    safety_checker::{
+        DependencyFallback, ValidationError, ValidationError::InitiatingMessageNotFound,
+    },
 };
 use alloy_primitives::{BlockHash, ChainId};
-use derive_more::Constructor;
 use kona_interop::InteropValidator;
 use kona_protocol::BlockInfo;
 use kona_supervisor_storage::{CrossChainSafetyProvider, StorageError};
@@ -12,12 +13,32 @@ use op_alloy_consensus::interop::SafetyLevel;
 use std::collections::HashSet;
 
 /// Uses a [`CrossChainSafetyProvider`] to verify the safety of cross-chain message dependencies.
-#[derive(Debug, Constructor)]
+#[derive(Debug)]
 pub struct CrossSafetyChecker<'a, P, V> {
     chain_id: ChainId,
     validator: &'a V,
     provider: &'a P,
     required_level: SafetyLevel,
+    fallback: Option<&'a dyn DependencyFallback>,
+}
+
+impl<'a, P, V> CrossSafetyChecker<'a, P, V> {
+    /// Creates a new [`CrossSafetyChecker`].
+    pub const fn new(
+        chain_id: ChainId,
+        validator: &'a V,
+        provider: &'a P,
+        required_level: SafetyLevel,
+    ) -> Self {
+        Self { chain_id, validator, provider, required_level, fallback: None }
+    }
+
+    /// Accepts dependencies missing from local storage if the given [`DependencyFallback`] has
+    /// already validated them.
+    pub const fn with_fallback(mut self, fallback: &'a dyn DependencyFallback) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
 }
 
 impl<P, V> CrossSafetyChecker<'_, P, V>
@@ -29,7 +50,9 @@ where
     /// checks
     pub fn validate_block(&self, block: BlockInfo) -> Result<(), CrossSafetyError> {
         self.map_dependent_block(&block, self.chain_id, |message, initiating_block_fetcher| {
-            // Step 1: Validate interop timestamps before any dependency checks
+            // Step 1: Validate interop timestamps before any dependency checks. Expiry is measured
+            // against the executing block's timestamp rather than wall-clock time, so the outcome
+            // only depends on the blocks being validated.
             self.validator
                 .validate_interop_timestamps(
                     message.chain_id,  // initiating chain id
@@ -40,6 +63,20 @@ where
                 )
                 .map_err(ValidationError::InteropValidationError)?;
 
+            // Dependencies that aren't indexed locally yet, e.g. while the initiating chain is
+            // backfilling, can only be accepted if the fallback has validated them.
+            if let Some(fallback) = self.fallback &&
+                self.is_missing_locally(&message)?
+            {
+                if fallback.is_verified(&message, self.required_level) {
+                    return Ok(());
+                }
+                return Err(CrossSafetyError::DependencyUnavailable {
+                    chain_id: message.chain_id,
+                    block_number: message.block_number,
+                });
+            }
+
             // Step 2: Verify message dependency without fetching the initiating block.
             // This avoids unnecessary I/O and ensures we skip validation when:
             //  - The current target head of the chain is behind the initiating block (must wait for
@@ -83,6 +120,15 @@ where
         Ok(())
     }
 
+    /// Returns `true` if the block a message depends on hasn't been indexed locally yet.
+    fn is_missing_locally(&self, message: &ExecutingMessage) -> Result<bool, CrossSafetyError> {
+        match self.provider.get_safety_head_ref(message.chain_id, SafetyLevel::LocalUnsafe) {
+            Ok(head) => Ok(head.number < message.block_number),
+            Err(StorageError::DatabaseNotInitialised | StorageError::FutureData) => Ok(true),
+            Err(err) => Err(err.into()),
+        }
+    }
+
     /// Recursively checks for a cyclic dependency in cross-chain messages.
     ///
     /// # Purpose
@@ -382,6 +428,73 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn validate_block_expiry_follows_executing_block_timestamp() {
+        use crate::config::{Config, RollupConfig, RollupConfigSet};
+        use kona_interop::DependencySet;
+        use std::{collections::HashMap, net::SocketAddr, path::PathBuf};
+
+        let init_chain_id = 1;
+        let exec_chain_id = 2;
+        let rollup =
+            RollupConfig { genesis: Default::default(), block_time: 2, interop_time: Some(0) };
+        let config = Config {
+            l1_rpc: Default::default(),
+            l2_consensus_nodes_config: vec![],
+            datadir: PathBuf::new(),
+            rpc_addr: SocketAddr::from(([127, 0, 0, 1], 8545)),
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
+            enable_admin_api: false,
+            dependency_set: DependencySet {
+                dependencies: Default::default(),
+                override_message_expiry_window: Some(10),
+            },
+            rollup_config_set: RollupConfigSet {
+                rollups: HashMap::from([(init_chain_id, rollup.clone()), (exec_chain_id, rollup)]),
+            },
+            event_channel_capacities: HashMap::new(),
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_table_metrics: false,
+        };
+
+        let dep_block =
+            BlockInfo { number: 100, hash: b256(100), parent_hash: b256(99), timestamp: 195 };
+        let exec_msg = ExecutingMessage {
+            chain_id: init_chain_id,
+            block_number: 100,
+            log_index: 0,
+            timestamp: 195,
+            hash: b256(999),
+        };
+        let exec_log = Log { index: 0, hash: b256(999), executing_message: Some(exec_msg) };
+        let init_log = Log { index: 0, hash: b256(999), executing_message: None };
+
+        let mut provider = MockProvider::default();
+        provider.expect_get_block_logs().returning(move |_, _| Ok(vec![exec_log.clone()]));
+        provider.expect_get_block().returning(move |_, _| Ok(dep_block));
+        provider.expect_get_log().returning(move |_, _, _| Ok(init_log.clone()));
+        provider.expect_get_safety_head_ref().returning(move |_, _| Ok(dep_block));
+
+        let checker =
+            CrossSafetyChecker::new(exec_chain_id, &config, &provider, SafetyLevel::CrossSafe);
+
+        // the message expires 10 seconds after the initiating block
+        let last_valid =
+            BlockInfo { number: 101, hash: b256(101), parent_hash: b256(100), timestamp: 205 };
+        assert!(checker.validate_block(last_valid).is_ok());
+
+        let expired = BlockInfo { timestamp: 206, ..last_valid };
+        assert!(matches!(
+            checker.validate_block(expired),
+            Err(CrossSafetyError::ValidationError(ValidationError::InteropValidationError(
+                InteropValidationError::InvalidInteropTimestamp(206)
+            )))
+        ));
+    }
+
     #[test]
     fn validate_executing_message_timestamp_violation() {
         let chain_id = 1;
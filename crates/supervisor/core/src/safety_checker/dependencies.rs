@@ -0,0 +1,250 @@
+use alloy_primitives::ChainId;
+use kona_protocol::BlockInfo;
+use kona_supervisor_rpc::{DependencyStatus, MessageDependency, MessageIdentifier};
+use kona_supervisor_storage::{CrossChainSafetyProvider, StorageError};
+use kona_supervisor_types::{ExecutingMessage, Log};
+use op_alloy_consensus::interop::SafetyLevel;
+
+/// Safety levels from the highest to the lowest.
+const SAFETY_LEVELS: [SafetyLevel; 4] = [
+    SafetyLevel::Finalized,
+    SafetyLevel::CrossSafe,
+    SafetyLevel::LocalSafe,
+    SafetyLevel::CrossUnsafe,
+];
+
+/// Resolves the initiating message of each executing message in the given block.
+///
+/// Dependencies that can't be resolved are returned with a [`DependencyStatus`] explaining why,
+/// rather than failing the whole lookup.
+pub fn message_dependencies<P: CrossChainSafetyProvider>(
+    provider: &P,
+    chain_id: ChainId,
+    block_number: u64,
+) -> Result<Vec<MessageDependency>, StorageError> {
+    provider
+        .get_block_logs(chain_id, block_number)?
+        .iter()
+        .filter_map(|log| log.executing_message.as_ref().map(|message| (log.index, message)))
+        .map(|(log_index, message)| resolve(provider, log_index, message))
+        .collect()
+}
+
+fn resolve<P: CrossChainSafetyProvider>(
+    provider: &P,
+    log_index: u32,
+    message: &ExecutingMessage,
+) -> Result<MessageDependency, StorageError> {
+    let mut dependency = MessageDependency {
+        log_index,
+        initiating: MessageIdentifier {
+            chain_id: message.chain_id,
+            block_number: message.block_number,
+            log_index: message.log_index,
+            timestamp: message.timestamp,
+        },
+        message_hash: message.hash,
+        initiating_block: None,
+        safety_level: None,
+        status: DependencyStatus::Resolved,
+    };
+
+    let block = match provider.get_block(message.chain_id, message.block_number) {
+        Ok(block) => block,
+        Err(StorageError::DatabaseNotInitialised) => {
+            dependency.status = DependencyStatus::UnknownChain;
+            return Ok(dependency);
+        }
+        Err(StorageError::EntryNotFound(_)) => {
+            dependency.status = DependencyStatus::BlockNotFound;
+            return Ok(dependency);
+        }
+        Err(err) => return Err(err),
+    };
+    dependency.initiating_block = Some(block.id());
+    dependency.safety_level = Some(safety_level(provider, message.chain_id, &block)?);
+
+    dependency.status =
+        match provider.get_log(message.chain_id, message.block_number, message.log_index) {
+            Ok(Log { hash, .. }) if hash == message.hash => DependencyStatus::Resolved,
+            Ok(_) => DependencyStatus::HashMismatch,
+            Err(StorageError::EntryNotFound(_)) => DependencyStatus::LogNotFound,
+            Err(err) => return Err(err),
+        };
+    Ok(dependency)
+}
+
+/// Returns the highest safety level reached by an indexed block.
+pub(crate) fn safety_level<P: CrossChainSafetyProvider>(
+    provider: &P,
+    chain_id: ChainId,
+    block: &BlockInfo,
+) -> Result<SafetyLevel, StorageError> {
+    for level in SAFETY_LEVELS {
+        match provider.get_safety_head_ref(chain_id, level) {
+            Ok(head) if head.number >= block.number => return Ok(level),
+            Ok(_) | Err(StorageError::FutureData) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    // indexed blocks are at least local unsafe
+    Ok(SafetyLevel::LocalUnsafe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use kona_interop::DerivedRefPair;
+    use kona_supervisor_storage::{ChainDbFactory, EntryNotFoundError, LogStorageWriter};
+    use mockall::mock;
+    use tempfile::TempDir;
+
+    mock! (
+        #[derive(Debug)]
+        pub Provider {}
+
+        impl CrossChainSafetyProvider for Provider {
+            fn get_block(&self, chain_id: ChainId, block_number: u64) -> Result<BlockInfo, StorageError>;
+            fn get_log(&self, chain_id: ChainId, block_number: u64, log_index: u32) -> Result<Log, StorageError>;
+            fn get_block_logs(&self, chain_id: ChainId, block_number: u64) -> Result<Vec<Log>, StorageError>;
+            fn get_safety_head_ref(&self, chain_id: ChainId, level: SafetyLevel) -> Result<BlockInfo, StorageError>;
+            fn update_current_cross_unsafe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<(), StorageError>;
+            fn update_current_cross_safe(&self, chain_id: ChainId, block: &BlockInfo) -> Result<DerivedRefPair, StorageError>;
+        }
+    );
+
+    fn executing_log(index: u32, chain_id: ChainId, block_number: u64) -> Log {
+        Log {
+            index,
+            hash: B256::repeat_byte(0xee),
+            executing_message: Some(ExecutingMessage {
+                chain_id,
+                block_number,
+                log_index: 0,
+                timestamp: 100,
+                hash: B256::repeat_byte(0x11),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_message_dependencies_flags_unresolved() {
+        let mut provider = MockProvider::new();
+        provider.expect_get_block_logs().returning(|_, _| {
+            Ok(vec![
+                Log { index: 0, hash: B256::ZERO, executing_message: None },
+                executing_log(1, 2, 10),
+                executing_log(2, 2, 11),
+                executing_log(3, 3, 10),
+            ])
+        });
+        provider.expect_get_block().returning(|chain_id, number| match (chain_id, number) {
+            (2, 10) => Ok(BlockInfo { number, timestamp: 100, ..Default::default() }),
+            (2, _) => Err(EntryNotFoundError::DerivedBlockNotFound(number).into()),
+            _ => Err(StorageError::DatabaseNotInitialised),
+        });
+        provider.expect_get_log().returning(|_, _, index| {
+            Ok(Log { index, hash: B256::repeat_byte(0x11), executing_message: None })
+        });
+        provider.expect_get_safety_head_ref().returning(|_, level| match level {
+            SafetyLevel::LocalSafe => Ok(BlockInfo { number: 10, ..Default::default() }),
+            SafetyLevel::Finalized => Err(StorageError::FutureData),
+            _ => Ok(BlockInfo { number: 9, ..Default::default() }),
+        });
+
+        let dependencies = message_dependencies(&provider, 1, 5).unwrap();
+        let statuses: Vec<_> = dependencies.iter().map(|dep| (dep.log_index, dep.status)).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, DependencyStatus::Resolved),
+                (2, DependencyStatus::BlockNotFound),
+                (3, DependencyStatus::UnknownChain),
+            ]
+        );
+        assert_eq!(dependencies[0].safety_level, Some(SafetyLevel::LocalSafe));
+        assert_eq!(dependencies[1].safety_level, None);
+    }
+
+    #[test]
+    fn test_message_dependencies_hash_mismatch() {
+        let mut provider = MockProvider::new();
+        provider.expect_get_block_logs().returning(|_, _| Ok(vec![executing_log(0, 2, 10)]));
+        provider
+            .expect_get_block()
+            .returning(|_, number| Ok(BlockInfo { number, ..Default::default() }));
+        provider.expect_get_log().returning(|_, _, index| {
+            Ok(Log { index, hash: B256::repeat_byte(0x22), executing_message: None })
+        });
+        provider
+            .expect_get_safety_head_ref()
+            .returning(|_, _| Ok(BlockInfo { number: 0, ..Default::default() }));
+
+        let dependencies = message_dependencies(&provider, 1, 5).unwrap();
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].status, DependencyStatus::HashMismatch);
+        assert_eq!(dependencies[0].safety_level, Some(SafetyLevel::LocalUnsafe));
+        assert!(!dependencies[0].is_resolved());
+    }
+
+    #[test]
+    fn test_message_dependencies_across_two_chains() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let factory = ChainDbFactory::new(tmp_dir.path().to_path_buf());
+
+        let block = |number: u64, hash: u8, parent_hash: B256| BlockInfo {
+            hash: B256::from([hash; 32]),
+            number,
+            parent_hash,
+            timestamp: 100 + number,
+        };
+
+        // chain 1 emits the initiating messages
+        let anchor_1 = block(0, 1, B256::ZERO);
+        let block_1 = block(1, 2, anchor_1.hash);
+        let db_1 = factory.get_or_create_db(1).expect("create chain 1 db");
+        db_1.initialise_log_storage(anchor_1).expect("initialise chain 1");
+        db_1.store_block_logs(
+            &block_1,
+            vec![Log { index: 0, hash: B256::repeat_byte(0x11), executing_message: None }],
+        )
+        .expect("store chain 1 logs");
+
+        // chain 2 executes them
+        let anchor_2 = block(0, 3, B256::ZERO);
+        let block_2 = block(1, 4, anchor_2.hash);
+        let executing = |index: u32, chain_id: ChainId, log_index: u32| Log {
+            index,
+            hash: B256::repeat_byte(0xee),
+            executing_message: Some(ExecutingMessage {
+                chain_id,
+                block_number: 1,
+                log_index,
+                timestamp: 101,
+                hash: B256::repeat_byte(0x11),
+            }),
+        };
+        let db_2 = factory.get_or_create_db(2).expect("create chain 2 db");
+        db_2.initialise_log_storage(anchor_2).expect("initialise chain 2");
+        db_2.store_block_logs(
+            &block_2,
+            vec![executing(0, 1, 0), executing(1, 1, 5), executing(2, 3, 0)],
+        )
+        .expect("store chain 2 logs");
+
+        let dependencies = message_dependencies(&factory, 2, 1).unwrap();
+        assert_eq!(dependencies.len(), 3);
+
+        assert!(dependencies[0].is_resolved());
+        assert_eq!(dependencies[0].initiating_block, Some(block_1.id()));
+        assert_eq!(dependencies[0].safety_level, Some(SafetyLevel::LocalUnsafe));
+
+        assert_eq!(dependencies[1].status, DependencyStatus::LogNotFound);
+        assert_eq!(dependencies[1].initiating_block, Some(block_1.id()));
+
+        assert_eq!(dependencies[2].status, DependencyStatus::UnknownChain);
+        assert_eq!(dependencies[2].initiating_block, None);
+        assert_eq!(dependencies[2].safety_level, None);
+    }
+}
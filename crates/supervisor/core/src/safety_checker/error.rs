@@ -23,6 +23,16 @@ pub enum CrossSafetyError {
         block_number: u64,
     },
 
+    /// The block that a message depends on is missing from local storage, and couldn't be
+    /// validated through the dependency fallback either.
+    #[error("dependency on block {block_number} (chain {chain_id}) is unavailable")]
+    DependencyUnavailable {
+        /// The ID of the chain containing the missing dependency.
+        chain_id: ChainId,
+        /// The block number of the missing dependency.
+        block_number: u64,
+    },
+
     /// No candidate block is currently available for promotion.
     #[error("no candidate block found to promote")]
     NoBlockToPromote,
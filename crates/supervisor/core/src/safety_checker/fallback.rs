@@ -0,0 +1,182 @@
+use crate::CrossSafetyError;
+use alloy_primitives::{B256, ChainId};
+use async_trait::async_trait;
+use core::fmt::Debug;
+use kona_interop::{ExecutingDescriptor, SafetyLevelCode};
+use kona_protocol::BlockInfo;
+use kona_supervisor_rpc::CheckAccessListClient;
+use kona_supervisor_types::{Access, ExecutingMessage};
+use op_alloy_consensus::interop::SafetyLevel;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+use tracing::warn;
+
+/// Validates message dependencies that are missing from local storage, e.g. while the initiating
+/// chain is still backfilling.
+#[async_trait]
+pub trait DependencyFallback: Debug + Send + Sync {
+    /// Returns `true` if the message has already been validated at the given safety level.
+    fn is_verified(&self, message: &ExecutingMessage, level: SafetyLevel) -> bool;
+
+    /// Validates the messages executed in `block` at the given safety level.
+    ///
+    /// Returns [`CrossSafetyError::DependencyUnavailable`] if the messages couldn't be validated.
+    async fn resolve(
+        &self,
+        chain_id: ChainId,
+        block: &BlockInfo,
+        messages: &[ExecutingMessage],
+        level: SafetyLevel,
+    ) -> Result<(), CrossSafetyError>;
+}
+
+/// A [`DependencyFallback`] that validates messages with the `supervisor_checkAccessList` method
+/// of a remote supervisor.
+///
+/// Only successful validations are cached, a dependency that isn't safe yet may become safe later.
+#[derive(Debug)]
+pub struct RemoteDependencyFallback<C> {
+    client: C,
+    /// Highest safety level each message was validated at, keyed by its access-list checksum.
+    verified: Mutex<HashMap<B256, u8>>,
+}
+
+impl<C> RemoteDependencyFallback<C> {
+    /// Maximum number of validated messages to cache. Entries are only needed until local storage
+    /// catches up, so the cache is simply cleared once full.
+    pub const MAX_CACHED_MESSAGES: usize = 10_000;
+
+    /// Creates a new [`RemoteDependencyFallback`] querying the given client.
+    pub fn new(client: C) -> Self {
+        Self { client, verified: Mutex::new(HashMap::new()) }
+    }
+}
+
+#[async_trait]
+impl<C> DependencyFallback for RemoteDependencyFallback<C>
+where
+    C: CheckAccessListClient + Debug + Send + Sync,
+{
+    fn is_verified(&self, message: &ExecutingMessage, level: SafetyLevel) -> bool {
+        let checksum = Access::from(message).checksum;
+        let verified = self.verified.lock().unwrap_or_else(PoisonError::into_inner);
+        verified.get(&checksum).is_some_and(|code| *code >= level.to_code())
+    }
+
+    async fn resolve(
+        &self,
+        chain_id: ChainId,
+        block: &BlockInfo,
+        messages: &[ExecutingMessage],
+        level: SafetyLevel,
+    ) -> Result<(), CrossSafetyError> {
+        let pending: Vec<_> =
+            messages.iter().filter(|message| !self.is_verified(message, level)).collect();
+        let Some(first) = pending.first() else {
+            return Ok(());
+        };
+
+        let accesses: Vec<_> = pending.iter().map(|message| Access::from(*message)).collect();
+        let entries: Vec<_> = accesses.iter().flat_map(Access::to_entries).collect();
+        let descriptor = ExecutingDescriptor::new(block.timestamp, None, Some(chain_id));
+
+        self.client.check_access_list(&entries, level, descriptor).await.map_err(|err| {
+            warn!(
+                target: "supervisor::safety_checker",
+                chain_id,
+                block_number = block.number,
+                %level,
+                %err,
+                "Failed to validate dependencies with the remote supervisor"
+            );
+            CrossSafetyError::DependencyUnavailable {
+                chain_id: first.chain_id,
+                block_number: first.block_number,
+            }
+        })?;
+
+        let mut verified = self.verified.lock().unwrap_or_else(PoisonError::into_inner);
+        if verified.len() + accesses.len() > Self::MAX_CACHED_MESSAGES {
+            verified.clear();
+        }
+        for access in accesses {
+            let code = verified.entry(access.checksum).or_default();
+            *code = (*code).max(level.to_code());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_supervisor_rpc::SupervisorClientError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug, Default)]
+    struct TestClient {
+        fail: bool,
+        calls: AtomicUsize,
+    }
+
+    impl CheckAccessListClient for TestClient {
+        async fn check_access_list(
+            &self,
+            inbox_entries: &[B256],
+            _min_safety: SafetyLevel,
+            executing_descriptor: ExecutingDescriptor,
+        ) -> Result<(), SupervisorClientError> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            assert_eq!(inbox_entries.len(), 2);
+            assert_eq!(executing_descriptor.chain_id, Some(1));
+            if self.fail {
+                return Err(SupervisorClientError::client(std::io::Error::other("unreachable")));
+            }
+            Ok(())
+        }
+    }
+
+    fn message() -> ExecutingMessage {
+        ExecutingMessage {
+            chain_id: 2,
+            block_number: 50,
+            log_index: 0,
+            timestamp: 100,
+            hash: B256::repeat_byte(0x11),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_caches_verified_messages() {
+        let fallback = RemoteDependencyFallback::new(TestClient::default());
+        let block = BlockInfo { number: 10, timestamp: 200, ..Default::default() };
+
+        assert!(!fallback.is_verified(&message(), SafetyLevel::CrossUnsafe));
+        fallback.resolve(1, &block, &[message()], SafetyLevel::CrossSafe).await.unwrap();
+
+        assert!(fallback.is_verified(&message(), SafetyLevel::CrossUnsafe));
+        assert!(fallback.is_verified(&message(), SafetyLevel::CrossSafe));
+        assert!(!fallback.is_verified(&message(), SafetyLevel::Finalized));
+
+        // cached messages aren't queried again
+        fallback.resolve(1, &block, &[message()], SafetyLevel::CrossSafe).await.unwrap();
+        assert_eq!(fallback.client.calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_failure_is_not_cached() {
+        let fallback =
+            RemoteDependencyFallback::new(TestClient { fail: true, ..Default::default() });
+        let block = BlockInfo { number: 10, timestamp: 200, ..Default::default() };
+
+        let err =
+            fallback.resolve(1, &block, &[message()], SafetyLevel::CrossSafe).await.unwrap_err();
+        assert_eq!(err, CrossSafetyError::DependencyUnavailable { chain_id: 2, block_number: 50 });
+        assert!(!fallback.is_verified(&message(), SafetyLevel::CrossUnsafe));
+
+        let _ = fallback.resolve(1, &block, &[message()], SafetyLevel::CrossSafe).await;
+        assert_eq!(fallback.client.calls.load(Ordering::Relaxed), 2);
+    }
+}
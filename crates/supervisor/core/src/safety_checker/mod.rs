@@ -8,7 +8,12 @@
 //! of messages are safely committed before the messages are executed in other chains.
 mod cross;
 pub use cross::CrossSafetyChecker;
+mod dependencies;
+pub use dependencies::message_dependencies;
+pub(crate) use dependencies::safety_level;
 mod error;
+mod fallback;
+pub use fallback::{DependencyFallback, RemoteDependencyFallback};
 mod task;
 mod traits;
 pub use traits::SafetyPromoter;
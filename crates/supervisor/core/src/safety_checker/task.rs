@@ -1,10 +1,9 @@
 use crate::{
     CrossSafetyError,
-    event::ChainEvent,
-    safety_checker::{CrossSafetyChecker, traits::SafetyPromoter},
+    event::{ChainEvent, Traced},
+    safety_checker::{CrossSafetyChecker, DependencyFallback, traits::SafetyPromoter},
 };
 use alloy_primitives::ChainId;
-use derive_more::Constructor;
 use kona_interop::InteropValidator;
 use kona_protocol::BlockInfo;
 use kona_supervisor_storage::{CrossChainSafetyProvider, StorageError};
@@ -18,15 +17,55 @@ use tracing::{debug, error, info};
 ///
 /// It uses [`CrossChainSafetyProvider`] to fetch candidate blocks and the [`CrossSafetyChecker`]
 /// to validate cross-chain message dependencies.
-#[derive(Debug, Constructor)]
+#[derive(Debug)]
 pub struct CrossSafetyCheckerJob<P, V, L> {
     chain_id: ChainId,
     provider: Arc<P>,
     cancel_token: CancellationToken,
     interval: Duration,
     promoter: L,
-    event_tx: mpsc::Sender<ChainEvent>,
+    event_tx: mpsc::Sender<Traced<ChainEvent>>,
     validator: Arc<V>,
+    fallback: Option<Arc<dyn DependencyFallback>>,
+    disabled_levels: Vec<SafetyLevel>,
+}
+
+impl<P, V, L> CrossSafetyCheckerJob<P, V, L> {
+    /// Creates a new [`CrossSafetyCheckerJob`].
+    pub const fn new(
+        chain_id: ChainId,
+        provider: Arc<P>,
+        cancel_token: CancellationToken,
+        interval: Duration,
+        promoter: L,
+        event_tx: mpsc::Sender<Traced<ChainEvent>>,
+        validator: Arc<V>,
+    ) -> Self {
+        Self {
+            chain_id,
+            provider,
+            cancel_token,
+            interval,
+            promoter,
+            event_tx,
+            validator,
+            fallback: None,
+            disabled_levels: Vec::new(),
+        }
+    }
+
+    /// Validates dependencies missing from local storage with the given [`DependencyFallback`]
+    /// instead of waiting for them to be indexed.
+    pub fn with_fallback(mut self, fallback: Arc<dyn DependencyFallback>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+
+    /// Skips promotion entirely if the target level of the job is among the given levels.
+    pub fn with_disabled_levels(mut self, levels: Vec<SafetyLevel>) -> Self {
+        self.disabled_levels = levels;
+        self
+    }
 }
 
 impl<P, V, L> CrossSafetyCheckerJob<P, V, L>
@@ -45,14 +84,27 @@ where
         let target_level = self.promoter.target_level();
         let chain_id = self.chain_id;
 
+        if self.disabled_levels.contains(&target_level) {
+            info!(
+                target: "supervisor::safety_checker",
+                chain_id,
+                %target_level,
+                "Safety level disabled, skipping promotion"
+            );
+            return;
+        }
+
         info!(
             target: "supervisor::safety_checker",
             chain_id,
             %target_level,
             "Started safety checker");
 
-        let checker =
+        let mut checker =
             CrossSafetyChecker::new(chain_id, &*self.validator, &*self.provider, target_level);
+        if let Some(fallback) = &self.fallback {
+            checker = checker.with_fallback(fallback.as_ref());
+        }
 
         loop {
             tokio::select! {
@@ -62,7 +114,7 @@ where
                 }
 
                 _ = async {
-                    match self.promote_next_block(&checker) {
+                    match self.try_promote_next_block(&checker).await {
                         Ok(block_info) => {
                             debug!(
                                 target: "supervisor::safety_checker",
@@ -78,9 +130,11 @@ where
                                 //  - no candidate is ready right now
                                 //  - validation failed (we already emitted invalidate event in promote_next_block for CrossSafe)
                                 //  - dependency not yet safe on another chain
+                                //  - dependency not yet indexed locally, nor validated remotely
                                 CrossSafetyError::NoBlockToPromote |
                                 CrossSafetyError::ValidationError(_) |
-                                CrossSafetyError::DependencyNotSafe { .. }   => {
+                                CrossSafetyError::DependencyNotSafe { .. } |
+                                CrossSafetyError::DependencyUnavailable { .. } => {
                                     debug!(
                                         target: "supervisor::safety_checker",
                                         chain_id,
@@ -109,6 +163,33 @@ where
         info!(target: "supervisor::safety_checker", chain_id = self.chain_id, %target_level, "Stopped safety checker");
     }
 
+    // Attempts to promote the next block, validating the dependencies missing from local storage
+    // with the fallback, if configured, and retrying once they are.
+    async fn try_promote_next_block(
+        &self,
+        checker: &CrossSafetyChecker<'_, P, V>,
+    ) -> Result<BlockInfo, CrossSafetyError> {
+        let result = self.promote_next_block(checker);
+        let (Err(CrossSafetyError::DependencyUnavailable { .. }), Some(fallback)) =
+            (&result, &self.fallback)
+        else {
+            return result;
+        };
+
+        let candidate = self.find_next_promotable_block()?;
+        let messages: Vec<_> = self
+            .provider
+            .get_block_logs(self.chain_id, candidate.number)?
+            .into_iter()
+            .filter_map(|log| log.executing_message)
+            .collect();
+        fallback
+            .resolve(self.chain_id, &candidate, &messages, self.promoter.target_level())
+            .await?;
+
+        self.promote_next_block(checker)
+    }
+
     // Attempts to promote the next block by the Promoter
     // after validating cross-chain dependencies.
     fn promote_next_block(
@@ -182,7 +263,7 @@ where
     }
 
     fn broadcast_event(&self, event: ChainEvent) {
-        if let Err(err) = self.event_tx.try_send(event) {
+        if let Err(err) = self.event_tx.try_send(Traced::new(event)) {
             error!(
                 target: "supervisor::safety_checker",
                 target_level = %self.promoter.target_level(),
@@ -198,11 +279,13 @@ mod tests {
     use super::*;
     use crate::safety_checker::promoter::{CrossSafePromoter, CrossUnsafePromoter};
     use alloy_primitives::{B256, ChainId};
+    use async_trait::async_trait;
     use kona_interop::{DerivedRefPair, InteropValidationError};
     use kona_supervisor_storage::{CrossChainSafetyProvider, StorageError};
     use kona_supervisor_types::{ExecutingMessage, Log};
     use mockall::mock;
     use op_alloy_consensus::interop::SafetyLevel;
+    use std::sync::atomic::{AtomicBool, Ordering};
 
     mock! {
         #[derive(Debug)]
@@ -238,6 +321,23 @@ mod tests {
         }
     );
 
+    mock! (
+        #[derive(Debug)]
+        pub Fallback {}
+
+        #[async_trait]
+        impl DependencyFallback for Fallback {
+            fn is_verified(&self, message: &ExecutingMessage, level: SafetyLevel) -> bool;
+            async fn resolve(
+                &self,
+                chain_id: ChainId,
+                block: &BlockInfo,
+                messages: &[ExecutingMessage],
+                level: SafetyLevel,
+            ) -> Result<(), CrossSafetyError>;
+        }
+    );
+
     fn b256(n: u64) -> B256 {
         let mut bytes = [0u8; 32];
         bytes[24..].copy_from_slice(&n.to_be_bytes());
@@ -248,12 +348,32 @@ mod tests {
         BlockInfo { number: n, hash: b256(n), parent_hash: b256(n - 1), timestamp: 0 }
     }
 
+    #[tokio::test]
+    async fn skips_disabled_level() {
+        let (event_tx, _event_rx) = mpsc::channel::<Traced<ChainEvent>>(10);
+
+        // the provider has no expectations, so any promotion attempt would panic
+        let job = CrossSafetyCheckerJob::new(
+            1,
+            Arc::new(MockProvider::default()),
+            CancellationToken::new(),
+            Duration::from_secs(1),
+            CrossUnsafePromoter,
+            event_tx,
+            Arc::new(MockValidator::default()),
+        )
+        .with_disabled_levels(vec![SafetyLevel::CrossUnsafe]);
+
+        // returns without being cancelled
+        tokio::time::timeout(Duration::from_secs(1), job.run()).await.unwrap();
+    }
+
     #[tokio::test]
     async fn promotes_next_cross_unsafe_successfully() {
         let chain_id = 1;
         let mut mock = MockProvider::default();
         let mock_validator = MockValidator::default();
-        let (event_tx, mut event_rx) = mpsc::channel::<ChainEvent>(10);
+        let (event_tx, mut event_rx) = mpsc::channel::<Traced<ChainEvent>>(10);
 
         mock.expect_get_safety_head_ref()
             .withf(move |cid, lvl| *cid == chain_id && *lvl == SafetyLevel::CrossUnsafe)
@@ -296,7 +416,8 @@ mod tests {
         assert_eq!(result.unwrap().number, 100);
 
         // Receive and assert the correct event
-        let received_event = event_rx.recv().await.expect("expected event not received");
+        let received_event =
+            event_rx.recv().await.expect("expected event not received").into_inner();
 
         assert_eq!(received_event, ChainEvent::CrossUnsafeUpdate { block: block(100) });
     }
@@ -306,7 +427,7 @@ mod tests {
         let chain_id = 1;
         let mut mock = MockProvider::default();
         let mock_validator = MockValidator::default();
-        let (event_tx, mut event_rx) = mpsc::channel::<ChainEvent>(10);
+        let (event_tx, mut event_rx) = mpsc::channel::<Traced<ChainEvent>>(10);
 
         mock.expect_get_safety_head_ref()
             .withf(move |cid, lvl| *cid == chain_id && *lvl == SafetyLevel::CrossSafe)
@@ -350,7 +471,8 @@ mod tests {
         assert_eq!(result.unwrap().number, 100);
 
         // Receive and assert the correct event
-        let received_event = event_rx.recv().await.expect("expected event not received");
+        let received_event =
+            event_rx.recv().await.expect("expected event not received").into_inner();
 
         assert_eq!(
             received_event,
@@ -365,7 +487,7 @@ mod tests {
         let chain_id = 1;
         let mut mock = MockProvider::default();
         let mut mock_validator = MockValidator::default();
-        let (event_tx, mut event_rx) = mpsc::channel::<ChainEvent>(10);
+        let (event_tx, mut event_rx) = mpsc::channel::<Traced<ChainEvent>>(10);
 
         let exec_msg = ExecutingMessage {
             chain_id: 2,
@@ -426,7 +548,8 @@ mod tests {
         );
 
         // Receive and assert the correct event
-        let received_event = event_rx.recv().await.expect("expected event not received");
+        let received_event =
+            event_rx.recv().await.expect("expected event not received").into_inner();
 
         assert_eq!(received_event, ChainEvent::InvalidateBlock { block: block(100) });
     }
@@ -436,7 +559,7 @@ mod tests {
         let chain_id = 1;
         let mut mock = MockProvider::default();
         let mock_validator = MockValidator::default();
-        let (event_tx, _) = mpsc::channel::<ChainEvent>(10);
+        let (event_tx, _) = mpsc::channel::<Traced<ChainEvent>>(10);
 
         mock.expect_get_safety_head_ref()
             .withf(|_, lvl| *lvl == SafetyLevel::CrossSafe)
@@ -466,4 +589,89 @@ mod tests {
 
         assert!(matches!(result, Err(CrossSafetyError::NoBlockToPromote)));
     }
+
+    #[tokio::test]
+    async fn promotes_with_dependency_resolved_by_fallback() {
+        let chain_id = 1;
+        let init_chain_id = 2;
+        let mut mock = MockProvider::default();
+        let mut mock_validator = MockValidator::default();
+        let mut fallback = MockFallback::default();
+        let (event_tx, mut event_rx) = mpsc::channel::<Traced<ChainEvent>>(10);
+
+        let message = ExecutingMessage {
+            chain_id: init_chain_id,
+            block_number: 50,
+            log_index: 0,
+            timestamp: 0,
+            hash: b256(50),
+        };
+
+        mock.expect_get_safety_head_ref()
+            .withf(move |cid, lvl| *cid == chain_id && *lvl == SafetyLevel::CrossUnsafe)
+            .returning(|_, _| Ok(block(99)));
+        mock.expect_get_safety_head_ref()
+            .withf(move |cid, lvl| *cid == chain_id && *lvl == SafetyLevel::LocalUnsafe)
+            .returning(|_, _| Ok(block(100)));
+        // the initiating chain is still backfilling
+        mock.expect_get_safety_head_ref()
+            .withf(move |cid, lvl| *cid == init_chain_id && *lvl == SafetyLevel::LocalUnsafe)
+            .returning(|_, _| Ok(block(10)));
+        mock.expect_get_block()
+            .withf(move |cid, num| *cid == chain_id && *num == 100)
+            .returning(|_, _| Ok(block(100)));
+        let log = Log { index: 0, hash: b256(1), executing_message: Some(message.clone()) };
+        mock.expect_get_block_logs()
+            .withf(move |cid, num| *cid == chain_id && *num == 100)
+            .returning(move |_, _| Ok(vec![log.clone()]));
+        mock.expect_update_current_cross_unsafe()
+            .withf(move |cid, blk| *cid == chain_id && blk.number == 100)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        mock_validator.expect_validate_interop_timestamps().returning(|_, _, _, _, _| Ok(()));
+
+        let resolved = Arc::new(AtomicBool::new(false));
+        let verified = resolved.clone();
+        fallback.expect_is_verified().returning(move |_, _| verified.load(Ordering::Relaxed));
+        let expected = message.clone();
+        fallback
+            .expect_resolve()
+            .withf(move |cid, blk, messages, lvl| {
+                *cid == chain_id &&
+                    blk.number == 100 &&
+                    messages == [expected.clone()] &&
+                    *lvl == SafetyLevel::CrossUnsafe
+            })
+            .times(1)
+            .returning(move |_, _, _, _| {
+                resolved.store(true, Ordering::Relaxed);
+                Ok(())
+            });
+
+        let job = CrossSafetyCheckerJob::new(
+            chain_id,
+            Arc::new(mock),
+            CancellationToken::new(),
+            Duration::from_secs(1),
+            CrossUnsafePromoter,
+            event_tx,
+            Arc::new(mock_validator),
+        )
+        .with_fallback(Arc::new(fallback));
+        let checker = CrossSafetyChecker::new(
+            job.chain_id,
+            &*job.validator,
+            &*job.provider,
+            CrossUnsafePromoter.target_level(),
+        )
+        .with_fallback(job.fallback.as_deref().unwrap());
+
+        let result = job.try_promote_next_block(&checker).await;
+        assert_eq!(result.unwrap().number, 100);
+
+        let received_event =
+            event_rx.recv().await.expect("expected event not received").into_inner();
+        assert_eq!(received_event, ChainEvent::CrossUnsafeUpdate { block: block(100) });
+    }
 }
@@ -1,26 +1,30 @@
 use alloy_eips::BlockNumHash;
-use alloy_primitives::{B256, Bytes, ChainId, keccak256};
+use alloy_primitives::{B256, Bytes, ChainId};
 use async_trait::async_trait;
 use core::fmt::Debug;
+use futures::future::join_all;
 use kona_interop::{
     DependencySet, ExecutingDescriptor, InteropValidator, OutputRootWithChain, SUPER_ROOT_VERSION,
     SafetyLevel, SuperRoot,
 };
 use kona_protocol::BlockInfo;
-use kona_supervisor_rpc::{ChainRootInfoRpc, SuperRootOutputRpc};
+use kona_supervisor_rpc::{
+    ChainRootInfoRpc, ChainStatus, ManagedNodeHealth, MessageDependency, SuperRootOutputRpc,
+};
 use kona_supervisor_storage::{
     ChainDb, ChainDbFactory, DerivationStorageReader, FinalizedL1Storage, HeadRefStorageReader,
-    LogStorageReader,
+    LogStorageReader, StorageError,
 };
-use kona_supervisor_types::{SuperHead, parse_access_list};
+use kona_supervisor_types::{SuperHead, dedup_access_list, parse_access_list};
 use op_alloy_rpc_types::SuperchainDAError;
-use std::{collections::HashMap, sync::Arc};
-use tokio::sync::RwLock;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::{sync::RwLock, time::timeout};
 use tracing::{error, warn};
 
 use crate::{
     SpecError, SupervisorError,
     config::Config,
+    safety_checker::{message_dependencies, safety_level},
     syncnode::{BlockProvider, ManagedNodeDataProvider},
 };
 
@@ -80,6 +84,10 @@ pub trait SupervisorService: Debug + Send + Sync {
     /// Returns the [`SuperRootOutput`] at a specified timestamp, which represents the global
     /// state across all monitored chains.
     ///
+    /// The `canonical` root reported for each chain is the v0 output root of the chain's
+    /// [`OutputV0`](kona_supervisor_types::OutputV0), matching the root committed to by the super
+    /// root. Earlier versions reported `keccak256` of the JSON-encoded output instead.
+    ///
     /// [`SuperRootOutput`]: kona_interop::SuperRootOutput
     async fn super_root_at_timestamp(
         &self,
@@ -93,8 +101,21 @@ pub trait SupervisorService: Debug + Send + Sync {
         min_safety: SafetyLevel,
         executing_descriptor: ExecutingDescriptor,
     ) -> Result<(), SupervisorError>;
+
+    /// Returns the resolved dependencies of every executing message in the given block.
+    fn message_dependencies(
+        &self,
+        chain: ChainId,
+        block: BlockNumHash,
+    ) -> Result<Vec<MessageDependency>, SupervisorError>;
+
+    /// Returns the status of every supervised chain, sorted by [`ChainId`].
+    async fn chains(&self) -> Result<Vec<ChainStatus>, SupervisorError>;
 }
 
+/// Time a managed node has to answer the health probe of [`SupervisorService::chains`].
+const HEALTH_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// The core Supervisor component responsible for monitoring and coordinating chain states.
 #[derive(Debug)]
 pub struct Supervisor<M> {
@@ -147,12 +168,21 @@ where
         let head_ref = self.database_factory.get_db(chain_id)?.get_safety_head_ref(safety)?;
 
         if head_ref.number < block.number {
-            return Err(SpecError::SuperchainDAError(SuperchainDAError::ConflictingData).into());
+            let actual = safety_level(self.database_factory.as_ref(), chain_id, block)?;
+            return Err(SpecError::InsufficientSafety { required: safety, actual }.into());
         }
 
         Ok(())
     }
 
+    fn ensure_safety_level_enabled(&self, level: SafetyLevel) -> Result<(), SupervisorError> {
+        if !self.config.is_safety_level_enabled(level) {
+            warn!(target: "supervisor::service", %level, "Requested safety level is disabled");
+            return Err(SupervisorError::SafetyLevelDisabled(level));
+        }
+        Ok(())
+    }
+
     fn get_db(&self, chain: ChainId) -> Result<Arc<ChainDb>, SupervisorError> {
         self.database_factory.get_db(chain).map_err(|err| {
             error!(target: "supervisor::service", %chain, %err, "Failed to get database for chain");
@@ -208,6 +238,7 @@ where
     }
 
     fn local_unsafe(&self, chain: ChainId) -> Result<BlockInfo, SupervisorError> {
+        self.ensure_safety_level_enabled(SafetyLevel::LocalUnsafe)?;
         Ok(self.get_db(chain)?.get_safety_head_ref(SafetyLevel::LocalUnsafe).map_err(|err| {
             error!(target: "supervisor::service", %chain, %err, "Failed to get local unsafe head ref for chain");
             SpecError::from(err)
@@ -215,6 +246,7 @@ where
     }
 
     fn local_safe(&self, chain: ChainId) -> Result<BlockInfo, SupervisorError> {
+        self.ensure_safety_level_enabled(SafetyLevel::LocalSafe)?;
         Ok(self.get_db(chain)?.get_safety_head_ref(SafetyLevel::LocalSafe).map_err(|err| {
             error!(target: "supervisor::service", %chain, %err, "Failed to get local safe head ref for chain");
             SpecError::from(err)
@@ -222,6 +254,7 @@ where
     }
 
     fn cross_safe(&self, chain: ChainId) -> Result<BlockInfo, SupervisorError> {
+        self.ensure_safety_level_enabled(SafetyLevel::CrossSafe)?;
         Ok(self.get_db(chain)?.get_safety_head_ref(SafetyLevel::CrossSafe).map_err(|err| {
             error!(target: "supervisor::service", %chain, %err, "Failed to get cross safe head ref for chain");
             SpecError::from(err)
@@ -229,6 +262,7 @@ where
     }
 
     fn finalized(&self, chain: ChainId) -> Result<BlockInfo, SupervisorError> {
+        self.ensure_safety_level_enabled(SafetyLevel::Finalized)?;
         Ok(self.get_db(chain)?.get_safety_head_ref(SafetyLevel::Finalized).map_err(|err| {
             error!(target: "supervisor::service", %chain, %err, "Failed to get finalized head ref for chain");
             SpecError::from(err)
@@ -266,11 +300,7 @@ where
                 }
             };
             let output_v0 = managed_node.output_v0_at_timestamp(timestamp).await?;
-            let output_v0_string = serde_json::to_string(&output_v0)
-                .inspect_err(|err| {
-                    error!(target: "supervisor::service", chain_id = %id, %err, "Failed to serialize output_v0 for chain");
-                })?;
-            let canonical_root = keccak256(output_v0_string.as_bytes());
+            let canonical_root = output_v0.hash();
 
             let pending_output_v0 = managed_node.pending_output_v0_at_timestamp(timestamp).await?;
             let pending_output_v0_string = serde_json::to_string(&pending_output_v0)
@@ -319,7 +349,11 @@ where
         min_safety: SafetyLevel,
         executing_descriptor: ExecutingDescriptor,
     ) -> Result<(), SupervisorError> {
-        let access_list = parse_access_list(inbox_entries)?;
+        self.ensure_safety_level_enabled(min_safety)?;
+        let (access_list, duplicates) = dedup_access_list(parse_access_list(inbox_entries)?);
+        if duplicates > 0 {
+            warn!(target: "supervisor::service", %duplicates, "Access list references the same message more than once");
+        }
 
         for access in &access_list {
             // Check all the invariants for each message
@@ -335,6 +369,11 @@ where
                     SupervisorError::ChainIdParseError()
                 })?;
 
+            if !self.config.dependency_set.dependencies.contains_key(&initiating_chain_id) {
+                warn!(target: "supervisor::service", %initiating_chain_id, "Initiating chain is not in the dependency set");
+                return Err(SpecError::UnknownChain.into());
+            }
+
             let executing_chain_id = executing_descriptor.chain_id.unwrap_or(initiating_chain_id);
 
             // Message must be valid at the time of execution.
@@ -346,7 +385,7 @@ where
                 executing_descriptor.timeout,
             ).map_err(|err| {
                 warn!(target: "supervisor::service", %err, "Failed to validate interop timestamps");
-                SpecError::SuperchainDAError(SuperchainDAError::ConflictingData)
+                SpecError::from(err)
             })?;
 
             // Verify the initiating message exists and valid for corresponding executing message.
@@ -354,7 +393,7 @@ where
 
             let block = db.get_block(access.block_number).map_err(|err| {
                 warn!(target: "supervisor::service", %initiating_chain_id, %err, "Failed to get block for chain");
-                SpecError::from(err)
+                initiating_message_error(err)
             })?;
             if block.timestamp != access.timestamp {
                 return Err(SupervisorError::from(SpecError::SuperchainDAError(
@@ -364,7 +403,7 @@ where
 
             let log = db.get_log(access.block_number, access.log_index).map_err(|err| {
                 warn!(target: "supervisor::service", %initiating_chain_id, %err, "Failed to get log for chain");
-                SpecError::from(err)
+                initiating_message_error(err)
             })?;
             access.verify_checksum(&log.hash).map_err(|err| {
                 warn!(target: "supervisor::service", %initiating_chain_id, %err, "Failed to verify checksum for access list");
@@ -381,4 +420,300 @@ where
 
         Ok(())
     }
+
+    fn message_dependencies(
+        &self,
+        chain: ChainId,
+        block: BlockNumHash,
+    ) -> Result<Vec<MessageDependency>, SupervisorError> {
+        let indexed = self.get_db(chain)?.get_block(block.number).map_err(SpecError::from)?;
+        if indexed.hash != block.hash {
+            warn!(target: "supervisor::service", %chain, block_number = block.number, "Block hash does not match indexed block");
+            return Err(SpecError::SuperchainDAError(SuperchainDAError::ConflictingData).into());
+        }
+
+        message_dependencies(self.database_factory.as_ref(), chain, block.number).map_err(|err| {
+            error!(target: "supervisor::service", %chain, %err, "Failed to resolve message dependencies");
+            SpecError::from(err).into()
+        })
+    }
+
+    async fn chains(&self) -> Result<Vec<ChainStatus>, SupervisorError> {
+        let mut chain_ids = self.chain_ids().collect::<Vec<_>>();
+        chain_ids.sort_unstable();
+
+        let mut heads = Vec::with_capacity(chain_ids.len());
+        for chain_id in chain_ids {
+            match self.super_head(chain_id) {
+                Ok(head) => heads.push((chain_id, Some(head))),
+                // database isn't initialised yet
+                Err(SupervisorError::SpecError(SpecError::ErrorNotInSpec)) => {
+                    heads.push((chain_id, None))
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        // probe all managed nodes at once, so one slow node doesn't hold up the others
+        let managed_nodes = self.managed_nodes.read().await.clone();
+        let probes = heads.iter().map(|(chain_id, head)| {
+            let node = managed_nodes.get(chain_id).cloned();
+            let probe = head.map_or(0, |head| head.local_unsafe.number);
+            async move {
+                let Some(node) = node else { return ManagedNodeHealth::Missing };
+                match timeout(HEALTH_PROBE_TIMEOUT, node.block_by_number(probe)).await {
+                    Ok(Ok(_)) => ManagedNodeHealth::Healthy,
+                    Ok(Err(err)) => {
+                        warn!(target: "supervisor::service", %chain_id, %err, "Managed node failed health probe");
+                        ManagedNodeHealth::Unreachable
+                    }
+                    Err(_) => {
+                        warn!(target: "supervisor::service", %chain_id, "Managed node health probe timed out");
+                        ManagedNodeHealth::Unreachable
+                    }
+                }
+            }
+        });
+        let health = join_all(probes).await;
+
+        let mut chains = Vec::with_capacity(heads.len());
+        for ((chain_id, head), managed_node) in heads.into_iter().zip(health) {
+            // disabled levels never advance, so they don't count as lagging
+            let synced = managed_node == ManagedNodeHealth::Healthy &&
+                head.is_some_and(|head| {
+                    (!self.config.is_safety_level_enabled(SafetyLevel::CrossUnsafe) ||
+                        head.cross_unsafe == Some(head.local_unsafe)) &&
+                        (!self.config.is_safety_level_enabled(SafetyLevel::CrossSafe) ||
+                            head.cross_safe == head.local_safe)
+                });
+
+            chains.push(ChainStatus { chain_id, head: head.map(Into::into), managed_node, synced });
+        }
+        Ok(chains)
+    }
+}
+
+/// Maps a failed lookup of an initiating message to a [`SpecError`].
+fn initiating_message_error(err: StorageError) -> SpecError {
+    match err {
+        StorageError::EntryNotFound(_) => SpecError::InitiatingMessageNotFound,
+        err => SpecError::from(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::RollupConfigSet, syncnode::ManagedNodeError};
+    use kona_interop::{ChainDependency, DerivedRefPair};
+    use kona_supervisor_storage::{DerivationStorageWriter, LogStorageWriter};
+    use kona_supervisor_types::{OutputV0, Receipts};
+    use mockall::mock;
+    use std::{net::SocketAddr, path::PathBuf};
+    use tempfile::TempDir;
+
+    mock!(
+        #[derive(Debug)]
+        pub Node {}
+
+        #[async_trait]
+        impl BlockProvider for Node {
+            async fn fetch_receipts(&self, _block_hash: B256) -> Result<Receipts, ManagedNodeError>;
+            async fn block_by_number(&self, _number: u64) -> Result<BlockInfo, ManagedNodeError>;
+        }
+
+        #[async_trait]
+        impl ManagedNodeDataProvider for Node {
+            async fn output_v0_at_timestamp(
+                &self,
+                _timestamp: u64,
+            ) -> Result<OutputV0, ManagedNodeError>;
+
+            async fn pending_output_v0_at_timestamp(
+                &self,
+                _timestamp: u64,
+            ) -> Result<OutputV0, ManagedNodeError>;
+
+            async fn l2_block_ref_by_timestamp(
+                &self,
+                _timestamp: u64,
+            ) -> Result<BlockInfo, ManagedNodeError>;
+        }
+    );
+
+    fn config(chain_ids: &[ChainId]) -> Config {
+        Config {
+            l1_rpc: Default::default(),
+            l2_consensus_nodes_config: vec![],
+            datadir: PathBuf::new(),
+            rpc_addr: SocketAddr::from(([127, 0, 0, 1], 8545)),
+            rpc_max_request_body_size: Config::DEFAULT_RPC_MAX_REQUEST_BODY_SIZE,
+            rpc_max_batch_len: Config::DEFAULT_RPC_MAX_BATCH_LEN,
+            enable_admin_api: false,
+            dependency_set: DependencySet {
+                dependencies: chain_ids.iter().map(|id| (*id, ChainDependency {})).collect(),
+                override_message_expiry_window: None,
+            },
+            rollup_config_set: RollupConfigSet::default(),
+            event_channel_capacities: HashMap::new(),
+            dependency_fallback_rpc: None,
+            log_indexer_concurrency: 1,
+            disabled_safety_levels: vec![],
+            storage_table_metrics: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chains_reports_status_of_two_chains() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let factory = Arc::new(ChainDbFactory::new(tmp_dir.path().to_path_buf()));
+
+        let block = |number: u64, hash: u8, parent_hash: B256| BlockInfo {
+            hash: B256::from([hash; 32]),
+            number,
+            parent_hash,
+            timestamp: 100 + number,
+        };
+        let l1_block = block(10, 0xaa, B256::ZERO);
+
+        // chain 1 is fully synced
+        let anchor_1 = block(0, 1, B256::ZERO);
+        let db_1 = factory.get_or_create_db(1).expect("create chain 1 db");
+        db_1.initialise_log_storage(anchor_1).expect("initialise chain 1 logs");
+        db_1.initialise_derivation_storage(DerivedRefPair { source: l1_block, derived: anchor_1 })
+            .expect("initialise chain 1 derivation");
+
+        // chain 2 has a local unsafe block that isn't cross unsafe yet
+        let anchor_2 = block(0, 2, B256::ZERO);
+        let block_2 = block(1, 3, anchor_2.hash);
+        let db_2 = factory.get_or_create_db(2).expect("create chain 2 db");
+        db_2.initialise_log_storage(anchor_2).expect("initialise chain 2 logs");
+        db_2.initialise_derivation_storage(DerivedRefPair { source: l1_block, derived: anchor_2 })
+            .expect("initialise chain 2 derivation");
+        db_2.store_block_logs(&block_2, vec![]).expect("store chain 2 logs");
+
+        let supervisor = Supervisor::<MockNode>::new(Arc::new(config(&[2, 1])), factory);
+
+        let mut node_1 = MockNode::new();
+        node_1.expect_block_by_number().returning(move |_| Ok(anchor_1));
+        supervisor.add_managed_node(1, Arc::new(node_1)).await.unwrap();
+
+        let mut node_2 = MockNode::new();
+        node_2.expect_block_by_number().returning(|_| Err(ManagedNodeError::Timeout));
+        supervisor.add_managed_node(2, Arc::new(node_2)).await.unwrap();
+
+        let chains = supervisor.chains().await.unwrap();
+        assert_eq!(chains.len(), 2);
+
+        assert_eq!(chains[0].chain_id, 1);
+        assert_eq!(chains[0].managed_node, ManagedNodeHealth::Healthy);
+        assert!(chains[0].synced);
+        let head_1 = chains[0].head.expect("chain 1 head");
+        assert_eq!(head_1.local_unsafe, anchor_1);
+        assert_eq!(head_1.cross_safe, anchor_1.id());
+
+        assert_eq!(chains[1].chain_id, 2);
+        assert_eq!(chains[1].managed_node, ManagedNodeHealth::Unreachable);
+        assert!(!chains[1].synced);
+        let head_2 = chains[1].head.expect("chain 2 head");
+        assert_eq!(head_2.local_unsafe, block_2);
+        assert_eq!(head_2.cross_unsafe, anchor_2.id());
+    }
+
+    #[tokio::test]
+    async fn test_chains_without_managed_node() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let factory = Arc::new(ChainDbFactory::new(tmp_dir.path().to_path_buf()));
+        let supervisor = Supervisor::<MockNode>::new(Arc::new(config(&[1])), factory);
+
+        let chains = supervisor.chains().await.unwrap();
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].head, None);
+        assert_eq!(chains[0].managed_node, ManagedNodeHealth::Missing);
+        assert!(!chains[0].synced);
+    }
+
+    /// Managed node that never answers.
+    #[derive(Debug)]
+    struct HangingNode;
+
+    #[async_trait]
+    impl BlockProvider for HangingNode {
+        async fn fetch_receipts(&self, _block_hash: B256) -> Result<Receipts, ManagedNodeError> {
+            std::future::pending().await
+        }
+
+        async fn block_by_number(&self, _number: u64) -> Result<BlockInfo, ManagedNodeError> {
+            std::future::pending().await
+        }
+    }
+
+    #[async_trait]
+    impl ManagedNodeDataProvider for HangingNode {
+        async fn output_v0_at_timestamp(
+            &self,
+            _timestamp: u64,
+        ) -> Result<OutputV0, ManagedNodeError> {
+            std::future::pending().await
+        }
+
+        async fn pending_output_v0_at_timestamp(
+            &self,
+            _timestamp: u64,
+        ) -> Result<OutputV0, ManagedNodeError> {
+            std::future::pending().await
+        }
+
+        async fn l2_block_ref_by_timestamp(
+            &self,
+            _timestamp: u64,
+        ) -> Result<BlockInfo, ManagedNodeError> {
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_chains_probes_managed_nodes_concurrently_with_timeout() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let factory = Arc::new(ChainDbFactory::new(tmp_dir.path().to_path_buf()));
+        let supervisor = Supervisor::<HangingNode>::new(Arc::new(config(&[1, 2])), factory);
+        supervisor.add_managed_node(1, Arc::new(HangingNode)).await.unwrap();
+        supervisor.add_managed_node(2, Arc::new(HangingNode)).await.unwrap();
+
+        let start = tokio::time::Instant::now();
+        let chains = supervisor.chains().await.unwrap();
+
+        // both probes time out together rather than one after the other
+        assert_eq!(start.elapsed(), HEALTH_PROBE_TIMEOUT);
+        assert_eq!(chains.len(), 2);
+        assert!(chains.iter().all(|chain| chain.managed_node == ManagedNodeHealth::Unreachable));
+        assert!(chains.iter().all(|chain| !chain.synced));
+    }
+
+    #[test]
+    fn test_disabled_safety_level_is_unsupported() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let factory = Arc::new(ChainDbFactory::new(tmp_dir.path().to_path_buf()));
+        let config = Config {
+            disabled_safety_levels: vec![SafetyLevel::CrossUnsafe, SafetyLevel::Finalized],
+            ..config(&[1])
+        };
+        let supervisor = Supervisor::<MockNode>::new(Arc::new(config), factory);
+
+        assert_eq!(
+            supervisor.finalized(1).unwrap_err(),
+            SupervisorError::SafetyLevelDisabled(SafetyLevel::Finalized)
+        );
+        assert_eq!(
+            supervisor
+                .check_access_list(vec![], SafetyLevel::CrossUnsafe, ExecutingDescriptor::default())
+                .unwrap_err(),
+            SupervisorError::SafetyLevelDisabled(SafetyLevel::CrossUnsafe)
+        );
+        assert!(
+            supervisor
+                .check_access_list(vec![], SafetyLevel::CrossSafe, ExecutingDescriptor::default())
+                .is_ok()
+        );
+    }
 }
@@ -4,6 +4,7 @@ use alloy_rpc_types_engine::{Claims, JwtSecret};
 use alloy_rpc_types_eth::BlockNumHash;
 use async_trait::async_trait;
 use jsonrpsee::{
+    client_transport::ws::WsHandshakeError,
     core::client::Subscription,
     ws_client::{HeaderMap, HeaderValue, WsClient, WsClientBuilder},
 };
@@ -11,11 +12,12 @@ use kona_supervisor_metrics::observe_metrics_for_result_async;
 use kona_supervisor_rpc::{BlockInfo, ManagedModeApiClient, jsonrpsee::SubscriptionTopic};
 use kona_supervisor_types::{BlockSeal, OutputV0, Receipts, SubscriptionEvent};
 use std::{
-    fmt::Debug,
-    sync::{Arc, OnceLock},
+    fmt::{self, Debug},
+    future::Future,
+    sync::{Arc, OnceLock, PoisonError, RwLock},
 };
 use tokio::sync::Mutex;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// Trait for a managed node client that provides various methods to interact with the node.
 #[async_trait]
@@ -81,6 +83,27 @@ pub trait ManagedNodeClient: Send + Sync + Debug {
     async fn reset_ws_client(&self);
 }
 
+/// Closure returning the current JWT secret of a managed node, used to refresh an expired token.
+///
+/// The closure may block, e.g. to read the secret from disk: it runs on the blocking thread pool.
+#[derive(Clone)]
+pub struct JwtRefresh(Arc<dyn Fn() -> Result<JwtSecret, AuthenticationError> + Send + Sync>);
+
+impl JwtRefresh {
+    /// Creates a new [`JwtRefresh`] from the given closure.
+    pub fn new(
+        refresh: impl Fn() -> Result<JwtSecret, AuthenticationError> + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(refresh))
+    }
+}
+
+impl Debug for JwtRefresh {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("JwtRefresh(..)")
+    }
+}
+
 /// [`ClientConfig`] sets the configuration for the managed node client.
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
@@ -88,12 +111,16 @@ pub struct ClientConfig {
     pub url: String,
     /// jwt secret for the managed node interop rpc
     pub jwt_secret: JwtSecret,
+    /// Refreshes the jwt secret when the managed node rejects the token as expired
+    pub jwt_refresh: Option<JwtRefresh>,
 }
 
 /// Client for interacting with a managed node.
 #[derive(Debug)]
 pub struct Client {
     config: ClientConfig,
+    /// Current jwt secret, replaced when the token is refreshed
+    jwt_secret: RwLock<JwtSecret>,
     /// Chain ID of the managed node
     chain_id: OnceLock<ChainId>,
     /// The attached web socket client
@@ -104,14 +131,52 @@ impl Client {
     /// Creates a new [`Client`] with the given configuration.
     pub fn new(config: ClientConfig) -> Self {
         Metrics::init(config.url.as_ref());
-        Self { config, chain_id: OnceLock::new(), ws_client: Mutex::new(None) }
+        Self {
+            jwt_secret: RwLock::new(config.jwt_secret),
+            config,
+            chain_id: OnceLock::new(),
+            ws_client: Mutex::new(None),
+        }
+    }
+
+    /// Runs the request, and if the managed node rejects the token as expired, refreshes the jwt
+    /// secret and retries the request once before propagating the error.
+    async fn with_token_refresh<T, F, Fut>(&self, request: F) -> Result<T, ClientError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, ClientError>>,
+    {
+        match request().await {
+            Err(ClientError::Authentication(AuthenticationError::Expired)) => {
+                let Some(refresh) = &self.config.jwt_refresh else {
+                    return Err(AuthenticationError::Expired.into());
+                };
+
+                warn!(target: "supervisor::managed_node", url = self.config.url, "JWT token expired, refreshing the secret");
+                let refresh = refresh.0.clone();
+                let secret = tokio::task::spawn_blocking(move || refresh())
+                    .await
+                    .unwrap_or_else(|err| {
+                        error!(target: "supervisor::managed_node", %err, "JWT secret refresh task failed");
+                        Err(AuthenticationError::InvalidJwt)
+                    })
+                    .inspect_err(|err| {
+                        error!(target: "supervisor::managed_node", %err, "Failed to refresh JWT secret");
+                    })?;
+                *self.jwt_secret.write().unwrap_or_else(PoisonError::into_inner) = secret;
+
+                request().await
+            }
+            result => result,
+        }
     }
 
     /// Creates authentication headers using JWT secret.
     fn create_auth_headers(&self) -> Result<HeaderMap, ClientError> {
         // Create JWT claims with current time
         let claims = Claims::with_current_timestamp();
-        let token = self.config.jwt_secret.encode(&claims).map_err(|err| {
+        let jwt_secret = *self.jwt_secret.read().unwrap_or_else(PoisonError::into_inner);
+        let token = jwt_secret.encode(&claims).map_err(|err| {
             error!(target: "supervisor::managed_node", %err, "Failed to encode JWT claims");
             AuthenticationError::InvalidJwt
         })?;
@@ -135,18 +200,41 @@ impl Client {
     pub async fn get_ws_client(&self) -> Result<Arc<WsClient>, ClientError> {
         let mut ws_client_guard = self.ws_client.lock().await;
         if ws_client_guard.is_none() {
-            let headers = self.create_auth_headers().inspect_err(|err| {
-                error!(target: "supervisor::managed_node", %err, "Failed to create auth headers");
-            })?;
-
-            info!(target: "supervisor::managed_node", ws_url = self.config.url, "Creating a new web socket client");
-            let client =
-                WsClientBuilder::default().set_headers(headers).build(&self.config.url).await?;
-
+            let client = self.with_token_refresh(|| self.connect()).await?;
             *ws_client_guard = Some(Arc::new(client));
         }
         Ok(ws_client_guard.clone().unwrap())
     }
+
+    /// Opens a new authenticated web socket connection to the managed node.
+    async fn connect(&self) -> Result<WsClient, ClientError> {
+        let headers = self.create_auth_headers().inspect_err(|err| {
+            error!(target: "supervisor::managed_node", %err, "Failed to create auth headers");
+        })?;
+
+        info!(target: "supervisor::managed_node", ws_url = self.config.url, "Creating a new web socket client");
+        WsClientBuilder::default().set_headers(headers).build(&self.config.url).await.map_err(
+            |err| {
+                if is_unauthorized(&err) {
+                    return AuthenticationError::Expired.into();
+                }
+                err.into()
+            },
+        )
+    }
+}
+
+/// Returns `true` if the managed node rejected the web socket handshake as unauthorized.
+fn is_unauthorized(err: &jsonrpsee::core::ClientError) -> bool {
+    // the handshake error is boxed as a transport error
+    matches!(
+        err,
+        jsonrpsee::core::ClientError::Transport(err)
+            if matches!(
+                err.downcast_ref::<WsHandshakeError>(),
+                Some(WsHandshakeError::Rejected { status_code: 401 })
+            )
+    )
 }
 
 #[async_trait]
@@ -404,3 +492,84 @@ impl ManagedNodeClient for Client {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn client(jwt_refresh: Option<JwtRefresh>) -> Client {
+        Client::new(ClientConfig {
+            url: "ws://localhost:8551".to_string(),
+            jwt_secret: JwtSecret::from_hex("01".repeat(32)).unwrap(),
+            jwt_refresh,
+        })
+    }
+
+    #[test]
+    fn test_is_unauthorized() {
+        let rejected = |status_code| {
+            jsonrpsee::core::ClientError::Transport(
+                WsHandshakeError::Rejected { status_code }.into(),
+            )
+        };
+
+        assert!(is_unauthorized(&rejected(401)));
+        assert!(!is_unauthorized(&rejected(403)));
+        assert!(!is_unauthorized(&jsonrpsee::core::ClientError::Transport(
+            "status code: 401".into()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_is_refreshed_and_request_retried() {
+        let refreshed = JwtSecret::from_hex("02".repeat(32)).unwrap();
+        let client = client(Some(JwtRefresh::new(move || Ok(refreshed))));
+
+        // the first call is rejected with the stale secret, the retried one succeeds
+        let calls = AtomicUsize::new(0);
+        let result = client
+            .with_token_refresh(|| async {
+                if calls.fetch_add(1, Ordering::Relaxed) == 0 {
+                    return Err(AuthenticationError::Expired.into());
+                }
+                Ok(*client.jwt_secret.read().unwrap())
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), refreshed);
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_is_retried_once() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let counter = refreshes.clone();
+        let client = client(Some(JwtRefresh::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            JwtSecret::from_hex("02".repeat(32)).map_err(|_| AuthenticationError::InvalidJwt)
+        })));
+
+        let result: Result<(), _> =
+            client.with_token_refresh(|| async { Err(AuthenticationError::Expired.into()) }).await;
+
+        assert_eq!(result, Err(ClientError::Authentication(AuthenticationError::Expired)));
+        assert_eq!(refreshes.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_without_refresh_is_propagated() {
+        let client = client(None);
+
+        let calls = AtomicUsize::new(0);
+        let result: Result<(), _> = client
+            .with_token_refresh(|| async {
+                calls.fetch_add(1, Ordering::Relaxed);
+                Err(AuthenticationError::Expired.into())
+            })
+            .await;
+
+        assert_eq!(result, Err(ClientError::Authentication(AuthenticationError::Expired)));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+}
@@ -1,9 +1,11 @@
+use super::{ManagedNodeError, ResetCause};
 use alloy_eips::BlockNumHash;
-use kona_supervisor_types::BlockSeal;
+use kona_supervisor_types::{BlockSeal, Receipts};
+use tokio::sync::oneshot;
 
 /// Commands for managing a node in the supervisor.
 /// These commands are sent to the managed node actor to perform various operations.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub enum ManagedNodeCommand {
     /// Updates the finalized block in the managed node.
     UpdateFinalized {
@@ -26,11 +28,22 @@ pub enum ManagedNodeCommand {
     },
 
     /// Resets the managed node.
-    Reset {},
+    Reset {
+        /// The [`ResetCause`] of the reset.
+        cause: ResetCause,
+    },
 
     /// Asks managed node to invalidate the block.
     InvalidateBlock {
         /// [`BlockSeal`] of the block to invalidate.
         seal: BlockSeal,
     },
+
+    /// Fetches the receipts of a block from the managed node.
+    FetchReceipts {
+        /// [`BlockNumHash`] of the block.
+        block_id: BlockNumHash,
+        /// Channel to send the receipts, or the error, back on.
+        resp: oneshot::Sender<Result<Receipts, ManagedNodeError>>,
+    },
 }
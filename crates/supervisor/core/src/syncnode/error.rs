@@ -1,4 +1,5 @@
 use kona_supervisor_storage::StorageError;
+use kona_supervisor_types::SafetyLevelUpdate;
 use thiserror::Error;
 
 /// Represents various errors that can occur during node management.
@@ -23,6 +24,14 @@ pub enum ManagedNodeError {
     /// Represents an error that occurred while resetting the managed node.
     #[error("failed to reset the managed node")]
     ResetFailed,
+
+    /// The safety level update can not be applied through the managed node controller.
+    #[error("unsupported safety level update: {0:?}")]
+    UnsupportedSafetyLevelUpdate(SafetyLevelUpdate),
+
+    /// The managed node did not respond in time.
+    #[error("managed node request timed out")]
+    Timeout,
 }
 
 /// Error establishing authenticated connection to managed node.
@@ -34,6 +43,9 @@ pub enum AuthenticationError {
     /// Invalid header format.
     #[error("invalid authorization header")]
     InvalidHeader,
+    /// The managed node rejected the token, e.g. because its JWT secret was rotated.
+    #[error("jwt token expired")]
+    Expired,
 }
 
 /// Represents errors that can occur while interacting with the managed node client.
@@ -1,5 +1,8 @@
 //! Metrics for the Managed Mode RPC client.
 
+use super::ResetCause;
+use alloy_primitives::ChainId;
+
 /// Container for metrics.
 #[derive(Debug, Clone)]
 pub(super) struct Metrics;
@@ -15,6 +18,8 @@ impl Metrics {
     /// Identifier for the histogram of RPC request durations. Labels: `method`.
     pub(crate) const MANAGED_NODE_RPC_REQUEST_DURATION_SECONDS: &'static str =
         "managed_node_rpc_request_duration_seconds";
+    /// Identifier for the counter of managed node resets. Labels: `chain_id`, `cause`.
+    pub(crate) const MANAGED_NODE_RESETS_TOTAL: &'static str = "managed_node_resets_total";
 
     pub(crate) const RPC_METHOD_CHAIN_ID: &'static str = "chain_id";
     pub(crate) const RPC_METHOD_SUBSCRIBE_EVENTS: &'static str = "subscribe_events";
@@ -60,6 +65,11 @@ impl Metrics {
             metrics::Unit::Seconds,
             "Duration of RPC requests processed by the managed mode client"
         );
+        metrics::describe_counter!(
+            Self::MANAGED_NODE_RESETS_TOTAL,
+            metrics::Unit::Count,
+            "Total number of managed node resets by cause"
+        );
     }
 
     fn zero_rpc_method(method: &str, node: &str) {
@@ -83,6 +93,16 @@ impl Metrics {
         .record(0.0);
     }
 
+    /// Records a reset of the managed node of the given chain.
+    pub(crate) fn record_reset(chain_id: ChainId, cause: ResetCause) {
+        metrics::counter!(
+            Self::MANAGED_NODE_RESETS_TOTAL,
+            "chain_id" => chain_id.to_string(),
+            "cause" => cause.as_str()
+        )
+        .increment(1);
+    }
+
     /// Initializes metrics with their labels to `0` so they appear in Prometheus from the start.
     fn zero(node: &str) {
         Self::zero_rpc_method(Self::RPC_METHOD_CHAIN_ID, node);
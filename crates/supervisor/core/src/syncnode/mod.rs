@@ -17,7 +17,8 @@ pub use traits::{
 };
 
 mod client;
-pub use client::{Client, ClientConfig, ManagedNodeClient};
+pub use client::{Client, ClientConfig, JwtRefresh, ManagedNodeClient};
 
 pub(super) mod metrics;
 pub(super) mod resetter;
+pub use resetter::ResetCause;
@@ -1,10 +1,10 @@
 //! [`ManagedNode`] implementation for subscribing to the events from managed node.
 
 use super::{
-    BlockProvider, ManagedNodeClient, ManagedNodeController, ManagedNodeDataProvider,
-    ManagedNodeError, SubscriptionHandler, resetter::Resetter,
+    BlockProvider, ClientError, ManagedNodeClient, ManagedNodeController, ManagedNodeDataProvider,
+    ManagedNodeError, ResetCause, SubscriptionHandler, resetter::Resetter,
 };
-use crate::event::ChainEvent;
+use crate::event::{ChainEvent, Traced};
 use alloy_eips::BlockNumberOrTag;
 use alloy_network::Ethereum;
 use alloy_primitives::{B256, ChainId};
@@ -15,7 +15,7 @@ use kona_interop::{BlockReplacement, DerivedRefPair};
 use kona_protocol::BlockInfo;
 use kona_supervisor_storage::{DerivationStorageReader, HeadRefStorageReader, LogStorageReader};
 use kona_supervisor_types::{BlockSeal, OutputV0, Receipts};
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tokio::sync::{Mutex, mpsc};
 use tracing::{debug, error, trace, warn};
 
@@ -31,7 +31,7 @@ pub struct ManagedNode<DB, C> {
     /// Resetter for handling node resets
     resetter: Arc<Resetter<DB, C>>,
     /// Channel for sending events to the chain processor
-    chain_event_sender: mpsc::Sender<ChainEvent>,
+    chain_event_sender: mpsc::Sender<Traced<ChainEvent>>,
 
     /// Cached chain ID
     chain_id: Mutex<Option<ChainId>>,
@@ -42,12 +42,15 @@ where
     DB: LogStorageReader + DerivationStorageReader + HeadRefStorageReader + Send + Sync + 'static,
     C: ManagedNodeClient + Send + Sync + 'static,
 {
+    /// Maximum time to wait for the managed node to return the receipts of a block.
+    pub const FETCH_RECEIPTS_TIMEOUT: Duration = Duration::from_secs(10);
+
     /// Creates a new [`ManagedNode`] with the specified client.
     pub fn new(
         client: Arc<C>,
         db_provider: Arc<DB>,
         l1_provider: RootProvider<Ethereum>,
-        chain_event_sender: mpsc::Sender<ChainEvent>,
+        chain_event_sender: mpsc::Sender<Traced<ChainEvent>>,
     ) -> Self {
         let resetter = Arc::new(Resetter::new(client.clone(), l1_provider.clone(), db_provider));
 
@@ -144,7 +147,7 @@ where
         let chain_id = self.chain_id().await?;
         trace!(target: "supervisor::managed_node", %chain_id, reset_id, "Handling reset event");
 
-        self.resetter.reset().await?;
+        self.resetter.reset(ResetCause::ProtocolError).await?;
         Ok(())
     }
 
@@ -152,7 +155,7 @@ where
         let chain_id = self.chain_id().await?;
         trace!(target: "supervisor::managed_node", %chain_id, %unsafe_block, "Unsafe block event received");
 
-        self.chain_event_sender.send(ChainEvent::UnsafeBlock { block: *unsafe_block }).await.map_err(|err| {
+        self.chain_event_sender.send(Traced::new(ChainEvent::UnsafeBlock { block: *unsafe_block })).await.map_err(|err| {
             warn!(target: "supervisor::managed_node", %chain_id, %err, "Failed to send unsafe block event");
             ManagedNodeError::ChannelSendFailed(err.to_string())
         })?;
@@ -166,7 +169,7 @@ where
         let chain_id = self.chain_id().await?;
         trace!(target: "supervisor::managed_node", %chain_id, "Derivation update event received");
 
-        self.chain_event_sender.send(ChainEvent::DerivedBlock { derived_ref_pair: *derived_ref_pair }).await.map_err(|err| {
+        self.chain_event_sender.send(Traced::new(ChainEvent::DerivedBlock { derived_ref_pair: *derived_ref_pair })).await.map_err(|err| {
             warn!(target: "supervisor::managed_node", %chain_id, %err, "Failed to send derivation update event");
             ManagedNodeError::ChannelSendFailed(err.to_string())
         })?;
@@ -180,7 +183,7 @@ where
         let chain_id = self.chain_id().await?;
         trace!(target: "supervisor::managed_node", %chain_id, %replacement, "Block replacement received");
 
-        self.chain_event_sender.send(ChainEvent::BlockReplaced { replacement: *replacement }).await.map_err(|err| {
+        self.chain_event_sender.send(Traced::new(ChainEvent::BlockReplaced { replacement: *replacement })).await.map_err(|err| {
             warn!(target: "supervisor::managed_node", %chain_id, %err, "Failed to send block replacement event");
             ManagedNodeError::ChannelSendFailed(err.to_string())
         })?;
@@ -194,7 +197,7 @@ where
         let chain_id = self.chain_id().await?;
         trace!(target: "supervisor::managed_node", %chain_id, %origin, "Derivation origin update received");
 
-        self.chain_event_sender.send(ChainEvent::DerivationOriginUpdate { origin: *origin }).await.map_err(|err| {
+        self.chain_event_sender.send(Traced::new(ChainEvent::DerivationOriginUpdate { origin: *origin })).await.map_err(|err| {
             warn!(target: "supervisor::managed_node", %chain_id, %err, "Failed to send derivation origin update event");
             ManagedNodeError::ChannelSendFailed(err.to_string())
         })?;
@@ -318,11 +321,11 @@ where
         Ok(())
     }
 
-    async fn reset(&self) -> Result<(), ManagedNodeError> {
+    async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError> {
         let chain_id = self.chain_id().await?;
-        trace!(target: "supervisor::managed_node", %chain_id, "Resetting managed node state");
+        trace!(target: "supervisor::managed_node", %chain_id, %cause, "Resetting managed node state");
 
-        self.resetter.reset().await?;
+        self.resetter.reset(cause).await?;
         Ok(())
     }
 
@@ -338,12 +341,39 @@ where
         self.client.invalidate_block(block_seal).await?;
         Ok(())
     }
+
+    async fn fetch_block_receipts(
+        &self,
+        block_id: BlockNumHash,
+    ) -> Result<Receipts, ManagedNodeError> {
+        let chain_id = self.chain_id().await?;
+        trace!(
+            target: "supervisor::managed_node",
+            %chain_id,
+            block_number = block_id.number,
+            "Fetching block receipts"
+        );
+
+        with_timeout(Self::FETCH_RECEIPTS_TIMEOUT, self.client.fetch_receipts(block_id.hash)).await
+    }
+}
+
+/// Awaits a request to the managed node, failing with [`ManagedNodeError::Timeout`] if it does
+/// not complete within `timeout`.
+async fn with_timeout<T>(
+    timeout: Duration,
+    request: impl Future<Output = Result<T, ClientError>>,
+) -> Result<T, ManagedNodeError> {
+    tokio::time::timeout(timeout, request)
+        .await
+        .map_err(|_| ManagedNodeError::Timeout)?
+        .map_err(Into::into)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::syncnode::ClientError;
+    use crate::syncnode::{ClientError, metrics::Metrics};
     use alloy_primitives::{B256, ChainId, hex::FromHex};
     use alloy_provider::RootProvider;
     use alloy_rpc_client::RpcClient;
@@ -354,9 +384,12 @@ mod tests {
     use kona_supervisor_storage::{
         DerivationStorageReader, HeadRefStorageReader, LogStorageReader, StorageError,
     };
-    use kona_supervisor_types::{BlockSeal, Log, OutputV0, Receipts, SubscriptionEvent, SuperHead};
+    use kona_supervisor_types::{
+        BlockSeal, Log, OutputV0, Receipts, SafetyLevelUpdate, SubscriptionEvent, SuperHead,
+    };
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
     use mockall::{mock, predicate::*};
-    use std::sync::Arc;
+    use std::{collections::HashMap, sync::Arc};
     use tokio::sync::mpsc;
 
     mock! {
@@ -450,7 +483,7 @@ mod tests {
         let result = node.handle_unsafe_block(&unsafe_block).await;
         assert!(result.is_ok());
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().into_inner();
         match event {
             ChainEvent::UnsafeBlock { block } => assert_eq!(block.number, 1),
             _ => panic!("Wrong event"),
@@ -477,7 +510,7 @@ mod tests {
         let result = node.handle_derivation_update(&derived_ref_pair).await;
         assert!(result.is_ok());
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().into_inner();
         match event {
             ChainEvent::DerivedBlock { derived_ref_pair: pair } => {
                 assert_eq!(pair, derived_ref_pair);
@@ -506,7 +539,7 @@ mod tests {
         let result = node.handle_replace_block(&replacement).await;
         assert!(result.is_ok());
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().into_inner();
         match event {
             ChainEvent::BlockReplaced { replacement: rep } => assert_eq!(rep, replacement),
             _ => panic!("Wrong event"),
@@ -531,7 +564,7 @@ mod tests {
         let result = node.handle_derivation_origin_update(&origin).await;
         assert!(result.is_ok());
 
-        let event = rx.recv().await.unwrap();
+        let event = rx.recv().await.unwrap().into_inner();
         match event {
             ChainEvent::DerivationOriginUpdate { origin: block } => assert_eq!(block.number, 10),
             _ => panic!("Wrong event"),
@@ -704,6 +737,56 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_resets_are_counted_per_cause() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let mut client = MockClient::new();
+        client.expect_chain_id().returning(|| Ok(ChainId::from(42u64)));
+        client.expect_reset_pre_interop().times(ResetCause::ALL.len() + 1).returning(|| Ok(()));
+
+        let mut db = MockDb::new();
+        db.expect_latest_derivation_state()
+            .times(ResetCause::ALL.len() + 1)
+            .returning(|| Err(StorageError::DatabaseNotInitialised));
+
+        let client = Arc::new(client);
+        let db = Arc::new(db);
+        let asserter = Asserter::new();
+        let transport = MockTransport::new(asserter.clone());
+        let l1_provider = RootProvider::<Ethereum>::new(RpcClient::new(transport, false));
+        let (tx, _rx) = mpsc::channel(10);
+        let node = ManagedNode::new(client.clone(), db, l1_provider, tx);
+
+        node.handle_reset("reset_id").await.unwrap();
+        for cause in ResetCause::ALL {
+            node.reset(cause).await.unwrap();
+        }
+
+        let resets: HashMap<_, _> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter_map(|(key, _, _, value)| {
+                let key = key.key();
+                if key.name() != Metrics::MANAGED_NODE_RESETS_TOTAL {
+                    return None;
+                }
+                let cause = key.labels().find(|label| label.key() == "cause")?.value().to_string();
+                match value {
+                    DebugValue::Counter(count) => Some((cause, count)),
+                    _ => None,
+                }
+            })
+            .collect();
+        for cause in ResetCause::ALL {
+            let expected = if cause == ResetCause::ProtocolError { 2 } else { 1 };
+            assert_eq!(resets.get(cause.as_str()), Some(&expected), "cause: {cause}");
+        }
+    }
+
     #[tokio::test]
     async fn test_block_by_number_delegates_to_client() {
         let mut client = MockClient::new();
@@ -892,6 +975,62 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_update_safety_levels_pushes_latest_of_each_level() {
+        let source = BlockNumHash { number: 100, hash: B256::from([1u8; 32]) };
+        let cross_safe = BlockNumHash { number: 10, hash: B256::from([2u8; 32]) };
+        let cross_unsafe = BlockNumHash { number: 12, hash: B256::from([3u8; 32]) };
+        let finalized = BlockNumHash { number: 9, hash: B256::from([4u8; 32]) };
+        let mut client = MockClient::new();
+        client.expect_chain_id().times(1).returning(|| Ok(ChainId::from(42u64)));
+        client.expect_update_cross_unsafe().with(eq(cross_unsafe)).times(1).returning(|_| Ok(()));
+        client
+            .expect_update_cross_safe()
+            .with(eq(source), eq(cross_safe))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        client.expect_update_finalized().with(eq(finalized)).times(1).returning(|_| Ok(()));
+
+        let client = Arc::new(client);
+        let db = Arc::new(MockDb::new());
+        let asserter = Asserter::new();
+        let transport = MockTransport::new(asserter.clone());
+        let l1_provider = RootProvider::<Ethereum>::new(RpcClient::new(transport, false));
+        let (tx, _rx) = mpsc::channel(10);
+        let node = ManagedNode::new(client.clone(), db, l1_provider, tx);
+
+        let updates = vec![
+            SafetyLevelUpdate::cross_unsafe(BlockNumHash {
+                number: 11,
+                hash: B256::from([5u8; 32]),
+            }),
+            SafetyLevelUpdate::cross_unsafe(cross_unsafe),
+            SafetyLevelUpdate::cross_safe(source, cross_safe),
+            SafetyLevelUpdate::finalized(finalized),
+        ];
+        node.update_safety_levels(updates).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_update_safety_levels_rejects_unsupported_level() {
+        let client = MockClient::new();
+        let client = Arc::new(client);
+        let db = Arc::new(MockDb::new());
+        let asserter = Asserter::new();
+        let transport = MockTransport::new(asserter.clone());
+        let l1_provider = RootProvider::<Ethereum>::new(RpcClient::new(transport, false));
+        let (tx, _rx) = mpsc::channel(10);
+        let node = ManagedNode::new(client.clone(), db, l1_provider, tx);
+
+        let update = SafetyLevelUpdate {
+            level: SafetyLevel::LocalSafe,
+            block: BlockNumHash { number: 1, hash: B256::ZERO },
+            source: None,
+        };
+        let result = node.update_safety_levels(vec![update]).await;
+        assert_eq!(result, Err(ManagedNodeError::UnsupportedSafetyLevelUpdate(update)));
+    }
+
     #[tokio::test]
     async fn test_invalidate_block_delegates_to_client() {
         let mut client = MockClient::new();
@@ -915,6 +1054,40 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_fetch_block_receipts_delegates_to_client() {
+        let block_hash = B256::from([6u8; 32]);
+        let mut client = MockClient::new();
+        client.expect_chain_id().times(1).returning(|| Ok(ChainId::from(42u64)));
+        client
+            .expect_fetch_receipts()
+            .withf(move |hash| *hash == block_hash)
+            .times(1)
+            .returning(|_| Ok(Receipts::default()));
+
+        let client = Arc::new(client);
+        let db = Arc::new(MockDb::new());
+        let asserter = Asserter::new();
+        let transport = MockTransport::new(asserter.clone());
+        let l1_provider = RootProvider::<Ethereum>::new(RpcClient::new(transport, false));
+        let (tx, _rx) = mpsc::channel(10);
+        let node = ManagedNode::new(client.clone(), db, l1_provider, tx);
+
+        let receipts = node.fetch_block_receipts(BlockNumHash::new(500, block_hash)).await;
+        assert_eq!(receipts, Ok(Receipts::default()));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_with_timeout() {
+        let timeout = Duration::from_secs(10);
+
+        let result = with_timeout(timeout, async { Ok(Receipts::default()) }).await;
+        assert_eq!(result, Ok(Receipts::default()));
+
+        let result = with_timeout(timeout, std::future::pending::<Result<(), ClientError>>()).await;
+        assert_eq!(result, Err(ManagedNodeError::Timeout));
+    }
+
     #[tokio::test]
     async fn test_reset_calls_resetter() {
         let mut client = MockClient::new();
@@ -934,7 +1107,7 @@ mod tests {
         let (tx, _rx) = mpsc::channel(10);
         let node = ManagedNode::new(client.clone(), db, l1_provider, tx);
 
-        let result = node.reset().await;
+        let result = node.reset(ResetCause::ForcedRewind).await;
         assert!(result.is_ok());
     }
 }
@@ -1,4 +1,4 @@
-use super::{ManagedNodeClient, ManagedNodeError};
+use super::{ManagedNodeClient, ManagedNodeError, metrics::Metrics};
 use alloy_eips::{BlockNumHash, BlockNumberOrTag};
 use alloy_network::Ethereum;
 use alloy_primitives::ChainId;
@@ -6,10 +6,49 @@ use alloy_provider::{Provider, RootProvider};
 use kona_protocol::BlockInfo;
 use kona_supervisor_storage::{DerivationStorageReader, HeadRefStorageReader, StorageError};
 use kona_supervisor_types::SuperHead;
-use std::sync::Arc;
+use std::{fmt, sync::Arc};
 use tokio::sync::Mutex;
 use tracing::{error, info, warn};
 
+/// Reason a managed node is reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResetCause {
+    /// The node requested a reset after hitting a protocol error.
+    ProtocolError,
+    /// The node reported a derivation origin that doesn't extend the stored L1 blocks.
+    SourceBlockOutOfOrder,
+    /// The node reported a safe block that doesn't extend the stored derived blocks.
+    DerivedBlockOutOfOrder,
+    /// A rewind was forced through the supervisor admin API.
+    ForcedRewind,
+}
+
+impl ResetCause {
+    /// All reset causes.
+    pub const ALL: [Self; 4] = [
+        Self::ProtocolError,
+        Self::SourceBlockOutOfOrder,
+        Self::DerivedBlockOutOfOrder,
+        Self::ForcedRewind,
+    ];
+
+    /// Returns the metric label of the cause.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ProtocolError => "protocol_error",
+            Self::SourceBlockOutOfOrder => "source_block_out_of_order",
+            Self::DerivedBlockOutOfOrder => "derived_block_out_of_order",
+            Self::ForcedRewind => "forced_rewind",
+        }
+    }
+}
+
+impl fmt::Display for ResetCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug)]
 pub(super) struct Resetter<DB, C> {
     client: Arc<C>,
@@ -33,10 +72,13 @@ where
     }
 
     /// Resets the node using the latest super head.
-    pub(crate) async fn reset(&self) -> Result<(), ManagedNodeError> {
+    pub(crate) async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError> {
         // get the chain ID to log it, this is useful for debugging
         // no performance impact as it is cached in the client
         let chain_id = self.client.chain_id().await?;
+        info!(target: "supervisor::syncnode_resetter", %chain_id, %cause, "Resetting managed node");
+        Metrics::record_reset(chain_id, cause);
+
         let _guard = self.reset_guard.lock().await;
 
         let local_safe = match self.get_latest_valid_local_safe(chain_id).await {
@@ -322,7 +364,7 @@ mod tests {
 
         let resetter = Resetter::new(Arc::new(client), l1_provider, Arc::new(db));
 
-        assert!(resetter.reset().await.is_ok());
+        assert!(resetter.reset(ResetCause::ProtocolError).await.is_ok());
     }
 
     #[tokio::test]
@@ -385,7 +427,7 @@ mod tests {
 
         let resetter = Resetter::new(Arc::new(client), l1_provider, Arc::new(db));
 
-        assert!(resetter.reset().await.is_err());
+        assert!(resetter.reset(ResetCause::ProtocolError).await.is_err());
     }
 
     #[tokio::test]
@@ -401,7 +443,7 @@ mod tests {
         let l1_provider = RootProvider::<Ethereum>::new(RpcClient::new(transport, false));
         let resetter = Resetter::new(Arc::new(client), l1_provider, Arc::new(db));
 
-        assert!(resetter.reset().await.is_err());
+        assert!(resetter.reset(ResetCause::ProtocolError).await.is_err());
     }
 
     #[tokio::test]
@@ -426,7 +468,7 @@ mod tests {
         let l1_provider = RootProvider::<Ethereum>::new(RpcClient::new(transport, false));
         let resetter = Resetter::new(Arc::new(client), l1_provider, Arc::new(db));
 
-        assert!(resetter.reset().await.is_err());
+        assert!(resetter.reset(ResetCause::ProtocolError).await.is_err());
     }
 
     #[tokio::test]
@@ -514,7 +556,7 @@ mod tests {
 
         let resetter = Resetter::new(Arc::new(client), l1_provider, Arc::new(db));
 
-        assert!(resetter.reset().await.is_ok());
+        assert!(resetter.reset(ResetCause::ProtocolError).await.is_ok());
     }
 
     #[tokio::test]
@@ -581,6 +623,6 @@ mod tests {
 
         let resetter = Resetter::new(Arc::new(client), l1_provider, Arc::new(db));
 
-        assert!(resetter.reset().await.is_err());
+        assert!(resetter.reset(ResetCause::ProtocolError).await.is_err());
     }
 }
@@ -1,10 +1,10 @@
-use super::ManagedNodeError;
+use super::{ManagedNodeError, ResetCause};
 use alloy_eips::BlockNumHash;
 use alloy_primitives::B256;
 use async_trait::async_trait;
-use kona_interop::{BlockReplacement, DerivedRefPair};
+use kona_interop::{BlockReplacement, DerivedRefPair, SafetyLevel};
 use kona_protocol::BlockInfo;
-use kona_supervisor_types::{BlockSeal, OutputV0, Receipts};
+use kona_supervisor_types::{BlockSeal, OutputV0, Receipts, SafetyLevelUpdate};
 use std::fmt::Debug;
 
 /// Represents a handler for subscription events.
@@ -143,14 +143,51 @@ pub trait ManagedNodeController: Send + Sync + Debug {
         derived_block_id: BlockNumHash,
     ) -> Result<(), ManagedNodeError>;
 
+    /// Pushes blocks that reached a new safety level to the managed node.
+    ///
+    /// The node only tracks the head of each level, so only the last update of each level is
+    /// pushed, through the update methods above.
+    ///
+    /// # Arguments
+    /// * `updates` - The [`SafetyLevelUpdate`]s to push
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(ManagedNodeError)` if an update is unsupported or fails
+    async fn update_safety_levels(
+        &self,
+        updates: Vec<SafetyLevelUpdate>,
+    ) -> Result<(), ManagedNodeError> {
+        let mut latest: Vec<SafetyLevelUpdate> = Vec::with_capacity(updates.len());
+        for update in updates {
+            latest.retain(|pending| pending.level != update.level);
+            latest.push(update);
+        }
+
+        for update in latest {
+            match (update.level, update.source) {
+                (SafetyLevel::CrossUnsafe, _) => self.update_cross_unsafe(update.block).await?,
+                (SafetyLevel::CrossSafe, Some(source)) => {
+                    self.update_cross_safe(source, update.block).await?
+                }
+                (SafetyLevel::Finalized, _) => self.update_finalized(update.block).await?,
+                _ => return Err(ManagedNodeError::UnsupportedSafetyLevelUpdate(update)),
+            }
+        }
+        Ok(())
+    }
+
     /// Reset the managed node based on the supervisor's state.
     /// This is typically used to reset the node's state
     /// when the supervisor detects a misalignment
     ///
+    /// # Arguments
+    /// * `cause` - The [`ResetCause`] of the reset
+    ///
     /// # Returns
     /// * `Ok(())` on success
     /// * `Err(ManagedNodeError)` if the reset fails
-    async fn reset(&self) -> Result<(), ManagedNodeError>;
+    async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
 
     /// Instructs the managed node to invalidate a block.
     /// This is used when the supervisor detects an invalid block
@@ -163,6 +200,20 @@ pub trait ManagedNodeController: Send + Sync + Debug {
     /// * `Ok(())` on success
     /// * `Err(ManagedNodeError)` if the invalidation fails
     async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+
+    /// Fetches the receipts of a block from the managed node.
+    ///
+    /// # Arguments
+    /// * `block_id` - The [`BlockNumHash`] of the block.
+    ///
+    /// # Returns
+    /// * `Ok(Receipts)` on success
+    /// * `Err(ManagedNodeError::Timeout)` if the node does not respond in time
+    /// * `Err(ManagedNodeError)` if the fetch fails
+    async fn fetch_block_receipts(
+        &self,
+        block_id: BlockNumHash,
+    ) -> Result<Receipts, ManagedNodeError>;
 }
 
 /// Composite trait for any node that provides:
@@ -16,10 +16,21 @@ pub struct SupervisorRpcConfig {
     pub socket_address: SocketAddr,
     /// The JWT secret for the RPC server.
     pub jwt_secret: JwtSecret,
+    /// Maximum size of a request body, in bytes. Larger requests are rejected unread.
+    pub max_request_body_size: u32,
+    /// Maximum number of calls in a batch request. Larger batches are rejected before any call is
+    /// deserialized.
+    pub max_batch_len: u32,
 }
 
 #[cfg(feature = "server")]
 impl SupervisorRpcConfig {
+    /// Default maximum size of a request body, 10 MiB.
+    pub const DEFAULT_MAX_REQUEST_BODY_SIZE: u32 = 10 * 1024 * 1024;
+
+    /// Default maximum number of calls in a batch request.
+    pub const DEFAULT_MAX_BATCH_LEN: u32 = 1000;
+
     /// Returns if the rpc is disabled.
     pub const fn is_disabled(&self) -> bool {
         self.rpc_disabled
@@ -36,6 +47,8 @@ impl std::default::Default for SupervisorRpcConfig {
             rpc_disabled: true,
             socket_address: SocketAddr::new(std::net::Ipv4Addr::UNSPECIFIED.into(), 9333),
             jwt_secret: JwtSecret::random(),
+            max_request_body_size: Self::DEFAULT_MAX_REQUEST_BODY_SIZE,
+            max_batch_len: Self::DEFAULT_MAX_BATCH_LEN,
         }
     }
 }
@@ -5,7 +5,9 @@ pub use jsonrpsee::{
     types::{ErrorCode, ErrorObjectOwned},
 };
 
-use crate::{SuperRootOutputRpc, SupervisorSyncStatus};
+use crate::{
+    ChainStatus, MessageDependency, ReorgReport, SuperRootOutputRpc, SupervisorSyncStatus,
+};
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{B256, BlockHash, ChainId, map::HashMap};
 use jsonrpsee::proc_macros::rpc;
@@ -121,6 +123,25 @@ pub trait SupervisorApi {
     /// TODO: Replace the link above after the PR is merged.
     #[method(name = "dependencySetV1")]
     async fn dependency_set_v1(&self) -> RpcResult<DependencySet>;
+
+    /// Returns the executing messages of the given block, each with the initiating message it
+    /// depends on and that message's current safety level. Dependencies that can't be resolved
+    /// are included and flagged.
+    ///
+    /// Not part of the spec, intended for debugging cross-safety stalls.
+    #[method(name = "messageDependencies")]
+    async fn message_dependencies(
+        &self,
+        chain_id: HexStringU64,
+        block: BlockNumHash,
+    ) -> RpcResult<Vec<MessageDependency>>;
+
+    /// Returns the status of every tracked chain: its heads, the health of its managed node and
+    /// whether it's fully synced.
+    ///
+    /// Not part of the spec, gives an overview without knowing the chain ids in advance.
+    #[method(name = "chains")]
+    async fn chains(&self) -> RpcResult<Vec<ChainStatus>>;
 }
 
 /// Supervisor API for admin operations.
@@ -130,6 +151,19 @@ pub trait SupervisorAdminApi {
     /// Adds L2RPC to the supervisor.
     #[method(name = "addL2RPC")]
     async fn add_l2_rpc(&self, url: String, jwt_secret: String) -> RpcResult<()>;
+
+    /// Rewinds the storage and safety heads of a chain, keeping the given source (L1) block as
+    /// the latest one.
+    ///
+    /// Intended for incident recovery. The rewind is rejected if it would reach below the source
+    /// of the finalized block, or if `confirm` is not set.
+    #[method(name = "forceRewind")]
+    async fn force_rewind(
+        &self,
+        chain_id: HexStringU64,
+        to_block: BlockNumHash,
+        confirm: bool,
+    ) -> RpcResult<ReorgReport>;
 }
 
 /// Represents the topics for subscriptions in the Managed Mode API.
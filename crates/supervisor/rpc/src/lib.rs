@@ -20,11 +20,15 @@ pub use server::SupervisorRpcServer;
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 #[cfg(feature = "reqwest")]
-pub use reqwest::{CheckAccessListClient, SupervisorClient, SupervisorClientError};
+pub use reqwest::{
+    CheckAccessListClient, SupervisorClient, SupervisorClientConfig, SupervisorClientError,
+};
 
 pub mod response;
 pub use response::{
-    ChainRootInfoRpc, SuperRootOutputRpc, SupervisorChainSyncStatus, SupervisorSyncStatus,
+    ChainRootInfoRpc, ChainStatus, DependencyStatus, ManagedNodeHealth, MessageDependency,
+    MessageIdentifier, ReorgReport, SuperRootOutputRpc, SupervisorChainSyncStatus,
+    SupervisorSyncStatus,
 };
 
 pub use kona_protocol::BlockInfo;
@@ -3,11 +3,18 @@
 #[cfg(feature = "reqwest")]
 use alloy_primitives::B256;
 #[cfg(feature = "reqwest")]
-use alloy_rpc_client::ReqwestClient;
+use alloy_rpc_client::{ReqwestClient, RpcClient};
 #[cfg(feature = "reqwest")]
-use derive_more::Constructor;
+use alloy_transport::{RpcError, TransportError, TransportErrorKind};
+#[cfg(feature = "reqwest")]
+use alloy_transport_http::{
+    Http,
+    reqwest::{self, Client, Url},
+};
 #[cfg(feature = "reqwest")]
 use kona_interop::{ExecutingDescriptor, SafetyLevel};
+#[cfg(feature = "reqwest")]
+use std::time::Duration;
 
 /// Error types for supervisor RPC interactions
 #[cfg(feature = "reqwest")]
@@ -16,6 +23,10 @@ pub enum SupervisorClientError {
     /// RPC client error
     #[error("RPC client error: {0}")]
     Client(Box<dyn std::error::Error + Send + Sync>),
+
+    /// Invalid client configuration
+    #[error("invalid client config: {0}")]
+    InvalidConfig(&'static str),
 }
 
 #[cfg(feature = "reqwest")]
@@ -24,6 +35,71 @@ impl SupervisorClientError {
     pub fn client(err: impl std::error::Error + Send + Sync + 'static) -> Self {
         Self::Client(Box::new(err))
     }
+
+    /// Returns the code of the JSON-RPC error response the server answered with, if any.
+    pub fn error_code(&self) -> Option<i64> {
+        match self {
+            Self::Client(err) => {
+                err.downcast_ref::<TransportError>()?.as_error_resp().map(|resp| resp.code)
+            }
+            Self::InvalidConfig(_) => None,
+        }
+    }
+}
+
+/// Connection pool and timeout settings of a [`SupervisorClient`].
+#[cfg(feature = "reqwest")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SupervisorClientConfig {
+    /// Maximum number of idle connections kept open per host.
+    pub max_idle_connections_per_host: usize,
+    /// Timeout of a whole request, from connecting until the response body is read.
+    pub request_timeout: Duration,
+    /// Timeout of establishing a connection.
+    pub connect_timeout: Duration,
+    /// Number of times a request is retried after failing to connect or timing out.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every further retry.
+    pub retry_backoff: Duration,
+}
+
+#[cfg(feature = "reqwest")]
+impl SupervisorClientConfig {
+    /// Default timeout of a whole request.
+    pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// Default timeout of establishing a connection.
+    pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Default number of retries of a request.
+    pub const DEFAULT_MAX_RETRIES: u32 = 2;
+
+    /// Default delay before the first retry.
+    pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+    /// Checks that the timeouts are non-zero.
+    pub const fn validate(&self) -> Result<(), SupervisorClientError> {
+        if self.request_timeout.is_zero() {
+            return Err(SupervisorClientError::InvalidConfig("request timeout must be non-zero"));
+        }
+        if self.connect_timeout.is_zero() {
+            return Err(SupervisorClientError::InvalidConfig("connect timeout must be non-zero"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl Default for SupervisorClientConfig {
+    fn default() -> Self {
+        Self {
+            max_idle_connections_per_host: usize::MAX,
+            request_timeout: Self::DEFAULT_REQUEST_TIMEOUT,
+            connect_timeout: Self::DEFAULT_CONNECT_TIMEOUT,
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            retry_backoff: Self::DEFAULT_RETRY_BACKOFF,
+        }
+    }
 }
 
 /// Subset of `op-supervisor` API, used for validating interop events.
@@ -40,10 +116,57 @@ pub trait CheckAccessListClient {
 
 /// A supervisor client.
 #[cfg(feature = "reqwest")]
-#[derive(Debug, Clone, Constructor)]
+#[derive(Debug, Clone)]
 pub struct SupervisorClient {
     /// The inner RPC client.
     client: ReqwestClient,
+    /// Number of times a request is retried on transient failures.
+    max_retries: u32,
+    /// Delay before the first retry.
+    retry_backoff: Duration,
+}
+
+#[cfg(feature = "reqwest")]
+impl SupervisorClient {
+    /// Creates a new [`SupervisorClient`] that doesn't retry failed requests.
+    pub const fn new(client: ReqwestClient) -> Self {
+        Self { client, max_retries: 0, retry_backoff: Duration::ZERO }
+    }
+
+    /// Creates a client of the supervisor at `url`, using the given pool and timeout settings.
+    pub fn with_config(
+        url: Url,
+        config: SupervisorClientConfig,
+    ) -> Result<Self, SupervisorClientError> {
+        config.validate()?;
+        let http_client = Client::builder()
+            .pool_max_idle_per_host(config.max_idle_connections_per_host)
+            .timeout(config.request_timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .map_err(SupervisorClientError::client)?;
+        let transport = Http::with_client(http_client, url);
+        let is_local = transport.guess_local();
+        Ok(Self {
+            client: RpcClient::new(transport, is_local),
+            max_retries: config.max_retries,
+            retry_backoff: config.retry_backoff,
+        })
+    }
+}
+
+/// Returns whether the request failed before the server could answer it, i.e. the connection
+/// couldn't be established or the request timed out.
+///
+/// Responses of the server, including error responses, are definitive and never retried.
+#[cfg(feature = "reqwest")]
+fn is_transient(err: &TransportError) -> bool {
+    match err {
+        RpcError::Transport(TransportErrorKind::Custom(err)) => err
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(|err| err.is_connect() || err.is_timeout()),
+        _ => false,
+    }
 }
 
 #[cfg(feature = "reqwest")]
@@ -54,12 +177,159 @@ impl CheckAccessListClient for SupervisorClient {
         min_safety: SafetyLevel,
         executing_descriptor: ExecutingDescriptor,
     ) -> Result<(), SupervisorClientError> {
-        self.client
-            .request(
-                "supervisor_checkAccessList",
-                (inbox_entries, min_safety, executing_descriptor),
-            )
-            .await
-            .map_err(SupervisorClientError::client)
+        // the call is idempotent, so it's safe to repeat if the first attempt got lost
+        let mut backoff = self.retry_backoff;
+        let mut retries = 0;
+        loop {
+            let result = self
+                .client
+                .request::<_, ()>(
+                    "supervisor_checkAccessList",
+                    (inbox_entries, min_safety, &executing_descriptor),
+                )
+                .await;
+            match result {
+                Err(err) if retries < self.max_retries && is_transient(&err) => {
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                    retries += 1;
+                }
+                result => return result.map_err(SupervisorClientError::client),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Instant,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    #[test]
+    fn test_zero_timeouts_are_rejected() {
+        let url: Url = "http://localhost:8545".parse().unwrap();
+        for config in [
+            SupervisorClientConfig { request_timeout: Duration::ZERO, ..Default::default() },
+            SupervisorClientConfig { connect_timeout: Duration::ZERO, ..Default::default() },
+        ] {
+            assert!(matches!(
+                SupervisorClient::with_config(url.clone(), config),
+                Err(SupervisorClientError::InvalidConfig(_))
+            ));
+        }
+        assert!(SupervisorClient::with_config(url, SupervisorClientConfig::default()).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_slow_server_trips_request_timeout() {
+        // accepts connections but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap()).parse().unwrap();
+        let server = tokio::spawn(async move {
+            let mut connections = Vec::new();
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                connections.push(stream);
+            }
+        });
+
+        let config = SupervisorClientConfig {
+            max_idle_connections_per_host: 1,
+            request_timeout: Duration::from_millis(200),
+            connect_timeout: Duration::from_secs(1),
+            max_retries: 0,
+            retry_backoff: Duration::ZERO,
+        };
+        let client = SupervisorClient::with_config(url, config).unwrap();
+
+        let start = Instant::now();
+        let result = client
+            .check_access_list(&[], SafetyLevel::CrossUnsafe, ExecutingDescriptor::default())
+            .await;
+        let elapsed = start.elapsed();
+
+        assert!(matches!(result, Err(SupervisorClientError::Client(_))));
+        assert!(elapsed >= config.request_timeout);
+        assert!(elapsed < Duration::from_secs(5), "request took {elapsed:?}");
+        server.abort();
+    }
+
+    /// Reads an HTTP request from `stream` and returns its JSON-RPC id.
+    async fn read_request_id(stream: &mut TcpStream) -> serde_json::Value {
+        let mut request = Vec::new();
+        let mut buf = [0u8; 1024];
+        loop {
+            let read = stream.read(&mut buf).await.unwrap();
+            assert!(read > 0, "connection closed before the request was read");
+            request.extend_from_slice(&buf[..read]);
+            let text = String::from_utf8_lossy(&request);
+            if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                let content_length = headers
+                    .lines()
+                    .find_map(|line| {
+                        let (name, value) = line.split_once(':')?;
+                        name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse())
+                    })
+                    .unwrap()
+                    .unwrap();
+                if body.len() >= content_length {
+                    let request: serde_json::Value = serde_json::from_str(body).unwrap();
+                    return request["id"].clone();
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_timeout() {
+        // ignores the first request, and answers every later one
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap()).parse().unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server_requests = requests.clone();
+        let server = tokio::spawn(async move {
+            let mut ignored = Vec::new();
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let id = read_request_id(&mut stream).await;
+                if server_requests.fetch_add(1, Ordering::SeqCst) == 0 {
+                    ignored.push(stream);
+                    continue;
+                }
+                let body = serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": null });
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        let config = SupervisorClientConfig {
+            request_timeout: Duration::from_millis(200),
+            max_retries: 1,
+            retry_backoff: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let client = SupervisorClient::with_config(url, config).unwrap();
+
+        let result = client
+            .check_access_list(&[], SafetyLevel::CrossUnsafe, ExecutingDescriptor::default())
+            .await;
+
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        server.abort();
     }
 }
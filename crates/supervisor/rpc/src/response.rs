@@ -4,6 +4,7 @@ use alloy_eips::BlockNumHash;
 use alloy_primitives::{B256, Bytes, ChainId, map::HashMap};
 use kona_protocol::BlockInfo;
 use kona_supervisor_types::SuperHead;
+use op_alloy_consensus::interop::SafetyLevel;
 use serde::{Deserialize, Serialize, Serializer};
 
 /// Describes superchain sync status.
@@ -99,6 +100,9 @@ pub struct ChainRootInfoRpc {
     #[serde(rename = "chainID", with = "alloy_serde::quantity")]
     pub chain_id: ChainId,
     /// The canonical output root of the latest canonical block at a particular timestamp.
+    ///
+    /// This is the v0 output root, `keccak256(version ++ state_root ++
+    /// message_passer_storage_root ++ block_hash)`, and not a hash of the JSON-encoded output.
     pub canonical: B256,
     /// The pending output root.
     ///
@@ -130,6 +134,135 @@ pub struct SuperRootOutputRpc {
     pub chains: Vec<ChainRootInfoRpc>,
 }
 
+/// Outcome of a rewind of a chain's storage, returned by
+/// [`force_rewind`](crate::jsonrpsee::SupervisorAdminApiServer::force_rewind).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ReorgReport {
+    /// The chain ID.
+    #[cfg_attr(feature = "serde", serde(rename = "chainID", with = "alloy_serde::quantity"))]
+    pub chain_id: ChainId,
+    /// The latest source (L1) block kept after the rewind.
+    pub source: BlockInfo,
+    /// The latest derived (L2) block kept after the rewind.
+    pub derived: BlockInfo,
+    /// The number of source blocks rewound.
+    pub l1_depth: u64,
+    /// The number of derived blocks rewound.
+    pub l2_depth: u64,
+}
+
+/// Identifies a message by the log that emitted it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct MessageIdentifier {
+    /// The chain ID the message was emitted on.
+    #[cfg_attr(feature = "serde", serde(rename = "chainID", with = "alloy_serde::quantity"))]
+    pub chain_id: ChainId,
+    /// The number of the block containing the message.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub block_number: u64,
+    /// The index of the log within the block.
+    pub log_index: u32,
+    /// The timestamp of the block containing the message.
+    #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
+    pub timestamp: u64,
+}
+
+/// Whether the initiating message of a [`MessageDependency`] could be found.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum DependencyStatus {
+    /// The initiating message was found and its hash matches.
+    Resolved,
+    /// The initiating chain isn't tracked by the supervisor.
+    UnknownChain,
+    /// The initiating block isn't indexed yet.
+    BlockNotFound,
+    /// The initiating block is indexed but doesn't contain the referenced log.
+    LogNotFound,
+    /// The referenced log exists but its hash doesn't match the executing message.
+    HashMismatch,
+}
+
+/// An executing message of a block and the initiating message it depends on, returned by
+/// [`message_dependencies`](crate::jsonrpsee::SupervisorApiServer::message_dependencies).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct MessageDependency {
+    /// The index of the executing message's log within the queried block.
+    pub log_index: u32,
+    /// The initiating message, as referenced by the executing message.
+    pub initiating: MessageIdentifier,
+    /// The hash of the initiating message, as referenced by the executing message.
+    pub message_hash: B256,
+    /// The initiating block, if it's indexed.
+    pub initiating_block: Option<BlockNumHash>,
+    /// The highest safety level reached by the initiating block, if it's indexed.
+    pub safety_level: Option<SafetyLevel>,
+    /// Whether the initiating message could be found.
+    pub status: DependencyStatus,
+}
+
+impl MessageDependency {
+    /// Returns `true` if the initiating message was found and its hash matches.
+    pub const fn is_resolved(&self) -> bool {
+        matches!(self.status, DependencyStatus::Resolved)
+    }
+}
+
+/// Health of the managed node of a chain, as reported in [`ChainStatus`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ManagedNodeHealth {
+    /// The managed node answered a probe for the chain's local unsafe block.
+    Healthy,
+    /// The managed node is registered but failed to answer the probe.
+    Unreachable,
+    /// No managed node is registered for the chain.
+    Missing,
+}
+
+/// Status of a tracked chain, returned by
+/// [`chains`](crate::jsonrpsee::SupervisorApiServer::chains).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct ChainStatus {
+    /// The chain ID.
+    #[cfg_attr(feature = "serde", serde(rename = "chainID", with = "alloy_serde::quantity"))]
+    pub chain_id: ChainId,
+    /// Heads of the chain, if its database is initialised.
+    pub head: Option<SupervisorChainSyncStatus>,
+    /// Health of the chain's managed node.
+    pub managed_node: ManagedNodeHealth,
+    /// Whether the managed node is healthy and the cross heads caught up with the local heads.
+    pub synced: bool,
+}
+
 /// Serializes a [u8] as a hex string. Ensure that the hex string has an even length.
 ///
 /// This is used to serialize the [`SuperRootOutputRpc`]'s version field as a hex string.
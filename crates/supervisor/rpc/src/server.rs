@@ -1,9 +1,11 @@
 //! Minimal supervisor RPC server implementation
 
+#[cfg(feature = "server")]
+use crate::SupervisorRpcConfig;
 #[cfg(feature = "server")]
 use alloy_rpc_types_engine::JwtSecret;
 #[cfg(feature = "server")]
-use jsonrpsee::server::ServerHandle;
+use jsonrpsee::server::{BatchRequestConfig, ServerBuilder, ServerHandle};
 #[cfg(feature = "server")]
 use kona_interop::{ControlEvent, ManagedEvent};
 #[cfg(feature = "server")]
@@ -26,6 +28,10 @@ pub struct SupervisorRpcServer {
     jwt_token: JwtSecret,
     /// The socket address for the RPC server.
     socket: SocketAddr,
+    /// Maximum size of a request body, in bytes.
+    max_request_body_size: u32,
+    /// Maximum number of calls in a batch request.
+    max_batch_len: u32,
 }
 
 #[cfg(feature = "server")]
@@ -37,7 +43,21 @@ impl SupervisorRpcServer {
         jwt_token: JwtSecret,
         socket: SocketAddr,
     ) -> Self {
-        Self { managed_events, control_events, jwt_token, socket }
+        Self {
+            managed_events,
+            control_events,
+            jwt_token,
+            socket,
+            max_request_body_size: SupervisorRpcConfig::DEFAULT_MAX_REQUEST_BODY_SIZE,
+            max_batch_len: SupervisorRpcConfig::DEFAULT_MAX_BATCH_LEN,
+        }
+    }
+
+    /// Applies the request size limits of the given [`SupervisorRpcConfig`].
+    pub const fn with_request_limits(mut self, config: &SupervisorRpcConfig) -> Self {
+        self.max_request_body_size = config.max_request_body_size;
+        self.max_batch_len = config.max_batch_len;
+        self
     }
 
     /// Returns the socket address for the RPC server.
@@ -47,9 +67,57 @@ impl SupervisorRpcServer {
 
     /// Launches the RPC server with the given socket address.
     pub async fn launch(self) -> std::io::Result<ServerHandle> {
-        let server = jsonrpsee::server::ServerBuilder::default().build(self.socket).await?;
+        // oversized requests and batches are rejected with a JSON-RPC error before any call is
+        // deserialized
+        let server = ServerBuilder::default()
+            .max_request_body_size(self.max_request_body_size)
+            .set_batch_request_config(BatchRequestConfig::Limit(self.max_batch_len))
+            .build(self.socket)
+            .await?;
         // For now, start without any RPC methods - this is a minimal implementation
         let module = jsonrpsee::RpcModule::new(());
         Ok(server.start(module))
     }
 }
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::*;
+    use jsonrpsee::types::error::TOO_BIG_BATCH_REQUEST_CODE;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    };
+
+    #[tokio::test]
+    async fn test_rejects_oversized_batch() {
+        let (_, managed_events) = broadcast::channel(1);
+        let (control_events, _) = broadcast::channel(1);
+        let config = SupervisorRpcConfig { max_batch_len: 2, ..Default::default() };
+        // the server doesn't expose the address it bound to, so pick a free port up front
+        let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let handle =
+            SupervisorRpcServer::new(managed_events, control_events, JwtSecret::random(), addr)
+                .with_request_limits(&config)
+                .launch()
+                .await
+                .unwrap();
+
+        let call = r#"{"jsonrpc":"2.0","id":1,"method":"supervisor_checkAccessList","params":[]}"#;
+        let body = format!("[{call},{call},{call}]");
+        let request = format!(
+            "POST / HTTP/1.1\r\nhost: {addr}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        let response: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(response["error"]["code"], TOO_BIG_BATCH_REQUEST_CODE);
+
+        handle.stop().unwrap();
+    }
+}
@@ -1,18 +1,80 @@
+use alloy_primitives::ChainId;
 use async_trait::async_trait;
 use kona_supervisor_metrics::MetricsReporter;
-use std::{io, sync::Arc, time::Duration};
+use kona_supervisor_storage::ChainDbFactory;
+use std::{cmp::Reverse, io, sync::Arc, time::Duration};
 use tokio::time::sleep;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
 use crate::SupervisorActor;
 
-#[derive(derive_more::Constructor)]
+/// Gauge for the largest cross-safety lag across all chains, in seconds.
+/// Labels: `chain_id` of the chain lagging the most.
+const MAX_CROSS_SAFETY_LAG_SECONDS: &str = "supervisor_max_cross_safety_lag_seconds";
+
+/// Provides how far the cross-safe head of each chain trails its local unsafe head.
+pub trait CrossSafetyLagProvider: Send + Sync {
+    /// Returns the cross-safety lag of each chain, in seconds.
+    fn cross_safety_lags(&self) -> Vec<(ChainId, u64)>;
+}
+
+impl CrossSafetyLagProvider for ChainDbFactory {
+    fn cross_safety_lags(&self) -> Vec<(ChainId, u64)> {
+        Self::cross_safety_lags(self)
+    }
+}
+
 pub struct MetricWorker<R> {
     interval: Duration,
     // list of reporters
     reporters: Vec<Arc<R>>,
     cancel_token: CancellationToken,
+    // source of the superchain-wide cross-safety lag, if aggregated
+    lag_provider: Option<Arc<dyn CrossSafetyLagProvider>>,
+    // chain reported as the laggard on the previous tick
+    laggard: Option<ChainId>,
+}
+
+impl<R> MetricWorker<R> {
+    /// Creates a new [`MetricWorker`].
+    pub const fn new(
+        interval: Duration,
+        reporters: Vec<Arc<R>>,
+        cancel_token: CancellationToken,
+    ) -> Self {
+        Self { interval, reporters, cancel_token, lag_provider: None, laggard: None }
+    }
+
+    /// Reports the largest cross-safety lag across all chains on each tick.
+    pub fn with_cross_safety_lag(mut self, lag_provider: Arc<dyn CrossSafetyLagProvider>) -> Self {
+        metrics::describe_gauge!(
+            MAX_CROSS_SAFETY_LAG_SECONDS,
+            metrics::Unit::Seconds,
+            "Largest cross-safety lag across all chains, labelled with the lagging chain",
+        );
+        self.lag_provider = Some(lag_provider);
+        self
+    }
+
+    /// Updates the max cross-safety lag gauge, returning the chain lagging the most and its lag.
+    fn report_cross_safety_lag(&mut self) -> Option<(ChainId, u64)> {
+        let lags = self.lag_provider.as_ref()?.cross_safety_lags();
+        // ties go to the lowest chain id, so the label doesn't flap
+        let (chain_id, lag) =
+            lags.into_iter().max_by_key(|&(chain_id, lag)| (lag, Reverse(chain_id)))?;
+
+        // the label moves with the laggard, so clear the series of the previous one
+        if let Some(previous) = self.laggard.filter(|previous| *previous != chain_id) {
+            metrics::gauge!(MAX_CROSS_SAFETY_LAG_SECONDS, "chain_id" => previous.to_string())
+                .set(0.0);
+        }
+        metrics::gauge!(MAX_CROSS_SAFETY_LAG_SECONDS, "chain_id" => chain_id.to_string())
+            .set(lag as f64);
+        self.laggard = Some(chain_id);
+
+        Some((chain_id, lag))
+    }
 }
 
 #[async_trait]
@@ -30,7 +92,6 @@ where
             self.interval
         );
 
-        let reporters = self.reporters;
         let interval = self.interval;
 
         loop {
@@ -39,10 +100,16 @@ where
                 break;
             }
 
-            for reporter in &reporters {
+            for reporter in &self.reporters {
                 reporter.report_metrics();
             }
-            sleep(interval).await;
+            self.report_cross_safety_lag();
+
+            // wake up on cancellation so shutdown isn't held up by a full interval
+            tokio::select! {
+                _ = sleep(interval) => {}
+                _ = self.cancel_token.cancelled() => {}
+            }
         }
         Ok(())
     }
@@ -64,6 +131,15 @@ mod tests {
         }
     );
 
+    mock! (
+        #[derive(Debug)]
+        pub LagProvider {}
+
+        impl CrossSafetyLagProvider for LagProvider {
+            fn cross_safety_lags(&self) -> Vec<(ChainId, u64)>;
+        }
+    );
+
     #[tokio::test]
     async fn test_metric_worker_reports_metrics_and_stops_on_cancel() {
         let mut mock_reporter = MockReporter::new();
@@ -104,4 +180,80 @@ mod tests {
 
         let _ = worker.start().await;
     }
+
+    /// Returns the values of the max cross-safety lag gauge, by `chain_id` label.
+    fn lag_gauges(
+        snapshotter: &metrics_util::debugging::Snapshotter,
+    ) -> std::collections::HashMap<String, f64> {
+        snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter_map(|(key, _, _, value)| {
+                let key = key.key();
+                if key.name() != MAX_CROSS_SAFETY_LAG_SECONDS {
+                    return None;
+                }
+                let chain_id = key.labels().find(|label| label.key() == "chain_id")?;
+                match value {
+                    metrics_util::debugging::DebugValue::Gauge(value) => {
+                        Some((chain_id.value().to_string(), value.into_inner()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_reports_max_cross_safety_lag_with_laggard_chain() {
+        let recorder = metrics_util::debugging::DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let mut lag_provider = MockLagProvider::new();
+        let mut ticks = vec![vec![(1, 4), (10, 12), (5, 7)], vec![(1, 20), (10, 3), (5, 20)]];
+        lag_provider.expect_cross_safety_lags().times(2).returning(move || ticks.remove(0));
+
+        let mut worker = MetricWorker::<MockReporter>::new(
+            Duration::from_secs(1),
+            vec![],
+            CancellationToken::new(),
+        )
+        .with_cross_safety_lag(Arc::new(lag_provider));
+
+        assert_eq!(worker.report_cross_safety_lag(), Some((10, 12)));
+        assert_eq!(worker.laggard, Some(10));
+        assert_eq!(lag_gauges(&snapshotter), [("10".to_string(), 12.0)].into());
+
+        // laggard moves to the lowest chain id among those tied for the max, and the series of
+        // the previous laggard is cleared
+        assert_eq!(worker.report_cross_safety_lag(), Some((1, 20)));
+        assert_eq!(worker.laggard, Some(1));
+        assert_eq!(
+            lag_gauges(&snapshotter),
+            [("1".to_string(), 20.0), ("10".to_string(), 0.0)].into()
+        );
+    }
+
+    #[test]
+    fn test_cross_safety_lag_not_reported_without_chains() {
+        let recorder = metrics_util::debugging::DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let mut lag_provider = MockLagProvider::new();
+        lag_provider.expect_cross_safety_lags().returning(Vec::new);
+
+        let mut worker = MetricWorker::<MockReporter>::new(
+            Duration::from_secs(1),
+            vec![],
+            CancellationToken::new(),
+        )
+        .with_cross_safety_lag(Arc::new(lag_provider));
+
+        assert_eq!(worker.report_cross_safety_lag(), None);
+        assert_eq!(worker.laggard, None);
+        assert!(lag_gauges(&snapshotter).is_empty());
+    }
 }
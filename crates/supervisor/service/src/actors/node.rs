@@ -1,15 +1,18 @@
+use alloy_primitives::ChainId;
 use anyhow::Error;
 use async_trait::async_trait;
 use derive_more::Constructor;
 use kona_interop::ManagedEvent;
-use kona_supervisor_core::syncnode::{
-    ManagedNodeClient, ManagedNodeCommand, ManagedNodeController, SubscriptionHandler,
+use kona_supervisor_core::{
+    event::Traced,
+    syncnode::{ManagedNodeClient, ManagedNodeCommand, ManagedNodeController, SubscriptionHandler},
 };
+use kona_supervisor_types::SafetyLevelUpdate;
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
-use tracing::{error, info, warn};
+use tracing::{Instrument, debug_span, error, info, warn};
 
 use crate::{SupervisorActor, actors::utils::spawn_task_with_retry};
 
@@ -18,7 +21,7 @@ use crate::{SupervisorActor, actors::utils::spawn_task_with_retry};
 pub struct ManagedNodeActor<C, N> {
     client: Arc<C>,
     node: Arc<N>,
-    command_rx: mpsc::Receiver<ManagedNodeCommand>,
+    command_rx: mpsc::Receiver<Traced<ManagedNodeCommand>>,
     cancel_token: CancellationToken,
 }
 
@@ -58,7 +61,7 @@ where
 
 async fn run_command_task<N>(
     node: Arc<N>,
-    mut command_rx: mpsc::Receiver<ManagedNodeCommand>,
+    mut command_rx: mpsc::Receiver<Traced<ManagedNodeCommand>>,
     cancel_token: CancellationToken,
 ) -> Result<(), SupervisorRpcActorError>
 where
@@ -73,58 +76,13 @@ where
             }
             maybe_cmd = command_rx.recv() => {
                 match maybe_cmd {
-                    Some(cmd) => {
-                        match cmd {
-                            ManagedNodeCommand::UpdateFinalized { block_id } => {
-                                let result = node.update_finalized(block_id).await;
-                                if let Err(err) = result {
-                                    warn!(
-                                        target: "supervisor::syncnode",
-                                        %err,
-                                        "Failed to update finalized block"
-                                    );
-                                }
-                            }
-                            ManagedNodeCommand::UpdateCrossUnsafe { block_id } => {
-                                let result = node.update_cross_unsafe(block_id).await;
-                                if let Err(err) = result {
-                                    warn!(
-                                        target: "supervisor::syncnode",
-                                        %err,
-                                        "Failed to update cross unsafe block"
-                                    );
-                                }
-                            }
-                            ManagedNodeCommand::UpdateCrossSafe { source_block_id, derived_block_id } => {
-                                let result = node.update_cross_safe(source_block_id, derived_block_id).await;
-                                if let Err(err) = result {
-                                    warn!(
-                                        target: "supervisor::syncnode",
-                                        %err,
-                                        "Failed to update cross safe block"
-                                    );
-                                }
-                            }
-                            ManagedNodeCommand::Reset {} => {
-                                let result = node.reset().await;
-                                if let Err(err) = result {
-                                    warn!(
-                                        target: "supervisor::syncnode",
-                                        %err,
-                                        "Failed to reset managed node"
-                                    );
-                                }
-                            }
-                            ManagedNodeCommand::InvalidateBlock { seal } => {
-                                let result = node.invalidate_block(seal).await;
-                                if let Err(err) = result {
-                                    warn!(
-                                        target: "supervisor::syncnode",
-                                        %err,
-                                        "Failed to invalidate block"
-                                    );
-                                }
-                            }
+                    Some(traced) => {
+                        let mut next = Some(traced);
+                        while let Some(traced) = next.take() {
+                            let (parent, cmd) = traced.into_parts();
+                            next = handle_command(&node, cmd, &mut command_rx)
+                                .instrument(debug_span!(parent: &parent, "managed_node_command"))
+                                .await;
                         }
                     }
                     None => {
@@ -137,12 +95,127 @@ where
     }
 }
 
+/// Handles a command, returning the next queued command if one was received along the way.
+///
+/// Safety level updates queued right behind an update are pushed to the node in the same batch.
+async fn handle_command<N>(
+    node: &Arc<N>,
+    cmd: ManagedNodeCommand,
+    command_rx: &mut mpsc::Receiver<Traced<ManagedNodeCommand>>,
+) -> Option<Traced<ManagedNodeCommand>>
+where
+    N: ManagedNodeController + 'static,
+{
+    let update = match cmd {
+        ManagedNodeCommand::UpdateFinalized { block_id } => SafetyLevelUpdate::finalized(block_id),
+        ManagedNodeCommand::UpdateCrossUnsafe { block_id } => {
+            SafetyLevelUpdate::cross_unsafe(block_id)
+        }
+        ManagedNodeCommand::UpdateCrossSafe { source_block_id, derived_block_id } => {
+            SafetyLevelUpdate::cross_safe(source_block_id, derived_block_id)
+        }
+        ManagedNodeCommand::Reset { cause } => {
+            let result = node.reset(cause).await;
+            if let Err(err) = result {
+                warn!(
+                    target: "supervisor::syncnode",
+                    %err,
+                    "Failed to reset managed node"
+                );
+            }
+            return None;
+        }
+        ManagedNodeCommand::InvalidateBlock { seal } => {
+            let result = node.invalidate_block(seal).await;
+            if let Err(err) = result {
+                warn!(
+                    target: "supervisor::syncnode",
+                    %err,
+                    "Failed to invalidate block"
+                );
+            }
+            return None;
+        }
+        ManagedNodeCommand::FetchReceipts { block_id, resp } => {
+            // fetches are bounded by a timeout, but must not hold up the commands queued behind
+            let node = node.clone();
+            tokio::spawn(
+                async move {
+                    let result = node.fetch_block_receipts(block_id).await;
+                    if let Err(err) = &result {
+                        warn!(
+                            target: "supervisor::syncnode",
+                            %err,
+                            block_number = block_id.number,
+                            "Failed to fetch block receipts"
+                        );
+                    }
+                    if resp.send(result).is_err() {
+                        warn!(
+                            target: "supervisor::syncnode",
+                            block_number = block_id.number,
+                            "Receiver of block receipts dropped"
+                        );
+                    }
+                }
+                .in_current_span(),
+            );
+            return None;
+        }
+    };
+
+    let mut updates = vec![update];
+    let mut next = None;
+    while let Ok(queued) = command_rx.try_recv() {
+        match safety_level_update(queued.inner()) {
+            Some(update) => updates.push(update),
+            None => {
+                next = Some(queued);
+                break;
+            }
+        }
+    }
+    if let Err(err) = node.update_safety_levels(updates).await {
+        warn!(
+            target: "supervisor::syncnode",
+            %err,
+            "Failed to push safety level updates"
+        );
+    }
+    next
+}
+
+/// Returns the [`SafetyLevelUpdate`] pushed by the command, if it is a safety level update.
+const fn safety_level_update(cmd: &ManagedNodeCommand) -> Option<SafetyLevelUpdate> {
+    match *cmd {
+        ManagedNodeCommand::UpdateFinalized { block_id } => {
+            Some(SafetyLevelUpdate::finalized(block_id))
+        }
+        ManagedNodeCommand::UpdateCrossUnsafe { block_id } => {
+            Some(SafetyLevelUpdate::cross_unsafe(block_id))
+        }
+        ManagedNodeCommand::UpdateCrossSafe { source_block_id, derived_block_id } => {
+            Some(SafetyLevelUpdate::cross_safe(source_block_id, derived_block_id))
+        }
+        _ => None,
+    }
+}
+
 async fn run_subscription_task<C: ManagedNodeClient, N: SubscriptionHandler>(
     client: Arc<C>,
     handler: Arc<N>,
 ) -> Result<(), Error> {
     info!(target: "supervisor::syncnode", "Starting subscription task for managed node");
 
+    let chain_id = client.chain_id().await.inspect_err(|err| {
+        error!(
+            target: "supervisor::syncnode",
+            %err,
+            "Failed to get chain ID from managed node"
+        );
+    })?;
+    let mut backfill = BackfillProgress::new(chain_id);
+
     let mut subscription = client.subscribe_events().await.inspect_err(|err| {
         error!(
             target: "supervisor::syncnode",
@@ -157,6 +230,7 @@ async fn run_subscription_task<C: ManagedNodeClient, N: SubscriptionHandler>(
                 match incoming_event {
                     Some(Ok(subscription_event)) => {
                         if let Some(event) = subscription_event.data {
+                            backfill.observe(&event);
                             handle_subscription_event(&handler, event).await;
                         }
                     }
@@ -180,6 +254,54 @@ async fn run_subscription_task<C: ManagedNodeClient, N: SubscriptionHandler>(
     Ok(())
 }
 
+/// Gauge for the number of blocks a managed node's local safe head trails its unsafe head.
+/// Labels: `chain_id`
+const BACKFILL_REMAINING_BLOCKS: &str = "supervisor_managed_node_backfill_remaining_blocks";
+
+/// Tracks a managed node's catch-up progress from its subscription events.
+///
+/// The node is caught up once its latest local safe block reaches its latest unsafe block.
+#[derive(Debug)]
+struct BackfillProgress {
+    chain_id: ChainId,
+    /// Latest local safe block reported by the node.
+    indexed: Option<u64>,
+    /// Latest unsafe block reported by the node.
+    target: Option<u64>,
+}
+
+impl BackfillProgress {
+    fn new(chain_id: ChainId) -> Self {
+        metrics::describe_gauge!(
+            BACKFILL_REMAINING_BLOCKS,
+            metrics::Unit::Count,
+            "Number of blocks a managed node still has to derive to catch up with its unsafe head",
+        );
+        Self { chain_id, indexed: None, target: None }
+    }
+
+    /// Number of blocks left to backfill, or `None` until both heads have been reported.
+    fn remaining(&self) -> Option<u64> {
+        // zero once synced, including when the safe head briefly runs ahead of the unsafe one
+        Some(self.target?.saturating_sub(self.indexed?))
+    }
+
+    /// Updates the progress from an incoming event, reporting the remaining blocks once known.
+    fn observe(&mut self, event: &ManagedEvent) -> Option<u64> {
+        if let Some(block) = &event.unsafe_block {
+            self.target = Some(block.number);
+        }
+        if let Some(pair) = event.derivation_update.as_ref().or(event.exhaust_l1.as_ref()) {
+            self.indexed = Some(pair.derived.number);
+        }
+
+        let remaining = self.remaining()?;
+        metrics::gauge!(BACKFILL_REMAINING_BLOCKS, "chain_id" => self.chain_id.to_string())
+            .set(remaining as f64);
+        Some(remaining)
+    }
+}
+
 async fn handle_subscription_event<N: SubscriptionHandler>(handler: &Arc<N>, event: ManagedEvent) {
     if let Some(reset_id) = &event.reset {
         if let Err(err) = handler.handle_reset(reset_id).await {
@@ -267,12 +389,14 @@ mod tests {
     use kona_protocol::BlockInfo;
     use kona_supervisor_core::syncnode::{
         ClientError, ManagedNodeClient, ManagedNodeCommand, ManagedNodeController,
-        ManagedNodeError, SubscriptionHandler,
+        ManagedNodeError, ResetCause, SubscriptionHandler,
+    };
+    use kona_supervisor_types::{
+        BlockSeal, OutputV0, Receipts, SafetyLevelUpdate, SubscriptionEvent,
     };
-    use kona_supervisor_types::{BlockSeal, OutputV0, Receipts, SubscriptionEvent};
     use mockall::{mock, predicate::*};
     use std::sync::Arc;
-    use tokio::sync::mpsc;
+    use tokio::sync::{mpsc, oneshot};
     use tokio_util::sync::CancellationToken;
 
     // Mock the ManagedNodeController trait
@@ -285,8 +409,10 @@ mod tests {
             async fn update_finalized(&self, finalized_block_id: BlockNumHash) -> Result<(), ManagedNodeError>;
             async fn update_cross_unsafe(&self, cross_unsafe_block_id: BlockNumHash) -> Result<(), ManagedNodeError>;
             async fn update_cross_safe(&self,source_block_id: BlockNumHash,derived_block_id: BlockNumHash) -> Result<(), ManagedNodeError>;
-            async fn reset(&self) -> Result<(), ManagedNodeError>;
+            async fn update_safety_levels(&self, updates: Vec<SafetyLevelUpdate>) -> Result<(), ManagedNodeError>;
+            async fn reset(&self, cause: ResetCause) -> Result<(), ManagedNodeError>;
             async fn invalidate_block(&self, seal: BlockSeal) -> Result<(), ManagedNodeError>;
+            async fn fetch_block_receipts(&self, block_id: BlockNumHash) -> Result<Receipts, ManagedNodeError>;
         }
 
         #[async_trait::async_trait]
@@ -333,9 +459,14 @@ mod tests {
 
     #[tokio::test]
     async fn test_run_command_task_update_finalized_and_reset() {
+        let block_id = BlockNumHash::new(1, B256::random());
         let mut mock_node = MockNode::new();
-        mock_node.expect_update_finalized().times(1).returning(|_| Ok(()));
-        mock_node.expect_reset().times(1).returning(|| Ok(()));
+        mock_node
+            .expect_update_safety_levels()
+            .with(eq(vec![SafetyLevelUpdate::finalized(block_id)]))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_node.expect_reset().with(eq(ResetCause::ForcedRewind)).times(1).returning(|_| Ok(()));
 
         let node = Arc::new(mock_node);
         let (tx, rx) = mpsc::channel(10);
@@ -345,12 +476,10 @@ mod tests {
         let handle = tokio::spawn(super::run_command_task(node.clone(), rx, cancel_token.clone()));
 
         // Send commands
-        tx.send(ManagedNodeCommand::UpdateFinalized {
-            block_id: BlockNumHash::new(1, B256::random()),
-        })
-        .await
-        .unwrap();
-        tx.send(ManagedNodeCommand::Reset {}).await.unwrap();
+        tx.send(Traced::new(ManagedNodeCommand::UpdateFinalized { block_id })).await.unwrap();
+        tx.send(Traced::new(ManagedNodeCommand::Reset { cause: ResetCause::ForcedRewind }))
+            .await
+            .unwrap();
 
         // Drop the sender to close the channel and end the task
         drop(tx);
@@ -359,4 +488,143 @@ mod tests {
         let result = handle.await.unwrap();
         assert!(matches!(result, Err(SupervisorRpcActorError::CommandReceiverClosed)));
     }
+
+    #[tokio::test]
+    async fn test_run_command_task_batches_queued_safety_level_updates() {
+        let source = BlockNumHash::new(3, B256::random());
+        let cross_unsafe = BlockNumHash::new(12, B256::random());
+        let cross_safe = BlockNumHash::new(10, B256::random());
+        let finalized = BlockNumHash::new(8, B256::random());
+        let mut mock_node = MockNode::new();
+        let mut seq = mockall::Sequence::new();
+        mock_node
+            .expect_update_safety_levels()
+            .with(eq(vec![
+                SafetyLevelUpdate::cross_unsafe(cross_unsafe),
+                SafetyLevelUpdate::cross_safe(source, cross_safe),
+            ]))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(()));
+        mock_node.expect_reset().times(1).in_sequence(&mut seq).returning(|_| Ok(()));
+        mock_node
+            .expect_update_safety_levels()
+            .with(eq(vec![SafetyLevelUpdate::finalized(finalized)]))
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_| Ok(()));
+
+        // queue all commands before the task starts, so they are received at once
+        let (tx, rx) = mpsc::channel(10);
+        let commands = [
+            ManagedNodeCommand::UpdateCrossUnsafe { block_id: cross_unsafe },
+            ManagedNodeCommand::UpdateCrossSafe {
+                source_block_id: source,
+                derived_block_id: cross_safe,
+            },
+            ManagedNodeCommand::Reset { cause: ResetCause::ForcedRewind },
+            ManagedNodeCommand::UpdateFinalized { block_id: finalized },
+        ];
+        for command in commands {
+            tx.send(Traced::new(command)).await.unwrap();
+        }
+        drop(tx);
+
+        let result =
+            super::run_command_task(Arc::new(mock_node), rx, CancellationToken::new()).await;
+        assert!(matches!(result, Err(SupervisorRpcActorError::CommandReceiverClosed)));
+    }
+
+    #[tokio::test]
+    async fn test_run_command_task_fetch_receipts() {
+        let block_id = BlockNumHash::new(7, B256::random());
+        let unknown_block_id = BlockNumHash::new(8, B256::random());
+        let mut mock_node = MockNode::new();
+        mock_node
+            .expect_fetch_block_receipts()
+            .with(eq(block_id))
+            .times(1)
+            .returning(|_| Ok(Receipts::default()));
+        mock_node
+            .expect_fetch_block_receipts()
+            .with(eq(unknown_block_id))
+            .times(1)
+            .returning(|_| Err(ManagedNodeError::Timeout));
+
+        let node = Arc::new(mock_node);
+        let (tx, rx) = mpsc::channel(10);
+        let cancel_token = CancellationToken::new();
+        let handle = tokio::spawn(super::run_command_task(node.clone(), rx, cancel_token.clone()));
+
+        let (resp, receipts) = oneshot::channel();
+        tx.send(Traced::new(ManagedNodeCommand::FetchReceipts { block_id, resp })).await.unwrap();
+        assert_eq!(receipts.await.unwrap(), Ok(Receipts::default()));
+
+        let (resp, receipts) = oneshot::channel();
+        tx.send(Traced::new(ManagedNodeCommand::FetchReceipts {
+            block_id: unknown_block_id,
+            resp,
+        }))
+        .await
+        .unwrap();
+        assert_eq!(receipts.await.unwrap(), Err(ManagedNodeError::Timeout));
+
+        drop(tx);
+        let result = handle.await.unwrap();
+        assert!(matches!(result, Err(SupervisorRpcActorError::CommandReceiverClosed)));
+    }
+
+    #[test]
+    fn test_backfill_progress_tracks_catch_up() {
+        fn unsafe_block(number: u64) -> ManagedEvent {
+            ManagedEvent {
+                unsafe_block: Some(BlockInfo { number, ..Default::default() }),
+                ..Default::default()
+            }
+        }
+        fn derived(number: u64) -> ManagedEvent {
+            ManagedEvent {
+                derivation_update: Some(DerivedRefPair {
+                    source: BlockInfo::default(),
+                    derived: BlockInfo { number, ..Default::default() },
+                }),
+                ..Default::default()
+            }
+        }
+
+        let recorder = metrics_util::debugging::DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+        let gauge = || {
+            snapshotter.snapshot().into_vec().into_iter().find_map(|(key, _, _, value)| {
+                let key = key.key();
+                let chain_id = key.labels().find(|label| label.key() == "chain_id")?;
+                match value {
+                    metrics_util::debugging::DebugValue::Gauge(value)
+                        if key.name() == BACKFILL_REMAINING_BLOCKS =>
+                    {
+                        Some((chain_id.value().to_string(), value.into_inner()))
+                    }
+                    _ => None,
+                }
+            })
+        };
+
+        let mut progress = BackfillProgress::new(10);
+
+        // nothing reported until both heads are known
+        assert_eq!(progress.observe(&derived(40)), None);
+        assert_eq!(gauge(), None);
+        assert_eq!(progress.observe(&unsafe_block(100)), Some(60));
+        assert_eq!(gauge(), Some(("10".to_string(), 60.0)));
+        assert_eq!(progress.observe(&derived(90)), Some(10));
+        assert_eq!(progress.observe(&unsafe_block(105)), Some(15));
+        assert_eq!(gauge(), Some(("10".to_string(), 15.0)));
+
+        // fully synced
+        assert_eq!(progress.observe(&derived(105)), Some(0));
+        assert_eq!(progress.observe(&derived(106)), Some(0));
+        assert_eq!(progress.remaining(), Some(0));
+        assert_eq!(gauge(), Some(("10".to_string(), 0.0)));
+    }
 }
@@ -1,38 +1,136 @@
 use async_trait::async_trait;
 use kona_interop::InteropValidator;
-use kona_supervisor_core::{ChainProcessor, event::ChainEvent, syncnode::BlockProvider};
-use kona_supervisor_storage::{
-    DerivationStorage, HeadRefStorageWriter, LogStorage, StorageRewinder,
+use kona_supervisor_core::{
+    ChainProcessor, ChainProcessorError,
+    event::{ChainEvent, Traced},
+    syncnode::BlockProvider,
 };
+use kona_supervisor_storage::{DerivationStorage, HeadRefStorage, LogStorage, StorageRewinder};
+use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+use tracing::{Instrument, debug_span, info, warn};
 
-use crate::SupervisorActor;
+use crate::{SupervisorActor, shutdown::DrainStats};
 
 /// Represents an actor that processes chain events using the [`ChainProcessor`].
 /// It listens for [`ChainEvent`]s and handles them accordingly.
+///
+/// On cancellation the actor stops accepting new events and handles the ones already queued,
+/// until the queue is empty or the force-stop token is cancelled.
+///
+/// The actor is restarted through its restart channel, after which the events still queued are
+/// handled from a clean processing state, see [`ChainProcessor::restart`].
 #[derive(Debug)]
 pub struct ChainProcessorActor<P, W, V> {
     chain_processor: ChainProcessor<P, W, V>,
     cancel_token: CancellationToken,
-    event_rx: mpsc::Receiver<ChainEvent>,
+    event_rx: mpsc::Receiver<Traced<ChainEvent>>,
+    restart_rx: mpsc::Receiver<()>,
+    force_stop_token: CancellationToken,
+    drain_stats: Arc<DrainStats>,
 }
 
 impl<P, W, V> ChainProcessorActor<P, W, V>
 where
     P: BlockProvider + 'static,
     V: InteropValidator + 'static,
-    W: LogStorage + DerivationStorage + HeadRefStorageWriter + StorageRewinder + 'static,
+    W: LogStorage + DerivationStorage + HeadRefStorage + StorageRewinder + 'static,
 {
     /// Creates a new [`ChainProcessorActor`].
-    pub const fn new(
+    pub fn new(
         chain_processor: ChainProcessor<P, W, V>,
         cancel_token: CancellationToken,
-        event_rx: mpsc::Receiver<ChainEvent>,
+        event_rx: mpsc::Receiver<Traced<ChainEvent>>,
     ) -> Self {
-        Self { chain_processor, cancel_token, event_rx }
+        Self {
+            chain_processor,
+            cancel_token,
+            event_rx,
+            restart_rx: mpsc::channel(1).1,
+            force_stop_token: CancellationToken::new(),
+            drain_stats: Arc::new(DrainStats::default()),
+        }
+    }
+
+    /// Bounds the shutdown drain by `force_stop_token`, recording its outcome in `drain_stats`.
+    pub(crate) fn with_drain(
+        mut self,
+        force_stop_token: CancellationToken,
+        drain_stats: Arc<DrainStats>,
+    ) -> Self {
+        self.force_stop_token = force_stop_token;
+        self.drain_stats = drain_stats;
+        self
+    }
+
+    /// Restarts the actor whenever a message is received on `restart_rx`.
+    pub(crate) fn with_restarts(mut self, restart_rx: mpsc::Receiver<()>) -> Self {
+        self.restart_rx = restart_rx;
+        self
+    }
+
+    async fn handle(&mut self, traced: Traced<ChainEvent>) -> Result<(), ChainProcessorError> {
+        let (parent, event) = traced.into_parts();
+        self.chain_processor
+            .handle_event(event)
+            .instrument(debug_span!(parent: &parent, "chain_event"))
+            .await
+    }
+
+    /// Closes the event channel and handles the events still queued in it.
+    ///
+    /// Stops at the first fatal error, dropping the remaining events. Once the force-stop token is
+    /// cancelled, the event being handled is abandoned and the remaining events are dropped.
+    async fn drain(&mut self) -> Result<(), ChainProcessorError> {
+        self.event_rx.close();
+        let force_stop_token = self.force_stop_token.clone();
+
+        loop {
+            let traced = tokio::select! {
+                biased;
+
+                _ = force_stop_token.cancelled() => {
+                    self.record_force_stop(self.event_rx.len());
+                    return Ok(());
+                }
+                maybe_event = self.event_rx.recv() => {
+                    let Some(traced) = maybe_event else {
+                        return Ok(());
+                    };
+                    traced
+                }
+            };
+
+            tokio::select! {
+                biased;
+
+                _ = force_stop_token.cancelled() => {
+                    self.record_force_stop(self.event_rx.len() + 1);
+                    return Ok(());
+                }
+                result = self.handle(traced) => {
+                    if let Err(err) = result {
+                        self.drain_stats.record_dropped(self.event_rx.len());
+                        return Err(err);
+                    }
+                    self.drain_stats.record_drained();
+                }
+            }
+        }
+    }
+
+    /// Records the events dropped because the drain was force-stopped.
+    fn record_force_stop(&self, dropped: usize) {
+        if dropped > 0 {
+            warn!(
+                target: "supervisor::chain_processor_actor",
+                dropped,
+                "Drain timed out, dropping queued chain events"
+            );
+        }
+        self.drain_stats.record_dropped(dropped);
     }
 }
 
@@ -41,7 +139,7 @@ impl<P, W, V> SupervisorActor for ChainProcessorActor<P, W, V>
 where
     P: BlockProvider + 'static,
     V: InteropValidator + 'static,
-    W: LogStorage + DerivationStorage + HeadRefStorageWriter + StorageRewinder + 'static,
+    W: LogStorage + DerivationStorage + HeadRefStorage + StorageRewinder + 'static,
 {
     type InboundEvent = ChainEvent;
     type Error = ChainProcessorActorError;
@@ -54,9 +152,28 @@ where
 
         loop {
             tokio::select! {
+                // a restart applies to all events still queued, and cancellation must not wait
+                // for the queue to run empty
+                biased;
+
+                Some(()) = self.restart_rx.recv() => {
+                    info!(
+                        target: "supervisor::chain_processor_actor",
+                        "Restarting ChainProcessorActor"
+                    );
+                    self.chain_processor.restart();
+                }
+                _ = self.cancel_token.cancelled() => {
+                    info!(
+                        target: "supervisor::chain_processor_actor",
+                        "ChainProcessorActor cancellation requested, draining queued events..."
+                    );
+                    self.drain().await?;
+                    break;
+                }
                 maybe_event = self.event_rx.recv() => {
-                    if let Some(event) = maybe_event {
-                        self.chain_processor.handle_event(event).await;
+                    if let Some(traced) = maybe_event {
+                        self.handle(traced).await?;
                     } else {
                         info!(
                             target: "supervisor::chain_processor_actor",
@@ -65,13 +182,6 @@ where
                         return Err(ChainProcessorActorError::ReceiverClosed);
                     }
                 }
-                _ = self.cancel_token.cancelled() => {
-                    info!(
-                        target: "supervisor::chain_processor_actor",
-                        "ChainProcessorActor cancellation requested, stopping..."
-                    );
-                    break;
-                }
             }
         }
 
@@ -84,26 +194,31 @@ pub enum ChainProcessorActorError {
     /// Error when the chain event receiver is closed.
     #[error("Chain event receiver closed")]
     ReceiverClosed,
+    /// The chain processor hit an error it can't recover from.
+    #[error(transparent)]
+    Fatal(#[from] ChainProcessorError),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::SupervisorActor;
+    use crate::{ShutdownReport, SupervisorActor};
     use alloy_eips::BlockNumHash;
     use alloy_primitives::{B256, ChainId};
-    use kona_interop::{DerivedRefPair, InteropValidationError};
+    use kona_interop::{DerivedRefPair, InteropValidationError, SafetyLevel};
     use kona_protocol::BlockInfo;
     use kona_supervisor_core::{
-        LogIndexer,
+        ChainProcessorErrorCategory, LogIndexer,
+        event::SupervisorEvent,
         syncnode::{BlockProvider, ManagedNodeCommand, ManagedNodeDataProvider, ManagedNodeError},
     };
     use kona_supervisor_storage::{
-        DerivationStorageReader, DerivationStorageWriter, HeadRefStorageWriter, LogStorageReader,
-        LogStorageWriter, StorageError, StorageRewinder,
+        DerivationStorageReader, DerivationStorageWriter, HeadRefStorageReader,
+        HeadRefStorageWriter, LogStorageReader, LogStorageWriter, StorageError, StorageRewinder,
     };
-    use kona_supervisor_types::{Log, OutputV0, Receipts};
+    use kona_supervisor_types::{Log, OutputV0, Receipts, SuperHead};
     use mockall::{mock, predicate::*};
+    use reth_db::{DatabaseError, mdbx};
     use std::sync::Arc;
     use tokio::sync::mpsc;
     use tokio_util::sync::CancellationToken;
@@ -186,6 +301,11 @@ mod tests {
             ) -> Result<(), StorageError>;
         }
 
+        impl HeadRefStorageReader for Db {
+            fn get_safety_head_ref(&self, safety_level: SafetyLevel) -> Result<BlockInfo, StorageError>;
+            fn get_super_head(&self) -> Result<SuperHead, StorageError>;
+        }
+
         impl HeadRefStorageWriter for Db {
             fn update_finalized_using_source(
                 &self,
@@ -255,7 +375,7 @@ mod tests {
             timestamp: 1000,
             ..Default::default()
         };
-        tx.send(ChainEvent::CrossUnsafeUpdate { block }).await.unwrap();
+        tx.send(Traced::new(ChainEvent::CrossUnsafeUpdate { block })).await.unwrap();
 
         // Cancel after a short delay to exit the loop
         let cancel = cancel_token.clone();
@@ -267,13 +387,174 @@ mod tests {
         let result = actor.start().await;
         assert!(result.is_ok());
 
-        if let Some(ManagedNodeCommand::UpdateCrossUnsafe { block_id }) = mn_receiver.recv().await {
+        if let Some(ManagedNodeCommand::UpdateCrossUnsafe { block_id }) =
+            mn_receiver.recv().await.map(Traced::into_inner)
+        {
             assert_eq!(block_id, block.id());
         } else {
             panic!("Expected UpdateCrossUnsafe command");
         }
     }
 
+    #[tokio::test]
+    async fn test_actor_reports_processor_failure_after_retries() {
+        let mock_node = MockNode::new();
+        let mut mock_db = MockDb::new();
+        // the initial attempt and both retries fail
+        mock_db.expect_update_finalized_using_source().times(3).returning(|_| {
+            Err(StorageError::from(DatabaseError::Commit(mdbx::Error::Busy.into())))
+        });
+        let validator = MockValidator::new();
+        let (mn_sender, _mn_receiver) = mpsc::channel(1);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+        let (supervisor_event_sender, mut supervisor_event_receiver) = mpsc::channel(1);
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender)
+                .with_max_retries(2)
+                .with_supervisor_event_sender(supervisor_event_sender);
+
+        let cancel_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(1);
+        let actor = ChainProcessorActor::new(processor, cancel_token.clone(), rx);
+        let handle = tokio::spawn(actor.start());
+
+        let finalized_source_block = BlockInfo { number: 1, ..Default::default() };
+        tx.send(Traced::new(ChainEvent::FinalizedSourceUpdate { finalized_source_block }))
+            .await
+            .unwrap();
+
+        match supervisor_event_receiver.recv().await {
+            Some(SupervisorEvent::ChainProcessorFailed { chain_id, category, error: _ }) => {
+                assert_eq!(chain_id, 1);
+                assert_eq!(category, ChainProcessorErrorCategory::Storage);
+            }
+            other => panic!("Expected ChainProcessorFailed event, got {other:?}"),
+        }
+        // the failure is reported once, after the last retry
+        assert!(supervisor_event_receiver.try_recv().is_err());
+
+        cancel_token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_actor_does_not_retry_persistent_failure() {
+        let mock_node = MockNode::new();
+        let mock_db = MockDb::new();
+        let validator = MockValidator::new();
+        // the managed node is gone, so every cross unsafe update fails
+        let (mn_sender, mn_receiver) = mpsc::channel(1);
+        drop(mn_receiver);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+        let (supervisor_event_sender, mut supervisor_event_receiver) = mpsc::channel(1);
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender)
+                .with_max_retries(2)
+                .with_supervisor_event_sender(supervisor_event_sender);
+
+        let cancel_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(1);
+        let actor = ChainProcessorActor::new(processor, cancel_token.clone(), rx);
+
+        let block = BlockInfo { number: 1, ..Default::default() };
+        tx.send(Traced::new(ChainEvent::CrossUnsafeUpdate { block })).await.unwrap();
+        cancel_token.cancel();
+
+        // a closed channel isn't transient, so the event is dropped without being retried
+        let started = tokio::time::Instant::now();
+        assert!(actor.start().await.is_ok());
+        assert!(started.elapsed() < ChainProcessor::<MockNode, MockDb, MockValidator>::RETRY_DELAY);
+        assert!(supervisor_event_receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_actor_restart_clears_invalidated_block() {
+        let mock_node = MockNode::new();
+        let mut mock_db = MockDb::new();
+        let source = BlockInfo { number: 10, ..Default::default() };
+        mock_db.expect_derived_to_source().times(2).returning(move |_| Ok(source));
+        mock_db.expect_rewind().times(2).returning(|_| Ok(()));
+        let validator = MockValidator::new();
+        let (mn_sender, mut mn_receiver) = mpsc::channel(1);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
+
+        let cancel_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(1);
+        let (restart_tx, restart_rx) = mpsc::channel(1);
+        let actor =
+            ChainProcessorActor::new(processor, cancel_token.clone(), rx).with_restarts(restart_rx);
+        let handle = tokio::spawn(actor.start());
+
+        let block = BlockInfo { number: 1, ..Default::default() };
+        tx.send(Traced::new(ChainEvent::InvalidateBlock { block })).await.unwrap();
+        assert!(matches!(
+            mn_receiver.recv().await.map(Traced::into_inner),
+            Some(ManagedNodeCommand::InvalidateBlock { .. })
+        ));
+
+        // without the restart, the pending invalidation would skip the second one
+        restart_tx.send(()).await.unwrap();
+        tx.send(Traced::new(ChainEvent::InvalidateBlock { block })).await.unwrap();
+        assert!(matches!(
+            mn_receiver.recv().await.map(Traced::into_inner),
+            Some(ManagedNodeCommand::InvalidateBlock { .. })
+        ));
+
+        cancel_token.cancel();
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_actor_halts_on_disk_full() {
+        let mock_node = MockNode::new();
+        let mut mock_db = MockDb::new();
+        mock_db
+            .expect_update_finalized_using_source()
+            .times(1)
+            .returning(|_| Err(StorageError::DiskFull));
+        let validator = MockValidator::new();
+        let (mn_sender, mut mn_receiver) = mpsc::channel(1);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+        let (supervisor_event_sender, mut supervisor_event_receiver) = mpsc::channel(1);
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender)
+                .with_max_retries(2)
+                .with_supervisor_event_sender(supervisor_event_sender);
+
+        let (tx, rx) = mpsc::channel(1);
+        let actor = ChainProcessorActor::new(processor, CancellationToken::new(), rx);
+
+        let finalized_source_block = BlockInfo { number: 1, ..Default::default() };
+        tx.send(Traced::new(ChainEvent::FinalizedSourceUpdate { finalized_source_block }))
+            .await
+            .unwrap();
+
+        // the error is neither retried nor reported, the actor stops with it instead
+        let result = actor.start().await;
+        assert!(matches!(
+            result,
+            Err(ChainProcessorActorError::Fatal(ChainProcessorError::StorageError(
+                StorageError::DiskFull
+            )))
+        ));
+        assert!(mn_receiver.try_recv().is_err());
+        assert!(supervisor_event_receiver.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_actor_receiver_closed() {
         let mock_node = MockNode::new();
@@ -288,7 +569,7 @@ mod tests {
             ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
 
         let cancel_token = CancellationToken::new();
-        let (tx, rx) = mpsc::channel::<ChainEvent>(1); // No sender, so channel is closed
+        let (tx, rx) = mpsc::channel::<Traced<ChainEvent>>(1); // No sender, so channel is closed
         drop(tx);
 
         let actor = ChainProcessorActor::new(processor, cancel_token, rx);
@@ -311,7 +592,7 @@ mod tests {
             ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
 
         let cancel_token = CancellationToken::new();
-        let (_tx, rx) = mpsc::channel::<ChainEvent>(1);
+        let (_tx, rx) = mpsc::channel::<Traced<ChainEvent>>(1);
 
         let actor = ChainProcessorActor::new(processor, cancel_token.clone(), rx);
 
@@ -321,4 +602,129 @@ mod tests {
         let result = actor.start().await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_actor_drains_queued_events_on_cancellation() {
+        let mock_node = MockNode::new();
+        let mock_db = MockDb::new();
+        let validator = MockValidator::new();
+        let (mn_sender, mut mn_receiver) = mpsc::channel(3);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
+
+        let cancel_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(3);
+        let stats = Arc::new(DrainStats::default());
+
+        let actor = ChainProcessorActor::new(processor, cancel_token.clone(), rx)
+            .with_drain(CancellationToken::new(), stats.clone());
+
+        // Enqueue events before the actor gets a chance to run, then request shutdown
+        let blocks: Vec<_> =
+            (1..=3).map(|number| BlockInfo { number, ..Default::default() }).collect();
+        for block in &blocks {
+            tx.send(Traced::new(ChainEvent::CrossUnsafeUpdate { block: *block })).await.unwrap();
+        }
+        cancel_token.cancel();
+
+        let result = actor.start().await;
+        assert!(result.is_ok());
+
+        // New events are rejected once draining starts
+        assert!(
+            tx.try_send(Traced::new(ChainEvent::CrossUnsafeUpdate { block: blocks[0] })).is_err()
+        );
+
+        // All in-flight events were handled
+        for block in &blocks {
+            match mn_receiver.recv().await.map(Traced::into_inner) {
+                Some(ManagedNodeCommand::UpdateCrossUnsafe { block_id }) => {
+                    assert_eq!(block_id, block.id())
+                }
+                other => panic!("Expected UpdateCrossUnsafe command, got {other:?}"),
+            }
+        }
+        assert_eq!(stats.report(false), ShutdownReport { drained: 3, dropped: 0, forced: false });
+    }
+
+    #[tokio::test]
+    async fn test_actor_drops_queued_events_on_force_stop() {
+        let mock_node = MockNode::new();
+        let mock_db = MockDb::new();
+        let validator = MockValidator::new();
+        let (mn_sender, mut mn_receiver) = mpsc::channel(2);
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
+
+        let cancel_token = CancellationToken::new();
+        let force_stop_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(2);
+        let stats = Arc::new(DrainStats::default());
+
+        let actor = ChainProcessorActor::new(processor, cancel_token.clone(), rx)
+            .with_drain(force_stop_token.clone(), stats.clone());
+
+        for number in 1..=2 {
+            let block = BlockInfo { number, ..Default::default() };
+            tx.send(Traced::new(ChainEvent::CrossUnsafeUpdate { block })).await.unwrap();
+        }
+        cancel_token.cancel();
+        force_stop_token.cancel();
+
+        let result = actor.start().await;
+        assert!(result.is_ok());
+
+        assert!(mn_receiver.try_recv().is_err());
+        assert_eq!(stats.report(true), ShutdownReport { drained: 0, dropped: 2, forced: true });
+    }
+
+    #[tokio::test]
+    async fn test_actor_force_stop_interrupts_event_in_flight() {
+        let mock_node = MockNode::new();
+        let mock_db = MockDb::new();
+        let validator = MockValidator::new();
+        // the managed node never reads its commands, so handling a cross unsafe update blocks
+        let (mn_sender, _mn_receiver) = mpsc::channel(1);
+        mn_sender
+            .try_send(Traced::new(ManagedNodeCommand::UpdateCrossUnsafe {
+                block_id: BlockNumHash::default(),
+            }))
+            .unwrap();
+
+        let db = Arc::new(mock_db);
+        let log_indexer = LogIndexer::new(1, Some(Arc::new(mock_node)), db.clone());
+
+        let processor =
+            ChainProcessor::new(Arc::new(validator), 1, Arc::new(log_indexer), db, mn_sender);
+
+        let cancel_token = CancellationToken::new();
+        let force_stop_token = CancellationToken::new();
+        let (tx, rx) = mpsc::channel(2);
+        let stats = Arc::new(DrainStats::default());
+
+        let actor = ChainProcessorActor::new(processor, cancel_token.clone(), rx)
+            .with_drain(force_stop_token.clone(), stats.clone());
+
+        for number in 1..=2 {
+            let block = BlockInfo { number, ..Default::default() };
+            tx.send(Traced::new(ChainEvent::CrossUnsafeUpdate { block })).await.unwrap();
+        }
+        cancel_token.cancel();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            force_stop_token.cancel();
+        });
+
+        let result = actor.start().await;
+        assert!(result.is_ok());
+        assert_eq!(stats.report(true), ShutdownReport { drained: 0, dropped: 2, forced: true });
+    }
 }
@@ -2,7 +2,10 @@ use std::{io, net::SocketAddr};
 
 use async_trait::async_trait;
 use derive_more::Constructor;
-use jsonrpsee::{RpcModule, server::ServerBuilder};
+use jsonrpsee::{
+    RpcModule,
+    server::{BatchRequestConfig, ServerBuilder},
+};
 use thiserror::Error;
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info};
@@ -12,6 +15,8 @@ use crate::SupervisorActor;
 #[derive(Debug, Constructor)]
 pub struct SupervisorRpcActor<D> {
     rpc_addr: SocketAddr,
+    max_request_body_size: u32,
+    max_batch_len: u32,
     rpc_module: RpcModule<D>,
     cancel_token: CancellationToken,
 }
@@ -32,7 +37,13 @@ where
         );
 
         // let supervisor_rpc = SupervisorRpc::new(self.supervisor.clone());
-        let server = ServerBuilder::default().build(self.rpc_addr).await?;
+        // oversized requests and batches are rejected with a JSON-RPC error before any call is
+        // deserialized
+        let server = ServerBuilder::default()
+            .max_request_body_size(self.max_request_body_size)
+            .set_batch_request_config(BatchRequestConfig::Limit(self.max_batch_len))
+            .build(self.rpc_addr)
+            .await?;
         // let mut root = supervisor_rpc.into_rpc();
         let handle = server.start(self.rpc_module);
 
@@ -84,7 +95,9 @@ mod tests {
     use kona_interop::{DependencySet, ExecutingDescriptor, SafetyLevel};
     use kona_protocol::BlockInfo;
     use kona_supervisor_core::{SupervisorError, SupervisorService};
-    use kona_supervisor_rpc::{SuperRootOutputRpc, SupervisorApiServer};
+    use kona_supervisor_rpc::{
+        ChainStatus, MessageDependency, SuperRootOutputRpc, SupervisorApiServer,
+    };
     use kona_supervisor_types::SuperHead;
     use mockall::mock;
     use std::{
@@ -111,7 +124,9 @@ mod tests {
             fn finalized(&self, chain: ChainId) -> Result<BlockInfo, SupervisorError>;
             fn finalized_l1(&self) -> Result<BlockInfo, SupervisorError>;
             fn check_access_list(&self, inbox_entries: Vec<B256>, min_safety: SafetyLevel, executing_descriptor: ExecutingDescriptor) -> Result<(), SupervisorError>;
+            fn message_dependencies(&self, chain: ChainId, block: BlockNumHash) -> Result<Vec<MessageDependency>, SupervisorError>;
             async fn super_root_at_timestamp(&self, timestamp: u64) -> Result<SuperRootOutputRpc, SupervisorError>;
+            async fn chains(&self) -> Result<Vec<ChainStatus>, SupervisorError>;
         }
     );
 
@@ -123,7 +138,7 @@ mod tests {
 
         let supervisor_rpc = kona_supervisor_core::rpc::SupervisorRpc::new(supervisor.clone());
         let rpc_module = supervisor_rpc.into_rpc();
-        let actor = SupervisorRpcActor::new(addr, rpc_module, cancel_token.clone());
+        let actor = SupervisorRpcActor::new(addr, 1024, 10, rpc_module, cancel_token.clone());
 
         let handle = tokio::spawn(actor.start());
 
@@ -137,4 +152,45 @@ mod tests {
         let result = handle.await.unwrap();
         assert!(result.is_ok() || matches!(result, Err(SupervisorRpcActorError::StopFailed)));
     }
+
+    #[tokio::test]
+    async fn test_supervisor_rpc_actor_rejects_oversized_batch() {
+        use jsonrpsee::types::error::TOO_BIG_BATCH_REQUEST_CODE;
+        use tokio::{
+            io::{AsyncReadExt, AsyncWriteExt},
+            net::TcpStream,
+        };
+
+        // the actor doesn't expose the address it bound to, so pick a free port up front
+        let addr = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let supervisor = Arc::new(MockSupervisorService::new());
+        let cancel_token = CancellationToken::new();
+        let rpc_module = kona_supervisor_core::rpc::SupervisorRpc::new(supervisor).into_rpc();
+        let actor = SupervisorRpcActor::new(addr, 1024 * 1024, 2, rpc_module, cancel_token.clone());
+        let handle = tokio::spawn(actor.start());
+
+        let call = r#"{"jsonrpc":"2.0","id":1,"method":"supervisor_checkAccessList","params":[]}"#;
+        let body = format!("[{call},{call},{call}]");
+        let request = format!(
+            "POST / HTTP/1.1\r\nhost: {addr}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        let mut stream = loop {
+            match TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            }
+        };
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        let response: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(response["error"]["code"], TOO_BIG_BATCH_REQUEST_CODE);
+
+        cancel_token.cancel();
+        let result = handle.await.unwrap();
+        assert!(result.is_ok() || matches!(result, Err(SupervisorRpcActorError::StopFailed)));
+    }
 }
@@ -5,5 +5,8 @@ mod service;
 
 pub use service::Service;
 
+mod shutdown;
+pub use shutdown::ShutdownReport;
+
 mod actors;
 pub use actors::SupervisorActor;
@@ -9,26 +9,49 @@ use jsonrpsee::client_transport::ws::Url;
 use kona_supervisor_core::{
     ChainProcessor, CrossSafetyCheckerJob, LogIndexer, ReorgHandler, Supervisor,
     config::Config,
-    event::ChainEvent,
+    event::{ChainEvent, SupervisorEvent, Traced},
     l1_watcher::L1Watcher,
     rpc::{AdminError, AdminRequest, AdminRpc, SupervisorRpc},
-    safety_checker::{CrossSafePromoter, CrossUnsafePromoter},
-    syncnode::{Client, ClientConfig, ManagedNode, ManagedNodeClient, ManagedNodeCommand},
+    safety_checker::{
+        CrossSafePromoter, CrossUnsafePromoter, DependencyFallback, RemoteDependencyFallback,
+    },
+    syncnode::{
+        Client, ClientConfig, ManagedNode, ManagedNodeClient, ManagedNodeCommand, ResetCause,
+    },
+};
+use kona_supervisor_rpc::{
+    SupervisorAdminApiServer, SupervisorApiServer, SupervisorClient, SupervisorClientConfig,
 };
-use kona_supervisor_rpc::{SupervisorAdminApiServer, SupervisorApiServer};
 use kona_supervisor_storage::{ChainDb, ChainDbFactory, DerivationStorageWriter, LogStorageWriter};
 use std::{collections::HashMap, sync::Arc};
-use tokio::{sync::mpsc, task::JoinSet, time::Duration};
+use tokio::{
+    sync::mpsc::{self, error::TrySendError},
+    task::JoinSet,
+    time::Duration,
+};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 
-use crate::actors::{
-    ChainProcessorActor, ManagedNodeActor, MetricWorker, SupervisorActor, SupervisorRpcActor,
+use crate::{
+    ShutdownReport,
+    actors::{
+        ChainProcessorActor, ManagedNodeActor, MetricWorker, SupervisorActor, SupervisorRpcActor,
+    },
+    shutdown::DrainStats,
 };
 
 // simplify long type signature
 type ManagedLogIndexer = LogIndexer<ManagedNode<ChainDb, Client>, ChainDb>;
 
+/// Default time allowed for in-flight events to drain on shutdown.
+const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time allowed for force-stopped tasks to record their dropped events before being aborted.
+const FORCE_STOP_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Number of times a chain processor retries a failed event before reporting the chain as failed.
+const CHAIN_PROCESSOR_MAX_RETRIES: usize = 3;
+
 /// The main service structure for the Kona
 /// [`SupervisorService`](`kona_supervisor_core::SupervisorService`). Orchestrates the various
 /// components of the supervisor.
@@ -42,13 +65,21 @@ pub struct Service {
     log_indexers: HashMap<ChainId, Arc<ManagedLogIndexer>>,
 
     // channels
-    chain_event_senders: HashMap<ChainId, mpsc::Sender<ChainEvent>>,
-    chain_event_receivers: HashMap<ChainId, mpsc::Receiver<ChainEvent>>,
-    managed_node_senders: HashMap<ChainId, mpsc::Sender<ManagedNodeCommand>>,
-    managed_node_receivers: HashMap<ChainId, mpsc::Receiver<ManagedNodeCommand>>,
+    chain_event_senders: HashMap<ChainId, mpsc::Sender<Traced<ChainEvent>>>,
+    chain_event_receivers: HashMap<ChainId, mpsc::Receiver<Traced<ChainEvent>>>,
+    managed_node_senders: HashMap<ChainId, mpsc::Sender<Traced<ManagedNodeCommand>>>,
+    managed_node_receivers: HashMap<ChainId, mpsc::Receiver<Traced<ManagedNodeCommand>>>,
     admin_receiver: Option<mpsc::Receiver<AdminRequest>>,
+    supervisor_event_sender: mpsc::Sender<SupervisorEvent>,
+    supervisor_event_receiver: mpsc::Receiver<SupervisorEvent>,
+    chain_processor_restart_senders: HashMap<ChainId, mpsc::Sender<()>>,
+    reorg_handler: Option<Arc<ReorgHandler<ChainDb>>>,
 
     cancel_token: CancellationToken,
+    // force-stops the tasks still draining once the drain timeout elapses
+    force_stop_token: CancellationToken,
+    drain_timeout: Duration,
+    drain_stats: Arc<DrainStats>,
     join_set: JoinSet<Result<(), anyhow::Error>>,
 }
 
@@ -56,8 +87,13 @@ impl Service {
     /// Creates a new Supervisor service instance.
     pub fn new(cfg: Config) -> Self {
         let config = Arc::new(cfg);
-        let database_factory = Arc::new(ChainDbFactory::new(config.datadir.clone()).with_metrics());
+        let mut database_factory = ChainDbFactory::new(config.datadir.clone()).with_metrics();
+        if config.storage_table_metrics {
+            database_factory = database_factory.with_table_metrics();
+        }
+        let database_factory = Arc::new(database_factory);
         let supervisor = Arc::new(Supervisor::new(config.clone(), database_factory.clone()));
+        let (supervisor_event_sender, supervisor_event_receiver) = mpsc::channel(100);
 
         Self {
             config,
@@ -72,25 +108,29 @@ impl Service {
             managed_node_senders: HashMap::new(),
             managed_node_receivers: HashMap::new(),
             admin_receiver: None,
+            supervisor_event_sender,
+            supervisor_event_receiver,
+            chain_processor_restart_senders: HashMap::new(),
+            reorg_handler: None,
 
             cancel_token: CancellationToken::new(),
+            force_stop_token: CancellationToken::new(),
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
+            drain_stats: Arc::new(DrainStats::default()),
             join_set: JoinSet::new(),
         }
     }
 
+    /// Sets how long [`Service::shutdown`] waits for in-flight events to drain before
+    /// force-stopping the remaining tasks.
+    pub const fn with_drain_timeout(mut self, drain_timeout: Duration) -> Self {
+        self.drain_timeout = drain_timeout;
+        self
+    }
+
     /// Initialises the Supervisor service.
     pub async fn initialise(&mut self) -> Result<()> {
-        // create sender and receiver channels for each chain
-        for chain_id in self.config.rollup_config_set.rollups.keys() {
-            let (chain_tx, chain_rx) = mpsc::channel::<ChainEvent>(1000);
-            self.chain_event_senders.insert(*chain_id, chain_tx);
-            self.chain_event_receivers.insert(*chain_id, chain_rx);
-
-            let (managed_node_tx, managed_node_rx) = mpsc::channel::<ManagedNodeCommand>(1000);
-            self.managed_node_senders.insert(*chain_id, managed_node_tx);
-            self.managed_node_receivers.insert(*chain_id, managed_node_rx);
-        }
-
+        self.init_channels();
         self.init_database().await?;
         self.init_chain_processor().await?;
         self.init_managed_nodes().await?;
@@ -103,12 +143,27 @@ impl Service {
         Ok(())
     }
 
+    fn init_channels(&mut self) {
+        // create sender and receiver channels for each chain
+        for chain_id in &self.config.rollup_config_set.chain_ids() {
+            let capacity = self.config.event_channel_capacity(*chain_id);
+            let (chain_tx, chain_rx) = mpsc::channel::<Traced<ChainEvent>>(capacity);
+            self.chain_event_senders.insert(*chain_id, chain_tx);
+            self.chain_event_receivers.insert(*chain_id, chain_rx);
+
+            let (managed_node_tx, managed_node_rx) =
+                mpsc::channel::<Traced<ManagedNodeCommand>>(1000);
+            self.managed_node_senders.insert(*chain_id, managed_node_tx);
+            self.managed_node_receivers.insert(*chain_id, managed_node_rx);
+        }
+    }
+
     async fn init_database(&self) -> Result<()> {
         info!(target: "supervisor::service", "Initialising databases for all chains...");
 
-        for (chain_id, config) in self.config.rollup_config_set.rollups.iter() {
+        for (chain_id, config) in self.config.rollup_config_set.iter() {
             // Initialise the database for each chain.
-            let db = self.database_factory.get_or_create_db(*chain_id)?;
+            let db = self.database_factory.get_or_create_db(chain_id)?;
             let interop_time = config.interop_time;
             let derived_pair = config.genesis.get_derived_pair();
             if config.is_interop(derived_pair.derived.timestamp) {
@@ -202,7 +257,7 @@ impl Service {
     async fn init_chain_processor(&mut self) -> Result<()> {
         info!(target: "supervisor::service", "Initialising chain processors for all chains...");
 
-        for (chain_id, _) in self.config.rollup_config_set.rollups.iter() {
+        for chain_id in &self.config.rollup_config_set.chain_ids() {
             let db = self.database_factory.get_db(*chain_id)?;
 
             let managed_node_sender = self
@@ -211,7 +266,12 @@ impl Service {
                 .ok_or(anyhow::anyhow!("no managed node sender found for chain {chain_id}"))?
                 .clone();
 
-            let log_indexer = Arc::new(LogIndexer::new(*chain_id, None, db.clone()));
+            let log_indexer = Arc::new(
+                LogIndexer::new(*chain_id, None, db.clone())
+                    .with_concurrency(self.config.log_indexer_concurrency)
+                    .with_managed_node_sender(managed_node_sender.clone())
+                    .with_metrics(),
+            );
             self.log_indexers.insert(*chain_id, log_indexer.clone());
 
             // initialise chain processor for the chain.
@@ -224,18 +284,27 @@ impl Service {
             );
 
             // todo: enable metrics only if configured
-            processor = processor.with_metrics();
+            processor = processor
+                .with_metrics()
+                .with_max_retries(CHAIN_PROCESSOR_MAX_RETRIES)
+                .with_supervisor_event_sender(self.supervisor_event_sender.clone());
 
             // Start the chain processor actor.
             let chain_event_receiver = self
                 .chain_event_receivers
                 .remove(chain_id)
                 .ok_or(anyhow::anyhow!("no chain event receiver found for chain {chain_id}"))?;
+            let (restart_sender, restart_receiver) = mpsc::channel(1);
+            self.chain_processor_restart_senders.insert(*chain_id, restart_sender);
 
             let cancel_token = self.cancel_token.clone();
+            let force_stop_token = self.force_stop_token.clone();
+            let drain_stats = self.drain_stats.clone();
             self.join_set.spawn(async move {
                 if let Err(err) =
                     ChainProcessorActor::new(processor, cancel_token, chain_event_receiver)
+                        .with_drain(force_stop_token, drain_stats)
+                        .with_restarts(restart_receiver)
                         .start()
                         .await
                 {
@@ -272,6 +341,10 @@ impl Service {
             })
             .collect::<Result<HashMap<ChainId, Arc<ChainDb>>>>()?;
 
+        // separate handler for rewinds requested through the admin API
+        self.reorg_handler =
+            Some(Arc::new(ReorgHandler::new(l1_rpc.clone(), chain_dbs_map.clone())));
+
         let database_factory = self.database_factory.clone();
         let cancel_token = self.cancel_token.clone();
         let event_senders = self.chain_event_senders.clone();
@@ -294,10 +367,31 @@ impl Service {
         Ok(())
     }
 
+    fn init_dependency_fallback(&self) -> Result<Option<Arc<dyn DependencyFallback>>> {
+        let Some(fallback_rpc) = &self.config.dependency_fallback_rpc else {
+            return Ok(None);
+        };
+
+        let url = Url::parse(fallback_rpc).map_err(|err| {
+            error!(target: "supervisor::service", %err, "Failed to parse dependency fallback RPC URL");
+            anyhow::anyhow!("failed to parse dependency fallback RPC URL: {err}")
+        })?;
+        info!(target: "supervisor::service", %url, "Validating missing dependencies with remote supervisor");
+
+        let client = SupervisorClient::with_config(url, SupervisorClientConfig::default())?;
+        Ok(Some(Arc::new(RemoteDependencyFallback::new(client))))
+    }
+
+    /// Spawns the cross-unsafe and cross-safe promotion jobs of every chain.
+    ///
+    /// Each job only advances its own chain, so promotion is correct in any order. Jobs are
+    /// spawned in ascending chain ID order, so startup and its logs are the same across runs. Once
+    /// spawned they run concurrently, and how their ticks interleave is up to the scheduler.
     async fn init_cross_safety_checker(&mut self) -> Result<()> {
         info!(target: "supervisor::service", "Initialising cross safety checker...");
 
-        for (&chain_id, config) in &self.config.rollup_config_set.rollups {
+        let fallback = self.init_dependency_fallback()?;
+        for (chain_id, config) in self.config.rollup_config_set.iter() {
             let db = Arc::clone(&self.database_factory);
             let cancel = self.cancel_token.clone();
 
@@ -307,7 +401,7 @@ impl Service {
                 .ok_or(anyhow::anyhow!("no chain event sender found for chain {chain_id}"))?
                 .clone();
 
-            let cross_safe_job = CrossSafetyCheckerJob::new(
+            let mut cross_safe_job = CrossSafetyCheckerJob::new(
                 chain_id,
                 db.clone(),
                 cancel.clone(),
@@ -315,14 +409,18 @@ impl Service {
                 CrossSafePromoter,
                 chain_event_sender.clone(),
                 self.config.clone(),
-            );
+            )
+            .with_disabled_levels(self.config.disabled_safety_levels.clone());
+            if let Some(fallback) = &fallback {
+                cross_safe_job = cross_safe_job.with_fallback(fallback.clone());
+            }
 
             self.join_set.spawn(async move {
                 cross_safe_job.run().await;
                 Ok(())
             });
 
-            let cross_unsafe_job = CrossSafetyCheckerJob::new(
+            let mut cross_unsafe_job = CrossSafetyCheckerJob::new(
                 chain_id,
                 db,
                 cancel,
@@ -330,7 +428,11 @@ impl Service {
                 CrossUnsafePromoter,
                 chain_event_sender,
                 self.config.clone(),
-            );
+            )
+            .with_disabled_levels(self.config.disabled_safety_levels.clone());
+            if let Some(fallback) = &fallback {
+                cross_unsafe_job = cross_unsafe_job.with_fallback(fallback.clone());
+            }
 
             self.join_set.spawn(async move {
                 cross_unsafe_job.run().await;
@@ -345,10 +447,14 @@ impl Service {
         let database_factory = self.database_factory.clone();
         let cancel_token = self.cancel_token.clone();
         self.join_set.spawn(async move {
-            if let Err(err) =
-                MetricWorker::new(Duration::from_secs(30), vec![database_factory], cancel_token)
-                    .start()
-                    .await
+            if let Err(err) = MetricWorker::new(
+                Duration::from_secs(30),
+                vec![database_factory.clone()],
+                cancel_token,
+            )
+            .with_cross_safety_lag(database_factory)
+            .start()
+            .await
             {
                 Err(anyhow::anyhow!(err))
             } else {
@@ -373,16 +479,15 @@ impl Service {
             self.admin_receiver = Some(admin_rx);
         }
 
-        let rpc_addr = self.config.rpc_addr;
-        let cancel_token = self.cancel_token.clone();
+        let actor = SupervisorRpcActor::new(
+            self.config.rpc_addr,
+            self.config.rpc_max_request_body_size,
+            self.config.rpc_max_batch_len,
+            rpc_module,
+            self.cancel_token.clone(),
+        );
         self.join_set.spawn(async move {
-            if let Err(err) =
-                SupervisorRpcActor::new(rpc_addr, rpc_module, cancel_token).start().await
-            {
-                Err(anyhow::anyhow!(err))
-            } else {
-                Ok(())
-            }
+            if let Err(err) = actor.start().await { Err(anyhow::anyhow!(err)) } else { Ok(()) }
         });
         Ok(())
     }
@@ -400,6 +505,55 @@ impl Service {
 
                 let _ = resp.send(result);
             }
+            AdminRequest::ForceRewind { chain_id, to_block, resp } => {
+                let result = match self.reorg_handler.as_ref() {
+                    Some(reorg_handler) => {
+                        reorg_handler.force_rewind(chain_id, to_block).map_err(|err| {
+                            error!(target: "supervisor::service", %err, "admin force_rewind failed");
+                            AdminError::ServiceError(err.to_string())
+                        })
+                    }
+                    None => Err(AdminError::ServiceError("reorg handler not initialised".to_string())),
+                };
+
+                // the managed node has to follow the rewound heads
+                if result.is_ok() {
+                    if let Some(sender) = self.managed_node_senders.get(&chain_id) {
+                        let reset = ManagedNodeCommand::Reset { cause: ResetCause::ForcedRewind };
+                        if let Err(err) = sender.send(Traced::new(reset)).await {
+                            warn!(target: "supervisor::service", chain_id, %err, "Failed to reset managed node after force rewind");
+                        }
+                    }
+                }
+
+                let _ = resp.send(result);
+            }
+        }
+    }
+
+    fn handle_supervisor_event(&self, event: SupervisorEvent) {
+        match event {
+            SupervisorEvent::ChainProcessorFailed { chain_id, category, error } => {
+                warn!(
+                    target: "supervisor::service",
+                    chain_id,
+                    ?category,
+                    %error,
+                    "Chain processor failed, restarting chain processor actor"
+                );
+                let Some(sender) = self.chain_processor_restart_senders.get(&chain_id) else {
+                    error!(target: "supervisor::service", chain_id, "No chain processor restart sender found");
+                    return;
+                };
+                // a full channel means a restart is already pending
+                if let Err(TrySendError::Closed(_)) = sender.try_send(()) {
+                    error!(
+                        target: "supervisor::service",
+                        chain_id,
+                        "Failed to restart chain processor actor, restart channel closed"
+                    );
+                }
+            }
         }
     }
 
@@ -426,6 +580,11 @@ impl Service {
                     }
                 }
 
+                // the service holds a sender, so the channel never closes
+                Some(event) = self.supervisor_event_receiver.recv() => {
+                    self.handle_supervisor_event(event);
+                }
+
                 // Supervisor task completions / failures
                 opt = self.join_set.join_next() => {
                     match opt {
@@ -450,10 +609,50 @@ impl Service {
         Ok(())
     }
 
-    pub async fn shutdown(mut self) -> Result<()> {
+    /// Shuts the Supervisor service down.
+    ///
+    /// Cancellation first stops every task from accepting new events, after which the chain
+    /// processors handle the events still queued. Tasks still running once the drain timeout
+    /// elapses are force-stopped, dropping their queued events, and aborted if they don't stop
+    /// promptly. The databases are closed last.
+    pub async fn shutdown(mut self) -> Result<ShutdownReport> {
         self.cancel_token.cancel(); // Signal cancellation to all tasks
 
-        // Wait for all tasks to finish.
+        // Wait for all tasks to finish draining.
+        let drain_timeout = self.drain_timeout;
+        let forced = tokio::time::timeout(drain_timeout, self.join_all()).await.is_err();
+        if forced {
+            warn!(
+                target: "supervisor::service",
+                ?drain_timeout,
+                "Drain timed out, force-stopping remaining tasks"
+            );
+            self.force_stop_token.cancel();
+            if tokio::time::timeout(FORCE_STOP_TIMEOUT, self.join_all()).await.is_err() {
+                warn!(
+                    target: "supervisor::service",
+                    remaining = self.join_set.len(),
+                    "Tasks did not stop after force-stop, aborting them"
+                );
+                self.join_set.abort_all();
+                self.join_all().await;
+            }
+        }
+
+        self.database_factory.close()?;
+
+        let report = self.drain_stats.report(forced);
+        info!(
+            target: "supervisor::service",
+            drained = report.drained,
+            dropped = report.dropped,
+            forced,
+            "Supervisor service shut down"
+        );
+        Ok(report)
+    }
+
+    async fn join_all(&mut self) {
         while let Some(res) = self.join_set.join_next().await {
             match res {
                 Ok(Ok(_)) => {
@@ -462,12 +661,14 @@ impl Service {
                 Ok(Err(err)) => {
                     error!(target: "supervisor::service", %err, "A task encountered an error during shutdown.");
                 }
+                Err(err) if err.is_cancelled() => {
+                    warn!(target: "supervisor::service", "Task aborted during shutdown.");
+                }
                 Err(err) => {
                     error!(target: "supervisor::service", %err, "A task encountered an error during shutdown.");
                 }
             }
         }
-        Ok(())
     }
 }
 
@@ -476,7 +677,8 @@ mod tests {
     use std::{net::SocketAddr, path::PathBuf};
 
     use kona_interop::DependencySet;
-    use kona_supervisor_core::config::RollupConfigSet;
+    use kona_protocol::BlockInfo;
+    use kona_supervisor_core::config::{RollupConfig, RollupConfigSet};
 
     use super::*;
 
@@ -492,6 +694,11 @@ mod tests {
                 override_message_expiry_window: None,
             },
             RollupConfigSet { rollups: HashMap::new() },
+            HashMap::new(),
+            None,
+            1,
+            vec![],
+            false,
         );
         cfg.enable_admin_api = enable_admin;
         cfg
@@ -506,4 +713,128 @@ mod tests {
         svc.init_rpc_server().await.expect("init_rpc_server failed");
         assert!(svc.admin_receiver.is_some(), "admin_receiver must be set when admin enabled");
     }
+
+    #[tokio::test]
+    async fn test_force_rewind_resets_managed_node() {
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let db = Arc::new(ChainDb::new(1, &tmp_dir.path().join("chaindb")).unwrap());
+        let anchor = kona_interop::DerivedRefPair {
+            source: BlockInfo { number: 100, ..Default::default() },
+            derived: BlockInfo::default(),
+        };
+        db.initialise_log_storage(anchor.derived).unwrap();
+        db.initialise_derivation_storage(anchor).unwrap();
+
+        let mut svc = Service::new(make_test_config(true));
+        let l1_rpc = RpcClient::new_http(Url::parse("http://localhost:8545").unwrap());
+        svc.reorg_handler = Some(Arc::new(ReorgHandler::new(l1_rpc, HashMap::from([(1, db)]))));
+        let (node_tx, mut node_rx) = mpsc::channel(1);
+        svc.managed_node_senders.insert(1, node_tx);
+
+        let (resp_tx, resp_rx) = tokio::sync::oneshot::channel();
+        svc.handle_admin_request(AdminRequest::ForceRewind {
+            chain_id: 1,
+            to_block: anchor.source.id(),
+            resp: resp_tx,
+        })
+        .await;
+
+        assert!(resp_rx.await.unwrap().is_ok());
+        assert!(matches!(
+            node_rx.try_recv().unwrap().into_inner(),
+            ManagedNodeCommand::Reset { cause: ResetCause::ForcedRewind }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_event_channel_honors_configured_capacity() {
+        let mut cfg = make_test_config(false);
+        cfg.rollup_config_set.rollups.insert(
+            1,
+            RollupConfig { genesis: Default::default(), block_time: 2, interop_time: None },
+        );
+        cfg.event_channel_capacities.insert(1, 2);
+
+        let mut svc = Service::new(cfg);
+        svc.init_channels();
+
+        let tx = svc.chain_event_senders.get(&1).unwrap().clone();
+        let mut rx = svc.chain_event_receivers.remove(&1).unwrap();
+        assert_eq!(tx.max_capacity(), 2);
+
+        let event = |number| {
+            Traced::new(ChainEvent::UnsafeBlock {
+                block: BlockInfo { number, ..Default::default() },
+            })
+        };
+        tx.send(event(1)).await.unwrap();
+        tx.send(event(2)).await.unwrap();
+
+        // a full channel makes producers wait instead of dropping events
+        assert!(matches!(tx.try_send(event(3)), Err(mpsc::error::TrySendError::Full(_))));
+        let blocked = tokio::spawn({
+            let tx = tx.clone();
+            async move { tx.send(event(3)).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!blocked.is_finished());
+
+        let mut received = Vec::new();
+        for _ in 0..3 {
+            match rx.recv().await.map(Traced::into_inner) {
+                Some(ChainEvent::UnsafeBlock { block }) => received.push(block.number),
+                other => panic!("unexpected event: {other:?}"),
+            }
+        }
+        blocked.await.unwrap().unwrap();
+        assert_eq!(received, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_tasks_to_drain() {
+        let mut svc = Service::new(make_test_config(false));
+
+        let cancel_token = svc.cancel_token.clone();
+        let drain_stats = svc.drain_stats.clone();
+        svc.join_set.spawn(async move {
+            cancel_token.cancelled().await;
+            // simulate in-flight work completing after cancellation
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drain_stats.record_drained();
+            Ok(())
+        });
+
+        let report = svc.shutdown().await.expect("shutdown failed");
+        assert_eq!(report, ShutdownReport { drained: 1, dropped: 0, forced: false });
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_force_stops_after_drain_timeout() {
+        let mut svc =
+            Service::new(make_test_config(false)).with_drain_timeout(Duration::from_millis(20));
+
+        let force_stop_token = svc.force_stop_token.clone();
+        let drain_stats = svc.drain_stats.clone();
+        svc.join_set.spawn(async move {
+            // never finishes draining on its own
+            force_stop_token.cancelled().await;
+            drain_stats.record_dropped(2);
+            Ok(())
+        });
+
+        let report = svc.shutdown().await.expect("shutdown failed");
+        assert_eq!(report, ShutdownReport { drained: 0, dropped: 2, forced: true });
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_shutdown_aborts_tasks_ignoring_force_stop() {
+        let mut svc =
+            Service::new(make_test_config(false)).with_drain_timeout(Duration::from_millis(20));
+
+        // never stops on its own, not even once force-stopped
+        svc.join_set.spawn(future::pending());
+
+        let report = svc.shutdown().await.expect("shutdown failed");
+        assert_eq!(report, ShutdownReport { drained: 0, dropped: 0, forced: true });
+    }
 }
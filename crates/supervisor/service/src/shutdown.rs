@@ -0,0 +1,66 @@
+//! Graceful shutdown of the supervisor [`Service`](crate::Service).
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Summary of the events handled while the [`Service`](crate::Service) was shutting down.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Number of in-flight events that were handled before stopping.
+    pub drained: usize,
+    /// Number of in-flight events that were discarded when the drain timed out.
+    pub dropped: usize,
+    /// Whether the drain timed out and the remaining tasks had to be force-stopped.
+    pub forced: bool,
+}
+
+impl ShutdownReport {
+    /// Returns `true` if every in-flight event was handled.
+    pub const fn is_clean(&self) -> bool {
+        self.dropped == 0 && !self.forced
+    }
+}
+
+/// Counters shared by the actors draining their inbound channels during shutdown.
+#[derive(Debug, Default)]
+pub(crate) struct DrainStats {
+    drained: AtomicUsize,
+    dropped: AtomicUsize,
+}
+
+impl DrainStats {
+    pub(crate) fn record_drained(&self) {
+        self.drained.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_dropped(&self, count: usize) {
+        self.dropped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Builds a [`ShutdownReport`] from the counters recorded so far.
+    pub(crate) fn report(&self, forced: bool) -> ShutdownReport {
+        ShutdownReport {
+            drained: self.drained.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            forced,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_reflects_recorded_counts() {
+        let stats = DrainStats::default();
+        assert!(stats.report(false).is_clean());
+
+        stats.record_drained();
+        stats.record_drained();
+        stats.record_dropped(3);
+
+        let report = stats.report(true);
+        assert_eq!(report, ShutdownReport { drained: 2, dropped: 3, forced: true });
+        assert!(!report.is_clean());
+    }
+}
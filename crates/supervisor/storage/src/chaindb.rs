@@ -19,27 +19,87 @@ use metrics::{Label, gauge};
 use op_alloy_consensus::interop::SafetyLevel;
 use reth_db::{
     DatabaseEnv,
-    mdbx::{DatabaseArguments, init_db_for},
+    lockfile::StorageLock,
+    mdbx::{DatabaseArguments, init_db_for, open_db_read_only},
 };
 use reth_db_api::database::Database;
 use std::path::Path;
 use tracing::warn;
 
+/// Number of entries and size on disk of a table, as returned by [`ChainDb::table_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableStats {
+    /// Name of the table.
+    pub name: &'static str,
+    /// Number of entries in the table.
+    pub entries: usize,
+    /// Size of the table's pages, in bytes.
+    pub size: usize,
+}
+
 /// Manages the database environment for a single chain.
 /// Provides transactional access to data via providers.
 #[derive(Debug)]
 pub struct ChainDb {
     chain_id: ChainId,
     metrics_enabled: Option<bool>,
+    table_metrics_enabled: bool,
 
     env: DatabaseEnv,
+    /// Storage lock held by a read-only environment, so no process can open the database for
+    /// writing while it's being read. A writable environment holds its own lock.
+    _lock: Option<StorageLock>,
 }
 
 impl ChainDb {
     /// Creates or opens a database environment at the given path.
     pub fn new(chain_id: ChainId, path: &Path) -> Result<Self, StorageError> {
         let env = init_db_for::<_, crate::models::Tables>(path, DatabaseArguments::default())?;
-        Ok(Self { chain_id, metrics_enabled: None, env })
+        Ok(Self { chain_id, metrics_enabled: None, table_metrics_enabled: false, env, _lock: None })
+    }
+
+    /// Opens an existing database environment at the given path without write access, e.g. to
+    /// inspect it offline.
+    ///
+    /// Returns [`StorageError::DatabaseInUse`] if the database is opened by a running process.
+    /// The storage lock is held until the returned [`ChainDb`] is dropped, so a process can't open
+    /// the database for writing in the meantime either.
+    pub fn open_read_only(chain_id: ChainId, path: &Path) -> Result<Self, StorageError> {
+        // a writable environment holds the storage lock for as long as it's open, so the lock is
+        // only free if no live process has the database open
+        let lock = StorageLock::try_acquire(path).map_err(|err| {
+            warn!(target: "supervisor::storage", %chain_id, %err, "Database is in use");
+            StorageError::DatabaseInUse
+        })?;
+
+        let env = open_db_read_only(path, DatabaseArguments::default())?;
+        Ok(Self {
+            chain_id,
+            metrics_enabled: None,
+            table_metrics_enabled: false,
+            env,
+            _lock: Some(lock),
+        })
+    }
+
+    /// Returns the number of entries and the size on disk of each table.
+    pub fn table_stats(&self) -> Result<Vec<TableStats>, StorageError> {
+        let stats = self.env.view(|tx| {
+            crate::models::Tables::ALL
+                .iter()
+                .map(|table| {
+                    let name = table.name();
+                    let stats = tx.inner.db_stat(&tx.inner.open_db(Some(name))?)?;
+                    let pages = stats.leaf_pages() + stats.branch_pages() + stats.overflow_pages();
+                    Ok(TableStats {
+                        name,
+                        entries: stats.entries(),
+                        size: stats.page_size() as usize * pages,
+                    })
+                })
+                .collect::<Result<Vec<_>, eyre::Report>>()
+        })??;
+        Ok(stats)
     }
 
     /// Enables metrics on the database environment.
@@ -49,6 +109,15 @@ impl ChainDb {
         self
     }
 
+    /// Records the latency of every read from and write to the database tables.
+    ///
+    /// The histograms are labelled by table, and are exposed through the installed metrics
+    /// recorder alongside the request metrics.
+    pub const fn with_table_metrics(mut self) -> Self {
+        self.table_metrics_enabled = true;
+        self
+    }
+
     fn observe_call<T, E, F: FnOnce() -> Result<T, E>>(
         &self,
         name: &'static str,
@@ -67,15 +136,25 @@ impl ChainDb {
             f()
         }
     }
+
+    const fn derivation_provider<'tx, TX>(&self, tx: &'tx TX) -> DerivationProvider<'tx, TX> {
+        DerivationProvider::new(tx, self.chain_id).with_table_metrics(self.table_metrics_enabled)
+    }
+
+    const fn log_provider<'tx, TX>(&self, tx: &'tx TX) -> LogProvider<'tx, TX> {
+        LogProvider::new(tx, self.chain_id).with_table_metrics(self.table_metrics_enabled)
+    }
+
+    const fn head_ref_provider<'tx, TX>(&self, tx: &'tx TX) -> SafetyHeadRefProvider<'tx, TX> {
+        SafetyHeadRefProvider::new(tx, self.chain_id).with_table_metrics(self.table_metrics_enabled)
+    }
 }
 
 // todo: make sure all get method return DatabaseNotInitialised error if db is not initialised
 impl DerivationStorageReader for ChainDb {
     fn derived_to_source(&self, derived_block_id: BlockNumHash) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_DERIVED_TO_SOURCE, || {
-            self.env.view(|tx| {
-                DerivationProvider::new(tx, self.chain_id).derived_to_source(derived_block_id)
-            })
+            self.env.view(|tx| self.derivation_provider(tx).derived_to_source(derived_block_id))
         })?
     }
 
@@ -85,29 +164,26 @@ impl DerivationStorageReader for ChainDb {
     ) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_LATEST_DERIVED_BLOCK_AT_SOURCE, || {
             self.env.view(|tx| {
-                DerivationProvider::new(tx, self.chain_id)
-                    .latest_derived_block_at_source(source_block_id)
+                self.derivation_provider(tx).latest_derived_block_at_source(source_block_id)
             })
         })?
     }
 
     fn latest_derivation_state(&self) -> Result<DerivedRefPair, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_LATEST_DERIVATION_STATE, || {
-            self.env.view(|tx| DerivationProvider::new(tx, self.chain_id).latest_derivation_state())
+            self.env.view(|tx| self.derivation_provider(tx).latest_derivation_state())
         })?
     }
 
     fn get_source_block(&self, source_block_number: u64) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_SOURCE_BLOCK, || {
-            self.env.view(|tx| {
-                DerivationProvider::new(tx, self.chain_id).get_source_block(source_block_number)
-            })
+            self.env.view(|tx| self.derivation_provider(tx).get_source_block(source_block_number))
         })?
     }
 
     fn get_activation_block(&self) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_ACTIVATION_BLOCK, || {
-            self.env.view(|tx| DerivationProvider::new(tx, self.chain_id).get_activation_block())
+            self.env.view(|tx| self.derivation_provider(tx).get_activation_block())
         })?
     }
 }
@@ -119,10 +195,10 @@ impl DerivationStorageWriter for ChainDb {
     ) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_INITIALISE_DERIVATION_STORAGE, || {
             self.env.update(|ctx| {
-                DerivationProvider::new(ctx, self.chain_id).initialise(incoming_pair)?;
-                SafetyHeadRefProvider::new(ctx, self.chain_id)
+                self.derivation_provider(ctx).initialise(incoming_pair)?;
+                self.head_ref_provider(ctx)
                     .update_safety_head_ref(SafetyLevel::LocalSafe, &incoming_pair.derived)?;
-                SafetyHeadRefProvider::new(ctx, self.chain_id)
+                self.head_ref_provider(ctx)
                     .update_safety_head_ref(SafetyLevel::CrossSafe, &incoming_pair.derived)
             })
         })?
@@ -131,7 +207,7 @@ impl DerivationStorageWriter for ChainDb {
     fn save_derived_block(&self, incoming_pair: DerivedRefPair) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_SAVE_DERIVED_BLOCK, || {
             self.env.update(|ctx| {
-                DerivationProvider::new(ctx, self.chain_id).save_derived_block(incoming_pair)?;
+                self.derivation_provider(ctx).save_derived_block(incoming_pair)?;
 
                 // Verify the consistency with log storage.
                 // The check is intentionally deferred until after saving the derived block,
@@ -140,9 +216,8 @@ impl DerivationStorageWriter for ChainDb {
                 // Example: If the parent derived block doesn't exist, it should return error from
                 // derivation provider, not from log provider.
                 let derived_block = incoming_pair.derived;
-                let block = LogProvider::new(ctx, self.chain_id)
-                    .get_block(derived_block.number)
-                    .map_err(|err| match err {
+                let block = self.log_provider(ctx).get_block(derived_block.number).map_err(
+                    |err| match err {
                         StorageError::EntryNotFound(_) => {
                             warn!(
                                 target: "supervisor::storage",
@@ -152,7 +227,8 @@ impl DerivationStorageWriter for ChainDb {
                             StorageError::FutureData
                         }
                         other => other, // propagate other errors as-is
-                    })?;
+                    },
+                )?;
                 if block != derived_block {
                     warn!(
                         target: "supervisor::storage",
@@ -163,7 +239,7 @@ impl DerivationStorageWriter for ChainDb {
                     return Err(StorageError::ReorgRequired);
                 }
 
-                SafetyHeadRefProvider::new(ctx, self.chain_id)
+                self.head_ref_provider(ctx)
                     .update_safety_head_ref(SafetyLevel::LocalSafe, &incoming_pair.derived)
             })
         })?
@@ -171,9 +247,7 @@ impl DerivationStorageWriter for ChainDb {
 
     fn save_source_block(&self, incoming_source: BlockInfo) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_SAVE_SOURCE_BLOCK, || {
-            self.env.update(|ctx| {
-                DerivationProvider::new(ctx, self.chain_id).save_source_block(incoming_source)
-            })
+            self.env.update(|ctx| self.derivation_provider(ctx).save_source_block(incoming_source))
         })?
     }
 }
@@ -182,25 +256,25 @@ impl DerivationStorageWriter for ChainDb {
 impl LogStorageReader for ChainDb {
     fn get_latest_block(&self) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_LATEST_BLOCK, || {
-            self.env.view(|tx| LogProvider::new(tx, self.chain_id).get_latest_block())
+            self.env.view(|tx| self.log_provider(tx).get_latest_block())
         })?
     }
 
     fn get_block(&self, block_number: u64) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_BLOCK, || {
-            self.env.view(|tx| LogProvider::new(tx, self.chain_id).get_block(block_number))
+            self.env.view(|tx| self.log_provider(tx).get_block(block_number))
         })?
     }
 
     fn get_log(&self, block_number: u64, log_index: u32) -> Result<Log, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_LOG, || {
-            self.env.view(|tx| LogProvider::new(tx, self.chain_id).get_log(block_number, log_index))
+            self.env.view(|tx| self.log_provider(tx).get_log(block_number, log_index))
         })?
     }
 
     fn get_logs(&self, block_number: u64) -> Result<Vec<Log>, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_LOGS, || {
-            self.env.view(|tx| LogProvider::new(tx, self.chain_id).get_logs(block_number))
+            self.env.view(|tx| self.log_provider(tx).get_logs(block_number))
         })?
     }
 }
@@ -209,11 +283,10 @@ impl LogStorageWriter for ChainDb {
     fn initialise_log_storage(&self, block: BlockInfo) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_INITIALISE_LOG_STORAGE, || {
             self.env.update(|ctx| {
-                LogProvider::new(ctx, self.chain_id).initialise(block)?;
-                SafetyHeadRefProvider::new(ctx, self.chain_id)
+                self.log_provider(ctx).initialise(block)?;
+                self.head_ref_provider(ctx)
                     .update_safety_head_ref(SafetyLevel::LocalUnsafe, &block)?;
-                SafetyHeadRefProvider::new(ctx, self.chain_id)
-                    .update_safety_head_ref(SafetyLevel::CrossUnsafe, &block)
+                self.head_ref_provider(ctx).update_safety_head_ref(SafetyLevel::CrossUnsafe, &block)
             })
         })?
     }
@@ -221,10 +294,9 @@ impl LogStorageWriter for ChainDb {
     fn store_block_logs(&self, block: &BlockInfo, logs: Vec<Log>) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_STORE_BLOCK_LOGS, || {
             self.env.update(|ctx| {
-                LogProvider::new(ctx, self.chain_id).store_block_logs(block, logs)?;
+                self.log_provider(ctx).store_block_logs(block, logs)?;
 
-                SafetyHeadRefProvider::new(ctx, self.chain_id)
-                    .update_safety_head_ref(SafetyLevel::LocalUnsafe, block)
+                self.head_ref_provider(ctx).update_safety_head_ref(SafetyLevel::LocalUnsafe, block)
             })
         })?
     }
@@ -233,9 +305,7 @@ impl LogStorageWriter for ChainDb {
 impl HeadRefStorageReader for ChainDb {
     fn get_safety_head_ref(&self, safety_level: SafetyLevel) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_SAFETY_HEAD_REF, || {
-            self.env.view(|tx| {
-                SafetyHeadRefProvider::new(tx, self.chain_id).get_safety_head_ref(safety_level)
-            })
+            self.env.view(|tx| self.head_ref_provider(tx).get_safety_head_ref(safety_level))
         })?
     }
 
@@ -243,7 +313,7 @@ impl HeadRefStorageReader for ChainDb {
     fn get_super_head(&self) -> Result<SuperHead, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_GET_SUPER_HEAD, || {
             self.env.view(|tx| {
-                let sp = SafetyHeadRefProvider::new(tx, self.chain_id);
+                let sp = self.head_ref_provider(tx);
                 let local_unsafe =
                     sp.get_safety_head_ref(SafetyLevel::LocalUnsafe).map_err(|err| {
                         if matches!(err, StorageError::FutureData) {
@@ -277,12 +347,11 @@ impl HeadRefStorageReader for ChainDb {
                     Err(err) => return Err(err),
                 };
 
-                let l1_source =
-                    match DerivationProvider::new(tx, self.chain_id).latest_derivation_state() {
-                        Ok(pair) => Some(pair.source),
-                        Err(StorageError::DatabaseNotInitialised) => None,
-                        Err(err) => return Err(err),
-                    };
+                let l1_source = match self.derivation_provider(tx).latest_derivation_state() {
+                    Ok(pair) => Some(pair.source),
+                    Err(StorageError::DatabaseNotInitialised) => None,
+                    Err(err) => return Err(err),
+                };
 
                 Ok(SuperHead {
                     l1_source,
@@ -304,10 +373,10 @@ impl HeadRefStorageWriter for ChainDb {
     ) -> Result<BlockInfo, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_UPDATE_FINALIZED_USING_SOURCE, || {
             self.env.update(|tx| {
-                let sp = SafetyHeadRefProvider::new(tx, self.chain_id);
+                let sp = self.head_ref_provider(tx);
                 let safe = sp.get_safety_head_ref(SafetyLevel::CrossSafe)?;
 
-                let dp = DerivationProvider::new(tx, self.chain_id);
+                let dp = self.derivation_provider(tx);
                 let safe_block_pair = dp.get_derived_block_pair(safe.id())?;
 
                 if finalized_source_block.number >= safe_block_pair.source.number {
@@ -334,8 +403,8 @@ impl HeadRefStorageWriter for ChainDb {
     fn update_current_cross_unsafe(&self, block: &BlockInfo) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_UPDATE_CURRENT_CROSS_UNSAFE, || {
             self.env.update(|tx| {
-                let lp = LogProvider::new(tx, self.chain_id);
-                let sp = SafetyHeadRefProvider::new(tx, self.chain_id);
+                let lp = self.log_provider(tx);
+                let sp = self.head_ref_provider(tx);
 
                 // Check parent-child relationship with current CrossUnsafe head, if it exists.
                 let parent = sp.get_safety_head_ref(SafetyLevel::CrossUnsafe)?;
@@ -372,8 +441,8 @@ impl HeadRefStorageWriter for ChainDb {
     fn update_current_cross_safe(&self, block: &BlockInfo) -> Result<DerivedRefPair, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_UPDATE_CURRENT_CROSS_SAFE, || {
             self.env.update(|tx| {
-                let dp = DerivationProvider::new(tx, self.chain_id);
-                let sp = SafetyHeadRefProvider::new(tx, self.chain_id);
+                let dp = self.derivation_provider(tx);
+                let sp = self.head_ref_provider(tx);
 
                 // Check parent-child relationship with current CrossUnsafe head, if it exists.
                 let parent = sp.get_safety_head_ref(SafetyLevel::CrossSafe)?;
@@ -403,8 +472,8 @@ impl StorageRewinder for ChainDb {
     fn rewind_log_storage(&self, to: &BlockNumHash) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_REWIND_LOG_STORAGE, || {
             self.env.update(|tx| {
-                let lp = LogProvider::new(tx, self.chain_id);
-                let hp = SafetyHeadRefProvider::new(tx, self.chain_id);
+                let lp = self.log_provider(tx);
+                let hp = self.head_ref_provider(tx);
 
                 // Ensure we don't rewind to or before the LocalSafe head.
                 match hp.get_safety_head_ref(SafetyLevel::LocalSafe) {
@@ -450,9 +519,9 @@ impl StorageRewinder for ChainDb {
     fn rewind(&self, to: &BlockNumHash) -> Result<(), StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_REWIND, || {
             self.env.update(|tx| {
-                let lp = LogProvider::new(tx, self.chain_id);
-                let dp = DerivationProvider::new(tx, self.chain_id);
-                let hp = SafetyHeadRefProvider::new(tx, self.chain_id);
+                let lp = self.log_provider(tx);
+                let dp = self.derivation_provider(tx);
+                let hp = self.head_ref_provider(tx);
 
                 lp.rewind_to(to)?;
                 dp.rewind_to(to)?;
@@ -485,9 +554,9 @@ impl StorageRewinder for ChainDb {
     fn rewind_to_source(&self, to: &BlockNumHash) -> Result<Option<BlockInfo>, StorageError> {
         self.observe_call(Metrics::STORAGE_METHOD_REWIND_TO_SOURCE, || {
             self.env.update(|tx| {
-                let lp = LogProvider::new(tx, self.chain_id);
-                let dp = DerivationProvider::new(tx, self.chain_id);
-                let hp = SafetyHeadRefProvider::new(tx, self.chain_id);
+                let lp = self.log_provider(tx);
+                let dp = self.derivation_provider(tx);
+                let hp = self.head_ref_provider(tx);
 
                 let derived_target_block = dp.rewind_to_source(to)?;
                 if let Some(rewind_target) = derived_target_block {
@@ -612,6 +681,140 @@ mod tests {
         assert!(db.is_ok(), "Should create or open database");
     }
 
+    #[test]
+    fn test_table_metrics_record_latency() {
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+        use std::collections::HashMap;
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let db = ChainDb::new(1, &tmp_dir.path().join("chaindb_table_metrics"))
+            .expect("create db")
+            .with_table_metrics();
+        let anchor = BlockInfo { number: 5, ..Default::default() };
+        db.initialise_log_storage(anchor).expect("initialise log storage");
+        assert_eq!(db.get_latest_block().unwrap(), anchor);
+        assert!(db.get_logs(anchor.number).unwrap().is_empty());
+
+        let samples: HashMap<_, _> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter_map(|(key, _, _, value)| {
+                let key = key.key();
+                let table = key.labels().find(|label| label.key() == "table")?.value().to_string();
+                match value {
+                    DebugValue::Histogram(samples) => {
+                        Some(((key.name().to_string(), table), samples.len()))
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        let read = |table: &str| {
+            samples.get(&(Metrics::STORAGE_TABLE_READ_DURATION_SECONDS.to_string(), table.into()))
+        };
+        let write = |table: &str| {
+            samples.get(&(Metrics::STORAGE_TABLE_WRITE_DURATION_SECONDS.to_string(), table.into()))
+        };
+        // one lookup of the anchor on initialisation, and one for the latest block
+        assert_eq!(read("BlockRefs"), Some(&2));
+        assert_eq!(read("LogEntries"), Some(&1));
+        assert_eq!(write("BlockRefs"), Some(&1));
+        assert!(write("SafetyHeadRefs").is_some_and(|&count| count > 0));
+    }
+
+    #[test]
+    fn test_table_metrics_disabled_by_default() {
+        use metrics_util::debugging::DebuggingRecorder;
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let db =
+            ChainDb::new(1, &tmp_dir.path().join("chaindb_no_table_metrics")).expect("create db");
+        db.initialise_log_storage(BlockInfo::default()).expect("initialise log storage");
+
+        assert!(snapshotter.snapshot().into_vec().iter().all(|(key, _, _, _)| {
+            key.key().name() != Metrics::STORAGE_TABLE_READ_DURATION_SECONDS &&
+                key.key().name() != Metrics::STORAGE_TABLE_WRITE_DURATION_SECONDS
+        }));
+    }
+
+    #[test]
+    fn test_open_read_only() {
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let db_path = tmp_dir.path().join("chaindb_read_only");
+        let anchor = BlockInfo { number: 5, ..Default::default() };
+        {
+            let db = ChainDb::new(1, &db_path).expect("create db");
+            db.initialise_log_storage(anchor).expect("initialise log storage");
+        }
+
+        let db = ChainDb::open_read_only(1, &db_path).expect("open db read-only");
+        assert_eq!(db.get_latest_block().unwrap(), anchor);
+        assert!(db.initialise_log_storage(anchor).is_err());
+
+        let stats = db.table_stats().unwrap();
+        assert_eq!(stats.len(), crate::models::Tables::ALL.len());
+        let block_refs = stats.iter().find(|table| table.name == "BlockRefs").unwrap();
+        assert_eq!(block_refs.entries, 1);
+    }
+
+    /// Environment variable pointing [`hold_db_open`] to the database to keep open.
+    const HOLD_DB_PATH: &str = "KONA_SUPERVISOR_TEST_HOLD_DB_PATH";
+
+    /// Keeps a writable database open until killed. Run in a child process by
+    /// [`test_open_read_only_refuses_live_db`], since the storage lock doesn't guard against the
+    /// process holding it.
+    #[test]
+    #[ignore = "run by test_open_read_only_refuses_live_db"]
+    fn hold_db_open() {
+        let path = std::env::var(HOLD_DB_PATH).expect("database path");
+        let _db = ChainDb::new(1, Path::new(&path)).expect("open db");
+        println!("ready");
+        std::thread::park();
+    }
+
+    #[test]
+    fn test_open_read_only_refuses_live_db() {
+        use std::{
+            io::{BufRead, BufReader},
+            process::{Command, Stdio},
+        };
+
+        let tmp_dir = TempDir::new().expect("create temp dir");
+        let db_path = tmp_dir.path().join("chaindb_live");
+
+        // the test harness names tests without the crate name
+        let (_, module) = module_path!().split_once("::").unwrap();
+        let mut live = Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", &format!("{module}::hold_db_open"), "--ignored", "--nocapture"])
+            .env(HOLD_DB_PATH, &db_path)
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("spawn live process");
+        let ready = BufReader::new(live.stdout.take().unwrap())
+            .lines()
+            .any(|line| line.is_ok_and(|line| line == "ready"));
+
+        let result = ChainDb::open_read_only(1, &db_path);
+        live.kill().unwrap();
+        live.wait().unwrap();
+
+        assert!(ready, "live process failed to open the database");
+        assert_eq!(result.unwrap_err(), StorageError::DatabaseInUse);
+
+        // the lock left behind by the killed process is stale
+        assert!(ChainDb::open_read_only(1, &db_path).is_ok());
+    }
+
     #[test]
     fn test_log_storage() {
         let tmp_dir = TempDir::new().expect("create temp dir");
@@ -23,6 +23,7 @@ use tracing::error;
 pub struct ChainDbFactory {
     db_path: PathBuf,
     metrics_enabled: Option<bool>,
+    table_metrics_enabled: bool,
 
     dbs: RwLock<HashMap<ChainId, Arc<ChainDb>>>,
     /// Finalized L1 block reference, used for tracking the finalized L1 block.
@@ -36,6 +37,7 @@ impl ChainDbFactory {
         Self {
             db_path,
             metrics_enabled: None,
+            table_metrics_enabled: false,
             dbs: RwLock::new(HashMap::new()),
             finalized_l1: RwLock::new(None),
         }
@@ -47,6 +49,13 @@ impl ChainDbFactory {
         self
     }
 
+    /// Records the latency of table reads and writes of every database, see
+    /// [`ChainDb::with_table_metrics`].
+    pub const fn with_table_metrics(mut self) -> Self {
+        self.table_metrics_enabled = true;
+        self
+    }
+
     fn observe_call<T, E, F: FnOnce() -> Result<T, E>>(
         &self,
         name: &'static str,
@@ -95,6 +104,9 @@ impl ChainDbFactory {
         if self.metrics_enabled.unwrap_or(false) {
             chain_db = chain_db.with_metrics();
         }
+        if self.table_metrics_enabled {
+            chain_db = chain_db.with_table_metrics();
+        }
         let db = Arc::new(chain_db);
         dbs.insert(chain_id, db.clone());
         Ok(db)
@@ -109,6 +121,37 @@ impl ChainDbFactory {
         let dbs = self.dbs.read().map_err(|_| StorageError::LockPoisoned)?;
         dbs.get(&chain_id).cloned().ok_or_else(|| StorageError::DatabaseNotInitialised)
     }
+
+    /// Returns how many seconds the cross-safe head trails the local unsafe head, per chain.
+    ///
+    /// Chains without a cross-safe head yet are skipped.
+    pub fn cross_safety_lags(&self) -> Vec<(ChainId, u64)> {
+        let dbs: Vec<(ChainId, Arc<ChainDb>)> = match self.dbs.read() {
+            Ok(dbs_guard) => dbs_guard.iter().map(|(id, db)| (*id, db.clone())).collect(),
+            Err(_) => {
+                error!(target: "supervisor::storage", "Failed to acquire read lock for cross safety lag");
+                return Vec::new();
+            }
+        };
+
+        dbs.into_iter()
+            .filter_map(|(chain_id, db)| {
+                let head = db.get_super_head().ok()?;
+                let cross_safe = head.cross_safe?;
+                Some((chain_id, head.local_unsafe.timestamp.saturating_sub(cross_safe.timestamp)))
+            })
+            .collect()
+    }
+
+    /// Releases the factory's handles to all chain databases.
+    ///
+    /// Each database is closed once the last remaining handle to it is dropped. Databases are
+    /// reopened on the next call to [`Self::get_or_create_db`].
+    pub fn close(&self) -> Result<(), StorageError> {
+        let mut dbs = self.dbs.write().map_err(|_| StorageError::LockPoisoned)?;
+        dbs.clear();
+        Ok(())
+    }
 }
 
 impl MetricsReporter for ChainDbFactory {
@@ -274,6 +317,24 @@ mod tests {
         assert!(tmp.path().join("2").exists());
     }
 
+    #[test]
+    fn test_cross_safety_lags_skips_chains_without_cross_safe_head() {
+        let (_tmp, factory) = temp_factory();
+        factory.get_or_create_db(1).unwrap();
+        assert!(factory.cross_safety_lags().is_empty());
+    }
+
+    #[test]
+    fn test_close_releases_dbs() {
+        let (_tmp, factory) = temp_factory();
+        let db = factory.get_or_create_db(3).unwrap();
+        assert_eq!(Arc::strong_count(&db), 2);
+
+        factory.close().unwrap();
+        assert_eq!(Arc::strong_count(&db), 1);
+        assert!(matches!(factory.get_db(3).unwrap_err(), StorageError::DatabaseNotInitialised));
+    }
+
     #[test]
     fn test_get_finalized_l1_returns_error_when_none() {
         let (_tmp, factory) = temp_factory();
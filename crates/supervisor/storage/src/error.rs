@@ -1,5 +1,6 @@
 use alloy_eips::BlockNumHash;
-use reth_db::DatabaseError;
+use reth_db::{DatabaseError, mdbx};
+use std::io;
 use thiserror::Error;
 
 /// Errors that may occur while interacting with supervisor log storage.
@@ -9,7 +10,7 @@ use thiserror::Error;
 pub enum StorageError {
     /// Represents a database error that occurred while interacting with storage.
     #[error(transparent)]
-    Database(#[from] DatabaseError),
+    Database(DatabaseError),
 
     /// Represents an error that occurred while initializing the database.
     #[error(transparent)]
@@ -31,6 +32,10 @@ pub enum StorageError {
     #[error("database not initialized")]
     DatabaseNotInitialised,
 
+    /// The database is opened by a running process.
+    #[error("database is in use by another process")]
+    DatabaseInUse,
+
     /// Represents a conflict occurred while attempting to write to the database.
     #[error("conflicting data")]
     ConflictError,
@@ -43,6 +48,10 @@ pub enum StorageError {
     #[error("reorg required due to inconsistent storage state")]
     ReorgRequired,
 
+    /// The database volume, or the database's maximum size, is exhausted.
+    #[error("no space left for the database")]
+    DiskFull,
+
     /// Represents an error that occurred when attempting to rewind log storage beyond the local
     /// safe head.
     #[error("rewinding log storage beyond local safe head. to: {to}, local_safe: {local_safe}")]
@@ -61,9 +70,10 @@ impl PartialEq for StorageError {
             (Database(a), Database(b)) => a == b,
             (DatabaseInit(a), DatabaseInit(b)) => format!("{a}") == format!("{b}"),
             (EntryNotFound(a), EntryNotFound(b)) => a == b,
-            (DatabaseNotInitialised, DatabaseNotInitialised) | (ConflictError, ConflictError) => {
-                true
-            }
+            (DatabaseNotInitialised, DatabaseNotInitialised) |
+            (ConflictError, ConflictError) |
+            (DatabaseInUse, DatabaseInUse) |
+            (DiskFull, DiskFull) => true,
             _ => false,
         }
     }
@@ -71,6 +81,29 @@ impl PartialEq for StorageError {
 
 impl Eq for StorageError {}
 
+impl StorageError {
+    /// Returns whether the failed operation may succeed when retried, i.e. the database failed to
+    /// run it, rather than the stored data being invalid or inconsistent.
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::Database(err) if !matches!(err, DatabaseError::Decode))
+    }
+}
+
+impl From<DatabaseError> for StorageError {
+    /// Maps database errors caused by the database running out of space to
+    /// [`StorageError::DiskFull`], so that callers can halt instead of retrying.
+    fn from(err: DatabaseError) -> Self {
+        let code = match &err {
+            DatabaseError::Commit(info) => info.code,
+            DatabaseError::Write(err) => err.info.code,
+            _ => return Self::Database(err),
+        };
+        let out_of_space = matches!(mdbx::Error::from_err_code(code), mdbx::Error::MapFull) ||
+            io::Error::from_raw_os_error(code).kind() == io::ErrorKind::StorageFull;
+        if out_of_space { Self::DiskFull } else { Self::Database(err) }
+    }
+}
+
 /// Entry not found error.
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum EntryNotFoundError {
@@ -95,3 +128,30 @@ pub enum EntryNotFoundError {
         log_index: u32,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_full_is_disk_full() {
+        let err = StorageError::from(DatabaseError::Commit(mdbx::Error::MapFull.into()));
+        assert_eq!(err, StorageError::DiskFull);
+    }
+
+    #[test]
+    fn test_other_database_errors_are_kept() {
+        let err = StorageError::from(DatabaseError::Commit(mdbx::Error::Corrupted.into()));
+        assert!(matches!(err, StorageError::Database(DatabaseError::Commit(_))));
+    }
+
+    #[test]
+    fn test_is_transient() {
+        let err = StorageError::from(DatabaseError::Commit(mdbx::Error::Busy.into()));
+        assert!(err.is_transient());
+
+        assert!(!StorageError::Database(DatabaseError::Decode).is_transient());
+        assert!(!StorageError::ConflictError.is_transient());
+        assert!(!StorageError::DiskFull.is_transient());
+    }
+}
@@ -28,7 +28,7 @@ pub use error::{EntryNotFoundError, StorageError};
 mod providers;
 
 mod chaindb;
-pub use chaindb::ChainDb;
+pub use chaindb::{ChainDb, TableStats};
 
 mod metrics;
 pub(crate) use metrics::Metrics;
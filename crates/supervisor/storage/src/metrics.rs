@@ -1,4 +1,6 @@
 use alloy_primitives::ChainId;
+use reth_db_api::table::Table;
+use std::time::Instant;
 
 /// Container for ChainDb metrics.
 #[derive(Debug, Clone)]
@@ -13,6 +15,11 @@ impl Metrics {
     pub(crate) const STORAGE_REQUEST_DURATION_SECONDS: &'static str =
         "kona_supervisor_storage_duration_seconds";
 
+    pub(crate) const STORAGE_TABLE_READ_DURATION_SECONDS: &'static str =
+        "kona_supervisor_storage_table_read_duration_seconds";
+    pub(crate) const STORAGE_TABLE_WRITE_DURATION_SECONDS: &'static str =
+        "kona_supervisor_storage_table_write_duration_seconds";
+
     pub(crate) const STORAGE_METHOD_DERIVED_TO_SOURCE: &'static str = "derived_to_source";
     pub(crate) const STORAGE_METHOD_LATEST_DERIVED_BLOCK_AT_SOURCE: &'static str =
         "latest_derived_block_at_source";
@@ -65,6 +72,16 @@ impl Metrics {
             metrics::Unit::Seconds,
             "Duration of Kona Supervisor Storage requests"
         );
+        metrics::describe_histogram!(
+            Self::STORAGE_TABLE_READ_DURATION_SECONDS,
+            metrics::Unit::Seconds,
+            "Duration of reads from a Kona Supervisor Storage table"
+        );
+        metrics::describe_histogram!(
+            Self::STORAGE_TABLE_WRITE_DURATION_SECONDS,
+            metrics::Unit::Seconds,
+            "Duration of writes to a Kona Supervisor Storage table"
+        );
     }
 
     fn zero_storage_methods(chain_id: ChainId, method_name: &'static str) {
@@ -116,3 +133,44 @@ impl Metrics {
         Self::zero_storage_methods(chain_id, Self::STORAGE_METHOD_REWIND_TO_SOURCE);
     }
 }
+
+/// Records the latency of the table operations issued by a provider.
+///
+/// Disabled by default, as timing every table access adds overhead to the hot path.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TableMetrics {
+    chain_id: ChainId,
+    enabled: bool,
+}
+
+impl TableMetrics {
+    pub(crate) const fn new(chain_id: ChainId, enabled: bool) -> Self {
+        Self { chain_id, enabled }
+    }
+
+    /// Runs `f`, recording its duration as a read from table `T`.
+    pub(crate) fn read<T: Table, R>(&self, f: impl FnOnce() -> R) -> R {
+        self.observe(Metrics::STORAGE_TABLE_READ_DURATION_SECONDS, T::NAME, f)
+    }
+
+    /// Runs `f`, recording its duration as a write to table `T`.
+    pub(crate) fn write<T: Table, R>(&self, f: impl FnOnce() -> R) -> R {
+        self.observe(Metrics::STORAGE_TABLE_WRITE_DURATION_SECONDS, T::NAME, f)
+    }
+
+    fn observe<R>(&self, name: &'static str, table: &'static str, f: impl FnOnce() -> R) -> R {
+        if !self.enabled {
+            return f();
+        }
+
+        let start = Instant::now();
+        let result = f();
+        metrics::histogram!(
+            name,
+            "table" => table,
+            "chain_id" => self.chain_id.to_string()
+        )
+        .record(start.elapsed().as_secs_f64());
+        result
+    }
+}
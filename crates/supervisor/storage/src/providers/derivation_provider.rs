@@ -1,6 +1,7 @@
 //! Provider for derivation-related database operations.
 use crate::{
     error::{EntryNotFoundError, StorageError},
+    metrics::TableMetrics,
     models::{
         BlockTraversal, DerivedBlocks, SourceBlockTraversal, StoredDerivedBlockPair, U64List,
     },
@@ -22,6 +23,7 @@ const DEFAULT_LOG_INTERVAL: u64 = 100;
 pub(crate) struct DerivationProvider<'tx, TX> {
     tx: &'tx TX,
     chain_id: ChainId,
+    metrics: TableMetrics,
     #[doc(hidden)]
     observability_interval: u64,
 }
@@ -36,7 +38,13 @@ impl<'tx, TX> DerivationProvider<'tx, TX> {
         chain_id: ChainId,
         observability_interval: u64,
     ) -> Self {
-        Self { tx, chain_id, observability_interval }
+        Self { tx, chain_id, metrics: TableMetrics::new(chain_id, false), observability_interval }
+    }
+
+    /// Records the latency of the provider's table operations.
+    pub(crate) const fn with_table_metrics(mut self, enabled: bool) -> Self {
+        self.metrics = TableMetrics::new(self.chain_id, enabled);
+        self
     }
 }
 
@@ -49,8 +57,10 @@ where
         &self,
         derived_block_number: u64,
     ) -> Result<StoredDerivedBlockPair, StorageError> {
-        let derived_block_pair_opt =
-            self.tx.get::<DerivedBlocks>(derived_block_number).inspect_err(|err| {
+        let derived_block_pair_opt = self
+            .metrics
+            .read::<DerivedBlocks, _>(|| self.tx.get::<DerivedBlocks>(derived_block_number))
+            .inspect_err(|err| {
                 error!(
                   target: "supervisor::storage",
                   chain_id = %self.chain_id,
@@ -118,8 +128,10 @@ where
         &self,
         source_block_number: u64,
     ) -> Result<SourceBlockTraversal, StorageError> {
-        let block_traversal =
-            self.tx.get::<BlockTraversal>(source_block_number).inspect_err(|err| {
+        let block_traversal = self
+            .metrics
+            .read::<BlockTraversal, _>(|| self.tx.get::<BlockTraversal>(source_block_number))
+            .inspect_err(|err| {
                 error!(
                     target: "supervisor::storage",
                     chain_id = %self.chain_id,
@@ -195,14 +207,15 @@ where
             );
         })?;
 
-        let result = cursor.last().inspect_err(|err| {
-            error!(
-                target: "supervisor::storage",
-                chain_id = %self.chain_id,
-                %err,
-                "Failed to seek to last block"
-            );
-        })?;
+        let result =
+            self.metrics.read::<DerivedBlocks, _>(|| cursor.last()).inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    %err,
+                    "Failed to seek to last block"
+                );
+            })?;
 
         let (_, block) = result.ok_or_else(|| {
             warn!(
@@ -231,7 +244,7 @@ where
     /// The latest [`SourceBlockTraversal`] in the database.
     fn latest_source_block_traversal(&self) -> Result<SourceBlockTraversal, StorageError> {
         let mut cursor = self.tx.cursor_read::<BlockTraversal>()?;
-        let result = cursor.last()?;
+        let result = self.metrics.read::<BlockTraversal, _>(|| cursor.last())?;
 
         let (_, block_traversal) = result.ok_or_else(|| StorageError::DatabaseNotInitialised)?;
         Ok(block_traversal)
@@ -263,7 +276,7 @@ where
     /// Gets the activation block, which is the first block in the database.
     pub(crate) fn get_activation_block(&self) -> Result<BlockInfo, StorageError> {
         let mut cursor = self.tx.cursor_read::<DerivedBlocks>()?;
-        let result = cursor.first()?;
+        let result = self.metrics.read::<DerivedBlocks, _>(|| cursor.first())?;
 
         let (_, derived_block_pair) = result.ok_or_else(|| StorageError::DatabaseNotInitialised)?;
         Ok(derived_block_pair.derived.into())
@@ -397,8 +410,10 @@ where
         block_traversal.derived_block_numbers.push(incoming_pair.derived.number);
 
         // Save the derived block pair to the database
-        self.tx
-            .put::<DerivedBlocks>(incoming_pair.derived.number, incoming_pair.into())
+        self.metrics
+            .write::<DerivedBlocks, _>(|| {
+                self.tx.put::<DerivedBlocks>(incoming_pair.derived.number, incoming_pair.into())
+            })
             .inspect_err(|err| {
                 error!(
                     target: "supervisor::storage",
@@ -410,8 +425,11 @@ where
             })?;
 
         // Save the SourceBlockTraversal to the database
-        self.tx.put::<BlockTraversal>(incoming_pair.source.number, block_traversal).inspect_err(
-            |err| {
+        self.metrics
+            .write::<BlockTraversal, _>(|| {
+                self.tx.put::<BlockTraversal>(incoming_pair.source.number, block_traversal)
+            })
+            .inspect_err(|err| {
                 error!(
                     target: "supervisor::storage",
                     chain_id = %self.chain_id,
@@ -419,8 +437,7 @@ where
                     %err,
                     "Failed to save derived block numbers for source block"
                 );
-            },
-        )?;
+            })?;
 
         Ok(())
     }
@@ -493,11 +510,13 @@ where
             derived_block_numbers: U64List::default(),
         };
 
-        self.tx.put::<BlockTraversal>(incoming_source.number, block_traversal).inspect_err(
-            |err| {
+        self.metrics
+            .write::<BlockTraversal, _>(|| {
+                self.tx.put::<BlockTraversal>(incoming_source.number, block_traversal)
+            })
+            .inspect_err(|err| {
                 error!(target: "supervisor::storage", chain_id = %self.chain_id, %err, "Failed to save block traversal");
-            },
-        )?;
+            })?;
 
         Ok(())
     }
@@ -614,11 +633,13 @@ where
         // If there's still something left, update the entry. Otherwise, skip — let the walker
         // delete it.
         if !traversal.derived_block_numbers.is_empty() {
-            self.tx.put::<BlockTraversal>(block_pair.source.number, traversal).inspect_err(
-                |err| {
+            self.metrics
+                .write::<BlockTraversal, _>(|| {
+                    self.tx.put::<BlockTraversal>(block_pair.source.number, traversal)
+                })
+                .inspect_err(|err| {
                     error!(target: "supervisor::storage", chain_id = %self.chain_id, %err, "Failed to update block traversal");
-                },
-            )?;
+                })?;
             walk_from += 1;
         }
 
@@ -1,17 +1,29 @@
 //! Provider for tracking block safety head reference
-use crate::{StorageError, models::SafetyHeadRefs};
+use crate::{StorageError, metrics::TableMetrics, models::SafetyHeadRefs};
 use alloy_primitives::ChainId;
-use derive_more::Constructor;
 use kona_protocol::BlockInfo;
 use op_alloy_consensus::interop::SafetyLevel;
 use reth_db_api::transaction::{DbTx, DbTxMut};
 use tracing::{error, warn};
 
 /// A Safety Head Reference storage that wraps transactional reference.
-#[derive(Debug, Constructor)]
+#[derive(Debug)]
 pub(crate) struct SafetyHeadRefProvider<'tx, TX> {
     tx: &'tx TX,
     chain_id: ChainId,
+    metrics: TableMetrics,
+}
+
+impl<'tx, TX> SafetyHeadRefProvider<'tx, TX> {
+    pub(crate) const fn new(tx: &'tx TX, chain_id: ChainId) -> Self {
+        Self { tx, chain_id, metrics: TableMetrics::new(chain_id, false) }
+    }
+
+    /// Records the latency of the provider's table operations.
+    pub(crate) const fn with_table_metrics(mut self, enabled: bool) -> Self {
+        self.metrics = TableMetrics::new(self.chain_id, enabled);
+        self
+    }
 }
 
 impl<TX> SafetyHeadRefProvider<'_, TX>
@@ -23,15 +35,18 @@ where
         safety_level: SafetyLevel,
     ) -> Result<BlockInfo, StorageError> {
         let head_ref_key = safety_level.into();
-        let result = self.tx.get::<SafetyHeadRefs>(head_ref_key).inspect_err(|err| {
-            error!(
-                target: "supervisor::storage",
-                chain_id = %self.chain_id,
-                %safety_level,
-                %err,
-                "Failed to seek head reference"
-            );
-        })?;
+        let result = self
+            .metrics
+            .read::<SafetyHeadRefs, _>(|| self.tx.get::<SafetyHeadRefs>(head_ref_key))
+            .inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    %safety_level,
+                    %err,
+                    "Failed to seek head reference"
+                );
+            })?;
         let block_ref = result.ok_or_else(|| StorageError::FutureData)?;
         Ok(block_ref.into())
     }
@@ -65,8 +80,10 @@ where
             }
         }
 
-        self.tx
-            .put::<SafetyHeadRefs>(safety_level.into(), (*incoming_head_ref).into())
+        self.metrics
+            .write::<SafetyHeadRefs, _>(|| {
+                self.tx.put::<SafetyHeadRefs>(safety_level.into(), (*incoming_head_ref).into())
+            })
             .inspect_err(|err| {
                 error!(
                     target: "supervisor::storage",
@@ -103,8 +120,10 @@ where
             Err(err) => return Err(err),
         }
 
-        self.tx
-            .put::<SafetyHeadRefs>(safety_level.into(), (*incoming_head_ref).into())
+        self.metrics
+            .write::<SafetyHeadRefs, _>(|| {
+                self.tx.put::<SafetyHeadRefs>(safety_level.into(), (*incoming_head_ref).into())
+            })
             .inspect_err(|err| {
                 error!(
                     target: "supervisor::storage",
@@ -123,15 +142,19 @@ where
         &self,
         safety_level: SafetyLevel,
     ) -> Result<(), StorageError> {
-        self.tx.delete::<SafetyHeadRefs>(safety_level.into(), None).inspect_err(|err| {
-            error!(
-                target: "supervisor::storage",
-                chain_id = %self.chain_id,
-                %safety_level,
-                %err,
-                "Failed to remove head reference"
-            )
-        })?;
+        self.metrics
+            .write::<SafetyHeadRefs, _>(|| {
+                self.tx.delete::<SafetyHeadRefs>(safety_level.into(), None)
+            })
+            .inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    %safety_level,
+                    %err,
+                    "Failed to remove head reference"
+                )
+            })?;
         Ok(())
     }
 }
@@ -15,6 +15,7 @@
 
 use crate::{
     error::{EntryNotFoundError, StorageError},
+    metrics::TableMetrics,
     models::{BlockRefs, LogEntries},
 };
 use alloy_eips::BlockNumHash;
@@ -35,6 +36,7 @@ const DEFAULT_LOG_INTERVAL: u64 = 100;
 pub(crate) struct LogProvider<'tx, TX> {
     tx: &'tx TX,
     chain_id: ChainId,
+    metrics: TableMetrics,
     #[doc(hidden)]
     observability_interval: u64,
 }
@@ -49,7 +51,13 @@ impl<'tx, TX> LogProvider<'tx, TX> {
         chain_id: ChainId,
         observability_interval: u64,
     ) -> Self {
-        Self { tx, chain_id, observability_interval }
+        Self { tx, chain_id, metrics: TableMetrics::new(chain_id, false), observability_interval }
+    }
+
+    /// Records the latency of the provider's table operations.
+    pub(crate) const fn with_table_metrics(mut self, enabled: bool) -> Self {
+        self.metrics = TableMetrics::new(self.chain_id, enabled);
+        self
     }
 }
 
@@ -123,15 +131,17 @@ where
         block: &BlockInfo,
         logs: Vec<Log>,
     ) -> Result<(), StorageError> {
-        self.tx.put::<BlockRefs>(block.number, (*block).into()).inspect_err(|err| {
-            error!(
-                target: "supervisor::storage",
-                chain_id = %self.chain_id,
-                block_number = block.number,
-                %err,
-                "Failed to insert block"
-            );
-        })?;
+        self.metrics
+            .write::<BlockRefs, _>(|| self.tx.put::<BlockRefs>(block.number, (*block).into()))
+            .inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    block_number = block.number,
+                    %err,
+                    "Failed to insert block"
+                );
+            })?;
 
         let mut cursor = self.tx.cursor_dup_write::<LogEntries>().inspect_err(|err| {
             error!(
@@ -143,15 +153,17 @@ where
         })?;
 
         for log in logs {
-            cursor.append_dup(block.number, log.into()).inspect_err(|err| {
-                error!(
-                    target: "supervisor::storage",
-                    chain_id = %self.chain_id,
-                    block_number = block.number,
-                    %err,
-                    "Failed to append logs"
-                );
-            })?;
+            self.metrics
+                .write::<LogEntries, _>(|| cursor.append_dup(block.number, log.into()))
+                .inspect_err(|err| {
+                    error!(
+                        target: "supervisor::storage",
+                        chain_id = %self.chain_id,
+                        block_number = block.number,
+                        %err,
+                        "Failed to append logs"
+                    );
+                })?;
         }
         Ok(())
     }
@@ -220,7 +232,7 @@ where
                 walker.delete_current()?;
 
                 // remove the logs of that block
-                self.tx.delete::<LogEntries>(key, None)?;
+                self.metrics.write::<LogEntries, _>(|| self.tx.delete::<LogEntries>(key, None))?;
 
                 processed_blocks += 1;
 
@@ -272,15 +284,18 @@ where
             "Fetching block"
         );
 
-        let block_option = self.tx.get::<BlockRefs>(block_number).inspect_err(|err| {
-            error!(
-                target: "supervisor::storage",
-                chain_id = %self.chain_id,
-                block_number,
-                %err,
-                "Failed to read block",
-            );
-        })?;
+        let block_option = self
+            .metrics
+            .read::<BlockRefs, _>(|| self.tx.get::<BlockRefs>(block_number))
+            .inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    block_number,
+                    %err,
+                    "Failed to read block",
+                );
+            })?;
 
         let block = block_option.ok_or_else(|| {
             warn!(
@@ -306,7 +321,7 @@ where
             );
         })?;
 
-        let result = cursor.last().inspect_err(|err| {
+        let result = self.metrics.read::<BlockRefs, _>(|| cursor.last()).inspect_err(|err| {
             error!(
                 target: "supervisor::storage",
                 chain_id = %self.chain_id,
@@ -344,16 +359,19 @@ where
             );
         })?;
 
-        let result = cursor.seek_by_key_subkey(block_number, log_index).inspect_err(|err| {
-            error!(
-                target: "supervisor::storage",
-                chain_id = %self.chain_id,
-                block_number,
-                log_index,
-                %err,
-                "Failed to read log entry"
-            );
-        })?;
+        let result = self
+            .metrics
+            .read::<LogEntries, _>(|| cursor.seek_by_key_subkey(block_number, log_index))
+            .inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    block_number,
+                    log_index,
+                    %err,
+                    "Failed to read log entry"
+                );
+            })?;
 
         let log_entry = result.ok_or_else(|| {
             warn!(
@@ -381,33 +399,35 @@ where
             );
         })?;
 
-        let walker = cursor.walk_range(block_number..=block_number).inspect_err(|err| {
-            error!(
-                target: "supervisor::storage",
-                chain_id = %self.chain_id,
-                block_number,
-                %err,
-                "Failed to walk dup range",
-            );
-        })?;
+        self.metrics.read::<LogEntries, _>(|| {
+            let walker = cursor.walk_range(block_number..=block_number).inspect_err(|err| {
+                error!(
+                    target: "supervisor::storage",
+                    chain_id = %self.chain_id,
+                    block_number,
+                    %err,
+                    "Failed to walk dup range",
+                );
+            })?;
 
-        let mut logs = Vec::new();
-        for row in walker {
-            match row {
-                Ok((_, entry)) => logs.push(entry.into()),
-                Err(err) => {
-                    error!(
-                        target: "supervisor::storage",
-                        chain_id = %self.chain_id,
-                        block_number,
-                        %err,
-                        "Failed to read log entry",
-                    );
-                    return Err(StorageError::Database(err));
+            let mut logs = Vec::new();
+            for row in walker {
+                match row {
+                    Ok((_, entry)) => logs.push(entry.into()),
+                    Err(err) => {
+                        error!(
+                            target: "supervisor::storage",
+                            chain_id = %self.chain_id,
+                            block_number,
+                            %err,
+                            "Failed to read log entry",
+                        );
+                        return Err(StorageError::Database(err));
+                    }
                 }
             }
-        }
-        Ok(logs)
+            Ok(logs)
+        })
     }
 }
 
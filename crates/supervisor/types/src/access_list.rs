@@ -1,8 +1,11 @@
-use alloy_primitives::{B256, keccak256};
+use crate::ExecutingMessage;
+use alloy_primitives::{Address, B256, U256};
+use kona_interop::MessageIdentifier;
+use std::collections::HashSet;
 use thiserror::Error;
 
 /// A structured representation of a parsed CrossL2Inbox message access entry.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Access {
     /// Full 256-bit chain ID (combined from lookup + extension)
     pub chain_id: [u8; 32],
@@ -41,35 +44,46 @@ impl Access {
         }
     }
 
+    /// Encodes this access into its access-list entries: a lookup entry, a chain ID extension
+    /// entry if the chain ID doesn't fit in 64 bits, and the checksum entry.
+    pub fn to_entries(&self) -> Vec<B256> {
+        let mut entries = Vec::with_capacity(3);
+
+        let mut lookup = [0u8; 32];
+        lookup[0] = PREFIX_LOOKUP;
+        lookup[4..12].copy_from_slice(&self.chain_id[24..32]);
+        lookup[12..20].copy_from_slice(&self.block_number.to_be_bytes());
+        lookup[20..28].copy_from_slice(&self.timestamp.to_be_bytes());
+        lookup[28..32].copy_from_slice(&self.log_index.to_be_bytes());
+        entries.push(B256::from(lookup));
+
+        if self.chain_id[0..24] != [0; 24] {
+            let mut extension = [0u8; 32];
+            extension[0] = PREFIX_CHAIN_ID_EXTENSION;
+            extension[8..32].copy_from_slice(&self.chain_id[0..24]);
+            entries.push(B256::from(extension));
+        }
+
+        entries.push(self.checksum);
+        entries
+    }
+
     /// Recomputes the checksum for this access entry.
     ///
-    /// This follows the spec:
-    /// - `idPacked = 12 zero bytes ++ block_number ++ timestamp ++ log_index`
-    /// - `idLogHash = keccak256(log_hash ++ idPacked)`
-    /// - `bareChecksum = keccak256(idLogHash ++ chain_id)`
-    /// - Prepend 0x03 to `bareChecksum[1..]`
+    /// See [`MessageIdentifier::checksum_from_log_hash`].
     ///
     /// Returns the full 32-byte checksum with prefix 0x03.
-    ///
-    /// Reference: [Checksum Calculation](https://github.com/ethereum-optimism/specs/blob/main/specs/interop/predeploys.md#type-3-checksum)
     pub fn recompute_checksum(&self, log_hash: &B256) -> B256 {
-        // Step 1: idPacked = [0u8; 12] ++ block_number ++ timestamp ++ log_index
-        let mut id_packed = [0u8; 12 + 8 + 8 + 4]; // 32 bytes
-        id_packed[12..20].copy_from_slice(&self.block_number.to_be_bytes());
-        id_packed[20..28].copy_from_slice(&self.timestamp.to_be_bytes());
-        id_packed[28..32].copy_from_slice(&self.log_index.to_be_bytes());
-
-        // Step 2: keccak256(log_hash ++ id_packed)
-        let id_log_hash = keccak256([log_hash.as_slice(), &id_packed].concat());
-
-        // Step 3: keccak256(id_log_hash ++ chain_id)
-        let bare_checksum = keccak256([id_log_hash.as_slice(), &self.chain_id].concat());
-
-        // Step 4: Prepend type byte 0x03 (overwrite first byte)
-        let mut checksum = bare_checksum;
-        checksum.0[0] = 0x03;
-
-        checksum
+        let identifier = MessageIdentifier {
+            origin: Address::ZERO,
+            blockNumber: U256::from(self.block_number),
+            logIndex: U256::from(self.log_index),
+            timestamp: U256::from(self.timestamp),
+            chainId: U256::from_be_bytes(self.chain_id),
+        };
+        identifier
+            .checksum_from_log_hash(*log_hash)
+            .expect("access fields fit the identifier sizes accepted on-chain")
     }
 
     /// Verify the checksums after recalculation
@@ -81,6 +95,24 @@ impl Access {
     }
 }
 
+impl From<&ExecutingMessage> for Access {
+    /// Builds the access that references the initiating message of the executing message.
+    fn from(message: &ExecutingMessage) -> Self {
+        let mut chain_id = [0u8; 32];
+        chain_id[24..32].copy_from_slice(&message.chain_id.to_be_bytes());
+
+        let mut access = Self {
+            chain_id,
+            block_number: message.block_number,
+            timestamp: message.timestamp,
+            log_index: message.log_index,
+            checksum: B256::ZERO,
+        };
+        access.checksum = access.recompute_checksum(&message.hash);
+        access
+    }
+}
+
 /// Represents a single entry in the access list.
 #[derive(Debug, Clone)]
 enum AccessListEntry {
@@ -203,6 +235,20 @@ pub fn parse_access_list(entries: Vec<B256>) -> Result<Vec<Access>, AccessListEr
     Ok(list)
 }
 
+/// Removes duplicate [`Access`]es from a parsed access list, preserving the order in which they're
+/// first listed.
+///
+/// Returns the deduplicated list, along with the number of duplicates that were dropped. A
+/// transaction referencing the same message more than once is unusual, so callers may want to log
+/// it.
+pub fn dedup_access_list(list: Vec<Access>) -> (Vec<Access>, usize) {
+    let len = list.len();
+    let mut seen = HashSet::with_capacity(len);
+    let list: Vec<_> = list.into_iter().filter(|access| seen.insert(access.clone())).collect();
+    let duplicates = len - list.len();
+    (list, duplicates)
+}
+
 /// Parses a single 32-byte access list entry into a typed [`AccessListEntry`].
 ///
 /// This function performs a prefix-based decoding of the input hash:
@@ -251,7 +297,7 @@ fn parse_entry(entry: &B256) -> Result<AccessListEntry, AccessListError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::{B256, U256, b256};
+    use alloy_primitives::{B256, U256, b256, keccak256};
 
     fn make_lookup_entry(
         block_number: u64,
@@ -316,6 +362,41 @@ mod tests {
         assert!(parsed[0].verify_checksum(&log_hash).is_ok());
     }
 
+    #[test]
+    fn test_executing_message_access_round_trip() {
+        let message = ExecutingMessage {
+            chain_id: 10,
+            block_number: 42,
+            log_index: 7,
+            timestamp: 1_000,
+            hash: keccak256([3u8; 32]),
+        };
+
+        let access = Access::from(&message);
+        let entries = access.to_entries();
+        assert_eq!(entries.len(), 2);
+
+        let parsed = parse_access_list(entries).unwrap();
+        assert_eq!(parsed, vec![access]);
+        assert!(parsed[0].verify_checksum(&message.hash).is_ok());
+    }
+
+    #[test]
+    fn test_to_entries_with_chain_id_ext() {
+        let mut access = Access {
+            chain_id: [2u8; 32],
+            block_number: 1,
+            timestamp: 2,
+            log_index: 3,
+            checksum: B256::ZERO,
+        };
+        access.checksum = access.recompute_checksum(&B256::ZERO);
+
+        let entries = access.to_entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(parse_access_list(entries).unwrap(), vec![access]);
+    }
+
     #[test]
     fn test_parse_access_list_without_chain_id_ext() {
         let block_number = 1;
@@ -393,4 +474,31 @@ mod tests {
 
         assert!(matches!(parse_access_list(entries), Err(AccessListError::MalformedEntry)));
     }
+
+    #[test]
+    fn test_dedup_access_list_keeps_messages_sharing_entries() {
+        // A chain ID above `u64::MAX`, so that both messages share the same extension entry.
+        let mut chain_id = [0u8; 32];
+        chain_id[23] = 1;
+        chain_id[31] = 5;
+
+        let access = |log_index: u32| {
+            let mut access =
+                Access { chain_id, block_number: 1, timestamp: 2, log_index, checksum: B256::ZERO };
+            access.checksum = access.recompute_checksum(&keccak256(log_index.to_be_bytes()));
+            access
+        };
+        let first = access(0);
+        let second = access(1);
+
+        let entries = [first.to_entries(), second.to_entries(), first.to_entries()].concat();
+        assert_eq!(entries[1], entries[4]);
+
+        let parsed = parse_access_list(entries).unwrap();
+        assert_eq!(parsed, vec![first.clone(), second.clone(), first.clone()]);
+
+        let (list, duplicates) = dedup_access_list(parsed);
+        assert_eq!(list, vec![first, second]);
+        assert_eq!(duplicates, 1);
+    }
 }
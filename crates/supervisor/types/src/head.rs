@@ -1,5 +1,6 @@
 //! Head of chain in context of superchain.
 
+use core::cmp::Ordering;
 use kona_protocol::BlockInfo;
 
 /// Head of a chain from superchain perspective.
@@ -34,3 +35,161 @@ pub struct SuperHead {
     /// [`Finalized`]: op_alloy_consensus::interop::SafetyLevel::Finalized
     pub finalized: Option<BlockInfo>,
 }
+
+impl SuperHead {
+    /// Compares the heads of this snapshot with a later snapshot, reporting how each head moved.
+    pub fn diff(&self, other: &Self) -> SuperHeadDiff {
+        SuperHeadDiff {
+            local_unsafe: HeadMovement::between(
+                Some(&self.local_unsafe),
+                Some(&other.local_unsafe),
+            ),
+            cross_unsafe: HeadMovement::between(
+                self.cross_unsafe.as_ref(),
+                other.cross_unsafe.as_ref(),
+            ),
+            local_safe: HeadMovement::between(self.local_safe.as_ref(), other.local_safe.as_ref()),
+            cross_safe: HeadMovement::between(self.cross_safe.as_ref(), other.cross_safe.as_ref()),
+            finalized: HeadMovement::between(self.finalized.as_ref(), other.finalized.as_ref()),
+        }
+    }
+}
+
+/// Movement of a single head between two [`SuperHead`] snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeadMovement {
+    /// The head moved to a higher block, or was set.
+    Advanced,
+    /// The head did not move.
+    Unchanged,
+    /// The head was replaced by a different block at the same height.
+    Replaced,
+    /// The head moved to a lower block, or was unset.
+    Regressed,
+}
+
+impl HeadMovement {
+    fn between(old: Option<&BlockInfo>, new: Option<&BlockInfo>) -> Self {
+        match (old, new) {
+            (None, None) => Self::Unchanged,
+            (None, Some(_)) => Self::Advanced,
+            (Some(_), None) => Self::Regressed,
+            (Some(old), Some(new)) => match new.number.cmp(&old.number) {
+                Ordering::Greater => Self::Advanced,
+                Ordering::Less => Self::Regressed,
+                Ordering::Equal if new.hash == old.hash => Self::Unchanged,
+                Ordering::Equal => Self::Replaced,
+            },
+        }
+    }
+
+    /// Returns `true` if the head moved backwards, or was replaced at the same height.
+    pub const fn is_reorg(&self) -> bool {
+        matches!(self, Self::Replaced | Self::Regressed)
+    }
+}
+
+/// Per-head movement between two [`SuperHead`] snapshots, see [`SuperHead::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuperHeadDiff {
+    /// Movement of the local unsafe head.
+    pub local_unsafe: HeadMovement,
+    /// Movement of the cross unsafe head.
+    pub cross_unsafe: HeadMovement,
+    /// Movement of the local safe head.
+    pub local_safe: HeadMovement,
+    /// Movement of the cross safe head.
+    pub cross_safe: HeadMovement,
+    /// Movement of the finalized head.
+    pub finalized: HeadMovement,
+}
+
+impl SuperHeadDiff {
+    /// Returns `true` if any head regressed or was replaced.
+    pub const fn has_reorg(&self) -> bool {
+        self.local_unsafe.is_reorg() ||
+            self.cross_unsafe.is_reorg() ||
+            self.local_safe.is_reorg() ||
+            self.cross_safe.is_reorg() ||
+            self.finalized.is_reorg()
+    }
+
+    /// Returns `true` if no head moved.
+    pub fn is_unchanged(&self) -> bool {
+        [self.local_unsafe, self.cross_unsafe, self.local_safe, self.cross_safe, self.finalized]
+            .iter()
+            .all(|movement| *movement == HeadMovement::Unchanged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    fn block(number: u64) -> BlockInfo {
+        BlockInfo { number, hash: B256::with_last_byte(number as u8), ..Default::default() }
+    }
+
+    fn head(unsafe_number: u64, safe_number: u64, finalized: Option<u64>) -> SuperHead {
+        SuperHead {
+            l1_source: Some(block(1)),
+            local_unsafe: block(unsafe_number),
+            cross_unsafe: Some(block(unsafe_number)),
+            local_safe: Some(block(safe_number)),
+            cross_safe: Some(block(safe_number)),
+            finalized: finalized.map(block),
+        }
+    }
+
+    #[test]
+    fn test_diff_advances() {
+        let diff = head(10, 5, None).diff(&head(12, 6, Some(3)));
+
+        assert_eq!(
+            diff,
+            SuperHeadDiff {
+                local_unsafe: HeadMovement::Advanced,
+                cross_unsafe: HeadMovement::Advanced,
+                local_safe: HeadMovement::Advanced,
+                cross_safe: HeadMovement::Advanced,
+                finalized: HeadMovement::Advanced,
+            }
+        );
+        assert!(!diff.has_reorg());
+        assert!(!diff.is_unchanged());
+    }
+
+    #[test]
+    fn test_diff_no_op() {
+        let diff = head(10, 5, Some(3)).diff(&head(10, 5, Some(3)));
+
+        assert!(diff.is_unchanged());
+        assert!(!diff.has_reorg());
+        assert!(SuperHead::default().diff(&SuperHead::default()).is_unchanged());
+    }
+
+    #[test]
+    fn test_diff_regressions() {
+        let diff = head(10, 5, Some(3)).diff(&head(8, 5, None));
+
+        assert_eq!(diff.local_unsafe, HeadMovement::Regressed);
+        assert_eq!(diff.cross_unsafe, HeadMovement::Regressed);
+        assert_eq!(diff.local_safe, HeadMovement::Unchanged);
+        assert_eq!(diff.cross_safe, HeadMovement::Unchanged);
+        assert_eq!(diff.finalized, HeadMovement::Regressed);
+        assert!(diff.has_reorg());
+    }
+
+    #[test]
+    fn test_diff_replaced_at_same_height() {
+        let old = head(10, 5, Some(3));
+        let mut new = old;
+        new.local_unsafe.hash = B256::with_last_byte(0xff);
+
+        let diff = old.diff(&new);
+        assert_eq!(diff.local_unsafe, HeadMovement::Replaced);
+        assert_eq!(diff.cross_unsafe, HeadMovement::Unchanged);
+        assert!(diff.has_reorg());
+    }
+}
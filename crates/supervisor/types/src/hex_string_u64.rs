@@ -1,5 +1,10 @@
+use serde::de::{IntoDeserializer, value::StringDeserializer};
+
 /// A wrapper around `u64` that supports hex string (e.g. `"0x1"`) or numeric deserialization
 /// for RPC inputs.
+///
+/// Hex strings may use underscores as digit separators (e.g. `"0x1_000"`). Serialization is
+/// always canonical, without separators.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct HexStringU64(pub u64);
 
@@ -17,9 +22,48 @@ impl<'de> serde::Deserialize<'de> for HexStringU64 {
     where
         D: serde::Deserializer<'de>,
     {
+        deserializer.deserialize_any(HexStringU64Visitor)
+    }
+}
+
+struct HexStringU64Visitor;
+
+impl serde::de::Visitor<'_> for HexStringU64Visitor {
+    type Value = HexStringU64;
+
+    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("a hex string or a number")
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(HexStringU64(v))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let Some(stripped) = strip_underscores(v) else {
+            return Err(E::invalid_value(serde::de::Unexpected::Str(v), &self));
+        };
+        let deserializer: StringDeserializer<E> = stripped.into_deserializer();
         let inner = alloy_serde::quantity::deserialize(deserializer)?;
-        Ok(Self(inner))
+        Ok(HexStringU64(inner))
+    }
+}
+
+/// Removes the digit separators from a literal such as `0x1_000`.
+///
+/// Underscores are only allowed between two digits, so `None` is returned for a literal with
+/// leading, trailing, or consecutive underscores.
+fn strip_underscores(literal: &str) -> Option<String> {
+    let (prefix, digits) = literal
+        .strip_prefix("0x")
+        .map(|digits| ("0x", digits))
+        .or_else(|| literal.strip_prefix("0X").map(|digits| ("0X", digits)))
+        .unwrap_or(("", literal));
+
+    if digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return None;
     }
+    Some(format!("{prefix}{}", digits.replace('_', "")))
 }
 
 impl From<HexStringU64> for u64 {
@@ -60,4 +104,34 @@ mod tests {
         let parsed: HexStringU64 = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.0, original.0);
     }
+
+    #[test]
+    fn test_deserialize_underscore_separated() {
+        let parsed: HexStringU64 = serde_json::from_str(r#""0x1_000""#).unwrap();
+        assert_eq!(parsed.0, 0x1000);
+
+        let parsed: HexStringU64 = serde_json::from_str(r#""0xdead_beef_0001""#).unwrap();
+        assert_eq!(parsed.0, 0xdead_beef_0001);
+
+        // serialization stays canonical
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), r#""0xdeadbeef0001""#);
+    }
+
+    #[test]
+    fn test_deserialize_plain() {
+        let parsed: HexStringU64 = serde_json::from_str(r#""0x1000""#).unwrap();
+        assert_eq!(parsed.0, 0x1000);
+
+        let parsed: HexStringU64 = serde_json::from_str("4096").unwrap();
+        assert_eq!(parsed.0, 4096);
+    }
+
+    #[test]
+    fn test_deserialize_invalid() {
+        for json in
+            [r#""0x_1000""#, r#""0x1000_""#, r#""0x1__000""#, r#""0x1_0g0""#, r#""_""#, r#""0x""#]
+        {
+            assert!(serde_json::from_str::<HexStringU64>(json).is_err(), "{json} should not parse");
+        }
+    }
 }
@@ -4,7 +4,7 @@
 //! Optimism supervisor.
 
 pub mod head;
-pub use head::SuperHead;
+pub use head::{HeadMovement, SuperHead, SuperHeadDiff};
 
 mod log;
 pub use log::Log;
@@ -13,14 +13,14 @@ mod message;
 pub use message::ExecutingMessage;
 
 mod receipt;
-pub use receipt::Receipts;
+pub use receipt::{Receipts, ReceiptsExt};
 
 mod access_list;
-pub use access_list::{Access, AccessListError, parse_access_list};
+pub use access_list::{Access, AccessListError, dedup_access_list, parse_access_list};
 
 mod hex_string_u64;
 mod types;
 
 pub use hex_string_u64::HexStringU64;
 
-pub use types::{BlockSeal, OutputV0, SubscriptionEvent};
+pub use types::{BlockSeal, OutputV0, SafetyLevelUpdate, SubscriptionEvent};
@@ -1,4 +1,80 @@
+use alloy_primitives::{B256, Bloom, BloomInput};
 use op_alloy_consensus::OpReceiptEnvelope;
 
 /// Collection of transaction receipts.
 pub type Receipts = Vec<OpReceiptEnvelope>;
+
+/// Extension methods for [`Receipts`].
+pub trait ReceiptsExt {
+    /// Returns `false` if the aggregated logs bloom of the receipts rules out any log with the
+    /// given executing message topic, so the receipts need not be scanned.
+    ///
+    /// The check never produces a false negative. Receipts carrying logs with an empty bloom are
+    /// assumed to match, since their bloom was not populated.
+    fn may_contain_executing_messages(&self, topic: B256) -> bool;
+}
+
+impl ReceiptsExt for [OpReceiptEnvelope] {
+    fn may_contain_executing_messages(&self, topic: B256) -> bool {
+        let mut bloom = Bloom::ZERO;
+        for receipt in self {
+            if receipt.logs().is_empty() {
+                continue;
+            }
+            if *receipt.logs_bloom() == Bloom::ZERO {
+                return true;
+            }
+            bloom.accrue_bloom(receipt.logs_bloom());
+        }
+        bloom.contains_input(BloomInput::Raw(topic.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, Bytes, Log};
+    use op_alloy_consensus::OpTxType;
+
+    const TOPIC: B256 = B256::repeat_byte(0xee);
+
+    fn receipt_with_topic(topic: B256) -> OpReceiptEnvelope {
+        let log = Log::new_unchecked(Address::repeat_byte(0x42), vec![topic], Bytes::new());
+        OpReceiptEnvelope::from_parts(true, 21_000, [&log], OpTxType::Eip1559, None, None)
+    }
+
+    #[test]
+    fn test_matching_logs_pass() {
+        let receipts: Receipts =
+            vec![receipt_with_topic(B256::repeat_byte(0x01)), receipt_with_topic(TOPIC)];
+        assert!(receipts.may_contain_executing_messages(TOPIC));
+    }
+
+    #[test]
+    fn test_empty_blocks_rejected() {
+        let receipts: Receipts = vec![];
+        assert!(!receipts.may_contain_executing_messages(TOPIC));
+
+        let receipts: Receipts = vec![OpReceiptEnvelope::from_parts(
+            true,
+            21_000,
+            vec![],
+            OpTxType::Eip1559,
+            None,
+            None,
+        )];
+        assert!(!receipts.may_contain_executing_messages(TOPIC));
+
+        let receipts: Receipts = vec![receipt_with_topic(B256::repeat_byte(0x01))];
+        assert!(!receipts.may_contain_executing_messages(TOPIC));
+    }
+
+    #[test]
+    fn test_unpopulated_bloom_passes() {
+        let mut receipt = receipt_with_topic(B256::repeat_byte(0x01));
+        if let OpReceiptEnvelope::Eip1559(receipt) = &mut receipt {
+            receipt.logs_bloom = Bloom::ZERO;
+        }
+        assert!(vec![receipt].may_contain_executing_messages(TOPIC));
+    }
+}
@@ -4,8 +4,10 @@
 //! and the op-node components in the rollup system. It includes block references,
 //! block seals, derivation events, and event notifications.
 
+use alloy_eips::BlockNumHash;
 use alloy_primitives::B256;
-use kona_interop::ManagedEvent;
+use kona_interop::{ManagedEvent, SafetyLevel};
+use kona_protocol::OutputRoot;
 use serde::{Deserialize, Serialize};
 
 // todo:: Determine appropriate locations for these structs and move them accordingly.
@@ -50,6 +52,43 @@ impl OutputV0 {
     ) -> Self {
         Self { state_root, message_passer_storage_root, block_hash }
     }
+
+    /// Returns the v0 output root committing to this [`OutputV0`].
+    ///
+    /// The output root is `keccak256(version ++ state_root ++ message_passer_storage_root ++
+    /// block_hash)` with a zero version, as defined by the [`OutputRoot`] commitment.
+    pub fn hash(&self) -> B256 {
+        OutputRoot::from_parts(self.state_root, self.message_passer_storage_root, self.block_hash)
+            .hash()
+    }
+}
+
+/// A block of a managed node reaching a new safety level, pushed by the supervisor to the node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyLevelUpdate {
+    /// The safety level the block reached
+    pub level: SafetyLevel,
+    /// The block that reached the safety level
+    pub block: BlockNumHash,
+    /// The L1 block the block was derived from, only set for cross-safe updates
+    pub source: Option<BlockNumHash>,
+}
+
+impl SafetyLevelUpdate {
+    /// Creates a [`SafetyLevel::CrossUnsafe`] update of the given block.
+    pub const fn cross_unsafe(block: BlockNumHash) -> Self {
+        Self { level: SafetyLevel::CrossUnsafe, block, source: None }
+    }
+
+    /// Creates a [`SafetyLevel::CrossSafe`] update of the given block, derived from `source`.
+    pub const fn cross_safe(source: BlockNumHash, block: BlockNumHash) -> Self {
+        Self { level: SafetyLevel::CrossSafe, block, source: Some(source) }
+    }
+
+    /// Creates a [`SafetyLevel::Finalized`] update of the given block.
+    pub const fn finalized(block: BlockNumHash) -> Self {
+        Self { level: SafetyLevel::Finalized, block, source: None }
+    }
 }
 
 /// Represents the events structure sent by the node to the supervisor.
@@ -62,9 +101,32 @@ pub struct SubscriptionEvent {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::B256;
+    use alloy_primitives::{B256, b256, keccak256};
     use serde_json::{Value, json};
 
+    #[test]
+    fn test_output_v0_hash() {
+        let output = OutputV0::new(
+            b256!("cca3e35b50432cd1669313bd75e434458dd8bc8d21437d2aa29d6c256f7b13d1"),
+            b256!("0568fdda45c07c69d9187bead7fb159244564e0f6658a704d0f8f31c9a086442"),
+            b256!("d7fd94528f836b27f28ba8d7c354705dfc5827b048ca48870ac47c9d5b9aa181"),
+        );
+
+        // keccak256(version ++ state_root ++ message_passer_storage_root ++ block_hash), computed
+        // outside of this crate.
+        let expected = b256!("eb1a0b33aa39d7fc3d5e459ce128add03ea94d1c65f195d391021341a1bb30d3");
+        assert_eq!(output.hash(), expected);
+
+        let preimage = [
+            B256::ZERO.as_slice(),
+            output.state_root.as_slice(),
+            output.message_passer_storage_root.as_slice(),
+            output.block_hash.as_slice(),
+        ]
+        .concat();
+        assert_eq!(keccak256(preimage), expected);
+    }
+
     #[test]
     fn test_output_v0_serialize_camel_case() {
         let output = OutputV0 {
@@ -1,8 +1,11 @@
 //! [tracing_subscriber] utilities.
 
+use tracing::Subscriber;
 use tracing_subscriber::{
     Layer,
+    fmt::MakeWriter,
     prelude::__tracing_subscriber_SubscriberExt,
+    registry::LookupSpan,
     util::{SubscriberInitExt, TryInitError},
 };
 
@@ -27,6 +30,29 @@ pub enum LogFormat {
     Pretty,
     /// Compact format.
     Compact,
+    /// JSON format with the event fields at the top level.
+    #[serde(rename = "json-flat")]
+    #[value(name = "json-flat")]
+    JsonFlat,
+}
+
+/// Returns a layer printing each event as a single-line JSON object, used by
+/// [`LogFormat::JsonFlat`].
+///
+/// Event fields, e.g. `chain_id`, are flattened into the object next to `timestamp`, `level` and
+/// `target`. The fields of the current span, if any, are nested under `span`.
+pub fn flat_json_layer<S, W>(writer: W) -> impl Layer<S>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt::layer()
+        .json()
+        .flatten_event(true)
+        .with_current_span(true)
+        .with_span_list(false)
+        .with_target(true)
+        .with_writer(writer)
 }
 
 impl LogConfig {
@@ -67,6 +93,7 @@ impl LogConfig {
                 LogFormat::Compact => {
                     tracing_subscriber::fmt::layer().compact().with_writer(appender).boxed()
                 }
+                LogFormat::JsonFlat => flat_json_layer(appender).boxed(),
             }
         });
 
@@ -75,6 +102,7 @@ impl LogConfig {
             LogFormat::Json => tracing_subscriber::fmt::layer().json().boxed(),
             LogFormat::Pretty => tracing_subscriber::fmt::layer().pretty().boxed(),
             LogFormat::Compact => tracing_subscriber::fmt::layer().compact().boxed(),
+            LogFormat::JsonFlat => flat_json_layer(std::io::stdout).boxed(),
         });
 
         let env_filter = env_filter
@@ -100,3 +128,59 @@ impl LogConfig {
 pub fn init_test_tracing() {
     let _ = LogConfig::default().init_tracing_subscriber(None::<EnvFilter>);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io,
+        sync::{Arc, Mutex, PoisonError},
+    };
+    use tracing::{info, info_span};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap_or_else(PoisonError::into_inner).extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_flat_json_layer_output() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber =
+            tracing_subscriber::registry().with(flat_json_layer(move || writer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = info_span!("chain_event", chain_id = 10).entered();
+            info!(target: "supervisor::chain_processor", block_number = 5, "Processed block");
+        });
+
+        let output = buffer.0.lock().unwrap().clone();
+        let line = String::from_utf8(output).unwrap();
+        let log: serde_json::Value = serde_json::from_str(line.trim()).unwrap();
+
+        assert!(log.get("timestamp").is_some());
+        assert_eq!(log["level"], "INFO");
+        assert_eq!(log["target"], "supervisor::chain_processor");
+        assert_eq!(log["message"], "Processed block");
+        assert_eq!(log["block_number"], 5);
+        assert_eq!(log["span"]["chain_id"], 10);
+    }
+
+    #[test]
+    fn test_parse_json_flat_format() {
+        use clap::ValueEnum;
+
+        assert_eq!(LogFormat::from_str("json", false).unwrap(), LogFormat::Json);
+        assert_eq!(LogFormat::from_str("json-flat", false).unwrap(), LogFormat::JsonFlat);
+    }
+}
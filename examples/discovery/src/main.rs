@@ -14,13 +14,30 @@
 //! - `-c` or `--l2-chain-id`: The L2 chain ID to use
 //! - `-l` or `--disc-port`: Port to listen for discovery on
 //! - `-i` or `--interval`: Interval to send discovery packets
+//! - `-f` or `--fork-id`: (Optional) Only discover peers advertising this fork id, instead of
+//!   passing `--l2-chain-id`
+//!
+//! ## Fork ID
+//!
+//! The fork id is the hex encoded value of the `opstack` ENR field: the unsigned varint encoded
+//! chain id followed by the unsigned varint encoded version. For example, the fork id of OP
+//! Mainnet (chain id 10, version 0) is `0x0a00`, and the one of Base (chain id 8453, version 0)
+//! is `0x854200`.
+//!
+//! The discovery service itself drops peers whose `opstack` field doesn't carry the chain id it
+//! was started with, or a version other than 0. The fork id is therefore decoded into the chain
+//! id the service is started with, and only version 0 fork ids are accepted.
 
 #![warn(unused_crate_dependencies)]
 
+use alloy_primitives::Bytes;
+use alloy_rlp::Decodable;
+use anyhow::{anyhow, bail};
 use clap::Parser;
 use discv5::enr::CombinedKey;
 use kona_cli::{LogArgs, LogConfig};
 use kona_disc::{Discv5Builder, LocalNode};
+use kona_peers::OpStackEnr;
 use std::net::{IpAddr, Ipv4Addr};
 
 /// The discovery command.
@@ -41,15 +58,38 @@ pub struct DiscCommand {
     /// Interval to send discovery packets.
     #[arg(long, short = 'i', default_value = "3", help = "Interval to send discovery packets")]
     pub interval: u64,
+    /// Only discover peers advertising this fork id.
+    #[arg(
+        long,
+        short = 'f',
+        conflicts_with = "l2_chain_id",
+        help = "Only discover peers advertising this fork id"
+    )]
+    pub fork_id: Option<Bytes>,
 }
 
 impl DiscCommand {
+    /// Returns the chain id to discover peers for, decoded from the fork id if one is set.
+    fn chain_id(&self) -> anyhow::Result<u64> {
+        let Some(fork_id) = &self.fork_id else {
+            return Ok(self.l2_chain_id);
+        };
+        let opstack = OpStackEnr::decode(&mut alloy_rlp::encode(fork_id).as_slice())
+            .map_err(|err| anyhow!("Invalid fork id {fork_id}: {err}"))?;
+        if opstack.version != 0 {
+            bail!("Unsupported fork id {fork_id}: version {} isn't 0", opstack.version);
+        }
+        Ok(opstack.chain_id)
+    }
+
     /// Run the discovery subcommand.
     pub async fn run(self) -> anyhow::Result<()> {
         let filter = tracing_subscriber::EnvFilter::from_default_env()
             .add_directive("discv5=error".parse()?);
         LogConfig::new(self.v).init_tracing_subscriber(Some(filter))?;
 
+        let chain_id = self.chain_id()?;
+
         let CombinedKey::Secp256k1(secret_key) = CombinedKey::generate_secp256k1() else {
             unreachable!()
         };
@@ -64,7 +104,7 @@ impl DiscCommand {
 
         let discovery_builder = Discv5Builder::new(
             socket,
-            self.l2_chain_id,
+            chain_id,
             discv5::ConfigBuilder::new(discv5::ListenConfig::Ipv4 {
                 ip: Ipv4Addr::UNSPECIFIED,
                 port: self.disc_port,
@@ -75,7 +115,7 @@ impl DiscCommand {
         discovery.interval = std::time::Duration::from_secs(self.interval);
         discovery.forward = false;
         let (handler, mut enr_receiver) = discovery.start();
-        tracing::info!(target: "discovery", "Discovery service started, receiving peers.");
+        tracing::info!(target: "discovery", chain_id, "Discovery service started, receiving peers.");
 
         let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(5));
         loop {
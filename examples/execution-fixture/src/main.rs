@@ -13,6 +13,8 @@
 //! - `-v` or `--verbosity`: Verbosity level (0-2)
 //! - `-r` or `--l2-rpc`: The L2 execution layer RPC URL to use. Must be archival.
 //! - `-b` or `--block-number`: L2 block number to execute for the fixture.
+//! - `-e` or `--end-block-number`: (Optional) Last L2 block number to execute. If provided, a
+//!   fixture is created for each block from `--block-number` to this block, sharing one witness.
 //! - `-o` or `--output-dir`: (Optional) The output directory for the fixture. If not provided,
 //!   defaults to `kona-executor`'s `testdata` directory.
 
@@ -37,6 +39,9 @@ pub struct ExecutionFixtureCommand {
     /// L2 block number to execute.
     #[arg(long, short = 'b')]
     pub block_number: u64,
+    /// Last L2 block number to execute, creating a fixture for each block of the range.
+    #[arg(long, short = 'e')]
+    pub end_block_number: Option<u64>,
     /// The output directory for the fixture.
     #[arg(long, short = 'o')]
     pub output_dir: Option<PathBuf>,
@@ -68,10 +73,18 @@ async fn main() -> Result<()> {
             .join("crates/proof/executor/testdata")
     };
 
-    ExecutorTestFixtureCreator::new(cli.l2_rpc.as_str(), cli.block_number, output_dir, cli.rollup_dir)
-        .create_static_fixture()
-        .await;
-
-    info!(target: "execution_fixture", block_number = cli.block_number, "Successfully created static test fixture");
+    let creator = ExecutorTestFixtureCreator::new(
+        cli.l2_rpc.as_str(),
+        cli.block_number,
+        output_dir,
+        cli.rollup_dir,
+    );
+    if let Some(end_block_number) = cli.end_block_number {
+        creator.create_static_fixture_range(cli.block_number, end_block_number).await;
+        info!(target: "execution_fixture", block_number = cli.block_number, end_block_number, "Successfully created static test fixtures");
+    } else {
+        creator.create_static_fixture().await;
+        info!(target: "execution_fixture", block_number = cli.block_number, "Successfully created static test fixture");
+    }
     Ok(())
 }
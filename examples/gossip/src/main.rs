@@ -14,6 +14,21 @@
 //! - `-c` or `--l2-chain-id`: The L2 chain ID to use
 //! - `-l` or `--gossip-port`: Port to listen for gossip on
 //! - `-i` or `--interval`: Interval to send discovery packets
+//! - `-r` or `--replay`: (Optional) A file of unsafe blocks to replay instead of waiting for live
+//!   blocks
+//!
+//! ## Replay
+//!
+//! With `--replay`, the blocks in the given file are posted to the network actor through its
+//! admin API, and the example exits once all of them were forwarded as unsafe blocks. The file
+//! holds one JSON encoded `OpExecutionPayloadEnvelope` per line, the format accepted by
+//! `admin_postUnsafePayload`. Blank lines are ignored and malformed lines are skipped with a
+//! warning.
+//!
+//! Replayed blocks bypass gossip validation, like any payload posted with
+//! `admin_postUnsafePayload`: they are neither checked against the unsafe block signer nor
+//! against the current time. Gossip validation requires a signed block with a timestamp close to
+//! the current time, which a recording can't provide. Only replay blocks from a trusted source.
 
 #![warn(unused_crate_dependencies)]
 
@@ -23,9 +38,12 @@ use kona_cli::{LogArgs, LogConfig};
 use kona_disc::LocalNode;
 use kona_node_service::{NetworkActor, NetworkConfig, NetworkContext, NodeActor};
 use kona_registry::ROLLUP_CONFIGS;
+use kona_rpc::{HealthRegistry, NetworkAdminQuery, NodeComponent};
 use libp2p::{Multiaddr, identity::Keypair};
+use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
     time::Duration,
 };
 use tokio_util::sync::CancellationToken;
@@ -49,6 +67,27 @@ pub struct GossipCommand {
     /// Interval to send discovery packets.
     #[arg(long, short = 'i', default_value = "1", help = "Interval to send discovery packets")]
     pub interval: u64,
+    /// A file of unsafe blocks to replay instead of waiting for live blocks.
+    #[arg(long, short = 'r', help = "A file of unsafe blocks to replay")]
+    pub replay: Option<PathBuf>,
+}
+
+/// Reads the unsafe blocks to replay from `path`, skipping malformed entries.
+fn read_replay_file(path: &Path) -> anyhow::Result<Vec<OpExecutionPayloadEnvelope>> {
+    let contents = std::fs::read_to_string(path)?;
+    let blocks = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| match serde_json::from_str(line) {
+            Ok(block) => Some(block),
+            Err(err) => {
+                tracing::warn!(target: "gossip", "Skipping malformed block on line {}: {err}", index + 1);
+                None
+            }
+        })
+        .collect();
+    Ok(blocks)
 }
 
 impl GossipCommand {
@@ -56,6 +95,8 @@ impl GossipCommand {
     pub async fn run(self) -> anyhow::Result<()> {
         LogConfig::new(self.v).init_tracing_subscriber(None::<EnvFilter>)?;
 
+        let replay = self.replay.as_deref().map(read_replay_file).transpose()?;
+
         let rollup_config = ROLLUP_CONFIGS
             .get(&self.l2_chain_id)
             .ok_or(anyhow::anyhow!("No rollup config found for chain ID"))?;
@@ -81,7 +122,7 @@ impl GossipCommand {
         let disc_addr =
             LocalNode::new(secret_key, IpAddr::V4(disc_ip), self.disc_port, self.disc_port);
 
-        let (_, network) = NetworkActor::new(
+        let (inbound, network) = NetworkActor::new(
             NetworkConfig {
                 discovery_address: disc_addr,
                 gossip_address: gossip_addr,
@@ -104,30 +145,57 @@ impl GossipCommand {
                 rollup_config: rollup_config.clone(),
                 gossip_signer: None,
                 enr_update: true,
+                prioritize_blocks: false,
             }
             .into(),
         );
 
         let (unsafe_blocks_tx, mut unsafe_blocks_rx) = tokio::sync::mpsc::channel(1024);
 
-        network
-            .start(NetworkContext {
-                blocks: unsafe_blocks_tx,
-                cancellation: CancellationToken::new(),
-            })
-            .await?;
-
-        tracing::info!(target: "gossip", "Gossip driver started, receiving blocks.");
-        loop {
-            match unsafe_blocks_rx.recv().await {
-                Some(block) => {
-                    tracing::info!(target: "gossip", "Received unsafe block: {:?}", block);
-                }
-                None => {
-                    tracing::warn!(target: "gossip", "unsafe block gossip channel closed");
+        let cancellation = CancellationToken::new();
+        let network = tokio::spawn(network.start(NetworkContext {
+            blocks: unsafe_blocks_tx,
+            cancellation: cancellation.clone(),
+            heartbeat: HealthRegistry::default().heartbeat(NodeComponent::Network),
+        }));
+
+        let Some(blocks) = replay else {
+            tracing::info!(target: "gossip", "Gossip driver started, receiving blocks.");
+            loop {
+                match unsafe_blocks_rx.recv().await {
+                    Some(block) => {
+                        tracing::info!(target: "gossip", "Received unsafe block: {:?}", block);
+                    }
+                    None => {
+                        tracing::warn!(target: "gossip", "unsafe block gossip channel closed");
+                        return Ok(network.await??);
+                    }
                 }
             }
+        };
+
+        let count = blocks.len();
+        tracing::info!(target: "gossip", "Gossip driver started, replaying {count} blocks.");
+        tracing::warn!(target: "gossip", "Replayed blocks are not validated.");
+        // Posted payloads skip the gossip validation of blocks received from peers.
+        for payload in blocks {
+            inbound
+                .admin_rpc
+                .send(NetworkAdminQuery::PostUnsafePayload { payload })
+                .await
+                .map_err(|_| anyhow::anyhow!("Network actor stopped during replay"))?;
+        }
+        for _ in 0..count {
+            let block = unsafe_blocks_rx
+                .recv()
+                .await
+                .ok_or(anyhow::anyhow!("unsafe block gossip channel closed"))?;
+            tracing::info!(target: "gossip", "Received unsafe block: {:?}", block);
         }
+
+        tracing::info!(target: "gossip", "Replayed {count} blocks.");
+        cancellation.cancel();
+        Ok(network.await??)
     }
 }
 
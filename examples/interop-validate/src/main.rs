@@ -0,0 +1,216 @@
+//! Example of validating an executing message against a supervisor
+//!
+//! The initiating message referenced by the executing message is checked with
+//! `supervisor_checkAccessList` at every safety level, from finalized down to local unsafe. The
+//! highest level the message passes is its resolved safety level.
+//!
+//! ## Usage
+//!
+//! ```sh
+//! cargo run --release -p example-interop-validate -- \
+//!     --supervisor-rpc http://localhost:8545 \
+//!     --chain-id 902 \
+//!     --origin-chain-id 901 \
+//!     --origin 0x4200000000000000000000000000000000000023 \
+//!     --block-number 1200 \
+//!     --log-index 0 \
+//!     --timestamp 1750000000 \
+//!     --payload-hash 0x...
+//! ```
+//!
+//! ## Inputs
+//!
+//! - `-v` or `--verbosity`: Verbosity level (0-2)
+//! - `-s` or `--supervisor-rpc`: The supervisor RPC URL.
+//! - `-c` or `--chain-id`: The chain ID the message is executed on.
+//! - `--origin-chain-id`: The chain ID of the initiating message.
+//! - `--origin`: The address that emitted the initiating message.
+//! - `--block-number`: The block number of the initiating message.
+//! - `--log-index`: The log index of the initiating message within its block.
+//! - `--timestamp`: The timestamp of the block of the initiating message.
+//! - `--payload-hash`: The hash of the initiating message payload.
+//! - `--executing-timestamp`: (Optional) The timestamp the message is executed at. Defaults to now.
+//! - `--timeout`: (Optional) How long after the executing timestamp the message must stay valid.
+//!
+//! ## Outcomes
+//!
+//! - The resolved safety level and the location of the initiating message, if it is valid.
+//! - An error if the message expired before the executing timestamp.
+//! - An error if the supervisor doesn't know the initiating message, e.g. because the origin chain
+//!   didn't sync up to it yet.
+
+#![warn(unused_crate_dependencies)]
+
+use alloy_primitives::{Address, B256};
+use alloy_transport::TransportError;
+use anyhow::{Result, anyhow};
+use clap::Parser;
+use kona_cli::{LogArgs, LogConfig};
+use kona_interop::{ExecutingDescriptor, SafetyLevel};
+use kona_supervisor_core::{SpecError, payload_hash_to_log_hash};
+use kona_supervisor_rpc::{
+    CheckAccessListClient, SupervisorClient, SupervisorClientConfig, SupervisorClientError,
+};
+use kona_supervisor_types::{Access, ExecutingMessage};
+use op_alloy_rpc_types::SuperchainDAError;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::debug;
+use tracing_subscriber::EnvFilter;
+use url::Url;
+
+/// The safety levels a message is checked at, from the highest to the lowest.
+const SAFETY_LEVELS: [SafetyLevel; 5] = [
+    SafetyLevel::Finalized,
+    SafetyLevel::CrossSafe,
+    SafetyLevel::LocalSafe,
+    SafetyLevel::CrossUnsafe,
+    SafetyLevel::LocalUnsafe,
+];
+
+/// The interop validation command.
+#[derive(Parser, Debug, Clone)]
+#[command(about = "Validates an executing message against a supervisor")]
+pub struct InteropValidateCommand {
+    #[command(flatten)]
+    pub v: LogArgs,
+    /// The supervisor RPC URL.
+    #[arg(long, short = 's')]
+    pub supervisor_rpc: Url,
+    /// The chain ID the message is executed on.
+    #[arg(long, short = 'c')]
+    pub chain_id: u64,
+    /// The chain ID of the initiating message.
+    #[arg(long)]
+    pub origin_chain_id: u64,
+    /// The address that emitted the initiating message.
+    #[arg(long)]
+    pub origin: Address,
+    /// The block number of the initiating message.
+    #[arg(long)]
+    pub block_number: u64,
+    /// The log index of the initiating message within its block.
+    #[arg(long)]
+    pub log_index: u32,
+    /// The timestamp of the block of the initiating message.
+    #[arg(long)]
+    pub timestamp: u64,
+    /// The hash of the initiating message payload.
+    #[arg(long)]
+    pub payload_hash: B256,
+    /// The timestamp the message is executed at. Defaults to now.
+    #[arg(long)]
+    pub executing_timestamp: Option<u64>,
+    /// How long after the executing timestamp the message must stay valid, in seconds.
+    #[arg(long)]
+    pub timeout: Option<u64>,
+}
+
+/// The reason the supervisor rejected a message.
+#[derive(Debug)]
+enum Rejection {
+    /// The message is valid, but not at the checked safety level.
+    InsufficientSafety,
+    /// The message expired before the executing timestamp.
+    Expired,
+    /// The supervisor doesn't know the initiating message.
+    Unresolved,
+    /// Any other error.
+    Other(SupervisorClientError),
+}
+
+impl From<SupervisorClientError> for Rejection {
+    fn from(err: SupervisorClientError) -> Self {
+        let SupervisorClientError::Client(inner) = &err else {
+            return Self::Other(err);
+        };
+        let Some(code) = inner
+            .downcast_ref::<TransportError>()
+            .and_then(|err| err.as_error_resp())
+            .map(|payload| payload.code)
+        else {
+            return Self::Other(err);
+        };
+
+        let insufficient_safety = SpecError::InsufficientSafety {
+            required: SafetyLevel::LocalUnsafe,
+            actual: SafetyLevel::LocalUnsafe,
+        };
+        match code {
+            code if code == insufficient_safety.code() as i64 => Self::InsufficientSafety,
+            code if code == SpecError::MessageExpired.code() as i64 => Self::Expired,
+            code if code == SpecError::InitiatingMessageNotFound.code() as i64 ||
+                code == SuperchainDAError::MissedData as i64 ||
+                code == SuperchainDAError::FutureData as i64 =>
+            {
+                Self::Unresolved
+            }
+            _ => Self::Other(err),
+        }
+    }
+}
+
+impl InteropValidateCommand {
+    /// Checks the message and prints its resolved safety level and location.
+    pub async fn run(self) -> Result<()> {
+        LogConfig::new(self.v.clone()).init_tracing_subscriber(None::<EnvFilter>)?;
+
+        let client = SupervisorClient::with_config(
+            self.supervisor_rpc.clone(),
+            SupervisorClientConfig::default(),
+        )?;
+        let message = ExecutingMessage {
+            chain_id: self.origin_chain_id,
+            block_number: self.block_number,
+            log_index: self.log_index,
+            timestamp: self.timestamp,
+            hash: payload_hash_to_log_hash(self.payload_hash, self.origin),
+        };
+        let inbox_entries = Access::from(&message).to_entries();
+        let executing_timestamp = match self.executing_timestamp {
+            Some(timestamp) => timestamp,
+            None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        };
+        let descriptor =
+            ExecutingDescriptor::new(executing_timestamp, self.timeout, Some(self.chain_id));
+
+        println!(
+            "initiating message: chain {} block {} log {} (timestamp {})",
+            message.chain_id, message.block_number, message.log_index, message.timestamp
+        );
+        for entry in &inbox_entries {
+            println!("  inbox entry: {entry}");
+        }
+
+        for safety in SAFETY_LEVELS {
+            debug!(target: "interop_validate", %safety, "Checking access list");
+            match client.check_access_list(&inbox_entries, safety, descriptor.clone()).await {
+                Ok(()) => {
+                    println!("resolved safety level: {safety}");
+                    return Ok(());
+                }
+                Err(err) => match Rejection::from(err) {
+                    Rejection::InsufficientSafety => continue,
+                    Rejection::Expired => {
+                        return Err(anyhow!(
+                            "message expired before the executing timestamp {executing_timestamp}"
+                        ));
+                    }
+                    Rejection::Unresolved => {
+                        return Err(anyhow!(
+                            "initiating message not found, chain {} may not be synced to block {}",
+                            message.chain_id,
+                            message.block_number
+                        ));
+                    }
+                    Rejection::Other(err) => return Err(err.into()),
+                },
+            }
+        }
+        Err(anyhow!("message is not valid at any safety level"))
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    InteropValidateCommand::parse().run().await
+}